@@ -1,19 +1,31 @@
 //! CPU renderer built on top of `tiny-skia`.
 
-use tiny_skia::{Color, Paint, Pixmap};
+mod kawase;
+
+use std::collections::HashMap;
+
+use tiny_skia::{BlendMode, Color, IntRect, Paint, Pixmap, PixmapPaint, Transform};
+use waterui::filter::ColorMatrix;
+use waterui_color::ResolvedColor;
 use waterui_core::Environment;
 
 use crate::{
+    Point, Rect, Size,
     backend::{FrameResult, RenderBackend},
-    scene::{DrawCommand, Scene},
-    tree::{DirtyReason, LayoutEngine, NodeId, RenderCtx, RenderTree},
+    effects::{self, KawasePlan, Layer, ShadowKey},
+    scene::{DrawCommand, Effect, Scene},
+    tree::{Constraints, DirtyReason, LayoutEngine, NodeId, RenderCtx, RenderTree},
 };
 
+/// The most blurred shadow masks kept between frames.
+const MAX_CACHED_SHADOWS: usize = 64;
+
 /// CPU surface that renders into a `tiny-skia` pixmap.
 pub struct TinySkiaBackend {
     pixmap: Pixmap,
     clear_color: Color,
     scale_factor: f32,
+    shadows: HashMap<ShadowKey, ShadowMask>,
 }
 
 impl core::fmt::Debug for TinySkiaBackend {
@@ -22,6 +34,7 @@ impl core::fmt::Debug for TinySkiaBackend {
             .field("width", &self.width())
             .field("height", &self.height())
             .field("scale_factor", &self.scale_factor)
+            .field("cached_shadows", &self.shadows.len())
             .finish()
     }
 }
@@ -35,6 +48,7 @@ impl TinySkiaBackend {
             pixmap,
             clear_color: Color::from_rgba8(0, 0, 0, 0),
             scale_factor: 1.0,
+            shadows: HashMap::new(),
         })
     }
 
@@ -109,8 +123,10 @@ impl RenderBackend for TinySkiaBackend {
 
         let root = tree.root();
         root.map_or(FrameResult::Idle, |root_id| {
-            let mut engine = LayoutEngine::new(tree, env);
-            engine.run();
+            let viewport = Size::new(self.width() as f32, self.height() as f32);
+            LayoutEngine::new(tree, env)
+                .constraints(Constraints::tight(viewport))
+                .run();
 
             let mut render_ctx = RenderCtx::new(env);
             paint_subtree(tree, &mut render_ctx, root_id);
@@ -129,43 +145,313 @@ fn paint_subtree(tree: &mut RenderTree, ctx: &mut RenderCtx<'_>, id: NodeId) {
     for child in children {
         paint_subtree(tree, ctx, child);
     }
+    if let Some(node) = tree.node_mut(id) {
+        node.paint_after(ctx);
+    }
 }
 
 impl TinySkiaBackend {
     fn rasterize(&mut self, scene: &Scene) {
-        for command in scene.commands() {
-            match command {
-                DrawCommand::SolidRect { rect, color } => {
-                    let ts_rect = match tiny_skia::Rect::from_xywh(
-                        rect.origin.x,
-                        rect.origin.y,
-                        rect.size.width,
-                        rect.size.height,
-                    ) {
-                        Some(value) => value,
-                        None => continue,
-                    };
-                    if let Some(ts_color) = tiny_skia::Color::from_rgba(
-                        color.red,
-                        color.green,
-                        color.blue,
-                        color.opacity,
-                    ) {
-                        let mut paint = Paint::default();
-                        paint.set_color(ts_color);
-                        self.pixmap.fill_rect(
-                            ts_rect,
-                            &paint,
-                            tiny_skia::Transform::identity(),
-                            None,
-                        );
-                    }
-                }
-                DrawCommand::Text { .. } => {
-                    // TODO(text-rendering): integrate cosmic-text to draw glyphs.
-                }
-                DrawCommand::Placeholder(_) => {}
+        let layers = effects::layers(scene.commands());
+        if self.shadows.len() > MAX_CACHED_SHADOWS {
+            self.shadows.clear();
+        }
+        draw_layers(&mut self.pixmap, &layers, &mut self.shadows);
+    }
+}
+
+fn draw_layers(
+    pixmap: &mut Pixmap,
+    layers: &[Layer<'_>],
+    shadows: &mut HashMap<ShadowKey, ShadowMask>,
+) {
+    for layer in layers {
+        match layer {
+            Layer::Draw(command) => draw_command(pixmap, command),
+            Layer::Effect { effect, children } => {
+                draw_effect(pixmap, effect, children, shadows);
+            }
+        }
+    }
+}
+
+fn draw_command(pixmap: &mut Pixmap, command: &DrawCommand) {
+    match command {
+        DrawCommand::SolidRect { rect, color } => {
+            let ts_rect = match tiny_skia::Rect::from_xywh(
+                rect.origin.x,
+                rect.origin.y,
+                rect.size.width,
+                rect.size.height,
+            ) {
+                Some(value) => value,
+                None => return,
+            };
+            if let Some(ts_color) =
+                tiny_skia::Color::from_rgba(color.red, color.green, color.blue, color.opacity)
+            {
+                let mut paint = Paint::default();
+                paint.set_color(ts_color);
+                pixmap.fill_rect(ts_rect, &paint, Transform::identity(), None);
             }
         }
+        DrawCommand::Text { .. } => {
+            // TODO(text-rendering): integrate cosmic-text to draw glyphs.
+        }
+        #[cfg(feature = "lottie")]
+        DrawCommand::Lottie { .. } => {
+            // TODO(lottie): rasterize animation frames without a GPU.
+        }
+        // Effects are grouped into layers before drawing.
+        DrawCommand::PushEffect(_) | DrawCommand::PopEffect | DrawCommand::Placeholder(_) => {}
+    }
+}
+
+fn draw_effect(
+    pixmap: &mut Pixmap,
+    effect: &Effect,
+    children: &[Layer<'_>],
+    shadows: &mut HashMap<ShadowKey, ShadowMask>,
+) {
+    let bounds = effects::bounds(children);
+    match *effect {
+        Effect::Shadow {
+            color,
+            offset,
+            radius,
+        } => {
+            if let Some(bounds) = bounds {
+                draw_shadow(pixmap, children, bounds, color, offset, radius, shadows);
+            }
+            draw_layers(pixmap, children, shadows);
+        }
+        Effect::Blur { radius } => {
+            let (Some(plan), Some(bounds)) = (KawasePlan::for_radius(radius), bounds) else {
+                draw_layers(pixmap, children, shadows);
+                return;
+            };
+            let Some(mut layer) = Pixmap::new(pixmap.width(), pixmap.height()) else {
+                return;
+            };
+            draw_layers(&mut layer, children, shadows);
+            let region = bounds.outset(plan.spread());
+            if let Some(blurred) = blurred_region(&layer, region, plan) {
+                draw(
+                    pixmap,
+                    &blurred.pixmap,
+                    blurred.x,
+                    blurred.y,
+                    BlendMode::SourceOver,
+                );
+            }
+        }
+        Effect::BackdropBlur { radius } => {
+            if let (Some(plan), Some(bounds)) = (KawasePlan::for_radius(radius), bounds) {
+                blur_backdrop(pixmap, bounds, plan);
+            }
+            draw_layers(pixmap, children, shadows);
+        }
+        Effect::ColorFilter { matrix } => {
+            let Some(bounds) = bounds else {
+                draw_layers(pixmap, children, shadows);
+                return;
+            };
+            let Some(mut layer) = Pixmap::new(pixmap.width(), pixmap.height()) else {
+                return;
+            };
+            draw_layers(&mut layer, children, shadows);
+            let Some(int_rect) = pixel_rect(bounds, &layer) else {
+                return;
+            };
+            if let Some(mut region) = layer.clone_rect(int_rect) {
+                filter_colors(region.data_mut(), &matrix);
+                draw(
+                    pixmap,
+                    &region,
+                    int_rect.x(),
+                    int_rect.y(),
+                    BlendMode::SourceOver,
+                );
+            }
+        }
+    }
+}
+
+/// Applies `matrix` to premultiplied RGBA pixels in place.
+fn filter_colors(data: &mut [u8], matrix: &ColorMatrix) {
+    for px in data.chunks_exact_mut(4) {
+        let premultiplied = [0, 1, 2, 3].map(|channel| f32::from(px[channel]) / 255.0);
+        let filtered = effects::filter_pixel(matrix, premultiplied);
+        for (channel, value) in px.iter_mut().zip(filtered) {
+            *channel = (value * 255.0).round() as u8;
+        }
+    }
+}
+
+/// Draws the shadow of `children`, reusing the blurred mask of plain rectangles.
+fn draw_shadow(
+    pixmap: &mut Pixmap,
+    children: &[Layer<'_>],
+    bounds: Rect,
+    color: ResolvedColor,
+    offset: Point,
+    radius: f32,
+    shadows: &mut HashMap<ShadowKey, ShadowMask>,
+) {
+    if let Some((rect, opacity)) = effects::plain_rect(children) {
+        let key = ShadowKey::new(rect.size, radius);
+        let mask = shadows
+            .entry(key)
+            .or_insert_with(|| ShadowMask::for_rect(key));
+        let target = key.mask_rect(rect.origin, offset);
+        if let Some(tinted) = mask.tint(color, opacity) {
+            draw(
+                pixmap,
+                &tinted,
+                target.origin.x.round() as i32,
+                target.origin.y.round() as i32,
+                BlendMode::SourceOver,
+            );
+        }
+        return;
+    }
+
+    // Arbitrary content: blur its silhouette every frame
+    let Some(mut layer) = Pixmap::new(pixmap.width(), pixmap.height()) else {
+        return;
+    };
+    draw_layers(&mut layer, children, shadows);
+    let plan = KawasePlan::for_radius(radius);
+    let region = bounds.outset(plan.map_or(0.0, |plan| plan.spread()));
+    let Some(int_rect) = pixel_rect(region, &layer) else {
+        return;
+    };
+    let Some(mut silhouette) = layer.clone_rect(int_rect) else {
+        return;
+    };
+    let alpha: Vec<u8> = silhouette.data().chunks_exact(4).map(|px| px[3]).collect();
+    fill_tinted(silhouette.data_mut(), &alpha, color, 1.0);
+    if let Some(plan) = plan {
+        let (width, height) = (silhouette.width() as usize, silhouette.height() as usize);
+        kawase::blur(silhouette.data_mut(), width, height, plan);
+    }
+    draw(
+        pixmap,
+        &silhouette,
+        int_rect.x() + offset.x.round() as i32,
+        int_rect.y() + offset.y.round() as i32,
+        BlendMode::SourceOver,
+    );
+}
+
+/// Blurs the pixels under `bounds`, sampling the surroundings so edges stay smooth.
+fn blur_backdrop(pixmap: &mut Pixmap, bounds: Rect, plan: KawasePlan) {
+    let Some(inner) = pixel_rect(bounds, pixmap) else {
+        return;
+    };
+    let Some(blurred) = blurred_region(pixmap, bounds.outset(plan.spread()), plan) else {
+        return;
+    };
+    let Some(clip) = IntRect::from_xywh(
+        inner.x() - blurred.x,
+        inner.y() - blurred.y,
+        inner.width(),
+        inner.height(),
+    ) else {
+        return;
+    };
+    if let Some(inside) = blurred.pixmap.clone_rect(clip) {
+        draw(pixmap, &inside, inner.x(), inner.y(), BlendMode::Source);
+    }
+}
+
+/// A blurred copy of part of a pixmap, and where it was taken from.
+struct Region {
+    pixmap: Pixmap,
+    x: i32,
+    y: i32,
+}
+
+fn blurred_region(source: &Pixmap, region: Rect, plan: KawasePlan) -> Option<Region> {
+    let int_rect = pixel_rect(region, source)?;
+    let mut pixmap = source.clone_rect(int_rect)?;
+    let (width, height) = (pixmap.width() as usize, pixmap.height() as usize);
+    kawase::blur(pixmap.data_mut(), width, height, plan);
+    Some(Region {
+        pixmap,
+        x: int_rect.x(),
+        y: int_rect.y(),
+    })
+}
+
+/// Returns the whole pixels of `rect` inside `pixmap`.
+fn pixel_rect(rect: Rect, pixmap: &Pixmap) -> Option<IntRect> {
+    let left = rect.origin.x.floor().max(0.0);
+    let top = rect.origin.y.floor().max(0.0);
+    let right = rect.max_x().ceil().min(pixmap.width() as f32);
+    let bottom = rect.max_y().ceil().min(pixmap.height() as f32);
+    if right <= left || bottom <= top {
+        return None;
+    }
+    IntRect::from_xywh(
+        left as i32,
+        top as i32,
+        (right - left) as u32,
+        (bottom - top) as u32,
+    )
+}
+
+fn draw(target: &mut Pixmap, source: &Pixmap, x: i32, y: i32, blend_mode: BlendMode) {
+    let paint = PixmapPaint {
+        blend_mode,
+        ..PixmapPaint::default()
+    };
+    target.draw_pixmap(x, y, source.as_ref(), &paint, Transform::identity(), None);
+}
+
+/// Writes `color` at the coverage in `alpha` into premultiplied RGBA pixels.
+fn fill_tinted(data: &mut [u8], alpha: &[u8], color: ResolvedColor, opacity: f32) {
+    let rgba = [color.red, color.green, color.blue];
+    let color_alpha = color.opacity.clamp(0.0, 1.0) * opacity.clamp(0.0, 1.0);
+    for (px, coverage) in data.chunks_exact_mut(4).zip(alpha) {
+        let a = color_alpha * f32::from(*coverage) / 255.0;
+        for (channel, value) in rgba.iter().enumerate() {
+            px[channel] = (value.clamp(0.0, 1.0) * a * 255.0).round() as u8;
+        }
+        px[3] = (a * 255.0).round() as u8;
+    }
+}
+
+/// The blurred coverage of a rectangle's shadow, as computed for a [`ShadowKey`].
+struct ShadowMask {
+    width: u32,
+    height: u32,
+    alpha: Vec<u8>,
+}
+
+impl ShadowMask {
+    fn for_rect(key: ShadowKey) -> Self {
+        let (width, height) = key.mask_size();
+        let padding = key.padding() as usize;
+        let (w, h) = (width as usize, height as usize);
+        let mut rgba = vec![0u8; w * h * 4];
+        for y in padding..padding + key.height as usize {
+            let row = y * w;
+            rgba[(row + padding) * 4..(row + padding + key.width as usize) * 4].fill(255);
+        }
+        if let Some(plan) = key.plan() {
+            kawase::blur(&mut rgba, w, h, plan);
+        }
+        Self {
+            width,
+            height,
+            alpha: rgba.chunks_exact(4).map(|px| px[3]).collect(),
+        }
+    }
+
+    fn tint(&self, color: ResolvedColor, opacity: f32) -> Option<Pixmap> {
+        let mut pixmap = Pixmap::new(self.width, self.height)?;
+        fill_tinted(pixmap.data_mut(), &self.alpha, color, opacity);
+        Some(pixmap)
     }
 }
@@ -0,0 +1,147 @@
+//! Dual-Kawase blur over premultiplied RGBA8 pixels.
+//!
+//! This mirrors the GPU passes in `backend/gpu/effects.wgsl` so both backends blur alike.
+
+use crate::effects::KawasePlan;
+
+/// Premultiplied RGBA pixels with floating point channels.
+struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<[f32; 4]>,
+}
+
+impl Image {
+    fn from_rgba8(data: &[u8], width: usize, height: usize) -> Self {
+        let pixels = data
+            .chunks_exact(4)
+            .map(|px| {
+                [
+                    f32::from(px[0]),
+                    f32::from(px[1]),
+                    f32::from(px[2]),
+                    f32::from(px[3]),
+                ]
+            })
+            .collect();
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    fn write_rgba8(&self, data: &mut [u8]) {
+        for (px, value) in data.chunks_exact_mut(4).zip(&self.pixels) {
+            // Rounding may push a color channel above its alpha, which premultiplied
+            // pixels must never have
+            let alpha = value[3].round().clamp(0.0, 255.0);
+            px[3] = alpha as u8;
+            for channel in 0..3 {
+                px[channel] = value[channel].round().clamp(0.0, alpha) as u8;
+            }
+        }
+    }
+
+    /// Samples with bilinear filtering at a position in pixels, clamping to the edges.
+    fn sample(&self, x: f32, y: f32) -> [f32; 4] {
+        let x = (x - 0.5).clamp(0.0, (self.width - 1) as f32);
+        let y = (y - 0.5).clamp(0.0, (self.height - 1) as f32);
+        let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+        let (x1, y1) = ((x0 + 1).min(self.width - 1), (y0 + 1).min(self.height - 1));
+        let (fx, fy) = (x - x0 as f32, y - y0 as f32);
+        let at = |x: usize, y: usize| self.pixels[y * self.width + x];
+        let (a, b, c, d) = (at(x0, y0), at(x1, y0), at(x0, y1), at(x1, y1));
+        core::array::from_fn(|i| {
+            let top = a[i] + (b[i] - a[i]) * fx;
+            let bottom = c[i] + (d[i] - c[i]) * fx;
+            top + (bottom - top) * fy
+        })
+    }
+
+    /// Renders an image of `width` by `height` pixels, calling `pixel` with the position each
+    /// pixel's center maps to in `self` and the size of a destination pixel there.
+    fn resample(
+        &self,
+        width: usize,
+        height: usize,
+        pixel: impl Fn(&Self, f32, f32, f32, f32) -> [f32; 4],
+    ) -> Self {
+        let scale_x = self.width as f32 / width as f32;
+        let scale_y = self.height as f32 / height as f32;
+        let mut pixels = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let center_x = (x as f32 + 0.5) * scale_x;
+                let center_y = (y as f32 + 0.5) * scale_y;
+                pixels.push(pixel(self, center_x, center_y, scale_x, scale_y));
+            }
+        }
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    fn downsample(&self, offset: f32) -> Self {
+        let width = (self.width / 2).max(1);
+        let height = (self.height / 2).max(1);
+        self.resample(width, height, |src, x, y, scale_x, scale_y| {
+            let dx = offset * scale_x * 0.5;
+            let dy = offset * scale_y * 0.5;
+            let taps = [
+                (src.sample(x, y), 4.0),
+                (src.sample(x - dx, y - dy), 1.0),
+                (src.sample(x + dx, y + dy), 1.0),
+                (src.sample(x + dx, y - dy), 1.0),
+                (src.sample(x - dx, y + dy), 1.0),
+            ];
+            weighted(&taps, 8.0)
+        })
+    }
+
+    fn upsample(&self, width: usize, height: usize, offset: f32) -> Self {
+        self.resample(width, height, |src, x, y, scale_x, scale_y| {
+            let dx = offset * scale_x;
+            let dy = offset * scale_y;
+            let taps = [
+                (src.sample(x - dx, y), 1.0),
+                (src.sample(x + dx, y), 1.0),
+                (src.sample(x, y - dy), 1.0),
+                (src.sample(x, y + dy), 1.0),
+                (src.sample(x - dx * 0.5, y - dy * 0.5), 2.0),
+                (src.sample(x + dx * 0.5, y - dy * 0.5), 2.0),
+                (src.sample(x - dx * 0.5, y + dy * 0.5), 2.0),
+                (src.sample(x + dx * 0.5, y + dy * 0.5), 2.0),
+            ];
+            weighted(&taps, 12.0)
+        })
+    }
+}
+
+fn weighted(taps: &[([f32; 4], f32)], total: f32) -> [f32; 4] {
+    core::array::from_fn(|i| {
+        taps.iter()
+            .map(|(color, weight)| color[i] * weight)
+            .sum::<f32>()
+            / total
+    })
+}
+
+/// Blurs `width` by `height` premultiplied RGBA8 pixels in place.
+pub fn blur(data: &mut [u8], width: usize, height: usize, plan: KawasePlan) {
+    if width == 0 || height == 0 {
+        return;
+    }
+    let mut levels = vec![Image::from_rgba8(data, width, height)];
+    for _ in 0..plan.iterations {
+        let next = levels[levels.len() - 1].downsample(plan.offset);
+        levels.push(next);
+    }
+    let mut image = levels.pop().expect("the source image is always present");
+    while let Some(target) = levels.pop() {
+        image = image.upsample(target.width, target.height, plan.offset);
+    }
+    image.write_rgba8(data);
+}
@@ -1,20 +1,32 @@
 //! GPU renderer built on top of Vello/wgpu.
+//!
+//! Scenes without effects are drawn by Vello into an intermediate texture and blitted to the
+//! surface; scenes with shadows or blurs are drawn by the passes in [`effects`].
+
+mod effects;
 
 use std::fmt;
 
 use tracing::error;
 use vello::{
-    Renderer, RendererError, RendererOptions, Scene as VelloScene, SceneBuilder,
+    AaConfig, Error as RendererError, RenderParams, Renderer, RendererOptions, Scene as VelloScene,
     kurbo::{Affine, Rect as KurboRect},
     peniko::{Brush, Color as PenikoColor, Fill},
 };
 use waterui_core::Environment;
-use wgpu::{Device, Queue, Surface, SurfaceConfiguration, SurfaceError, SurfaceTexture};
+use wgpu::{
+    CommandEncoderDescriptor, Device, Extent3d, Queue, Surface, SurfaceConfiguration, SurfaceError,
+    SurfaceTexture, Texture, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+    TextureViewDescriptor, util::TextureBlitter,
+};
+
+use self::effects::EffectRenderer;
 
 use crate::{
+    Size,
     backend::{FrameResult, RenderBackend},
     scene::{DrawCommand, Scene as HydroScene},
-    tree::{DirtyReason, LayoutEngine, NodeId, RenderCtx, RenderTree},
+    tree::{Constraints, DirtyReason, LayoutEngine, NodeId, RenderCtx, RenderTree},
 };
 
 /// GPU backend that renders Hydrolysis scenes using Vello and wgpu surfaces.
@@ -24,6 +36,13 @@ pub struct VelloWgpuBackend<'surface> {
     queue: Queue,
     config: SurfaceConfiguration,
     renderer: Renderer,
+    /// Vello only writes to storage textures, so frames are drawn here and blitted to the surface.
+    target: Option<Texture>,
+    blitter: TextureBlitter,
+    /// Created on the first frame with effects.
+    effects: Option<EffectRenderer>,
+    #[cfg(feature = "lottie")]
+    lottie: velato::Renderer,
 }
 
 impl<'surface> fmt::Debug for VelloWgpuBackend<'surface> {
@@ -44,12 +63,18 @@ impl<'surface> VelloWgpuBackend<'surface> {
         config: SurfaceConfiguration,
     ) -> Result<Self, RendererError> {
         let renderer = Renderer::new(&device, RendererOptions::default())?;
+        let blitter = TextureBlitter::new(&device, config.format);
         let mut backend = Self {
             surface,
             device,
             queue,
             config,
             renderer,
+            target: None,
+            blitter,
+            effects: None,
+            #[cfg(feature = "lottie")]
+            lottie: velato::Renderer::new(),
         };
         backend.configure_surface();
         Ok(backend)
@@ -57,7 +82,11 @@ impl<'surface> VelloWgpuBackend<'surface> {
 
     /// Reconfigures the swapchain (call when the window resizes).
     pub fn reconfigure(&mut self, config: SurfaceConfiguration) {
+        if config.format != self.config.format {
+            self.blitter = TextureBlitter::new(&self.device, config.format);
+        }
         self.config = config;
+        self.target = None;
         self.configure_surface();
     }
 
@@ -74,6 +103,9 @@ fn paint_subtree(tree: &mut RenderTree, ctx: &mut RenderCtx<'_>, id: NodeId) {
     for child in children {
         paint_subtree(tree, ctx, child);
     }
+    if let Some(node) = tree.node_mut(id) {
+        node.paint_after(ctx);
+    }
 }
 
 impl<'surface> RenderBackend for VelloWgpuBackend<'surface> {
@@ -110,8 +142,10 @@ impl<'surface> RenderBackend for VelloWgpuBackend<'surface> {
             }
         };
 
-        let mut engine = LayoutEngine::new(tree, env);
-        engine.run();
+        let viewport = Size::new(self.config.width as f32, self.config.height as f32);
+        LayoutEngine::new(tree, env)
+            .constraints(Constraints::tight(viewport))
+            .run();
 
         let mut render_ctx = RenderCtx::new(env);
         paint_subtree(tree, &mut render_ctx, root_id);
@@ -123,45 +157,147 @@ impl<'surface> RenderBackend for VelloWgpuBackend<'surface> {
 
 impl<'surface> VelloWgpuBackend<'surface> {
     fn present_scene(&mut self, scene: HydroScene, surface_texture: SurfaceTexture) {
-        let vello_scene = self.build_vello_scene(&scene);
-        if let Err(error) = self.renderer.render_to_surface(
+        let has_effects = scene
+            .commands()
+            .iter()
+            .any(|command| matches!(command, DrawCommand::PushEffect(_)));
+        if has_effects {
+            self.present_with_effects(&scene, &surface_texture);
+            surface_texture.present();
+            return;
+        }
+
+        let vello_scene = self.build_vello_scene(scene.commands());
+        let target = self
+            .target
+            .take()
+            .unwrap_or_else(|| create_target(&self.device, self.config.width, self.config.height));
+        let target_view = target.create_view(&TextureViewDescriptor::default());
+        if let Err(error) = self.renderer.render_to_texture(
             &self.device,
             &self.queue,
             &vello_scene,
-            &surface_texture,
-            &self.config,
+            &target_view,
+            &self.params(),
         ) {
-            error!("vello render_to_surface failed: {error:?}");
+            error!("vello render_to_texture failed: {error:?}");
         }
 
+        let surface_view = surface_texture
+            .texture
+            .create_view(&TextureViewDescriptor::default());
+        let mut encoder = self
+            .device
+            .create_command_encoder(&CommandEncoderDescriptor {
+                label: Some("hydrolysis-blit"),
+            });
+        self.blitter
+            .copy(&self.device, &mut encoder, &target_view, &surface_view);
+        self.queue.submit([encoder.finish()]);
+        self.target = Some(target);
+
         surface_texture.present();
     }
 
-    fn build_vello_scene(&self, scene: &HydroScene) -> VelloScene {
-        let mut builder = SceneBuilder::new();
-        for command in scene.commands() {
+    const fn params(&self) -> RenderParams {
+        RenderParams {
+            base_color: PenikoColor::TRANSPARENT,
+            width: self.config.width,
+            height: self.config.height,
+            antialiasing_method: AaConfig::Area,
+        }
+    }
+
+    fn present_with_effects(&mut self, scene: &HydroScene, surface_texture: &SurfaceTexture) {
+        let device = self.device.clone();
+        let queue = self.queue.clone();
+        let mut effects = self
+            .effects
+            .take()
+            .unwrap_or_else(|| EffectRenderer::new(&device));
+        let params = self.params();
+        let mut draw = |commands: &[&DrawCommand], view: &wgpu::TextureView| {
+            let vello_scene = self.build_vello_scene(commands.iter().copied());
+            if let Err(error) =
+                self.renderer
+                    .render_to_texture(&device, &queue, &vello_scene, view, &params)
+            {
+                error!("vello render_to_texture failed: {error:?}");
+            }
+        };
+        effects.render(
+            &device,
+            &queue,
+            scene.commands(),
+            &surface_texture.texture,
+            &mut draw,
+        );
+        self.effects = Some(effects);
+    }
+
+    fn build_vello_scene<'a>(
+        &mut self,
+        commands: impl IntoIterator<Item = &'a DrawCommand>,
+    ) -> VelloScene {
+        let mut vello_scene = VelloScene::new();
+        for command in commands {
             match command {
                 DrawCommand::SolidRect { rect, color } => {
-                    let brush = Brush::Solid(PenikoColor::new(
-                        color.opacity,
+                    let brush = Brush::Solid(PenikoColor::new([
                         color.red,
                         color.green,
                         color.blue,
-                    ));
+                        color.opacity,
+                    ]));
                     let kurbo_rect = KurboRect::new(
                         f64::from(rect.origin.x),
                         f64::from(rect.origin.y),
                         f64::from(rect.origin.x + rect.size.width),
                         f64::from(rect.origin.y + rect.size.height),
                     );
-                    builder.fill(Fill::NonZero, Affine::IDENTITY, &brush, None, &kurbo_rect);
+                    vello_scene.fill(Fill::NonZero, Affine::IDENTITY, &brush, None, &kurbo_rect);
                 }
                 DrawCommand::Text { .. } => {
                     // TODO(text-rendering): convert text commands into Vello glyph runs.
                 }
+                #[cfg(feature = "lottie")]
+                DrawCommand::Lottie {
+                    composition,
+                    frame,
+                    rect,
+                } => {
+                    let scale = (f64::from(rect.size.width) / composition.width as f64)
+                        .min(f64::from(rect.size.height) / composition.height as f64);
+                    let transform =
+                        Affine::translate((f64::from(rect.origin.x), f64::from(rect.origin.y)))
+                            * Affine::scale(scale);
+                    let mut frame_scene = VelloScene::new();
+                    self.lottie
+                        .append(composition, *frame, transform, 1.0, &mut frame_scene);
+                    vello_scene.append(&frame_scene, None);
+                }
                 DrawCommand::Placeholder(_) => {}
+                // Effects are applied by `EffectRenderer` around the runs it hands to Vello.
+                DrawCommand::PushEffect(_) | DrawCommand::PopEffect => {}
             }
         }
-        builder.finish()
+        vello_scene
     }
 }
+
+fn create_target(device: &Device, width: u32, height: u32) -> Texture {
+    device.create_texture(&TextureDescriptor {
+        label: Some("hydrolysis-vello-target"),
+        size: Extent3d {
+            width: width.max(1),
+            height: height.max(1),
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format: TextureFormat::Rgba8Unorm,
+        usage: TextureUsages::STORAGE_BINDING | TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    })
+}
@@ -0,0 +1,954 @@
+//! GPU passes for shadow, blur, backdrop and color filter effects.
+//!
+//! Vello has no filter primitives, so scenes containing effects are drawn in segments onto an
+//! offscreen canvas. Runs of plain commands are rendered by Vello into a transparent scratch
+//! texture and composited onto the canvas. At each effect:
+//!
+//! - blur: the group is drawn into its own layer, which is blurred and composited
+//! - backdrop blur: the canvas under the group is blurred in place, then the group is drawn
+//! - shadow: the group's silhouette is blurred, tinted and composited at the shadow offset
+//!   before the group is drawn. Plain rectangles take their blurred mask from the shadow atlas
+//!   instead, so repeated shadows are blurred once.
+//! - color filter: the group is drawn into its own layer, which is composited through the color
+//!   matrix
+//!
+//! Blurs are dual-Kawase (see [`KawasePlan`]), one render pass per downsample or upsample step.
+//! Finally the canvas is copied to the surface.
+
+use std::collections::HashMap;
+
+use wgpu::{
+    BindGroupDescriptor, BindGroupEntry, BindGroupLayout, BindGroupLayoutDescriptor,
+    BindGroupLayoutEntry, BindingResource, BindingType, BlendState, BufferBindingType,
+    BufferUsages, Color, ColorTargetState, ColorWrites, CommandEncoder, CommandEncoderDescriptor,
+    Device, Extent3d, FilterMode, FragmentState, LoadOp, MultisampleState, Operations, Origin3d,
+    PipelineLayoutDescriptor, PrimitiveState, PrimitiveTopology, Queue, RenderPassColorAttachment,
+    RenderPassDescriptor, RenderPipeline, RenderPipelineDescriptor, Sampler, SamplerBindingType,
+    SamplerDescriptor, ShaderModule, ShaderModuleDescriptor, ShaderSource, ShaderStages, StoreOp,
+    TexelCopyTextureInfo, Texture, TextureAspect, TextureDescriptor, TextureDimension,
+    TextureFormat, TextureSampleType, TextureUsages, TextureView, TextureViewDescriptor,
+    TextureViewDimension, VertexState,
+    util::{BufferInitDescriptor, DeviceExt},
+};
+
+use waterui::filter::ColorMatrix;
+use waterui_color::ResolvedColor;
+
+use crate::{
+    Point, Rect, Size,
+    effects::{self, AtlasRect, KawasePlan, Layer, ShadowAtlas, ShadowKey},
+    scene::{DrawCommand, Effect},
+};
+
+/// Format of the canvas, layers and atlas, which Vello renders into.
+const FORMAT: TextureFormat = TextureFormat::Rgba8Unorm;
+
+/// Width and height of the shadow atlas texture.
+const ATLAS_SIZE: u32 = 2048;
+
+/// Most unused textures kept for later passes.
+const MAX_POOLED_TEXTURES: usize = 16;
+
+/// Renders Vello output for a run of commands into a texture view.
+pub type DrawCommands<'a> = dyn FnMut(&[&DrawCommand], &TextureView) + 'a;
+
+/// Fragment entry points of `effects.wgsl`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Pass {
+    Down,
+    Up,
+    Composite,
+    ColorMatrix,
+}
+
+impl Pass {
+    const fn entry_point(self) -> &'static str {
+        match self {
+            Self::Down => "fs_down",
+            Self::Up => "fs_up",
+            Self::Composite => "fs_composite",
+            Self::ColorMatrix => "fs_color_matrix",
+        }
+    }
+}
+
+/// How a composite pass treats its source, matching the modes of `fs_composite`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Premultiplied = 0,
+    Straight = 1,
+    Tint = 2,
+    Solid = 3,
+}
+
+/// How a pass combines its output with the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Blend {
+    Over,
+    Replace,
+}
+
+impl Blend {
+    const fn state(self) -> Option<BlendState> {
+        match self {
+            Self::Over => Some(BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            Self::Replace => None,
+        }
+    }
+}
+
+/// The uniforms of `effects.wgsl`.
+#[derive(Debug, Clone, Copy, Default)]
+struct Uniforms {
+    dst: [f32; 4],
+    src: [f32; 4],
+    color: [f32; 4],
+    params: [f32; 4],
+    /// Columns of the color matrix of `fs_color_matrix`: the weights of red, green, blue and
+    /// alpha, then the offsets.
+    matrix: [[f32; 4]; 5],
+}
+
+impl Uniforms {
+    fn to_bytes(self) -> Vec<u8> {
+        [self.dst, self.src, self.color, self.params]
+            .iter()
+            .chain(&self.matrix)
+            .flatten()
+            .flat_map(|value| value.to_ne_bytes())
+            .collect()
+    }
+}
+
+/// Returns the columns of a row-major color matrix.
+fn matrix_columns(matrix: &ColorMatrix) -> [[f32; 4]; 5] {
+    core::array::from_fn(|column| core::array::from_fn(|row| matrix.values[row * 5 + column]))
+}
+
+/// A region of a texture, in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Region {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+impl Region {
+    fn of(texture: &Texture) -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            width: texture.width(),
+            height: texture.height(),
+        }
+    }
+
+    fn rect(self) -> Rect {
+        Rect::new(
+            Point::new(self.x as f32, self.y as f32),
+            Size::new(self.width as f32, self.height as f32),
+        )
+    }
+
+    /// Returns the region relative to `outer`, which must contain it.
+    const fn within(self, outer: Self) -> Self {
+        Self {
+            x: self.x - outer.x,
+            y: self.y - outer.y,
+            ..self
+        }
+    }
+}
+
+impl From<AtlasRect> for Region {
+    fn from(rect: AtlasRect) -> Self {
+        Self {
+            x: rect.x,
+            y: rect.y,
+            width: rect.width,
+            height: rect.height,
+        }
+    }
+}
+
+/// The GPU state of one frame with effects.
+struct Frame<'a, 'draw> {
+    device: &'a Device,
+    queue: &'a Queue,
+    width: u32,
+    height: u32,
+    draw: &'a mut DrawCommands<'draw>,
+}
+
+impl Frame<'_, '_> {
+    /// Returns the whole pixels of `rect` on the canvas.
+    fn region(&self, rect: Rect) -> Option<Region> {
+        let left = rect.origin.x.floor().max(0.0);
+        let top = rect.origin.y.floor().max(0.0);
+        let right = rect.max_x().ceil().min(self.width as f32);
+        let bottom = rect.max_y().ceil().min(self.height as f32);
+        (right > left && bottom > top).then_some(Region {
+            x: left as u32,
+            y: top as u32,
+            width: (right - left) as u32,
+            height: (bottom - top) as u32,
+        })
+    }
+
+    fn encoder(&self) -> CommandEncoder {
+        self.device
+            .create_command_encoder(&CommandEncoderDescriptor {
+                label: Some("hydrolysis-effects"),
+            })
+    }
+
+    /// Submits `encoder` right away, so its passes run before Vello renders the next segment.
+    fn submit(&self, encoder: CommandEncoder) {
+        self.queue.submit([encoder.finish()]);
+    }
+}
+
+/// Renders scenes containing effects.
+pub struct EffectRenderer {
+    shader: ShaderModule,
+    bind_group_layout: BindGroupLayout,
+    sampler: Sampler,
+    pipelines: HashMap<(TextureFormat, Pass, Blend), RenderPipeline>,
+    pool: Vec<Texture>,
+    canvas: Option<Texture>,
+    /// Bound as the source of passes that do not sample anything.
+    blank: Texture,
+    atlas: ShadowAtlas,
+    atlas_texture: Texture,
+}
+
+impl core::fmt::Debug for EffectRenderer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EffectRenderer")
+            .field("pipelines", &self.pipelines.len())
+            .field("pooled_textures", &self.pool.len())
+            .field("atlas", &self.atlas)
+            .finish_non_exhaustive()
+    }
+}
+
+impl EffectRenderer {
+    /// Creates the shaders, sampler and shadow atlas.
+    pub fn new(device: &Device) -> Self {
+        let shader = device.create_shader_module(ShaderModuleDescriptor {
+            label: Some("hydrolysis-effects"),
+            source: ShaderSource::Wgsl(include_str!("effects.wgsl").into()),
+        });
+        let bind_group_layout = device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            label: Some("hydrolysis-effects"),
+            entries: &[
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: TextureSampleType::Float { filterable: true },
+                        view_dimension: TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: ShaderStages::VERTEX_FRAGMENT,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let sampler = device.create_sampler(&SamplerDescriptor {
+            label: Some("hydrolysis-effects"),
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..SamplerDescriptor::default()
+        });
+        let atlas = ShadowAtlas::new(ATLAS_SIZE, ATLAS_SIZE);
+        Self {
+            shader,
+            bind_group_layout,
+            sampler,
+            pipelines: HashMap::new(),
+            pool: Vec::new(),
+            canvas: None,
+            blank: create_texture(device, 1, 1),
+            atlas_texture: create_texture(device, ATLAS_SIZE, ATLAS_SIZE),
+            atlas,
+        }
+    }
+
+    /// Draws `commands` with their effects onto `target`, which must be `width` by `height`
+    /// pixels, using `draw` to render runs of plain commands with Vello.
+    pub fn render(
+        &mut self,
+        device: &Device,
+        queue: &Queue,
+        commands: &[DrawCommand],
+        target: &Texture,
+        draw: &mut DrawCommands<'_>,
+    ) {
+        let (width, height) = (target.width(), target.height());
+        let mut frame = Frame {
+            device,
+            queue,
+            width,
+            height,
+            draw,
+        };
+        self.atlas.begin_frame();
+
+        let canvas = match self.canvas.take() {
+            Some(canvas) if canvas.width() == width && canvas.height() == height => canvas,
+            _ => create_texture(device, width, height),
+        };
+        self.clear(&frame, &canvas);
+        self.draw_layers(&mut frame, &effects::layers(commands), &canvas);
+
+        let mut encoder = frame.encoder();
+        self.pass(
+            &frame,
+            &mut encoder,
+            Pass::Composite,
+            Blend::Replace,
+            &canvas,
+            Region::of(&canvas),
+            target,
+            Region::of(target).rect(),
+            composite_params(Mode::Premultiplied),
+            [0.0; 4],
+        );
+        frame.submit(encoder);
+        self.canvas = Some(canvas);
+    }
+
+    fn draw_layers(&mut self, frame: &mut Frame<'_, '_>, layers: &[Layer<'_>], target: &Texture) {
+        let mut run = Vec::new();
+        for layer in layers {
+            match layer {
+                Layer::Draw(command) => run.push(*command),
+                Layer::Effect { effect, children } => {
+                    self.flush(frame, &mut run, target);
+                    self.draw_effect(frame, effect, children, target);
+                }
+            }
+        }
+        self.flush(frame, &mut run, target);
+    }
+
+    /// Renders a run of plain commands with Vello and composites it onto `target`.
+    fn flush(&mut self, frame: &mut Frame<'_, '_>, run: &mut Vec<&DrawCommand>, target: &Texture) {
+        if run.is_empty() {
+            return;
+        }
+        let scratch = self.take(frame.device, frame.width, frame.height);
+        (frame.draw)(run, &scratch.create_view(&TextureViewDescriptor::default()));
+        run.clear();
+        self.composite(
+            frame,
+            &scratch,
+            Region::of(&scratch),
+            target,
+            Region::of(target).rect(),
+            Mode::Straight,
+            [0.0; 4],
+        );
+        self.give(scratch);
+    }
+
+    fn draw_effect(
+        &mut self,
+        frame: &mut Frame<'_, '_>,
+        effect: &Effect,
+        children: &[Layer<'_>],
+        target: &Texture,
+    ) {
+        let bounds = effects::bounds(children);
+        match *effect {
+            Effect::Shadow {
+                color,
+                offset,
+                radius,
+            } => {
+                if let Some(bounds) = bounds {
+                    self.draw_shadow(frame, children, bounds, color, offset, radius, target);
+                }
+                self.draw_layers(frame, children, target);
+            }
+            Effect::Blur { radius } => {
+                let (Some(plan), Some(bounds)) = (KawasePlan::for_radius(radius), bounds) else {
+                    self.draw_layers(frame, children, target);
+                    return;
+                };
+                let layer = self.layer(frame);
+                self.draw_layers(frame, children, &layer);
+                if let Some(region) = frame.region(bounds.outset(plan.spread())) {
+                    let blurred = self.blur(frame, &layer, region, plan);
+                    self.composite(
+                        frame,
+                        &blurred,
+                        Region::of(&blurred),
+                        target,
+                        region.rect(),
+                        Mode::Premultiplied,
+                        [0.0; 4],
+                    );
+                    self.give(blurred);
+                }
+                self.give(layer);
+            }
+            Effect::BackdropBlur { radius } => {
+                if let (Some(plan), Some(bounds)) = (KawasePlan::for_radius(radius), bounds) {
+                    self.blur_backdrop(frame, bounds, plan, target);
+                }
+                self.draw_layers(frame, children, target);
+            }
+            Effect::ColorFilter { matrix } => {
+                let Some(bounds) = bounds else {
+                    self.draw_layers(frame, children, target);
+                    return;
+                };
+                let layer = self.layer(frame);
+                self.draw_layers(frame, children, &layer);
+                if let Some(region) = frame.region(bounds) {
+                    let mut encoder = frame.encoder();
+                    self.quad(
+                        frame,
+                        &mut encoder,
+                        Pass::ColorMatrix,
+                        Blend::Over,
+                        &layer,
+                        target,
+                        Uniforms {
+                            dst: clip_rect(region.rect(), target),
+                            src: uv_rect(region, &layer),
+                            matrix: matrix_columns(&matrix),
+                            ..Uniforms::default()
+                        },
+                    );
+                    frame.submit(encoder);
+                }
+                self.give(layer);
+            }
+        }
+    }
+
+    /// Blurs the pixels of `target` under `bounds`, sampling the surroundings so edges stay
+    /// smooth.
+    fn blur_backdrop(
+        &mut self,
+        frame: &Frame<'_, '_>,
+        bounds: Rect,
+        plan: KawasePlan,
+        target: &Texture,
+    ) {
+        let (Some(inner), Some(outer)) = (
+            frame.region(bounds),
+            frame.region(bounds.outset(plan.spread())),
+        ) else {
+            return;
+        };
+        let blurred = self.blur(frame, target, outer, plan);
+        let mut encoder = frame.encoder();
+        self.pass(
+            frame,
+            &mut encoder,
+            Pass::Composite,
+            Blend::Replace,
+            &blurred,
+            inner.within(outer),
+            target,
+            inner.rect(),
+            composite_params(Mode::Premultiplied),
+            [0.0; 4],
+        );
+        frame.submit(encoder);
+        self.give(blurred);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_shadow(
+        &mut self,
+        frame: &mut Frame<'_, '_>,
+        children: &[Layer<'_>],
+        bounds: Rect,
+        color: ResolvedColor,
+        offset: Point,
+        radius: f32,
+        target: &Texture,
+    ) {
+        if let Some((rect, opacity)) = effects::plain_rect(children) {
+            let key = ShadowKey::new(rect.size, radius);
+            if let Some(slot) = self.shadow_mask(frame, key) {
+                let atlas = self.atlas_texture.clone();
+                self.composite(
+                    frame,
+                    &atlas,
+                    slot.into(),
+                    target,
+                    key.mask_rect(rect.origin, offset),
+                    Mode::Tint,
+                    tint(color, opacity),
+                );
+                return;
+            }
+        }
+
+        // Arbitrary content, or a mask too large for the atlas: blur its silhouette every frame
+        let layer = self.layer(frame);
+        self.draw_layers(frame, children, &layer);
+        let plan = KawasePlan::for_radius(radius);
+        if let Some(region) = frame.region(bounds.outset(plan.map_or(0.0, |plan| plan.spread()))) {
+            let silhouette = match plan {
+                Some(plan) => self.blur(frame, &layer, region, plan),
+                None => self.copy(frame, &layer, region),
+            };
+            self.composite(
+                frame,
+                &silhouette,
+                Region::of(&silhouette),
+                target,
+                region.rect().translate(offset),
+                Mode::Tint,
+                tint(color, 1.0),
+            );
+            self.give(silhouette);
+        }
+        self.give(layer);
+    }
+
+    /// Returns where the blurred mask of `key` is in the atlas, rendering it if needed.
+    fn shadow_mask(&mut self, frame: &Frame<'_, '_>, key: ShadowKey) -> Option<AtlasRect> {
+        if let Some(slot) = self.atlas.get(key) {
+            return Some(slot);
+        }
+        let slot = self.atlas.allocate(key)?;
+        let (width, height) = key.mask_size();
+        let mask = self.take(frame.device, width, height);
+        self.clear(frame, &mask);
+        let padding = key.padding() as f32;
+        let blank = self.blank.clone();
+        let mut encoder = frame.encoder();
+        self.pass(
+            frame,
+            &mut encoder,
+            Pass::Composite,
+            Blend::Replace,
+            &blank,
+            Region::of(&blank),
+            &mask,
+            Rect::new(
+                Point::new(padding, padding),
+                Size::new(key.width as f32, key.height as f32),
+            ),
+            composite_params(Mode::Solid),
+            [1.0; 4],
+        );
+        frame.submit(encoder);
+
+        let blurred = match key.plan() {
+            Some(plan) => {
+                let blurred = self.blur(frame, &mask, Region::of(&mask), plan);
+                self.give(mask);
+                blurred
+            }
+            None => mask,
+        };
+        let mut encoder = frame.encoder();
+        copy_region(
+            &mut encoder,
+            &blurred,
+            Region::of(&blurred),
+            &self.atlas_texture,
+            slot.x,
+            slot.y,
+        );
+        frame.submit(encoder);
+        self.give(blurred);
+        Some(slot)
+    }
+
+    /// Returns a blurred copy of `region` of `source`, the size of the region.
+    fn blur(
+        &mut self,
+        frame: &Frame<'_, '_>,
+        source: &Texture,
+        region: Region,
+        plan: KawasePlan,
+    ) -> Texture {
+        let mut levels = vec![self.copy(frame, source, region)];
+        let mut encoder = frame.encoder();
+        for _ in 0..plan.iterations {
+            let previous = &levels[levels.len() - 1];
+            let (width, height) = (
+                (previous.width() / 2).max(1),
+                (previous.height() / 2).max(1),
+            );
+            let next = self.take(frame.device, width, height);
+            self.kawase_pass(
+                frame,
+                &mut encoder,
+                Pass::Down,
+                previous,
+                &next,
+                plan.offset,
+            );
+            levels.push(next);
+        }
+        // Textures go back to the pool only once the passes reading them are submitted
+        let mut used = Vec::new();
+        let mut image = levels.pop().expect("the copied region is always present");
+        while let Some(level) = levels.pop() {
+            let next = self.take(frame.device, level.width(), level.height());
+            self.kawase_pass(frame, &mut encoder, Pass::Up, &image, &next, plan.offset);
+            used.push(std::mem::replace(&mut image, next));
+            used.push(level);
+        }
+        frame.submit(encoder);
+        for texture in used {
+            self.give(texture);
+        }
+        image
+    }
+
+    fn kawase_pass(
+        &mut self,
+        frame: &Frame<'_, '_>,
+        encoder: &mut CommandEncoder,
+        pass: Pass,
+        source: &Texture,
+        target: &Texture,
+        offset: f32,
+    ) {
+        let params = [
+            offset,
+            0.0,
+            1.0 / target.width() as f32,
+            1.0 / target.height() as f32,
+        ];
+        self.pass(
+            frame,
+            encoder,
+            pass,
+            Blend::Replace,
+            source,
+            Region::of(source),
+            target,
+            Region::of(target).rect(),
+            params,
+            [0.0; 4],
+        );
+    }
+
+    /// Returns a copy of `region` of `source`, the size of the region.
+    fn copy(&mut self, frame: &Frame<'_, '_>, source: &Texture, region: Region) -> Texture {
+        let copy = self.take(frame.device, region.width, region.height);
+        let mut encoder = frame.encoder();
+        copy_region(&mut encoder, source, region, &copy, 0, 0);
+        frame.submit(encoder);
+        copy
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn composite(
+        &mut self,
+        frame: &Frame<'_, '_>,
+        source: &Texture,
+        src: Region,
+        target: &Texture,
+        dst: Rect,
+        mode: Mode,
+        color: [f32; 4],
+    ) {
+        let mut encoder = frame.encoder();
+        self.pass(
+            frame,
+            &mut encoder,
+            Pass::Composite,
+            Blend::Over,
+            source,
+            src,
+            target,
+            dst,
+            composite_params(mode),
+            color,
+        );
+        frame.submit(encoder);
+    }
+
+    /// Records one quad drawing `src` of `source` into `dst` of `target`.
+    #[allow(clippy::too_many_arguments)]
+    fn pass(
+        &mut self,
+        frame: &Frame<'_, '_>,
+        encoder: &mut CommandEncoder,
+        pass: Pass,
+        blend: Blend,
+        source: &Texture,
+        src: Region,
+        target: &Texture,
+        dst: Rect,
+        params: [f32; 4],
+        color: [f32; 4],
+    ) {
+        let uniforms = Uniforms {
+            dst: clip_rect(dst, target),
+            src: uv_rect(src, source),
+            color,
+            params,
+            ..Uniforms::default()
+        };
+        self.quad(frame, encoder, pass, blend, source, target, uniforms);
+    }
+
+    /// Records one quad drawn with `uniforms`.
+    #[allow(clippy::too_many_arguments)]
+    fn quad(
+        &mut self,
+        frame: &Frame<'_, '_>,
+        encoder: &mut CommandEncoder,
+        pass: Pass,
+        blend: Blend,
+        source: &Texture,
+        target: &Texture,
+        uniforms: Uniforms,
+    ) {
+        let pipeline = self.pipeline(frame.device, target.format(), pass, blend);
+        let buffer = frame.device.create_buffer_init(&BufferInitDescriptor {
+            label: Some("hydrolysis-effects"),
+            contents: &uniforms.to_bytes(),
+            usage: BufferUsages::UNIFORM,
+        });
+        let source_view = source.create_view(&TextureViewDescriptor::default());
+        let bind_group = frame.device.create_bind_group(&BindGroupDescriptor {
+            label: Some("hydrolysis-effects"),
+            layout: &self.bind_group_layout,
+            entries: &[
+                BindGroupEntry {
+                    binding: 0,
+                    resource: BindingResource::TextureView(&source_view),
+                },
+                BindGroupEntry {
+                    binding: 1,
+                    resource: BindingResource::Sampler(&self.sampler),
+                },
+                BindGroupEntry {
+                    binding: 2,
+                    resource: buffer.as_entire_binding(),
+                },
+            ],
+        });
+        let target_view = target.create_view(&TextureViewDescriptor::default());
+        let mut render_pass = encoder.begin_render_pass(&RenderPassDescriptor {
+            label: Some("hydrolysis-effects"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: &target_view,
+                depth_slice: None,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        render_pass.set_pipeline(&pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[]);
+        render_pass.draw(0..4, 0..1);
+    }
+
+    fn pipeline(
+        &mut self,
+        device: &Device,
+        format: TextureFormat,
+        pass: Pass,
+        blend: Blend,
+    ) -> RenderPipeline {
+        self.pipelines
+            .entry((format, pass, blend))
+            .or_insert_with(|| {
+                let layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+                    label: Some("hydrolysis-effects"),
+                    bind_group_layouts: &[&self.bind_group_layout],
+                    push_constant_ranges: &[],
+                });
+                device.create_render_pipeline(&RenderPipelineDescriptor {
+                    label: Some(pass.entry_point()),
+                    layout: Some(&layout),
+                    vertex: VertexState {
+                        module: &self.shader,
+                        entry_point: Some("vs_quad"),
+                        compilation_options: Default::default(),
+                        buffers: &[],
+                    },
+                    primitive: PrimitiveState {
+                        topology: PrimitiveTopology::TriangleStrip,
+                        ..PrimitiveState::default()
+                    },
+                    depth_stencil: None,
+                    multisample: MultisampleState::default(),
+                    fragment: Some(FragmentState {
+                        module: &self.shader,
+                        entry_point: Some(pass.entry_point()),
+                        compilation_options: Default::default(),
+                        targets: &[Some(ColorTargetState {
+                            format,
+                            blend: blend.state(),
+                            write_mask: ColorWrites::ALL,
+                        })],
+                    }),
+                    multiview: None,
+                    cache: None,
+                })
+            })
+            .clone()
+    }
+
+    /// Returns a cleared texture the size of the canvas.
+    fn layer(&mut self, frame: &Frame<'_, '_>) -> Texture {
+        let layer = self.take(frame.device, frame.width, frame.height);
+        self.clear(frame, &layer);
+        layer
+    }
+
+    fn clear(&self, frame: &Frame<'_, '_>, texture: &Texture) {
+        let view = texture.create_view(&TextureViewDescriptor::default());
+        let mut encoder = frame.encoder();
+        encoder.begin_render_pass(&RenderPassDescriptor {
+            label: Some("hydrolysis-effects-clear"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: &view,
+                depth_slice: None,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Clear(Color::TRANSPARENT),
+                    store: StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        frame.submit(encoder);
+    }
+
+    /// Takes a texture of the given size from the pool, creating one if there is none.
+    ///
+    /// Its contents are undefined.
+    fn take(&mut self, device: &Device, width: u32, height: u32) -> Texture {
+        match self
+            .pool
+            .iter()
+            .position(|texture| texture.width() == width && texture.height() == height)
+        {
+            Some(index) => self.pool.swap_remove(index),
+            None => create_texture(device, width, height),
+        }
+    }
+
+    fn give(&mut self, texture: Texture) {
+        if self.pool.len() < MAX_POOLED_TEXTURES {
+            self.pool.push(texture);
+        }
+    }
+}
+
+fn create_texture(device: &Device, width: u32, height: u32) -> Texture {
+    device.create_texture(&TextureDescriptor {
+        label: Some("hydrolysis-effects"),
+        size: Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format: FORMAT,
+        // Vello writes its output through a storage binding
+        usage: TextureUsages::RENDER_ATTACHMENT
+            | TextureUsages::TEXTURE_BINDING
+            | TextureUsages::STORAGE_BINDING
+            | TextureUsages::COPY_SRC
+            | TextureUsages::COPY_DST,
+        view_formats: &[],
+    })
+}
+
+fn copy_region(
+    encoder: &mut CommandEncoder,
+    source: &Texture,
+    region: Region,
+    target: &Texture,
+    x: u32,
+    y: u32,
+) {
+    encoder.copy_texture_to_texture(
+        TexelCopyTextureInfo {
+            texture: source,
+            mip_level: 0,
+            origin: Origin3d {
+                x: region.x,
+                y: region.y,
+                z: 0,
+            },
+            aspect: TextureAspect::All,
+        },
+        TexelCopyTextureInfo {
+            texture: target,
+            mip_level: 0,
+            origin: Origin3d { x, y, z: 0 },
+            aspect: TextureAspect::All,
+        },
+        Extent3d {
+            width: region.width,
+            height: region.height,
+            depth_or_array_layers: 1,
+        },
+    );
+}
+
+const fn composite_params(mode: Mode) -> [f32; 4] {
+    [0.0, mode as u32 as f32, 0.0, 0.0]
+}
+
+fn tint(color: ResolvedColor, opacity: f32) -> [f32; 4] {
+    [color.red, color.green, color.blue, color.opacity * opacity]
+}
+
+/// Converts a rectangle in pixels of `target` to clip space.
+fn clip_rect(rect: Rect, target: &Texture) -> [f32; 4] {
+    let (width, height) = (target.width() as f32, target.height() as f32);
+    [
+        rect.origin.x / width * 2.0 - 1.0,
+        1.0 - rect.origin.y / height * 2.0,
+        rect.max_x() / width * 2.0 - 1.0,
+        1.0 - rect.max_y() / height * 2.0,
+    ]
+}
+
+/// Converts a region of `texture` to texture coordinates.
+fn uv_rect(region: Region, texture: &Texture) -> [f32; 4] {
+    let (width, height) = (texture.width() as f32, texture.height() as f32);
+    [
+        region.x as f32 / width,
+        region.y as f32 / height,
+        (region.x + region.width) as f32 / width,
+        (region.y + region.height) as f32 / height,
+    ]
+}
@@ -0,0 +1,110 @@
+// Dual-Kawase blur, color matrix and compositing passes for Hydrolysis effects.
+//
+// Every pass draws one quad (`u.dst`, in clip space) sampling `u.src` (in texture coordinates)
+// of the bound source texture.
+
+struct Uniforms {
+    // Quad corners in clip space: left, top, right, bottom.
+    dst: vec4<f32>,
+    // Sampled region in texture coordinates: left, top, right, bottom.
+    src: vec4<f32>,
+    // Tint color: straight rgb plus opacity.
+    color: vec4<f32>,
+    // x: Kawase tap offset, y: composite mode, zw: size of a target pixel in texture coordinates.
+    params: vec4<f32>,
+    // Color matrix columns: weights of straight r, g, b and a, then offsets.
+    matrix: array<vec4<f32>, 5>,
+};
+
+@group(0) @binding(0) var source: texture_2d<f32>;
+@group(0) @binding(1) var source_sampler: sampler;
+@group(0) @binding(2) var<uniform> u: Uniforms;
+
+struct VertexOut {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+// Emits the four corners of the quad as a triangle strip.
+@vertex
+fn vs_quad(@builtin(vertex_index) index: u32) -> VertexOut {
+    let corner = vec2<f32>(f32(index & 1u), f32((index >> 1u) & 1u));
+    var out: VertexOut;
+    out.position = vec4<f32>(mix(u.dst.xy, u.dst.zw, corner), 0.0, 1.0);
+    out.uv = mix(u.src.xy, u.src.zw, corner);
+    return out;
+}
+
+fn tap(uv: vec2<f32>) -> vec4<f32> {
+    return textureSample(source, source_sampler, uv);
+}
+
+// Halves the resolution: the center and four diagonal taps.
+@fragment
+fn fs_down(in: VertexOut) -> @location(0) vec4<f32> {
+    let d = u.params.zw * u.params.x * 0.5;
+    var sum = tap(in.uv) * 4.0;
+    sum += tap(in.uv - d);
+    sum += tap(in.uv + d);
+    sum += tap(in.uv + vec2<f32>(d.x, -d.y));
+    sum += tap(in.uv - vec2<f32>(d.x, -d.y));
+    return sum / 8.0;
+}
+
+// Doubles the resolution: four axis taps and four closer diagonal taps weighted twice.
+@fragment
+fn fs_up(in: VertexOut) -> @location(0) vec4<f32> {
+    let d = u.params.zw * u.params.x;
+    let h = d * 0.5;
+    var sum = tap(in.uv + vec2<f32>(-d.x, 0.0));
+    sum += tap(in.uv + vec2<f32>(d.x, 0.0));
+    sum += tap(in.uv + vec2<f32>(0.0, -d.y));
+    sum += tap(in.uv + vec2<f32>(0.0, d.y));
+    sum += tap(in.uv + vec2<f32>(-h.x, -h.y)) * 2.0;
+    sum += tap(in.uv + vec2<f32>(h.x, -h.y)) * 2.0;
+    sum += tap(in.uv + vec2<f32>(-h.x, h.y)) * 2.0;
+    sum += tap(in.uv + vec2<f32>(h.x, h.y)) * 2.0;
+    return sum / 12.0;
+}
+
+// Outputs premultiplied color. Modes:
+// 0: the source is premultiplied
+// 1: the source has straight alpha, as Vello writes it
+// 2: the source's alpha tinted with `u.color` (shadows)
+// 3: `u.color` alone
+@fragment
+fn fs_composite(in: VertexOut) -> @location(0) vec4<f32> {
+    let texel = tap(in.uv);
+    switch u32(u.params.y) {
+        case 1u: {
+            return vec4<f32>(texel.rgb * texel.a, texel.a);
+        }
+        case 2u: {
+            let alpha = u.color.a * texel.a;
+            return vec4<f32>(u.color.rgb * alpha, alpha);
+        }
+        case 3u: {
+            return vec4<f32>(u.color.rgb * u.color.a, u.color.a);
+        }
+        default: {
+            return texel;
+        }
+    }
+}
+
+// Recolors a premultiplied source through the color matrix, as `effects::filter_pixel` does.
+@fragment
+fn fs_color_matrix(in: VertexOut) -> @location(0) vec4<f32> {
+    let texel = tap(in.uv);
+    var straight = vec4<f32>(0.0);
+    if texel.a > 0.0 {
+        straight = vec4<f32>(texel.rgb / texel.a, texel.a);
+    }
+    let color = clamp(
+        u.matrix[0] * straight.r + u.matrix[1] * straight.g + u.matrix[2] * straight.b
+            + u.matrix[3] * straight.a + u.matrix[4],
+        vec4<f32>(0.0),
+        vec4<f32>(1.0),
+    );
+    return vec4<f32>(color.rgb * color.a, color.a);
+}
@@ -0,0 +1,232 @@
+//! Render node that plays `AnimatedVector` animations.
+//!
+//! With the `lottie` feature, Lottie JSON files are interpreted with `velato`
+//! and drawn by the GPU backend. dotLottie archives, and every animation when
+//! the feature is off, are reported through the view's error event and drawn
+//! as a placeholder.
+//!
+//! While the animation plays, every paint marks the node dirty again so the
+//! host keeps rendering frames; pausing stops the requests until `playing`
+//! changes back.
+
+#[cfg(feature = "lottie")]
+use std::sync::Arc;
+use std::time::Instant;
+
+use nami::SignalExt;
+use waterui::{
+    media::{
+        Url,
+        animated_vector::{AnimatedVectorConfig, Event, VectorFormat},
+    },
+    task::now,
+    time::Duration,
+};
+
+use crate::{
+    DirtyHandle, DirtyReason, DrawCommand, LayoutCtx, LayoutResult, NodeSignal, RenderCtx,
+    RenderNode, Size,
+};
+#[cfg(feature = "lottie")]
+use crate::{Point, Rect};
+
+/// A loaded animation.
+struct Animation {
+    duration: Duration,
+    size: Size,
+    #[cfg(feature = "lottie")]
+    composition: Arc<velato::Composition>,
+}
+
+/// Node playing a Lottie animation.
+pub struct AnimatedVectorNode {
+    config: AnimatedVectorConfig,
+    source: NodeSignal<Url>,
+    /// Wakes the node when playback is resumed from outside.
+    playing: NodeSignal<bool>,
+    animation: Option<Animation>,
+    last_frame: Option<Instant>,
+    size: Size,
+    /// Requests the next frame while the animation plays.
+    handle: Option<DirtyHandle>,
+}
+
+impl core::fmt::Debug for AnimatedVectorNode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnimatedVectorNode")
+            .field("source", self.source.current())
+            .field("loaded", &self.animation.is_some())
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AnimatedVectorNode {
+    /// Creates an animation node from the provided configuration.
+    #[must_use]
+    pub fn new(config: AnimatedVectorConfig) -> Self {
+        let source = NodeSignal::new(config.source.clone());
+        let playing = NodeSignal::new(config.playing.clone().computed());
+        let mut node = Self {
+            config,
+            source,
+            playing,
+            animation: None,
+            last_frame: None,
+            size: Size::default(),
+            handle: None,
+        };
+        node.reload();
+        node
+    }
+
+    fn reload(&mut self) {
+        self.last_frame = None;
+        self.animation = match load(self.source.current()) {
+            Ok(animation) => {
+                (self.config.on_event)(Event::Loaded {
+                    duration: animation.duration,
+                });
+                Some(animation)
+            }
+            Err(message) => {
+                (self.config.on_event)(Event::Error(message));
+                None
+            }
+        };
+    }
+}
+
+#[cfg(feature = "lottie")]
+fn load(url: &Url) -> Result<Animation, String> {
+    if VectorFormat::of(url) == VectorFormat::DotLottie {
+        return Err("dotLottie archives are only supported by native players".into());
+    }
+    let path = url
+        .to_file_path()
+        .ok_or_else(|| String::from("only local animations can be loaded"))?;
+    let bytes = std::fs::read(path).map_err(|error| error.to_string())?;
+    let composition = velato::Composition::from_slice(&bytes).map_err(|error| error.to_string())?;
+    let frames = composition.frames.end - composition.frames.start;
+    #[allow(clippy::cast_precision_loss)]
+    let size = Size::new(composition.width as f32, composition.height as f32);
+    Ok(Animation {
+        duration: Duration::try_from_secs_f64(frames / composition.frame_rate).unwrap_or_default(),
+        size,
+        composition: Arc::new(composition),
+    })
+}
+
+#[cfg(not(feature = "lottie"))]
+fn load(url: &Url) -> Result<Animation, String> {
+    if VectorFormat::of(url) == VectorFormat::DotLottie {
+        return Err("dotLottie archives are only supported by native players".into());
+    }
+    Err("Lottie playback requires the `lottie` feature of hydrolysis".into())
+}
+
+impl RenderNode for AnimatedVectorNode {
+    fn layout(&mut self, _ctx: LayoutCtx<'_>) -> LayoutResult {
+        self.size = self
+            .animation
+            .as_ref()
+            .map_or_else(Size::default, |animation| animation.size);
+        LayoutResult { size: self.size }
+    }
+
+    fn paint(&mut self, ctx: &mut RenderCtx<'_>) {
+        let Some(animation) = &self.animation else {
+            ctx.push(DrawCommand::Placeholder("AnimatedVector"));
+            return;
+        };
+        let now = now();
+        if let Some(last_frame) = self.last_frame {
+            self.config
+                .advance(now.duration_since(last_frame), animation.duration);
+        }
+        if self.config.playing.get() {
+            self.last_frame = Some(now);
+            if let Some(handle) = &self.handle {
+                handle.mark(DirtyReason::Paint);
+            }
+        } else {
+            // Time spent paused must not count once playback resumes
+            self.last_frame = None;
+        }
+
+        #[cfg(feature = "lottie")]
+        {
+            let frames = &animation.composition.frames;
+            let progress = f64::from(self.config.progress.get());
+            ctx.push(DrawCommand::Lottie {
+                composition: animation.composition.clone(),
+                frame: (frames.end - frames.start).mul_add(progress, frames.start),
+                rect: Rect::new(Point::new(0.0, 0.0), self.size),
+            });
+        }
+        #[cfg(not(feature = "lottie"))]
+        ctx.push(DrawCommand::Placeholder("AnimatedVector"));
+    }
+
+    fn bind(&mut self, handle: &DirtyHandle) {
+        self.source.bind(handle);
+        self.playing.bind(handle);
+        self.handle = Some(handle.clone());
+    }
+
+    fn update_reactive(&mut self) {
+        self.playing.refresh();
+        if self.source.refresh() {
+            self.reload();
+        }
+    }
+}
+
+#[cfg(all(test, feature = "lottie"))]
+mod tests {
+    use nami::binding;
+    use waterui::media::animated_vector::AnimatedVector;
+    use waterui_core::{Environment, view::ConfigurableView};
+
+    use super::*;
+    use crate::RenderTree;
+
+    #[test]
+    fn playing_animations_request_frames() {
+        let path = std::env::temp_dir().join("hydrolysis-animated-vector.json");
+        std::fs::write(
+            &path,
+            r#"{"v":"5.7.4","fr":30,"ip":0,"op":30,"w":10,"h":10,"layers":[]}"#,
+        )
+        .unwrap();
+        let playing = binding(true);
+        let config = AnimatedVector::new(Url::from_file_path(&path))
+            .playing(&playing)
+            .config();
+        let env = Environment::new();
+        let mut tree = RenderTree::new();
+        let id = tree.replace_root(Box::new(AnimatedVectorNode::new(config)));
+        let frame = |tree: &mut RenderTree| {
+            let dirty: Vec<_> = tree.drain_dirty().collect();
+            let node = tree.node_mut(id).unwrap();
+            if dirty
+                .iter()
+                .any(|dirty| dirty.reason == DirtyReason::Reactive)
+            {
+                node.update_reactive();
+            }
+            node.paint(&mut RenderCtx::new(&env));
+        };
+
+        frame(&mut tree);
+        assert!(tree.needs_frame());
+
+        // Pausing stops the requests after the frame that observes it
+        playing.set(false);
+        frame(&mut tree);
+        assert!(!tree.needs_frame());
+
+        playing.set(true);
+        assert!(tree.needs_frame());
+    }
+}
@@ -6,7 +6,9 @@ use waterui_controls::{
 };
 use waterui_core::Str;
 
-use crate::{DrawCommand, LayoutCtx, LayoutResult, NodeSignal, RenderCtx, RenderNode, Size};
+use crate::{
+    DirtyHandle, DrawCommand, LayoutCtx, LayoutResult, NodeSignal, RenderCtx, RenderNode, Size,
+};
 
 /// Simplified slider node (placeholder visuals until a real skin exists).
 #[derive(Debug)]
@@ -44,6 +46,14 @@ impl RenderNode for SliderNode {
         ctx.push(DrawCommand::Placeholder("Slider track"));
         ctx.push(DrawCommand::Placeholder("Slider thumb"));
     }
+
+    fn bind(&mut self, handle: &DirtyHandle) {
+        self.value.bind(handle);
+    }
+
+    fn update_reactive(&mut self) {
+        self.value.refresh();
+    }
 }
 
 /// Placeholder toggle node (draws checkboxes until skins are ready).
@@ -66,7 +76,6 @@ impl ToggleNode {
 
 impl RenderNode for ToggleNode {
     fn layout(&mut self, _ctx: LayoutCtx<'_>) -> LayoutResult {
-        self.value.refresh();
         LayoutResult {
             size: Size::new(32.0, 32.0),
         }
@@ -75,6 +84,14 @@ impl RenderNode for ToggleNode {
     fn paint(&mut self, ctx: &mut RenderCtx<'_>) {
         ctx.push(DrawCommand::Placeholder("Toggle body"));
     }
+
+    fn bind(&mut self, handle: &DirtyHandle) {
+        self.value.bind(handle);
+    }
+
+    fn update_reactive(&mut self) {
+        self.value.refresh();
+    }
 }
 
 /// Placeholder node for numeric steppers.
@@ -103,7 +120,6 @@ impl StepperNode {
 
 impl RenderNode for StepperNode {
     fn layout(&mut self, _ctx: LayoutCtx<'_>) -> LayoutResult {
-        self.step.refresh();
         LayoutResult {
             size: Size::new(160.0, 32.0),
         }
@@ -112,6 +128,14 @@ impl RenderNode for StepperNode {
     fn paint(&mut self, ctx: &mut RenderCtx<'_>) {
         ctx.push(DrawCommand::Placeholder("Stepper control"));
     }
+
+    fn bind(&mut self, handle: &DirtyHandle) {
+        self.step.bind(handle);
+    }
+
+    fn update_reactive(&mut self) {
+        self.step.refresh();
+    }
 }
 
 /// Placeholder node for text fields.
@@ -134,7 +158,6 @@ impl TextFieldNode {
 
 impl RenderNode for TextFieldNode {
     fn layout(&mut self, _ctx: LayoutCtx<'_>) -> LayoutResult {
-        self.value.refresh();
         LayoutResult {
             size: Size::new(220.0, 32.0),
         }
@@ -143,4 +166,12 @@ impl RenderNode for TextFieldNode {
     fn paint(&mut self, ctx: &mut RenderCtx<'_>) {
         ctx.push(DrawCommand::Placeholder("Text field"));
     }
+
+    fn bind(&mut self, handle: &DirtyHandle) {
+        self.value.bind(handle);
+    }
+
+    fn update_reactive(&mut self) {
+        self.value.refresh();
+    }
 }
@@ -0,0 +1,123 @@
+//! Render node applying shadow, material and color filter modifiers to its content.
+//!
+//! The node wraps its children in a [`DrawCommand::PushEffect`] /
+//! [`DrawCommand::PopEffect`] pair; backends render the effect with the
+//! passes described in [`effects`](crate::effects).
+
+use waterui::{
+    filter::{ColorFilter, ColorMatrix},
+    style::Shadow,
+};
+use waterui_color::ResolvedColor;
+use waterui_core::Environment;
+
+use crate::{
+    Constraints, DirtyHandle, DrawCommand, Effect, LayoutCtx, LayoutResult, NodeSignal, Point,
+    RenderCtx, RenderNode, Size,
+};
+
+/// Blur radius of material backgrounds, in logical pixels.
+pub const MATERIAL_BLUR_RADIUS: f32 = 30.0;
+
+#[derive(Debug)]
+enum EffectKind {
+    Shadow {
+        color: NodeSignal<ResolvedColor>,
+        offset: Point,
+        radius: f32,
+    },
+    BackdropBlur {
+        radius: f32,
+    },
+    ColorFilter {
+        matrix: ColorMatrix,
+    },
+}
+
+/// Node drawing its children under a visual effect.
+#[derive(Debug)]
+pub struct EffectNode {
+    kind: EffectKind,
+}
+
+impl EffectNode {
+    /// Creates a node drawing a shadow behind its children.
+    #[must_use]
+    pub fn shadow(shadow: Shadow, env: &Environment) -> Self {
+        Self {
+            kind: EffectKind::Shadow {
+                color: NodeSignal::new(shadow.color.resolve(env)),
+                offset: Point::new(shadow.offset.x, shadow.offset.y),
+                radius: shadow.radius,
+            },
+        }
+    }
+
+    /// Creates a node blurring what is behind its children, as material backgrounds do.
+    #[must_use]
+    pub const fn backdrop_blur(radius: f32) -> Self {
+        Self {
+            kind: EffectKind::BackdropBlur { radius },
+        }
+    }
+
+    /// Creates a node recoloring its children with `filter`.
+    #[must_use]
+    pub fn color_filter(filter: ColorFilter) -> Self {
+        Self {
+            kind: EffectKind::ColorFilter {
+                matrix: filter.matrix(),
+            },
+        }
+    }
+
+    fn effect(&self) -> Effect {
+        match &self.kind {
+            EffectKind::Shadow {
+                color,
+                offset,
+                radius,
+            } => Effect::Shadow {
+                color: *color.current(),
+                offset: *offset,
+                radius: *radius,
+            },
+            EffectKind::BackdropBlur { radius } => Effect::BackdropBlur { radius: *radius },
+            EffectKind::ColorFilter { matrix } => Effect::ColorFilter { matrix: *matrix },
+        }
+    }
+}
+
+impl RenderNode for EffectNode {
+    fn layout(&mut self, _ctx: LayoutCtx<'_>) -> LayoutResult {
+        // TODO(layout): report the size of the wrapped content once containers place children.
+        LayoutResult {
+            size: Size::default(),
+        }
+    }
+
+    fn child_constraints(&self, constraints: Constraints, _index: usize) -> Constraints {
+        // Effects draw around their content without changing its size
+        constraints
+    }
+
+    fn paint(&mut self, ctx: &mut RenderCtx<'_>) {
+        ctx.push(DrawCommand::PushEffect(self.effect()));
+    }
+
+    fn paint_after(&mut self, ctx: &mut RenderCtx<'_>) {
+        ctx.push(DrawCommand::PopEffect);
+    }
+
+    fn bind(&mut self, handle: &DirtyHandle) {
+        if let EffectKind::Shadow { color, .. } = &self.kind {
+            color.bind(handle);
+        }
+    }
+
+    fn update_reactive(&mut self) {
+        if let EffectKind::Shadow { color, .. } = &mut self.kind {
+            color.refresh();
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! Collection of render nodes that correspond to `WaterUI` components.
 
+pub mod animated_vector;
 pub mod controls;
 pub mod divider;
+pub mod effect;
 pub mod layout;
 pub mod progress;
 pub mod text;
@@ -7,7 +7,8 @@ use waterui::{
 use waterui_color::ResolvedColor;
 
 use crate::{
-    DrawCommand, LayoutCtx, LayoutResult, NodeSignal, Point, Rect, RenderCtx, RenderNode, Size,
+    DirtyHandle, DrawCommand, LayoutCtx, LayoutResult, NodeSignal, Point, Rect, RenderCtx,
+    RenderNode, Size,
 };
 
 /// Placeholder node for `Progress` views.
@@ -35,7 +36,6 @@ impl ProgressNode {
 
 impl RenderNode for ProgressNode {
     fn layout(&mut self, _ctx: LayoutCtx<'_>) -> LayoutResult {
-        self.value.refresh();
         let size = match self.style {
             ProgressStyle::Circular => Size::new(48.0, 48.0),
             ProgressStyle::Linear => Size::new(160.0, 12.0),
@@ -86,4 +86,12 @@ impl RenderNode for ProgressNode {
             _ => ctx.push(DrawCommand::Placeholder("Progress")),
         }
     }
+
+    fn bind(&mut self, handle: &DirtyHandle) {
+        self.value.bind(handle);
+    }
+
+    fn update_reactive(&mut self) {
+        self.value.refresh();
+    }
 }
@@ -5,7 +5,8 @@ use waterui_core::Environment;
 use waterui_text::{TextConfig, styled::StyledStr};
 
 use crate::{
-    DrawCommand, LayoutCtx, LayoutResult, NodeSignal, Point, Rect, RenderCtx, RenderNode, Size,
+    DirtyHandle, DrawCommand, LayoutCtx, LayoutResult, NodeSignal, Point, Rect, RenderCtx,
+    RenderNode, Size,
 };
 
 /// Naive text node; renders plain strings until shaping is implemented.
@@ -50,7 +51,6 @@ impl TextNode {
 
 impl RenderNode for TextNode {
     fn layout(&mut self, _ctx: LayoutCtx<'_>) -> LayoutResult {
-        self.refresh_plain();
         // TODO(text-measurement): integrate real font metrics (cosmic-text).
         let width = self.plain.chars().count() as f32 * (self.font_size * 0.6);
         let height = self.font_size * 1.2;
@@ -70,6 +70,10 @@ impl RenderNode for TextNode {
         });
     }
 
+    fn bind(&mut self, handle: &DirtyHandle) {
+        self.content.bind(handle);
+    }
+
     fn update_reactive(&mut self) {
         self.refresh_plain();
     }
@@ -0,0 +1,389 @@
+//! Backend-agnostic planning for blur, shadow, backdrop and color filter effects.
+//!
+//! Blurs use the dual-Kawase algorithm: the source is downsampled through a chain of half-size
+//! targets and upsampled back, each pass averaging a handful of bilinear taps around every pixel.
+//! That approximates a wide Gaussian at a fraction of its cost, and every pass maps onto a single
+//! GPU render pass. [`KawasePlan`] picks the passes for a blur radius; backends run them.
+//!
+//! The shadow of a plain rectangle only depends on the rectangle's size and blur radius, so its
+//! blurred mask is computed once per [`ShadowKey`] and tinted when drawn. The GPU backend packs
+//! these masks into one texture managed by a [`ShadowAtlas`].
+//!
+//! Color filters map every pixel of a group through a [`ColorMatrix`], as [`filter_pixel`] does;
+//! the GPU shader mirrors it.
+
+use std::collections::HashMap;
+
+use waterui::filter::ColorMatrix;
+
+use crate::{
+    scene::{DrawCommand, Effect},
+    tree::layout::{Point, Rect, Size},
+};
+
+/// The passes of a dual-Kawase blur.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KawasePlan {
+    /// Number of downsample passes, each mirrored by an upsample pass.
+    pub iterations: u32,
+    /// Distance of the sample taps from the pixel center, in texels of each pass's source.
+    pub offset: f32,
+}
+
+impl KawasePlan {
+    /// The most downsample passes a blur uses; larger radii widen the tap offset instead.
+    pub const MAX_ITERATIONS: u32 = 6;
+
+    /// Returns the passes approximating a Gaussian blur of `radius` pixels, or `None` if the
+    /// blur would not be visible.
+    #[must_use]
+    pub fn for_radius(radius: f32) -> Option<Self> {
+        if radius.is_nan() || radius < 0.5 {
+            return None;
+        }
+        // Every level doubles the distance a tap covers, so a plan spreads colors by about
+        // `(offset + 0.5) * 2^iterations` pixels. Offsets around 1 look smoothest.
+        let levels = (radius / 1.5).log2().ceil();
+        let iterations = (levels.max(1.0) as u32).min(Self::MAX_ITERATIONS);
+        let offset = (radius / (1u32 << iterations) as f32 - 0.5).max(0.0);
+        Some(Self { iterations, offset })
+    }
+
+    /// Returns how far the blur spreads colors, in pixels.
+    ///
+    /// Regions are padded by this much so the blur neither clips nor samples past the content.
+    #[must_use]
+    pub fn spread(&self) -> f32 {
+        (self.offset + 0.5) * (1u32 << self.iterations) as f32
+    }
+}
+
+/// Returns how far an effect with blur `radius` reaches past its content.
+fn blur_spread(radius: f32) -> f32 {
+    KawasePlan::for_radius(radius).map_or(0.0, |plan| plan.spread())
+}
+
+/// A draw command, or a group of them under an effect.
+#[derive(Debug)]
+pub enum Layer<'a> {
+    /// A command drawn as is.
+    Draw(&'a DrawCommand),
+    /// Commands drawn together so an effect can be applied to them.
+    Effect {
+        /// The effect applied to the group.
+        effect: Effect,
+        /// The commands of the group.
+        children: Vec<Layer<'a>>,
+    },
+}
+
+impl Layer<'_> {
+    /// Returns the area the layer draws into, including what its effects add.
+    #[must_use]
+    pub fn bounds(&self) -> Option<Rect> {
+        match self {
+            Self::Draw(command) => command.bounds(),
+            Self::Effect { effect, children } => {
+                bounds(children).map(|content| effect_bounds(effect, content))
+            }
+        }
+    }
+}
+
+/// Groups the commands of a scene by the effects they are drawn under.
+///
+/// Unbalanced [`DrawCommand::PopEffect`]s are ignored and effects that are never popped end with
+/// the scene.
+#[must_use]
+pub fn layers(commands: &[DrawCommand]) -> Vec<Layer<'_>> {
+    let mut root = Vec::new();
+    let mut open: Vec<(Effect, Vec<Layer<'_>>)> = Vec::new();
+    for command in commands {
+        match command {
+            DrawCommand::PushEffect(effect) => open.push((*effect, Vec::new())),
+            DrawCommand::PopEffect => match open.pop() {
+                Some((effect, children)) => {
+                    current(&mut open, &mut root).push(Layer::Effect { effect, children });
+                }
+                None => tracing::warn!("ignoring PopEffect without a matching PushEffect"),
+            },
+            command => current(&mut open, &mut root).push(Layer::Draw(command)),
+        }
+    }
+    while let Some((effect, children)) = open.pop() {
+        current(&mut open, &mut root).push(Layer::Effect { effect, children });
+    }
+    root
+}
+
+/// Returns the innermost open group, or the root.
+fn current<'s, 'a>(
+    open: &'s mut [(Effect, Vec<Layer<'a>>)],
+    root: &'s mut Vec<Layer<'a>>,
+) -> &'s mut Vec<Layer<'a>> {
+    match open.last_mut() {
+        Some((_, children)) => children,
+        None => root,
+    }
+}
+
+/// Returns the area a list of layers draws into.
+#[must_use]
+pub fn bounds(layers: &[Layer<'_>]) -> Option<Rect> {
+    layers
+        .iter()
+        .filter_map(Layer::bounds)
+        .reduce(|a, b| a.union(&b))
+}
+
+/// Returns the area `effect` draws into when applied to content covering `content`.
+#[must_use]
+pub fn effect_bounds(effect: &Effect, content: Rect) -> Rect {
+    match *effect {
+        Effect::Shadow { offset, radius, .. } => {
+            content.union(&content.translate(offset).outset(blur_spread(radius)))
+        }
+        Effect::Blur { radius } => content.outset(blur_spread(radius)),
+        Effect::BackdropBlur { .. } | Effect::ColorFilter { .. } => content,
+    }
+}
+
+/// Applies `matrix` to a premultiplied RGBA pixel, returning a premultiplied pixel.
+///
+/// The matrix works on straight colors, so the pixel is unpremultiplied first and the result is
+/// clamped to the displayable range.
+#[must_use]
+pub fn filter_pixel(matrix: &ColorMatrix, premultiplied: [f32; 4]) -> [f32; 4] {
+    let [red, green, blue, alpha] = premultiplied;
+    let straight = if alpha > 0.0 {
+        [red / alpha, green / alpha, blue / alpha, alpha]
+    } else {
+        [0.0; 4]
+    };
+    let [red, green, blue, alpha] = matrix.apply(straight).map(|value| value.clamp(0.0, 1.0));
+    [red * alpha, green * alpha, blue * alpha, alpha]
+}
+
+/// Returns the rectangle and opacity of a group that is a single solid rectangle.
+///
+/// The shadows of such groups can be drawn from a cached mask.
+#[must_use]
+pub fn plain_rect(layers: &[Layer<'_>]) -> Option<(Rect, f32)> {
+    match layers {
+        [Layer::Draw(DrawCommand::SolidRect { rect, color })] => Some((*rect, color.opacity)),
+        _ => None,
+    }
+}
+
+/// Identifies the blurred mask of a rectangle's shadow, in physical pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ShadowKey {
+    /// Width of the rectangle.
+    pub width: u32,
+    /// Height of the rectangle.
+    pub height: u32,
+    /// Blur radius.
+    pub radius: u32,
+}
+
+impl ShadowKey {
+    /// Returns the key for the shadow of a `size` rectangle blurred by `radius`, rounded to whole
+    /// pixels so nearly equal shapes share a mask.
+    #[must_use]
+    pub fn new(size: Size, radius: f32) -> Self {
+        Self {
+            width: size.width.max(0.0).round() as u32,
+            height: size.height.max(0.0).round() as u32,
+            radius: radius.max(0.0).round() as u32,
+        }
+    }
+
+    /// Returns the passes blurring the mask.
+    #[must_use]
+    pub fn plan(self) -> Option<KawasePlan> {
+        KawasePlan::for_radius(self.radius as f32)
+    }
+
+    /// Returns how far the mask extends past the rectangle on every side.
+    #[must_use]
+    pub fn padding(self) -> u32 {
+        self.plan().map_or(0, |plan| plan.spread().ceil() as u32)
+    }
+
+    /// Returns the width and height of the mask.
+    #[must_use]
+    pub fn mask_size(self) -> (u32, u32) {
+        let padding = self.padding() * 2;
+        (self.width + padding, self.height + padding)
+    }
+
+    /// Returns where the mask is drawn for a rectangle at `origin` with a shadow `offset`.
+    #[must_use]
+    pub fn mask_rect(self, origin: Point, offset: Point) -> Rect {
+        let padding = self.padding() as f32;
+        let (width, height) = self.mask_size();
+        Rect::new(
+            Point::new(origin.x + offset.x - padding, origin.y + offset.y - padding),
+            Size::new(width as f32, height as f32),
+        )
+    }
+}
+
+/// A region of an atlas texture, in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtlasRect {
+    /// Left edge.
+    pub x: u32,
+    /// Top edge.
+    pub y: u32,
+    /// Width of the region.
+    pub width: u32,
+    /// Height of the region.
+    pub height: u32,
+}
+
+/// A row of the atlas holding masks up to its height.
+#[derive(Debug)]
+struct Shelf {
+    y: u32,
+    height: u32,
+    used: u32,
+}
+
+#[derive(Debug)]
+struct AtlasEntry {
+    rect: AtlasRect,
+    last_used: u64,
+}
+
+/// Packs shadow masks into one texture so repeated shadows are blurred once.
+///
+/// Masks are placed on shelves, rows as tall as the first mask placed on them. When the atlas is
+/// full, all masks are dropped if some were not used in the current frame, and callers render
+/// them again when next needed.
+#[derive(Debug)]
+pub struct ShadowAtlas {
+    width: u32,
+    height: u32,
+    shelves: Vec<Shelf>,
+    entries: HashMap<ShadowKey, AtlasEntry>,
+    frame: u64,
+}
+
+impl ShadowAtlas {
+    /// Space left around every mask so filtering never samples a neighbor.
+    const GUTTER: u32 = 1;
+
+    /// Creates an empty atlas of `width` by `height` pixels.
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            shelves: Vec::new(),
+            entries: HashMap::new(),
+            frame: 0,
+        }
+    }
+
+    /// Returns the width and height of the atlas texture.
+    #[must_use]
+    pub const fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the number of cached masks.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if no masks are cached.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Starts a new frame, which masks are marked as used in.
+    pub const fn begin_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    /// Returns where the mask of `key` is stored, if it is cached.
+    pub fn get(&mut self, key: ShadowKey) -> Option<AtlasRect> {
+        let entry = self.entries.get_mut(&key)?;
+        entry.last_used = self.frame;
+        Some(entry.rect)
+    }
+
+    /// Reserves space for the mask of `key`, which the caller must then render into the returned
+    /// region.
+    ///
+    /// Returns `None` if the mask does not fit, in which case it should be drawn uncached.
+    pub fn allocate(&mut self, key: ShadowKey) -> Option<AtlasRect> {
+        let (width, height) = key.mask_size();
+        let rect = self.pack(width, height).or_else(|| {
+            let stale = self
+                .entries
+                .values()
+                .any(|entry| entry.last_used != self.frame);
+            if !stale {
+                return None;
+            }
+            self.clear();
+            self.pack(width, height)
+        })?;
+        self.entries.insert(
+            key,
+            AtlasEntry {
+                rect,
+                last_used: self.frame,
+            },
+        );
+        Some(rect)
+    }
+
+    /// Drops every cached mask.
+    pub fn clear(&mut self) {
+        self.shelves.clear();
+        self.entries.clear();
+    }
+
+    fn pack(&mut self, width: u32, height: u32) -> Option<AtlasRect> {
+        let padded_width = width + Self::GUTTER * 2;
+        let padded_height = height + Self::GUTTER * 2;
+        if padded_width > self.width {
+            return None;
+        }
+        let width_limit = self.width;
+        let index = match self.shelves.iter().position(|shelf| {
+            shelf.height >= padded_height && width_limit - shelf.used >= padded_width
+        }) {
+            Some(index) => index,
+            None => {
+                let y = self
+                    .shelves
+                    .last()
+                    .map_or(0, |shelf| shelf.y + shelf.height);
+                if self.height - y < padded_height {
+                    return None;
+                }
+                self.shelves.push(Shelf {
+                    y,
+                    height: padded_height,
+                    used: 0,
+                });
+                self.shelves.len() - 1
+            }
+        };
+        let shelf = &mut self.shelves[index];
+        let rect = AtlasRect {
+            x: shelf.used + Self::GUTTER,
+            y: shelf.y + Self::GUTTER,
+            width,
+            height,
+        };
+        shelf.used += padded_width;
+        Some(rect)
+    }
+}
@@ -9,16 +9,17 @@
 pub mod backend;
 pub mod components;
 pub mod dispatcher;
+pub mod effects;
 pub mod renderer;
 pub mod scene;
 pub mod tree;
 
 pub use dispatcher::ViewDispatcher;
 pub use renderer::HydrolysisRenderer;
-pub use scene::{DrawCommand, Scene};
+pub use scene::{DrawCommand, Effect, Scene};
 pub use tree::{
-    DirtyNode, DirtyReason, NodeId, RenderTree, build_tree,
-    layout::{LayoutCtx, LayoutResult, Point, Rect, Size},
+    DirtyHandle, DirtyNode, DirtyReason, NodeId, RenderTree, build_tree,
+    layout::{Constraints, LayoutCtx, LayoutResult, Point, Rect, Size},
     reactive::NodeSignal,
     render::{RenderCtx, RenderNode},
 };
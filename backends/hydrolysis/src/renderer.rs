@@ -10,9 +10,11 @@ use crate::{
 
 /// Entry point for rendering `WaterUI` views through Hydrolysis.
 ///
+/// The render tree of the mounted view is kept across frames. Its nodes mark themselves dirty
+/// when their reactive inputs change, so a frame only lays out what changed.
+///
 /// # TODO
-/// - diff and reuse render trees rather than rebuilding every frame.
-/// - wire reactive watchers and bindings so updates mark nodes dirty automatically.
+/// - diff against the previous tree when a new view is mounted instead of rebuilding it.
 pub struct HydrolysisRenderer<B: RenderBackend> {
     backend: B,
     tree: RenderTree,
@@ -29,24 +31,42 @@ impl<B: RenderBackend> core::fmt::Debug for HydrolysisRenderer<B> {
 impl<B: RenderBackend> HydrolysisRenderer<B> {
     /// Creates a renderer backed by the provided surface implementation.
     #[must_use]
-    pub const fn new(backend: B) -> Self {
+    pub fn new(backend: B) -> Self {
         Self {
             backend,
             tree: RenderTree::new(),
         }
     }
 
-    /// Renders a view once.
-    ///
-    /// This rebuilds the entire render tree every call (TODO: incremental diffing).
-    pub fn render_view<V: View>(&mut self, env: &Environment, view: V) -> FrameResult {
+    /// Replaces the rendered view, rebuilding the render tree.
+    pub fn mount<V: View>(&mut self, env: &Environment, view: V) {
         self.tree = build_tree(env, AnyView::new(view));
         if let Some(root) = self.tree.root() {
             self.tree.mark_dirty(root, DirtyReason::Layout);
         }
+    }
+
+    /// Renders the next frame of the mounted view.
+    ///
+    /// Returns [`FrameResult::Idle`] without touching the surface when nothing changed.
+    pub fn render(&mut self, env: &Environment) -> FrameResult {
         self.backend.render(&mut self.tree, env)
     }
 
+    /// Mounts `view` and renders its first frame.
+    ///
+    /// Later frames should go through [`render`](Self::render), which reuses the tree.
+    pub fn render_view<V: View>(&mut self, env: &Environment, view: V) -> FrameResult {
+        self.mount(env, view);
+        self.render(env)
+    }
+
+    /// Returns `true` if the mounted view changed since the last frame.
+    #[must_use]
+    pub fn needs_frame(&self) -> bool {
+        self.tree.needs_frame()
+    }
+
     /// Returns a reference to the underlying backend.
     pub const fn backend(&self) -> &B {
         &self.backend
@@ -57,3 +77,79 @@ impl<B: RenderBackend> HydrolysisRenderer<B> {
         &mut self.backend
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use nami::Binding;
+    use waterui::{ViewExt, layout::overlay, text::text};
+    use waterui_core::Str;
+
+    use super::*;
+    use crate::{
+        Constraints, Size,
+        tree::{LayoutEngine, LayoutStats},
+    };
+
+    /// Backend that only lays out, recording what each frame cost.
+    #[derive(Debug, Default)]
+    struct LayoutBackend {
+        frames: Vec<LayoutStats>,
+    }
+
+    impl RenderBackend for LayoutBackend {
+        fn render(&mut self, tree: &mut RenderTree, env: &Environment) -> FrameResult {
+            let dirty: Vec<_> = tree.drain_dirty().collect();
+            if dirty.is_empty() {
+                return FrameResult::Idle;
+            }
+            for dirty in dirty {
+                if dirty.reason == DirtyReason::Reactive
+                    && let Some(node) = tree.node_mut(dirty.id)
+                {
+                    node.update_reactive();
+                }
+            }
+            let stats = LayoutEngine::new(tree, env)
+                .constraints(Constraints::tight(Size::new(320.0, 240.0)))
+                .run();
+            self.frames.push(stats);
+            FrameResult::Presented
+        }
+    }
+
+    #[test]
+    fn reactive_changes_only_lay_out_the_changed_path() {
+        let env = Environment::new();
+        let title = Binding::container(Str::from("Title"));
+        let mut renderer = HydrolysisRenderer::new(LayoutBackend::default());
+
+        // padding -> overlay -> [title, caption]
+        renderer.mount(
+            &env,
+            overlay(text(title.clone()), text("Caption")).padding(),
+        );
+        assert_eq!(renderer.render(&env), FrameResult::Presented);
+        assert_eq!(renderer.render(&env), FrameResult::Idle);
+
+        title.set(Str::from("A longer title"));
+        assert!(renderer.needs_frame());
+        assert_eq!(renderer.render(&env), FrameResult::Presented);
+
+        let frames = &renderer.backend().frames;
+        assert_eq!(
+            frames[0],
+            LayoutStats {
+                laid_out: 4,
+                reused: 0
+            }
+        );
+        // The caption keeps its layout
+        assert_eq!(
+            frames[1],
+            LayoutStats {
+                laid_out: 3,
+                reused: 1
+            }
+        );
+    }
+}
@@ -1,8 +1,12 @@
 //! Backend-agnostic drawing commands recorded during rendering.
 
+#[cfg(feature = "lottie")]
+use std::sync::Arc;
+
+use waterui::filter::ColorMatrix;
 use waterui_color::ResolvedColor;
 
-use crate::tree::layout::{Point, Rect};
+use crate::tree::layout::{Point, Rect, Size};
 
 /// A fully recorded scene containing draw commands.
 #[derive(Debug, Clone, Default)]
@@ -72,6 +76,74 @@ pub enum DrawCommand {
         /// Font size in logical pixels.
         size: f32,
     },
+    /// Draw a frame of a Lottie animation scaled to fit `rect`.
+    #[cfg(feature = "lottie")]
+    Lottie {
+        /// The parsed animation.
+        composition: Arc<velato::Composition>,
+        /// The frame to draw, within the animation's frame range.
+        frame: f64,
+        /// Rectangle the animation is fitted into (logical pixels).
+        rect: Rect,
+    },
+    /// Start applying an effect to the commands up to the matching
+    /// [`PopEffect`](Self::PopEffect).
+    PushEffect(Effect),
+    /// Stop applying the most recently pushed effect.
+    PopEffect,
     /// Reserved for future commands (gradients, images, strokes, etc.).
     Placeholder(&'static str),
 }
+
+impl DrawCommand {
+    /// Returns the area this command draws into, if it draws anything.
+    #[must_use]
+    pub fn bounds(&self) -> Option<Rect> {
+        match self {
+            Self::SolidRect { rect, .. } => Some(*rect),
+            Self::Text {
+                content,
+                origin,
+                size,
+                ..
+            } => {
+                // TODO(text-measurement): use shaped run bounds once text is laid out.
+                let width = content.chars().count() as f32 * size * 0.6;
+                Some(Rect::new(*origin, Size::new(width, size * 1.2)))
+            }
+            #[cfg(feature = "lottie")]
+            Self::Lottie { rect, .. } => Some(*rect),
+            Self::PushEffect(_) | Self::PopEffect | Self::Placeholder(_) => None,
+        }
+    }
+}
+
+/// A visual effect applied to a group of draw commands.
+#[derive(Debug, Clone, Copy)]
+pub enum Effect {
+    /// Draw a blurred, tinted copy of the group's silhouette behind it.
+    Shadow {
+        /// Shadow color resolved from the environment.
+        color: ResolvedColor,
+        /// Offset of the shadow from the group (logical pixels).
+        offset: Point,
+        /// Blur radius (logical pixels).
+        radius: f32,
+    },
+    /// Blur the group itself.
+    Blur {
+        /// Blur radius (logical pixels).
+        radius: f32,
+    },
+    /// Blur whatever was drawn behind the group, within its bounds, before
+    /// drawing the group on top.
+    BackdropBlur {
+        /// Blur radius (logical pixels).
+        radius: f32,
+    },
+    /// Transform the colors of the group with a color matrix.
+    ColorFilter {
+        /// Matrix applied to the straight (not premultiplied) RGBA of every pixel.
+        matrix: ColorMatrix,
+    },
+}
@@ -6,13 +6,14 @@ use waterui_core::Environment;
 #[derive(Debug)]
 pub struct LayoutCtx<'a> {
     env: &'a Environment,
+    constraints: Constraints,
 }
 
 impl<'a> LayoutCtx<'a> {
     /// Creates a new layout context.
     #[must_use]
-    pub const fn new(env: &'a Environment) -> Self {
-        Self { env }
+    pub const fn new(env: &'a Environment, constraints: Constraints) -> Self {
+        Self { env, constraints }
     }
 
     /// Returns the environment associated with this layout pass.
@@ -20,6 +21,60 @@ impl<'a> LayoutCtx<'a> {
     pub const fn env(&self) -> &'a Environment {
         self.env
     }
+
+    /// Returns the sizes the node may take.
+    #[must_use]
+    pub const fn constraints(&self) -> Constraints {
+        self.constraints
+    }
+}
+
+/// Range of sizes a node may take during layout.
+///
+/// Layout results are cached per node together with the constraints they
+/// were computed under, so a node is only laid out again when its content
+/// or its constraints change.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Constraints {
+    /// Smallest allowed size.
+    pub min: Size,
+    /// Largest allowed size; infinite along unbounded axes.
+    pub max: Size,
+}
+
+impl Constraints {
+    /// Constraints allowing any size.
+    #[must_use]
+    pub const fn unbounded() -> Self {
+        Self {
+            min: Size::new(0.0, 0.0),
+            max: Size::new(f32::INFINITY, f32::INFINITY),
+        }
+    }
+
+    /// Constraints allowing any size up to `max`.
+    #[must_use]
+    pub const fn loose(max: Size) -> Self {
+        Self {
+            min: Size::new(0.0, 0.0),
+            max,
+        }
+    }
+
+    /// Constraints allowing exactly `size`.
+    #[must_use]
+    pub const fn tight(size: Size) -> Self {
+        Self {
+            min: size,
+            max: size,
+        }
+    }
+}
+
+impl Default for Constraints {
+    fn default() -> Self {
+        Self::unbounded()
+    }
 }
 
 /// Two-dimensional size expressed in logical pixels.
@@ -94,6 +149,41 @@ impl Rect {
     pub fn max_y(&self) -> f32 {
         self.origin.y + self.size.height
     }
+
+    /// Returns the smallest rectangle containing both rectangles.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let origin = Point::new(
+            self.origin.x.min(other.origin.x),
+            self.origin.y.min(other.origin.y),
+        );
+        let max = Point::new(
+            self.max_x().max(other.max_x()),
+            self.max_y().max(other.max_y()),
+        );
+        Self::new(origin, Size::new(max.x - origin.x, max.y - origin.y))
+    }
+
+    /// Returns the rectangle grown by `amount` on every side.
+    #[must_use]
+    pub fn outset(&self, amount: f32) -> Self {
+        Self::new(
+            Point::new(self.origin.x - amount, self.origin.y - amount),
+            Size::new(
+                self.size.width + amount * 2.0,
+                self.size.height + amount * 2.0,
+            ),
+        )
+    }
+
+    /// Returns the rectangle moved by `offset`.
+    #[must_use]
+    pub fn translate(&self, offset: Point) -> Self {
+        Self::new(
+            Point::new(self.origin.x + offset.x, self.origin.y + offset.y),
+            self.size,
+        )
+    }
 }
 
 /// Result returned by [`RenderNode::layout`](super::render::RenderNode::layout).
@@ -4,35 +4,173 @@ use waterui_core::Environment;
 
 use crate::{LayoutCtx, NodeId, RenderTree};
 
+use super::Constraints;
+
+/// Number of nodes a layout pass laid out and reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LayoutStats {
+    /// Nodes whose `layout` ran.
+    pub laid_out: usize,
+    /// Subtrees whose cached layout was kept without visiting their descendants.
+    pub reused: usize,
+}
+
 /// Layout executor that drives `WaterUI` layout trait objects and writes results back to nodes.
+///
+/// Only nodes whose content or constraints changed since the previous pass are laid out; see
+/// [`RenderTree::mark_dirty`].
 #[derive(Debug)]
 pub struct LayoutEngine<'a> {
     tree: &'a mut RenderTree,
     env: &'a Environment,
+    constraints: Constraints,
 }
 
 impl<'a> LayoutEngine<'a> {
-    /// Creates a new engine bound to the provided render tree.
+    /// Creates a new engine bound to the provided render tree, with unbounded constraints.
     pub const fn new(tree: &'a mut RenderTree, env: &'a Environment) -> Self {
-        Self { tree, env }
+        Self {
+            tree,
+            env,
+            constraints: Constraints::unbounded(),
+        }
     }
 
-    /// Runs layout for the entire tree.
-    ///
-    pub fn run(&mut self) {
+    /// Sets the constraints of the root node, usually the viewport size.
+    #[must_use]
+    pub const fn constraints(mut self, constraints: Constraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
+    /// Lays out the nodes of the tree that changed since the previous pass.
+    pub fn run(&mut self) -> LayoutStats {
+        let mut stats = LayoutStats::default();
         if let Some(root) = self.tree.root() {
-            self.layout_node(root);
+            self.layout_node(root, self.constraints, &mut stats);
         }
+        stats
     }
 
-    fn layout_node(&mut self, id: NodeId) {
-        if let Some(node) = self.tree.node_mut(id) {
-            let ctx = LayoutCtx::new(self.env);
-            node.layout(ctx);
+    fn layout_node(&mut self, id: NodeId, constraints: Constraints, stats: &mut LayoutStats) {
+        if self.tree.cached_layout(id, constraints).is_some() {
+            stats.reused += 1;
+            return;
         }
         let children = self.tree.children(id).to_vec();
-        for child in children {
-            self.layout_node(child);
+        let Some(node) = self.tree.node_mut(id) else {
+            return;
+        };
+        let result = node.layout(LayoutCtx::new(self.env, constraints));
+        let proposals: Vec<_> = (0..children.len())
+            .map(|index| node.child_constraints(constraints, index))
+            .collect();
+        stats.laid_out += 1;
+        for (child, proposal) in children.into_iter().zip(proposals) {
+            self.layout_node(child, proposal, stats);
+        }
+        self.tree.store_layout(id, constraints, result);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DirtyReason, LayoutResult, RenderCtx, RenderNode, Size};
+
+    #[derive(Debug)]
+    struct Leaf;
+
+    impl RenderNode for Leaf {
+        fn layout(&mut self, _ctx: LayoutCtx<'_>) -> LayoutResult {
+            LayoutResult {
+                size: Size::new(10.0, 10.0),
+            }
         }
+
+        fn paint(&mut self, _ctx: &mut RenderCtx<'_>) {}
+    }
+
+    /// Builds `root -> [branch -> [leaf], sibling]`.
+    fn tree() -> (RenderTree, NodeId, NodeId, NodeId) {
+        let mut tree = RenderTree::new();
+        let root = tree.replace_root(Box::new(Leaf));
+        let branch = tree.insert_child(root, Box::new(Leaf));
+        let leaf = tree.insert_child(branch, Box::new(Leaf));
+        let sibling = tree.insert_child(root, Box::new(Leaf));
+        (tree, branch, leaf, sibling)
+    }
+
+    fn run(tree: &mut RenderTree, env: &Environment) -> LayoutStats {
+        LayoutEngine::new(tree, env)
+            .constraints(Constraints::tight(Size::new(100.0, 50.0)))
+            .run()
+    }
+
+    #[test]
+    fn only_dirty_subtrees_are_laid_out_again() {
+        let env = Environment::new();
+        let (mut tree, _, leaf, _) = tree();
+
+        let first = run(&mut tree, &env);
+        assert_eq!(
+            first,
+            LayoutStats {
+                laid_out: 4,
+                reused: 0
+            }
+        );
+
+        let clean = run(&mut tree, &env);
+        assert_eq!(
+            clean,
+            LayoutStats {
+                laid_out: 0,
+                reused: 1
+            }
+        );
+
+        // The leaf and its ancestors run again; the sibling subtree is kept
+        tree.mark_dirty(leaf, DirtyReason::Layout);
+        let dirty = run(&mut tree, &env);
+        assert_eq!(
+            dirty,
+            LayoutStats {
+                laid_out: 3,
+                reused: 1
+            }
+        );
+    }
+
+    #[test]
+    fn paint_only_changes_keep_layout() {
+        let env = Environment::new();
+        let (mut tree, branch, _, _) = tree();
+        run(&mut tree, &env);
+
+        tree.mark_dirty(branch, DirtyReason::Paint);
+        assert_eq!(
+            run(&mut tree, &env),
+            LayoutStats {
+                laid_out: 0,
+                reused: 1
+            }
+        );
+    }
+
+    #[test]
+    fn children_receive_the_constraints_their_parent_proposes() {
+        let env = Environment::new();
+        let (mut tree, branch, leaf, sibling) = tree();
+        run(&mut tree, &env);
+
+        let proposal = Constraints::loose(Size::new(100.0, 50.0));
+        assert!(tree.cached_layout(branch, proposal).is_some());
+        assert!(tree.cached_layout(sibling, proposal).is_some());
+        assert!(tree.cached_layout(leaf, proposal).is_some());
+        assert!(
+            tree.cached_layout(branch, Constraints::tight(Size::new(100.0, 50.0)))
+                .is_none()
+        );
     }
 }
@@ -3,5 +3,5 @@
 pub mod context;
 pub mod engine;
 
-pub use context::{LayoutCtx, LayoutResult, Point, Rect, Size};
-pub use engine::LayoutEngine;
+pub use context::{Constraints, LayoutCtx, LayoutResult, Point, Rect, Size};
+pub use engine::{LayoutEngine, LayoutStats};
@@ -2,17 +2,26 @@
 //!
 //! Hydrolysis parses `AnyView` values into [`RenderNode`] implementations and stores them inside a
 //! [`RenderTree`]. Backends consume this tree to drive layout and painting.
+//!
+//! Layout is incremental: every node caches its last [`LayoutResult`] with the constraints and
+//! content version it was computed for. Marking a node dirty for layout bumps the version of the
+//! node and its ancestors, so the next [`LayoutEngine`] pass lays out only those nodes and skips
+//! every unchanged subtree. Nodes bind their [`NodeSignal`]s to the tree, so reactive changes mark
+//! them dirty on their own.
 
 pub mod layout;
 pub mod parser;
 pub mod reactive;
 pub mod render;
 
-use std::vec::Vec;
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    vec::Vec,
+};
 
-pub use layout::{LayoutCtx, LayoutEngine, LayoutResult};
+pub use layout::{Constraints, LayoutCtx, LayoutEngine, LayoutResult, LayoutStats};
 pub use parser::build_tree;
-pub use reactive::NodeSignal;
+pub use reactive::{DirtyHandle, NodeSignal};
 pub use render::{RenderCtx, RenderNode};
 
 /// Identifier for a render node stored inside the [`RenderTree`].
@@ -53,11 +62,22 @@ pub struct DirtyNode {
     pub reason: DirtyReason,
 }
 
+/// Layout result of a node with the inputs it was computed from.
+#[derive(Debug, Clone, Copy)]
+struct CachedLayout {
+    constraints: Constraints,
+    version: u64,
+    result: LayoutResult,
+}
+
 #[derive(Debug)]
 struct NodeEntry {
     parent: Option<NodeId>,
     children: Vec<NodeId>,
     node: Box<dyn RenderNode>,
+    /// Bumped whenever the node or one of its descendants needs layout.
+    version: u64,
+    layout: Option<CachedLayout>,
 }
 
 impl NodeEntry {
@@ -66,8 +86,15 @@ impl NodeEntry {
             parent,
             children: Vec::new(),
             node,
+            version: 0,
+            layout: None,
         }
     }
+
+    fn layout_is_valid(&self) -> bool {
+        self.layout
+            .is_some_and(|cached| cached.version == self.version)
+    }
 }
 
 /// Arena storing the parsed render nodes.
@@ -76,23 +103,26 @@ pub struct RenderTree {
     nodes: Vec<NodeEntry>,
     root: Option<NodeId>,
     dirty: Vec<DirtyNode>,
+    /// Marks queued through [`DirtyHandle`]s since the last [`drain_dirty`](Self::drain_dirty).
+    pending: Arc<Mutex<Vec<DirtyNode>>>,
 }
 
 impl RenderTree {
     /// Creates an empty render tree.
     #[must_use]
-    pub const fn new() -> Self {
-        Self {
-            nodes: Vec::new(),
-            root: None,
-            dirty: Vec::new(),
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
     /// Replaces the root node of the tree, clearing any existing nodes.
     pub fn replace_root(&mut self, node: Box<dyn RenderNode>) -> NodeId {
         self.nodes.clear();
         self.dirty.clear();
+        // Replaced nodes may have queued marks for ids that are about to be reused
+        self.pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
 
         let root_id = self.push_entry(NodeEntry::new(node, None));
         self.root = Some(root_id);
@@ -114,6 +144,7 @@ impl RenderTree {
 
         let id = self.push_entry(NodeEntry::new(node, Some(parent)));
         self.nodes[parent_index].children.push(id);
+        self.invalidate_layout(parent);
         id
     }
 
@@ -138,6 +169,9 @@ impl RenderTree {
     }
 
     /// Marks a node as dirty for the provided reason.
+    ///
+    /// Layout and reactive changes invalidate the cached layout of the node and its ancestors;
+    /// paint-only changes keep it.
     pub fn mark_dirty(&mut self, id: NodeId, reason: DirtyReason) {
         if self
             .dirty
@@ -146,14 +180,79 @@ impl RenderTree {
         {
             return;
         }
+        if reason != DirtyReason::Paint {
+            self.invalidate_layout(id);
+        }
         self.dirty.push(DirtyNode { id, reason });
     }
 
-    /// Drains all dirty nodes discovered since the previous frame.
+    /// Bumps the content version of `id` and its ancestors so their cached layouts are ignored.
+    fn invalidate_layout(&mut self, id: NodeId) {
+        let mut current = Some(id);
+        while let Some(id) = current {
+            let Some(entry) = self.nodes.get_mut(id.index()) else {
+                return;
+            };
+            let was_valid = entry.layout_is_valid();
+            entry.version += 1;
+            // Ancestors of a node awaiting layout await layout too
+            if !was_valid {
+                return;
+            }
+            current = entry.parent;
+        }
+    }
+
+    /// Returns the cached layout of `id` if it is still valid under `constraints`.
+    ///
+    /// A valid cache also means none of the node's descendants need layout.
+    #[must_use]
+    pub fn cached_layout(&self, id: NodeId, constraints: Constraints) -> Option<LayoutResult> {
+        let entry = self.nodes.get(id.index())?;
+        let cached = entry.layout?;
+        (cached.version == entry.version && cached.constraints == constraints)
+            .then_some(cached.result)
+    }
+
+    /// Records the layout computed for `id` under `constraints`.
+    pub fn store_layout(&mut self, id: NodeId, constraints: Constraints, result: LayoutResult) {
+        if let Some(entry) = self.nodes.get_mut(id.index()) {
+            entry.layout = Some(CachedLayout {
+                constraints,
+                version: entry.version,
+                result,
+            });
+        }
+    }
+
+    /// Returns the last layout computed for `id`, whether or not it is still valid.
+    #[must_use]
+    pub fn layout(&self, id: NodeId) -> Option<LayoutResult> {
+        Some(self.nodes.get(id.index())?.layout?.result)
+    }
+
+    /// Drains all dirty nodes discovered since the previous frame, including those marked
+    /// through a [`DirtyHandle`].
     pub fn drain_dirty(&mut self) -> impl Iterator<Item = DirtyNode> + '_ {
+        let pending =
+            core::mem::take(&mut *self.pending.lock().unwrap_or_else(PoisonError::into_inner));
+        for dirty in pending {
+            self.mark_dirty(dirty.id, dirty.reason);
+        }
         self.dirty.drain(..)
     }
 
+    /// Returns `true` if a node was marked dirty since the previous frame.
+    #[must_use]
+    pub fn needs_frame(&self) -> bool {
+        !self.dirty.is_empty()
+            || !self
+                .pending
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .is_empty()
+    }
+
     /// Visits a node mutably.
     #[must_use]
     pub fn node_mut(&mut self, id: NodeId) -> Option<&mut (dyn RenderNode + '_)> {
@@ -164,8 +263,9 @@ impl RenderTree {
         }
     }
 
-    fn push_entry(&mut self, entry: NodeEntry) -> NodeId {
+    fn push_entry(&mut self, mut entry: NodeEntry) -> NodeId {
         let id = NodeId::new(self.nodes.len());
+        entry.node.bind(&DirtyHandle::new(id, self.pending.clone()));
         self.nodes.push(entry);
         id
     }
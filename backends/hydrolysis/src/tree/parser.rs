@@ -1,18 +1,25 @@
 //! Utilities for converting `AnyView` trees into Hydrolysis render nodes.
 
+use waterui::background::Background;
 use waterui::component::progress::ProgressConfig;
+use waterui::filter::ColorFilter;
+use waterui::media::animated_vector::AnimatedVectorConfig;
 use waterui::prelude::Divider;
+use waterui::style::Shadow;
 use waterui_controls::{
     slider::SliderConfig, stepper::StepperConfig, text_field::TextFieldConfig, toggle::ToggleConfig,
 };
-use waterui_core::{AnyView, Environment, Native, View};
+use waterui_core::{AnyView, Environment, Metadata, Native, View};
 use waterui_layout::{
     ScrollView, container::FixedContainer as LayoutFixedContainer, spacer::Spacer,
 };
 use waterui_text::TextConfig;
 
 use crate::{
-    components::text::TextNode,
+    components::{
+        effect::{EffectNode, MATERIAL_BLUR_RADIUS},
+        text::TextNode,
+    },
     tree::{NodeId, RenderTree},
 };
 
@@ -30,7 +37,7 @@ struct TreeBuilder<'env> {
 }
 
 impl<'env> TreeBuilder<'env> {
-    const fn new(env: &'env Environment) -> Self {
+    fn new(env: &'env Environment) -> Self {
         Self {
             env,
             tree: RenderTree::new(),
@@ -139,7 +146,55 @@ impl<'env> TreeBuilder<'env> {
             Err(view) => view,
         };
 
-        // TODO(parser): handle layout containers, images, controls, and metadata.
+        let view = match view.downcast::<Native<AnimatedVectorConfig>>() {
+            Ok(native) => {
+                let node = crate::components::animated_vector::AnimatedVectorNode::new(
+                    native.into_inner(),
+                );
+                return Some(self.insert_node(parent, Box::new(node)));
+            }
+            Err(view) => view,
+        };
+
+        // Effect modifiers wrap their content in an effect node.
+        let view = match view.downcast::<Metadata<Shadow>>() {
+            Ok(metadata) => {
+                let Metadata { content, value } = *metadata;
+                let id = self.insert_node(parent, Box::new(EffectNode::shadow(value, self.env)));
+                self.build_any(content, Some(id));
+                return Some(id);
+            }
+            Err(view) => view,
+        };
+
+        let view = match view.downcast::<Metadata<ColorFilter>>() {
+            Ok(metadata) => {
+                let Metadata { content, value } = *metadata;
+                let id = self.insert_node(parent, Box::new(EffectNode::color_filter(value)));
+                self.build_any(content, Some(id));
+                return Some(id);
+            }
+            Err(view) => view,
+        };
+
+        let view = match view.downcast::<Metadata<Background>>() {
+            Ok(metadata) => {
+                let Metadata { content, value } = *metadata;
+                if let Background::Material(_) = value {
+                    let id = self.insert_node(
+                        parent,
+                        Box::new(EffectNode::backdrop_blur(MATERIAL_BLUR_RADIUS)),
+                    );
+                    self.build_any(content, Some(id));
+                    return Some(id);
+                }
+                // TODO(parser): paint color, gradient and image backgrounds.
+                return self.build_any(content, parent);
+            }
+            Err(view) => view,
+        };
+
+        // TODO(parser): handle layout containers, images, controls, and remaining metadata.
 
         // Default fallback: expand body and keep parsing.
         let next = view.body(self.env);
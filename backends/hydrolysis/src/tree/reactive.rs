@@ -2,15 +2,54 @@
 
 extern crate alloc;
 
-use alloc::sync::Arc;
+use alloc::{sync::Arc, vec::Vec};
 use core::{
     fmt,
     sync::atomic::{AtomicBool, Ordering},
 };
+use std::sync::{Mutex, OnceLock, PoisonError};
 
 use nami::{Computed, Signal, watcher::BoxWatcherGuard};
 
+use super::{DirtyNode, DirtyReason, NodeId};
+
+/// Marks one node of a [`RenderTree`](super::RenderTree) dirty from outside a frame.
+///
+/// The tree hands a handle to every node it stores; marks are queued and picked up by the next
+/// [`drain_dirty`](super::RenderTree::drain_dirty).
+#[derive(Debug, Clone)]
+pub struct DirtyHandle {
+    id: NodeId,
+    pending: Arc<Mutex<Vec<DirtyNode>>>,
+}
+
+impl DirtyHandle {
+    pub(super) const fn new(id: NodeId, pending: Arc<Mutex<Vec<DirtyNode>>>) -> Self {
+        Self { id, pending }
+    }
+
+    /// Returns the node this handle marks.
+    #[must_use]
+    pub const fn id(&self) -> NodeId {
+        self.id
+    }
+
+    /// Queues the node for the next frame.
+    pub fn mark(&self, reason: DirtyReason) {
+        self.pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(DirtyNode {
+                id: self.id,
+                reason,
+            });
+    }
+}
+
 /// Wraps a `Computed<T>` and tracks whether its value changed since the last refresh.
+///
+/// Once [bound](Self::bind) to its node, every change also marks the node
+/// [`DirtyReason::Reactive`].
 pub struct NodeSignal<T>
 where
     T: Clone + 'static,
@@ -18,6 +57,7 @@ where
     computed: Computed<T>,
     current: T,
     dirty: Arc<AtomicBool>,
+    handle: Arc<OnceLock<DirtyHandle>>,
     _guard: BoxWatcherGuard,
 }
 
@@ -31,17 +71,30 @@ where
         let current = computed.get();
         let dirty = Arc::new(AtomicBool::new(false));
         let dirty_flag = dirty.clone();
+        let handle = Arc::new(OnceLock::<DirtyHandle>::new());
+        let node_handle = handle.clone();
         let guard = computed.watch(move |_| {
             dirty_flag.store(true, Ordering::Relaxed);
+            if let Some(handle) = node_handle.get() {
+                handle.mark(DirtyReason::Reactive);
+            }
         });
         Self {
             computed,
             current,
             dirty,
+            handle,
             _guard: guard,
         }
     }
 
+    /// Marks the node behind `handle` dirty whenever the value changes.
+    ///
+    /// Only the first handle is kept; nodes are bound once, when inserted into the tree.
+    pub fn bind(&self, handle: &DirtyHandle) {
+        let _ = self.handle.set(handle.clone());
+    }
+
     /// Returns the cached value.
     #[must_use]
     pub const fn current(&self) -> &T {
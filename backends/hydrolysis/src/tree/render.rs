@@ -4,7 +4,10 @@ use std::fmt::Debug;
 
 use waterui_core::Environment;
 
-use super::layout::{LayoutCtx, LayoutResult};
+use super::{
+    layout::{Constraints, LayoutCtx, LayoutResult},
+    reactive::DirtyHandle,
+};
 use crate::scene::{DrawCommand, Scene, SceneBuilder};
 
 /// Context passed to nodes when painting into a backend-specific surface.
@@ -47,9 +50,25 @@ pub trait RenderNode: Debug {
     /// Performs layout using the provided context and returns the resulting size.
     fn layout(&mut self, ctx: LayoutCtx<'_>) -> LayoutResult;
 
+    /// Returns the constraints proposed to the child at `index` when this node is laid out
+    /// under `constraints`.
+    ///
+    /// By default children may take any size up to the node's largest allowed size.
+    fn child_constraints(&self, constraints: Constraints, _index: usize) -> Constraints {
+        Constraints::loose(constraints.max)
+    }
+
     /// Emits draw calls into the backend-specific renderer.
     fn paint(&mut self, ctx: &mut RenderCtx<'_>);
 
+    /// Emits draw calls after the node's children have painted, such as closing
+    /// an effect opened in [`paint`](Self::paint).
+    fn paint_after(&mut self, _ctx: &mut RenderCtx<'_>) {}
+
+    /// Connects the node's reactive inputs to the tree, usually by
+    /// [binding](super::NodeSignal::bind) its signals. Called once, when the node is inserted.
+    fn bind(&mut self, _handle: &DirtyHandle) {}
+
     /// Updates reactive state. Called whenever Hydrolysis detects binding/computed changes.
     fn update_reactive(&mut self) {}
 }
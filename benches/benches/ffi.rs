@@ -0,0 +1,71 @@
+//! Conversion of large arrays across the FFI boundary.
+//!
+//! Layout results and text runs cross the boundary as `WuiArray`s, converting
+//! every element on the way.
+
+use std::hint::black_box;
+
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use waterui::Str;
+use waterui::layout::{Point, Rect, Size};
+use waterui_ffi::IntoFFI;
+
+const SIZES: [usize; 3] = [100, 10_000, 1_000_000];
+
+fn rects(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ffi/rects");
+    for size in SIZES {
+        let rects: Vec<Rect> = (0..size)
+            .map(|index| {
+                let y = index as f32 * 44.0;
+                Rect::new(Point::new(0.0, y), Size::new(390.0, 44.0))
+            })
+            .collect();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &rects, |b, rects| {
+            b.iter_batched(
+                || rects.clone(),
+                |rects| black_box(rects.into_ffi()),
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+fn bytes(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ffi/bytes");
+    for size in SIZES {
+        let data: Vec<u8> = (0..size).map(|index| index as u8).collect();
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &data, |b, data| {
+            b.iter_batched(
+                || data.clone(),
+                |data| black_box(data.into_ffi()),
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+fn strings(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ffi/strings");
+    for size in [100, 10_000] {
+        let strings: Vec<Str> = (0..size)
+            .map(|index| Str::from(format!("Row {index}")))
+            .collect();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &strings, |b, strings| {
+            b.iter_batched(
+                || strings.clone(),
+                |strings| black_box(strings.into_ffi()),
+                BatchSize::LargeInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, rects, bytes, strings);
+criterion_main!(benches);
@@ -0,0 +1,78 @@
+//! The id diff backends run when list contents change.
+//!
+//! After a change notification, backends read the id of every row through
+//! [`Views::get_id`] and match it against the ids they show to decide which
+//! rows to move, insert and remove.
+
+use std::{collections::HashMap, hint::black_box};
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use waterui::component::list::{List, ListConfig};
+use waterui::view::ConfigurableView;
+use waterui::views::Views;
+use waterui_benches::{Feed, Item, row};
+
+const SIZES: [u64; 3] = [100, 1_000, 10_000];
+
+fn config(feed: Feed) -> ListConfig {
+    List::for_each(feed, row).config()
+}
+
+fn ids(config: &ListConfig) -> Vec<i32> {
+    (0..config.contents.len())
+        .filter_map(|index| config.contents.get_id(index))
+        .map(|id| i32::from(*id))
+        .collect()
+}
+
+/// Returns, for each row after the change, its index before the change.
+fn diff(old: &[i32], new: &[i32]) -> Vec<Option<usize>> {
+    let positions: HashMap<i32, usize> = old
+        .iter()
+        .enumerate()
+        .map(|(index, id)| (*id, index))
+        .collect();
+    new.iter().map(|id| positions.get(id).copied()).collect()
+}
+
+fn read_ids(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list/read_ids");
+    for size in SIZES {
+        let config = config(Feed::new(size));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &config, |b, config| {
+            b.iter(|| black_box(ids(config)));
+        });
+    }
+    group.finish();
+}
+
+fn insert_middle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("list/diff_insert_middle");
+    for size in SIZES {
+        group.bench_function(BenchmarkId::from_parameter(size), |b| {
+            b.iter_batched(
+                || {
+                    let feed = Feed::new(size);
+                    let config = config(feed.clone());
+                    let old = ids(&config);
+                    (feed, config, old)
+                },
+                |(feed, config, old)| {
+                    feed.insert(
+                        old.len() / 2,
+                        Item {
+                            id: size,
+                            title: "Inserted".to_string(),
+                        },
+                    );
+                    black_box(diff(&old, &ids(&config)))
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, read_ids, insert_middle);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+//! URL and color parsing.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use waterui::Color;
+use waterui::media::Url;
+use waterui_benches::{COLORS, URLS};
+
+fn urls(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse/url");
+    for url in URLS {
+        group.bench_function(*url, |b| {
+            b.iter(|| black_box(black_box(*url).parse::<Url>()));
+        });
+    }
+    group.bench_function("web", |b| {
+        b.iter(|| {
+            for url in URLS {
+                black_box(Url::parse(black_box(url)));
+            }
+        });
+    });
+    group.finish();
+}
+
+fn colors(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse/color");
+    for color in COLORS {
+        group.bench_function(*color, |b| {
+            b.iter(|| black_box(Color::try_srgb_hex(black_box(color))));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, urls, colors);
+criterion_main!(benches);
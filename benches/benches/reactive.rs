@@ -0,0 +1,79 @@
+//! Fan-out of binding updates to watchers and computed signals.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use waterui::prelude::*;
+
+const FAN_OUT: [usize; 3] = [1, 100, 1_000];
+
+fn watchers(c: &mut Criterion) {
+    let mut group = c.benchmark_group("binding/watchers");
+    for count in FAN_OUT {
+        let value = Binding::container(0_u64);
+        let _guards: Vec<_> = (0..count)
+            .map(|_| {
+                value.watch(|ctx| {
+                    black_box(ctx.into_value());
+                })
+            })
+            .collect();
+        group.bench_with_input(BenchmarkId::from_parameter(count), &value, |b, value| {
+            let mut next = 0;
+            b.iter(|| {
+                next += 1;
+                value.set(black_box(next));
+            });
+        });
+    }
+    group.finish();
+}
+
+fn computed(c: &mut Criterion) {
+    let mut group = c.benchmark_group("binding/computed");
+    for count in FAN_OUT {
+        let value = Binding::container(0_u64);
+        let _guards: Vec<_> = (0..count)
+            .map(|offset| {
+                value
+                    .clone()
+                    .map(move |value| value + offset as u64)
+                    .watch(|ctx| {
+                        black_box(ctx.into_value());
+                    })
+            })
+            .collect();
+        group.bench_with_input(BenchmarkId::from_parameter(count), &value, |b, value| {
+            let mut next = 0;
+            b.iter(|| {
+                next += 1;
+                value.set(black_box(next));
+            });
+        });
+    }
+    group.finish();
+}
+
+fn chain(c: &mut Criterion) {
+    let mut group = c.benchmark_group("binding/chain");
+    for depth in [1, 10, 100] {
+        let value = Binding::container(0_u64);
+        let tail = (0..depth).fold(value.clone().computed(), |signal, _| {
+            signal.map(|value| value + 1).computed()
+        });
+        let _guard = tail.watch(|ctx| {
+            black_box(ctx.into_value());
+        });
+        group.bench_with_input(BenchmarkId::from_parameter(depth), &value, |b, value| {
+            let mut next = 0;
+            b.iter(|| {
+                next += 1;
+                value.set(black_box(next));
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, watchers, computed, chain);
+criterion_main!(benches);
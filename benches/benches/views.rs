@@ -0,0 +1,29 @@
+//! Construction of deep and wide view trees.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use waterui_benches::{deep_tree, wide_tree};
+
+fn deep(c: &mut Criterion) {
+    let mut group = c.benchmark_group("views/deep");
+    for depth in [10, 100, 1_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(depth), &depth, |b, &depth| {
+            b.iter(|| black_box(deep_tree(depth)));
+        });
+    }
+    group.finish();
+}
+
+fn wide(c: &mut Criterion) {
+    let mut group = c.benchmark_group("views/wide");
+    for width in [10, 100, 1_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(width), &width, |b, &width| {
+            b.iter(|| black_box(wide_tree(width)));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, deep, wide);
+criterion_main!(benches);
@@ -5,9 +5,7 @@ use std::env;
 fn main() {
     println!("cargo:rerun-if-env-changed=WATERUI_ENABLE_HOT_RELOAD");
     println!("cargo:rustc-check-cfg=cfg(waterui_hot_reload_lib)");
-    let enable = env::var("WATERUI_ENABLE_HOT_RELOAD")
-        .map(|val| val != "0")
-        .unwrap_or(false);
+    let enable = env::var("WATERUI_ENABLE_HOT_RELOAD").is_ok_and(|val| val != "0");
     if enable {
         println!("cargo:rustc-cfg=waterui_hot_reload_lib");
     }
@@ -44,6 +44,12 @@ impl AndroidBackend {
         &self.project_path
     }
 
+    /// Get the pinned version of the Android backend, if any.
+    #[must_use]
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
     /// Get the path to the Gradle wrapper script within the Android project.
     #[must_use]
     pub fn gradlew_path(&self) -> PathBuf {
@@ -10,6 +10,7 @@ use crate::{
         device::AndroidDevice,
         toolchain::{AndroidNdk, AndroidSdk, AndroidToolchain},
     },
+    assets::{AssetLayout, bundle_assets},
     build::{BuildOptions, RustBuild},
     device::Artifact,
     platform::{PackageOptions, Platform},
@@ -479,6 +480,12 @@ impl Platform for AndroidPlatform {
         let dest_lib = output_dir.join("libwaterui_app.so");
         copy_file(&source_lib, &dest_lib).await?;
 
+        // Bundle `asset!` files; Gradle packages `app/src/main/assets` into the APK
+        let assets_dir = project
+            .backend_path::<AndroidBackend>()
+            .join("app/src/main/assets");
+        bundle_assets(project.root(), &assets_dir, AssetLayout::Plain).await?;
+
         Ok(lib_dir)
     }
 
@@ -0,0 +1,155 @@
+//! Asset bundling for files referenced with `waterui::asset!`.
+//!
+//! Assets live in the `assets/` directory at the project root. The `asset!`
+//! macro resolves each file to a platform-specific URL at compile time; this
+//! module copies the files to where those URLs point:
+//!
+//! - Apple: `<app resources>/assets/<path>`, copied by the Xcode build script
+//! - Android: `app/src/main/assets/<path>`, served as `file:///android_asset/<path>`
+//! - Web: `<output>/assets/<path with content hash>`, written by `water build --platform web`
+//!
+//! Other desktop targets are not bundled: their `assets/<path>` URLs resolve
+//! against the working directory, so those apps must be run from the project
+//! root.
+//!
+//! The content hash must stay in sync with the `asset!` macro in `waterui-macros`.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use smol::{fs, unblock};
+
+use crate::utils::copy_file;
+
+/// Directory, relative to the project root, that holds bundled assets.
+pub const ASSETS_DIR: &str = "assets";
+
+/// How asset files are named in the output directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssetLayout {
+    /// Keep the original relative paths (Apple and Android bundles).
+    Plain,
+    /// Insert the content hash before the extension, for cache-busting on the web.
+    Hashed,
+}
+
+/// FNV-1a 64-bit hash of the file contents, as 16 lowercase hex digits.
+#[must_use]
+pub fn content_hash(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Inserts `hash` before the extension: `images/logo.png` -> `images/logo.<hash>.png`.
+#[must_use]
+pub fn hashed_path(path: &Path, hash: &str) -> PathBuf {
+    let file_name = match (path.file_stem(), path.extension()) {
+        (Some(stem), Some(ext)) => format!(
+            "{}.{hash}.{}",
+            stem.to_string_lossy(),
+            ext.to_string_lossy()
+        ),
+        _ => format!(
+            "{}.{hash}",
+            path.file_name().unwrap_or_default().to_string_lossy()
+        ),
+    };
+    path.with_file_name(file_name)
+}
+
+/// List all files under `dir`, relative to it.
+fn list_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![PathBuf::new()];
+    while let Some(relative) = pending.pop() {
+        for entry in std::fs::read_dir(dir.join(&relative))? {
+            let entry = entry?;
+            let path = relative.join(entry.file_name());
+            if entry.file_type()?.is_dir() {
+                pending.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Copy the project's `assets/` directory into `dest`.
+///
+/// Returns the number of files copied. Projects without an `assets/`
+/// directory are skipped.
+///
+/// # Errors
+/// - If the assets cannot be read or copied.
+pub async fn bundle_assets(root: &Path, dest: &Path, layout: AssetLayout) -> io::Result<usize> {
+    let source = root.join(ASSETS_DIR);
+    if !source.is_dir() {
+        return Ok(0);
+    }
+
+    let files = {
+        let source = source.clone();
+        unblock(move || list_files(&source)).await?
+    };
+
+    for relative in &files {
+        let from = source.join(relative);
+        let to = match layout {
+            AssetLayout::Plain => dest.join(relative),
+            AssetLayout::Hashed => {
+                let bytes = fs::read(&from).await?;
+                dest.join(hashed_path(relative, &content_hash(&bytes)))
+            }
+        };
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        copy_file(&from, &to).await?;
+    }
+
+    tracing::debug!("Bundled {} asset(s) into {}", files.len(), dest.display());
+    Ok(files.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn content_hash_matches_fnv1a() {
+        assert_eq!(content_hash(b""), "cbf29ce484222325");
+        assert_eq!(content_hash(b"a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn hash_is_inserted_before_extension() {
+        assert_eq!(
+            hashed_path(Path::new("images/logo.png"), "abc"),
+            Path::new("images/logo.abc.png")
+        );
+        assert_eq!(
+            hashed_path(Path::new("LICENSE"), "abc"),
+            Path::new("LICENSE.abc")
+        );
+    }
+
+    #[test]
+    fn bundles_nested_assets() {
+        let root = std::env::temp_dir().join(format!("water-assets-{}", std::process::id()));
+        let dest = root.join("out");
+        std::fs::create_dir_all(root.join("assets/images")).unwrap();
+        std::fs::write(root.join("assets/images/logo.png"), b"a").unwrap();
+
+        let count = smol::block_on(bundle_assets(&root, &dest, AssetLayout::Hashed)).unwrap();
+        assert_eq!(count, 1);
+        assert!(dest.join("images/logo.af63dc4c8601ec8c.png").is_file());
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
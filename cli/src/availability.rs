@@ -0,0 +1,337 @@
+//! Warnings for components that need a newer OS than the project targets.
+//!
+//! Components backed by a native control that only exists from some OS
+//! version on implement `Available` in `waterui-core` and should be wrapped
+//! in `if_available` with a fallback. [`check`] compares the floors in
+//! [`FLOORS`] with the deployment targets of the platform projects and
+//! reports every use of such a component that is not wrapped.
+
+use std::{
+    fmt, io,
+    path::{Path, PathBuf},
+};
+
+use smol::{fs, unblock};
+
+use crate::{
+    android::backend::AndroidBackend, apple::backend::AppleBackend, i18n::list_sources,
+    project::Project,
+};
+
+/// An OS version, or an API level on Android.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    /// The major version, or the API level.
+    pub major: u32,
+    /// The minor version.
+    pub minor: u32,
+}
+
+impl Version {
+    /// Creates a version.
+    #[must_use]
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    /// Parses `major.minor`, with an optional patch version that is ignored.
+    #[must_use]
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.trim().split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().map_or(Some(0), |minor| minor.parse().ok())?;
+        Some(Self { major, minor })
+    }
+}
+
+/// A platform with a deployment target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetPlatform {
+    /// iOS.
+    Ios,
+    /// macOS.
+    Macos,
+    /// Android.
+    Android,
+}
+
+impl TargetPlatform {
+    /// Formats `version` the way the platform names it.
+    fn describe(self, version: Version) -> String {
+        match self {
+            Self::Ios => format!("iOS {}.{}", version.major, version.minor),
+            Self::Macos => format!("macOS {}.{}", version.major, version.minor),
+            Self::Android => format!("Android API level {}", version.major),
+        }
+    }
+}
+
+/// The minimum OS versions of a component.
+#[derive(Debug, Clone, Copy)]
+pub struct Floor {
+    /// Type name of the component.
+    pub component: &'static str,
+    /// Minimum iOS version.
+    pub ios: Option<Version>,
+    /// Minimum macOS version.
+    pub macos: Option<Version>,
+    /// Minimum Android API level.
+    pub android: Option<Version>,
+}
+
+impl Floor {
+    const fn get(&self, platform: TargetPlatform) -> Option<Version> {
+        match platform {
+            TargetPlatform::Ios => self.ios,
+            TargetPlatform::Macos => self.macos,
+            TargetPlatform::Android => self.android,
+        }
+    }
+}
+
+/// Floors of the components that implement `Available`.
+///
+/// Keep in sync with the `Available` implementations in `waterui`.
+pub const FLOORS: &[Floor] = &[Floor {
+    component: "Table",
+    ios: Some(Version::new(16, 0)),
+    macos: Some(Version::new(12, 0)),
+    android: None,
+}];
+
+/// The deployment targets of a project's platform projects.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeploymentTargets {
+    /// `IPHONEOS_DEPLOYMENT_TARGET` of the Xcode project.
+    pub ios: Option<Version>,
+    /// `MACOSX_DEPLOYMENT_TARGET` of the Xcode project.
+    pub macos: Option<Version>,
+    /// `minSdk` of the Android app.
+    pub android: Option<Version>,
+}
+
+impl DeploymentTargets {
+    const fn get(&self, platform: TargetPlatform) -> Option<Version> {
+        match platform {
+            TargetPlatform::Ios => self.ios,
+            TargetPlatform::Macos => self.macos,
+            TargetPlatform::Android => self.android,
+        }
+    }
+}
+
+/// Returns the lowest value of `setting` in an Xcode `project.pbxproj`.
+#[must_use]
+pub fn xcode_deployment_target(pbxproj: &str, setting: &str) -> Option<Version> {
+    pbxproj
+        .lines()
+        .filter_map(|line| {
+            line.trim()
+                .strip_prefix(setting)?
+                .trim_start()
+                .strip_prefix('=')
+        })
+        .filter_map(|value| Version::parse(value.trim().trim_end_matches(';').trim_matches('"')))
+        .min()
+}
+
+/// Returns the `minSdk` of an Android `build.gradle.kts`.
+#[must_use]
+pub fn gradle_min_sdk(build_script: &str) -> Option<Version> {
+    build_script
+        .lines()
+        .filter_map(|line| {
+            line.trim()
+                .strip_prefix("minSdk")?
+                .trim_start()
+                .strip_prefix('=')
+        })
+        .find_map(|value| Some(Version::new(value.trim().parse().ok()?, 0)))
+}
+
+/// A use of a component on a target older than the component supports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvailabilityWarning {
+    /// Type name of the component.
+    pub component: &'static str,
+    /// The platform whose target is too old.
+    pub platform: TargetPlatform,
+    /// The minimum version the component needs.
+    pub required: Version,
+    /// The project's deployment target.
+    pub target: Version,
+    /// Source file of the use.
+    pub file: PathBuf,
+    /// One-based line number of the use.
+    pub line: usize,
+}
+
+impl fmt::Display for AvailabilityWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: `{}` needs {} but the project targets {}; wrap it in `if_available` with a fallback",
+            self.file.display(),
+            self.line,
+            self.component,
+            self.platform.describe(self.required),
+            self.platform.describe(self.target),
+        )
+    }
+}
+
+/// Returns the one-based lines of `source` that use `component` outside of
+/// an `if_available` call on the same line.
+#[must_use]
+pub fn unguarded_uses(source: &str, component: &str) -> Vec<usize> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.contains("if_available") && !line.trim_start().starts_with("//"))
+        .filter(|(_, line)| {
+            line.match_indices(component).any(|(index, _)| {
+                let before = line[..index].chars().next_back();
+                let after = line[index + component.len()..].chars().next();
+                !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+            })
+        })
+        .map(|(index, _)| index + 1)
+        .collect()
+}
+
+/// Read the deployment targets of the project's platform projects.
+///
+/// Platforms without a backend have no target.
+///
+/// # Errors
+/// - If a platform project exists but cannot be read.
+pub async fn deployment_targets(project: &Project) -> io::Result<DeploymentTargets> {
+    let mut targets = DeploymentTargets::default();
+
+    if project.apple_backend().is_some() {
+        let dir = project.backend_path::<AppleBackend>();
+        if let Some(pbxproj) = unblock(move || find_pbxproj(&dir)).await? {
+            let content = fs::read_to_string(pbxproj).await?;
+            targets.ios = xcode_deployment_target(&content, "IPHONEOS_DEPLOYMENT_TARGET");
+            targets.macos = xcode_deployment_target(&content, "MACOSX_DEPLOYMENT_TARGET");
+        }
+    }
+
+    if project.android_backend().is_some() {
+        let build_script = project
+            .backend_path::<AndroidBackend>()
+            .join("app")
+            .join("build.gradle.kts");
+        match fs::read_to_string(&build_script).await {
+            Ok(content) => targets.android = gradle_min_sdk(&content),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(targets)
+}
+
+/// Find the `project.pbxproj` of the Xcode project in `dir`.
+fn find_pbxproj(dir: &Path) -> io::Result<Option<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(None);
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "xcodeproj") {
+            let pbxproj = path.join("project.pbxproj");
+            if pbxproj.is_file() {
+                return Ok(Some(pbxproj));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Find uses of components that need a newer OS than the project targets.
+///
+/// # Errors
+/// - If the sources or platform projects cannot be read.
+pub async fn check(project: &Project) -> io::Result<Vec<AvailabilityWarning>> {
+    let targets = deployment_targets(project).await?;
+    let platforms = [
+        TargetPlatform::Ios,
+        TargetPlatform::Macos,
+        TargetPlatform::Android,
+    ];
+    let too_new: Vec<(&Floor, TargetPlatform, Version, Version)> = FLOORS
+        .iter()
+        .flat_map(|floor| {
+            platforms.into_iter().filter_map(move |platform| {
+                let required = floor.get(platform)?;
+                let target = targets.get(platform)?;
+                (target < required).then_some((floor, platform, required, target))
+            })
+        })
+        .collect();
+    if too_new.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let src = project.root().join("src");
+    let files = unblock(move || list_sources(&src)).await?;
+    let mut warnings = Vec::new();
+    for file in files {
+        let source = fs::read_to_string(&file).await?;
+        for &(floor, platform, required, target) in &too_new {
+            for line in unguarded_uses(&source, floor.component) {
+                warnings.push(AvailabilityWarning {
+                    component: floor.component,
+                    platform,
+                    required,
+                    target,
+                    file: file
+                        .strip_prefix(project.root())
+                        .unwrap_or(&file)
+                        .to_path_buf(),
+                    line,
+                });
+            }
+        }
+    }
+    Ok(warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_deployment_targets() {
+        let pbxproj = "
+            IPHONEOS_DEPLOYMENT_TARGET = 17.0;
+            MACOSX_DEPLOYMENT_TARGET = 14.0;
+            IPHONEOS_DEPLOYMENT_TARGET = \"15.4\";
+        ";
+        assert_eq!(
+            xcode_deployment_target(pbxproj, "IPHONEOS_DEPLOYMENT_TARGET"),
+            Some(Version::new(15, 4))
+        );
+        assert_eq!(
+            xcode_deployment_target(pbxproj, "MACOSX_DEPLOYMENT_TARGET"),
+            Some(Version::new(14, 0))
+        );
+        assert_eq!(
+            gradle_min_sdk("    defaultConfig {\n        minSdk = 24\n    }"),
+            Some(Version::new(24, 0))
+        );
+    }
+
+    #[test]
+    fn skips_guarded_uses() {
+        let source = "
+            let table = Table::new(columns);
+            let tables = TableColumn::new(\"Name\", rows);
+            let guarded = if_available(Table::new(columns), text(\"Unsupported\"));
+            // Table is only shown on new systems
+        ";
+        assert_eq!(unguarded_uses(source, "Table"), [2]);
+    }
+}
@@ -1,11 +1,15 @@
 //! Build system
 
-use std::path::{Path, PathBuf};
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::Stdio,
+};
 
 use smol::{process::Command, unblock};
 use target_lexicon::{Environment, OperatingSystem, Triple};
 
-use crate::utils::{command, run_command};
+use crate::utils::{command, is_std_output, run_command};
 
 /// Represents a Rust build for a specific target triple.
 #[derive(Debug, Clone)]
@@ -70,6 +74,10 @@ pub enum RustBuildError {
     /// Cargo executed but failed to build the Rust library.
     #[error("Failed to build Rust library: {0}")]
     FailToBuildRustLibrary(std::io::Error),
+
+    /// The crate did not compile; carries the compiler's error messages.
+    #[error("Failed to compile Rust library:\n{0}")]
+    CompileError(String),
 }
 
 impl RustBuild {
@@ -148,12 +156,25 @@ impl RustBuild {
             cmd = cmd.arg("--release");
         }
 
-        let status = cmd
-            .status()
+        // Hot reload builds always capture compiler errors so they can be shown in the app
+        if self.hot_reload {
+            cmd = cmd.stderr(Stdio::piped());
+        }
+
+        let output = cmd
+            .output()
             .await
             .map_err(RustBuildError::FailToExecuteCargoBuild)?;
 
-        if !status.success() {
+        if self.hot_reload && is_std_output() {
+            let _ = std::io::stderr().write_all(&output.stderr);
+        }
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if self.hot_reload && !stderr.is_empty() {
+                return Err(RustBuildError::CompileError(compile_errors(&stderr)));
+            }
             return Err(RustBuildError::FailToBuildRustLibrary(
                 std::io::Error::other("Cargo build failed"),
             ));
@@ -223,3 +244,73 @@ impl RustBuild {
         ))
     }
 }
+
+/// Extract the error diagnostics from cargo's standard error.
+///
+/// Progress lines, warnings and cargo's own summary are dropped. Falls back to
+/// the end of the output when no diagnostic is recognized.
+fn compile_errors(stderr: &str) -> String {
+    let mut errors = Vec::new();
+    let mut in_error = false;
+    for line in stderr.lines() {
+        if line.starts_with("error") {
+            in_error = !line.starts_with("error: could not compile");
+            if in_error && !errors.is_empty() {
+                errors.push("");
+            }
+        } else if line.trim().is_empty() || line.starts_with("warning") {
+            in_error = false;
+        }
+        if in_error {
+            errors.push(line);
+        }
+    }
+
+    if errors.is_empty() {
+        let lines: Vec<&str> = stderr.lines().collect();
+        errors = lines[lines.len().saturating_sub(20)..].to_vec();
+    }
+    errors.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compile_errors;
+
+    #[test]
+    fn keeps_only_error_diagnostics() {
+        let stderr = "\
+   Compiling app v0.1.0 (/tmp/app)
+warning: unused variable: `x`
+ --> src/lib.rs:2:9
+  |
+2 |     let x = 1;
+  |         ^ help: prefix it with an underscore: `_x`
+
+error[E0308]: mismatched types
+ --> src/lib.rs:5:5
+  |
+5 |     \"a\"
+  |     ^^^ expected `i32`, found `&str`
+
+error: could not compile `app` (lib) due to 1 previous error; 1 warning emitted
+";
+        assert_eq!(
+            compile_errors(stderr),
+            "\
+error[E0308]: mismatched types
+ --> src/lib.rs:5:5
+  |
+5 |     \"a\"
+  |     ^^^ expected `i32`, found `&str`"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_the_end_of_the_output() {
+        assert_eq!(
+            compile_errors("   Compiling app\nlinker `cc` not found\n"),
+            "   Compiling app\nlinker `cc` not found"
+        );
+    }
+}
@@ -247,7 +247,7 @@ async fn parse_ips_report(path: &Path) -> Option<IpsReport> {
 }
 
 fn parse_ips_timestamp(timestamp: &str) -> Option<OffsetDateTime> {
-    use time::format_description::{parse, well_known::Rfc3339};
+    use time::format_description::{parse_borrowed, well_known::Rfc3339};
 
     if let Ok(dt) = OffsetDateTime::parse(timestamp, &Rfc3339) {
         return Some(dt);
@@ -261,7 +261,7 @@ fn parse_ips_timestamp(timestamp: &str) -> Option<OffsetDateTime> {
     ];
 
     for fmt in formats {
-        if let Ok(format) = parse(fmt) {
+        if let Ok(format) = parse_borrowed::<2>(fmt) {
             if let Ok(dt) = OffsetDateTime::parse(timestamp, &format) {
                 return Some(dt);
             }
@@ -1,6 +1,10 @@
 //! Hot reload server for `WaterUI` CLI.
 //!
 //! Provides a WebSocket server that broadcasts dylib updates to connected apps.
+//!
+//! Besides library binaries, apps receive text messages: `building` when a
+//! rebuild starts, and `build_failed` followed by a newline and the compiler
+//! errors when it fails, so the app can show them instead of staying stale.
 
 use std::net::SocketAddr;
 use std::path::PathBuf;
@@ -35,6 +39,20 @@ pub enum BroadcastMessage {
     Binary(Vec<u8>),
 }
 
+impl BroadcastMessage {
+    /// Message telling clients that a build is starting.
+    #[must_use]
+    pub fn building() -> Self {
+        Self::Text("building".to_string())
+    }
+
+    /// Message carrying the errors of a failed build.
+    #[must_use]
+    pub fn build_failed(error: &str) -> Self {
+        Self::Text(format!("build_failed\n{error}"))
+    }
+}
+
 /// Hot reload server that broadcasts dylib updates to connected apps.
 #[derive(Debug)]
 pub struct HotReloadServer {
@@ -190,9 +208,16 @@ impl HotReloadServer {
     ///
     /// This provides instant feedback to the user before compilation completes.
     pub fn send_building(&self) {
+        let _ = self.broadcast_tx.try_send(BroadcastMessage::building());
+    }
+
+    /// Show the errors of a failed build in all connected clients.
+    ///
+    /// Clients keep running the last successfully built library.
+    pub fn send_build_failed(&self, error: &str) {
         let _ = self
             .broadcast_tx
-            .try_send(BroadcastMessage::Text("building".to_string()));
+            .try_send(BroadcastMessage::build_failed(error));
     }
 
     /// Broadcast a library binary to all connected clients.
@@ -9,7 +9,7 @@ use target_lexicon::Triple;
 
 use super::file_watcher::FileWatcher;
 use super::hot_reload::{BroadcastMessage, BuildManager, DEFAULT_PORT, HotReloadServer};
-use crate::build::RustBuild;
+use crate::build::{RustBuild, RustBuildError};
 use crate::project::Project;
 
 /// Events emitted by the hot reload runner.
@@ -32,8 +32,11 @@ pub enum HotReloadEvent {
         path: PathBuf,
     },
     /// Build failed with an error message.
+    ///
+    /// The error is also shown in connected apps, which keep running the last
+    /// successful build.
     BuildFailed {
-        /// Error message.
+        /// Error message, the compiler errors if the crate did not compile.
         error: String,
     },
     /// Library broadcast to connected clients.
@@ -164,9 +167,9 @@ async fn run_loop(
                             let dylib_path = lib_dir.join(&lib_name);
 
                             if !dylib_path.exists() {
-                                let _ = event_tx.send(HotReloadEvent::BuildFailed {
-                                    error: format!("Library not found: {}", dylib_path.display()),
-                                }).await;
+                                let error = format!("Library not found: {}", dylib_path.display());
+                                let _ = broadcast_tx.send(BroadcastMessage::build_failed(&error)).await;
+                                let _ = event_tx.send(HotReloadEvent::BuildFailed { error }).await;
                                 reported_change = false;
                                 continue;
                             }
@@ -183,17 +186,21 @@ async fn run_loop(
                                     reported_change = false;
                                 }
                                 Err(e) => {
-                                    let _ = event_tx.send(HotReloadEvent::BuildFailed {
-                                        error: format!("Failed to read library: {e}"),
-                                    }).await;
+                                    let error = format!("Failed to read library: {e}");
+                                    let _ = broadcast_tx.send(BroadcastMessage::build_failed(&error)).await;
+                                    let _ = event_tx.send(HotReloadEvent::BuildFailed { error }).await;
                                     reported_change = false;
                                 }
                             }
                         }
                         Err(e) => {
-                            let _ = event_tx.send(HotReloadEvent::BuildFailed {
-                                error: e.to_string(),
-                            }).await;
+                            let error = match e {
+                                RustBuildError::CompileError(errors) => errors,
+                                e => e.to_string(),
+                            };
+                            // Show the errors in the running app, which keeps the last build
+                            let _ = broadcast_tx.send(BroadcastMessage::build_failed(&error)).await;
+                            let _ = event_tx.send(HotReloadEvent::BuildFailed { error }).await;
                             reported_change = false;
                         }
                     }
@@ -202,7 +209,7 @@ async fn run_loop(
                 // Check if debounce completed and we should start building
                 if build_manager.should_start_build() {
                     // Notify clients that building is starting (instant feedback)
-                    let _ = broadcast_tx.send(BroadcastMessage::Text("building".to_string())).await;
+                    let _ = broadcast_tx.send(BroadcastMessage::building()).await;
                     let _ = event_tx.send(HotReloadEvent::Rebuilding).await;
                     build_manager.start_build(rust_build.clone());
                 }
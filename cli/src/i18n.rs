@@ -0,0 +1,351 @@
+//! Translation tables for strings used with `waterui::t!`.
+//!
+//! Tables live in the `locales/` directory at the project root, one flat TOML
+//! file per language (`locales/de.toml`), mapping each key to its
+//! translation. [`extract`] scans the crate's `src/` directory for `t!`
+//! calls and adds every key that a table is missing as an empty entry, which
+//! `waterui` shows untranslated until it is filled in.
+//!
+//! The file format must stay readable by `Translations::with_table` in
+//! `waterui-text`: one `"key" = "value"` entry per line.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write as _,
+    io,
+    path::{Path, PathBuf},
+};
+
+use smol::{fs, unblock};
+
+/// Directory, relative to the project root, that holds translation tables.
+pub const LOCALES_DIR: &str = "locales";
+
+/// Errors that can occur while extracting translation keys.
+#[derive(Debug, thiserror::Error)]
+pub enum FailToExtract {
+    /// Failed to read the sources or to read or write a table.
+    #[error("Failed to access translation files: {0}")]
+    Io(#[from] io::Error),
+    /// An existing table is not a flat table of strings.
+    #[error("Invalid translation table {}: {error}", .path.display())]
+    InvalidTable {
+        /// Path of the table.
+        path: PathBuf,
+        /// Parse error.
+        error: toml::de::Error,
+    },
+}
+
+/// The result of bringing one table in sync with the sources.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableUpdate {
+    /// Language of the table, from its file name.
+    pub language: String,
+    /// Keys added as empty entries.
+    pub added: Vec<String>,
+    /// Unused keys removed from the table.
+    pub removed: Vec<String>,
+    /// Keys in the table that no `t!` call uses anymore but were kept.
+    pub unused: Vec<String>,
+    /// Number of entries that still have no translation.
+    pub untranslated: usize,
+}
+
+impl TableUpdate {
+    /// Whether the table was already in sync with the sources.
+    #[must_use]
+    pub const fn is_unchanged(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Returns the keys of all `t!` calls in `source`, in order of appearance.
+///
+/// Calls whose first argument is not a string literal are skipped.
+#[must_use]
+pub fn extract_keys(source: &str) -> Vec<String> {
+    let mut keys = Vec::new();
+    let mut rest = source;
+    while let Some(index) = rest.find("t!") {
+        let preceded_by_ident = rest[..index]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_');
+        rest = &rest[index + 2..];
+        if preceded_by_ident {
+            continue;
+        }
+        let Some(args) = rest.trim_start().strip_prefix(['(', '[', '{']) else {
+            continue;
+        };
+        if let Some(key) = parse_literal(args.trim_start()) {
+            keys.push(key);
+        }
+    }
+    keys
+}
+
+/// Parses a Rust string literal, plain or raw, at the start of `input`.
+fn parse_literal(input: &str) -> Option<String> {
+    if let Some(raw) = input.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let body = raw[hashes..].strip_prefix('"')?;
+        let end = body.find(&format!("\"{}", "#".repeat(hashes)))?;
+        return Some(body[..end].to_string());
+    }
+
+    let mut chars = input.strip_prefix('"')?.chars().peekable();
+    let mut out = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Some(out),
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                't' => out.push('\t'),
+                'r' => out.push('\r'),
+                '0' => out.push('\0'),
+                '\\' => out.push('\\'),
+                '"' => out.push('"'),
+                '\'' => out.push('\''),
+                'u' => {
+                    let code: String = chars
+                        .by_ref()
+                        .skip_while(|&c| c == '{')
+                        .take_while(|&c| c != '}')
+                        .collect();
+                    out.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+                }
+                // A line continuation skips the newline and leading whitespace
+                '\n' => while chars.next_if(|c| c.is_whitespace()).is_some() {},
+                _ => return None,
+            },
+            c => out.push(c),
+        }
+    }
+    None
+}
+
+/// Writes `value` as a basic TOML string.
+fn quote(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c if c.is_control() => {
+                let _ = write!(out, "\\u{:04X}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Merges `keys` into the table `entries` for `language`.
+///
+/// Existing translations are kept. Unused entries are removed only if
+/// `prune` is set. Returns the new file contents and what changed.
+#[must_use]
+pub fn update_table(
+    language: &str,
+    mut entries: BTreeMap<String, String>,
+    keys: &BTreeSet<String>,
+    prune: bool,
+) -> (String, TableUpdate) {
+    let added: Vec<String> = keys
+        .iter()
+        .filter(|key| !entries.contains_key(*key))
+        .cloned()
+        .collect();
+    let unused: Vec<String> = entries
+        .keys()
+        .filter(|key| !keys.contains(*key))
+        .cloned()
+        .collect();
+    for key in &added {
+        entries.insert(key.clone(), String::new());
+    }
+    let (removed, unused) = if prune {
+        for key in &unused {
+            entries.remove(key);
+        }
+        (unused, Vec::new())
+    } else {
+        (Vec::new(), unused)
+    };
+
+    let mut content = format!(
+        "# Translations for `{language}`, kept in sync by `water i18n extract`.\n\
+         # Entries left empty are shown untranslated.\n\n"
+    );
+    for (key, value) in &entries {
+        let _ = writeln!(content, "{} = {}", quote(key), quote(value));
+    }
+
+    let untranslated = entries.values().filter(|value| value.is_empty()).count();
+    (
+        content,
+        TableUpdate {
+            language: language.to_string(),
+            added,
+            removed,
+            unused,
+            untranslated,
+        },
+    )
+}
+
+/// List the Rust files under `dir`.
+pub(crate) fn list_sources(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_type()?.is_dir() {
+                pending.push(path);
+            } else if path.extension().is_some_and(|ext| ext == "rs") {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// List the languages of the tables in `dir`.
+fn list_tables(dir: &Path) -> io::Result<Vec<String>> {
+    let mut languages = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_table = path.extension().is_some_and(|ext| ext == "toml");
+        if let Some(stem) = path.file_stem().filter(|_| is_table) {
+            languages.push(stem.to_string_lossy().into_owned());
+        }
+    }
+    Ok(languages)
+}
+
+/// Collect the keys of all `t!` calls in the project's `src/` directory.
+///
+/// # Errors
+/// - If the sources cannot be read.
+pub async fn collect_keys(root: &Path) -> io::Result<BTreeSet<String>> {
+    let src = root.join("src");
+    let files = unblock(move || list_sources(&src)).await?;
+    let mut keys = BTreeSet::new();
+    for file in files {
+        keys.extend(extract_keys(&fs::read_to_string(&file).await?));
+    }
+    Ok(keys)
+}
+
+/// Bring the translation tables of the project at `root` in sync with its sources.
+///
+/// Every existing table in `locales/` is updated, and a table is created for
+/// each of `languages` that has none. With `write` unset, nothing is written
+/// and the returned updates describe what would change.
+///
+/// # Errors
+/// - If the sources or tables cannot be read or written.
+/// - If an existing table is not a flat table of strings.
+pub async fn extract(
+    root: &Path,
+    languages: &[String],
+    prune: bool,
+    write: bool,
+) -> Result<Vec<TableUpdate>, FailToExtract> {
+    let keys = collect_keys(root).await?;
+    let dir = root.join(LOCALES_DIR);
+
+    let mut tables: BTreeSet<String> = languages.iter().cloned().collect();
+    if dir.is_dir() {
+        let dir = dir.clone();
+        tables.extend(unblock(move || list_tables(&dir)).await?);
+    }
+
+    let mut updates = Vec::with_capacity(tables.len());
+    for language in tables {
+        let path = dir.join(format!("{language}.toml"));
+        let entries = match fs::read_to_string(&path).await {
+            Ok(content) => {
+                toml::from_str(&content).map_err(|error| FailToExtract::InvalidTable {
+                    path: path.clone(),
+                    error,
+                })?
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let (content, update) = update_table(&language, entries, &keys, prune);
+        if write && !update.is_unchanged() {
+            fs::create_dir_all(&dir).await?;
+            fs::write(&path, content).await?;
+        }
+        updates.push(update);
+    }
+    Ok(updates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    // The source contains `"#`, which the lint does not notice
+    #[allow(clippy::needless_raw_string_hashes)]
+    fn extracts_literal_keys() {
+        let source = r##"
+            vstack((
+                t!("Settings"),
+                t! ( "Hello, {name}!", name ),
+                waterui::t!(r#"Say "hi""#),
+                t!("Line\none \u{e9}"),
+                text!("not a key"),
+                format!("not a key either"),
+                t!(key),
+            ))
+        "##;
+        assert_eq!(
+            extract_keys(source),
+            [
+                "Settings",
+                "Hello, {name}!",
+                "Say \"hi\"",
+                "Line\none \u{e9}"
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_translations_and_adds_stubs() {
+        let keys: BTreeSet<String> = ["Hello", "Quit \"now\""].map(String::from).into();
+        let entries = BTreeMap::from([
+            ("Hello".to_string(), "Hallo".to_string()),
+            ("Old".to_string(), "Alt".to_string()),
+        ]);
+
+        let (content, update) = update_table("de", entries.clone(), &keys, false);
+        assert_eq!(update.added, ["Quit \"now\""]);
+        assert_eq!(update.unused, ["Old"]);
+        assert_eq!(update.untranslated, 1);
+        assert!(content.contains("\"Hello\" = \"Hallo\"\n"));
+        assert!(content.contains("\"Old\" = \"Alt\"\n"));
+        assert!(content.contains("\"Quit \\\"now\\\"\" = \"\"\n"));
+
+        let parsed: BTreeMap<String, String> = toml::from_str(&content).unwrap();
+        assert_eq!(parsed.len(), 3);
+
+        let (content, update) = update_table("de", entries, &keys, true);
+        assert!(!content.contains("Old"));
+        assert_eq!(update.removed, ["Old"]);
+        assert!(update.unused.is_empty());
+    }
+}
@@ -1,14 +1,18 @@
 //! `WaterUI` CLI library for managing cross-platform builds and development workflows.
 pub mod android;
 pub mod apple;
+pub mod assets;
+pub mod availability;
 pub mod backend;
 pub mod brew;
 pub mod build;
 pub mod debug;
 pub mod device;
+pub mod i18n;
 pub mod platform;
 pub mod project;
 pub mod templates;
 pub mod toolchain;
 pub mod utils;
 pub mod water_dir;
+pub mod web;
@@ -274,6 +274,8 @@ pub struct CreateOptions {
     pub waterui_path: Option<PathBuf>,
     /// Author name for Cargo.toml.
     pub author: String,
+    /// Starter code to scaffold.
+    pub template: ProjectTemplate,
 }
 
 impl Project {
@@ -335,7 +337,7 @@ impl Project {
         };
 
         // Scaffold root files (Cargo.toml, src/lib.rs, .gitignore)
-        templates::root::scaffold(&path, &ctx)
+        templates::root::scaffold(&path, &ctx, options.template)
             .await
             .map_err(FailToCreateProject::Scaffold)?;
 
@@ -357,7 +359,7 @@ impl Project {
                 .waterui_path
                 .as_ref()
                 .map(|p| p.display().to_string()),
-            permissions: HashMap::default(),
+            permissions: Self::template_permissions(options.template),
         };
 
         // Save Water.toml
@@ -378,6 +380,21 @@ impl Project {
         })
     }
 
+    /// Permissions the starter code of a template needs.
+    fn template_permissions(template: ProjectTemplate) -> HashMap<String, PermissionEntry> {
+        match template {
+            ProjectTemplate::Basic => HashMap::new(),
+            ProjectTemplate::Gallery => HashMap::from([(
+                "internet".to_string(),
+                PermissionEntry {
+                    enable: true,
+                    description: "Required to load the photos and videos shown in the media demo"
+                        .to_string(),
+                },
+            )]),
+        }
+    }
+
     /// Ensure the project is initialized with git.
     ///
     /// Checks if the project directory is already part of a git repository.
@@ -392,8 +409,7 @@ impl Project {
             .current_dir(path)
             .output()
             .await
-            .map(|output| output.status.success())
-            .unwrap_or(false);
+            .is_ok_and(|output| output.status.success());
 
         if !is_in_git {
             // Initialize a new git repository
@@ -508,9 +524,7 @@ impl Project {
         // 1. Running inside Xcode's sandboxed build script phase (WATERUI_SKIP_RUST_BUILD=1)
         // 2. Running inside any sandbox (sandbox-exec sets __XCODE_BUILT_PRODUCTS_DIR_PATHS or similar)
         // 3. Xcode is the current build tool (ACTION env var is set by Xcode)
-        let skip_backend_init = std::env::var("WATERUI_SKIP_RUST_BUILD")
-            .map(|v| v == "1")
-            .unwrap_or(false)
+        let skip_backend_init = std::env::var("WATERUI_SKIP_RUST_BUILD").is_ok_and(|v| v == "1")
             || std::env::var("ACTION").is_ok() // Xcode sets this during builds
             || std::env::var("XCODE_PRODUCT_BUILD_VERSION").is_ok();
 
@@ -562,7 +576,7 @@ use crate::{
     build::BuildOptions,
     device::{Artifact, Device, FailToRun, RunOptions, Running},
     platform::{PackageOptions, Platform},
-    templates::{self, TemplateContext},
+    templates::{self, ProjectTemplate, TemplateContext},
     utils::command,
 };
 
@@ -20,6 +20,27 @@ fn normalize_path_for_config(path: &Path) -> String {
     path.to_string_lossy().replace('\\', "/")
 }
 
+/// Starter code for a newly created project.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProjectTemplate {
+    /// A minimal single-view app.
+    #[default]
+    Basic,
+    /// A searchable gallery with a demo page for every major component.
+    Gallery,
+}
+
+impl ProjectTemplate {
+    /// Cargo features the template enables on the `waterui` dependency.
+    #[must_use]
+    pub const fn waterui_features(self) -> &'static [&'static str] {
+        match self {
+            Self::Basic => &[],
+            Self::Gallery => &["graphics"],
+        }
+    }
+}
+
 /// Embedded template directories.
 mod embedded {
     use super::{Dir, include_dir};
@@ -437,24 +458,47 @@ pub mod android {
 
 /// Root-level templates (Cargo.toml, lib.rs, .gitignore).
 pub mod root {
+    use std::collections::BTreeMap;
+
     use crate::templates::{WATERUI_FFI_VERSION, WATERUI_VERSION};
 
-    use super::{Path, TemplateContext, embedded, fs, io, normalize_path_for_config};
+    use super::{
+        Path, ProjectTemplate, TemplateContext, embedded, fs, io, normalize_path_for_config,
+        scaffold_dir,
+    };
 
     /// Root template files (only .tpl files at the root level, excluding Cargo.toml).
     static ROOT_TEMPLATES: &[&str] = &["lib.rs.tpl", ".gitignore.tpl"];
 
     /// Write root templates to the given directory.
     ///
+    /// For [`ProjectTemplate::Gallery`], the gallery sources replace `src/lib.rs`.
+    ///
     /// # Errors
     ///
     /// Returns an error if file operations fail.
-    pub async fn scaffold(base_dir: &Path, ctx: &TemplateContext) -> io::Result<()> {
+    pub async fn scaffold(
+        base_dir: &Path,
+        ctx: &TemplateContext,
+        template: ProjectTemplate,
+    ) -> io::Result<()> {
         // Generate Cargo.toml programmatically using toml_edit
-        generate_cargo_toml(base_dir, ctx).await?;
+        generate_cargo_toml(base_dir, ctx, template).await?;
+
+        if template == ProjectTemplate::Gallery {
+            let gallery = embedded::ROOT.get_dir("gallery").ok_or_else(|| {
+                io::Error::new(io::ErrorKind::NotFound, "Missing gallery template")
+            })?;
+            // Embedded paths keep their `gallery/` prefix, so move the tree into place
+            scaffold_dir(gallery, base_dir, ctx).await?;
+            fs::rename(base_dir.join("gallery"), base_dir.join("src")).await?;
+        }
 
         // Process remaining templates
         for template_name in ROOT_TEMPLATES {
+            if *template_name == "lib.rs.tpl" && template == ProjectTemplate::Gallery {
+                continue;
+            }
             if let Some(file) = embedded::ROOT.get_file(template_name) {
                 let dest_name = template_name.strip_suffix(".tpl").unwrap_or(template_name);
                 let dest_path = if dest_name == "lib.rs" {
@@ -478,10 +522,85 @@ pub mod root {
         Ok(())
     }
 
+    #[derive(serde::Serialize)]
+    #[serde(untagged)]
+    enum DependencyValue {
+        Simple(String),
+        Detailed(DependencyDetail),
+    }
+
+    #[derive(serde::Serialize)]
+    struct DependencyDetail {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        version: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        path: Option<String>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        features: Vec<String>,
+    }
+
+    /// Returns the `waterui` and `waterui-ffi` dependencies, with the features
+    /// `template` needs enabled on `waterui`.
+    fn waterui_dependencies(
+        ctx: &TemplateContext,
+        template: ProjectTemplate,
+    ) -> BTreeMap<String, DependencyValue> {
+        let features: Vec<String> = template
+            .waterui_features()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        let mut dependencies = BTreeMap::new();
+
+        if let Some(waterui_path) = &ctx.waterui_path {
+            // Local path dependencies
+            dependencies.insert(
+                "waterui".to_string(),
+                DependencyValue::Detailed(DependencyDetail {
+                    version: None,
+                    path: Some(normalize_path_for_config(waterui_path)),
+                    features,
+                }),
+            );
+
+            let ffi_path = waterui_path.join("ffi");
+            dependencies.insert(
+                "waterui-ffi".to_string(),
+                DependencyValue::Detailed(DependencyDetail {
+                    version: None,
+                    path: Some(normalize_path_for_config(&ffi_path)),
+                    features: Vec::new(),
+                }),
+            );
+        } else {
+            // Registry dependencies
+            let waterui = if features.is_empty() {
+                DependencyValue::Simple(WATERUI_VERSION.to_string())
+            } else {
+                DependencyValue::Detailed(DependencyDetail {
+                    version: Some(WATERUI_VERSION.to_string()),
+                    path: None,
+                    features,
+                })
+            };
+            dependencies.insert("waterui".to_string(), waterui);
+            dependencies.insert(
+                "waterui-ffi".to_string(),
+                DependencyValue::Simple(WATERUI_FFI_VERSION.to_string()),
+            );
+        }
+
+        dependencies
+    }
+
     /// Generate Cargo.toml programmatically using serde-compatible structs for type safety.
-    async fn generate_cargo_toml(base_dir: &Path, ctx: &TemplateContext) -> io::Result<()> {
+    async fn generate_cargo_toml(
+        base_dir: &Path,
+        ctx: &TemplateContext,
+        template: ProjectTemplate,
+    ) -> io::Result<()> {
         use serde::Serialize;
-        use std::collections::BTreeMap;
 
         #[derive(Serialize)]
         struct CargoManifest {
@@ -508,47 +627,7 @@ pub mod root {
         #[derive(Serialize)]
         struct WorkspaceSection {}
 
-        #[derive(Serialize)]
-        #[serde(untagged)]
-        enum DependencyValue {
-            Simple(String),
-            Detailed(DependencyDetail),
-        }
-
-        #[derive(Serialize)]
-        struct DependencyDetail {
-            path: String,
-        }
-
-        let mut dependencies = BTreeMap::new();
-
-        if let Some(waterui_path) = &ctx.waterui_path {
-            // Local path dependencies
-            dependencies.insert(
-                "waterui".to_string(),
-                DependencyValue::Detailed(DependencyDetail {
-                    path: normalize_path_for_config(waterui_path),
-                }),
-            );
-
-            let ffi_path = waterui_path.join("ffi");
-            dependencies.insert(
-                "waterui-ffi".to_string(),
-                DependencyValue::Detailed(DependencyDetail {
-                    path: normalize_path_for_config(&ffi_path),
-                }),
-            );
-        } else {
-            // Registry dependencies
-            dependencies.insert(
-                "waterui".to_string(),
-                DependencyValue::Simple(WATERUI_VERSION.to_string()),
-            );
-            dependencies.insert(
-                "waterui-ffi".to_string(),
-                DependencyValue::Simple(WATERUI_FFI_VERSION.to_string()),
-            );
-        }
+        let dependencies = waterui_dependencies(ctx, template);
 
         let manifest = CargoManifest {
             package: PackageSection {
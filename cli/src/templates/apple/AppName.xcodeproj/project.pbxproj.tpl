@@ -148,6 +148,7 @@ __SWIFT_PACKAGE_REFERENCE_ENTRY__
 			inputPaths = (
 				"$(SRCROOT)/../Cargo.toml",
 				"$(SRCROOT)/../src/lib.rs",
+				"$(SRCROOT)/../assets",
 				"$(PROJECT_DIR)/build-rust.sh",
 			);
 			name = "Build Rust Library";
@@ -156,6 +157,7 @@ __SWIFT_PACKAGE_REFERENCE_ENTRY__
 			outputPaths = (
 				"$(BUILT_PRODUCTS_DIR)/libwaterui_app.a",
 				"$(PROJECT_DIR)/rust_build_info.xcconfig",
+				"$(TARGET_BUILD_DIR)/$(UNLOCALIZED_RESOURCES_FOLDER_PATH)/assets",
 			);
 			runOnlyForDeploymentPostprocessing = 0;
 			shellPath = /bin/bash;
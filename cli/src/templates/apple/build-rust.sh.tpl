@@ -1,15 +1,22 @@
 #!/usr/bin/env bash
 set -euo pipefail
 
+SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" && pwd )"
+PROJECT_ROOT="$( cd "$SCRIPT_DIR/__PROJECT_ROOT_RELATIVE_PATH__" && pwd )"
+
+# Bundle files referenced with `asset!` into the app resources (before code signing)
+if [ -d "$PROJECT_ROOT/assets" ] && [ -n "${TARGET_BUILD_DIR:-}" ] && [ -n "${UNLOCALIZED_RESOURCES_FOLDER_PATH:-}" ]; then
+    ASSETS_DEST="$TARGET_BUILD_DIR/$UNLOCALIZED_RESOURCES_FOLDER_PATH/assets"
+    mkdir -p "$ASSETS_DEST"
+    rsync -a --delete "$PROJECT_ROOT/assets/" "$ASSETS_DEST/"
+fi
+
 # Skip Rust build when invoked by `water run` (it already builds the library)
 if [ "${WATERUI_SKIP_RUST_BUILD:-}" = "1" ]; then
     echo "Skipping Rust build (managed by water run)"
     exit 0
 fi
 
-SCRIPT_DIR="$( cd "$( dirname "${BASH_SOURCE[0]}" )" && pwd )"
-PROJECT_ROOT="$( cd "$SCRIPT_DIR/__PROJECT_ROOT_RELATIVE_PATH__" && pwd )"
-
 # Xcode runs build scripts in a restricted shell environment without the user's
 # full PATH. Add common tool locations explicitly.
 export PATH="$HOME/.cargo/bin:/opt/homebrew/bin:/usr/local/bin:$PATH"
@@ -0,0 +1,49 @@
+//! 2D shapes drawn on a GPU surface.
+//!
+//! The vector `Canvas` is not available on this branch yet, so the shapes are
+//! drawn with signed distance functions in a fragment shader instead.
+
+use waterui::graphics::ShaderSurface;
+use waterui::prelude::*;
+
+const SHAPES: &str = r"
+fn circle(p: vec2<f32>, center: vec2<f32>, radius: f32) -> f32 {
+    return length(p - center) - radius;
+}
+
+fn rect(p: vec2<f32>, center: vec2<f32>, half_size: vec2<f32>) -> f32 {
+    let d = abs(p - center) - half_size;
+    return length(max(d, vec2<f32>(0.0))) + min(max(d.x, d.y), 0.0);
+}
+
+fn fill(distance: f32) -> f32 {
+    return 1.0 - smoothstep(0.0, 0.004, distance);
+}
+
+@fragment
+fn main(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
+    let aspect = uniforms.resolution.x / max(uniforms.resolution.y, 1.0);
+    let p = vec2<f32>(uv.x * aspect, uv.y);
+
+    var color = vec3<f32>(0.96, 0.96, 0.98);
+    let pink = vec3<f32>(0.95, 0.55, 0.66);
+    let blue = vec3<f32>(0.13, 0.59, 0.95);
+    let orange = vec3<f32>(1.0, 0.6, 0.0);
+
+    color = mix(color, pink, fill(rect(p, vec2<f32>(0.3 * aspect, 0.5), vec2<f32>(0.12, 0.2))));
+    color = mix(color, blue, fill(circle(p, vec2<f32>(0.55 * aspect, 0.45), 0.18)));
+    // Ring: the band where the distance to the circle's edge is small
+    color = mix(color, orange, fill(abs(circle(p, vec2<f32>(0.78 * aspect, 0.6), 0.14)) - 0.02));
+    return vec4<f32>(color, 1.0);
+}
+";
+
+pub fn demo() -> AnyView {
+    AnyView::new(
+        vstack((
+            text("Rectangles, circles and rings").size(20.0),
+            ShaderSurface::new(SHAPES).height(300.0),
+        ))
+        .padding_with(EdgeInsets::all(16.0)),
+    )
+}
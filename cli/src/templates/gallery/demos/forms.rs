@@ -0,0 +1,46 @@
+//! Forms generated with `#[form]` next to hand-built controls.
+
+use waterui::prelude::*;
+use waterui::reactive::binding;
+
+#[form]
+struct Profile {
+    /// Display name
+    name: String,
+    /// Email address
+    email: String,
+    /// Age in years
+    age: i32,
+    /// Receive the newsletter
+    newsletter: bool,
+}
+
+pub fn demo() -> AnyView {
+    let profile = Profile::binding();
+    let username = binding(Str::new());
+    let enabled = binding(false);
+    let count = binding(5);
+    let volume = binding(0.5);
+
+    AnyView::new(scroll(
+        vstack((
+            text("Generated Form").size(20.0),
+            form(&profile),
+            hstack(("Name: ", waterui::text!("{}", profile.project().name))),
+            Divider,
+            text("Controls").size(20.0),
+            TextField::new(&username)
+                .label(text("Username"))
+                .prompt("Enter your username"),
+            Toggle::new(&enabled).label(text("Enable Feature")),
+            Stepper::new(&count)
+                .label(text("Item Count"))
+                .range(0..=100)
+                .step(5),
+            Slider::new(0.0..=1.0, &volume).label(text("Volume")),
+            progress(volume.clone()),
+            hstack(("Count: ", waterui::text!("{}", count))),
+        ))
+        .padding_with(EdgeInsets::all(16.0)),
+    ))
+}
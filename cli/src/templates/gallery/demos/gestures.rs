@@ -0,0 +1,53 @@
+//! Tap, long press and drag recognizers updating counters.
+
+use waterui::gesture::{DragGesture, LongPressGesture, TapGesture};
+use waterui::prelude::*;
+use waterui::reactive::Binding;
+use waterui::time::Duration;
+
+fn target(label: &'static str, hex: &'static str) -> impl View {
+    text(label)
+        .padding()
+        .background(Color::srgb_hex(hex).with_opacity(0.3))
+}
+
+pub fn demo() -> AnyView {
+    let taps = Binding::int(0);
+    let long_presses = Binding::int(0);
+    let drags = Binding::int(0);
+
+    AnyView::new(scroll(
+        vstack((
+            {
+                let taps = taps.clone();
+                target("Double Tap Me!", "#2196F3").gesture(TapGesture::repeat(2), move || {
+                    taps.set(taps.get() + 1);
+                })
+            },
+            hstack(("Double taps: ", waterui::text!("{}", taps))),
+            Divider,
+            {
+                let long_presses = long_presses.clone();
+                target("Long Press Me!", "#FF9800").gesture(
+                    LongPressGesture::new(Duration::from_millis(500)),
+                    move || {
+                        long_presses.set(long_presses.get() + 1);
+                    },
+                )
+            },
+            hstack(("Long presses: ", waterui::text!("{}", long_presses))),
+            Divider,
+            {
+                let drags = drags.clone();
+                target("Drag Here", "#9C27B0")
+                    .width(200.0)
+                    .height(100.0)
+                    .gesture(DragGesture::new(5.0), move || {
+                        drags.set(drags.get() + 1);
+                    })
+            },
+            hstack(("Drag events: ", waterui::text!("{}", drags))),
+        ))
+        .padding_with(EdgeInsets::all(16.0)),
+    ))
+}
@@ -0,0 +1,85 @@
+//! A selectable list of contacts with pull to refresh.
+//!
+//! Backends highlight the selected row; pulling down waits a second and
+//! bumps the refresh counter.
+
+use core::time::Duration;
+
+use waterui::component::list::{List, ListItem};
+use waterui::prelude::*;
+use waterui::reactive::binding;
+use waterui::task::sleep;
+use waterui::{AnyView, Identifiable};
+
+#[derive(Clone)]
+struct Contact {
+    id: u64,
+    name: &'static str,
+    role: &'static str,
+}
+
+impl Identifiable for Contact {
+    type Id = u64;
+    fn id(&self) -> Self::Id {
+        self.id
+    }
+}
+
+const CONTACTS: [Contact; 5] = [
+    Contact {
+        id: 1,
+        name: "Alice Chen",
+        role: "Software Engineer",
+    },
+    Contact {
+        id: 2,
+        name: "Bob Smith",
+        role: "Product Manager",
+    },
+    Contact {
+        id: 3,
+        name: "Carol Williams",
+        role: "Designer",
+    },
+    Contact {
+        id: 4,
+        name: "David Kim",
+        role: "DevOps Engineer",
+    },
+    Contact {
+        id: 5,
+        name: "Eva Martinez",
+        role: "Data Scientist",
+    },
+];
+
+pub fn demo() -> AnyView {
+    let selected: Binding<Option<u64>> = Binding::default();
+    let refreshes: Binding<i32> = binding(0);
+
+    let list = List::for_each(CONTACTS.to_vec(), |contact| ListItem {
+        content: AnyView::new(
+            vstack((
+                text(contact.name).size(17.0).bold(),
+                text(contact.role)
+                    .size(14.0)
+                    .foreground(Color::srgb(128, 128, 128)),
+            ))
+            .padding_with(EdgeInsets::symmetric(12.0, 16.0)),
+        ),
+        on_delete: None,
+    })
+    .selectable(&selected)
+    .refreshable({
+        let refreshes = refreshes.clone();
+        async move || {
+            sleep(Duration::from_secs(1)).await;
+            refreshes.set(refreshes.get() + 1);
+        }
+    });
+
+    AnyView::new(vstack((
+        hstack(("Refreshed: ", waterui::text!("{}", refreshes))).padding(),
+        list,
+    )))
+}
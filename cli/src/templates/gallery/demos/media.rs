@@ -0,0 +1,21 @@
+//! A remote photo and a video player.
+
+use waterui::prelude::*;
+use waterui::reactive::Computed;
+
+const PHOTO_URL: &str = "https://picsum.photos/id/1015/800/600";
+const VIDEO_URL: &str =
+    "https://commondatastorage.googleapis.com/gtv-videos-bucket/sample/BigBuckBunny.mp4";
+
+pub fn demo() -> AnyView {
+    AnyView::new(scroll(
+        vstack((
+            text("Photo").size(20.0),
+            Photo::new(PHOTO_URL).height(240.0),
+            Divider,
+            text("Video").size(20.0),
+            VideoPlayer::new(Computed::constant(Url::from(VIDEO_URL))).height(240.0),
+        ))
+        .padding_with(EdgeInsets::all(16.0)),
+    ))
+}
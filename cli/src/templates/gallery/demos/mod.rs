@@ -0,0 +1,50 @@
+//! The demos listed in the gallery, one module per component family.
+
+mod canvas;
+mod forms;
+mod gestures;
+mod lists;
+mod media;
+mod shaders;
+
+use crate::Demo;
+
+/// Every demo, in the order the index lists them.
+pub const ALL: &[Demo] = &[
+    Demo {
+        name: "Forms",
+        summary: "Generated forms, text fields, toggles, sliders and steppers",
+        keywords: &["form", "TextField", "Toggle", "Slider", "Stepper", "input"],
+        view: forms::demo,
+    },
+    Demo {
+        name: "Gestures",
+        summary: "Tap, long press and drag recognizers",
+        keywords: &["gesture", "TapGesture", "LongPressGesture", "DragGesture"],
+        view: gestures::demo,
+    },
+    Demo {
+        name: "Lists",
+        summary: "Selectable rows with pull to refresh",
+        keywords: &["List", "ListItem", "selection", "refreshable"],
+        view: lists::demo,
+    },
+    Demo {
+        name: "Media",
+        summary: "Remote photos and video playback",
+        keywords: &["Photo", "VideoPlayer", "image", "video"],
+        view: media::demo,
+    },
+    Demo {
+        name: "Canvas",
+        summary: "2D shapes drawn on a GPU surface",
+        keywords: &["drawing", "shapes", "graphics"],
+        view: canvas::demo,
+    },
+    Demo {
+        name: "Shaders",
+        summary: "Animated WGSL fragment shaders",
+        keywords: &["ShaderSurface", "wgsl", "gpu", "graphics"],
+        view: shaders::demo,
+    },
+];
@@ -0,0 +1,23 @@
+// Plasma (single-pass, ShaderSurface-compatible)
+// Uses: uniforms.time, uniforms.resolution
+
+@fragment
+fn main(@location(0) uv: vec2<f32>) -> @location(0) vec4<f32> {
+    let t = uniforms.time;
+    let aspect = uniforms.resolution.x / max(uniforms.resolution.y, 1.0);
+    let p = (uv - vec2<f32>(0.5)) * vec2<f32>(aspect, 1.0) * 6.0;
+
+    var v = sin(p.x + t);
+    v += sin((p.y + t) * 0.5);
+    v += sin((p.x + p.y + t) * 0.5);
+    let c = p + vec2<f32>(sin(t * 0.33), cos(t * 0.5)) * 3.0;
+    v += sin(sqrt(c.x * c.x + c.y * c.y + 1.0) + t);
+    v *= 0.5;
+
+    let color = vec3<f32>(
+        sin(v * 3.14159),
+        sin(v * 3.14159 + 2.094),
+        sin(v * 3.14159 + 4.188),
+    ) * 0.5 + vec3<f32>(0.5);
+    return vec4<f32>(color, 1.0);
+}
@@ -0,0 +1,15 @@
+//! An animated plasma effect loaded with the `shader!` macro.
+
+use waterui::graphics::shader;
+use waterui::prelude::*;
+
+pub fn demo() -> AnyView {
+    AnyView::new(
+        vstack((
+            text("Plasma").size(20.0),
+            "Animated with the built-in time uniform",
+            shader!("plasma.wgsl").height(400.0),
+        ))
+        .padding_with(EdgeInsets::all(16.0)),
+    )
+}
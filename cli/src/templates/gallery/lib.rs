@@ -0,0 +1,116 @@
+//! Gallery Example - Every WaterUI component demo in one app
+//!
+//! This example showcases:
+//! - A searchable index of demos behind a NavigationStack
+//! - NavigationLink pushing each demo onto the stack
+//! - Forms, gestures, lists, media, canvas drawing and shaders
+//!
+//! Since it touches the whole component surface, building and running the
+//! gallery on each backend doubles as an integration test. `water create
+//! --template gallery` scaffolds a copy of it.
+
+mod demos;
+
+use waterui::app::App;
+use waterui::prelude::*;
+use waterui::reactive::binding;
+
+/// A demo listed in the gallery index.
+pub struct Demo {
+    /// Title shown in the index and the navigation bar.
+    pub name: &'static str,
+    /// One-line description shown under the title.
+    pub summary: &'static str,
+    /// Extra words the search matches, such as component names.
+    pub keywords: &'static [&'static str],
+    /// Builds the demo's content.
+    pub view: fn() -> AnyView,
+}
+
+impl Demo {
+    /// Returns `true` if the demo matches `query`, ignoring case.
+    ///
+    /// An empty query matches every demo.
+    #[must_use]
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.trim().to_lowercase();
+        [self.name, self.summary]
+            .iter()
+            .chain(self.keywords)
+            .any(|text| text.to_lowercase().contains(&query))
+    }
+}
+
+/// Returns the demos matching `query`, in index order.
+#[must_use]
+pub fn search(query: &str) -> Vec<&'static Demo> {
+    demos::ALL
+        .iter()
+        .filter(|demo| demo.matches(query))
+        .collect()
+}
+
+fn demo_link(demo: &'static Demo) -> impl View {
+    NavigationLink::new(
+        vstack((
+            text(demo.name).size(17.0).bold(),
+            text(demo.summary)
+                .size(14.0)
+                .foreground(Color::srgb(128, 128, 128)),
+        ))
+        .padding_with(EdgeInsets::symmetric(8.0, 16.0)),
+        move || NavigationView::new(demo.name, (demo.view)()),
+    )
+}
+
+fn index(query: &Binding<Str>) -> impl View {
+    vstack((
+        TextField::new(query)
+            .prompt("Search components")
+            .padding_with(EdgeInsets::symmetric(8.0, 16.0)),
+        scroll(watch(query.clone(), |query: Str| {
+            let demos = search(&query);
+            if demos.is_empty() {
+                return AnyView::new(text("No matching demos").padding());
+            }
+            AnyView::new(vstack(
+                demos
+                    .into_iter()
+                    .map(|demo| AnyView::new(demo_link(demo)))
+                    .collect::<Vec<_>>(),
+            ))
+        })),
+    ))
+}
+
+#[hot_reload]
+fn main() -> impl View {
+    let query = binding(Str::new());
+    NavigationStack::new(NavigationView::new("Gallery", index(&query)))
+}
+
+pub fn app(env: Environment) -> App {
+    App::new(main, env)
+}
+
+waterui_ffi::export!();
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_demo_builds() {
+        for demo in demos::ALL {
+            let _ = (demo.view)();
+        }
+    }
+
+    #[test]
+    fn search_matches_names_summaries_and_keywords() {
+        assert_eq!(search("").len(), demos::ALL.len());
+        assert_eq!(search("  LIST ")[0].name, "Lists");
+        assert!(search("wgsl").iter().any(|demo| demo.name == "Shaders"));
+        assert!(search("no such component").is_empty());
+    }
+}
@@ -6,10 +6,10 @@ use clap::{Args as ClapArgs, ValueEnum};
 use color_eyre::eyre::{Result, bail};
 
 use crate::shell::{self, display_output};
-use crate::{error, header, success};
+use crate::{error, header, success, warn};
 use waterui_cli::{
-    android::platform::AndroidPlatform, apple::platform::ApplePlatform, build::BuildOptions,
-    platform::Platform as _, project::Project, toolchain::Toolchain,
+    android::platform::AndroidPlatform, apple::platform::ApplePlatform, availability,
+    build::BuildOptions, platform::Platform as _, project::Project, toolchain::Toolchain, web,
 };
 
 /// Target platform for building.
@@ -23,6 +23,8 @@ pub enum TargetPlatform {
     Android,
     /// macOS.
     Macos,
+    /// Web (`wasm32`), with content-hashed assets.
+    Web,
 }
 
 /// Target architecture for building.
@@ -58,7 +60,8 @@ pub struct Args {
     path: PathBuf,
 
     /// Output directory to copy the built library to.
-    /// The library will be copied as `libwaterui_app.a` (Apple) or `libwaterui_app.so` (Android).
+    /// The library will be copied as `libwaterui_app.a` (Apple) or `libwaterui_app.so` (Android);
+    /// web builds write the whole site there instead of `target/web`.
     #[arg(long)]
     output_dir: Option<PathBuf>,
 }
@@ -94,6 +97,10 @@ pub async fn run(args: Args) -> Result<()> {
     }
     success!("Toolchain ready");
 
+    for warning in availability::check(&project).await? {
+        warn!("{warning}");
+    }
+
     // Step 2: Build
     let spinner = shell::spinner("Compiling Rust library...");
     let result = display_output(async {
@@ -170,6 +177,12 @@ pub async fn run(args: Args) -> Result<()> {
             (TargetPlatform::Macos, Some(arch)) => {
                 bail!("macOS only supports arm64 or x86_64, not {:?}", arch)
             }
+
+            // Web - a single wasm32 target
+            (TargetPlatform::Web, None) => web::build(&project, build_options).await,
+            (TargetPlatform::Web, Some(arch)) => {
+                bail!("Web builds only target wasm32, not {:?}", arch)
+            }
         }
     })
     .await;
@@ -211,6 +224,11 @@ async fn check_toolchain(platform: TargetPlatform) -> Result<()> {
                 bail!("Toolchain check failed: {e}");
             }
         }
+        TargetPlatform::Web => {
+            if let Err(e) = web::toolchain().check().await {
+                bail!("Toolchain check failed: {e}");
+            }
+        }
     }
     Ok(())
 }
@@ -221,5 +239,6 @@ const fn platform_name(platform: TargetPlatform) -> &'static str {
         TargetPlatform::IosSimulator => "iOS Simulator",
         TargetPlatform::Android => "Android",
         TargetPlatform::Macos => "macOS",
+        TargetPlatform::Web => "Web",
     }
 }
@@ -2,14 +2,17 @@
 
 use std::path::PathBuf;
 
-use clap::Args as ClapArgs;
+use clap::{Args as ClapArgs, ValueEnum};
 use color_eyre::eyre::Result;
 use dialoguer::{Input, MultiSelect, theme::ColorfulTheme};
 use heck::{ToKebabCase, ToSnakeCase};
 
 use crate::shell;
 use crate::{header, line, success};
-use waterui_cli::project::{CreateOptions, Project};
+use waterui_cli::{
+    project::{CreateOptions, Project},
+    templates::ProjectTemplate,
+};
 
 /// Arguments for the create command.
 #[derive(ClapArgs, Debug)]
@@ -36,6 +39,28 @@ pub struct Args {
     /// Create a playground project (auto-managed backends, no manual backend files).
     #[arg(long)]
     playground: bool,
+
+    /// Starter code for the new project.
+    #[arg(long, value_enum, default_value_t = Template::Basic)]
+    template: Template,
+}
+
+/// Starter code options.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Template {
+    /// A minimal single-view app.
+    Basic,
+    /// A searchable gallery of component demos.
+    Gallery,
+}
+
+impl From<Template> for ProjectTemplate {
+    fn from(template: Template) -> Self {
+        match template {
+            Template::Basic => Self::Basic,
+            Template::Gallery => Self::Gallery,
+        }
+    }
 }
 
 /// Platform options for scaffolding.
@@ -130,6 +155,7 @@ pub async fn run(args: Args) -> Result<()> {
             playground: args.playground,
             waterui_path,
             author: whoami::username(),
+            template: args.template.into(),
         },
     )
     .await?;
@@ -1,11 +1,16 @@
 //! `water doctor` command implementation.
 
+use std::path::PathBuf;
+
 use clap::Args as ClapArgs;
 use color_eyre::eyre::Result;
 
 use crate::shell;
-use crate::{header, line, note, success, warn};
-use waterui_cli::toolchain::doctor::{CheckStatus, doctor};
+use crate::{error, header, line, note, success, warn};
+use waterui_cli::{
+    project::Project,
+    toolchain::doctor::{CheckStatus, doctor},
+};
 
 /// Arguments for the doctor command.
 #[derive(ClapArgs, Debug)]
@@ -13,19 +18,27 @@ pub struct Args {
     /// Attempt to fix issues automatically.
     #[arg(long)]
     fix: bool,
+
+    /// Project directory path (defaults to current directory).
+    ///
+    /// Outside a project, every toolchain usable on this host is checked.
+    #[arg(long, default_value = ".")]
+    path: PathBuf,
 }
 
 /// Run the doctor command.
 pub async fn run(args: Args) -> Result<()> {
     header!("Checking development environment...");
 
+    let project = Project::open(&args.path).await.ok();
+
     let spinner = shell::spinner("Running diagnostics...");
-    let items = doctor().await;
+    let items = doctor(project.as_ref()).await;
     if let Some(pb) = spinner {
         pb.finish_and_clear();
     }
 
-    let mut all_ok = true;
+    let mut missing_count = 0;
     let mut fixable_count = 0;
 
     for item in &items {
@@ -34,8 +47,8 @@ pub async fn run(args: Args) -> Result<()> {
                 success!("{}", item.name);
             }
             CheckStatus::Missing => {
-                all_ok = false;
-                if item.fixable {
+                missing_count += 1;
+                if item.is_fixable() {
                     fixable_count += 1;
                 }
                 if let Some(msg) = &item.message {
@@ -51,14 +64,26 @@ pub async fn run(args: Args) -> Result<()> {
     }
 
     line!();
-    if all_ok {
+    if missing_count == 0 {
         success!("All checks passed!");
     } else if args.fix {
-        if fixable_count > 0 {
-            note!("Auto-fix is not yet implemented. Please fix issues manually.");
-        } else {
+        if fixable_count == 0 {
             note!("Nothing to fix automatically. Please fix issues manually.");
         }
+        for fix in items.iter().filter_map(|item| item.fix.as_ref()) {
+            let spinner = shell::spinner(format!("{}...", fix.description()));
+            let result = fix.apply().await;
+            if let Some(pb) = spinner {
+                pb.finish_and_clear();
+            }
+            match result {
+                Ok(()) => success!("{}", fix.description()),
+                Err(e) => error!("{} failed: {e}", fix.description()),
+            }
+        }
+        if fixable_count > 0 && fixable_count < missing_count {
+            note!("Remaining issues need to be fixed manually. See above for details.");
+        }
     } else if fixable_count > 0 {
         warn!("Some checks failed. Run `water doctor --fix` to attempt automatic fixes.");
     } else {
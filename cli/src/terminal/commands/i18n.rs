@@ -0,0 +1,102 @@
+//! `water i18n` command implementation.
+
+use std::path::PathBuf;
+
+use clap::{Args as ClapArgs, Subcommand};
+use color_eyre::eyre::{Result, bail};
+
+use crate::{header, line, note, success, warn};
+use waterui_cli::{
+    i18n::{LOCALES_DIR, extract},
+    project::Project,
+};
+
+/// Arguments for the i18n command.
+#[derive(ClapArgs, Debug)]
+pub struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Add the keys of all `t!` calls to the translation tables.
+    Extract(ExtractArgs),
+}
+
+/// Arguments for `water i18n extract`.
+#[derive(ClapArgs, Debug)]
+struct ExtractArgs {
+    /// Create a table for this language if it has none (e.g. `de`).
+    ///
+    /// Existing tables in `locales/` are always updated.
+    #[arg(short, long = "lang")]
+    languages: Vec<String>,
+
+    /// Remove entries whose key is no longer used.
+    #[arg(long)]
+    prune: bool,
+
+    /// Fail instead of writing if a table is out of sync, for CI.
+    #[arg(long)]
+    check: bool,
+
+    /// Project directory path (defaults to current directory).
+    #[arg(long, default_value = ".")]
+    path: PathBuf,
+}
+
+/// Run the i18n command.
+pub async fn run(args: Args) -> Result<()> {
+    match args.command {
+        Command::Extract(args) => run_extract(args).await,
+    }
+}
+
+async fn run_extract(args: ExtractArgs) -> Result<()> {
+    let project = Project::open(&args.path).await?;
+
+    header!("Extracting translation keys...");
+
+    let updates = extract(project.root(), &args.languages, args.prune, !args.check).await?;
+    if updates.is_empty() {
+        note!(
+            "No translation tables in `{LOCALES_DIR}/`. Create one with `water i18n extract --lang <language>`."
+        );
+        return Ok(());
+    }
+
+    let mut out_of_sync = 0;
+    for update in &updates {
+        if update.is_unchanged() {
+            success!("{}: up to date", update.language);
+        } else if args.check {
+            out_of_sync += 1;
+            warn!("{}: {} key(s) missing", update.language, update.added.len());
+        } else {
+            success!(
+                "{}: {} key(s) added, {} removed",
+                update.language,
+                update.added.len(),
+                update.removed.len()
+            );
+        }
+        if update.untranslated > 0 {
+            line!("  {} entries not translated yet", update.untranslated);
+        }
+        if !update.unused.is_empty() {
+            warn!(
+                "{}: {} unused key(s); run with --prune to remove them",
+                update.language,
+                update.unused.len()
+            );
+        }
+    }
+
+    if args.check && out_of_sync > 0 {
+        bail!(
+            "{out_of_sync} translation table(s) out of sync. Run `water i18n extract` to update them."
+        );
+    }
+    Ok(())
+}
@@ -5,5 +5,6 @@ pub mod clean;
 pub mod create;
 pub mod devices;
 pub mod doctor;
+pub mod i18n;
 pub mod package;
 pub mod run;
@@ -9,7 +9,7 @@ use clap::{Parser, Subcommand};
 use color_eyre::eyre::Result;
 use futures::future::{self, Either};
 
-use commands::{build, clean, create, devices, doctor, package, run};
+use commands::{build, clean, create, devices, doctor, i18n, package, run};
 
 /// Flag to track if Ctrl+C was pressed.
 static CANCELLED: AtomicBool = AtomicBool::new(false);
@@ -58,6 +58,10 @@ enum Commands {
 
     /// List available devices.
     Devices(devices::Args),
+
+    /// Manage translation tables.
+    #[command(name = "i18n")]
+    I18n(i18n::Args),
 }
 
 fn main() -> Result<()> {
@@ -94,6 +98,7 @@ fn main() -> Result<()> {
                 Commands::Clean(args) => clean::run(args).await,
                 Commands::Doctor(args) => doctor::run(args).await,
                 Commands::Devices(args) => devices::run(args).await,
+                Commands::I18n(args) => i18n::run(args).await,
             }
         };
 
@@ -6,6 +6,9 @@ use color_eyre::eyre;
 
 pub mod cmake;
 pub mod doctor;
+pub mod matrix;
+pub mod rust;
+
 /// A toolchain that cannot be fixed automatically.
 #[derive(Debug, Clone, thiserror::Error)]
 #[error("Unfixable toolchain: {message}\n Suggestion: {suggestion}")]
@@ -1,9 +1,32 @@
 //! Toolchain diagnostics for the `water doctor` command.
+//!
+//! Besides checking that each native toolchain is installed, the doctor
+//! validates installed versions against the [requirements](Requirements) of
+//! the backends pinned in `Water.toml`, looks for simulators and emulators to
+//! run on, and checks the Rust targets. Issues that can be resolved without
+//! user interaction carry a [`Fix`] that the installer runs on `--fix`.
+
+use std::path::Path;
+
+use color_eyre::eyre;
 
 use crate::{
-    android::toolchain::{AndroidNdk, AndroidSdk, Java},
-    apple::toolchain::{AppleSdk, Xcode},
-    toolchain::Toolchain,
+    android::{
+        platform::AndroidPlatform,
+        toolchain::{AndroidNdk, AndroidSdk, Java},
+    },
+    apple::{
+        device::AppleSimulator,
+        toolchain::{AppleSdk, Xcode},
+    },
+    project::Project,
+    toolchain::{
+        Installation, Toolchain, ToolchainError, UnfixableToolchain,
+        cmake::{Cmake, CmakeInstallation},
+        matrix::{self, Requirements},
+        rust::{RustTargets, RustTargetsInstallation},
+    },
+    utils::{run_command, run_command_output},
 };
 
 /// Status of a toolchain check.
@@ -17,6 +40,39 @@ pub enum CheckStatus {
     Skipped,
 }
 
+/// An automatic fix for a failed check.
+#[derive(Debug, Clone)]
+pub enum Fix {
+    /// Install missing Rust targets.
+    RustTargets(RustTargetsInstallation),
+    /// Install `CMake`.
+    Cmake(CmakeInstallation),
+}
+
+impl Fix {
+    /// Describe what the fix will do.
+    #[must_use]
+    pub fn description(&self) -> String {
+        match self {
+            Self::RustTargets(install) => {
+                format!("Install Rust targets {}", install.missing().join(", "))
+            }
+            Self::Cmake(_) => "Install CMake".to_string(),
+        }
+    }
+
+    /// Apply the fix.
+    ///
+    /// # Errors
+    /// - If the installation fails.
+    pub async fn apply(&self) -> eyre::Result<()> {
+        match self {
+            Self::RustTargets(install) => install.install().await,
+            Self::Cmake(install) => install.install().await.map_err(Into::into),
+        }
+    }
+}
+
 /// A single item in the doctor report.
 #[derive(Debug)]
 pub struct DoctorItem {
@@ -26,8 +82,8 @@ pub struct DoctorItem {
     pub status: CheckStatus,
     /// Optional message with details or suggestions.
     pub message: Option<String>,
-    /// Whether the issue can be fixed automatically.
-    pub fixable: bool,
+    /// Automatic fix for the issue, if one is available.
+    pub fix: Option<Fix>,
 }
 
 impl DoctorItem {
@@ -36,7 +92,7 @@ impl DoctorItem {
             name,
             status: CheckStatus::Ok,
             message: None,
-            fixable: false,
+            fix: None,
         }
     }
 
@@ -45,7 +101,14 @@ impl DoctorItem {
             name,
             status: CheckStatus::Missing,
             message: Some(message.into()),
-            fixable: false,
+            fix: None,
+        }
+    }
+
+    fn fixable(name: &'static str, message: impl Into<String>, fix: Fix) -> Self {
+        Self {
+            fix: Some(fix),
+            ..Self::missing(name, message)
         }
     }
 
@@ -54,59 +117,213 @@ impl DoctorItem {
             name,
             status: CheckStatus::Skipped,
             message: None,
-            fixable: false,
+            fix: None,
         }
     }
+
+    /// Whether the issue can be fixed automatically.
+    #[must_use]
+    pub const fn is_fixable(&self) -> bool {
+        self.fix.is_some()
+    }
+
+    fn unfixable(name: &'static str, error: &UnfixableToolchain) -> Self {
+        Self::missing(name, format!("{}. {}", error.message(), error.suggestion()))
+    }
+
+    fn from_check<I: Installation>(
+        name: &'static str,
+        result: Result<(), ToolchainError<I>>,
+    ) -> Result<(), Self> {
+        result.map_err(|e| match &e {
+            ToolchainError::Unfixable(error) => Self::unfixable(name, error),
+            ToolchainError::Fixable(_) => Self::missing(name, e.to_string()),
+        })
+    }
+
+    fn report(name: &'static str, result: Result<(), Self>) -> Self {
+        result.err().unwrap_or_else(|| Self::ok(name))
+    }
 }
 
-/// Run diagnostics on all toolchains and return a report.
-pub async fn doctor() -> Vec<DoctorItem> {
+/// Run diagnostics and return a report.
+///
+/// With a project, only the toolchains of its configured backends are checked,
+/// against the requirements of the pinned backend versions. Without one, every
+/// toolchain usable on this host is checked against the newest requirements.
+pub async fn doctor(project: Option<&Project>) -> Vec<DoctorItem> {
+    let backends = project.map(Project::backends);
+    let apple = backends.is_none_or(|backends| backends.apple().is_some());
+    let android = backends.is_none_or(|backends| backends.android().is_some());
+
     let mut items = Vec::new();
 
-    // Check Xcode (macOS only)
-    if cfg!(target_os = "macos") {
-        match Xcode.check().await {
-            Ok(()) => items.push(DoctorItem::ok("Xcode")),
-            Err(e) => items.push(DoctorItem::missing("Xcode", e.to_string())),
+    if apple {
+        if cfg!(target_os = "macos") {
+            let version = backends
+                .and_then(|backends| backends.apple())
+                .and_then(|backend| backend.revision.as_deref());
+            check_apple(Requirements::for_backend(version), &mut items).await;
+        } else {
+            items.extend(
+                ["Xcode", "iOS SDK", "macOS SDK", "iOS Simulator"].map(DoctorItem::skipped),
+            );
         }
+    }
 
-        // Check iOS SDK
-        match AppleSdk::Ios.check().await {
-            Ok(()) => items.push(DoctorItem::ok("iOS SDK")),
-            Err(e) => items.push(DoctorItem::missing("iOS SDK", e.to_string())),
-        }
+    if android {
+        let version = backends
+            .and_then(|backends| backends.android())
+            .and_then(|backend| backend.version());
+        check_android(Requirements::for_backend(version), &mut items).await;
+    }
 
-        // Check macOS SDK
-        match AppleSdk::Macos.check().await {
-            Ok(()) => items.push(DoctorItem::ok("macOS SDK")),
-            Err(e) => items.push(DoctorItem::missing("macOS SDK", e.to_string())),
-        }
-    } else {
-        items.push(DoctorItem::skipped("Xcode"));
-        items.push(DoctorItem::skipped("iOS SDK"));
-        items.push(DoctorItem::skipped("macOS SDK"));
+    let targets = matrix::rust_targets(backends);
+    items.push(match RustTargets::new(targets).check().await {
+        Ok(()) => DoctorItem::ok("Rust targets"),
+        Err(ToolchainError::Fixable(install)) => DoctorItem::fixable(
+            "Rust targets",
+            format!("Missing {}", install.missing().join(", ")),
+            Fix::RustTargets(install),
+        ),
+        Err(ToolchainError::Unfixable(error)) => DoctorItem::unfixable("Rust targets", &error),
+    });
+
+    items
+}
+
+async fn check_apple(requirements: Requirements, items: &mut Vec<DoctorItem>) {
+    items.push(DoctorItem::report("Xcode", check_xcode(requirements).await));
+
+    for (sdk, name) in [(AppleSdk::Ios, "iOS SDK"), (AppleSdk::Macos, "macOS SDK")] {
+        let result = DoctorItem::from_check(name, sdk.check().await);
+        items.push(DoctorItem::report(name, result));
     }
 
-    // Check Android SDK
-    match AndroidSdk.check().await {
-        Ok(()) => items.push(DoctorItem::ok("Android SDK")),
-        Err(e) => items.push(DoctorItem::missing("Android SDK", e.to_string())),
+    let has_simulator = AppleSimulator::scan().await.is_ok_and(|simulators| {
+        simulators.iter().any(|simulator| {
+            simulator.is_available && simulator.device_type_identifier.contains("iPhone")
+        })
+    });
+    items.push(if has_simulator {
+        DoctorItem::ok("iOS Simulator")
+    } else {
+        DoctorItem::missing(
+            "iOS Simulator",
+            "No iPhone simulator available. Install the iOS platform in Xcode -> Settings -> Components.",
+        )
+    });
+}
+
+async fn check_xcode(requirements: Requirements) -> Result<(), DoctorItem> {
+    DoctorItem::from_check("Xcode", Xcode.check().await)?;
+
+    let major = run_command("xcodebuild", ["-version"])
+        .await
+        .ok()
+        .and_then(|output| matrix::parse_xcode_major(&output));
+    match major {
+        Some(major) if major >= requirements.xcode => Ok(()),
+        Some(major) => Err(DoctorItem::missing(
+            "Xcode",
+            format!(
+                "Xcode {major} is too old, the Apple backend requires Xcode {} or newer",
+                requirements.xcode
+            ),
+        )),
+        None => Err(DoctorItem::missing(
+            "Xcode",
+            "Could not determine the Xcode version. Run `sudo xcode-select --switch /Applications/Xcode.app`.",
+        )),
     }
+}
+
+async fn check_android(requirements: Requirements, items: &mut Vec<DoctorItem>) {
+    let sdk = DoctorItem::from_check("Android SDK", AndroidSdk.check().await);
+    items.push(DoctorItem::report("Android SDK", sdk));
+    items.push(DoctorItem::report(
+        "Android NDK",
+        check_ndk(requirements).await,
+    ));
+    items.push(DoctorItem::report("Java", check_java(requirements).await));
 
-    // Check Android NDK
-    match AndroidNdk.check().await {
-        Ok(()) => items.push(DoctorItem::ok("Android NDK")),
-        Err(e) => items.push(DoctorItem::missing("Android NDK", e.to_string())),
+    items.push(match Cmake::default().check().await {
+        Ok(()) => DoctorItem::ok("CMake"),
+        Err(ToolchainError::Fixable(install)) if cfg!(target_os = "macos") => {
+            DoctorItem::fixable("CMake", "CMake is not installed", Fix::Cmake(install))
+        }
+        Err(_) => DoctorItem::missing(
+            "CMake",
+            "CMake is not installed. Install it with your package manager.",
+        ),
+    });
+
+    let has_emulator = AndroidPlatform::list_avds()
+        .await
+        .is_ok_and(|avds| !avds.is_empty());
+    items.push(if has_emulator {
+        DoctorItem::ok("Android Emulator")
+    } else {
+        DoctorItem::missing(
+            "Android Emulator",
+            "No Android Virtual Device found. Create one in Android Studio -> Device Manager.",
+        )
+    });
+}
+
+async fn check_ndk(requirements: Requirements) -> Result<(), DoctorItem> {
+    DoctorItem::from_check("Android NDK", AndroidNdk.check().await)?;
+
+    let major = AndroidNdk::detect_path()
+        .and_then(|path| std::fs::read_to_string(path.join("source.properties")).ok())
+        .and_then(|properties| matrix::parse_ndk_major(&properties));
+    match major {
+        Some(major) if major >= requirements.ndk => Ok(()),
+        Some(major) => Err(DoctorItem::missing(
+            "Android NDK",
+            format!(
+                "NDK {major} is too old, the Android backend requires NDK {} or newer. \
+                 Install it in Android Studio -> SDK Manager -> SDK Tools -> 'NDK (Side by side)'.",
+                requirements.ndk
+            ),
+        )),
+        None => Err(DoctorItem::missing(
+            "Android NDK",
+            "Could not determine the NDK version from source.properties",
+        )),
     }
+}
 
-    // Check Java
-    match Java::detect_path().await {
-        Some(_) => items.push(DoctorItem::ok("Java")),
-        None => items.push(DoctorItem::missing(
+async fn check_java(requirements: Requirements) -> Result<(), DoctorItem> {
+    let Some(java) = Java::detect_path().await else {
+        return Err(DoctorItem::missing(
             "Java",
             "Install JDK or set JAVA_HOME. Android Studio includes a bundled JDK.",
+        ));
+    };
+
+    let major = java_major(&java).await;
+    match major {
+        Some(major) if major >= requirements.jdk => Ok(()),
+        Some(major) => Err(DoctorItem::missing(
+            "Java",
+            format!(
+                "JDK {major} is too old, the Android backend requires JDK {} or newer. \
+                 Point JAVA_HOME to a newer JDK.",
+                requirements.jdk
+            ),
+        )),
+        None => Err(DoctorItem::missing(
+            "Java",
+            format!("Could not determine the version of {}", java.display()),
         )),
     }
+}
 
-    items
+async fn java_major(java: &Path) -> Option<u32> {
+    // `java -version` prints to stderr
+    let output = run_command_output(java.to_str()?, ["-version"])
+        .await
+        .ok()?;
+    matrix::parse_java_major(&String::from_utf8_lossy(&output.stderr))
 }
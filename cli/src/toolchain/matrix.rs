@@ -0,0 +1,141 @@
+//! Native toolchain versions required by each backend release.
+//!
+//! Backends pinned in `Water.toml` are built by the platform toolchains, so a
+//! backend release only works with recent enough Xcode, NDK and JDK versions.
+//! `water doctor` checks the installed versions against this matrix.
+
+use crate::backend::Backends;
+
+/// Minimum native toolchain versions required by a backend release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Requirements {
+    /// Minimum Xcode major version, for the Apple backend.
+    pub xcode: u32,
+    /// Minimum Android NDK major version, for the Android backend.
+    pub ndk: u32,
+    /// Minimum JDK major version, for the Android backend.
+    pub jdk: u32,
+}
+
+/// Requirements of each backend release line, newest first.
+///
+/// Add a row when a backend release raises a requirement.
+const MATRIX: &[(&str, Requirements)] = &[(
+    "0.2",
+    Requirements {
+        // Templates target iOS 26
+        xcode: 26,
+        ndk: 26,
+        // Templates compile for Java 21
+        jdk: 21,
+    },
+)];
+
+impl Requirements {
+    /// Get the requirements of the backend release `version`, such as `0.2.1`
+    /// or the tag `v0.2.1`.
+    ///
+    /// Unpinned and unknown versions use the newest release line.
+    #[must_use]
+    pub fn for_backend(version: Option<&str>) -> Self {
+        let version = version.map(|version| version.trim_start_matches('v'));
+        MATRIX
+            .iter()
+            .find(|(line, _)| {
+                version.is_some_and(|version| {
+                    version == *line || version.starts_with(&format!("{line}."))
+                })
+            })
+            .unwrap_or(&MATRIX[0])
+            .1
+    }
+}
+
+/// Rust targets needed to build for the configured backends.
+///
+/// Without a project, targets for every backend usable on this host.
+#[must_use]
+pub fn rust_targets(backends: Option<&Backends>) -> Vec<&'static str> {
+    let apple = backends.map_or(cfg!(target_os = "macos"), |backends| {
+        backends.apple().is_some()
+    });
+    let android = backends.is_none_or(|backends| backends.android().is_some());
+
+    let mut targets = Vec::new();
+    if apple {
+        targets.extend(["aarch64-apple-ios", "aarch64-apple-ios-sim"]);
+    }
+    if android {
+        targets.extend(["aarch64-linux-android", "x86_64-linux-android"]);
+    }
+    targets
+}
+
+/// Parse the major version from `xcodebuild -version` output.
+#[must_use]
+pub fn parse_xcode_major(output: &str) -> Option<u32> {
+    output
+        .lines()
+        .find_map(|line| line.strip_prefix("Xcode "))
+        .and_then(parse_major)
+}
+
+/// Parse the major version from an NDK's `source.properties`.
+#[must_use]
+pub fn parse_ndk_major(properties: &str) -> Option<u32> {
+    properties.lines().find_map(|line| {
+        let (key, value) = line.split_once('=')?;
+        (key.trim() == "Pkg.Revision")
+            .then(|| parse_major(value.trim()))
+            .flatten()
+    })
+}
+
+/// Parse the major version from `java -version` output.
+///
+/// Handles both the legacy `1.8.0` and the modern `21.0.2` schemes.
+#[must_use]
+pub fn parse_java_major(output: &str) -> Option<u32> {
+    let version = output.split('"').nth(1)?;
+    let version = version.strip_prefix("1.").unwrap_or(version);
+    parse_major(version)
+}
+
+fn parse_major(version: &str) -> Option<u32> {
+    version
+        .split(|c: char| !c.is_ascii_digit())
+        .next()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_backend_versions_use_the_newest_requirements() {
+        let newest = MATRIX[0].1;
+        assert_eq!(Requirements::for_backend(None), newest);
+        assert_eq!(Requirements::for_backend(Some("v0.2.1")), newest);
+        assert_eq!(Requirements::for_backend(Some("main")), newest);
+    }
+
+    #[test]
+    fn parses_toolchain_versions() {
+        assert_eq!(
+            parse_xcode_major("Xcode 26.0.1\nBuild version 17A400\n"),
+            Some(26)
+        );
+        assert_eq!(
+            parse_ndk_major("Pkg.Desc = Android NDK\nPkg.Revision = 27.0.12077973\n"),
+            Some(27)
+        );
+        assert_eq!(
+            parse_java_major("openjdk version \"21.0.2\" 2024-01-16\n"),
+            Some(21)
+        );
+        assert_eq!(parse_java_major("java version \"1.8.0_392\"\n"), Some(8));
+        assert_eq!(parse_java_major("not java"), None);
+    }
+}
@@ -0,0 +1,87 @@
+//! Toolchain support for Rust compilation targets.
+
+use color_eyre::eyre;
+
+use crate::{
+    toolchain::{Installation, Toolchain, ToolchainError},
+    utils::{run_command, which},
+};
+
+/// Rust standard libraries for the given targets, installed through `rustup`.
+#[derive(Debug, Clone)]
+pub struct RustTargets {
+    targets: Vec<&'static str>,
+}
+
+impl RustTargets {
+    /// Create a toolchain requiring the given targets.
+    #[must_use]
+    pub const fn new(targets: Vec<&'static str>) -> Self {
+        Self { targets }
+    }
+}
+
+impl Toolchain for RustTargets {
+    type Installation = RustTargetsInstallation;
+
+    async fn check(&self) -> Result<(), ToolchainError<Self::Installation>> {
+        if which("rustup").await.is_err() {
+            return Err(ToolchainError::unfixable(
+                "rustup is not installed",
+                "Install Rust through rustup from https://rustup.rs to manage compilation targets.",
+            ));
+        }
+
+        let installed = run_command("rustup", ["target", "list", "--installed"])
+            .await
+            .map_err(|e| {
+                ToolchainError::unfixable(
+                    format!("Failed to list installed Rust targets: {e}"),
+                    "Check that `rustup target list --installed` works.",
+                )
+            })?;
+        let installed: Vec<&str> = installed.lines().map(str::trim).collect();
+
+        let missing: Vec<&'static str> = self
+            .targets
+            .iter()
+            .copied()
+            .filter(|target| !installed.contains(target))
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(ToolchainError::fixable(RustTargetsInstallation { missing }))
+        }
+    }
+}
+
+/// Installation of missing Rust targets through `rustup target add`.
+#[derive(Debug, Clone)]
+pub struct RustTargetsInstallation {
+    missing: Vec<&'static str>,
+}
+
+impl RustTargetsInstallation {
+    /// Get the targets that will be installed.
+    #[must_use]
+    pub fn missing(&self) -> &[&'static str] {
+        &self.missing
+    }
+}
+
+impl Installation for RustTargetsInstallation {
+    type Error = eyre::Report;
+
+    async fn install(&self) -> Result<(), Self::Error> {
+        run_command(
+            "rustup",
+            ["target", "add"]
+                .into_iter()
+                .chain(self.missing.iter().copied()),
+        )
+        .await?;
+        Ok(())
+    }
+}
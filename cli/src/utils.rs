@@ -31,6 +31,11 @@ pub fn set_std_output(enabled: bool) {
     STD_OUTPUT.store(enabled, std::sync::atomic::Ordering::SeqCst);
 }
 
+/// Whether command output is forwarded to the terminal.
+pub(crate) fn is_std_output() -> bool {
+    STD_OUTPUT.load(Ordering::SeqCst)
+}
+
 // Warn: You will lose stdout/stderr piping if you modify this function!
 pub(crate) fn command(command: &mut Command) -> &mut Command {
     command
@@ -0,0 +1,96 @@
+//! Web build support.
+//!
+//! Web builds compile the app library to WebAssembly and lay out a directory
+//! that a static file server can host as-is:
+//!
+//! - `<output>/<crate>.wasm`
+//! - `<output>/assets/<path with content hash>`, matching the `/assets/...`
+//!   URLs the `asset!` macro emits on `wasm32`
+
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{self, bail};
+use smol::fs;
+use target_lexicon::{Architecture, BinaryFormat, Environment, OperatingSystem, Triple, Vendor};
+
+use crate::{
+    assets::{ASSETS_DIR, AssetLayout, bundle_assets},
+    build::{BuildOptions, RustBuild},
+    project::Project,
+    toolchain::rust::RustTargets,
+    utils::copy_file,
+};
+
+/// Name of the web target, as `rustup` and `cargo` spell it.
+pub const WEB_TARGET: &str = "wasm32-unknown-unknown";
+
+/// Target triple of web builds.
+#[must_use]
+pub const fn triple() -> Triple {
+    Triple {
+        architecture: Architecture::Wasm32,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Unknown,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Wasm,
+    }
+}
+
+/// Toolchain required for web builds.
+#[must_use]
+pub fn toolchain() -> RustTargets {
+    RustTargets::new(vec![WEB_TARGET])
+}
+
+/// Directory web builds are written to unless an output directory is given.
+#[must_use]
+pub fn default_output_dir(project: &Project) -> PathBuf {
+    project.target_dir().join("web")
+}
+
+/// Build the app for the web and bundle its assets next to it.
+///
+/// Return the directory containing the built site.
+///
+/// # Errors
+/// - If the library cannot be compiled for `wasm32`.
+/// - If the library or the assets cannot be copied.
+pub async fn build(project: &Project, options: BuildOptions) -> eyre::Result<PathBuf> {
+    let build = RustBuild::new(project.root(), triple(), options.is_hot_reload());
+    let lib_dir = build.build_lib(options.is_release()).await?;
+
+    let wasm_name = format!("{}.wasm", project.crate_name().replace('-', "_"));
+    let source_wasm = lib_dir.join(&wasm_name);
+    if !source_wasm.exists() {
+        bail!(
+            "WebAssembly module not found at {}. Does the crate build a `cdylib`?",
+            source_wasm.display()
+        );
+    }
+
+    let output_dir = options
+        .output_dir()
+        .map_or_else(|| default_output_dir(project), Path::to_path_buf);
+    fs::create_dir_all(&output_dir).await?;
+    copy_file(&source_wasm, &output_dir.join(&wasm_name)).await?;
+
+    // Hashed names let the server cache assets forever
+    bundle_assets(
+        project.root(),
+        &output_dir.join(ASSETS_DIR),
+        AssetLayout::Hashed,
+    )
+    .await?;
+
+    Ok(output_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triple_names_the_web_target() {
+        assert_eq!(triple().to_string(), WEB_TARGET);
+    }
+}
@@ -53,6 +53,8 @@ pub enum ButtonStyle {
 }
 
 use alloc::boxed::Box;
+use nami::{Computed, signal::IntoComputed};
+use waterui_core::feedback::{ControlKind, play_control_feedback};
 use waterui_core::handler::{
     BoxHandler, Handler, HandlerFn, HandlerFnWithState, IntoHandler, IntoHandlerWithState,
     into_handler, into_handler_with_state,
@@ -87,22 +89,26 @@ pub struct ButtonConfig {
     pub action: BoxHandler<()>,
     /// The visual style of the button
     pub style: ButtonStyle,
+    /// Whether the button is disabled and ignores taps
+    pub disabled: Computed<bool>,
 }
 
 impl_debug!(ButtonConfig);
 
 impl NativeView for ButtonConfig {}
 
-impl<Label, Action> View for Button<Label, Action>
+impl<Label, Action, Disabled> View for Button<Label, Action, Disabled>
 where
     Label: View,
     Action: Handler<()>,
+    Disabled: IntoComputed<bool>,
 {
     fn body(self, env: &Environment) -> impl View {
-        let config = self.config();
+        let mut config = self.config();
         if let Some(hook) = env.get::<Hook<ButtonConfig>>() {
             hook.apply(env, config)
         } else {
+            config.action = Box::new(WithFeedback(config.action));
             AnyView::new(Native::new(config))
         }
     }
@@ -112,22 +118,34 @@ where
     }
 }
 
+/// Plays the button feedback of the environment before running the action.
+struct WithFeedback(BoxHandler<()>);
+
+impl Handler<()> for WithFeedback {
+    fn handle(&mut self, env: &Environment) {
+        play_control_feedback(env, ControlKind::Button);
+        self.0.handle(env);
+    }
+}
+
 impl ViewConfiguration for ButtonConfig {
-    type View = Button<AnyView, BoxHandler<()>>;
+    type View = Button<AnyView, BoxHandler<()>, Computed<bool>>;
 
     fn render(self) -> Self::View {
         Button {
             label: self.label,
             action: self.action,
             style: self.style,
+            disabled: self.disabled,
         }
     }
 }
 
-impl<Label, Action> ConfigurableView for Button<Label, Action>
+impl<Label, Action, Disabled> ConfigurableView for Button<Label, Action, Disabled>
 where
     Label: View,
     Action: Handler<()>,
+    Disabled: IntoComputed<bool>,
 {
     type Config = ButtonConfig;
 
@@ -136,16 +154,18 @@ where
             label: AnyView::new(self.label),
             action: Box::new(self.action),
             style: self.style,
+            disabled: self.disabled.into_computed(),
         }
     }
 }
 
 /// A button component that can be configured with a label and an action.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
-pub struct Button<Label, Action> {
+pub struct Button<Label, Action, Disabled = bool> {
     label: Label,
     action: Action,
     style: ButtonStyle,
+    disabled: Disabled,
 }
 
 impl<Label> Button<Label, ()> {
@@ -159,11 +179,12 @@ impl<Label> Button<Label, ()> {
             label,
             action: (),
             style: ButtonStyle::Automatic,
+            disabled: false,
         }
     }
 }
 
-impl<Label, Action> Button<Label, Action> {
+impl<Label, Action, Disabled> Button<Label, Action, Disabled> {
     /// Sets the visual style of the button.
     ///
     /// # Arguments
@@ -179,6 +200,29 @@ impl<Label, Action> Button<Label, Action> {
         self
     }
 
+    /// Disables the button while `disabled` is `true`.
+    ///
+    /// A disabled button is dimmed and does not run its action.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let data = Signup::binding();
+    /// button("Sign Up").disabled(Signup::is_valid(&data).map(|valid| !valid));
+    /// ```
+    #[must_use]
+    pub fn disabled<D>(self, disabled: D) -> Button<Label, Action, D>
+    where
+        D: IntoComputed<bool>,
+    {
+        Button {
+            label: self.label,
+            action: self.action,
+            style: self.style,
+            disabled,
+        }
+    }
+
     /// Sets the action to be performed when the button is clicked.
     ///
     /// # Arguments
@@ -189,7 +233,7 @@ impl<Label, Action> Button<Label, Action> {
     ///
     /// The modified button with the action set
     #[must_use]
-    pub fn action<H, P>(self, action: H) -> Button<Label, IntoHandler<H, P, ()>>
+    pub fn action<H, P>(self, action: H) -> Button<Label, IntoHandler<H, P, ()>, Disabled>
     where
         H: HandlerFn<P, ()>,
         P: 'static,
@@ -198,6 +242,7 @@ impl<Label, Action> Button<Label, Action> {
             label: self.label,
             action: into_handler(action),
             style: self.style,
+            disabled: self.disabled,
         }
     }
     /// Sets the action to be performed when the button is clicked, with access to a state.
@@ -215,7 +260,7 @@ impl<Label, Action> Button<Label, Action> {
         self,
         state: &S,
         action: H,
-    ) -> Button<Label, IntoHandlerWithState<H, P, (), S>>
+    ) -> Button<Label, IntoHandlerWithState<H, P, (), S>, Disabled>
     where
         H: HandlerFnWithState<P, (), S>,
         S: 'static + Clone,
@@ -225,6 +270,7 @@ impl<Label, Action> Button<Label, Action> {
             label: self.label,
             action: into_handler_with_state(action, state.clone()),
             style: self.style,
+            disabled: self.disabled,
         }
     }
 }
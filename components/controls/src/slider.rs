@@ -8,7 +8,12 @@
 use core::ops::RangeInclusive;
 
 use nami::Binding;
-use waterui_core::{AnyView, View, configurable, layout::StretchAxis};
+use waterui_core::{
+    AnyView, Environment, Native, NativeView, View,
+    feedback::{ControlKind, FeedbackTrigger},
+    layout::StretchAxis,
+    view::{ConfigurableView, Hook, ViewConfiguration},
+};
 use waterui_text::text;
 
 /// Configuration for the [`Slider`] widget.
@@ -27,56 +32,109 @@ pub struct SliderConfig {
     pub value: Binding<f64>,
 }
 
-configurable!(
-    /// A control for selecting a value from a continuous range.
-    ///
-    /// Slider lets users select a value by dragging a thumb along a track.
-    ///
-    /// # Layout Behavior
-    ///
-    /// Slider **expands horizontally** to fill available space, but has a fixed height.
-    /// In an `HStack`, it will take up all remaining width after other views are sized.
-    ///
-    /// # Examples
-    ///
-    /// ```ignore
-    /// // Basic slider (0 to 100)
-    /// slider(0.0..=100.0, &volume)
-    ///
-    /// // With custom labels
-    /// slider(0.0..=1.0, &brightness)
-    ///     .label("Brightness")
-    ///     .min_value_label("Dark")
-    ///     .max_value_label("Bright")
-    ///
-    /// // In a form (slider fills remaining width)
-    /// hstack((
-    ///     text("Volume"),
-    ///     slider(0.0..=100.0, &volume),
-    /// ))
-    /// ```
-    //
-    // ═══════════════════════════════════════════════════════════════════════════
-    // INTERNAL: Layout Contract for Backend Implementers
-    // ═══════════════════════════════════════════════════════════════════════════
-    //
+/// A control for selecting a value from a continuous range.
+///
+/// Slider lets users select a value by dragging a thumb along a track.
+///
+/// # Layout Behavior
+///
+/// Slider **expands horizontally** to fill available space, but has a fixed height.
+/// In an `HStack`, it will take up all remaining width after other views are sized.
+///
+/// # Examples
+///
+/// ```ignore
+/// // Basic slider (0 to 100)
+/// slider(0.0..=100.0, &volume)
+///
+/// // With custom labels
+/// slider(0.0..=1.0, &brightness)
+///     .label("Brightness")
+///     .min_value_label("Dark")
+///     .max_value_label("Bright")
+///
+/// // In a form (slider fills remaining width)
+/// hstack((
+///     text("Volume"),
+///     slider(0.0..=100.0, &volume),
+/// ))
+/// ```
+//
+// ═══════════════════════════════════════════════════════════════════════════
+// INTERNAL: Layout Contract for Backend Implementers
+// ═══════════════════════════════════════════════════════════════════════════
+//
 
-    // Height: Fixed intrinsic (platform-determined)
-    // Width: Reports minimum usable width, expands during layout phase
-    //
-    // ═══════════════════════════════════════════════════════════════════════════
-    //
-    Slider,
-    SliderConfig,
-    StretchAxis::Horizontal
-);
+// Height: Fixed intrinsic (platform-determined)
+// Width: Reports minimum usable width, expands during layout phase
+//
+// ═══════════════════════════════════════════════════════════════════════════
+//
+#[derive(Debug)]
+pub struct Slider(SliderConfig);
+
+impl NativeView for SliderConfig {
+    fn stretch_axis(&self) -> StretchAxis {
+        StretchAxis::Horizontal
+    }
+}
+
+impl ConfigurableView for Slider {
+    type Config = SliderConfig;
+    fn config(self) -> Self::Config {
+        self.0
+    }
+}
+
+impl ViewConfiguration for SliderConfig {
+    type View = Slider;
+    fn render(self) -> Self::View {
+        Slider(self)
+    }
+}
+
+impl From<SliderConfig> for Slider {
+    fn from(value: SliderConfig) -> Self {
+        Self(value)
+    }
+}
+
+impl View for Slider {
+    fn body(self, env: &Environment) -> impl View {
+        let mut config = self.config();
+        if let Some(hook) = env.get::<Hook<SliderConfig>>() {
+            AnyView::new(hook.apply(env, config))
+        } else {
+            if let Some(trigger) = FeedbackTrigger::new(env, ControlKind::Slider) {
+                // Only changes made by the user go through the native binding
+                let range = config.range.clone();
+                let at_end = move |value: f64| value <= *range.start() || value >= *range.end();
+                config.value = Binding::mapping(
+                    &config.value,
+                    |value| value,
+                    move |binding, value| {
+                        if at_end(value) && !at_end(binding.get()) {
+                            trigger.play();
+                        }
+                        binding.set(value);
+                    },
+                );
+            }
+            AnyView::new(Native::new(config))
+        }
+    }
+
+    fn stretch_axis(&self) -> StretchAxis {
+        NativeView::stretch_axis(&self.0)
+    }
+}
 
 impl Slider {
     /// Creates a new [`Slider`] widget.
     #[must_use]
     pub fn new(range: RangeInclusive<f64>, value: &Binding<f64>) -> Self {
         Self(SliderConfig {
-            label: AnyView::new(text!("{:.2}", value)),
+            label: AnyView::new(text!("{:.2}", value.clone())),
             min_value_label: AnyView::default(),
             max_value_label: AnyView::default(),
             range,
@@ -1,6 +1,21 @@
 //! A text input component wired to a reactive string binding.
 //!
 //! ![`TextField`](https://raw.githubusercontent.com/water-rs/waterui/dev/docs/illustrations/text_field.svg)
+//!
+//! # Input method composition
+//!
+//! Input methods for Chinese, Japanese and other languages first show
+//! uncommitted *marked text* and only insert the final characters once the
+//! user picks them. Marked text never reaches the value binding: backends
+//! report it through [`TextField::on_composition`] and write the value when
+//! the composition is committed.
+//!
+//! Changing the value from code while the user is composing must not discard
+//! the marked text either. Backends therefore hold such updates back until
+//! the composition ends: if it is cancelled, the latest held-back value is
+//! shown; if it is committed, the backend writes the committed text to the
+//! binding, which wins over anything held back.
+use alloc::boxed::Box;
 use core::num::NonZeroUsize;
 
 use nami::Binding;
@@ -54,11 +69,13 @@ configurable!(
 
 /// Configuration options for a `TextField`.
 #[non_exhaustive]
-#[derive(Debug)]
 pub struct TextFieldConfig {
     /// The label displayed for the text field.
     pub label: AnyView,
     /// The binding to the text value.
+    ///
+    /// It only ever holds committed text, never the marked text of an input
+    /// method.
     pub value: Binding<Str>,
     /// The placeholder text shown when the field is empty.
     pub prompt: Text,
@@ -67,6 +84,41 @@ pub struct TextFieldConfig {
     /// The maximum number of lines to show.
     /// If `None`, the text field will show as many lines as needed.
     pub line_limit: Option<NonZeroUsize>,
+    /// The event handler for input method composition.
+    pub on_composition: OnComposition,
+}
+
+type OnComposition = Box<dyn Fn(CompositionEvent) + 'static>;
+
+impl core::fmt::Debug for TextFieldConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TextFieldConfig")
+            .field("label", &self.label)
+            .field("value", &self.value)
+            .field("prompt", &self.prompt)
+            .field("keyboard", &self.keyboard)
+            .field("line_limit", &self.line_limit)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Events reported while an input method composes text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompositionEvent {
+    /// The input method started composing.
+    Began,
+    /// The marked text changed.
+    Updated {
+        /// The uncommitted text shown inline, such as `"nihao"` or `"にほん"`.
+        marked_text: Str,
+    },
+    /// The composition finished and `text` was inserted into the value.
+    Committed {
+        /// The committed text, such as `"你好"` or `"日本"`.
+        text: Str,
+    },
+    /// The composition was abandoned without inserting anything.
+    Cancelled,
 }
 
 #[derive(Debug, Default)]
@@ -96,6 +148,7 @@ impl TextField {
             prompt: Text::default(),
             keyboard: KeyboardType::default(),
             line_limit: NonZeroUsize::new(1),
+            on_composition: Box::new(|_| {}),
         })
     }
     /// Sets the label for the text field.
@@ -132,6 +185,16 @@ impl TextField {
         self.0.prompt = prompt.into();
         self
     }
+
+    /// Sets the event handler for input method composition.
+    ///
+    /// Use it to observe the marked text while the user types in Chinese,
+    /// Japanese or another language that composes characters.
+    #[must_use]
+    pub fn on_composition(mut self, handler: impl Fn(CompositionEvent) + 'static) -> Self {
+        self.0.on_composition = Box::new(handler);
+        self
+    }
 }
 
 /// Creates a new [`TextField`] with the specified label and value binding.
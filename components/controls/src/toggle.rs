@@ -3,9 +3,12 @@
 //! ![Toggle](https://raw.githubusercontent.com/water-rs/waterui/dev/docs/illustrations/toggle.svg)
 
 use nami::Binding;
-use waterui_core::configurable;
-
-use waterui_core::{AnyView, View};
+use waterui_core::{
+    AnyView, Environment, Native, NativeView, View,
+    feedback::{ControlKind, FeedbackTrigger},
+    layout::StretchAxis,
+    view::{ConfigurableView, Hook, ViewConfiguration},
+};
 
 #[derive(Debug)]
 #[non_exhaustive]
@@ -17,48 +20,99 @@ pub struct ToggleConfig {
     pub toggle: Binding<bool>,
 }
 
-configurable!(
-    /// A control that toggles between on and off states.
-    ///
-    /// Toggle displays a switch with an optional label. It's commonly used
-    /// for settings that can be turned on or off.
-    ///
-    /// # Layout Behavior
-    ///
-    /// With a label: Toggle expands horizontally to fill available space,
-    /// placing the label on the left and switch on the right.
-    /// Without a label: Toggle is content-sized (just the switch).
-    ///
-    /// # Examples
-    ///
-    /// ```ignore
-    /// // Simple toggle with label
-    /// toggle("Wi-Fi", &is_enabled)
-    ///
-    /// // Toggle without label
-    /// Toggle::new(&dark_mode)
-    ///
-    /// // In a settings list
-    /// vstack((
-    ///     toggle("Notifications", &notifications),
-    ///     toggle("Sound", &sound),
-    /// ))
-    /// ```
-    //
-    // ═══════════════════════════════════════════════════════════════════════════
-    // INTERNAL: Layout Contract for Backend Implementers
-    // ═══════════════════════════════════════════════════════════════════════════
-    //
-    // - stretchAxis: .horizontal (toggle expands to fill available width)
-    // - sizeThatFits: Returns proposed width (or minimum), intrinsic height
-    // - Layout: label on left, switch on right, flexible space between
-    //
-    // ═══════════════════════════════════════════════════════════════════════════
-    //
-    Toggle,
-    ToggleConfig,
-    waterui_core::layout::StretchAxis::Horizontal
-);
+/// A control that toggles between on and off states.
+///
+/// Toggle displays a switch with an optional label. It's commonly used
+/// for settings that can be turned on or off.
+///
+/// # Layout Behavior
+///
+/// With a label: Toggle expands horizontally to fill available space,
+/// placing the label on the left and switch on the right.
+/// Without a label: Toggle is content-sized (just the switch).
+///
+/// # Examples
+///
+/// ```ignore
+/// // Simple toggle with label
+/// toggle("Wi-Fi", &is_enabled)
+///
+/// // Toggle without label
+/// Toggle::new(&dark_mode)
+///
+/// // In a settings list
+/// vstack((
+///     toggle("Notifications", &notifications),
+///     toggle("Sound", &sound),
+/// ))
+/// ```
+//
+// ═══════════════════════════════════════════════════════════════════════════
+// INTERNAL: Layout Contract for Backend Implementers
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// - stretchAxis: .horizontal (toggle expands to fill available width)
+// - sizeThatFits: Returns proposed width (or minimum), intrinsic height
+// - Layout: label on left, switch on right, flexible space between
+//
+// ═══════════════════════════════════════════════════════════════════════════
+//
+#[derive(Debug)]
+pub struct Toggle(ToggleConfig);
+
+impl NativeView for ToggleConfig {
+    fn stretch_axis(&self) -> StretchAxis {
+        StretchAxis::Horizontal
+    }
+}
+
+impl ConfigurableView for Toggle {
+    type Config = ToggleConfig;
+    fn config(self) -> Self::Config {
+        self.0
+    }
+}
+
+impl ViewConfiguration for ToggleConfig {
+    type View = Toggle;
+    fn render(self) -> Self::View {
+        Toggle(self)
+    }
+}
+
+impl From<ToggleConfig> for Toggle {
+    fn from(value: ToggleConfig) -> Self {
+        Self(value)
+    }
+}
+
+impl View for Toggle {
+    fn body(self, env: &Environment) -> impl View {
+        let mut config = self.config();
+        if let Some(hook) = env.get::<Hook<ToggleConfig>>() {
+            AnyView::new(hook.apply(env, config))
+        } else {
+            if let Some(trigger) = FeedbackTrigger::new(env, ControlKind::Toggle) {
+                // Only changes made by the user go through the native binding
+                config.toggle = Binding::mapping(
+                    &config.toggle,
+                    |on| on,
+                    move |toggle, on| {
+                        if toggle.get() != on {
+                            trigger.play();
+                        }
+                        toggle.set(on);
+                    },
+                );
+            }
+            AnyView::new(Native::new(config))
+        }
+    }
+
+    fn stretch_axis(&self) -> StretchAxis {
+        NativeView::stretch_axis(&self.0)
+    }
+}
 
 impl Toggle {
     #[must_use]
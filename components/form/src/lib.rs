@@ -11,6 +11,7 @@ use waterui_color::Color;
 /// Picker form component module.
 pub mod picker;
 pub mod valid;
+pub use valid::FormValidation;
 
 use waterui_core::{AnyView, Binding, Str, View};
 
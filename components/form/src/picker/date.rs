@@ -3,7 +3,7 @@
 use core::ops::{RangeBounds, RangeInclusive};
 
 use nami::Binding;
-use time::Date;
+use waterui_core::time::Date;
 use waterui_core::{AnyView, View, configurable};
 /// Configuration for the `DatePicker` component.
 #[derive(Debug)]
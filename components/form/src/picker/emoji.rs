@@ -0,0 +1,60 @@
+//! Emoji Picker Component
+
+use nami::Binding;
+use waterui_core::{AnyView, Str, View, configurable};
+
+#[derive(Debug)]
+#[non_exhaustive]
+/// Configuration for the `EmojiPicker` component.
+pub struct EmojiPickerConfig {
+    /// The label of the emoji picker.
+    pub label: AnyView,
+    /// The binding to the selected emoji, a single grapheme cluster.
+    ///
+    /// Empty when nothing has been picked.
+    pub value: Binding<Str>,
+}
+
+configurable!(
+    /// A control for picking a single emoji.
+    ///
+    /// EmojiPicker presents the platform's emoji palette (or a built-in grid where
+    /// none exists) and writes the chosen emoji, including any skin tone or ZWJ
+    /// sequence, to its binding.
+    ///
+    /// # Layout Behavior
+    ///
+    /// EmojiPicker sizes itself to fit its content and never stretches to fill extra space.
+    /// In a stack, it takes only the space it needs.
+    //
+    // ═══════════════════════════════════════════════════════════════════════════
+    // INTERNAL: Layout Contract for Backend Implementers
+    // ═══════════════════════════════════════════════════════════════════════════
+    //
+
+    // Size: Label plus a button showing the current emoji; the palette is presented
+    //       as a popover or sheet and does not affect layout
+    //
+    // ═══════════════════════════════════════════════════════════════════════════
+    //
+    EmojiPicker,
+    EmojiPickerConfig
+);
+
+impl EmojiPicker {
+    /// Creates a new `EmojiPicker` bound to `value`.
+    #[must_use]
+    pub fn new(value: &Binding<Str>) -> Self {
+        Self(EmojiPickerConfig {
+            label: AnyView::default(),
+            value: value.clone(),
+        })
+    }
+
+    /// Sets the label of the emoji picker.
+    #[must_use]
+    pub fn label(mut self, label: impl View) -> Self {
+        self.0.label = AnyView::new(label);
+        self
+    }
+}
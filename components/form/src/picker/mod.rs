@@ -2,6 +2,8 @@ pub mod color;
 pub use color::ColorPicker;
 pub mod date;
 pub use date::DatePicker;
+pub mod emoji;
+pub use emoji::EmojiPicker;
 pub mod multi_date;
 
 use alloc::vec::Vec;
@@ -8,7 +8,7 @@
 use alloc::collections::BTreeSet;
 
 use nami::Binding;
-use time::Date;
+use waterui_core::time::Date;
 use waterui_core::{AnyView, View, configurable};
 #[derive(Debug)]
 #[non_exhaustive]
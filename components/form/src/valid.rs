@@ -1,4 +1,34 @@
 //! Validation utilities for form components.
+//!
+//! Fields of a `#[form]` struct can be validated with `#[form(validate = path::to::fn)]`,
+//! where the function takes `&FieldType` and returns `Result<(), E>` with `E: Display`:
+//!
+//! ```text
+//! use waterui::prelude::*;
+//!
+//! fn non_empty(name: &String) -> Result<(), &'static str> {
+//!     if name.trim().is_empty() { Err("Name is required.") } else { Ok(()) }
+//! }
+//!
+//! #[form]
+//! pub struct Signup {
+//!     #[form(validate = non_empty)]
+//!     pub name: String,
+//!     pub newsletter: bool,
+//! }
+//!
+//! fn signup() -> impl View {
+//!     let data = Signup::binding();
+//!     let is_valid = Signup::is_valid(&data);
+//!     vstack((
+//!         form(&data),
+//!         button("Sign Up").disabled(is_valid.map(|valid| !valid)),
+//!     ))
+//! }
+//! ```
+//!
+//! Each validated field shows its error inline under the control, and
+//! [`FormValidation`] exposes per-field error signals plus an overall `is_valid` signal.
 
 use core::{
     error::Error,
@@ -7,9 +37,10 @@ use core::{
 };
 
 use alloc::string::{String, ToString};
-use nami::{Binding, SignalExt};
+use nami::{Binding, Computed, SignalExt};
 use regex::Regex;
-use waterui_core::View;
+use waterui_color::Destructive;
+use waterui_core::{Environment, Str, View, dynamic::watch};
 use waterui_layout::stack::vstack;
 use waterui_text::text;
 
@@ -86,6 +117,65 @@ pub trait Validator<T>: Clone + 'static {
     }
 }
 
+/// Reactive validation state of a form.
+///
+/// Implemented by `#[form]`; fields marked with `#[form(validate = ...)]` report
+/// errors, all other fields are always valid.
+pub trait FormValidation: Sized {
+    /// A struct with one `Computed<Option<Str>>` error signal per field.
+    type Errors;
+
+    /// Returns the error signal of every field.
+    fn errors(binding: &Binding<Self>) -> Self::Errors;
+
+    /// Returns a signal that is `true` while every field is valid.
+    ///
+    /// Bind a submit button's disabled state to its negation.
+    fn is_valid(binding: &Binding<Self>) -> Computed<bool>;
+}
+
+/// Returns a signal of the message `validate` reports for the current value, if any.
+pub fn field_error<T, E>(
+    value: &Binding<T>,
+    validate: impl Fn(&T) -> Result<(), E> + Clone + 'static,
+) -> Computed<Option<Str>>
+where
+    T: 'static,
+    E: Display,
+{
+    value
+        .clone()
+        .map(move |value| validate(&value).err().map(|e| Str::from(e.to_string())))
+        .computed()
+}
+
+/// A form control with its validation error shown underneath.
+///
+/// Generated by `#[form]` for fields marked with `#[form(validate = ...)]`.
+#[derive(Debug)]
+pub struct ValidatedField<V> {
+    view: V,
+    error: Computed<Option<Str>>,
+}
+
+impl<V> ValidatedField<V> {
+    /// Wraps `view`, showing the current message of `error` below it.
+    pub const fn new(view: V, error: Computed<Option<Str>>) -> Self {
+        Self { view, error }
+    }
+}
+
+impl<V: View> View for ValidatedField<V> {
+    fn body(self, _env: &Environment) -> impl View {
+        vstack((
+            self.view,
+            watch(self.error, |error| {
+                error.map(|message| text(message).caption().foreground(Destructive))
+            }),
+        ))
+    }
+}
+
 /// A view that combines a view with a validator.
 /// This struct holds a view and a validator, allowing the view's value
 /// to be validated
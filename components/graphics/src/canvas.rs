@@ -43,6 +43,10 @@ pub mod image;
 /// Text rendering support for Canvas.
 pub mod text;
 
+/// SVG images shown as views.
+#[cfg(feature = "svg")]
+pub mod svg;
+
 pub use path::Path;
 
 pub use state::{LineCap, LineJoin};
@@ -53,6 +57,9 @@ pub use gradient::{ConicGradient, LinearGradient, RadialGradient};
 
 pub use image::{CanvasImage, ImageError};
 
+#[cfg(feature = "svg")]
+pub use svg::Svg;
+
 #[cfg(feature = "canvas")]
 pub use text::{FontSpec, FontStyle, FontWeight, TextMetrics};
 
@@ -65,7 +72,7 @@ use vello::{kurbo, peniko};
 
 use crate::canvas::conversions::{point_to_kurbo, rect_to_kurbo, resolved_color_to_peniko};
 use crate::canvas::state::{DrawingState, FillStyle, StrokeStyle};
-use crate::{GpuContext, GpuFrame, GpuRenderer, GpuSurface};
+use crate::{GpuContext, GpuFrame, GpuRenderer, GpuSurface, SurfaceSizing};
 
 /// A canvas for 2D vector graphics rendering.
 ///
@@ -73,6 +80,7 @@ use crate::{GpuContext, GpuFrame, GpuRenderer, GpuSurface};
 /// [`DrawingContext`] to draw shapes, paths, and text.
 pub struct Canvas {
     draw_fn: Box<dyn FnMut(&mut DrawingContext) + Send>,
+    sizing: SurfaceSizing,
 }
 
 impl core::fmt::Debug for Canvas {
@@ -102,13 +110,41 @@ impl Canvas {
     {
         Self {
             draw_fn: Box::new(draw),
+            sizing: SurfaceSizing::Fill,
         }
     }
+
+    /// Keeps `aspect_ratio` (width divided by height) within the proposed size.
+    ///
+    /// See [`GpuSurface::fit_content`].
+    #[must_use]
+    pub const fn fit_content(mut self, aspect_ratio: f32) -> Self {
+        self.sizing = SurfaceSizing::FitContent { aspect_ratio };
+        self
+    }
+
+    /// Reports `width` by `height` points as the canvas's size.
+    ///
+    /// See [`GpuSurface::preferred_size`].
+    #[must_use]
+    pub const fn preferred_size(mut self, width: f32, height: f32) -> Self {
+        self.sizing = SurfaceSizing::Preferred { width, height };
+        self
+    }
+
+    /// Sets how the canvas sizes itself in the layout pass.
+    ///
+    /// See [`GpuSurface::sizing`].
+    #[must_use]
+    pub const fn sizing(mut self, sizing: SurfaceSizing) -> Self {
+        self.sizing = sizing;
+        self
+    }
 }
 
 impl waterui_core::View for Canvas {
     fn body(self, _env: &waterui_core::Environment) -> impl waterui_core::View {
-        GpuSurface::new(CanvasRenderer::new(self.draw_fn))
+        GpuSurface::new(CanvasRenderer::new(self.draw_fn)).sizing(self.sizing)
     }
 }
 
@@ -609,8 +645,8 @@ impl DrawingContext<'_> {
     /// ```
     pub fn draw_image_scaled(&mut self, image: &CanvasImage, dest: Rect) {
         // Calculate transform to scale image to destination rectangle
-        let scale_x = f64::from(dest.size().width) / f64::from(image.width());
-        let scale_y = f64::from(dest.size().height) / f64::from(image.height());
+        let scale_x = f64::from(dest.size().width) / f64::from(image.size().width);
+        let scale_y = f64::from(dest.size().height) / f64::from(image.size().height);
 
         // Create transform: translate to dest position, then scale
         let image_transform =
@@ -620,11 +656,7 @@ impl DrawingContext<'_> {
         // Compose with current transform
         let final_transform = self.current_state.transform * image_transform;
 
-        // Wrap ImageData in ImageBrush
-        let image_brush = peniko::ImageBrush::new(image.inner().clone());
-
-        // Draw image using vello
-        self.scene.draw_image(&image_brush, final_transform);
+        image.draw(self.scene, final_transform);
     }
 
     /// Draws a sub-rectangle of an image, scaled to fit the destination.
@@ -675,10 +707,7 @@ impl DrawingContext<'_> {
         self.scene
             .push_clip_layer(self.current_state.transform, &clip_rect);
 
-        // Wrap ImageData in ImageBrush
-        let image_brush = peniko::ImageBrush::new(image.inner().clone());
-
-        self.scene.draw_image(&image_brush, final_transform);
+        image.draw(self.scene, final_transform);
 
         self.scene.pop_layer();
     }
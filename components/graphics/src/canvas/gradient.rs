@@ -3,7 +3,7 @@
 //! This module provides HTML5 Canvas-style gradient builders that use
 //! WaterUI's native `ResolvedColor` type.
 
-use waterui_color::ResolvedColor;
+use waterui_color::{ResolvedColor, ResolvedGradientStop};
 use waterui_core::layout::Point;
 
 // Internal imports for rendering
@@ -33,6 +33,12 @@ impl ColorStop {
     }
 }
 
+impl From<ResolvedGradientStop> for ColorStop {
+    fn from(stop: ResolvedGradientStop) -> Self {
+        Self::new(stop.offset, stop.color)
+    }
+}
+
 /// Linear gradient builder.
 ///
 /// Creates a gradient that transitions colors along a straight line
@@ -1,16 +1,20 @@
 //! Image loading and handling for Canvas.
 //!
-//! This module provides image loading from various sources (raw pixels, PNG, JPEG)
-//! for use with the Canvas drawing API.
+//! This module provides image loading from various sources (raw pixels, PNG, JPEG,
+//! SVG) for use with the Canvas drawing API.
+
+use alloc::{sync::Arc, vec::Vec};
 
 use waterui_core::layout::Size;
 
 // Internal imports for rendering
-use vello::peniko;
+use vello::{kurbo, peniko};
 
 /// An image that can be drawn on the canvas.
 ///
 /// Images can be created from raw RGBA pixels or decoded from PNG/JPEG bytes.
+/// SVG images (feature `svg`) stay vector graphics, so they are sharp at any
+/// scale.
 ///
 /// # Example
 ///
@@ -25,10 +29,16 @@ use vello::peniko;
 /// ctx.draw_image_scaled(&image, Rect::new(Point::ZERO, Size::new(200.0, 150.0)));
 /// ```
 pub struct CanvasImage {
-    /// Internal peniko image (not exposed to users)
-    image: peniko::ImageData,
-    width: u32,
-    height: u32,
+    content: Content,
+    size: Size,
+}
+
+/// What an image draws.
+enum Content {
+    /// Decoded pixels.
+    Raster(peniko::ImageData),
+    /// A scene of vector paths, in the image's own units.
+    Vector(Arc<vello::Scene>),
 }
 
 impl CanvasImage {
@@ -59,11 +69,7 @@ impl CanvasImage {
             height,
         };
 
-        Ok(Self {
-            image,
-            width,
-            height,
-        })
+        Ok(Self::raster(image))
     }
 
     /// Creates an image by decoding PNG or JPEG bytes.
@@ -74,68 +80,121 @@ impl CanvasImage {
     /// # Errors
     /// Returns an error if the image format is unsupported or decoding fails.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, ImageError> {
-        // Decode image using the image crate
-        let img = image::load_from_memory(bytes).map_err(ImageError::DecodeError)?;
-
-        // Convert to RGBA8
-        let rgba = img.to_rgba8();
-        let width = rgba.width();
-        let height = rgba.height();
-        let pixels = rgba.into_raw();
+        decode_raster(bytes).map(Self::raster)
+    }
 
-        // Create peniko image
-        let image = peniko::ImageData {
-            data: peniko::Blob::from(pixels),
-            format: peniko::ImageFormat::Rgba8,
-            alpha_type: peniko::ImageAlphaType::Alpha,
-            width,
-            height,
-        };
+    /// Decodes an image from PNG or JPEG bytes on the media decode pool.
+    ///
+    /// Unlike [`from_bytes`](Self::from_bytes), this keeps decoding off the
+    /// main thread, so it can be awaited while loading images for a scrolling
+    /// canvas. Dropping the future before decoding starts cancels it.
+    ///
+    /// # Errors
+    /// Returns an error if the image format is unsupported or decoding fails.
+    pub async fn decode(bytes: Vec<u8>) -> Result<Self, ImageError> {
+        waterui_media::decode::decode(move || decode_raster(&bytes))
+            .await
+            .map(Self::raster)
+    }
 
+    /// Creates an image by parsing SVG bytes.
+    ///
+    /// The image keeps the SVG's paths, so it stays sharp however it is
+    /// scaled. Its size is the SVG's `width` and `height`, or its `viewBox`.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid SVG document.
+    #[cfg(feature = "svg")]
+    pub fn from_svg(bytes: &[u8]) -> Result<Self, ImageError> {
+        use vello_svg::usvg;
+
+        let tree = usvg::Tree::from_data(bytes, &usvg::Options::default())
+            .map_err(ImageError::SvgError)?;
+        let size = tree.size();
         Ok(Self {
-            image,
-            width,
-            height,
+            content: Content::Vector(Arc::new(vello_svg::render_tree(&tree))),
+            size: Size::new(size.width(), size.height()),
         })
     }
 
-    /// Returns the width of the image in pixels.
+    #[allow(clippy::cast_precision_loss)]
+    fn raster(image: peniko::ImageData) -> Self {
+        let size = Size::new(image.width as f32, image.height as f32);
+        Self {
+            content: Content::Raster(image),
+            size,
+        }
+    }
+
+    /// Returns the width of the image in pixels, rounded up for SVG images.
     #[must_use]
-    pub const fn width(&self) -> u32 {
-        self.width
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn width(&self) -> u32 {
+        self.size.width.ceil() as u32
     }
 
-    /// Returns the height of the image in pixels.
+    /// Returns the height of the image in pixels, rounded up for SVG images.
     #[must_use]
-    pub const fn height(&self) -> u32 {
-        self.height
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn height(&self) -> u32 {
+        self.size.height.ceil() as u32
     }
 
     /// Returns the size of the image.
     #[must_use]
-    #[allow(clippy::cast_precision_loss)]
     pub const fn size(&self) -> Size {
-        Size::new(self.width as f32, self.height as f32)
+        self.size
     }
 
-    /// Returns a reference to the internal peniko `ImageData`.
+    /// Returns true if the image is made of vector paths.
+    #[must_use]
+    pub const fn is_vector(&self) -> bool {
+        matches!(self.content, Content::Vector(_))
+    }
+
+    /// Draws the image into `scene`, mapping its size in its own units
+    /// through `transform`.
     ///
     /// This is used internally by the canvas renderer.
-    #[must_use]
-    pub(crate) const fn inner(&self) -> &peniko::ImageData {
-        &self.image
+    pub(crate) fn draw(&self, scene: &mut vello::Scene, transform: kurbo::Affine) {
+        match &self.content {
+            Content::Raster(image) => {
+                scene.draw_image(&peniko::ImageBrush::new(image.clone()), transform);
+            }
+            Content::Vector(vector) => scene.append(vector, Some(transform)),
+        }
     }
 }
 
 impl core::fmt::Debug for CanvasImage {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("CanvasImage")
-            .field("width", &self.width)
-            .field("height", &self.height)
+            .field("size", &self.size)
+            .field("vector", &self.is_vector())
             .finish_non_exhaustive()
     }
 }
 
+/// Decodes PNG or JPEG bytes into RGBA pixels.
+fn decode_raster(bytes: &[u8]) -> Result<peniko::ImageData, ImageError> {
+    // Decode image using the image crate
+    let img = image::load_from_memory(bytes).map_err(ImageError::DecodeError)?;
+
+    // Convert to RGBA8
+    let rgba = img.to_rgba8();
+    let width = rgba.width();
+    let height = rgba.height();
+    let pixels = rgba.into_raw();
+
+    Ok(peniko::ImageData {
+        data: peniko::Blob::from(pixels),
+        format: peniko::ImageFormat::Rgba8,
+        alpha_type: peniko::ImageAlphaType::Alpha,
+        width,
+        height,
+    })
+}
+
 /// Errors that can occur when loading or creating images.
 #[derive(Debug)]
 pub enum ImageError {
@@ -148,6 +207,9 @@ pub enum ImageError {
     },
     /// Failed to decode image from bytes.
     DecodeError(image::ImageError),
+    /// Failed to parse an SVG document.
+    #[cfg(feature = "svg")]
+    SvgError(vello_svg::usvg::Error),
 }
 
 impl core::fmt::Display for ImageError {
@@ -160,6 +222,8 @@ impl core::fmt::Display for ImageError {
                 )
             }
             Self::DecodeError(err) => write!(f, "Failed to decode image: {err}"),
+            #[cfg(feature = "svg")]
+            Self::SvgError(err) => write!(f, "Failed to parse SVG: {err}"),
         }
     }
 }
@@ -169,6 +233,8 @@ impl std::error::Error for ImageError {
         match self {
             Self::InvalidPixelData { .. } => None,
             Self::DecodeError(err) => Some(err),
+            #[cfg(feature = "svg")]
+            Self::SvgError(err) => Some(err),
         }
     }
 }
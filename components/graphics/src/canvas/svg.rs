@@ -0,0 +1,112 @@
+//! SVG images shown as views.
+//!
+//! [`Svg`] draws a [`CanvasImage`] parsed from an SVG document onto a
+//! [`Canvas`]. By default it asks for the document's own size, shrinking
+//! when less space is proposed; [`Svg::resizable`] makes it fill the
+//! proposed space instead. Either way the image keeps its aspect ratio and is
+//! redrawn from its paths, so it stays sharp at any size:
+//!
+//! ```ignore
+//! let logo = Svg::new(include_bytes!("logo.svg"))?;
+//! vstack((logo.resizable(), text("WaterUI")))
+//! ```
+
+use alloc::sync::Arc;
+
+use waterui_core::{
+    Environment, View,
+    layout::{Point, Rect, Size},
+};
+
+use super::{Canvas, CanvasImage, ImageError};
+use crate::SurfaceSizing;
+
+/// A view showing an SVG image.
+#[derive(Debug)]
+pub struct Svg {
+    image: Arc<CanvasImage>,
+    sizing: SurfaceSizing,
+}
+
+impl Svg {
+    /// Creates a view by parsing SVG bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes are not a valid SVG document.
+    pub fn new(bytes: &[u8]) -> Result<Self, ImageError> {
+        CanvasImage::from_svg(bytes).map(Self::from_image)
+    }
+
+    /// Creates a view showing `image` at its own size.
+    #[must_use]
+    pub fn from_image(image: CanvasImage) -> Self {
+        let size = image.size();
+        Self {
+            image: Arc::new(image),
+            sizing: SurfaceSizing::Preferred {
+                width: size.width,
+                height: size.height,
+            },
+        }
+    }
+
+    /// Fills as much of the proposed space as the image's aspect ratio allows.
+    #[must_use]
+    pub fn resizable(mut self) -> Self {
+        let size = self.image.size();
+        if size.height > 0.0 {
+            self.sizing = SurfaceSizing::FitContent {
+                aspect_ratio: size.width / size.height,
+            };
+        }
+        self
+    }
+
+    /// Asks for `width` by `height` points instead of the image's own size.
+    #[must_use]
+    pub const fn frame(mut self, width: f32, height: f32) -> Self {
+        self.sizing = SurfaceSizing::Preferred { width, height };
+        self
+    }
+}
+
+/// Returns the largest rectangle with the aspect ratio of `image` centered in `bounds`.
+fn fitted(image: Size, bounds: Size) -> Rect {
+    if image.width <= 0.0 || image.height <= 0.0 {
+        return Rect::new(Point::zero(), Size::zero());
+    }
+    let scale = (bounds.width / image.width).min(bounds.height / image.height);
+    let size = Size::new(image.width * scale, image.height * scale);
+    let origin = Point::new(
+        (bounds.width - size.width) / 2.0,
+        (bounds.height - size.height) / 2.0,
+    );
+    Rect::new(origin, size)
+}
+
+impl View for Svg {
+    fn body(self, _env: &Environment) -> impl View {
+        let image = self.image;
+        Canvas::new(move |ctx| {
+            let dest = fitted(image.size(), ctx.size());
+            ctx.draw_image_scaled(&image, dest);
+        })
+        .sizing(self.sizing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fitted_keeps_aspect_ratio_centered() {
+        let rect = fitted(Size::new(20.0, 10.0), Size::new(100.0, 100.0));
+        assert_eq!(
+            rect,
+            Rect::new(Point::new(0.0, 25.0), Size::new(100.0, 50.0))
+        );
+        let empty = fitted(Size::zero(), Size::new(100.0, 100.0));
+        assert_eq!(empty.size(), Size::zero());
+    }
+}
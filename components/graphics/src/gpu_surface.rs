@@ -2,12 +2,26 @@
 //!
 //! This module provides `GpuSurface`, a raw view that enables direct wgpu access
 //! for custom GPU rendering at up to 120fps+.
+//!
+//! Surfaces fill the space proposed by their parent unless given a
+//! [`SurfaceSizing`], which lets them size themselves from an aspect ratio or a
+//! preferred size and sit inside stacks and grids like any other view.
+//!
+//! Frames can also be captured with [`GpuSurface::snapshot`], which renders the
+//! next frame into an offscreen texture and reads it back as a [`GpuSnapshot`].
 
 extern crate alloc;
 
 use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::future::Future;
+use std::sync::Mutex;
 
-use waterui_core::{layout::StretchAxis, raw_view};
+use waterui_core::{
+    Environment, Native, NativeView, View,
+    layout::{ProposalSize, Size, StretchAxis},
+};
 
 /// Picks the best surface format for a [`GpuSurface`].
 ///
@@ -167,7 +181,8 @@ pub trait GpuRenderer: Send + 'static {
 /// # Layout Behavior
 ///
 /// - Stretches in both directions by default (`StretchAxis::Both`)
-/// - Control size using `.frame()` modifier externally
+/// - [`GpuSurface::fit_content`] keeps an aspect ratio within the proposed size
+/// - [`GpuSurface::preferred_size`] reports a size like content-sized views do
 /// - Current size is provided via `GpuFrame.width/height` during rendering
 ///
 /// # Example
@@ -176,13 +191,19 @@ pub trait GpuRenderer: Send + 'static {
 /// // Fill available space
 /// GpuSurface::new(MyRenderer::default())
 ///
-/// // Fixed size
-/// GpuSurface::new(MyRenderer::default())
-///     .frame(width: 400.0, height: 300.0)
+/// // As wide as the stack, 16:9
+/// vstack((
+///     text("Preview"),
+///     GpuSurface::new(MyRenderer::default()).fit_content(16.0 / 9.0),
+/// ))
 /// ```
 pub struct GpuSurface {
     /// The renderer that handles GPU drawing.
     pub renderer: Box<dyn GpuRenderer>,
+    /// Pending [`GpuSurface::snapshot`] requests, fulfilled by the backend.
+    pub snapshots: SnapshotRequests,
+    /// How the surface is sized by the layout system.
+    pub sizing: SurfaceSizing,
 }
 
 impl core::fmt::Debug for GpuSurface {
@@ -207,9 +228,369 @@ impl GpuSurface {
     pub fn new<R: GpuRenderer>(renderer: R) -> Self {
         Self {
             renderer: Box::new(renderer),
+            snapshots: SnapshotRequests::default(),
+            sizing: SurfaceSizing::Fill,
         }
     }
+
+    /// Sizes the surface to the largest size with `aspect_ratio` (width
+    /// divided by height) that fits the proposed size.
+    ///
+    /// When only one dimension is proposed, the other one follows from the
+    /// aspect ratio, so the surface can be placed in a stack without a frame.
+    #[must_use]
+    pub const fn fit_content(mut self, aspect_ratio: f32) -> Self {
+        self.sizing = SurfaceSizing::FitContent { aspect_ratio };
+        self
+    }
+
+    /// Reports `width` by `height` points as the surface's size, shrunk to
+    /// the proposed size when that is smaller.
+    #[must_use]
+    pub const fn preferred_size(mut self, width: f32, height: f32) -> Self {
+        self.sizing = SurfaceSizing::Preferred { width, height };
+        self
+    }
+
+    /// Sets how the surface is sized by the layout system.
+    #[must_use]
+    pub const fn sizing(mut self, sizing: SurfaceSizing) -> Self {
+        self.sizing = sizing;
+        self
+    }
+
+    /// Captures the next rendered frame.
+    ///
+    /// The backend renders the frame a second time into an offscreen texture and
+    /// reads it back, so the result does not depend on platform screenshot APIs.
+    /// The snapshot keeps the surface format: RGBA8/BGRA8 on SDR surfaces, or
+    /// linear `Rgba16Float` on HDR ones. Use [`GpuSnapshot::to_rgba8`] to get a
+    /// displayable image either way.
+    ///
+    /// Resolves to `None` if the surface is destroyed before a frame is captured.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let surface = GpuSurface::new(MyRenderer::default());
+    /// let capture = surface.snapshot();
+    /// // ... show `surface` ...
+    /// if let Some(image) = capture.await.and_then(|s| s.to_rgba8()) {
+    ///     image.save("frame.png")?;
+    /// }
+    /// ```
+    pub fn snapshot(&self) -> impl Future<Output = Option<GpuSnapshot>> + use<> {
+        self.snapshots.request()
+    }
+}
+
+/// How a [`GpuSurface`] takes part in layout.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SurfaceSizing {
+    /// Fills all the space proposed by the parent.
+    #[default]
+    Fill,
+    /// Takes the largest size with this aspect ratio that fits the proposal.
+    FitContent {
+        /// Width divided by height.
+        aspect_ratio: f32,
+    },
+    /// Reports a preferred size, shrunk to fit the proposal.
+    Preferred {
+        /// Preferred width in points.
+        width: f32,
+        /// Preferred height in points.
+        height: f32,
+    },
+}
+
+impl SurfaceSizing {
+    /// Returns the axes the surface stretches along.
+    #[must_use]
+    pub const fn stretch_axis(&self) -> StretchAxis {
+        match self {
+            Self::Fill => StretchAxis::Both,
+            Self::FitContent { .. } | Self::Preferred { .. } => StretchAxis::None,
+        }
+    }
+
+    /// Returns the size of the surface for `proposal`.
+    ///
+    /// Unspecified and infinite dimensions count as not proposed.
+    #[must_use]
+    pub fn size_that_fits(&self, proposal: ProposalSize) -> Size {
+        let width = proposal.width.filter(|width| width.is_finite());
+        let height = proposal.height.filter(|height| height.is_finite());
+        match *self {
+            Self::Fill => Size::new(width.unwrap_or(0.0), height.unwrap_or(0.0)),
+            Self::FitContent { aspect_ratio } => {
+                if !(aspect_ratio.is_finite() && aspect_ratio > 0.0) {
+                    return Size::zero();
+                }
+                let width = match (width, height) {
+                    (Some(width), Some(height)) => width.min(height * aspect_ratio),
+                    (Some(width), None) => width,
+                    (None, Some(height)) => height * aspect_ratio,
+                    (None, None) => return Size::zero(),
+                };
+                Size::new(width, width / aspect_ratio)
+            }
+            Self::Preferred {
+                width: preferred_width,
+                height: preferred_height,
+            } => Size::new(
+                width.map_or(preferred_width, |width| width.min(preferred_width)),
+                height.map_or(preferred_height, |height| height.min(preferred_height)),
+            ),
+        }
+    }
+}
+
+/// Snapshot requests shared between a [`GpuSurface`] and the backend rendering it.
+///
+/// Cloning yields another handle to the same queue.
+#[derive(Clone, Default)]
+pub struct SnapshotRequests(Arc<Mutex<Vec<async_oneshot::Sender<GpuSnapshot>>>>);
+
+impl core::fmt::Debug for SnapshotRequests {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SnapshotRequests")
+            .field("pending", &self.is_pending())
+            .finish()
+    }
+}
+
+impl SnapshotRequests {
+    fn queue(&self) -> std::sync::MutexGuard<'_, Vec<async_oneshot::Sender<GpuSnapshot>>> {
+        self.0
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn request(&self) -> impl Future<Output = Option<GpuSnapshot>> + use<> {
+        let (sender, receiver) = async_oneshot::oneshot();
+        self.queue().push(sender);
+        async move { receiver.await.ok() }
+    }
+
+    /// Returns `true` if at least one caller is waiting for a snapshot.
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        !self.queue().is_empty()
+    }
+
+    /// Delivers `snapshot` to every pending request.
+    pub fn fulfill(&self, snapshot: &GpuSnapshot) {
+        for mut sender in self.queue().drain(..) {
+            let _ = sender.send(snapshot.clone());
+        }
+    }
+
+    /// Drops every pending request, resolving them to `None`.
+    pub fn cancel(&self) {
+        self.queue().clear();
+    }
+}
+
+/// A frame captured from a [`GpuSurface`].
+///
+/// Pixels are tightly packed rows in [`GpuSnapshot::format`], top row first.
+#[derive(Clone, PartialEq, Eq)]
+pub struct GpuSnapshot {
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// Pixel format of `data`, the surface format the frame was rendered in.
+    pub format: wgpu::TextureFormat,
+    /// Raw pixel data, `width * height * bytes_per_pixel` bytes.
+    pub data: Vec<u8>,
+}
+
+impl core::fmt::Debug for GpuSnapshot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GpuSnapshot")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GpuSnapshot {
+    /// Returns `true` if the snapshot holds HDR (floating-point) pixels.
+    #[must_use]
+    pub const fn is_hdr(&self) -> bool {
+        matches!(self.format, wgpu::TextureFormat::Rgba16Float)
+    }
+
+    /// Converts the snapshot to an 8-bit sRGB RGBA image.
+    ///
+    /// HDR pixels are clamped to the SDR range and sRGB-encoded. Returns `None`
+    /// for formats other than RGBA8/BGRA8 (unorm or sRGB) and `Rgba16Float`, or
+    /// if `data` has the wrong length.
+    #[must_use]
+    pub fn to_rgba8(&self) -> Option<image::RgbaImage> {
+        use wgpu::TextureFormat as F;
+
+        let pixels: Vec<u8> = match self.format {
+            F::Rgba8Unorm | F::Rgba8UnormSrgb => self.data.clone(),
+            F::Bgra8Unorm | F::Bgra8UnormSrgb => self
+                .data
+                .chunks_exact(4)
+                .flat_map(|px| [px[2], px[1], px[0], px[3]])
+                .collect(),
+            F::Rgba16Float => self
+                .data
+                .chunks_exact(8)
+                .flat_map(|px| {
+                    let channel = |i: usize| f16_to_f32(u16::from_le_bytes([px[i], px[i + 1]]));
+                    [
+                        linear_to_srgb8(channel(0)),
+                        linear_to_srgb8(channel(2)),
+                        linear_to_srgb8(channel(4)),
+                        unorm8(channel(6)),
+                    ]
+                })
+                .collect(),
+            _ => return None,
+        };
+        image::RgbaImage::from_raw(self.width, self.height, pixels)
+    }
+}
+
+/// Decodes an IEEE 754 half-precision float.
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = if bits & 0x8000 == 0 { 1.0 } else { -1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1f);
+    let mantissa = bits & 0x3ff;
+    match (exponent, mantissa) {
+        (0, _) => sign * f32::from(mantissa) * 2f32.powi(-24),
+        (0x1f, 0) => sign * f32::INFINITY,
+        (0x1f, _) => f32::NAN,
+        _ => sign * (1.0 + f32::from(mantissa) / 1024.0) * 2f32.powi(exponent - 15),
+    }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn unorm8(value: f32) -> u8 {
+    // NaN clamps to 0 because `f32 as u8` saturates.
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn linear_to_srgb8(value: f32) -> u8 {
+    let value = value.clamp(0.0, 1.0);
+    let encoded = if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055f32.mul_add(value.powf(1.0 / 2.4), -0.055)
+    };
+    unorm8(encoded)
 }
 
 // Stretches in both directions by default, like SwiftUI's Color
-raw_view!(GpuSurface, StretchAxis::Both);
+impl NativeView for GpuSurface {
+    fn stretch_axis(&self) -> StretchAxis {
+        self.sizing.stretch_axis()
+    }
+}
+
+impl View for GpuSurface {
+    fn body(self, _env: &Environment) -> impl View {
+        Native::new(self)
+    }
+
+    fn stretch_axis(&self) -> StretchAxis {
+        self.sizing.stretch_axis()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_content_keeps_aspect_ratio() {
+        let sizing = SurfaceSizing::FitContent { aspect_ratio: 2.0 };
+        assert_eq!(
+            sizing.size_that_fits(ProposalSize::new(300.0, None)),
+            Size::new(300.0, 150.0)
+        );
+        assert_eq!(
+            sizing.size_that_fits(ProposalSize::new(300.0, 100.0)),
+            Size::new(200.0, 100.0)
+        );
+        assert_eq!(
+            sizing.size_that_fits(ProposalSize::new(f32::INFINITY, 50.0)),
+            Size::new(100.0, 50.0)
+        );
+        assert_eq!(
+            sizing.size_that_fits(ProposalSize::UNSPECIFIED),
+            Size::zero()
+        );
+    }
+
+    #[test]
+    fn test_preferred_size_shrinks_to_proposal() {
+        let sizing = SurfaceSizing::Preferred {
+            width: 200.0,
+            height: 100.0,
+        };
+        assert_eq!(
+            sizing.size_that_fits(ProposalSize::UNSPECIFIED),
+            Size::new(200.0, 100.0)
+        );
+        assert_eq!(
+            sizing.size_that_fits(ProposalSize::new(150.0, 400.0)),
+            Size::new(150.0, 100.0)
+        );
+    }
+
+    #[test]
+    fn test_bgra_snapshot_is_swizzled() {
+        let snapshot = GpuSnapshot {
+            width: 1,
+            height: 1,
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            data: alloc::vec![1, 2, 3, 4],
+        };
+        assert_eq!(snapshot.to_rgba8().unwrap().into_raw(), [3, 2, 1, 4]);
+    }
+
+    #[test]
+    fn test_hdr_snapshot_is_clamped_and_encoded() {
+        // R = 0.0, G = 0.5, B = 2.0 (out of SDR range), A = 1.0 as little-endian f16.
+        let halves: [u16; 4] = [0x0000, 0x3800, 0x4000, 0x3c00];
+        let snapshot = GpuSnapshot {
+            width: 1,
+            height: 1,
+            format: wgpu::TextureFormat::Rgba16Float,
+            data: halves.iter().flat_map(|h| h.to_le_bytes()).collect(),
+        };
+        assert!(snapshot.is_hdr());
+        assert_eq!(snapshot.to_rgba8().unwrap().into_raw(), [0, 188, 255, 255]);
+    }
+
+    #[test]
+    fn test_snapshot_requests_resolve() {
+        let requests = SnapshotRequests::default();
+        let first = requests.request();
+        let second = requests.request();
+        assert!(requests.is_pending());
+
+        let snapshot = GpuSnapshot {
+            width: 1,
+            height: 1,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            data: alloc::vec![0; 4],
+        };
+        requests.fulfill(&snapshot);
+        assert!(!requests.is_pending());
+        assert_eq!(pollster::block_on(first), Some(snapshot.clone()));
+        assert_eq!(pollster::block_on(second), Some(snapshot));
+
+        let cancelled = requests.request();
+        requests.cancel();
+        assert_eq!(pollster::block_on(cancelled), None);
+    }
+}
@@ -15,11 +15,15 @@ pub mod shader_surface;
 // #[cfg(feature = "canvas")]
 //pub mod canvas;
 //pub use canvas::{Canvas, DrawingContext};
+// #[cfg(feature = "svg")]
+// pub use canvas::Svg;
 // Canvas is not available on main branch yet
 
 // Re-export key types for user convenience.
 #[cfg(feature = "wgpu")]
-pub use gpu_surface::{GpuContext, GpuFrame, GpuRenderer, GpuSurface};
+pub use gpu_surface::{
+    GpuContext, GpuFrame, GpuRenderer, GpuSnapshot, GpuSurface, SnapshotRequests, SurfaceSizing,
+};
 
 #[cfg(feature = "wgpu")]
 pub use shader_surface::ShaderSurface;
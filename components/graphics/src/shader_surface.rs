@@ -85,6 +85,24 @@ impl ShaderSurface {
         }
     }
 
+    /// Keeps `aspect_ratio` (width divided by height) within the proposed size.
+    ///
+    /// See [`GpuSurface::fit_content`].
+    #[must_use]
+    pub fn fit_content(mut self, aspect_ratio: f32) -> Self {
+        self.inner = self.inner.fit_content(aspect_ratio);
+        self
+    }
+
+    /// Reports `width` by `height` points as the surface's size.
+    ///
+    /// See [`GpuSurface::preferred_size`].
+    #[must_use]
+    pub fn preferred_size(mut self, width: f32, height: f32) -> Self {
+        self.inner = self.inner.preferred_size(width, height);
+        self
+    }
+
     /// Consumes the `ShaderSurface` and returns the inner `GpuSurface`.
     #[must_use]
     pub fn into_inner(self) -> GpuSurface {
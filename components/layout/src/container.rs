@@ -1,16 +1,25 @@
 //! View wrapper that lets arbitrary [`Layout`] implementations
 //! participate in the `WaterUI` view tree.
+//!
+//! For very long collections, [`LazyVStack`] skips the layout protocol and
+//! lets the backend build only the rows it shows through a [`LazyWindow`].
 
-use core::fmt::Debug;
+use core::{cell::RefCell, fmt::Debug, ops::Range};
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{
+    boxed::Box,
+    rc::{Rc, Weak},
+    vec::Vec,
+};
+use nami::{collection::Collection, watcher::BoxWatcherGuard};
 use waterui_core::{
-    AnyView, Native, NativeView, View,
+    AnyView, Environment, Native, NativeView, View,
+    id::Identifiable,
     view::TupleViews,
-    views::{AnyViews, Views, ViewsExt},
+    views::{AnyViews, ForEach, Views, ViewsExt},
 };
 
-use crate::{Layout, StretchAxis};
+use crate::{Layout, Spacing, StretchAxis};
 
 /// A view wrapper that executes an arbitrary [`Layout`]
 /// implementation.
@@ -106,3 +115,258 @@ impl View for LazyContainer {
         self.layout.stretch_axis()
     }
 }
+
+/// Identifier of a row in a [`LazyWindow`].
+pub type RowId = <AnyViews<AnyView> as Views>::Id;
+
+/// A vertical stack that only builds the views of the rows on screen.
+///
+/// Unlike [`LazyContainer`], which hands every row to the backend's layout, a
+/// `LazyVStack` scrolls by itself and lets the backend request the rows it is
+/// about to show through its [`LazyWindow`]. Rows scrolled out of view are
+/// dropped and rebuilt when they come back, so feeds with thousands of items
+/// stay cheap. Rows follow the collection: inserting, removing or replacing
+/// items rebuilds only the affected visible rows.
+///
+/// ```ignore
+/// let posts: nami::collection::List<Post> = load_posts();
+/// LazyVStack::for_each(posts, |post| text(post.title)).spacing(Spacing::M)
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct LazyVStack {
+    spacing: Spacing,
+    contents: AnyViews<AnyView>,
+}
+
+impl LazyVStack {
+    /// Creates a lazy stack showing the views of `contents`.
+    pub fn new<V: View>(contents: impl Views<View = V> + 'static) -> Self {
+        Self {
+            spacing: Spacing::Points(0.0),
+            contents: AnyViews::new(contents.map(|v| AnyView::new(v))),
+        }
+    }
+
+    /// Creates a lazy stack by generating a view for each item of `data`.
+    pub fn for_each<C, F, V>(data: C, generator: F) -> Self
+    where
+        C: Collection + 'static,
+        C::Item: Identifiable,
+        F: 'static + Fn(C::Item) -> V,
+        V: View,
+    {
+        Self::new(ForEach::new(data, generator))
+    }
+
+    /// Sets the spacing between rows, either in points or as a theme-relative
+    /// [`Spacing`] token.
+    pub fn spacing(mut self, spacing: impl Into<Spacing>) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+}
+
+impl View for LazyVStack {
+    fn body(self, env: &Environment) -> impl View {
+        Native::new(LazyList {
+            spacing: self.spacing.resolve(env),
+            window: LazyWindow::new(self.contents),
+        })
+    }
+}
+
+/// Configuration of a [`LazyVStack`], read by backends.
+#[derive(Debug)]
+pub struct LazyList {
+    /// Spacing between rows, in points.
+    pub spacing: f32,
+    /// Builds the rows on demand.
+    pub window: LazyWindow,
+}
+
+impl NativeView for LazyList {
+    fn stretch_axis(&self) -> StretchAxis {
+        StretchAxis::Both
+    }
+}
+
+/// Builds the rows of a [`LazyVStack`] for the range shown by the backend.
+///
+/// Backends call [`request`](Self::request) with the rows about to become
+/// visible and drop the views of rows that left the screen. Once rows were
+/// requested, changes to them or to the number of rows are reported to the
+/// callback set with [`on_invalidate`](Self::on_invalidate).
+#[derive(Clone)]
+pub struct LazyWindow(Rc<Window>);
+
+struct Window {
+    contents: AnyViews<AnyView>,
+    state: RefCell<WindowState>,
+}
+
+type InvalidateHandler = Rc<dyn Fn(usize, Range<usize>)>;
+
+#[derive(Default)]
+struct WindowState {
+    /// Rows built by the last request.
+    range: Range<usize>,
+    /// Identifiers of those rows, to find which ones changed.
+    ids: Vec<RowId>,
+    len: usize,
+    guard: Option<BoxWatcherGuard>,
+    on_invalidate: Option<InvalidateHandler>,
+}
+
+impl Debug for LazyWindow {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LazyWindow")
+            .field("len", &self.len())
+            .field("range", &self.0.state.borrow().range)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LazyWindow {
+    /// Creates a window over `contents` with no rows built yet.
+    #[must_use]
+    pub fn new(contents: AnyViews<AnyView>) -> Self {
+        Self(Rc::new(Window {
+            contents,
+            state: RefCell::default(),
+        }))
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.contents.len()
+    }
+
+    /// Returns `true` if there are no rows.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the identifier of the row at `index`, stable while the item is
+    /// in the collection.
+    #[must_use]
+    pub fn id(&self, index: usize) -> Option<RowId> {
+        self.0.contents.get_id(index)
+    }
+
+    /// Builds the views of the rows in `range`, clamped to the number of rows.
+    ///
+    /// Replaces the previous request: only rows in `range` are watched for
+    /// changes afterwards.
+    #[must_use]
+    pub fn request(&self, range: Range<usize>) -> Vec<AnyView> {
+        let contents = &self.0.contents;
+        let len = contents.len();
+        let range = range.start.min(len)..range.end.min(len);
+        let views = range
+            .clone()
+            .filter_map(|index| contents.get_view(index))
+            .collect();
+
+        // Watch from the first row to the end, so rows added after the window
+        // still update the row count.
+        let window = Rc::downgrade(&self.0);
+        let guard = contents.watch(range.start.., move |context| {
+            Window::changed(&window, context.into_value());
+        });
+
+        let mut state = self.0.state.borrow_mut();
+        state.ids = range
+            .clone()
+            .filter_map(|index| contents.get_id(index))
+            .collect();
+        state.range = range;
+        state.len = len;
+        state.guard = Some(guard);
+        views
+    }
+
+    /// Sets the callback told about changes to the requested rows.
+    ///
+    /// It receives the new number of rows and the range of rows to request
+    /// again; the range is empty if only rows after the window changed.
+    pub fn on_invalidate(&self, f: impl Fn(usize, Range<usize>) + 'static) {
+        self.0.state.borrow_mut().on_invalidate = Some(Rc::new(f));
+    }
+}
+
+impl Window {
+    /// Compares the rows from the window's start onward with the built ones.
+    fn changed(window: &Weak<Self>, rows: &[RowId]) {
+        let Some(window) = window.upgrade() else {
+            return;
+        };
+        let (len, invalidated, on_invalidate) = {
+            let mut state = window.state.borrow_mut();
+            let start = state.range.start;
+            let len = start + rows.len();
+            let ids = &rows[..state.ids.len().min(rows.len())];
+            let invalidated = invalidated_rows(start, &state.ids, ids);
+            if invalidated.is_empty() && len == state.len {
+                return;
+            }
+            state.ids = ids.to_vec();
+            state.range.end = start + ids.len();
+            state.len = len;
+            (len, invalidated, state.on_invalidate.clone())
+        };
+        if let Some(on_invalidate) = on_invalidate {
+            on_invalidate(len, invalidated);
+        }
+    }
+}
+
+/// Returns the rows that differ between `old` and `new`, both starting at row
+/// `start`.
+fn invalidated_rows<T: PartialEq>(start: usize, old: &[T], new: &[T]) -> Range<usize> {
+    let end = old.len().max(new.len());
+    let first = old
+        .iter()
+        .zip(new)
+        .position(|(old, new)| old != new)
+        .unwrap_or_else(|| old.len().min(new.len()));
+    if first == end {
+        return start..start;
+    }
+    let unchanged_tail = old
+        .iter()
+        .rev()
+        .zip(new.iter().rev())
+        .take_while(|(old, new)| old == new)
+        .count();
+    let last = if old.len() == new.len() {
+        end - unchanged_tail
+    } else {
+        end
+    };
+    start + first..start + last
+}
+
+#[cfg(test)]
+mod tests {
+    use super::invalidated_rows;
+
+    #[test]
+    fn unchanged_rows_are_not_invalidated() {
+        assert!(invalidated_rows(10, &[1, 2, 3], &[1, 2, 3]).is_empty());
+    }
+
+    #[test]
+    fn replaced_rows_are_invalidated() {
+        assert_eq!(invalidated_rows(10, &[1, 2, 3, 4], &[1, 5, 6, 4]), 11..13);
+    }
+
+    #[test]
+    fn insertions_and_removals_invalidate_up_to_the_window_end() {
+        assert_eq!(invalidated_rows(0, &[1, 2, 3], &[1, 9, 2]), 1..3);
+        assert_eq!(invalidated_rows(0, &[1, 2, 3], &[1, 3]), 1..3);
+        assert_eq!(invalidated_rows(5, &[1, 2], &[1, 2, 3]), 7..8);
+    }
+}
@@ -2,17 +2,26 @@
 
 use alloc::{vec, vec::Vec};
 use core::num::NonZeroUsize;
-use waterui_core::{AnyView, Environment, View, view::TupleViews};
+use nami::Binding;
+use waterui_core::{AnyView, Environment, Metadata, View, id::Identifiable, view::TupleViews};
 
 use crate::{
-    Layout, Point, ProposalSize, Rect, Size, SubView,
+    Layout, Point, ProposalSize, Rect, Reorderable, Size, SubView,
     container::FixedContainer,
     stack::{Alignment, HorizontalAlignment, VerticalAlignment},
 };
 
-/// Cached measurement for a child during layout
-struct ChildMeasurement {
-    size: Size,
+/// Where one child of a [`GridLayout`] sits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GridPlacement {
+    /// Row of the cell, counted from zero.
+    pub row: usize,
+    /// Leading column of the cell, counted from zero.
+    pub column: usize,
+    /// Number of columns the cell spans, at least one.
+    pub span: usize,
+    /// Alignment within the cell, overriding the grid's alignment.
+    pub alignment: Option<Alignment>,
 }
 
 /// The core layout engine for a `Grid`.
@@ -21,17 +30,95 @@ pub struct GridLayout {
     columns: NonZeroUsize,
     spacing: Size, // (horizontal, vertical)
     alignment: Alignment,
+    placements: Vec<GridPlacement>,
 }
 
 impl GridLayout {
     /// Creates a new `GridLayout` with the specified columns, spacing, and alignment.
+    ///
+    /// Children fill the grid row by row, one cell each, unless
+    /// [placements](Self::with_placements) are given.
     #[must_use]
     pub const fn new(columns: NonZeroUsize, spacing: Size, alignment: Alignment) -> Self {
         Self {
             columns,
             spacing,
             alignment,
+            placements: Vec::new(),
+        }
+    }
+
+    /// Places the children explicitly, in child order.
+    ///
+    /// Children without a placement continue row by row after the last row used.
+    #[must_use]
+    pub fn with_placements(mut self, placements: Vec<GridPlacement>) -> Self {
+        self.placements = placements;
+        self
+    }
+
+    /// Returns the placement of every child, clamped to the grid's columns.
+    fn placements(&self, count: usize) -> Vec<GridPlacement> {
+        let columns = self.columns.get();
+        let first_free_row = self
+            .placements
+            .iter()
+            .map(|placement| placement.row + 1)
+            .max()
+            .unwrap_or(0);
+        (0..count)
+            .map(|index| {
+                self.placements.get(index).map_or_else(
+                    || {
+                        let index = index - self.placements.len();
+                        GridPlacement {
+                            row: first_free_row + index / columns,
+                            column: index % columns,
+                            span: 1,
+                            alignment: None,
+                        }
+                    },
+                    |placement| {
+                        let column = placement.column.min(columns - 1);
+                        GridPlacement {
+                            column,
+                            span: placement.span.clamp(1, columns - column),
+                            ..*placement
+                        }
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the width of a cell spanning `span` columns.
+    #[allow(clippy::cast_precision_loss)]
+    fn span_width(&self, column_width: f32, span: usize) -> f32 {
+        column_width * span as f32 + self.spacing.width * (span - 1) as f32
+    }
+
+    /// Returns the width of a single column, given the grid's width.
+    #[allow(clippy::cast_precision_loss)]
+    fn column_width(&self, width: f32) -> f32 {
+        let num_columns = self.columns.get();
+        let total_spacing = self.spacing.width * (num_columns - 1) as f32;
+        ((width - total_spacing) / num_columns as f32).max(0.0)
+    }
+
+    /// Returns the height of every row: the tallest of its children's `heights`.
+    fn row_heights(placements: &[GridPlacement], heights: impl Iterator<Item = f32>) -> Vec<f32> {
+        let num_rows = placements
+            .iter()
+            .map(|placement| placement.row + 1)
+            .max()
+            .unwrap_or(0);
+        let mut row_heights = vec![0.0_f32; num_rows];
+        for (placement, height) in placements.iter().zip(heights) {
+            if height.is_finite() {
+                row_heights[placement.row] = row_heights[placement.row].max(height);
+            }
         }
+        row_heights
     }
 }
 
@@ -42,39 +129,23 @@ impl Layout for GridLayout {
             return Size::zero();
         }
 
-        let num_columns = self.columns.get();
-        let num_rows = children.len().div_ceil(num_columns);
+        let placements = self.placements(children.len());
 
         // Calculate the width available for each column.
         // A Grid requires a defined width from its parent to function correctly.
-        let child_width = proposal.width.map(|w| {
-            let total_spacing = self.spacing.width * (num_columns - 1) as f32;
-            ((w - total_spacing) / num_columns as f32).max(0.0)
-        });
+        let column_width = proposal.width.map(|w| self.column_width(w));
 
         // Grids are vertically unconstrained during the proposal phase.
-        // Each child is asked for its ideal height given the calculated column width.
-        let child_proposal = ProposalSize::new(child_width, None);
-
-        let measurements: Vec<ChildMeasurement> = children
-            .iter()
-            .map(|child| ChildMeasurement {
-                size: child.size_that_fits(child_proposal),
-            })
-            .collect();
+        // Each child is asked for its ideal height given the width of its cell.
+        let heights = children.iter().zip(&placements).map(|(child, placement)| {
+            let width = column_width.map(|w| self.span_width(w, placement.span));
+            child.size_that_fits(ProposalSize::new(width, None)).height
+        });
 
         // The grid's height is the sum of the tallest item in each row, plus vertical spacing.
-        let mut total_height = 0.0;
-        for row_children in measurements.chunks(num_columns) {
-            let row_height = row_children
-                .iter()
-                .map(|m| m.size.height)
-                .filter(|h| h.is_finite())
-                .fold(0.0, f32::max);
-            total_height += row_height;
-        }
-
-        total_height += self.spacing.height * (num_rows.saturating_sub(1) as f32);
+        let row_heights = Self::row_heights(&placements, heights);
+        let total_height = row_heights.iter().sum::<f32>()
+            + self.spacing.height * (row_heights.len().saturating_sub(1) as f32);
 
         // A Grid's width is defined by its parent. If not, it has no intrinsic width.
         let final_width = proposal.width.unwrap_or(0.0);
@@ -88,64 +159,66 @@ impl Layout for GridLayout {
             return vec![Rect::new(Point::zero(), Size::zero()); children.len()];
         }
 
-        let num_columns = self.columns.get();
-
-        // Calculate column width
-        let total_h_spacing = self.spacing.width * (num_columns - 1) as f32;
-        let column_width = ((bounds.width() - total_h_spacing) / num_columns as f32).max(0.0);
+        let placements = self.placements(children.len());
+        let column_width = self.column_width(bounds.width());
 
-        // Measure all children with the column width constraint
-        let child_proposal = ProposalSize::new(Some(column_width), None);
-
-        let measurements: Vec<ChildMeasurement> = children
+        // Measure all children with the width of their cell
+        let measurements: Vec<Size> = children
             .iter()
-            .map(|child| ChildMeasurement {
-                size: child.size_that_fits(child_proposal),
+            .zip(&placements)
+            .map(|(child, placement)| {
+                let width = self.span_width(column_width, placement.span);
+                child.size_that_fits(ProposalSize::new(Some(width), None))
             })
             .collect();
 
         // Pre-calculate the height of each row by finding the tallest child in that row.
-        let row_heights: Vec<f32> = measurements
-            .chunks(num_columns)
-            .map(|row_children| {
-                row_children
-                    .iter()
-                    .map(|m| m.size.height)
-                    .filter(|h| h.is_finite())
-                    .fold(0.0, f32::max)
+        let row_heights = Self::row_heights(
+            &placements,
+            measurements.iter().map(|measurement| measurement.height),
+        );
+        let row_offsets: Vec<f32> = row_heights
+            .iter()
+            .scan(bounds.y(), |cursor_y, row_height| {
+                let y = *cursor_y;
+                *cursor_y += row_height + self.spacing.height;
+                Some(y)
             })
             .collect();
 
-        let mut placements = Vec::with_capacity(children.len());
-        let mut cursor_y = bounds.y();
-
-        for (row_index, row_measurements) in measurements.chunks(num_columns).enumerate() {
-            let row_height = row_heights.get(row_index).copied().unwrap_or(0.0);
-            let mut cursor_x = bounds.x();
-
-            for measurement in row_measurements {
+        placements
+            .iter()
+            .zip(measurements)
+            .map(|(placement, measurement)| {
                 let cell_frame = Rect::new(
-                    Point::new(cursor_x, cursor_y),
-                    Size::new(column_width, row_height),
+                    Point::new(
+                        bounds.x() + placement.column as f32 * (column_width + self.spacing.width),
+                        row_offsets[placement.row],
+                    ),
+                    Size::new(
+                        self.span_width(column_width, placement.span),
+                        row_heights[placement.row],
+                    ),
                 );
 
                 // Handle infinite dimensions
-                let child_width = if measurement.size.width.is_infinite() {
-                    column_width
+                let child_width = if measurement.width.is_infinite() {
+                    cell_frame.width()
                 } else {
-                    measurement.size.width
+                    measurement.width
                 };
 
-                let child_height = if measurement.size.height.is_infinite() {
-                    row_height
+                let child_height = if measurement.height.is_infinite() {
+                    cell_frame.height()
                 } else {
-                    measurement.size.height
+                    measurement.height
                 };
 
                 let child_size = Size::new(child_width, child_height);
+                let alignment = placement.alignment.unwrap_or(self.alignment);
 
                 // Align the child within its cell
-                let child_x = match self.alignment.horizontal() {
+                let child_x = match alignment.horizontal() {
                     HorizontalAlignment::Leading => cell_frame.x(),
                     HorizontalAlignment::Center => {
                         cell_frame.x() + (cell_frame.width() - child_size.width) / 2.0
@@ -153,7 +226,7 @@ impl Layout for GridLayout {
                     HorizontalAlignment::Trailing => cell_frame.max_x() - child_size.width,
                 };
 
-                let child_y = match self.alignment.vertical() {
+                let child_y = match alignment.vertical() {
                     VerticalAlignment::Top => cell_frame.y(),
                     VerticalAlignment::Center => {
                         cell_frame.y() + (cell_frame.height() - child_size.height) / 2.0
@@ -161,15 +234,9 @@ impl Layout for GridLayout {
                     VerticalAlignment::Bottom => cell_frame.max_y() - child_size.height,
                 };
 
-                placements.push(Rect::new(Point::new(child_x, child_y), child_size));
-
-                cursor_x += column_width + self.spacing.width;
-            }
-
-            cursor_y += row_height + self.spacing.height;
-        }
-
-        placements
+                Rect::new(Point::new(child_x, child_y), child_size)
+            })
+            .collect()
     }
 }
 
@@ -179,6 +246,9 @@ impl Layout for GridLayout {
 
 /// A data-carrying struct that represents a single row in a `Grid`.
 /// It does not implement `View` itself; it is consumed by the `Grid`.
+///
+/// Each row starts on a new line of the grid. Cells that span more columns
+/// than are left wrap onto the following line.
 #[derive(Debug)]
 pub struct GridRow {
     pub(crate) contents: Vec<AnyView>,
@@ -193,6 +263,224 @@ impl GridRow {
     }
 }
 
+/// How a view sits in the [`Grid`] containing it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GridCell {
+    columns: Option<NonZeroUsize>,
+    position: Option<(usize, usize)>,
+    alignment: Option<Alignment>,
+}
+
+impl GridCell {
+    /// Returns the number of columns the cell spans, if set.
+    #[must_use]
+    pub const fn columns(&self) -> Option<NonZeroUsize> {
+        self.columns
+    }
+
+    /// Returns the explicit `(row, column)` of the cell, if set.
+    #[must_use]
+    pub const fn position(&self) -> Option<(usize, usize)> {
+        self.position
+    }
+
+    /// Returns the alignment within the cell, if set.
+    #[must_use]
+    pub const fn alignment(&self) -> Option<Alignment> {
+        self.alignment
+    }
+
+    /// Fills the settings left unset here with those of `inner`.
+    const fn or(self, inner: Self) -> Self {
+        Self {
+            columns: if self.columns.is_some() {
+                self.columns
+            } else {
+                inner.columns
+            },
+            position: if self.position.is_some() {
+                self.position
+            } else {
+                inner.position
+            },
+            alignment: if self.alignment.is_some() {
+                self.alignment
+            } else {
+                inner.alignment
+            },
+        }
+    }
+}
+
+/// A view carrying [`GridCell`] settings for the enclosing [`Grid`].
+///
+/// Created by the `grid_cell_*` view modifiers. Outside of a grid the
+/// settings have no effect and the content is shown as is.
+#[derive(Debug)]
+#[must_use]
+pub struct GridCellView {
+    cell: GridCell,
+    content: AnyView,
+}
+
+impl GridCellView {
+    /// Wraps `content`, merging with any cell settings it already carries.
+    fn wrap(content: impl View, cell: GridCell) -> Self {
+        match AnyView::new(content).downcast::<Self>() {
+            Ok(inner) => Self {
+                cell: cell.or(inner.cell),
+                content: inner.content,
+            },
+            Err(content) => Self { cell, content },
+        }
+    }
+
+    /// Makes `content` span `columns` columns of the grid.
+    ///
+    /// The span is capped to the number of columns in the grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is 0.
+    pub fn columns(content: impl View, columns: usize) -> Self {
+        let columns =
+            NonZeroUsize::new(columns).expect("A grid cell must span at least one column");
+        Self::wrap(
+            content,
+            GridCell {
+                columns: Some(columns),
+                ..GridCell::default()
+            },
+        )
+    }
+
+    /// Anchors `content` at `row` and `column` of the grid, counted from zero.
+    ///
+    /// Anchored cells are placed first; the other cells flow around them, and
+    /// the cells following this one in its row continue after it.
+    pub fn position(content: impl View, row: usize, column: usize) -> Self {
+        Self::wrap(
+            content,
+            GridCell {
+                position: Some((row, column)),
+                ..GridCell::default()
+            },
+        )
+    }
+
+    /// Aligns `content` within its cell, overriding the grid's alignment.
+    pub fn alignment(content: impl View, alignment: Alignment) -> Self {
+        Self::wrap(
+            content,
+            GridCell {
+                alignment: Some(alignment),
+                ..GridCell::default()
+            },
+        )
+    }
+
+    /// Returns the cell settings.
+    #[must_use]
+    pub const fn cell(&self) -> GridCell {
+        self.cell
+    }
+}
+
+impl View for GridCellView {
+    fn body(self, _env: &Environment) -> impl View {
+        self.content
+    }
+}
+
+/// Splits a grid child into its cell settings and its content.
+fn into_cell(child: AnyView) -> (GridCell, AnyView) {
+    match child.downcast::<GridCellView>() {
+        Ok(cell) => (cell.cell, cell.content),
+        Err(child) => (GridCell::default(), child),
+    }
+}
+
+/// Assigns every cell of `rows` a place in a grid of `columns` columns.
+///
+/// Anchored cells are placed first. The remaining cells fill the free columns
+/// row by row, each [`GridRow`] starting on a fresh line and cells following
+/// an anchored cell continuing after it.
+fn resolve_placements(columns: usize, rows: &[Vec<GridCell>]) -> Vec<GridPlacement> {
+    let mut occupied: Vec<Vec<bool>> = Vec::new();
+    let occupy = |occupied: &mut Vec<Vec<bool>>, row: usize, column: usize, span: usize| {
+        if occupied.len() <= row {
+            occupied.resize(row + 1, vec![false; columns]);
+        }
+        occupied[row][column..column + span].fill(true);
+    };
+    let span_of = |cell: &GridCell| cell.columns.map_or(1, NonZeroUsize::get).min(columns);
+
+    let mut placements: Vec<Option<GridPlacement>> = rows
+        .iter()
+        .flatten()
+        .map(|cell| {
+            cell.position.map(|(row, column)| {
+                let column = column.min(columns - 1);
+                let span = span_of(cell).min(columns - column);
+                occupy(&mut occupied, row, column, span);
+                GridPlacement {
+                    row,
+                    column,
+                    span,
+                    alignment: cell.alignment,
+                }
+            })
+        })
+        .collect();
+
+    let is_free = |occupied: &Vec<Vec<bool>>, row: usize, column: usize, span: usize| {
+        occupied
+            .get(row)
+            .is_none_or(|line| line[column..column + span].iter().all(|taken| !taken))
+    };
+
+    let mut index = 0;
+    let mut line = 0;
+    for cells in rows {
+        let mut column = 0;
+        let mut used = false;
+        for cell in cells {
+            if let Some(anchored) = placements[index] {
+                // Following cells continue after the anchored one
+                line = anchored.row;
+                column = anchored.column + anchored.span;
+            } else {
+                let span = span_of(cell);
+                loop {
+                    if column + span > columns {
+                        line += 1;
+                        column = 0;
+                    } else if is_free(&occupied, line, column, span) {
+                        break;
+                    } else {
+                        column += 1;
+                    }
+                }
+                occupy(&mut occupied, line, column, span);
+                placements[index] = Some(GridPlacement {
+                    row: line,
+                    column,
+                    span,
+                    alignment: cell.alignment,
+                });
+                column += span;
+            }
+            used = true;
+            index += 1;
+        }
+        if used {
+            line += 1;
+        }
+    }
+
+    placements.into_iter().flatten().collect()
+}
+
 /// A view that arranges content in rows and columns.
 ///
 /// ![Grid](https://raw.githubusercontent.com/water-rs/waterui/dev/docs/illustrations/grid.svg)
@@ -215,6 +503,16 @@ impl GridRow {
 ///     .alignment(Alignment::Leading)
 /// ```
 ///
+/// Cells can span several columns, be anchored to a row and column, or use
+/// their own alignment with the `grid_cell_*` view modifiers:
+///
+/// ```ignore
+/// grid(7, [
+///     row((text("Week 1").grid_cell_columns(7),)),
+///     row((text("1").grid_cell_position(1, 3), text("2"), text("3"))),
+/// ])
+/// ```
+///
 /// The grid sizes columns equally based on available width, and row heights
 /// are determined by the tallest item in each row.
 #[derive(Debug)]
@@ -256,19 +554,36 @@ impl Grid {
         self.layout.alignment = alignment;
         self
     }
+
+    /// Lets users drag the children into a new order, which is written back
+    /// to `items`.
+    ///
+    /// Child `n` must show item `n`. See [`reorder`](crate::reorder).
+    pub fn reorderable<T>(self, items: &Binding<Vec<T>>) -> Metadata<Reorderable>
+    where
+        Self: View,
+        T: Identifiable + Clone + 'static,
+    {
+        Metadata::new(self, Reorderable::new(items))
+    }
 }
 
 impl View for Grid {
     fn body(self, _env: &Environment) -> impl View {
-        // Flatten the children from all GridRows into a single Vec<AnyView>.
+        // Flatten the children from all GridRows into a single Vec<AnyView>,
+        // keeping the cell settings of each row to resolve where they go.
         // This is the list that the GridLayout engine will operate on.
-        let flattened_children = self
-            .rows
-            .into_iter()
-            .flat_map(|row| row.contents)
-            .collect::<Vec<AnyView>>();
+        let mut cells = Vec::with_capacity(self.rows.len());
+        let mut flattened_children = Vec::new();
+        for row in self.rows {
+            let (row_cells, contents): (Vec<GridCell>, Vec<AnyView>) =
+                row.contents.into_iter().map(into_cell).unzip();
+            cells.push(row_cells);
+            flattened_children.extend(contents);
+        }
 
-        FixedContainer::new(self.layout, flattened_children)
+        let placements = resolve_placements(self.layout.columns.get(), &cells);
+        FixedContainer::new(self.layout.with_placements(placements), flattened_children)
     }
 }
 
@@ -372,4 +687,111 @@ mod tests {
         assert!((rects[1].x() - 55.0).abs() < f32::EPSILON);
         assert!((rects[1].y() - 0.0).abs() < f32::EPSILON);
     }
+
+    #[test]
+    fn test_grid_spanning_and_anchored_cells() {
+        let layout = GridLayout::new(
+            NonZeroUsize::new(3).unwrap(),
+            Size::new(10.0, 10.0),
+            Alignment::TopLeading,
+        )
+        .with_placements(vec![
+            GridPlacement {
+                row: 0,
+                column: 0,
+                span: 3,
+                alignment: None,
+            },
+            GridPlacement {
+                row: 1,
+                column: 1,
+                span: 2,
+                alignment: Some(Alignment::BottomTrailing),
+            },
+        ]);
+
+        let mut header = MockSubView {
+            size: Size::new(20.0, 30.0),
+        };
+        let mut cell = MockSubView {
+            size: Size::new(20.0, 20.0),
+        };
+        let mut tall = MockSubView {
+            size: Size::new(20.0, 40.0),
+        };
+
+        // The third child has no placement and goes on the first free row
+        let children: Vec<&dyn SubView> = vec![&mut header, &mut cell, &mut tall];
+        let bounds = Rect::new(Point::new(0.0, 0.0), Size::new(100.0, 200.0));
+        let rects = layout.place(bounds, &children);
+
+        // Column width: (100 - 2 * 10) / 3 = 26.67
+        assert!((rects[0].x() - 0.0).abs() < f32::EPSILON);
+        // Spanning cell: 2 * 26.67 + 10 = 63.33, aligned to the trailing edge
+        assert!((rects[1].max_x() - 100.0).abs() < 0.001);
+        assert!((rects[1].max_y() - 60.0).abs() < 0.001);
+        assert!((rects[2].x() - 0.0).abs() < f32::EPSILON);
+        assert!((rects[2].y() - 70.0).abs() < f32::EPSILON);
+
+        let size = layout.size_that_fits(ProposalSize::new(Some(100.0), None), &children);
+        // 30 + 10 + 20 + 10 + 40
+        assert!((size.height - 110.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_resolve_placements() {
+        let cell = |columns: usize, position: Option<(usize, usize)>| GridCell {
+            columns: NonZeroUsize::new(columns),
+            position,
+            alignment: None,
+        };
+        let cells = |placements: Vec<GridPlacement>| {
+            placements
+                .iter()
+                .map(|placement| (placement.row, placement.column, placement.span))
+                .collect::<Vec<_>>()
+        };
+
+        // Spanning cells wrap onto a new line when they don't fit
+        let placements = resolve_placements(
+            3,
+            &[
+                vec![cell(1, None), cell(1, None)],
+                vec![cell(3, None)],
+                vec![cell(2, None), cell(2, None)],
+            ],
+        );
+        assert_eq!(
+            cells(placements),
+            vec![(0, 0, 1), (0, 1, 1), (1, 0, 3), (2, 0, 2), (3, 0, 2)]
+        );
+
+        // Cells after an anchored cell continue after it, like days in a calendar
+        let placements = resolve_placements(
+            7,
+            &[
+                vec![cell(9, None)],
+                vec![cell(1, Some((1, 3))), cell(1, None), cell(1, None)],
+            ],
+        );
+        assert_eq!(
+            cells(placements),
+            vec![(0, 0, 7), (1, 3, 1), (1, 4, 1), (1, 5, 1)]
+        );
+
+        // Other cells flow around anchored ones
+        let placements = resolve_placements(
+            3,
+            &[vec![
+                cell(1, None),
+                cell(1, Some((0, 0))),
+                cell(1, None),
+                cell(1, None),
+            ]],
+        );
+        assert_eq!(
+            cells(placements),
+            vec![(0, 1, 1), (0, 0, 1), (0, 2, 1), (1, 0, 1)]
+        );
+    }
 }
@@ -45,19 +45,23 @@ pub use waterui_core::layout::*;
 
 pub mod spacer;
 pub use spacer::{Spacer, spacer};
+pub mod spacing;
+pub use spacing::Spacing;
 pub mod stack;
 
 pub mod scroll;
-pub use scroll::{ScrollView, scroll};
+pub use scroll::{Prefetch, ReachEnd, Refresh, ScrollBehavior, ScrollPosition, ScrollView, scroll};
 pub mod frame;
 
 pub mod container;
 
-pub use container::LazyContainer;
+pub use container::{LazyContainer, LazyVStack};
 
 pub mod grid;
 pub mod overlay;
 pub mod padding;
+pub mod reorder;
+pub use reorder::Reorderable;
 pub mod safe_area;
 
 pub use overlay::{Overlay, OverlayLayout, overlay};
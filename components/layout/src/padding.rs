@@ -1,7 +1,7 @@
 //! Padding layouts that inset a child by fixed edge distances.
 
 use alloc::{vec, vec::Vec};
-use waterui_core::{AnyView, View};
+use waterui_core::{AnyView, View, animation::Lerp};
 
 use crate::{Layout, Point, ProposalSize, Rect, Size, SubView, container::FixedContainer};
 
@@ -126,6 +126,17 @@ impl EdgeInsets {
     }
 }
 
+impl Lerp for EdgeInsets {
+    fn lerp(&self, to: &Self, t: f64) -> Self {
+        Self {
+            top: self.top.lerp(&to.top, t),
+            bottom: self.bottom.lerp(&to.bottom, t),
+            leading: self.leading.lerp(&to.leading, t),
+            trailing: self.trailing.lerp(&to.trailing, t),
+        }
+    }
+}
+
 /// View wrapper that applies [`PaddingLayout`] to a single child.
 #[derive(Debug)]
 pub struct Padding {
@@ -188,6 +199,12 @@ mod tests {
         assert!((size.height - 50.0).abs() < f32::EPSILON); // 30 + 10 + 10
     }
 
+    #[test]
+    fn test_edge_insets_lerp() {
+        let insets = EdgeInsets::all(0.0).lerp(&EdgeInsets::symmetric(10.0, 20.0), 0.5);
+        assert_eq!(insets, EdgeInsets::symmetric(5.0, 10.0));
+    }
+
     #[test]
     fn test_padding_placement() {
         let layout = PaddingLayout {
@@ -0,0 +1,182 @@
+//! Drag-to-reorder for the children of stacks and grids.
+//!
+//! Calling `reorderable` on a [`VStack`](crate::stack::VStack),
+//! [`HStack`](crate::stack::HStack) or [`Grid`](crate::grid::Grid) whose
+//! children are generated from a list lets users drag the children into a new
+//! order, which is written back to the list:
+//!
+//! ```ignore
+//! let tracks: Binding<Vec<Track>> = binding(playlist());
+//!
+//! VStack::for_each(tracks.clone(), |track| text(track.title))
+//!     .reorderable(&tracks)
+//! ```
+//!
+//! Backends turn each child into a drag handle, immediately with a pointer and
+//! after a long press on touch screens. While a child is dragged they move
+//! its siblings aside to open a gap where it would land, then report the drop
+//! through [`Reorderable`]. Child `n` must be the view of item `n`; for a
+//! grid, children count cells in reading order.
+
+use alloc::{boxed::Box, vec::Vec};
+use core::{cell::RefCell, fmt};
+
+use nami::Binding;
+use waterui_core::{id::Identifiable, metadata::MetadataKey};
+
+/// Moves the item at `from` so it ends up at index `to`.
+///
+/// Indices past the end are clamped. Returns whether the order changed.
+pub fn move_item<T>(items: &mut Vec<T>, from: usize, to: usize) -> bool {
+    if from >= items.len() {
+        return false;
+    }
+    let to = to.min(items.len() - 1);
+    if from == to {
+        return false;
+    }
+    let item = items.remove(from);
+    items.insert(to, item);
+    true
+}
+
+/// Applies a drag to the list a stack was generated from.
+trait ReorderTarget {
+    fn begin(&self, index: usize) -> bool;
+    fn finish(&self, destination: usize) -> bool;
+    fn cancel(&self);
+}
+
+/// The items of a reorderable stack and the one being dragged.
+struct BindingTarget<T: Identifiable + 'static> {
+    items: Binding<Vec<T>>,
+    dragged: RefCell<Option<T::Id>>,
+}
+
+impl<T> ReorderTarget for BindingTarget<T>
+where
+    T: Identifiable + Clone + 'static,
+{
+    fn begin(&self, index: usize) -> bool {
+        let id = self.items.get().get(index).map(Identifiable::id);
+        let started = id.is_some();
+        *self.dragged.borrow_mut() = id;
+        started
+    }
+
+    fn finish(&self, destination: usize) -> bool {
+        let Some(id) = self.dragged.borrow_mut().take() else {
+            return false;
+        };
+        let mut items = self.items.get();
+        // The list may have changed during the drag, so find the item again
+        let Some(from) = items.iter().position(|item| item.id() == id) else {
+            return false;
+        };
+        let moved = move_item(&mut items, from, destination);
+        if moved {
+            self.items.set(items);
+        }
+        moved
+    }
+
+    fn cancel(&self) {
+        self.dragged.borrow_mut().take();
+    }
+}
+
+/// Lets users drag the children of a stack or grid to reorder them.
+///
+/// Backends call [`begin`](Self::begin) when a child is picked up and
+/// [`finish`](Self::finish) with the index it was dropped at, or
+/// [`cancel`](Self::cancel) if the drag was abandoned.
+pub struct Reorderable {
+    target: Box<dyn ReorderTarget>,
+}
+
+impl fmt::Debug for Reorderable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reorderable").finish_non_exhaustive()
+    }
+}
+
+impl MetadataKey for Reorderable {}
+
+impl Reorderable {
+    /// Creates a reorderable target writing the new order to `items`.
+    #[must_use]
+    pub fn new<T>(items: &Binding<Vec<T>>) -> Self
+    where
+        T: Identifiable + Clone + 'static,
+    {
+        Self {
+            target: Box::new(BindingTarget {
+                items: items.clone(),
+                dragged: RefCell::new(None),
+            }),
+        }
+    }
+
+    /// Starts dragging the child at `index`.
+    ///
+    /// Returns `false` if there is no such child, in which case backends
+    /// should not start the drag.
+    #[must_use]
+    pub fn begin(&self, index: usize) -> bool {
+        self.target.begin(index)
+    }
+
+    /// Drops the dragged child so it ends up at `destination`.
+    ///
+    /// Returns whether the order changed.
+    #[must_use]
+    pub fn finish(&self, destination: usize) -> bool {
+        self.target.finish(destination)
+    }
+
+    /// Abandons the current drag, keeping the order.
+    pub fn cancel(&self) {
+        self.target.cancel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use waterui_core::id::SelfId;
+
+    use super::*;
+
+    #[test]
+    fn moves_keep_other_items_in_order() {
+        let mut items = vec![1, 2, 3, 4];
+        assert!(move_item(&mut items, 0, 2));
+        assert_eq!(items, [2, 3, 1, 4]);
+        assert!(move_item(&mut items, 3, 0));
+        assert_eq!(items, [4, 2, 3, 1]);
+        assert!(move_item(&mut items, 1, 10));
+        assert_eq!(items, [4, 3, 1, 2]);
+        assert!(!move_item(&mut items, 2, 2));
+        assert!(!move_item(&mut items, 9, 0));
+    }
+
+    #[test]
+    fn drops_follow_the_dragged_item() {
+        let items = Binding::container(vec![SelfId::new('a'), SelfId::new('b'), SelfId::new('c')]);
+        let reorderable = Reorderable::new(&items);
+        let order = || items.get().iter().map(Identifiable::id).collect::<Vec<_>>();
+
+        assert!(reorderable.begin(0));
+        // The list is reordered elsewhere while dragging
+        items.set(vec![SelfId::new('b'), SelfId::new('a'), SelfId::new('c')]);
+        assert!(reorderable.finish(2));
+        assert_eq!(order(), ['b', 'c', 'a']);
+
+        assert!(!reorderable.begin(5));
+        assert!(reorderable.begin(1));
+        reorderable.cancel();
+        assert!(!reorderable.finish(0));
+        assert_eq!(order(), ['b', 'c', 'a']);
+    }
+}
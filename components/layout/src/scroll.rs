@@ -1,8 +1,17 @@
 //! Scroll containers that defer behaviour to the active renderer backend.
 
-use waterui_core::{AnyView, View, raw_view};
+use alloc::{boxed::Box, rc::Rc};
+use core::{
+    cell::{Cell, RefCell},
+    future::Future,
+    ops::Range,
+    pin::Pin,
+};
 
-use crate::StretchAxis;
+use nami::{Binding, Computed, SignalExt};
+use waterui_core::{AnyView, Environment, View, raw_view};
+
+use crate::{Point, StretchAxis, stack::Alignment};
 
 /// A scrollable view that displays content larger than its frame.
 ///
@@ -31,10 +40,426 @@ use crate::StretchAxis;
 /// ```ignore
 /// scroll_both(large_image)
 /// ```
+///
+/// Use [`ScrollView::prefetch`] to load data for items before they scroll into view.
+///
+/// Indicators, paging and bouncing can be configured for carousels and kiosk
+/// interfaces:
+///
+/// ```ignore
+/// scroll(cards)
+///     .axis(Axis::Horizontal)
+///     .paging(true)
+///     .indicators(false)
+///     .bounces(false)
+/// ```
+///
+/// Use [`ScrollView::refreshable`] to let the user pull to refresh, and
+/// [`ScrollView::on_reach_end`] to load more content as the user nears the end.
+///
+/// A [`ScrollPosition`] reads the scroll offset and scrolls programmatically:
+///
+/// ```ignore
+/// let position = ScrollPosition::new();
+/// scroll(messages_view).scroll_position(&position);
+/// // Later, after a message arrives
+/// position.scroll_to_end();
+/// ```
 #[derive(Debug)]
 pub struct ScrollView {
     axis: Axis,
     content: AnyView,
+    prefetch: Option<Prefetch>,
+    behavior: ScrollBehavior,
+    refresh: Option<Refresh>,
+    reach_end: Option<ReachEnd>,
+    position: Option<ScrollPosition>,
+}
+
+/// How a [`ScrollView`] presents and settles its scrolling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScrollBehavior {
+    /// Whether scroll indicators are shown while scrolling.
+    pub indicators: bool,
+    /// Whether scrolling settles on multiples of the view's size, one page at a time.
+    pub paging: bool,
+    /// Whether scrolling past the edges bounces back, where the platform supports it.
+    pub bounces: bool,
+}
+
+impl ScrollBehavior {
+    /// Indicators shown, no paging, bouncing at the edges.
+    const DEFAULT: Self = Self {
+        indicators: true,
+        paging: false,
+        bounces: true,
+    };
+}
+
+impl Default for ScrollBehavior {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+type PrefetchHandler = Rc<dyn Fn(&Environment, Range<usize>)>;
+
+/// A hint fired ahead of the visible window of a scrolling container.
+///
+/// Backends call the handler with the range of item indices that are about to
+/// become visible, within [`distance`](Prefetch::distance) items past either
+/// edge of the visible window. Use it to start downloads or load the next page
+/// of data before rows appear. Each index is reported at most once until it
+/// leaves the prefetch window again.
+///
+/// For a [`ScrollView`], indices refer to the children of its lazy content
+/// (such as a lazy stack); for a list, they refer to its rows.
+#[derive(Clone)]
+pub struct Prefetch {
+    handler: PrefetchHandler,
+    distance: usize,
+}
+
+impl core::fmt::Debug for Prefetch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Prefetch")
+            .field("distance", &self.distance)
+            .finish_non_exhaustive()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// INTERNAL: Prefetch Contract for Backend Implementers
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// After each scroll update, compute the window of `distance` items beyond the
+// visible items in the scroll direction (both directions when unknown). Call
+// the handler with the indices of that window that were not in the previous
+// window, coalesced into ranges. Never include indices that are on screen.
+//
+// ═══════════════════════════════════════════════════════════════════════════
+
+impl Prefetch {
+    /// The default number of items to look ahead.
+    pub const DEFAULT_DISTANCE: usize = 10;
+
+    /// Creates a prefetch hint from a handler that can read the environment.
+    pub fn new(handler: impl Fn(&Environment, Range<usize>) + 'static) -> Self {
+        Self {
+            handler: Rc::new(handler),
+            distance: Self::DEFAULT_DISTANCE,
+        }
+    }
+
+    /// Sets how many items past the visible window are prefetched.
+    #[must_use]
+    pub const fn with_distance(mut self, items: usize) -> Self {
+        self.distance = items;
+        self
+    }
+
+    /// Returns how many items past the visible window are prefetched.
+    #[must_use]
+    pub const fn distance(&self) -> usize {
+        self.distance
+    }
+
+    /// Reports that the items in `range` are about to become visible.
+    pub fn call(&self, env: &Environment, range: Range<usize>) {
+        if !range.is_empty() {
+            (self.handler)(env, range);
+        }
+    }
+}
+
+/// A pull-to-refresh action for a scrolling container.
+///
+/// Backends show the platform refresh control and call [`begin`](Refresh::begin)
+/// when the user pulls it, running the returned future on the local executor.
+/// The control keeps spinning while [`is_refreshing`](Refresh::is_refreshing)
+/// is `true`, which ends once the action's future resolves.
+#[derive(Clone)]
+pub struct Refresh {
+    action: Rc<dyn Fn() -> Pin<Box<dyn Future<Output = ()>>>>,
+    refreshing: Binding<bool>,
+}
+
+impl core::fmt::Debug for Refresh {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Refresh")
+            .field("refreshing", &self.refreshing.get())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Refresh {
+    /// Creates a refresh action from an async closure.
+    pub fn new(action: impl AsyncFn() + 'static) -> Self {
+        let action = Rc::new(action);
+        Self {
+            action: Rc::new(move || {
+                let action = action.clone();
+                Box::pin(async move { action().await })
+            }),
+            refreshing: Binding::container(false),
+        }
+    }
+
+    /// Returns whether a refresh is in progress.
+    #[must_use]
+    pub fn is_refreshing(&self) -> Computed<bool> {
+        self.refreshing.clone().computed()
+    }
+
+    /// Starts refreshing, returning the future that performs the refresh.
+    ///
+    /// Refreshing ends when the future resolves or is dropped. Returns `None`
+    /// if a refresh is already in progress.
+    #[must_use]
+    pub fn begin(&self) -> Option<Pin<Box<dyn Future<Output = ()>>>> {
+        if self.refreshing.get() {
+            return None;
+        }
+        self.refreshing.set(true);
+        let end = EndRefresh(self.refreshing.clone());
+        let future = (self.action)();
+        Some(Box::pin(async move {
+            let _end = end;
+            future.await;
+        }))
+    }
+}
+
+struct EndRefresh(Binding<bool>);
+
+impl Drop for EndRefresh {
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
+}
+
+/// A callback fired when scrolling nears the end of the content.
+///
+/// Backends [`report`](ReachEnd::report) the scroll position along the scroll
+/// axis after each scroll update and whenever the content size changes. The
+/// handler fires once the end of the content is within
+/// [`threshold`](ReachEnd::threshold) points of the end of the viewport, and
+/// again only after the content length changes or the user scrolls back out of
+/// the threshold. This makes it suitable for appending the next page of a
+/// paginated data source.
+#[derive(Clone)]
+pub struct ReachEnd {
+    handler: Rc<dyn Fn(&Environment)>,
+    threshold: f32,
+    // Content length at which the handler last fired, while still within the threshold
+    fired_at: Rc<Cell<Option<f32>>>,
+}
+
+impl core::fmt::Debug for ReachEnd {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ReachEnd")
+            .field("threshold", &self.threshold)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ReachEnd {
+    /// The default distance from the end, in points, at which the handler fires.
+    pub const DEFAULT_THRESHOLD: f32 = 200.0;
+
+    /// Creates a reach-end callback from a handler that can read the environment.
+    pub fn new(threshold: f32, handler: impl Fn(&Environment) + 'static) -> Self {
+        Self {
+            handler: Rc::new(handler),
+            threshold: threshold.max(0.0),
+            fired_at: Rc::new(Cell::new(None)),
+        }
+    }
+
+    /// Returns the distance from the end, in points, at which the handler fires.
+    #[must_use]
+    pub const fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Reports the scroll position along the scroll axis, firing the handler
+    /// if the end of the content is near.
+    ///
+    /// `offset` is the distance scrolled from the start of the content,
+    /// `content_length` the total length of the content and `viewport_length`
+    /// the visible length, all in points.
+    pub fn report(
+        &self,
+        env: &Environment,
+        offset: f32,
+        content_length: f32,
+        viewport_length: f32,
+    ) {
+        let remaining = content_length - offset - viewport_length;
+        if remaining > self.threshold {
+            self.fired_at.set(None);
+            return;
+        }
+        if self.fired_at.get() == Some(content_length) {
+            return;
+        }
+        self.fired_at.set(Some(content_length));
+        (self.handler)(env);
+    }
+}
+
+/// Where a programmatic scroll should go.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScrollDestination {
+    /// Scroll so that the content's top-leading corner is at this offset.
+    Offset(Point),
+    /// Scroll the item at `index` into view, aligning its `anchor` with the same
+    /// point of the viewport.
+    ///
+    /// Indices follow the same rules as for [`Prefetch`].
+    Item {
+        /// Index of the item to reveal.
+        index: usize,
+        /// Point of the item to align with the viewport.
+        anchor: Alignment,
+    },
+    /// Scroll to the start of the content.
+    Start,
+    /// Scroll to the end of the content, such as the newest message of a chat.
+    End,
+}
+
+/// A request to scroll a [`ScrollView`] programmatically.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScrollRequest {
+    /// Where to scroll to.
+    pub destination: ScrollDestination,
+    /// Whether the platform should animate the scroll.
+    pub animated: bool,
+}
+
+type RequestHandler = Rc<dyn Fn(ScrollRequest)>;
+
+/// The scroll offset of a [`ScrollView`], with programmatic scrolling.
+///
+/// Clones share the same position. Attach it with
+/// [`ScrollView::scroll_position`]; the offset is then kept up to date by the
+/// backend, and requests such as [`scroll_to`](ScrollPosition::scroll_to) are
+/// forwarded to it. Requests made before the view appears are applied when it
+/// does, which restores a saved position.
+#[derive(Clone)]
+pub struct ScrollPosition {
+    offset: Binding<Point>,
+    handler: Rc<RefCell<Option<RequestHandler>>>,
+    pending: Rc<Cell<Option<ScrollRequest>>>,
+}
+
+impl core::fmt::Debug for ScrollPosition {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ScrollPosition")
+            .field("offset", &self.offset.get())
+            .field("pending", &self.pending.get())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for ScrollPosition {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// INTERNAL: Scroll Position Contract for Backend Implementers
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// When the scroll view appears, register a handler with `on_request`; a
+// request made before that is delivered immediately. Perform each request
+// with the platform's scroll API, clamping destinations to the content.
+// After every scroll update, including ones caused by requests, `report` the
+// content offset so that `offset` stays current.
+//
+// ═══════════════════════════════════════════════════════════════════════════
+
+impl ScrollPosition {
+    /// Creates a position at the start of the content.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            offset: Binding::container(Point::zero()),
+            handler: Rc::default(),
+            pending: Rc::default(),
+        }
+    }
+
+    /// Returns the current scroll offset, as last reported by the backend.
+    #[must_use]
+    pub fn offset(&self) -> Computed<Point> {
+        self.offset.clone().computed()
+    }
+
+    /// Scrolls to `offset` without animation, such as to restore a saved position.
+    pub fn set_offset(&self, offset: Point) {
+        self.request(ScrollRequest {
+            destination: ScrollDestination::Offset(offset),
+            animated: false,
+        });
+    }
+
+    /// Scrolls the item at `index` into view with animation, aligning its
+    /// `anchor` with the same point of the viewport.
+    pub fn scroll_to(&self, index: usize, anchor: Alignment) {
+        self.request(ScrollRequest {
+            destination: ScrollDestination::Item { index, anchor },
+            animated: true,
+        });
+    }
+
+    /// Scrolls to the start of the content with animation.
+    pub fn scroll_to_start(&self) {
+        self.request(ScrollRequest {
+            destination: ScrollDestination::Start,
+            animated: true,
+        });
+    }
+
+    /// Scrolls to the end of the content with animation.
+    pub fn scroll_to_end(&self) {
+        self.request(ScrollRequest {
+            destination: ScrollDestination::End,
+            animated: true,
+        });
+    }
+
+    /// Sends a scroll request to the backend.
+    ///
+    /// If the scroll view has not appeared yet, the request is kept until it
+    /// does; a later request replaces it.
+    pub fn request(&self, request: ScrollRequest) {
+        let handler = self.handler.borrow().clone();
+        match handler {
+            Some(handler) => handler(request),
+            None => self.pending.set(Some(request)),
+        }
+    }
+
+    /// Sets the handler that performs scroll requests, replacing the previous one.
+    ///
+    /// Called by backends. A pending request is delivered immediately.
+    pub fn on_request(&self, handler: impl Fn(ScrollRequest) + 'static) {
+        let handler: RequestHandler = Rc::new(handler);
+        *self.handler.borrow_mut() = Some(handler.clone());
+        if let Some(request) = self.pending.take() {
+            handler(request);
+        }
+    }
+
+    /// Reports the current scroll offset. Called by backends.
+    pub fn report(&self, offset: Point) {
+        if self.offset.get() != offset {
+            self.offset.set(offset);
+        }
+    }
 }
 
 /// Defines the scrolling directions supported by `ScrollView`.
@@ -54,7 +479,67 @@ impl ScrollView {
     /// Creates a new `ScrollView` with the specified scroll axis and content.
     #[must_use]
     pub const fn new(axis: Axis, content: AnyView) -> Self {
-        Self { axis, content }
+        Self {
+            axis,
+            content,
+            prefetch: None,
+            behavior: ScrollBehavior::DEFAULT,
+            refresh: None,
+            reach_end: None,
+            position: None,
+        }
+    }
+
+    /// Returns the indicator, paging and bounce configuration.
+    #[must_use]
+    pub const fn behavior(&self) -> ScrollBehavior {
+        self.behavior
+    }
+
+    /// Returns the pull-to-refresh action, if one is set.
+    #[must_use]
+    pub const fn refresh(&self) -> Option<&Refresh> {
+        self.refresh.as_ref()
+    }
+
+    /// Returns the reach-end callback, if one is set.
+    #[must_use]
+    pub const fn reach_end(&self) -> Option<&ReachEnd> {
+        self.reach_end.as_ref()
+    }
+
+    /// Returns the scroll position handle, if one is attached.
+    #[must_use]
+    pub const fn position(&self) -> Option<&ScrollPosition> {
+        self.position.as_ref()
+    }
+
+    /// Sets the scroll axis.
+    #[must_use]
+    pub const fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Shows or hides the scroll indicators. Shown by default.
+    #[must_use]
+    pub const fn indicators(mut self, visible: bool) -> Self {
+        self.behavior.indicators = visible;
+        self
+    }
+
+    /// Makes scrolling settle one page, the size of the view, at a time. Off by default.
+    #[must_use]
+    pub const fn paging(mut self, enabled: bool) -> Self {
+        self.behavior.paging = enabled;
+        self
+    }
+
+    /// Lets scrolling overshoot the edges and bounce back. On by default.
+    #[must_use]
+    pub const fn bounces(mut self, enabled: bool) -> Self {
+        self.behavior.bounces = enabled;
+        self
     }
 
     /// Decomposes the `ScrollView` into its axis and content.
@@ -62,6 +547,73 @@ impl ScrollView {
         (self.axis, self.content)
     }
 
+    /// Decomposes the `ScrollView` into its axis, content and prefetch hint.
+    pub fn into_parts(self) -> (Axis, AnyView, Option<Prefetch>) {
+        (self.axis, self.content, self.prefetch)
+    }
+
+    /// Calls `handler` with the indices of items that are about to scroll into view.
+    ///
+    /// ```ignore
+    /// Lazy::for_each(posts.clone(), post_row)
+    ///     .prefetch(move |range| feed.load_until(range.end))
+    /// ```
+    #[must_use]
+    pub fn prefetch(self, handler: impl Fn(Range<usize>) + 'static) -> Self {
+        self.prefetch_with(Prefetch::new(move |_env, range| handler(range)))
+    }
+
+    /// Sets the prefetch hint of this scroll view.
+    #[must_use]
+    pub fn prefetch_with(mut self, prefetch: Prefetch) -> Self {
+        self.prefetch = Some(prefetch);
+        self
+    }
+
+    /// Lets the user pull to refresh, running `action` until it completes.
+    ///
+    /// ```ignore
+    /// scroll(feed_view).refreshable(async move || feed.reload().await)
+    /// ```
+    #[must_use]
+    pub fn refreshable(self, action: impl AsyncFn() + 'static) -> Self {
+        self.refresh_with(Refresh::new(action))
+    }
+
+    /// Sets the pull-to-refresh action of this scroll view.
+    #[must_use]
+    pub fn refresh_with(mut self, refresh: Refresh) -> Self {
+        self.refresh = Some(refresh);
+        self
+    }
+
+    /// Calls `handler` when the user scrolls within `threshold` points of the end.
+    ///
+    /// The handler fires once per content length, so appending items re-arms it.
+    ///
+    /// ```ignore
+    /// scroll(feed_view).on_reach_end(ReachEnd::DEFAULT_THRESHOLD, move || feed.load_next_page())
+    /// ```
+    #[must_use]
+    pub fn on_reach_end(self, threshold: f32, handler: impl Fn() + 'static) -> Self {
+        self.reach_end_with(ReachEnd::new(threshold, move |_env| handler()))
+    }
+
+    /// Sets the reach-end callback of this scroll view.
+    #[must_use]
+    pub fn reach_end_with(mut self, reach_end: ReachEnd) -> Self {
+        self.reach_end = Some(reach_end);
+        self
+    }
+
+    /// Keeps `position` in sync with this scroll view's offset and lets it
+    /// scroll the view programmatically.
+    #[must_use]
+    pub fn scroll_position(mut self, position: &ScrollPosition) -> Self {
+        self.position = Some(position.clone());
+        self
+    }
+
     /// Creates a `ScrollView` with horizontal scrolling.
     pub fn horizontal(content: impl View) -> Self {
         Self::new(Axis::Horizontal, AnyView::new(content))
@@ -103,3 +655,94 @@ pub fn scroll_horizontal(content: impl View) -> ScrollView {
 pub fn scroll_both(content: impl View) -> ScrollView {
     ScrollView::both(content)
 }
+
+#[cfg(test)]
+mod tests {
+    use core::task::{Context, Poll, Waker};
+
+    use super::*;
+    use nami::Signal;
+
+    #[test]
+    fn refreshing_ends_when_the_action_completes() {
+        let runs = Binding::container(0);
+        let refresh = {
+            let runs = runs.clone();
+            Refresh::new(async move || runs.set(runs.get() + 1))
+        };
+        let refreshing = refresh.is_refreshing();
+
+        let mut future = refresh.begin().unwrap();
+        assert!(refreshing.get());
+        assert!(refresh.begin().is_none());
+
+        let mut cx = Context::from_waker(Waker::noop());
+        assert_eq!(future.as_mut().poll(&mut cx), Poll::Ready(()));
+        assert!(!refreshing.get());
+        assert_eq!(runs.get(), 1);
+
+        drop(refresh.begin());
+        assert!(!refreshing.get());
+        assert_eq!(runs.get(), 1);
+    }
+
+    #[test]
+    fn reach_end_fires_once_per_content_length() {
+        let env = Environment::new();
+        let fired = Rc::new(Cell::new(0));
+        let reach_end = {
+            let fired = fired.clone();
+            ReachEnd::new(100.0, move |_| fired.set(fired.get() + 1))
+        };
+
+        reach_end.report(&env, 0.0, 1000.0, 500.0);
+        assert_eq!(fired.get(), 0);
+
+        reach_end.report(&env, 450.0, 1000.0, 500.0);
+        reach_end.report(&env, 480.0, 1000.0, 500.0);
+        assert_eq!(fired.get(), 1);
+
+        // More items were appended, but they still end within the threshold
+        reach_end.report(&env, 480.0, 1050.0, 500.0);
+        assert_eq!(fired.get(), 2);
+
+        // Scrolling back out re-arms the callback
+        reach_end.report(&env, 0.0, 1050.0, 500.0);
+        reach_end.report(&env, 500.0, 1050.0, 500.0);
+        assert_eq!(fired.get(), 3);
+    }
+
+    #[test]
+    fn scroll_requests_wait_for_the_backend() {
+        let position = ScrollPosition::new();
+        position.set_offset(Point::new(0.0, 120.0));
+        position.scroll_to_end();
+
+        let received = Rc::new(RefCell::new(alloc::vec::Vec::new()));
+        {
+            let received = received.clone();
+            position.on_request(move |request| received.borrow_mut().push(request));
+        }
+        // Only the latest request made before the view appeared is applied
+        assert_eq!(
+            *received.borrow(),
+            [ScrollRequest {
+                destination: ScrollDestination::End,
+                animated: true,
+            }]
+        );
+
+        position.scroll_to(3, Alignment::Top);
+        assert_eq!(
+            received.borrow()[1].destination,
+            ScrollDestination::Item {
+                index: 3,
+                anchor: Alignment::Top,
+            }
+        );
+
+        let offset = position.offset();
+        position.report(Point::new(0.0, 48.0));
+        assert_eq!(offset.get(), Point::new(0.0, 48.0));
+    }
+}
@@ -32,6 +32,9 @@ use crate::{Layout, ProposalSize, Rect, Size, StretchAxis, SubView};
 ///
 /// // Spacer with minimum length (never shrinks below 20pt)
 /// spacer_min(20.0)
+///
+/// // Spacer that grows from 8pt up to at most 64pt
+/// spacer().min(8.0).max(64.0)
 /// ```
 //
 // ═══════════════════════════════════════════════════════════════════════════
@@ -39,8 +42,11 @@ use crate::{Layout, ProposalSize, Rect, Size, StretchAxis, SubView};
 // ═══════════════════════════════════════════════════════════════════════════
 //
 
-// Measurement: Returns (minLength, minLength) as intrinsic size
-// Layout: Expands to fill remaining surplus space during place() phase
+// Measurement: Returns (minLength, minLength) as intrinsic size. A proposal of
+//   f32::INFINITY on an axis is a probe for the maximum: if `max` was set, it
+//   returns maxLength on that axis.
+// Layout: Expands to fill remaining surplus space during place() phase, up to
+//   maxLength; stacks hand the rest to other stretching children
 // Overflow: Collapses to minLength when space is insufficient
 //
 // ═══════════════════════════════════════════════════════════════════════════
@@ -48,19 +54,51 @@ use crate::{Layout, ProposalSize, Rect, Size, StretchAxis, SubView};
 #[derive(Debug, Clone, PartialEq)]
 pub struct Spacer {
     min_length: f32,
+    max_length: f32,
 }
 
 impl Spacer {
     /// Creates a new spacer with the specified minimum length.
     #[must_use]
     pub const fn new(min_length: f32) -> Self {
-        Self { min_length }
+        Self {
+            min_length,
+            max_length: f32::INFINITY,
+        }
     }
 
     /// Creates a spacer with zero minimum length.
     #[must_use]
     pub const fn flexible() -> Self {
-        Self { min_length: 0.0 }
+        Self::new(0.0)
+    }
+
+    /// Sets the length the spacer never shrinks below.
+    #[must_use]
+    pub const fn min(mut self, min_length: f32) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    /// Sets the length the spacer never grows beyond.
+    ///
+    /// Only a maximum above the minimum length has an effect.
+    #[must_use]
+    pub const fn max(mut self, max_length: f32) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Returns the length the spacer never shrinks below.
+    #[must_use]
+    pub const fn min_length(&self) -> f32 {
+        self.min_length
+    }
+
+    /// Returns the length the spacer never grows beyond, infinite if unbounded.
+    #[must_use]
+    pub const fn max_length(&self) -> f32 {
+        self.max_length
     }
 }
 
@@ -71,13 +109,26 @@ impl Spacer {
 #[derive(Debug, Clone)]
 pub struct SpacerLayout {
     min_length: f32,
+    max_length: f32,
+}
+
+impl SpacerLayout {
+    /// Answers a maximum probe with the maximum length, if one was set, and
+    /// anything else with the minimum.
+    fn length(&self, proposal: Option<f32>) -> f32 {
+        if proposal.is_some_and(f32::is_infinite) && self.max_length.is_finite() {
+            self.max_length
+        } else {
+            self.min_length
+        }
+    }
 }
 
 impl Layout for SpacerLayout {
-    fn size_that_fits(&self, _proposal: ProposalSize, _children: &[&dyn SubView]) -> Size {
+    fn size_that_fits(&self, proposal: ProposalSize, _children: &[&dyn SubView]) -> Size {
         // Spacer reports its minimum length as intrinsic size (like SwiftUI)
         // The parent stack will expand it to fill remaining space during place()
-        Size::new(self.min_length, self.min_length)
+        Size::new(self.length(proposal.width), self.length(proposal.height))
     }
 
     fn place(&self, _bounds: Rect, _children: &[&dyn SubView]) -> Vec<Rect> {
@@ -90,6 +141,7 @@ impl From<Spacer> for SpacerLayout {
     fn from(spacer: Spacer) -> Self {
         Self {
             min_length: spacer.min_length,
+            max_length: spacer.max_length,
         }
     }
 }
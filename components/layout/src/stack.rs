@@ -1,17 +1,23 @@
 //! Stack-based layout primitives.
 //!
-//! The submodules implement horizontal, vertical, and overlay stacks. These
+//! The submodules implement horizontal, vertical, wrapping, and overlay stacks. These
 //! views arrange child content according to alignments and spacing and are the
 //! backbone of most declarative layouts in `WaterUI`.
 //!
 //! ![Stack](https://raw.githubusercontent.com/water-rs/waterui/dev/docs/illustrations/stack.svg)
 
+use alloc::{vec, vec::Vec};
+
+use crate::StretchAxis;
+
 mod vstack;
 pub use vstack::*;
 mod hstack;
 pub use hstack::*;
 mod zstack;
 pub use zstack::*;
+mod flowstack;
+pub use flowstack::*;
 
 /// Defines the axis of a stack.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -108,3 +114,106 @@ impl Alignment {
         }
     }
 }
+
+/// Returns the `(min, max)` length a stretching child accepts along a stack's
+/// main axis, given its intrinsic length and a `probe` for its maximum.
+///
+/// Only [`StretchAxis::MainAxis`] children such as spacers are bounded; other
+/// stretching views keep growing without limit. Views that ignore the probe
+/// answer it with their intrinsic length, so only a larger answer counts as a
+/// maximum.
+fn stretch_limits(
+    stretch_axis: StretchAxis,
+    intrinsic: f32,
+    probe: impl FnOnce() -> f32,
+) -> (f32, f32) {
+    if stretch_axis != StretchAxis::MainAxis {
+        return (0.0, f32::INFINITY);
+    }
+    let max = probe();
+    (intrinsic, if max > intrinsic { max } else { f32::INFINITY })
+}
+
+/// Splits `space` between stretching children as evenly as their `(min, max)`
+/// limits allow.
+///
+/// Space a bounded child cannot take goes to the others, and children are
+/// never shrunk below their minimum, even if that overflows `space`.
+#[allow(clippy::cast_precision_loss)]
+fn distribute(space: f32, limits: &[(f32, f32)]) -> Vec<f32> {
+    let mut lengths = vec![None; limits.len()];
+    let mut remaining = space;
+    loop {
+        let open: Vec<usize> = (0..limits.len())
+            .filter(|&idx| lengths[idx].is_none())
+            .collect();
+        if open.is_empty() {
+            break;
+        }
+        let share = (remaining / open.len() as f32).max(0.0);
+        // Pin the children the even share violates, favouring minimums when
+        // they need more space than the maximums free up, then share again
+        let violation: f32 = open
+            .iter()
+            .map(|&idx| share.clamp(limits[idx].0, limits[idx].1) - share)
+            .sum();
+        let pinned: Vec<usize> = open
+            .iter()
+            .copied()
+            .filter(|&idx| {
+                let (min, max) = limits[idx];
+                if violation >= 0.0 {
+                    share < min
+                } else {
+                    share > max
+                }
+            })
+            .collect();
+        if pinned.is_empty() {
+            for idx in open {
+                lengths[idx] = Some(share);
+            }
+            break;
+        }
+        for idx in pinned {
+            let length = share.clamp(limits[idx].0, limits[idx].1);
+            lengths[idx] = Some(length);
+            remaining -= length;
+        }
+    }
+    lengths
+        .into_iter()
+        .map(|length| length.unwrap_or(0.0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FREE: (f32, f32) = (0.0, f32::INFINITY);
+
+    #[test]
+    fn unbounded_children_share_evenly() {
+        assert_eq!(distribute(90.0, &[FREE, FREE, FREE]), vec![30.0; 3]);
+        assert_eq!(distribute(-10.0, &[FREE, FREE]), vec![0.0; 2]);
+    }
+
+    #[test]
+    fn capped_children_leave_space_to_others() {
+        assert_eq!(distribute(100.0, &[(8.0, 20.0), FREE]), vec![20.0, 80.0]);
+        assert_eq!(
+            distribute(100.0, &[(8.0, 20.0), (0.0, 30.0)]),
+            vec![20.0, 30.0]
+        );
+    }
+
+    #[test]
+    fn minimums_are_kept() {
+        assert_eq!(distribute(30.0, &[(24.0, 64.0), FREE]), vec![24.0, 6.0]);
+        assert_eq!(
+            distribute(10.0, &[(8.0, 64.0), (8.0, 64.0)]),
+            vec![8.0, 8.0]
+        );
+    }
+}
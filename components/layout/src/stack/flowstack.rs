@@ -0,0 +1,299 @@
+//! Wrapping horizontal stack layout.
+
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+use waterui_core::{AnyView, View, env::with, view::TupleViews};
+
+use crate::{
+    Layout, Point, ProposalSize, Rect, Size, Spacing, SubView,
+    container::FixedContainer,
+    stack::{Axis, HorizontalAlignment, VerticalAlignment},
+};
+
+/// Layout engine shared by the public [`FlowStack`] view.
+#[derive(Debug, Default, Clone)]
+pub struct FlowStackLayout {
+    /// The horizontal alignment of each line within the stack.
+    pub alignment: HorizontalAlignment,
+    /// The vertical alignment of children within their line.
+    pub line_alignment: VerticalAlignment,
+    /// The spacing between children in a line.
+    pub spacing: f32,
+    /// The spacing between lines.
+    pub line_spacing: f32,
+}
+
+/// A line of children, with its width and height.
+struct Line {
+    children: Range<usize>,
+    size: Size,
+}
+
+impl FlowStackLayout {
+    /// Measures the children and breaks them into lines no wider than `width`.
+    fn lines(&self, width: Option<f32>, children: &[&dyn SubView]) -> (Vec<Size>, Vec<Line>) {
+        let max_width = width.unwrap_or(f32::INFINITY);
+        let proposal = ProposalSize::new(width, None);
+        let sizes: Vec<Size> = children
+            .iter()
+            .map(|child| {
+                let size = child.size_that_fits(proposal);
+                Size::new(size.width.min(max_width), size.height)
+            })
+            .collect();
+
+        let mut lines: Vec<Line> = Vec::new();
+        for (index, size) in sizes.iter().enumerate() {
+            match lines.last_mut() {
+                Some(line) if line.size.width + self.spacing + size.width <= max_width => {
+                    line.children.end = index + 1;
+                    line.size.width += self.spacing + size.width;
+                    line.size.height = line.size.height.max(size.height);
+                }
+                _ => lines.push(Line {
+                    children: index..index + 1,
+                    size: *size,
+                }),
+            }
+        }
+        (sizes, lines)
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl Layout for FlowStackLayout {
+    fn size_that_fits(&self, proposal: ProposalSize, children: &[&dyn SubView]) -> Size {
+        if children.is_empty() {
+            return Size::zero();
+        }
+
+        let (_, lines) = self.lines(proposal.width, children);
+        let width = lines
+            .iter()
+            .map(|line| line.size.width)
+            .max_by(f32::total_cmp)
+            .unwrap_or(0.0);
+        let height = lines.iter().map(|line| line.size.height).sum::<f32>()
+            + (lines.len() - 1) as f32 * self.line_spacing;
+        Size::new(width, height)
+    }
+
+    fn place(&self, bounds: Rect, children: &[&dyn SubView]) -> Vec<Rect> {
+        if children.is_empty() {
+            return vec![];
+        }
+
+        let (sizes, lines) = self.lines(Some(bounds.width()), children);
+        let mut rects = Vec::with_capacity(children.len());
+        let mut y = bounds.y();
+
+        for line in lines {
+            let mut x = match self.alignment {
+                HorizontalAlignment::Leading => bounds.x(),
+                HorizontalAlignment::Center => {
+                    bounds.x() + (bounds.width() - line.size.width) / 2.0
+                }
+                HorizontalAlignment::Trailing => bounds.x() + bounds.width() - line.size.width,
+            };
+            for size in &sizes[line.children] {
+                let child_y = match self.line_alignment {
+                    VerticalAlignment::Top => y,
+                    VerticalAlignment::Center => y + (line.size.height - size.height) / 2.0,
+                    VerticalAlignment::Bottom => y + line.size.height - size.height,
+                };
+                rects.push(Rect::new(Point::new(x, child_y), *size));
+                x += size.width + self.spacing;
+            }
+            y += line.size.height + self.line_spacing;
+        }
+
+        rects
+    }
+}
+
+/// A view that arranges its children in lines, wrapping to a new line when
+/// the current one is full.
+///
+/// Use a `FlowStack` for tag clouds, chip groups and other content whose
+/// number of items per line depends on the available width. Children keep
+/// their ideal size; a child wider than the stack gets a line of its own and
+/// is narrowed to fit.
+///
+/// ```ignore
+/// flow_stack((
+///     chip("Rust"),
+///     chip("Swift"),
+///     chip("Kotlin"),
+/// ))
+/// .spacing(8.0)
+/// .line_spacing(4.0)
+/// ```
+///
+/// Chips built from data can be collected into a stack:
+///
+/// ```ignore
+/// tags.iter().map(|tag| chip(tag.clone())).collect::<FlowStack<_>>()
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlowStack<C> {
+    layout: FlowStackLayout,
+    spacing: Spacing,
+    line_spacing: Option<Spacing>,
+    contents: C,
+}
+
+impl<C: TupleViews> FlowStack<(C,)> {
+    /// Creates a flow stack with the provided line alignment, spacing, and
+    /// children.
+    ///
+    /// The spacing is used both between children and between lines until
+    /// [`line_spacing`](Self::line_spacing) sets the latter.
+    pub const fn new(alignment: HorizontalAlignment, spacing: f32, contents: C) -> Self {
+        Self {
+            layout: FlowStackLayout {
+                alignment,
+                line_alignment: VerticalAlignment::Center,
+                spacing,
+                line_spacing: spacing,
+            },
+            spacing: Spacing::Points(spacing),
+            line_spacing: None,
+            contents: (contents,),
+        }
+    }
+}
+
+impl<C> FlowStack<C> {
+    /// Sets the horizontal alignment of each line.
+    #[must_use]
+    pub const fn alignment(mut self, alignment: HorizontalAlignment) -> Self {
+        self.layout.alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of children within their line.
+    #[must_use]
+    pub const fn line_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.layout.line_alignment = alignment;
+        self
+    }
+
+    /// Sets the spacing between children in a line, either in points or as a
+    /// theme-relative [`Spacing`] token.
+    #[must_use]
+    pub fn spacing(mut self, spacing: impl Into<Spacing>) -> Self {
+        self.spacing = spacing.into();
+        self
+    }
+
+    /// Sets the spacing between lines.
+    #[must_use]
+    pub fn line_spacing(mut self, spacing: impl Into<Spacing>) -> Self {
+        self.line_spacing = Some(spacing.into());
+        self
+    }
+}
+
+impl<V> FromIterator<V> for FlowStack<(Vec<AnyView>,)>
+where
+    V: View,
+{
+    fn from_iter<T: IntoIterator<Item = V>>(iter: T) -> Self {
+        let contents = iter.into_iter().map(AnyView::new).collect::<Vec<_>>();
+        Self::new(HorizontalAlignment::Leading, 10.0, contents)
+    }
+}
+
+/// Convenience constructor that aligns lines to the leading edge and uses
+/// the default spacing.
+pub const fn flow_stack<C: TupleViews>(contents: C) -> FlowStack<(C,)> {
+    FlowStack::new(HorizontalAlignment::Leading, 10.0, contents)
+}
+
+impl<C: TupleViews + 'static> View for FlowStack<(C,)> {
+    fn body(self, env: &waterui_core::Environment) -> impl View {
+        let mut layout = self.layout;
+        layout.spacing = self.spacing.resolve(env);
+        layout.line_spacing = self.line_spacing.unwrap_or(self.spacing).resolve(env);
+        // Lines run along the horizontal axis
+        with(
+            FixedContainer::new(layout, self.contents.0),
+            Axis::Horizontal,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StretchAxis;
+
+    struct MockSubView {
+        size: Size,
+    }
+
+    impl SubView for MockSubView {
+        fn size_that_fits(&self, _proposal: ProposalSize) -> Size {
+            self.size
+        }
+        fn stretch_axis(&self) -> StretchAxis {
+            StretchAxis::None
+        }
+        fn priority(&self) -> i32 {
+            0
+        }
+    }
+
+    fn chips(widths: &[f32]) -> Vec<MockSubView> {
+        widths
+            .iter()
+            .map(|&width| MockSubView {
+                size: Size::new(width, 20.0),
+            })
+            .collect()
+    }
+
+    fn layout(alignment: HorizontalAlignment) -> FlowStackLayout {
+        FlowStackLayout {
+            alignment,
+            line_alignment: VerticalAlignment::Center,
+            spacing: 10.0,
+            line_spacing: 5.0,
+        }
+    }
+
+    #[test]
+    fn wraps_children_onto_lines() {
+        let chips = chips(&[40.0, 50.0, 30.0, 60.0]);
+        let children: Vec<&dyn SubView> = chips.iter().map(|c| c as &dyn SubView).collect();
+        let layout = layout(HorizontalAlignment::Leading);
+
+        // 40 + 10 + 50 = 100 fits, the third chip would not
+        let size = layout.size_that_fits(ProposalSize::new(Some(100.0), None), &children);
+        assert_eq!(size, Size::new(100.0, 45.0));
+
+        let rects = layout.place(Rect::new(Point::zero(), Size::new(100.0, 45.0)), &children);
+        assert_eq!(rects[0].origin(), Point::new(0.0, 0.0));
+        assert_eq!(rects[1].origin(), Point::new(50.0, 0.0));
+        assert_eq!(rects[2].origin(), Point::new(0.0, 25.0));
+        assert_eq!(rects[3].origin(), Point::new(40.0, 25.0));
+
+        // Without a width everything stays on one line
+        let size = layout.size_that_fits(ProposalSize::UNSPECIFIED, &children);
+        assert_eq!(size, Size::new(210.0, 20.0));
+    }
+
+    #[test]
+    fn aligns_lines_and_narrows_wide_children() {
+        let chips = chips(&[40.0, 150.0]);
+        let children: Vec<&dyn SubView> = chips.iter().map(|c| c as &dyn SubView).collect();
+
+        let rects = layout(HorizontalAlignment::Trailing)
+            .place(Rect::new(Point::zero(), Size::new(100.0, 45.0)), &children);
+        assert_eq!(rects[0].origin(), Point::new(60.0, 0.0));
+        assert_eq!(
+            rects[1],
+            Rect::new(Point::new(0.0, 25.0), Size::new(100.0, 20.0))
+        );
+    }
+}
@@ -1,13 +1,16 @@
 //! Horizontal stack layout.
 
 use alloc::{vec, vec::Vec};
-use nami::collection::Collection;
-use waterui_core::{AnyView, View, env::with, id::Identifiable, view::TupleViews, views::ForEach};
+use nami::{Binding, collection::Collection};
+use waterui_core::{
+    AnyView, Metadata, View, env::with, id::Identifiable, view::TupleViews, views::ForEach,
+};
 
 use crate::{
-    Layout, LazyContainer, Point, ProposalSize, Rect, Size, StretchAxis, SubView,
+    Layout, LazyContainer, Point, ProposalSize, Rect, Reorderable, Size, Spacing, StretchAxis,
+    SubView,
     container::FixedContainer,
-    stack::{Axis, VerticalAlignment},
+    stack::{Axis, VerticalAlignment, distribute, stretch_limits},
 };
 
 /// A view that arranges its children in a horizontal line.
@@ -43,6 +46,7 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct HStack<C> {
     layout: HStackLayout,
+    spacing: Spacing,
     contents: C,
 }
 
@@ -338,20 +342,32 @@ impl Layout for HStackLayout {
             .sum();
 
         let remaining_width = (available_width - actual_fixed_width).max(0.0);
-        let stretch_width = if main_axis_stretch_count > 0 {
-            remaining_width / main_axis_stretch_count as f32
-        } else {
-            0.0
-        };
+        // Share the remaining width, keeping bounded spacers within their limits
+        let limits: Vec<(f32, f32)> = main_axis_stretch_indices
+            .iter()
+            .map(|&idx| {
+                let measurement = &measurements[idx];
+                stretch_limits(measurement.stretch_axis, measurement.size.width, || {
+                    let probe = ProposalSize::new(Some(f32::INFINITY), Some(bounds.height()));
+                    children[idx].size_that_fits(probe).width
+                })
+            })
+            .collect();
+        let mut stretch_widths = vec![0.0; children.len()];
+        for (&idx, width) in main_axis_stretch_indices
+            .iter()
+            .zip(distribute(remaining_width, &limits))
+        {
+            stretch_widths[idx] = width;
+        }
 
         // Measure stretching children with their allocated width so cross-axis sizing is accurate.
-        if main_axis_stretch_count > 0 {
-            for idx in &main_axis_stretch_indices {
-                let constrained_proposal =
-                    ProposalSize::new(Some(stretch_width), Some(bounds.height()));
-                measurements[*idx].size = children[*idx].size_that_fits(constrained_proposal);
-                measurements[*idx].size.width = measurements[*idx].size.width.min(stretch_width);
-            }
+        for &idx in &main_axis_stretch_indices {
+            let stretch_width = stretch_widths[idx];
+            let constrained_proposal =
+                ProposalSize::new(Some(stretch_width), Some(bounds.height()));
+            measurements[idx].size = children[idx].size_that_fits(constrained_proposal);
+            measurements[idx].size.width = measurements[idx].size.width.min(stretch_width);
         }
 
         // Place children
@@ -374,7 +390,7 @@ impl Layout for HStackLayout {
             };
 
             let child_width = if measurement.stretches_main_axis() {
-                stretch_width
+                stretch_widths[i]
             } else {
                 measurement.size.width
             };
@@ -404,6 +420,7 @@ impl<C> HStack<(C,)> {
     pub const fn new(alignment: VerticalAlignment, spacing: f32, contents: C) -> Self {
         Self {
             layout: HStackLayout { alignment, spacing },
+            spacing: Spacing::Points(spacing),
             contents: (contents,),
         }
     }
@@ -417,12 +434,25 @@ impl<C> HStack<C> {
         self
     }
 
-    /// Sets the spacing between children in the stack.
+    /// Sets the spacing between children in the stack, either in points or
+    /// as a theme-relative [`Spacing`] token.
     #[must_use]
-    pub const fn spacing(mut self, spacing: f32) -> Self {
-        self.layout.spacing = spacing;
+    pub fn spacing(mut self, spacing: impl Into<Spacing>) -> Self {
+        self.spacing = spacing.into();
         self
     }
+
+    /// Lets users drag the children into a new order, which is written back
+    /// to `items`.
+    ///
+    /// Child `n` must show item `n`. See [`reorder`](crate::reorder).
+    pub fn reorderable<T>(self, items: &Binding<Vec<T>>) -> Metadata<Reorderable>
+    where
+        Self: View,
+        T: Identifiable + Clone + 'static,
+    {
+        Metadata::new(self, Reorderable::new(items))
+    }
 }
 
 impl<V> FromIterator<V> for HStack<(Vec<AnyView>,)>
@@ -447,20 +477,21 @@ where
     F: 'static + Fn(C::Item) -> V,
     V: View,
 {
-    fn body(self, _env: &waterui_core::Environment) -> impl View {
+    fn body(self, env: &waterui_core::Environment) -> impl View {
+        let mut layout = self.layout;
+        layout.spacing = self.spacing.resolve(env);
         // Inject the horizontal axis into the container
-        with(
-            LazyContainer::new(self.layout, self.contents),
-            Axis::Horizontal,
-        )
+        with(LazyContainer::new(layout, self.contents), Axis::Horizontal)
     }
 }
 
 impl<C: TupleViews + 'static> View for HStack<(C,)> {
-    fn body(self, _env: &waterui_core::Environment) -> impl View {
+    fn body(self, env: &waterui_core::Environment) -> impl View {
+        let mut layout = self.layout;
+        layout.spacing = self.spacing.resolve(env);
         // Inject the horizontal axis into the container
         with(
-            FixedContainer::new(self.layout, self.contents.0),
+            FixedContainer::new(layout, self.contents.0),
             Axis::Horizontal,
         )
     }
@@ -1,13 +1,16 @@
 //! Vertical stack layout.
 
 use alloc::{vec, vec::Vec};
-use nami::collection::Collection;
-use waterui_core::{AnyView, View, env::with, id::Identifiable, view::TupleViews, views::ForEach};
+use nami::{Binding, collection::Collection};
+use waterui_core::{
+    AnyView, Metadata, View, env::with, id::Identifiable, view::TupleViews, views::ForEach,
+};
 
 use crate::{
-    Layout, LazyContainer, Point, ProposalSize, Rect, Size, StretchAxis, SubView,
+    Layout, LazyContainer, Point, ProposalSize, Rect, Reorderable, Size, Spacing, StretchAxis,
+    SubView,
     container::FixedContainer,
-    stack::{Axis, HorizontalAlignment},
+    stack::{Axis, HorizontalAlignment, distribute, stretch_limits},
 };
 
 /// Layout engine shared by the public [`VStack`] view.
@@ -72,10 +75,11 @@ impl Layout for VStackLayout {
             .any(ChildMeasurement::stretches_main_axis);
 
         // Height: sum of children that don't stretch on main axis (vertically) + spacing
-        // (axis-expanding components like TextField report their intrinsic height here)
+        // (axis-expanding components like TextField report their intrinsic height here),
+        // plus the minimum height of spacers
         let non_stretch_height: f32 = measurements
             .iter()
-            .filter(|m| !m.stretches_main_axis())
+            .filter(|m| !m.stretches_main_axis() || m.stretch_axis == StretchAxis::MainAxis)
             .map(|m| m.size.height)
             .sum();
 
@@ -125,10 +129,12 @@ impl Layout for VStackLayout {
             .collect();
 
         // Calculate stretch child height - only for main-axis (vertically) stretching children
-        let main_axis_stretch_count = measurements
+        let main_axis_stretch_indices: Vec<usize> = measurements
             .iter()
-            .filter(|m| m.stretches_main_axis())
-            .count();
+            .enumerate()
+            .filter(|(_, m)| m.stretches_main_axis())
+            .map(|(idx, _)| idx)
+            .collect();
         let non_stretch_height: f32 = measurements
             .iter()
             .filter(|m| !m.stretches_main_axis())
@@ -142,11 +148,24 @@ impl Layout for VStackLayout {
         };
 
         let remaining_height = bounds.height() - non_stretch_height - total_spacing;
-        let stretch_height = if main_axis_stretch_count > 0 {
-            (remaining_height / main_axis_stretch_count as f32).max(0.0)
-        } else {
-            0.0
-        };
+        // Share the remaining height, keeping bounded spacers within their limits
+        let limits: Vec<(f32, f32)> = main_axis_stretch_indices
+            .iter()
+            .map(|&idx| {
+                let measurement = &measurements[idx];
+                stretch_limits(measurement.stretch_axis, measurement.size.height, || {
+                    let probe = ProposalSize::new(Some(bounds.width()), Some(f32::INFINITY));
+                    children[idx].size_that_fits(probe).height
+                })
+            })
+            .collect();
+        let mut stretch_heights = vec![0.0; children.len()];
+        for (idx, height) in main_axis_stretch_indices
+            .into_iter()
+            .zip(distribute(remaining_height, &limits))
+        {
+            stretch_heights[idx] = height;
+        }
 
         // Place children
         let mut rects = Vec::with_capacity(children.len());
@@ -169,7 +188,7 @@ impl Layout for VStackLayout {
             };
 
             let child_height = if measurement.stretches_main_axis() {
-                stretch_height
+                stretch_heights[i]
             } else {
                 measurement.size.height
             };
@@ -231,6 +250,7 @@ impl Layout for VStackLayout {
 #[derive(Debug, Clone)]
 pub struct VStack<C> {
     layout: VStackLayout,
+    spacing: Spacing,
     contents: C,
 }
 
@@ -240,6 +260,7 @@ impl<C: TupleViews> VStack<(C,)> {
     pub const fn new(alignment: HorizontalAlignment, spacing: f32, contents: C) -> Self {
         Self {
             layout: VStackLayout { alignment, spacing },
+            spacing: Spacing::Points(spacing),
             contents: (contents,),
         }
     }
@@ -256,6 +277,7 @@ where
     pub fn for_each(collection: C, generator: F) -> Self {
         Self {
             layout: VStackLayout::default(),
+            spacing: Spacing::Points(0.0),
             contents: ForEach::new(collection, generator),
         }
     }
@@ -269,12 +291,25 @@ impl<C> VStack<C> {
         self
     }
 
-    /// Sets the spacing between children in the stack.
+    /// Sets the spacing between children in the stack, either in points or
+    /// as a theme-relative [`Spacing`] token.
     #[must_use]
-    pub const fn spacing(mut self, spacing: f32) -> Self {
-        self.layout.spacing = spacing;
+    pub fn spacing(mut self, spacing: impl Into<Spacing>) -> Self {
+        self.spacing = spacing.into();
         self
     }
+
+    /// Lets users drag the children into a new order, which is written back
+    /// to `items`.
+    ///
+    /// Child `n` must show item `n`. See [`reorder`](crate::reorder).
+    pub fn reorderable<T>(self, items: &Binding<Vec<T>>) -> Metadata<Reorderable>
+    where
+        Self: View,
+        T: Identifiable + Clone + 'static,
+    {
+        Metadata::new(self, Reorderable::new(items))
+    }
 }
 
 impl<V> FromIterator<V> for VStack<(Vec<AnyView>,)>
@@ -299,22 +334,20 @@ where
     F: 'static + Fn(C::Item) -> V,
     V: View,
 {
-    fn body(self, _env: &waterui_core::Environment) -> impl View {
+    fn body(self, env: &waterui_core::Environment) -> impl View {
+        let mut layout = self.layout;
+        layout.spacing = self.spacing.resolve(env);
         // Inject the vertical axis into the container
-        with(
-            LazyContainer::new(self.layout, self.contents),
-            Axis::Vertical,
-        )
+        with(LazyContainer::new(layout, self.contents), Axis::Vertical)
     }
 }
 
 impl<C: TupleViews + 'static> View for VStack<(C,)> {
-    fn body(self, _env: &waterui_core::Environment) -> impl View {
+    fn body(self, env: &waterui_core::Environment) -> impl View {
+        let mut layout = self.layout;
+        layout.spacing = self.spacing.resolve(env);
         // Inject the vertical axis into the container
-        with(
-            FixedContainer::new(self.layout, self.contents.0),
-            Axis::Vertical,
-        )
+        with(FixedContainer::new(layout, self.contents.0), Axis::Vertical)
     }
 }
 
@@ -1,8 +1,11 @@
 //! Overlay stack layout for multiple layers.
 
 use alloc::{vec, vec::Vec};
-use nami::collection::Collection;
-use waterui_core::{AnyView, View, id::Identifiable, view::TupleViews, views::ForEach};
+use nami::{Computed, collection::Collection, signal::IntoComputed};
+use waterui_core::{
+    AnyView, Environment, View, id::Identifiable, metadata::MetadataKey, view::TupleViews,
+    views::ForEach,
+};
 
 use crate::{
     Layout, LazyContainer, Point, ProposalSize, Rect, Size, StretchAxis, SubView,
@@ -25,6 +28,7 @@ struct ChildMeasurement {
 pub struct ZStackLayout {
     /// The alignment used to position children within the `ZStack`
     pub alignment: Alignment,
+    alignments: Vec<Option<Alignment>>,
 }
 
 impl Layout for ZStackLayout {
@@ -91,7 +95,7 @@ impl Layout for ZStackLayout {
         // Place each child according to alignment
         let mut rects = Vec::with_capacity(children.len());
 
-        for measurement in &measurements {
+        for (index, measurement) in measurements.iter().enumerate() {
             // Handle infinite dimensions (axis-expanding views)
             let child_width = if measurement.size.width.is_infinite() {
                 bounds.width()
@@ -106,7 +110,12 @@ impl Layout for ZStackLayout {
             };
 
             let child_size = Size::new(child_width, child_height);
-            let (x, y) = self.calculate_position(&bounds, child_size);
+            let alignment = self
+                .alignments
+                .get(index)
+                .flatten()
+                .unwrap_or(self.alignment);
+            let (x, y) = Self::calculate_position(alignment, &bounds, child_size);
 
             rects.push(Rect::new(Point::new(x, y), child_size));
         }
@@ -116,12 +125,30 @@ impl Layout for ZStackLayout {
 }
 
 impl ZStackLayout {
+    /// Creates a layout aligning every child with `alignment`.
+    #[must_use]
+    pub const fn new(alignment: Alignment) -> Self {
+        Self {
+            alignment,
+            alignments: Vec::new(),
+        }
+    }
+
+    /// Aligns children individually, in child order.
+    ///
+    /// Children without an alignment use the stack's alignment.
+    #[must_use]
+    pub fn with_alignments(mut self, alignments: Vec<Option<Alignment>>) -> Self {
+        self.alignments = alignments;
+        self
+    }
+
     /// Calculate the position of a child within the `ZStack` bounds based on alignment
-    fn calculate_position(&self, bound: &Rect, child_size: Size) -> (f32, f32) {
+    fn calculate_position(alignment: Alignment, bound: &Rect, child_size: Size) -> (f32, f32) {
         let available_width = bound.width();
         let available_height = bound.height();
 
-        match self.alignment {
+        match alignment {
             Alignment::TopLeading => (bound.x(), bound.y()),
             Alignment::Top => (
                 bound.x() + (available_width - child_size.width) / 2.0,
@@ -174,6 +201,16 @@ impl ZStackLayout {
 /// ))
 /// ```
 ///
+/// Children can override the stack's alignment, and be raised above their
+/// siblings:
+///
+/// ```ignore
+/// zstack((
+///     content_view,
+///     toast.z_index(1).zstack_alignment(Alignment::Bottom),
+/// ))
+/// ```
+///
 /// **Note:** If you only need a decorative background without affecting layout size,
 /// use `.background()` instead.
 #[derive(Debug, Clone)]
@@ -219,7 +256,7 @@ impl<C: TupleViews> ZStack<(C,)> {
     /// * `contents` - A collection of views to be stacked
     pub const fn new(alignment: Alignment, contents: C) -> Self {
         Self {
-            layout: ZStackLayout { alignment },
+            layout: ZStackLayout::new(alignment),
             contents: (contents,),
         }
     }
@@ -246,8 +283,15 @@ impl<C> View for ZStack<(C,)>
 where
     C: TupleViews + 'static,
 {
-    fn body(self, _env: &waterui_core::Environment) -> impl View {
-        FixedContainer::new(self.layout, self.contents.0)
+    fn body(self, _env: &Environment) -> impl View {
+        let (alignments, contents): (Vec<Option<Alignment>>, Vec<AnyView>) = self
+            .contents
+            .0
+            .into_views()
+            .into_iter()
+            .map(into_item)
+            .unzip();
+        FixedContainer::new(self.layout.with_alignments(alignments), contents)
     }
 }
 
@@ -258,11 +302,72 @@ where
     F: 'static + Fn(C::Item) -> V,
     V: View,
 {
-    fn body(self, _env: &waterui_core::Environment) -> impl View {
+    fn body(self, _env: &Environment) -> impl View {
         LazyContainer::new(self.layout, self.contents)
     }
 }
 
+/// A view carrying its own alignment within the enclosing [`ZStack`].
+///
+/// Created by the `zstack_alignment` view modifier. Only stacks with fixed
+/// contents honor it; elsewhere the content is shown as is. Apply it after
+/// other modifiers so the stack can see it.
+#[derive(Debug)]
+#[must_use]
+pub struct ZStackItem {
+    alignment: Alignment,
+    content: AnyView,
+}
+
+impl ZStackItem {
+    /// Aligns `content` within the stack, overriding the stack's alignment.
+    pub fn new(content: impl View, alignment: Alignment) -> Self {
+        let content = match AnyView::new(content).downcast::<Self>() {
+            Ok(inner) => inner.content,
+            Err(content) => content,
+        };
+        Self { alignment, content }
+    }
+
+    /// Returns the alignment within the stack.
+    #[must_use]
+    pub const fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+}
+
+impl View for ZStackItem {
+    fn body(self, _env: &Environment) -> impl View {
+        self.content
+    }
+}
+
+/// Splits a stack child into its own alignment, if any, and its content.
+fn into_item(child: AnyView) -> (Option<Alignment>, AnyView) {
+    match child.downcast::<ZStackItem>() {
+        Ok(item) => (Some(item.alignment), item.content),
+        Err(child) => (None, child),
+    }
+}
+
+/// Drawing order of a view among the siblings it overlaps.
+///
+/// Siblings with a higher index are drawn above those with a lower one, and
+/// siblings with the same index keep their declaration order. Views without
+/// this metadata have an index of 0. The index is reactive, so an overlay
+/// such as a toast can stay above content inserted after it.
+#[derive(Debug, Clone)]
+pub struct ZIndex(pub Computed<i32>);
+
+impl MetadataKey for ZIndex {}
+
+impl ZIndex {
+    /// Creates a z-index from a static or reactive value.
+    pub fn new(index: impl IntoComputed<i32>) -> Self {
+        Self(index.into_computed())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -286,9 +391,7 @@ mod tests {
 
     #[test]
     fn test_zstack_size_multiple_children() {
-        let layout = ZStackLayout {
-            alignment: Alignment::Center,
-        };
+        let layout = ZStackLayout::new(Alignment::Center);
 
         let mut child1 = MockSubView {
             size: Size::new(50.0, 30.0),
@@ -311,9 +414,7 @@ mod tests {
 
     #[test]
     fn test_zstack_placement_center() {
-        let layout = ZStackLayout {
-            alignment: Alignment::Center,
-        };
+        let layout = ZStackLayout::new(Alignment::Center);
 
         let mut child1 = MockSubView {
             size: Size::new(40.0, 20.0),
@@ -335,4 +436,30 @@ mod tests {
         assert!((rects[1].x() - 20.0).abs() < f32::EPSILON); // (100 - 60) / 2
         assert!((rects[1].y() - 30.0).abs() < f32::EPSILON); // (100 - 40) / 2
     }
+
+    #[test]
+    fn test_zstack_per_child_alignment() {
+        let layout = ZStackLayout::new(Alignment::Center)
+            .with_alignments(vec![None, Some(Alignment::BottomTrailing)]);
+
+        let mut child1 = MockSubView {
+            size: Size::new(40.0, 20.0),
+        };
+        let mut child2 = MockSubView {
+            size: Size::new(60.0, 40.0),
+        };
+
+        let children: Vec<&dyn SubView> = vec![&mut child1, &mut child2];
+
+        let bounds = Rect::new(Point::new(0.0, 0.0), Size::new(100.0, 100.0));
+        let rects = layout.place(bounds, &children);
+
+        // Child 1: uses the stack's center alignment
+        assert!((rects[0].x() - 30.0).abs() < f32::EPSILON);
+        assert!((rects[0].y() - 40.0).abs() < f32::EPSILON);
+
+        // Child 2: pinned to the bottom-trailing corner
+        assert!((rects[1].x() - 40.0).abs() < f32::EPSILON);
+        assert!((rects[1].y() - 60.0).abs() < f32::EPSILON);
+    }
 }
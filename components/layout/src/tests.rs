@@ -1533,3 +1533,80 @@ fn test_hstack_horizontal_stretch_textfield() {
     );
     assert_eq!(rects[2].width(), 80.0, "Button should keep intrinsic width");
 }
+
+// ============================================================================
+// Bounded Spacer Tests
+// ============================================================================
+
+/// A real [`Spacer`](crate::Spacer) measured through its [`SpacerLayout`](crate::spacer::SpacerLayout).
+struct BoundedSpacerView(crate::spacer::SpacerLayout);
+
+impl BoundedSpacerView {
+    fn new(spacer: crate::Spacer) -> Self {
+        Self(spacer.into())
+    }
+}
+
+impl SubView for BoundedSpacerView {
+    fn size_that_fits(&self, proposal: ProposalSize) -> Size {
+        self.0.size_that_fits(proposal, &[])
+    }
+    fn stretch_axis(&self) -> StretchAxis {
+        StretchAxis::MainAxis
+    }
+    fn priority(&self) -> i32 {
+        0
+    }
+}
+
+#[test]
+fn test_hstack_spacer_max_leaves_space_to_other_spacers() {
+    let layout = HStackLayout {
+        alignment: VerticalAlignment::Center,
+        spacing: 0.0,
+    };
+
+    let mut bounded = BoundedSpacerView::new(crate::spacer().min(8.0).max(64.0));
+    let mut child = FixedSizeView {
+        size: Size::new(40.0, 30.0),
+    };
+    let mut free = BoundedSpacerView::new(crate::spacer());
+
+    let bounds = Rect::new(Point::zero(), Size::new(300.0, 50.0));
+    let children: Vec<&dyn SubView> = vec![&mut bounded, &mut child, &mut free];
+
+    let rects = layout.place(bounds, &children);
+
+    // Remaining space = 300 - 40 = 260; the bounded spacer stops at 64
+    assert_eq!(rects[0].width(), 64.0);
+    assert_eq!(rects[1].x(), 64.0);
+    assert_eq!(rects[2].width(), 196.0);
+}
+
+#[test]
+fn test_vstack_spacer_min_is_kept() {
+    let layout = VStackLayout {
+        alignment: HorizontalAlignment::Center,
+        spacing: 0.0,
+    };
+
+    let mut top = FixedSizeView {
+        size: Size::new(100.0, 90.0),
+    };
+    let mut spacer = BoundedSpacerView::new(crate::spacer().min(24.0).max(64.0));
+    let mut bottom = FixedSizeView {
+        size: Size::new(100.0, 90.0),
+    };
+
+    let children: Vec<&dyn SubView> = vec![&mut top, &mut spacer, &mut bottom];
+
+    // Intrinsic height includes the spacer's minimum
+    let size = layout.size_that_fits(ProposalSize::new(Some(100.0), None), &children);
+    assert_eq!(size.height, 204.0);
+
+    // Not enough room: the spacer keeps its minimum
+    let bounds = Rect::new(Point::zero(), Size::new(100.0, 190.0));
+    let rects = layout.place(bounds, &children);
+    assert_eq!(rects[1].height(), 24.0);
+    assert_eq!(rects[2].y(), 114.0);
+}
@@ -0,0 +1,253 @@
+//! Vector animations in the Lottie and dotLottie formats.
+//!
+//! [`AnimatedVector`] plays an animation exported from After Effects or a
+//! similar tool. Native backends hand it to the platform's Lottie player;
+//! self-drawn backends interpret it themselves and drive playback with
+//! [`AnimatedVectorConfig::advance`].
+//!
+//! Playback is controlled through two bindings, so the animation can be
+//! paused or scrubbed reactively:
+//!
+//! ```ignore
+//! use waterui_media::animated_vector::AnimatedVector;
+//!
+//! let playing = binding(false);
+//! let progress = binding(0.0_f32);
+//! vstack((
+//!     AnimatedVector::new("confetti.lottie")
+//!         .playing(&playing)
+//!         .progress(&progress)
+//!         .loops(false),
+//!     Slider::new(&progress),
+//! ))
+//! ```
+
+use alloc::{boxed::Box, string::String};
+use core::time::Duration;
+
+use waterui_core::{
+    Binding, Computed, binding, configurable, layout::StretchAxis, reactive::signal::IntoComputed,
+};
+
+use crate::{Url, async_image::ContentMode};
+
+/// The file format of a vector animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorFormat {
+    /// A Lottie JSON document.
+    Lottie,
+    /// A dotLottie archive, a zip file bundling animations and their assets.
+    DotLottie,
+}
+
+impl VectorFormat {
+    /// Guesses the format of `url` from its extension.
+    ///
+    /// Anything other than `.lottie` is treated as Lottie JSON.
+    #[must_use]
+    pub fn of(url: &Url) -> Self {
+        let is_archive = url
+            .path()
+            .rsplit_once('.')
+            .is_some_and(|(_, extension)| extension.eq_ignore_ascii_case("lottie"));
+        if is_archive {
+            Self::DotLottie
+        } else {
+            Self::Lottie
+        }
+    }
+}
+
+/// Events emitted by an [`AnimatedVector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The animation was loaded and lasts `duration` at normal speed.
+    Loaded {
+        /// The length of one pass through the animation.
+        duration: Duration,
+    },
+    /// The animation reached its end without looping.
+    Completed,
+    /// The animation could not be loaded.
+    Error(String),
+}
+
+type OnEvent = Box<dyn Fn(Event) + 'static>;
+
+/// Configuration for the [`AnimatedVector`] component.
+pub struct AnimatedVectorConfig {
+    /// The URL of the Lottie or dotLottie file.
+    pub source: Computed<Url>,
+    /// Whether the animation is playing.
+    ///
+    /// Backends set it to `false` when a non-looping animation completes.
+    pub playing: Binding<bool>,
+    /// How far the animation has played, from 0 to 1.
+    ///
+    /// Backends write it while playing and seek when it is set from outside.
+    pub progress: Binding<f32>,
+    /// Whether the animation starts over when it ends.
+    pub loops: bool,
+    /// The playback speed, where 1 is normal speed and negative values play
+    /// backwards.
+    pub speed: f32,
+    /// How the animation is drawn into its bounds.
+    pub content_mode: ContentMode,
+    /// Event handler for loading and playback events.
+    pub on_event: OnEvent,
+}
+
+impl core::fmt::Debug for AnimatedVectorConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AnimatedVectorConfig")
+            .field("loops", &self.loops)
+            .field("speed", &self.speed)
+            .field("content_mode", &self.content_mode)
+            .finish_non_exhaustive()
+    }
+}
+
+configurable!(
+    /// A Lottie or dotLottie animation.
+    ///
+    /// # Platform Implementation
+    ///
+    /// - **iOS/macOS**: Uses the Lottie player
+    /// - **Android**: Uses `LottieAnimationView`
+    /// - **Hydrolysis**: Interprets Lottie JSON with Vello
+    AnimatedVector,
+    AnimatedVectorConfig,
+    |config| match config.content_mode {
+        ContentMode::Fit => StretchAxis::Horizontal,
+        ContentMode::Fill | ContentMode::Tile => StretchAxis::Both,
+    }
+);
+
+impl AnimatedVector {
+    /// Creates an animation loaded from `source` that plays in a loop.
+    pub fn new(source: impl IntoComputed<Url>) -> Self {
+        Self(AnimatedVectorConfig {
+            source: source.into_computed(),
+            playing: binding(true),
+            progress: binding(0.0),
+            loops: true,
+            speed: 1.0,
+            content_mode: ContentMode::default(),
+            on_event: Box::new(|_| {}),
+        })
+    }
+
+    /// Plays the animation while `playing` is `true`.
+    #[must_use]
+    pub fn playing(mut self, playing: &Binding<bool>) -> Self {
+        self.0.playing = playing.clone();
+        self
+    }
+
+    /// Binds the playback position, from 0 to 1, to `progress`.
+    ///
+    /// Setting it seeks the animation, so a slider bound to it scrubs.
+    #[must_use]
+    pub fn progress(mut self, progress: &Binding<f32>) -> Self {
+        self.0.progress = progress.clone();
+        self
+    }
+
+    /// Sets whether the animation starts over when it ends.
+    #[must_use]
+    pub const fn loops(mut self, loops: bool) -> Self {
+        self.0.loops = loops;
+        self
+    }
+
+    /// Sets the playback speed, where negative values play backwards.
+    #[must_use]
+    pub const fn speed(mut self, speed: f32) -> Self {
+        self.0.speed = speed;
+        self
+    }
+
+    /// Sets how the animation is drawn into its bounds.
+    #[must_use]
+    pub const fn content_mode(mut self, mode: ContentMode) -> Self {
+        self.0.content_mode = mode;
+        self
+    }
+
+    /// Sets the event handler for loading and playback events.
+    #[must_use]
+    pub fn on_event(mut self, handler: impl Fn(Event) + 'static) -> Self {
+        self.0.on_event = Box::new(handler);
+        self
+    }
+}
+
+impl AnimatedVectorConfig {
+    /// Moves playback forward by `elapsed` for an animation lasting
+    /// `duration`.
+    ///
+    /// For backends that draw animations themselves. Does nothing while
+    /// paused. When a non-looping animation reaches its end, playback stops
+    /// and [`Event::Completed`] is emitted.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn advance(&self, elapsed: Duration, duration: Duration) {
+        if !self.playing.get() || duration.is_zero() {
+            return;
+        }
+        let step = (elapsed.as_secs_f64() / duration.as_secs_f64()) as f32 * self.speed;
+        let progress = self.progress.get() + step;
+        if (0.0..=1.0).contains(&progress) {
+            self.progress.set(progress);
+        } else if self.loops {
+            self.progress.set(progress.rem_euclid(1.0));
+        } else {
+            self.progress.set(progress.clamp(0.0, 1.0));
+            self.playing.set(false);
+            (self.on_event)(Event::Completed);
+        }
+    }
+}
+
+/// Convenience constructor for building an `AnimatedVector` inline.
+pub fn animated_vector(source: impl IntoComputed<Url>) -> AnimatedVector {
+    AnimatedVector::new(source)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    use super::*;
+
+    #[test]
+    fn advances_loops_and_completes() {
+        let AnimatedVector(mut config) = AnimatedVector::new(Url::from("spinner.json"));
+        let second = Duration::from_secs(1);
+        config.advance(Duration::from_millis(250), second);
+        assert!((config.progress.get() - 0.25).abs() < 1e-6);
+        config.advance(second, second);
+        assert!((config.progress.get() - 0.25).abs() < 1e-6);
+
+        let completed = Rc::new(Cell::new(false));
+        let flag = completed.clone();
+        config.loops = false;
+        config.on_event = Box::new(move |event| flag.set(event == Event::Completed));
+        config.advance(second, second);
+        assert!((config.progress.get() - 1.0).abs() < f32::EPSILON);
+        assert!(!config.playing.get());
+        assert!(completed.get());
+    }
+
+    #[test]
+    fn detects_the_format_from_the_extension() {
+        assert_eq!(
+            VectorFormat::of(&Url::from("https://example.com/a.LOTTIE?v=2")),
+            VectorFormat::DotLottie
+        );
+        assert_eq!(
+            VectorFormat::of(&Url::from("assets/a.json")),
+            VectorFormat::Lottie
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! An image loaded in the background with placeholder and error views.
+//!
+//! [`AsyncImage`] shows its placeholder while the backend downloads and
+//! decodes the image, then the image itself, or the error view if loading
+//! failed:
+//!
+//! ```ignore
+//! use waterui_media::async_image::{AsyncImage, ContentMode};
+//!
+//! AsyncImage::new("https://example.com/avatar.jpg")
+//!     .placeholder(ProgressView::new())
+//!     .error_view(text("No avatar"))
+//!     .content_mode(ContentMode::Fill)
+//!     .target_size(64.0, 64.0)
+//! ```
+//!
+//! Backends share one in-memory and on-disk cache between all images, keyed by
+//! [`cache_key`]. URLs that only differ in case, default ports, dot segments
+//! or fragments therefore load once. [`PhotoCache`](crate::photo::PhotoCache)
+//! prefetches warm the same cache.
+
+use alloc::string::String;
+
+use waterui_core::{
+    AnyView, View, configurable,
+    layout::{Size, StretchAxis},
+};
+
+use crate::{Url, photo::Event};
+
+/// How an image is drawn into the space it is given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContentMode {
+    /// Scales the image to fit inside the bounds, keeping its aspect ratio.
+    #[default]
+    Fit,
+    /// Scales the image to cover the bounds, keeping its aspect ratio and
+    /// cropping the overflow.
+    Fill,
+    /// Repeats the image at its natural size to cover the bounds.
+    Tile,
+}
+
+/// Where a loaded image is kept for later use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CachePolicy {
+    /// Keeps the image in memory and on disk.
+    #[default]
+    MemoryAndDisk,
+    /// Keeps the image in memory only, such as for private content.
+    MemoryOnly,
+    /// Always loads the image from its source.
+    Disabled,
+}
+
+type OnEvent = Box<dyn Fn(Event) + 'static>;
+
+/// Configuration for the [`AsyncImage`] component.
+pub struct AsyncImageConfig {
+    /// The URL of the image.
+    pub source: Url,
+    /// The key the image is cached under, see [`cache_key`].
+    pub cache_key: Url,
+    /// Where the loaded image is cached.
+    pub cache_policy: CachePolicy,
+    /// How the image is drawn into its bounds.
+    pub content_mode: ContentMode,
+    /// The size, in points, the image is displayed at, if known.
+    ///
+    /// Backends decode large images down to this size times the display scale
+    /// to save memory.
+    pub target_size: Option<Size>,
+    /// Shown while the image is loading.
+    pub placeholder: AnyView,
+    /// Shown if the image fails to load.
+    pub error: AnyView,
+    /// Event handler for loading events.
+    pub on_event: OnEvent,
+}
+
+impl core::fmt::Debug for AsyncImageConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AsyncImageConfig")
+            .field("source", &self.source)
+            .field("cache_key", &self.cache_key)
+            .field("cache_policy", &self.cache_policy)
+            .field("content_mode", &self.content_mode)
+            .field("target_size", &self.target_size)
+            .field("placeholder", &self.placeholder)
+            .field("error", &self.error)
+            .finish_non_exhaustive()
+    }
+}
+
+configurable!(
+    #[doc = "An image loaded in the background, with placeholder and error views."]
+    AsyncImage,
+    AsyncImageConfig,
+    |config| match config.content_mode {
+        ContentMode::Fit => StretchAxis::Horizontal,
+        ContentMode::Fill | ContentMode::Tile => StretchAxis::Both,
+    }
+);
+
+impl AsyncImage {
+    /// Creates an image loaded from `source`.
+    ///
+    /// Until it is loaded, nothing is shown.
+    pub fn new(source: impl Into<Url>) -> Self {
+        let source = source.into();
+        Self(AsyncImageConfig {
+            cache_key: cache_key(&source),
+            source,
+            cache_policy: CachePolicy::default(),
+            content_mode: ContentMode::default(),
+            target_size: None,
+            placeholder: AnyView::default(),
+            error: AnyView::default(),
+            on_event: Box::new(|_event| {}),
+        })
+    }
+
+    /// Sets the view shown while the image is loading.
+    #[must_use]
+    pub fn placeholder(mut self, placeholder: impl View) -> Self {
+        self.0.placeholder = AnyView::new(placeholder);
+        self
+    }
+
+    /// Sets the view shown if the image fails to load.
+    #[must_use]
+    pub fn error_view(mut self, error: impl View) -> Self {
+        self.0.error = AnyView::new(error);
+        self
+    }
+
+    /// Sets how the image is drawn into its bounds.
+    #[must_use]
+    pub const fn content_mode(mut self, mode: ContentMode) -> Self {
+        self.0.content_mode = mode;
+        self
+    }
+
+    /// Sets where the loaded image is cached.
+    #[must_use]
+    pub const fn cache_policy(mut self, policy: CachePolicy) -> Self {
+        self.0.cache_policy = policy;
+        self
+    }
+
+    /// Hints the size, in points, the image is displayed at.
+    ///
+    /// Backends use it to decode large images at a smaller resolution.
+    #[must_use]
+    pub const fn target_size(mut self, width: f32, height: f32) -> Self {
+        self.0.target_size = Some(Size::new(width, height));
+        self
+    }
+
+    /// Sets the event handler for loading events.
+    #[must_use]
+    pub fn on_event(mut self, handler: impl Fn(Event) + 'static) -> Self {
+        self.0.on_event = Box::new(handler);
+        self
+    }
+}
+
+/// Convenience constructor for building an `AsyncImage` inline.
+pub fn async_image(source: impl Into<Url>) -> AsyncImage {
+    AsyncImage::new(source)
+}
+
+/// Returns the key `url` is cached under.
+///
+/// This is the normalized URL without its fragment, which never reaches the
+/// server.
+#[must_use]
+pub fn cache_key(url: &Url) -> Url {
+    let normalized = url.normalize();
+    let raw = normalized.as_str();
+    match raw.find('#') {
+        Some(fragment) if url.is_web() => Url::from(String::from(&raw[..fragment])),
+        _ => normalized,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equivalent_urls_share_a_cache_key() {
+        let key = cache_key(&Url::from("https://example.com/a/b.png?size=2"));
+        assert_eq!(
+            cache_key(&Url::from("HTTPS://Example.com:443/a/./b.png?size=2#top")),
+            key
+        );
+        assert_ne!(
+            cache_key(&Url::from("https://example.com/a/b.png?size=3")),
+            key
+        );
+    }
+}
@@ -0,0 +1,175 @@
+//! Audio playback.
+//!
+//! [`AudioPlayer`] plays an audio file or stream while it is in the view
+//! tree. It draws nothing and takes no space; build the controls you need
+//! from its bindings:
+//!
+//! ```ignore
+//! use waterui_media::audio::{AudioPlayer, Event};
+//!
+//! let playing = binding(false);
+//! let position = binding(Duration::ZERO);
+//! let rate = binding(1.0_f32);
+//!
+//! vstack((
+//!     AudioPlayer::new("https://example.com/episode.mp3")
+//!         .playing(&playing)
+//!         .position(&position)
+//!         .rate(&rate)
+//!         .background(true)
+//!         .on_event(|event| {
+//!             if let Event::Error { message } = event {
+//!                 tracing::warn!("playback failed: {message}");
+//!             }
+//!         }),
+//!     Toggle::new(&playing).label(text("Play")),
+//! ))
+//! ```
+//!
+//! Setting `position` seeks; while playing, backends keep it up to date.
+
+use alloc::{boxed::Box, string::String};
+use core::time::Duration;
+
+use waterui_core::{Binding, Computed, binding, configurable, reactive::signal::IntoComputed};
+
+use crate::{Url, video::Volume};
+
+/// Events emitted by an [`AudioPlayer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The audio is ready to play.
+    ReadyToPlay {
+        /// The length of the audio, or zero for live streams.
+        duration: Duration,
+    },
+    /// Playback stalled while more audio is loaded.
+    Buffering,
+    /// Playback resumed after buffering.
+    BufferingEnded,
+    /// Playback reached the end of the audio.
+    Ended,
+    /// The audio could not be loaded or played.
+    Error {
+        /// The error message describing what went wrong.
+        message: String,
+    },
+}
+
+type OnEvent = Box<dyn Fn(Event) + 'static>;
+
+/// Configuration for the [`AudioPlayer`] component.
+pub struct AudioPlayerConfig {
+    /// The URL of the audio source.
+    pub source: Computed<Url>,
+    /// Whether the audio is playing.
+    ///
+    /// Backends set it to `false` when playback ends without looping.
+    pub playing: Binding<bool>,
+    /// The playback position.
+    ///
+    /// Backends write it while playing and seek when it is set from outside.
+    pub position: Binding<Duration>,
+    /// The volume, using the same muted encoding as [`Volume`].
+    pub volume: Binding<Volume>,
+    /// The playback rate, where 1 is normal speed.
+    pub rate: Binding<f32>,
+    /// Whether the audio keeps playing while the app is in the background.
+    pub background: bool,
+    /// Whether the audio starts over when it ends.
+    pub loops: bool,
+    /// The event handler for playback events.
+    pub on_event: OnEvent,
+}
+
+impl core::fmt::Debug for AudioPlayerConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AudioPlayerConfig")
+            .field("background", &self.background)
+            .field("loops", &self.loops)
+            .finish_non_exhaustive()
+    }
+}
+
+configurable!(
+    /// An invisible view playing audio while it is in the view tree.
+    ///
+    /// # Platform Implementation
+    ///
+    /// - **iOS/macOS**: Uses `AVPlayer`
+    /// - **Android**: Uses ExoPlayer
+    AudioPlayer,
+    AudioPlayerConfig
+);
+
+impl AudioPlayer {
+    /// Creates a paused audio player for `source`.
+    pub fn new(source: impl IntoComputed<Url>) -> Self {
+        Self(AudioPlayerConfig {
+            source: source.into_computed(),
+            playing: binding(false),
+            position: binding(Duration::ZERO),
+            volume: binding(1.0),
+            rate: binding(1.0),
+            background: false,
+            loops: false,
+            on_event: Box::new(|_| {}),
+        })
+    }
+
+    /// Plays the audio while `playing` is `true`.
+    #[must_use]
+    pub fn playing(mut self, playing: &Binding<bool>) -> Self {
+        self.0.playing = playing.clone();
+        self
+    }
+
+    /// Binds the playback position to `position`; setting it seeks.
+    #[must_use]
+    pub fn position(mut self, position: &Binding<Duration>) -> Self {
+        self.0.position = position.clone();
+        self
+    }
+
+    /// Sets the volume binding.
+    #[must_use]
+    pub fn volume(mut self, volume: &Binding<Volume>) -> Self {
+        self.0.volume = volume.clone();
+        self
+    }
+
+    /// Sets the playback rate binding, where 1 is normal speed.
+    #[must_use]
+    pub fn rate(mut self, rate: &Binding<f32>) -> Self {
+        self.0.rate = rate.clone();
+        self
+    }
+
+    /// Sets whether the audio keeps playing in the background.
+    ///
+    /// Apps also need the platform's background audio capability.
+    #[must_use]
+    pub const fn background(mut self, background: bool) -> Self {
+        self.0.background = background;
+        self
+    }
+
+    /// Sets whether the audio starts over when it ends.
+    #[must_use]
+    pub const fn loops(mut self, loops: bool) -> Self {
+        self.0.loops = loops;
+        self
+    }
+
+    /// Sets the event handler for playback events.
+    #[must_use]
+    pub fn on_event(mut self, handler: impl Fn(Event) + 'static) -> Self {
+        self.0.on_event = Box::new(handler);
+        self
+    }
+}
+
+/// Convenience constructor for building an `AudioPlayer` inline.
+pub fn audio_player(source: impl IntoComputed<Url>) -> AudioPlayer {
+    AudioPlayer::new(source)
+}
@@ -0,0 +1,217 @@
+//! A bounded pool for decoding images off the main thread.
+//!
+//! Decoding a large JPEG can take longer than a frame, so every decode in
+//! `WaterUI` goes through [`decode`]. The work runs on the shared background
+//! thread pool, but only a few decodes run at once, so scrolling through an
+//! image-heavy list cannot flood it. Decodes start in the order they were
+//! requested.
+//!
+//! The returned future resumes wherever it is awaited, so awaiting it from a
+//! view's task delivers the decoded bitmap to the main thread. Dropping the
+//! future, such as when the view disappears, cancels the decode if it has not
+//! started yet and discards its result otherwise:
+//!
+//! ```ignore
+//! let task = spawn_local(async move {
+//!     let bitmap = decode(move || image::load_from_memory(&bytes)).await;
+//!     // Back on the main thread
+//! });
+//! // Dropping `task` cancels the decode
+//! ```
+
+use alloc::collections::VecDeque;
+use core::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::sync::{Mutex, MutexGuard};
+
+use blocking::unblock;
+
+/// The number of decodes running at once unless configured otherwise.
+const DEFAULT_MAX_CONCURRENT: usize = 4;
+
+static POOL: Mutex<Pool> = Mutex::new(Pool {
+    running: 0,
+    limit: None,
+    next_ticket: 0,
+    queue: VecDeque::new(),
+});
+
+/// Decodes running and waiting for a turn.
+struct Pool {
+    running: usize,
+    limit: Option<usize>,
+    next_ticket: u64,
+    queue: VecDeque<(u64, Option<Waker>)>,
+}
+
+impl Pool {
+    fn lock() -> MutexGuard<'static, Self> {
+        POOL.lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn limit(&self) -> usize {
+        self.limit.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map_or(1, |cores| cores.get().saturating_sub(1))
+                .clamp(1, DEFAULT_MAX_CONCURRENT)
+        })
+    }
+
+    /// Wakes the first waiting decode if one may start.
+    fn wake_next(&mut self) {
+        if self.running < self.limit()
+            && let Some((_, Some(waker))) = self.queue.front_mut()
+        {
+            waker.wake_by_ref();
+        }
+    }
+}
+
+/// Returns how many decodes may run at once.
+///
+/// By default this is one less than the number of cores, between 1 and 4.
+#[must_use]
+pub fn max_concurrent_decodes() -> usize {
+    Pool::lock().limit()
+}
+
+/// Sets how many decodes may run at once.
+///
+/// Decodes already running are not interrupted when the limit is lowered.
+///
+/// # Panics
+///
+/// Panics if `limit` is zero.
+pub fn set_max_concurrent_decodes(limit: usize) {
+    assert!(limit > 0, "at least one decode must be able to run");
+    let mut pool = Pool::lock();
+    pool.limit = Some(limit);
+    pool.wake_next();
+}
+
+/// Runs `job` on the decode pool and returns its result.
+///
+/// The job starts once fewer than [`max_concurrent_decodes`] decodes are
+/// running. Dropping the future before then cancels it.
+pub async fn decode<T, F>(job: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let permit = Turn::new().await;
+    unblock(move || {
+        // Held until the job finishes, even if its result is discarded
+        let _permit = permit;
+        job()
+    })
+    .await
+}
+
+/// Waits for a decode to be allowed to start.
+struct Turn {
+    ticket: Option<u64>,
+}
+
+impl Turn {
+    fn new() -> Self {
+        let mut pool = Pool::lock();
+        let ticket = pool.next_ticket;
+        pool.next_ticket += 1;
+        pool.queue.push_back((ticket, None));
+        drop(pool);
+        Self {
+            ticket: Some(ticket),
+        }
+    }
+}
+
+impl Future for Turn {
+    type Output = Permit;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Permit> {
+        let Some(ticket) = self.ticket else {
+            unreachable!("`Turn` polled after completion");
+        };
+        let mut pool = Pool::lock();
+        let first = pool.queue.front().map(|(first, _)| *first);
+        if first == Some(ticket) && pool.running < pool.limit() {
+            pool.queue.pop_front();
+            pool.running += 1;
+            pool.wake_next();
+            drop(pool);
+            self.ticket = None;
+            return Poll::Ready(Permit);
+        }
+        if let Some((_, waker)) = pool.queue.iter_mut().find(|(queued, _)| *queued == ticket) {
+            *waker = Some(cx.waker().clone());
+        }
+        drop(pool);
+        Poll::Pending
+    }
+}
+
+impl Drop for Turn {
+    fn drop(&mut self) {
+        let Some(ticket) = self.ticket else {
+            return;
+        };
+        let mut pool = Pool::lock();
+        pool.queue.retain(|(queued, _)| *queued != ticket);
+        pool.wake_next();
+    }
+}
+
+/// A running decode, which lets the next one start when dropped.
+struct Permit;
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        let mut pool = Pool::lock();
+        pool.running -= 1;
+        pool.wake_next();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    };
+
+    use futures::{FutureExt, executor::block_on};
+
+    use super::*;
+
+    #[test]
+    fn decodes_wait_for_a_turn_and_can_be_cancelled() {
+        set_max_concurrent_decodes(1);
+        let (started, running) = mpsc::channel::<()>();
+        let (release, blocked) = mpsc::channel::<()>();
+        let first = std::thread::spawn(move || {
+            block_on(decode(move || {
+                started.send(()).unwrap();
+                blocked.recv().unwrap();
+            }));
+        });
+        running.recv().unwrap();
+
+        // Waits behind the running decode, then is cancelled
+        let ran = Arc::new(AtomicBool::new(false));
+        let mut waiting = Box::pin(decode({
+            let ran = ran.clone();
+            move || ran.store(true, Ordering::SeqCst)
+        }));
+        assert!((&mut waiting).now_or_never().is_none());
+        drop(waiting);
+
+        release.send(()).unwrap();
+        first.join().unwrap();
+        assert_eq!(block_on(decode(|| 42)), 42);
+        assert!(!ran.load(Ordering::SeqCst));
+    }
+}
@@ -1,11 +1,76 @@
+//! Decoding, transforming and encoding images in Rust.
+//!
+//! All heavy work runs on a background thread pool, so these utilities can be
+//! awaited from UI code without blocking rendering. Decoding goes through the
+//! bounded [`decode`](crate::decode) pool:
+//!
+//! ```ignore
+//! let media = selection.load().await;
+//! let mut avatar = media.load_image().await?;
+//! avatar.resize_to_fill(256, 256).await;
+//! let jpeg = avatar.try_encode_jpeg(85).await?;
+//! ```
+
+use core::fmt::{self, Display};
 use std::{io, path::Path, sync::Arc};
 
 use base64::{Engine, prelude::BASE64_STANDARD};
 use blocking::unblock;
-use image::{DynamicImage, GenericImageView, ImageFormat};
+use image::{DynamicImage, GenericImageView, ImageFormat, codecs::jpeg::JpegEncoder};
 use mime::Mime;
 use waterui_color::{Srgb, WithOpacity};
 
+use crate::{Media, Url, decode::decode, source::LocalSource};
+
+/// Errors that can occur while loading, decoding or encoding an image.
+#[derive(Debug)]
+pub enum ImageError {
+    /// The image format or MIME type is not supported.
+    UnsupportedFormat(String),
+    /// The URL cannot be loaded by the image utilities (e.g. a remote URL).
+    UnsupportedSource(Url),
+    /// The media item has no still image (e.g. a video).
+    NotAnImage,
+    /// Reading the image data failed.
+    Io(io::Error),
+    /// The image data could not be decoded or encoded.
+    Codec(image::ImageError),
+}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedFormat(format) => write!(f, "unsupported image format: {format}"),
+            Self::UnsupportedSource(url) => write!(f, "cannot load image from {url}"),
+            Self::NotAnImage => f.write_str("media item is not an image"),
+            Self::Io(error) => write!(f, "failed to read image: {error}"),
+            Self::Codec(error) => write!(f, "failed to process image: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for ImageError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Codec(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImageError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<image::ImageError> for ImageError {
+    fn from(error: image::ImageError) -> Self {
+        Self::Codec(error)
+    }
+}
+
 /// Represents a loaded image.
 #[derive(Debug, Clone)]
 pub struct Image {
@@ -16,7 +81,7 @@ pub struct Image {
 impl Image {
     /// Creates a new `Image` from raw image data.
     ///
-    /// It will decode the image on a background thread, preventing UI blocking.
+    /// It will decode the image on the decode pool, preventing UI blocking.
     ///
     /// # Panics
     ///
@@ -26,7 +91,7 @@ impl Image {
         let format =
             ImageFormat::from_mime_type(mime.essence_str()).expect("Unsupported MIME type");
 
-        let image = unblock(move || {
+        let image = decode(move || {
             image::load_from_memory_with_format(data.as_ref(), format)
                 .expect("Failed to decode image")
         })
@@ -38,6 +103,82 @@ impl Image {
         }
     }
 
+    /// Decodes an image from raw data, detecting its format from the content.
+    ///
+    /// Decoding happens on the decode pool and is cancelled if the returned
+    /// future is dropped before it starts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the format is not recognized or the data is corrupt.
+    pub async fn decode(data: Vec<u8>) -> Result<Self, ImageError> {
+        let format = image::guess_format(&data)?;
+        let mime = format
+            .to_mime_type()
+            .parse::<Mime>()
+            .map_err(|_| ImageError::UnsupportedFormat(format!("{format:?}")))?;
+        let image = decode(move || image::load_from_memory_with_format(&data, format)).await?;
+        Ok(Self {
+            mime,
+            image: Arc::new(image),
+        })
+    }
+
+    /// Loads and decodes an image from a local file or `data:` URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ImageError::UnsupportedSource`] for remote URLs, or an error if
+    /// the data cannot be read or decoded.
+    pub async fn load(url: &Url) -> Result<Self, ImageError> {
+        let source =
+            LocalSource::new(url).ok_or_else(|| ImageError::UnsupportedSource(url.clone()))?;
+        let data = unblock(move || source.read()).await?;
+        Self::decode(data).await
+    }
+
+    /// Returns the underlying decoded image.
+    #[must_use]
+    pub fn as_dynamic(&self) -> &DynamicImage {
+        &self.image
+    }
+
+    /// Encodes the image to the specified MIME type.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the MIME type is not supported or encoding fails.
+    pub async fn try_encode(&self, mime: &Mime) -> Result<Vec<u8>, ImageError> {
+        let format = ImageFormat::from_mime_type(mime.essence_str())
+            .ok_or_else(|| ImageError::UnsupportedFormat(mime.to_string()))?;
+        let image = self.image.clone();
+        let data = unblock(move || {
+            let mut buf = io::Cursor::new(Vec::new());
+            image.write_to(&mut buf, format).map(|()| buf.into_inner())
+        })
+        .await?;
+        Ok(data)
+    }
+
+    /// Encodes the image as JPEG with the given quality (1-100).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if encoding fails.
+    pub async fn try_encode_jpeg(&self, quality: u8) -> Result<Vec<u8>, ImageError> {
+        let image = self.image.clone();
+        let data = unblock(move || {
+            let mut buf = Vec::new();
+            // JPEG has no alpha channel
+            let rgb = image.to_rgb8();
+            JpegEncoder::new_with_quality(&mut buf, quality.clamp(1, 100))
+                .encode_image(&rgb)
+                .map(|()| buf)
+        })
+        .await?;
+        Ok(data)
+    }
+
     /// Process the image with a closure on a background thread
     pub async fn process<F>(&mut self, func: F)
     where
@@ -74,10 +215,16 @@ impl Image {
         self.encode(mime::IMAGE_PNG).await
     }
 
-    /// Encodes the image as JPEG with the specified quality (currently unused).
+    /// Encodes the image as JPEG with the specified quality (1-100).
+    ///
+    /// # Panics
+    ///
+    /// Panics if encoding fails.
     #[must_use]
-    pub async fn encode_jpeg(&self, _quality: u8) -> Vec<u8> {
-        self.encode(mime::IMAGE_JPEG).await
+    pub async fn encode_jpeg(&self, quality: u8) -> Vec<u8> {
+        self.try_encode_jpeg(quality)
+            .await
+            .expect("Failed to encode image")
     }
 
     /// Rotates the image by the specified angle in degrees (0, 90, 180, or 270).
@@ -296,3 +443,82 @@ impl Image {
         }
     }
 }
+
+impl Media {
+    /// Loads the still image of this media item.
+    ///
+    /// For a Live Photo this is its key photo.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ImageError::NotAnImage`] for videos, or an error if the image
+    /// cannot be loaded.
+    pub async fn load_image(&self) -> Result<Image, ImageError> {
+        match self {
+            Self::Image(url) => Image::load(url).await,
+            Self::LivePhoto(live) => Image::load(&live.image).await,
+            Self::Video(_) => Err(ImageError::NotAnImage),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    fn checkerboard_png() -> Vec<u8> {
+        let image = image::RgbaImage::from_fn(4, 2, |x, y| {
+            if (x + y) % 2 == 0 {
+                image::Rgba([255, 0, 0, 255])
+            } else {
+                image::Rgba([0, 0, 255, 255])
+            }
+        });
+        let mut buf = io::Cursor::new(Vec::new());
+        DynamicImage::ImageRgba8(image)
+            .write_to(&mut buf, ImageFormat::Png)
+            .unwrap();
+        buf.into_inner()
+    }
+
+    #[test]
+    fn decode_transform_encode_round_trip() {
+        block_on(async {
+            let mut image = Image::decode(checkerboard_png()).await.unwrap();
+            assert_eq!(image.mime(), &mime::IMAGE_PNG);
+            assert_eq!(image.dimensions(), (4, 2));
+
+            image.rotate_90().await;
+            assert_eq!(image.dimensions(), (2, 4));
+            assert!(image.crop(0, 1, 2, 2).await);
+            assert!(!image.crop(1, 1, 4, 4).await);
+            image.resize_exact(8, 8).await;
+            assert_eq!(image.dimensions(), (8, 8));
+
+            let jpeg = image.try_encode_jpeg(80).await.unwrap();
+            let decoded = Image::decode(jpeg).await.unwrap();
+            assert_eq!(decoded.mime(), &mime::IMAGE_JPEG);
+            assert_eq!(decoded.dimensions(), (8, 8));
+        });
+    }
+
+    #[test]
+    fn loads_data_urls_and_rejects_remote_ones() {
+        block_on(async {
+            let url = Url::from_data("image/png", &checkerboard_png());
+            let image = Media::Image(url).load_image().await.unwrap();
+            assert_eq!(image.width(), 4);
+
+            let remote = Url::new("https://example.com/a.png");
+            assert!(matches!(
+                Image::load(&remote).await,
+                Err(ImageError::UnsupportedSource(_))
+            ));
+            assert!(matches!(
+                Media::Video(remote).load_image().await,
+                Err(ImageError::NotAnImage)
+            ));
+        });
+    }
+}
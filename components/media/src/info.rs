@@ -0,0 +1,750 @@
+//! Reading media metadata in Rust.
+//!
+//! Metadata is parsed from the media container itself: EXIF for still images
+//! and the ISO base media file format (`.mp4`, `.mov`) for videos.
+//!
+//! ```ignore
+//! let media = selection.load().await;
+//! let metadata = media.metadata().await;
+//! if let Some(location) = metadata.location {
+//!     tracing::debug!("taken at {}, {}", location.latitude, location.longitude);
+//! }
+//! ```
+
+use core::time::Duration;
+use std::io::{self, BufRead, Read, Seek, SeekFrom};
+
+use blocking::unblock;
+use image::{ImageDecoder, ImageReader};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::{Media, source::LocalSource};
+
+/// Metadata describing a media item.
+///
+/// Every field is optional, since containers are free to omit any of them.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct MediaMetadata {
+    /// Width in pixels, as stored (before applying [`orientation`](Self::orientation)).
+    pub width: Option<u32>,
+    /// Height in pixels, as stored (before applying [`orientation`](Self::orientation)).
+    pub height: Option<u32>,
+    /// Playback duration of videos and Live Photos.
+    pub duration: Option<Duration>,
+    /// When the media was captured.
+    pub created: Option<OffsetDateTime>,
+    /// Where the media was captured.
+    ///
+    /// Only present if the file carries a location. Platform pickers strip
+    /// location data unless the user granted access to it.
+    pub location: Option<Location>,
+    /// How the stored pixels must be transformed for display.
+    pub orientation: Orientation,
+}
+
+impl MediaMetadata {
+    /// Returns the size of the media as displayed, with the orientation applied.
+    #[must_use]
+    pub fn display_size(&self) -> Option<(u32, u32)> {
+        let size = (self.width?, self.height?);
+        Some(if self.orientation.swaps_dimensions() {
+            (size.1, size.0)
+        } else {
+            size
+        })
+    }
+}
+
+/// A geographic location in WGS 84 coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Location {
+    /// Latitude in degrees, positive north of the equator.
+    pub latitude: f64,
+    /// Longitude in degrees, positive east of the prime meridian.
+    pub longitude: f64,
+    /// Altitude in meters above sea level, if recorded.
+    pub altitude: Option<f64>,
+}
+
+/// How stored pixels must be transformed for display, following the EXIF
+/// orientation tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    /// Displayed as stored (EXIF 1).
+    #[default]
+    Up,
+    /// Mirrored horizontally (EXIF 2).
+    UpMirrored,
+    /// Rotated 180° (EXIF 3).
+    Down,
+    /// Mirrored vertically (EXIF 4).
+    DownMirrored,
+    /// Mirrored horizontally, then rotated 90° counter-clockwise (EXIF 5).
+    LeftMirrored,
+    /// Rotated 90° clockwise (EXIF 6).
+    Right,
+    /// Mirrored horizontally, then rotated 90° clockwise (EXIF 7).
+    RightMirrored,
+    /// Rotated 90° counter-clockwise (EXIF 8).
+    Left,
+}
+
+impl Orientation {
+    /// Converts an EXIF orientation value, returning `None` if it is out of range.
+    #[must_use]
+    pub const fn from_exif(value: u16) -> Option<Self> {
+        Some(match value {
+            1 => Self::Up,
+            2 => Self::UpMirrored,
+            3 => Self::Down,
+            4 => Self::DownMirrored,
+            5 => Self::LeftMirrored,
+            6 => Self::Right,
+            7 => Self::RightMirrored,
+            8 => Self::Left,
+            _ => return None,
+        })
+    }
+
+    /// Returns the EXIF orientation value.
+    #[must_use]
+    pub const fn to_exif(self) -> u16 {
+        self as u16 + 1
+    }
+
+    /// Returns the clockwise rotation in degrees needed for display.
+    #[must_use]
+    pub const fn rotation(self) -> u16 {
+        match self {
+            Self::Up | Self::UpMirrored => 0,
+            Self::Down | Self::DownMirrored => 180,
+            Self::Right | Self::RightMirrored => 90,
+            Self::Left | Self::LeftMirrored => 270,
+        }
+    }
+
+    /// Returns `true` if the stored pixels are mirrored.
+    #[must_use]
+    pub const fn is_mirrored(self) -> bool {
+        matches!(
+            self,
+            Self::UpMirrored | Self::DownMirrored | Self::LeftMirrored | Self::RightMirrored
+        )
+    }
+
+    /// Returns `true` if width and height are swapped for display.
+    #[must_use]
+    pub const fn swaps_dimensions(self) -> bool {
+        !self.rotation().is_multiple_of(180)
+    }
+}
+
+impl Media {
+    /// Reads the metadata of this media item.
+    ///
+    /// Only local files and `data:` URLs can be inspected; for remote URLs or
+    /// unreadable data the returned metadata is empty. For Live Photos, the
+    /// still image provides the metadata and the video provides the duration.
+    pub async fn metadata(&self) -> MediaMetadata {
+        match self {
+            Self::Image(url) => match LocalSource::new(url) {
+                Some(source) => unblock(move || read_image(source)).await,
+                None => MediaMetadata::default(),
+            },
+            Self::Video(url) => match LocalSource::new(url) {
+                Some(source) => unblock(move || read_video(source)).await,
+                None => MediaMetadata::default(),
+            },
+            Self::LivePhoto(live) => {
+                let image = LocalSource::new(&live.image);
+                let video = LocalSource::new(&live.video);
+                unblock(move || {
+                    let image = image.map(read_image).unwrap_or_default();
+                    let video = video.map(read_video).unwrap_or_default();
+                    MediaMetadata {
+                        duration: video.duration,
+                        created: image.created.or(video.created),
+                        location: image.location.or(video.location),
+                        ..image
+                    }
+                })
+                .await
+            }
+        }
+    }
+}
+
+fn read_image(source: LocalSource) -> MediaMetadata {
+    source
+        .open()
+        .ok()
+        .and_then(|reader| image_metadata(reader).ok())
+        .unwrap_or_default()
+}
+
+fn read_video(source: LocalSource) -> MediaMetadata {
+    source
+        .open()
+        .ok()
+        .and_then(|mut reader| video_metadata(&mut reader).ok())
+        .unwrap_or_default()
+}
+
+fn image_metadata(reader: impl BufRead + Seek) -> image::ImageResult<MediaMetadata> {
+    let mut decoder = ImageReader::new(reader)
+        .with_guessed_format()?
+        .into_decoder()?;
+    let (width, height) = decoder.dimensions();
+    let mut metadata = decoder
+        .exif_metadata()?
+        .map(|exif| parse_exif(&exif))
+        .unwrap_or_default();
+    metadata.width = Some(width);
+    metadata.height = Some(height);
+    Ok(metadata)
+}
+
+// ============================================================================
+// EXIF
+// ============================================================================
+
+const TAG_ORIENTATION: u16 = 0x0112;
+const TAG_DATE_TIME: u16 = 0x0132;
+const TAG_EXIF_IFD: u16 = 0x8769;
+const TAG_GPS_IFD: u16 = 0x8825;
+const TAG_DATE_TIME_ORIGINAL: u16 = 0x9003;
+const TAG_OFFSET_TIME_ORIGINAL: u16 = 0x9011;
+
+/// A little- or big-endian TIFF structure, as embedded in EXIF blocks.
+struct Tiff<'a> {
+    data: &'a [u8],
+    little_endian: bool,
+}
+
+/// A single IFD entry, with the offset of its value.
+#[derive(Clone, Copy)]
+struct Entry {
+    tag: u16,
+    count: u32,
+    value: usize,
+}
+
+impl<'a> Tiff<'a> {
+    fn new(data: &'a [u8]) -> Option<Self> {
+        let data = data.strip_prefix(b"Exif\0\0").unwrap_or(data);
+        let little_endian = match data.get(..4)? {
+            b"II*\0" => true,
+            b"MM\0*" => false,
+            _ => return None,
+        };
+        Some(Self {
+            data,
+            little_endian,
+        })
+    }
+
+    fn bytes<const N: usize>(&self, offset: usize) -> Option<[u8; N]> {
+        self.data
+            .get(offset..offset.checked_add(N)?)?
+            .try_into()
+            .ok()
+    }
+
+    fn u16(&self, offset: usize) -> Option<u16> {
+        let bytes = self.bytes(offset)?;
+        Some(if self.little_endian {
+            u16::from_le_bytes(bytes)
+        } else {
+            u16::from_be_bytes(bytes)
+        })
+    }
+
+    fn u32(&self, offset: usize) -> Option<u32> {
+        let bytes = self.bytes(offset)?;
+        Some(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn first_ifd(&self) -> Option<usize> {
+        self.u32(4).map(|offset| offset as usize)
+    }
+
+    fn entries(&self, ifd: usize) -> impl Iterator<Item = Entry> + '_ {
+        let count = self.u16(ifd).unwrap_or(0);
+        (0..usize::from(count)).map_while(move |index| {
+            let offset = ifd + 2 + index * 12;
+            let tag = self.u16(offset)?;
+            let kind = self.u16(offset + 2)?;
+            let count = self.u32(offset + 4)?;
+            let size: usize = match kind {
+                1 | 2 | 6 | 7 => 1,
+                3 | 8 => 2,
+                4 | 9 | 11 => 4,
+                _ => 8,
+            };
+            // Values of up to four bytes are stored inline; a length that
+            // overflows means the metadata is malformed.
+            let len = size.checked_mul(count as usize)?;
+            let value = if len <= 4 {
+                offset + 8
+            } else {
+                self.u32(offset + 8)? as usize
+            };
+            Some(Entry { tag, count, value })
+        })
+    }
+
+    fn find(&self, ifd: usize, tag: u16) -> Option<Entry> {
+        self.entries(ifd).find(|entry| entry.tag == tag)
+    }
+
+    fn ascii(&self, entry: Entry) -> Option<&'a str> {
+        let end = entry.value.checked_add(entry.count as usize)?;
+        let bytes = self.data.get(entry.value..end)?;
+        let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        core::str::from_utf8(&bytes[..end]).ok()
+    }
+
+    fn rational(&self, offset: usize) -> Option<f64> {
+        let numerator = self.u32(offset)?;
+        let denominator = self.u32(offset + 4)?;
+        (denominator != 0).then(|| f64::from(numerator) / f64::from(denominator))
+    }
+}
+
+fn parse_exif(exif: &[u8]) -> MediaMetadata {
+    let mut metadata = MediaMetadata::default();
+    let Some(tiff) = Tiff::new(exif) else {
+        return metadata;
+    };
+    let Some(ifd) = tiff.first_ifd() else {
+        return metadata;
+    };
+
+    if let Some(entry) = tiff.find(ifd, TAG_ORIENTATION) {
+        metadata.orientation = tiff
+            .u16(entry.value)
+            .and_then(Orientation::from_exif)
+            .unwrap_or_default();
+    }
+
+    let exif_ifd = tiff
+        .find(ifd, TAG_EXIF_IFD)
+        .and_then(|entry| tiff.u32(entry.value));
+    let original = exif_ifd.and_then(|exif| {
+        let exif = exif as usize;
+        let date = tiff.ascii(tiff.find(exif, TAG_DATE_TIME_ORIGINAL)?)?;
+        let offset = tiff
+            .find(exif, TAG_OFFSET_TIME_ORIGINAL)
+            .and_then(|entry| tiff.ascii(entry));
+        parse_exif_date(date, offset)
+    });
+    metadata.created = original.or_else(|| {
+        let date = tiff.ascii(tiff.find(ifd, TAG_DATE_TIME)?)?;
+        parse_exif_date(date, None)
+    });
+
+    metadata.location = tiff
+        .find(ifd, TAG_GPS_IFD)
+        .and_then(|entry| tiff.u32(entry.value))
+        .and_then(|gps| parse_gps(&tiff, gps as usize));
+
+    metadata
+}
+
+/// Parses `YYYY:MM:DD HH:MM:SS` with an optional `±HH:MM` offset, assuming UTC
+/// when no offset is recorded.
+fn parse_exif_date(date: &str, offset: Option<&str>) -> Option<OffsetDateTime> {
+    let field = |range: core::ops::Range<usize>| date.get(range)?.parse::<u8>().ok();
+    let year = date.get(0..4)?.parse().ok()?;
+    let month = Month::try_from(field(5..7)?).ok()?;
+    let date_time = PrimitiveDateTime::new(
+        Date::from_calendar_date(year, month, field(8..10)?).ok()?,
+        Time::from_hms(field(11..13)?, field(14..16)?, field(17..19)?).ok()?,
+    );
+
+    let offset = offset
+        .and_then(|offset| {
+            let sign = match offset.get(0..1)? {
+                "+" => 1,
+                "-" => -1,
+                _ => return None,
+            };
+            let hours = offset.get(1..3)?.parse::<i8>().ok()?;
+            let minutes = offset.get(4..6)?.parse::<i8>().ok()?;
+            UtcOffset::from_hms(sign * hours, sign * minutes, 0).ok()
+        })
+        .unwrap_or(UtcOffset::UTC);
+    Some(date_time.assume_offset(offset))
+}
+
+fn parse_gps(tiff: &Tiff<'_>, ifd: usize) -> Option<Location> {
+    let degrees = |tag: u16| {
+        let value = tiff.find(ifd, tag)?.value;
+        let degrees = tiff.rational(value)?;
+        let minutes = tiff.rational(value + 8).unwrap_or(0.0);
+        let seconds = tiff.rational(value + 16).unwrap_or(0.0);
+        Some(minutes.mul_add(1.0 / 60.0, seconds.mul_add(1.0 / 3600.0, degrees)))
+    };
+    let reference = |tag: u16| tiff.find(ifd, tag).and_then(|entry| tiff.ascii(entry));
+
+    let mut latitude = degrees(2)?;
+    if reference(1) == Some("S") {
+        latitude = -latitude;
+    }
+    let mut longitude = degrees(4)?;
+    if reference(3) == Some("W") {
+        longitude = -longitude;
+    }
+    let altitude = tiff
+        .find(ifd, 6)
+        .and_then(|entry| tiff.rational(entry.value))
+        .map(|altitude| {
+            let below_sea_level = tiff
+                .find(ifd, 5)
+                .and_then(|entry| tiff.data.get(entry.value))
+                == Some(&1);
+            if below_sea_level { -altitude } else { altitude }
+        });
+
+    Some(Location {
+        latitude,
+        longitude,
+        altitude,
+    })
+}
+
+// ============================================================================
+// ISO base media file format (MP4 / QuickTime)
+// ============================================================================
+
+/// Seconds between 1904-01-01 (the `QuickTime` epoch) and the Unix epoch.
+const QUICKTIME_EPOCH_OFFSET: i64 = 2_082_844_800;
+
+/// A box found in a container, with the byte range of its content.
+struct BoxRange {
+    kind: [u8; 4],
+    start: u64,
+    end: u64,
+}
+
+fn children(reader: &mut (impl Read + Seek), start: u64, end: u64) -> io::Result<Vec<BoxRange>> {
+    let mut boxes = Vec::new();
+    let mut position = start;
+    while position + 8 <= end {
+        reader.seek(SeekFrom::Start(position))?;
+        let mut header = [0; 8];
+        reader.read_exact(&mut header)?;
+        let size = u64::from(u32::from_be_bytes([
+            header[0], header[1], header[2], header[3],
+        ]));
+        let kind = [header[4], header[5], header[6], header[7]];
+        let (content, size) = match size {
+            0 => (position + 8, end - position),
+            1 => (position + 16, read_u64(reader)?),
+            size => (position + 8, size),
+        };
+        let box_end = position.saturating_add(size).min(end);
+        if box_end < content {
+            break;
+        }
+        boxes.push(BoxRange {
+            kind,
+            start: content,
+            end: box_end,
+        });
+        position = box_end;
+    }
+    Ok(boxes)
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_be_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_be_bytes(bytes))
+}
+
+/// Reads a version-dependent 32- or 64-bit field.
+fn read_versioned(reader: &mut impl Read, version: u8) -> io::Result<u64> {
+    if version == 1 {
+        read_u64(reader)
+    } else {
+        read_u32(reader).map(u64::from)
+    }
+}
+
+fn video_metadata(reader: &mut (impl Read + Seek)) -> io::Result<MediaMetadata> {
+    let mut metadata = MediaMetadata::default();
+    let end = reader.seek(SeekFrom::End(0))?;
+    let Some(moov) = children(reader, 0, end)?
+        .into_iter()
+        .find(|b| &b.kind == b"moov")
+    else {
+        return Ok(metadata);
+    };
+
+    for child in children(reader, moov.start, moov.end)? {
+        match &child.kind {
+            b"mvhd" => read_movie_header(reader, child.start, &mut metadata)?,
+            b"trak" if metadata.width.is_none() => {
+                if let Some(tkhd) = children(reader, child.start, child.end)?
+                    .into_iter()
+                    .find(|b| &b.kind == b"tkhd")
+                {
+                    read_track_header(reader, tkhd.start, &mut metadata)?;
+                }
+            }
+            b"udta" => {
+                for data in children(reader, child.start, child.end)? {
+                    if data.kind == *b"\xa9xyz" {
+                        metadata.location = read_iso6709(reader, &data)?;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(metadata)
+}
+
+fn read_movie_header(
+    reader: &mut (impl Read + Seek),
+    start: u64,
+    metadata: &mut MediaMetadata,
+) -> io::Result<()> {
+    reader.seek(SeekFrom::Start(start))?;
+    let version = read_u32(reader)?.to_be_bytes()[0];
+    let created = read_versioned(reader, version)?;
+    let _modified = read_versioned(reader, version)?;
+    let timescale = read_u32(reader)?;
+    let duration = read_versioned(reader, version)?;
+
+    if created != 0 {
+        metadata.created = i64::try_from(created).ok().and_then(|seconds| {
+            OffsetDateTime::from_unix_timestamp(seconds - QUICKTIME_EPOCH_OFFSET).ok()
+        });
+    }
+    if timescale != 0 && duration != u64::MAX {
+        let nanos = u128::from(duration) * 1_000_000_000 / u128::from(timescale);
+        metadata.duration = u64::try_from(nanos).ok().map(Duration::from_nanos);
+    }
+    Ok(())
+}
+
+fn read_track_header(
+    reader: &mut (impl Read + Seek),
+    start: u64,
+    metadata: &mut MediaMetadata,
+) -> io::Result<()> {
+    reader.seek(SeekFrom::Start(start))?;
+    let version = read_u32(reader)?.to_be_bytes()[0];
+    let matrix = start + if version == 1 { 52 } else { 40 };
+    reader.seek(SeekFrom::Start(matrix))?;
+    let mut values = [0_i32; 9];
+    for value in &mut values {
+        *value = read_u32(reader)?.cast_signed();
+    }
+    // Width and height are 16.16 fixed-point numbers following the matrix.
+    let width = read_u32(reader)? >> 16;
+    let height = read_u32(reader)? >> 16;
+
+    // Audio-only tracks have no size.
+    if width != 0 && height != 0 {
+        metadata.width = Some(width);
+        metadata.height = Some(height);
+        metadata.orientation = match (values[0].signum(), values[1].signum()) {
+            (0, 1) => Orientation::Right,
+            (-1, 0) => Orientation::Down,
+            (0, -1) => Orientation::Left,
+            _ => Orientation::Up,
+        };
+    }
+    Ok(())
+}
+
+/// Reads a `QuickTime` `©xyz` box holding an ISO 6709 string like `+37.3349-122.0090+010.000/`.
+fn read_iso6709(reader: &mut (impl Read + Seek), data: &BoxRange) -> io::Result<Option<Location>> {
+    reader.seek(SeekFrom::Start(data.start))?;
+    let mut header = [0; 4];
+    reader.read_exact(&mut header)?;
+    let length = u64::from(u16::from_be_bytes([header[0], header[1]]));
+    let length = length.min(data.end.saturating_sub(data.start + 4));
+    let mut text = vec![0; usize::try_from(length).unwrap_or_default()];
+    reader.read_exact(&mut text)?;
+    Ok(core::str::from_utf8(&text).ok().and_then(parse_iso6709))
+}
+
+fn parse_iso6709(text: &str) -> Option<Location> {
+    let text = text.trim_end_matches('/');
+    let mut components = Vec::new();
+    let mut start = 0;
+    for (index, c) in text.char_indices().skip(1) {
+        if c == '+' || c == '-' {
+            components.push(&text[start..index]);
+            start = index;
+        }
+    }
+    components.push(&text[start..]);
+
+    let mut values = components
+        .into_iter()
+        .map(|value| value.parse::<f64>().ok());
+    Some(Location {
+        latitude: values.next()??,
+        longitude: values.next()??,
+        altitude: values.next().flatten(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// Builds a big-endian EXIF block with orientation, capture date and GPS position.
+    fn exif() -> Vec<u8> {
+        fn entry(tag: u16, kind: u16, count: u32, value: u32) -> Vec<u8> {
+            let mut out = tag.to_be_bytes().to_vec();
+            out.extend(kind.to_be_bytes());
+            out.extend(count.to_be_bytes());
+            out.extend(value.to_be_bytes());
+            out
+        }
+        fn rationals(values: &[(u32, u32)]) -> Vec<u8> {
+            values
+                .iter()
+                .flat_map(|(n, d)| n.to_be_bytes().into_iter().chain(d.to_be_bytes()))
+                .collect()
+        }
+
+        // Layout: header (8), IFD0 @ 8 (3 entries), ExifIFD @ 50 (2 entries),
+        // GPS IFD @ 80 (4 entries), data @ 134.
+        let mut out = b"MM\0*\0\0\0\x08".to_vec();
+        out.extend(3_u16.to_be_bytes());
+        out.extend(entry(TAG_ORIENTATION, 3, 1, 6 << 16));
+        out.extend(entry(TAG_EXIF_IFD, 4, 1, 50));
+        out.extend(entry(TAG_GPS_IFD, 4, 1, 80));
+        out.extend(0_u32.to_be_bytes());
+        assert_eq!(out.len(), 50);
+
+        out.extend(2_u16.to_be_bytes());
+        out.extend(entry(TAG_DATE_TIME_ORIGINAL, 2, 20, 134));
+        out.extend(entry(TAG_OFFSET_TIME_ORIGINAL, 2, 7, 154));
+        out.extend(0_u32.to_be_bytes());
+        assert_eq!(out.len(), 80);
+
+        out.extend(4_u16.to_be_bytes());
+        out.extend(entry(1, 2, 2, u32::from_be_bytes(*b"N\0\0\0")));
+        out.extend(entry(2, 5, 3, 161));
+        out.extend(entry(3, 2, 2, u32::from_be_bytes(*b"W\0\0\0")));
+        out.extend(entry(4, 5, 3, 185));
+        out.extend(0_u32.to_be_bytes());
+        assert_eq!(out.len(), 134);
+
+        out.extend(b"2024:01:05 13:30:00\0");
+        out.extend(b"+02:00\0");
+        out.extend(rationals(&[(48, 1), (30, 1), (0, 1)]));
+        out.extend(rationals(&[(2, 1), (15, 1), (36, 1)]));
+        out
+    }
+
+    #[test]
+    fn parses_exif_orientation_date_and_location() {
+        let metadata = parse_exif(&exif());
+        assert_eq!(metadata.orientation, Orientation::Right);
+
+        let created = metadata.created.unwrap();
+        assert_eq!(created.offset(), UtcOffset::from_hms(2, 0, 0).unwrap());
+        assert_eq!(created.unix_timestamp(), 1_704_454_200);
+
+        let location = metadata.location.unwrap();
+        assert!((location.latitude - 48.5).abs() < 1e-9);
+        assert!((location.longitude + 2.26).abs() < 1e-9);
+        assert!(location.altitude.is_none());
+    }
+
+    #[test]
+    fn oversized_entries_are_ignored() {
+        let mut exif = exif();
+        // Claim the capture date spans u32::MAX bytes.
+        exif[56..60].copy_from_slice(&u32::MAX.to_be_bytes());
+        let metadata = parse_exif(&exif);
+        assert!(metadata.created.is_none());
+        assert_eq!(metadata.orientation, Orientation::Right);
+    }
+
+    #[test]
+    fn reads_png_dimensions_without_exif() {
+        let mut png = Cursor::new(Vec::new());
+        image::DynamicImage::new_rgb8(3, 5)
+            .write_to(&mut png, image::ImageFormat::Png)
+            .unwrap();
+        png.set_position(0);
+
+        let metadata = image_metadata(png).unwrap();
+        assert_eq!((metadata.width, metadata.height), (Some(3), Some(5)));
+        assert_eq!(metadata.orientation, Orientation::Up);
+        assert!(metadata.created.is_none());
+    }
+
+    #[test]
+    fn parses_mp4_header_boxes() {
+        fn boxed(kind: [u8; 4], content: &[u8]) -> Vec<u8> {
+            let mut out = u32::try_from(content.len() + 8)
+                .unwrap()
+                .to_be_bytes()
+                .to_vec();
+            out.extend(kind);
+            out.extend(content);
+            out
+        }
+
+        // 2024-01-05 00:00:00 UTC, 5.5 s at a timescale of 600.
+        let mut mvhd = vec![0; 4];
+        mvhd.extend(3_787_257_600_u32.to_be_bytes());
+        mvhd.extend(3_787_257_600_u32.to_be_bytes());
+        mvhd.extend(600_u32.to_be_bytes());
+        mvhd.extend(3300_u32.to_be_bytes());
+        mvhd.resize(100, 0);
+
+        // Rotated 90° clockwise, 1920x1080.
+        let mut tkhd = vec![0; 40];
+        for value in [0, 0x1_0000, 0, -0x1_0000, 0, 0, 0, 0, 0x4000_0000_i32] {
+            tkhd.extend(value.to_be_bytes());
+        }
+        tkhd.extend((1920_u32 << 16).to_be_bytes());
+        tkhd.extend((1080_u32 << 16).to_be_bytes());
+
+        let mut xyz = 26_u16.to_be_bytes().to_vec();
+        xyz.extend([0x15, 0xc7]);
+        xyz.extend(b"+37.3349-122.0090+010.000/");
+
+        let moov = [
+            boxed(*b"mvhd", &mvhd),
+            boxed(*b"trak", &boxed(*b"tkhd", &tkhd)),
+            boxed(*b"udta", &boxed(*b"\xa9xyz", &xyz)),
+        ]
+        .concat();
+        let file = [boxed(*b"ftyp", b"isom"), boxed(*b"moov", &moov)].concat();
+
+        let metadata = video_metadata(&mut Cursor::new(file)).unwrap();
+        assert_eq!(metadata.duration, Some(Duration::from_millis(5500)));
+        assert_eq!(metadata.created.unwrap().unix_timestamp(), 1_704_412_800);
+        assert_eq!(metadata.orientation, Orientation::Right);
+        assert_eq!(metadata.display_size(), Some((1080, 1920)));
+
+        let location = metadata.location.unwrap();
+        assert!((location.latitude - 37.3349).abs() < 1e-9);
+        assert!((location.longitude + 122.009).abs() < 1e-9);
+        assert_eq!(location.altitude, Some(10.0));
+    }
+}
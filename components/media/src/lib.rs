@@ -1,14 +1,18 @@
 //! # `WaterUI` Media Components
 //!
 //! This crate provides media handling and display components for the `WaterUI` framework.
-//! It includes support for images, videos, and Live Photos with a reactive, configurable API.
+//! It includes support for images, videos, audio and Live Photos with a reactive, configurable API.
 //!
 //! ## Components
 //!
 //! - [`Photo`]: Display static images with customizable placeholders
+//! - [`AsyncImage`]: Cached images with placeholder and error views, content modes
+//!   and downsampling hints
 //! - [`Video`]: Video sources that can be used with [`VideoPlayer`]
 //! - [`VideoPlayer`]: Video playback with reactive volume control
+//! - [`AudioPlayer`]: Audio playback with play, seek, volume and rate bindings
 //! - [`LivePhoto`]: Apple Live Photo display with image and video components
+//! - [`AnimatedVector`]: Lottie and dotLottie animations that can be paused and scrubbed
 //! - [`Media`]: Unified enum for different media types
 //!
 //! ## Features
@@ -16,6 +20,7 @@
 //! - **Reactive**: All components integrate with `WaterUI`'s reactive system
 //! - **Configurable**: Built using `WaterUI`'s configuration pattern
 //! - **Media Picker**: Platform-native media selection (feature: `media-picker`)
+//! - **Photo Library**: Album and asset queries for custom gallery UIs
 //! - **Type Safety**: Strong typing with URLs and media sources
 //!
 //! ## Examples
@@ -59,6 +64,12 @@
 
 extern crate alloc;
 
+/// Lottie and dotLottie animations with reactive playback.
+pub mod animated_vector;
+/// Images loaded in the background with placeholder and error views.
+pub mod async_image;
+/// Audio playback with reactive play, seek, volume and rate controls.
+pub mod audio;
 /// Live Photo components and types.
 ///
 /// This module provides the [`LivePhoto`] component for displaying Apple Live Photos,
@@ -70,6 +81,8 @@ pub mod live;
 /// with customizable placeholder views.
 pub mod photo;
 
+/// Photo library queries for building custom gallery UIs.
+pub mod library;
 /// Media picker functionality for platform-native media selection.
 pub mod media_picker;
 /// Video components and types.
@@ -78,16 +91,27 @@ pub mod media_picker;
 /// for video playback with reactive controls.
 pub mod video;
 pub use {
+    animated_vector::AnimatedVector,
+    async_image::AsyncImage,
+    audio::AudioPlayer,
     live::LivePhoto,
     photo::Photo,
     video::{AspectRatio, Video, VideoConfig, VideoPlayer, VideoPlayerConfig},
 };
 
+/// A bounded pool decoding images off the main thread.
+pub mod decode;
 /// URL types for working with media resources
 pub mod url;
 pub use url::Url;
-/// Image view primitives and supporting types.
+/// Image decoding and processing utilities.
+#[cfg(feature = "image")]
 pub mod image;
+/// Dimensions, duration, capture date, location and orientation of media.
+#[cfg(feature = "image")]
+pub mod info;
+#[cfg(feature = "image")]
+mod source;
 
 use waterui_core::{AnyView, Environment, View, reactive::impl_constant};
 
@@ -0,0 +1,478 @@
+//! # Photo Library
+//!
+//! This module provides [`PhotoLibrary`], a query API over the user's photo
+//! library for building custom gallery UIs beyond the system [`MediaPicker`](crate::media_picker::MediaPicker) sheet.
+//!
+//! Native backends install a [`PhotoLibrary`] into the environment. Views then
+//! extract it to list albums, page through assets and react to library changes.
+//!
+//! ## Permissions
+//!
+//! Every query checks [`PhotoLibrary::permission`] first. If the user has not
+//! decided yet, access is requested once; if access is denied or restricted the
+//! query fails with [`PhotoLibraryError::PermissionDenied`].
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use waterui_media::library::{Asset, AssetQuery, PhotoLibrary, PhotoLibraryError};
+//!
+//! async fn first_page(library: PhotoLibrary) -> Result<Vec<Asset>, PhotoLibraryError> {
+//!     let albums = library.albums().await?;
+//!     let query = AssetQuery::new().album(albums[0].id.clone()).page(0, 60);
+//!     library.assets(query).await
+//! }
+//! ```
+
+use core::fmt::{self, Debug, Display};
+use core::ops::Range;
+
+use alloc::{rc::Rc, vec::Vec};
+
+use waterui_core::reactive::signal::IntoComputed;
+use waterui_core::{Binding, Computed, Str};
+
+use crate::media_picker::MediaFilter;
+use crate::{Media, Url};
+
+/// Authorization state of the photo library.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhotoLibraryPermission {
+    /// The user has not been asked yet.
+    NotDetermined,
+    /// Access is blocked by system policy (e.g. parental controls).
+    Restricted,
+    /// The user declined access.
+    Denied,
+    /// The user granted access to a subset of the library.
+    Limited,
+    /// The user granted access to the whole library.
+    Authorized,
+}
+
+impl PhotoLibraryPermission {
+    /// Returns `true` if assets can be read, either fully or limited.
+    #[must_use]
+    pub const fn allows_access(self) -> bool {
+        matches!(self, Self::Limited | Self::Authorized)
+    }
+}
+
+/// Errors that can occur while querying the photo library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PhotoLibraryError {
+    /// No photo library is available on this platform.
+    Unavailable,
+    /// The user has not granted access to the photo library.
+    PermissionDenied(PhotoLibraryPermission),
+}
+
+impl Display for PhotoLibraryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unavailable => f.write_str("photo library is not available"),
+            Self::PermissionDenied(permission) => {
+                write!(f, "photo library access not granted ({permission:?})")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PhotoLibraryError {}
+
+/// Kind of media stored in an [`Asset`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AssetKind {
+    /// A still image.
+    Image,
+    /// A video.
+    Video,
+    /// A Live Photo / Motion Photo.
+    LivePhoto,
+}
+
+/// An album (or smart collection) in the photo library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Album {
+    /// Platform identifier of the album.
+    pub id: Str,
+    /// Display title of the album.
+    pub title: Str,
+    /// Number of assets in the album.
+    pub count: usize,
+}
+
+/// A single photo or video in the photo library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    /// Platform identifier of the asset.
+    pub id: Str,
+    /// Kind of media stored in the asset.
+    pub kind: AssetKind,
+    /// URL of a small preview image, suitable for grid cells.
+    pub thumbnail: Url,
+}
+
+/// Describes which assets to fetch from a [`PhotoLibrary`].
+///
+/// Queries default to all images and videos in the library, newest first,
+/// starting at the first asset with no upper bound.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetQuery {
+    /// Album to fetch from, or `None` for the whole library.
+    pub album: Option<Str>,
+    /// Kinds of media to include.
+    pub filter: MediaFilter,
+    /// Index of the first asset to return.
+    pub offset: usize,
+    /// Maximum number of assets to return, or `None` for all remaining assets.
+    pub limit: Option<usize>,
+}
+
+impl Default for AssetQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AssetQuery {
+    /// Creates a query for every image and video in the library.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            album: None,
+            filter: MediaFilter::Any(alloc::vec![MediaFilter::Image, MediaFilter::Video]),
+            offset: 0,
+            limit: None,
+        }
+    }
+
+    /// Restricts the query to the album with the given identifier.
+    #[must_use]
+    pub fn album(mut self, id: impl Into<Str>) -> Self {
+        self.album = Some(id.into());
+        self
+    }
+
+    /// Restricts the query to the given kinds of media.
+    #[must_use]
+    pub fn filter(mut self, filter: MediaFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Returns at most `limit` assets starting at `offset`.
+    #[must_use]
+    pub const fn page(mut self, offset: usize, limit: usize) -> Self {
+        self.offset = offset;
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the query for the page following this one.
+    ///
+    /// Queries without a limit have no next page and are returned unchanged.
+    #[must_use]
+    pub const fn next_page(mut self) -> Self {
+        if let Some(limit) = self.limit {
+            self.offset += limit;
+        }
+        self
+    }
+
+    /// The range of asset indices this query covers.
+    #[must_use]
+    pub const fn range(&self) -> Range<usize> {
+        let end = match self.limit {
+            Some(limit) => self.offset.saturating_add(limit),
+            None => usize::MAX,
+        };
+        self.offset..end
+    }
+}
+
+/// Platform photo library backend.
+/// Installed by native backends via FFI.
+///
+/// Callbacks may be invoked synchronously or later from the main thread.
+pub trait CustomPhotoLibrary: 'static {
+    /// Returns the current authorization state without prompting the user.
+    fn permission(&self) -> PhotoLibraryPermission;
+
+    /// Prompts the user for access and reports the resulting authorization state.
+    fn request_permission(&self, callback: impl FnOnce(PhotoLibraryPermission) + 'static);
+
+    /// Fetches the albums of the library.
+    fn albums(&self, callback: impl FnOnce(Vec<Album>) + 'static);
+
+    /// Fetches the assets matching `query`, in library order.
+    fn assets(&self, query: AssetQuery, callback: impl FnOnce(Vec<Asset>) + 'static);
+
+    /// Loads the full-size media of the asset with the given identifier.
+    fn load(&self, id: Str, callback: impl FnOnce(Media) + 'static);
+
+    /// Registers `on_change` to be called whenever the library content changes.
+    ///
+    /// The default implementation never reports changes.
+    fn observe(&self, on_change: impl Fn() + 'static) {
+        let _ = on_change;
+    }
+}
+
+trait PhotoLibraryImpl: 'static {
+    fn permission(&self) -> PhotoLibraryPermission;
+    fn request_permission(&self, callback: Box<dyn FnOnce(PhotoLibraryPermission)>);
+    fn albums(&self, callback: Box<dyn FnOnce(Vec<Album>)>);
+    fn assets(&self, query: AssetQuery, callback: Box<dyn FnOnce(Vec<Asset>)>);
+    fn load(&self, id: Str, callback: Box<dyn FnOnce(Media)>);
+}
+
+impl<T: CustomPhotoLibrary> PhotoLibraryImpl for T {
+    fn permission(&self) -> PhotoLibraryPermission {
+        CustomPhotoLibrary::permission(self)
+    }
+
+    fn request_permission(&self, callback: Box<dyn FnOnce(PhotoLibraryPermission)>) {
+        CustomPhotoLibrary::request_permission(self, callback);
+    }
+
+    fn albums(&self, callback: Box<dyn FnOnce(Vec<Album>)>) {
+        CustomPhotoLibrary::albums(self, callback);
+    }
+
+    fn assets(&self, query: AssetQuery, callback: Box<dyn FnOnce(Vec<Asset>)>) {
+        CustomPhotoLibrary::assets(self, query, callback);
+    }
+
+    fn load(&self, id: Str, callback: Box<dyn FnOnce(Media)>) {
+        CustomPhotoLibrary::load(self, id, callback);
+    }
+}
+
+/// Type-erased photo library stored in Environment.
+#[derive(Clone)]
+pub struct PhotoLibrary {
+    inner: Rc<dyn PhotoLibraryImpl>,
+    revision: Binding<u64>,
+}
+
+impl Debug for PhotoLibrary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PhotoLibrary").finish_non_exhaustive()
+    }
+}
+
+impl PhotoLibrary {
+    /// Creates a new `PhotoLibrary` from any type implementing `CustomPhotoLibrary`.
+    pub fn new<T: CustomPhotoLibrary>(library: T) -> Self {
+        let revision = Binding::<u64>::container(0);
+        library.observe({
+            let revision = revision.clone();
+            move || revision.set(revision.get().wrapping_add(1))
+        });
+        Self {
+            inner: Rc::new(library),
+            revision,
+        }
+    }
+
+    /// Returns the current authorization state without prompting the user.
+    #[must_use]
+    pub fn permission(&self) -> PhotoLibraryPermission {
+        self.inner.permission()
+    }
+
+    /// Prompts the user for access if they have not decided yet, and returns
+    /// the resulting authorization state.
+    pub async fn request_permission(&self) -> PhotoLibraryPermission {
+        let permission = self.permission();
+        if permission != PhotoLibraryPermission::NotDetermined {
+            return permission;
+        }
+        let (mut sender, receiver) = async_oneshot::oneshot();
+        self.inner.request_permission(Box::new(move |permission| {
+            let _ = sender.send(permission);
+        }));
+        receiver.await.unwrap_or(PhotoLibraryPermission::Denied)
+    }
+
+    /// A signal that changes every time the library content changes.
+    ///
+    /// Watch it to refetch albums or assets when photos are added or removed.
+    #[must_use]
+    pub fn changes(&self) -> Computed<u64> {
+        self.revision.clone().into_computed()
+    }
+
+    /// Fetches the albums of the library.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PhotoLibraryError::PermissionDenied`] if access is not granted.
+    pub async fn albums(&self) -> Result<Vec<Album>, PhotoLibraryError> {
+        self.ensure_access().await?;
+        let (mut sender, receiver) = async_oneshot::oneshot();
+        self.inner.albums(Box::new(move |albums| {
+            let _ = sender.send(albums);
+        }));
+        receiver.await.map_err(|_| PhotoLibraryError::Unavailable)
+    }
+
+    /// Fetches the assets matching `query`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PhotoLibraryError::PermissionDenied`] if access is not granted.
+    pub async fn assets(&self, query: AssetQuery) -> Result<Vec<Asset>, PhotoLibraryError> {
+        self.ensure_access().await?;
+        let limit = query.limit;
+        let (mut sender, receiver) = async_oneshot::oneshot();
+        self.inner.assets(
+            query,
+            Box::new(move |assets| {
+                let _ = sender.send(assets);
+            }),
+        );
+        let mut assets = receiver.await.map_err(|_| PhotoLibraryError::Unavailable)?;
+        if let Some(limit) = limit {
+            assets.truncate(limit);
+        }
+        Ok(assets)
+    }
+
+    /// Loads the full-size media of `asset`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PhotoLibraryError::PermissionDenied`] if access is not granted.
+    pub async fn load(&self, asset: &Asset) -> Result<Media, PhotoLibraryError> {
+        self.ensure_access().await?;
+        let (mut sender, receiver) = async_oneshot::oneshot();
+        self.inner.load(
+            asset.id.clone(),
+            Box::new(move |media| {
+                let _ = sender.send(media);
+            }),
+        );
+        receiver.await.map_err(|_| PhotoLibraryError::Unavailable)
+    }
+
+    async fn ensure_access(&self) -> Result<(), PhotoLibraryError> {
+        let permission = self.request_permission().await;
+        if permission.allows_access() {
+            Ok(())
+        } else {
+            Err(PhotoLibraryError::PermissionDenied(permission))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::{Cell, RefCell};
+    use futures::executor::block_on;
+    use waterui_core::Signal;
+
+    type ChangeHandler = Rc<RefCell<Option<Box<dyn Fn()>>>>;
+
+    /// An in-memory library of `count` images.
+    struct MemoryLibrary {
+        permission: Rc<Cell<PhotoLibraryPermission>>,
+        grant: PhotoLibraryPermission,
+        count: usize,
+        on_change: ChangeHandler,
+    }
+
+    impl MemoryLibrary {
+        fn new(grant: PhotoLibraryPermission, count: usize) -> Self {
+            Self {
+                permission: Rc::new(Cell::new(PhotoLibraryPermission::NotDetermined)),
+                grant,
+                count,
+                on_change: Rc::default(),
+            }
+        }
+    }
+
+    impl CustomPhotoLibrary for MemoryLibrary {
+        fn permission(&self) -> PhotoLibraryPermission {
+            self.permission.get()
+        }
+
+        fn request_permission(&self, callback: impl FnOnce(PhotoLibraryPermission) + 'static) {
+            self.permission.set(self.grant);
+            callback(self.grant);
+        }
+
+        fn albums(&self, callback: impl FnOnce(Vec<Album>) + 'static) {
+            callback(alloc::vec![Album {
+                id: "recents".into(),
+                title: "Recents".into(),
+                count: self.count,
+            }]);
+        }
+
+        fn assets(&self, query: AssetQuery, callback: impl FnOnce(Vec<Asset>) + 'static) {
+            let range = query.range();
+            let assets = (range.start..range.end.min(self.count))
+                .map(|index| Asset {
+                    id: alloc::format!("{index}").into(),
+                    kind: AssetKind::Image,
+                    thumbnail: Url::from_file_path_str(alloc::format!("/thumbs/{index}.jpg")),
+                })
+                .collect();
+            callback(assets);
+        }
+
+        fn load(&self, id: Str, callback: impl FnOnce(Media) + 'static) {
+            callback(Media::Image(Url::from_file_path_str(alloc::format!(
+                "/photos/{id}.jpg"
+            ))));
+        }
+
+        fn observe(&self, on_change: impl Fn() + 'static) {
+            *self.on_change.borrow_mut() = Some(Box::new(on_change));
+        }
+    }
+
+    #[test]
+    fn assets_are_paginated() {
+        let library = PhotoLibrary::new(MemoryLibrary::new(PhotoLibraryPermission::Authorized, 5));
+        let query = AssetQuery::new().page(0, 2);
+
+        let first = block_on(library.assets(query.clone())).unwrap();
+        let last = block_on(library.assets(query.next_page().next_page())).unwrap();
+
+        assert_eq!(first.len(), 2);
+        assert_eq!(&*first[1].id, "1");
+        assert_eq!(last.len(), 1);
+        assert_eq!(last[0].thumbnail.as_str(), "/thumbs/4.jpg");
+    }
+
+    #[test]
+    fn denied_permission_fails_queries() {
+        let library = PhotoLibrary::new(MemoryLibrary::new(PhotoLibraryPermission::Denied, 5));
+
+        assert_eq!(
+            block_on(library.albums()),
+            Err(PhotoLibraryError::PermissionDenied(
+                PhotoLibraryPermission::Denied
+            ))
+        );
+        assert_eq!(library.permission(), PhotoLibraryPermission::Denied);
+    }
+
+    #[test]
+    fn changes_follow_library_notifications() {
+        let backend = MemoryLibrary::new(PhotoLibraryPermission::Limited, 1);
+        let on_change = backend.on_change.clone();
+        let library = PhotoLibrary::new(backend);
+        let changes = library.changes();
+
+        assert_eq!(changes.get(), 0);
+        (on_change.borrow().as_ref().unwrap())();
+        (on_change.borrow().as_ref().unwrap())();
+        assert_eq!(changes.get(), 2);
+    }
+}
@@ -5,10 +5,17 @@
 //! ## Platform Support
 //!
 //! The `MediaPicker` is available on iOS, macOS, and Android platforms.
+//!
+//! ## Multiple Selection
+//!
+//! [`MediaPicker::multiple`] lets users pick several items at once, in order,
+//! optionally capped with [`MediaPicker::limit`]. Use [`Selected::load_each`] to
+//! load them incrementally as each one becomes available.
 
 use std::fmt::Debug;
 
-use alloc::rc::Rc;
+use alloc::{rc::Rc, vec, vec::Vec};
+use core::cell::RefCell;
 
 use waterui_core::extract::Use;
 use waterui_core::reactive::signal::IntoComputed;
@@ -27,6 +34,22 @@ pub trait CustomMediaPickerManager: 'static {
     /// Returns the selected media ID via callback when user picks media.
     fn present(&self, filter: MediaFilter, callback: impl FnOnce(SelectedId) + 'static);
 
+    /// Present the native media picker allowing several items to be selected.
+    ///
+    /// Returns the selected media IDs in selection order via callback. `limit`
+    /// caps the number of selectable items; `None` means no limit.
+    ///
+    /// The default implementation falls back to single selection.
+    fn present_multiple(
+        &self,
+        filter: MediaFilter,
+        limit: Option<usize>,
+        callback: impl FnOnce(Vec<SelectedId>) + 'static,
+    ) {
+        let _ = limit;
+        self.present(filter, move |selected| callback(vec![selected]));
+    }
+
     /// Load media content for the given selection ID.
     /// Returns the loaded Media via callback.
     fn load(&self, selected: SelectedId, callback: impl FnOnce(Media) + 'static);
@@ -38,6 +61,12 @@ pub struct MediaPickerManager(Rc<dyn MediaPickerManagerImpl>);
 
 trait MediaPickerManagerImpl: 'static {
     fn present(&self, filter: MediaFilter, callback: Box<dyn FnOnce(SelectedId)>);
+    fn present_multiple(
+        &self,
+        filter: MediaFilter,
+        limit: Option<usize>,
+        callback: Box<dyn FnOnce(Vec<SelectedId>)>,
+    );
     fn load(&self, selected: SelectedId, callback: Box<dyn FnOnce(Media)>);
 }
 
@@ -46,6 +75,15 @@ impl<T: CustomMediaPickerManager> MediaPickerManagerImpl for T {
         CustomMediaPickerManager::present(self, filter, callback);
     }
 
+    fn present_multiple(
+        &self,
+        filter: MediaFilter,
+        limit: Option<usize>,
+        callback: Box<dyn FnOnce(Vec<SelectedId>)>,
+    ) {
+        CustomMediaPickerManager::present_multiple(self, filter, limit, callback);
+    }
+
     fn load(&self, selected: SelectedId, callback: Box<dyn FnOnce(Media)>) {
         CustomMediaPickerManager::load(self, selected, callback);
     }
@@ -70,11 +108,19 @@ impl Debug for MediaPickerManager {
 /// platform media picker. The selected media ID is written to the provided binding.
 #[derive(Debug)]
 pub struct MediaPicker<Label> {
-    selection: Binding<Option<Selected>>,
+    selection: PickerSelection,
     filter: Computed<MediaFilter>,
+    limit: Option<usize>,
     label: Label,
 }
 
+/// Where a [`MediaPicker`] writes its selection.
+#[derive(Debug, Clone)]
+enum PickerSelection {
+    Single(Binding<Option<Selected>>),
+    Multiple(Binding<Vec<Selected>>),
+}
+
 impl MediaPicker<Text> {
     /// Creates a new `MediaPicker` with a selection binding.
     ///
@@ -87,8 +133,30 @@ impl MediaPicker<Text> {
     #[must_use]
     pub fn new(selection: &Binding<Option<Selected>>) -> Self {
         Self {
-            selection: selection.clone(),
+            selection: PickerSelection::Single(selection.clone()),
             filter: MediaFilter::Image.into_computed(),
+            limit: None,
+            label: text("Select Media"),
+        }
+    }
+
+    /// Creates a `MediaPicker` that lets users select several items at once.
+    ///
+    /// The binding receives the picked items in the order they were selected,
+    /// replacing any previous selection.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let attachments = binding(Vec::new());
+    /// let picker = MediaPicker::multiple(&attachments).limit(4);
+    /// ```
+    #[must_use]
+    pub fn multiple(selection: &Binding<Vec<Selected>>) -> Self {
+        Self {
+            selection: PickerSelection::Multiple(selection.clone()),
+            filter: MediaFilter::Image.into_computed(),
+            limit: None,
             label: text("Select Media"),
         }
     }
@@ -111,6 +179,15 @@ where
         self
     }
 
+    /// Limits how many items can be selected with a [`multiple`](MediaPicker::multiple) picker.
+    ///
+    /// A limit of zero is treated as one. Single-selection pickers ignore it.
+    #[must_use]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit.max(1));
+        self
+    }
+
     /// Sets a custom label for the picker button.
     ///
     /// # Example
@@ -123,6 +200,7 @@ where
         MediaPicker {
             selection: self.selection,
             filter: self.filter,
+            limit: self.limit,
             label,
         }
     }
@@ -141,6 +219,16 @@ impl MediaPickerManager {
     pub fn present(&self, filter: MediaFilter, callback: impl FnOnce(SelectedId) + 'static) {
         self.0.present(filter, Box::new(callback));
     }
+
+    /// Present the media picker allowing up to `limit` items to be selected.
+    pub fn present_multiple(
+        &self,
+        filter: MediaFilter,
+        limit: Option<usize>,
+        callback: impl FnOnce(Vec<SelectedId>) + 'static,
+    ) {
+        self.0.present_multiple(filter, limit, Box::new(callback));
+    }
 }
 
 impl<Label> View for MediaPicker<Label>
@@ -150,21 +238,40 @@ where
     fn body(self, _env: &Environment) -> impl View {
         use waterui_controls::button;
 
-        let selection = self.selection.clone();
-        let filter = self.filter.clone();
-
-        button(self.label).action(move |manager: Use<MediaPickerManager>| {
-            let sel = selection.clone();
-            manager.present(filter.get(), {
-                let manager = manager.0.clone();
-                Box::new(move |selected| {
-                    sel.set(Some(Selected {
-                        id: selected,
-                        manager: manager.clone(),
-                    }));
-                })
-            });
-        })
+        let selection = self.selection;
+        let filter = self.filter;
+        let limit = self.limit;
+
+        button(self.label).action(
+            move |Use(manager): Use<MediaPickerManager>| match &selection {
+                PickerSelection::Single(sel) => {
+                    let sel = sel.clone();
+                    let handle = manager.clone();
+                    manager.present(filter.get(), move |selected| {
+                        sel.set(Some(Selected {
+                            id: selected,
+                            manager: handle,
+                        }));
+                    });
+                }
+                PickerSelection::Multiple(sel) => {
+                    let sel = sel.clone();
+                    let handle = manager.clone();
+                    manager.present_multiple(filter.get(), limit, move |selected| {
+                        sel.set(
+                            selected
+                                .into_iter()
+                                .take(limit.unwrap_or(usize::MAX))
+                                .map(|id| Selected {
+                                    id,
+                                    manager: handle.clone(),
+                                })
+                                .collect(),
+                        );
+                    });
+                }
+            },
+        )
     }
 }
 
@@ -234,6 +341,30 @@ impl Selected {
         );
         receiver.await.expect("Failed to receive media")
     }
+
+    /// Loads every item of a selection, calling `on_load` with the item's
+    /// position as soon as it becomes available.
+    ///
+    /// All loads start immediately and may complete in any order, so large
+    /// selections can be shown progressively instead of waiting for the slowest item.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let loaded = binding(vec![None; attachments.get().len()]);
+    /// Selected::load_each(&attachments.get(), move |index, media| {
+    ///     loaded.with_mut(|items| items[index] = Some(media));
+    /// });
+    /// ```
+    pub fn load_each(selection: &[Self], on_load: impl FnMut(usize, Media) + 'static) {
+        let on_load = Rc::new(RefCell::new(on_load));
+        for (index, selected) in selection.iter().enumerate() {
+            let on_load = on_load.clone();
+            selected.manager.load(selected.id, move |media| {
+                (on_load.borrow_mut())(index, media);
+            });
+        }
+    }
 }
 
 /// Represents filters that can be applied to media selection.
@@ -254,3 +385,61 @@ pub enum MediaFilter {
 }
 
 impl_constant!(MediaFilter);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use waterui_url::Url;
+
+    /// A manager that only supports single selection and loads IDs as image URLs.
+    struct SinglePicker;
+
+    impl CustomMediaPickerManager for SinglePicker {
+        fn present(&self, _filter: MediaFilter, callback: impl FnOnce(SelectedId) + 'static) {
+            callback(7);
+        }
+
+        fn load(&self, selected: SelectedId, callback: impl FnOnce(Media) + 'static) {
+            let url = Url::from_file_path_str(format!("/photos/{selected}.jpg"));
+            callback(Media::Image(url));
+        }
+    }
+
+    #[test]
+    fn multiple_selection_falls_back_to_single() {
+        let manager = MediaPickerManager::new(SinglePicker);
+        let picked = Rc::new(RefCell::new(Vec::new()));
+        manager.present_multiple(MediaFilter::Image, Some(3), {
+            let picked = picked.clone();
+            move |ids| *picked.borrow_mut() = ids
+        });
+        assert_eq!(*picked.borrow(), [7]);
+    }
+
+    #[test]
+    fn load_each_reports_positions() {
+        let manager = MediaPickerManager::new(SinglePicker);
+        let selection: Vec<_> = [3, 1, 2]
+            .into_iter()
+            .map(|id| Selected::new(id, manager.clone()))
+            .collect();
+
+        let loaded = Rc::new(RefCell::new(Vec::new()));
+        let count = Rc::new(Cell::new(0));
+        Selected::load_each(&selection, {
+            let loaded = loaded.clone();
+            let count = count.clone();
+            move |index, media| {
+                count.set(count.get() + 1);
+                if let Media::Image(url) = media {
+                    loaded.borrow_mut().push((index, url.as_str().to_owned()));
+                }
+            }
+        });
+
+        assert_eq!(count.get(), 3);
+        assert_eq!(loaded.borrow()[0], (0, "/photos/3.jpg".to_owned()));
+        assert_eq!(loaded.borrow()[2], (2, "/photos/2.jpg".to_owned()));
+    }
+}
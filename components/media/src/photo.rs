@@ -9,8 +9,33 @@
 //! let url = Url::parse("https://example.com/image.jpg").unwrap();
 //! let _photo = Photo::new(url);
 //! ```
-use crate::image::Image;
-use waterui_core::configurable;
+//!
+//! Use [`Photo::sources`] to provide several variants of the same image; the
+//! one matching the display scale and color scheme is picked automatically:
+//!
+//! ```no_run
+//! use waterui_media::Photo;
+//! use waterui_media::photo::PhotoSources;
+//!
+//! let _photo = Photo::sources(
+//!     PhotoSources::scaled("https://example.com/logo.png", 3)
+//!         .dark(2.0, "https://example.com/logo-dark@2x.png"),
+//! );
+//! ```
+//!
+//! Use [`prefetch_photos`] to warm the backend's image cache for rows that are
+//! about to scroll into view.
+use alloc::{rc::Rc, vec::Vec};
+use core::ops::Range;
+
+#[cfg(feature = "image")]
+use crate::image::{Image, ImageError};
+use waterui_color::{ColorScheme, current_color_scheme};
+use waterui_core::{
+    Dynamic, Environment, SignalExt, View, configurable, display::current_display_scale,
+};
+
+use waterui_layout::scroll::Prefetch;
 
 use crate::Url;
 
@@ -76,14 +101,25 @@ impl Photo {
         self
     }
 
-    /// Loads the image associated with this `Photo`.
+    /// Creates a photo that picks the best of several sources.
     ///
-    /// # Panics
+    /// The source is re-selected whenever the display scale or color scheme
+    /// in the environment changes.
+    pub fn sources(sources: impl Into<PhotoSources>) -> ResponsivePhoto {
+        ResponsivePhoto {
+            sources: sources.into(),
+            on_event: Rc::new(|_event| {}),
+        }
+    }
+
+    /// Loads and decodes the image associated with this `Photo`.
     ///
-    /// Panics because the loader is not implemented yet.
-    #[allow(clippy::unused_async)]
-    pub async fn load(&self) -> Image {
-        todo!()
+    /// # Errors
+    ///
+    /// Returns an error if the source cannot be read or decoded.
+    #[cfg(feature = "image")]
+    pub async fn load(&self) -> Result<Image, ImageError> {
+        Image::load(&self.0.source).await
     }
 }
 
@@ -91,3 +127,374 @@ impl Photo {
 pub fn photo(source: impl Into<Url>) -> Photo {
     Photo::new(source)
 }
+
+/// Describes which displays a [`PhotoSource`] is intended for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SourceDescriptor {
+    /// Intended for displays with this pixel density (e.g. `2.0` for `@2x`).
+    Scale(f32),
+    /// The image is this many pixels wide.
+    Width(u32),
+}
+
+impl From<f32> for SourceDescriptor {
+    fn from(scale: f32) -> Self {
+        Self::Scale(scale)
+    }
+}
+
+impl From<u32> for SourceDescriptor {
+    fn from(width: u32) -> Self {
+        Self::Width(width)
+    }
+}
+
+/// A single candidate image in a [`PhotoSources`] set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhotoSource {
+    /// Which displays this source is intended for.
+    pub descriptor: SourceDescriptor,
+    /// The URL of the image.
+    pub url: Url,
+    /// Whether this source is meant for the dark color scheme.
+    pub dark: bool,
+}
+
+/// A set of image variants, similar to an HTML `srcset`.
+///
+/// Selection works as follows:
+/// 1. In the dark color scheme, dark variants are used if there are any.
+///    Otherwise light variants are used.
+/// 2. Among those, the source with the lowest density that still covers the
+///    display scale wins. If none covers it, the densest source is used.
+///
+/// Width descriptors are converted to densities using [`display_width`]. Without
+/// a display width, the narrowest candidate is treated as `1x`.
+///
+/// [`display_width`]: PhotoSources::display_width
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PhotoSources {
+    sources: Vec<PhotoSource>,
+    display_width: Option<f32>,
+}
+
+impl PhotoSources {
+    /// Creates an empty source set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates `1x` to `max_scale`x sources from a base URL using the
+    /// `name@2x.ext` naming convention.
+    #[must_use]
+    pub fn scaled(base: impl Into<Url>, max_scale: u8) -> Self {
+        Self::new().with_scaled(&base.into(), max_scale, false)
+    }
+
+    /// Adds a source for the light color scheme.
+    #[must_use]
+    pub fn source(mut self, descriptor: impl Into<SourceDescriptor>, url: impl Into<Url>) -> Self {
+        self.sources.push(PhotoSource {
+            descriptor: descriptor.into(),
+            url: url.into(),
+            dark: false,
+        });
+        self
+    }
+
+    /// Adds a source for the dark color scheme.
+    #[must_use]
+    pub fn dark(mut self, descriptor: impl Into<SourceDescriptor>, url: impl Into<Url>) -> Self {
+        self.sources.push(PhotoSource {
+            descriptor: descriptor.into(),
+            url: url.into(),
+            dark: true,
+        });
+        self
+    }
+
+    /// Adds `1x` to `max_scale`x dark sources using the `name@2x.ext` naming convention.
+    #[must_use]
+    pub fn dark_scaled(self, base: impl Into<Url>, max_scale: u8) -> Self {
+        self.with_scaled(&base.into(), max_scale, true)
+    }
+
+    /// Sets the width, in points, the image is displayed at.
+    ///
+    /// This lets width descriptors be compared against the display scale.
+    #[must_use]
+    pub const fn display_width(mut self, points: f32) -> Self {
+        self.display_width = Some(points);
+        self
+    }
+
+    /// Returns all sources in this set.
+    #[must_use]
+    pub fn as_slice(&self) -> &[PhotoSource] {
+        &self.sources
+    }
+
+    fn with_scaled(mut self, base: &Url, max_scale: u8, dark: bool) -> Self {
+        for scale in 1..=max_scale.max(1) {
+            let url = if scale == 1 {
+                base.clone()
+            } else {
+                scaled_url(base, scale)
+            };
+            self.sources.push(PhotoSource {
+                descriptor: SourceDescriptor::Scale(f32::from(scale)),
+                url,
+                dark,
+            });
+        }
+        self
+    }
+
+    /// Chooses the source for the given display scale and color scheme.
+    #[must_use]
+    pub fn select(&self, scale: f32, scheme: ColorScheme) -> Option<&Url> {
+        let want_dark = scheme == ColorScheme::Dark && self.sources.iter().any(|s| s.dark);
+        let mut candidates: Vec<&PhotoSource> = self
+            .sources
+            .iter()
+            .filter(|s| s.dark == want_dark)
+            .collect();
+        if candidates.is_empty() {
+            candidates = self.sources.iter().collect();
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let base_width = self.display_width.unwrap_or_else(|| {
+            candidates
+                .iter()
+                .filter_map(|s| match s.descriptor {
+                    SourceDescriptor::Width(width) => Some(width as f32),
+                    SourceDescriptor::Scale(_) => None,
+                })
+                .fold(f32::INFINITY, f32::min)
+        });
+        #[allow(clippy::cast_precision_loss)]
+        let density = |source: &PhotoSource| match source.descriptor {
+            SourceDescriptor::Scale(scale) => scale,
+            SourceDescriptor::Width(width) => width as f32 / base_width,
+        };
+
+        let covering = candidates
+            .iter()
+            .filter(|s| density(s) >= scale)
+            .min_by(|a, b| density(a).total_cmp(&density(b)));
+        covering
+            .or_else(|| {
+                candidates
+                    .iter()
+                    .max_by(|a, b| density(a).total_cmp(&density(b)))
+            })
+            .map(|s| &s.url)
+    }
+}
+
+impl<D, U, const N: usize> From<[(D, U); N]> for PhotoSources
+where
+    D: Into<SourceDescriptor>,
+    U: Into<Url>,
+{
+    fn from(sources: [(D, U); N]) -> Self {
+        sources
+            .into_iter()
+            .fold(Self::new(), |set, (descriptor, url)| {
+                set.source(descriptor, url)
+            })
+    }
+}
+
+/// Inserts `@{scale}x` before the file extension of `url`'s path.
+fn scaled_url(url: &Url, scale: u8) -> Url {
+    let raw = url.as_str();
+    let path_end = raw.find(['?', '#']).unwrap_or(raw.len());
+    let name_start = raw[..path_end].rfind('/').map_or(0, |i| i + 1);
+    let insert_at = raw[name_start..path_end]
+        .rfind('.')
+        .map_or(path_end, |dot| name_start + dot);
+    let scaled = alloc::format!("{}@{scale}x{}", &raw[..insert_at], &raw[insert_at..]);
+    Url::from(scaled)
+}
+
+/// A photo that chooses among several sources based on the environment.
+///
+/// Created with [`Photo::sources`].
+pub struct ResponsivePhoto {
+    sources: PhotoSources,
+    on_event: Rc<dyn Fn(Event)>,
+}
+
+impl core::fmt::Debug for ResponsivePhoto {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ResponsivePhoto")
+            .field("sources", &self.sources)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ResponsivePhoto {
+    /// Sets the event handler, which is invoked for whichever source is shown.
+    #[must_use]
+    pub fn on_event(mut self, handler: impl Fn(Event) + 'static) -> Self {
+        self.on_event = Rc::new(handler);
+        self
+    }
+}
+
+impl View for ResponsivePhoto {
+    fn body(self, env: &Environment) -> impl View {
+        let sources = self.sources;
+        let url = current_display_scale(env)
+            .zip(current_color_scheme(env))
+            .map(move |(scale, scheme)| sources.select(scale, scheme).cloned());
+        let on_event = self.on_event;
+
+        Dynamic::watch(url, move |url: Option<Url>| {
+            let on_event = on_event.clone();
+            url.map(|url| Photo::new(url).on_event(move |event| on_event(event)))
+        })
+    }
+}
+
+/// Platform image cache used by [`Photo`].
+/// Installed by native backends via FFI.
+pub trait CustomPhotoCache: 'static {
+    /// Starts downloading and decoding `url` into the cache without displaying it.
+    fn prefetch(&self, url: &Url);
+
+    /// Cancels a prefetch that has not finished, such as after a fast scroll.
+    ///
+    /// The default implementation lets the prefetch finish.
+    fn cancel_prefetch(&self, url: &Url) {
+        let _ = url;
+    }
+}
+
+/// Type-erased photo cache stored in the environment.
+#[derive(Clone)]
+pub struct PhotoCache(Rc<dyn CustomPhotoCache>);
+
+impl core::fmt::Debug for PhotoCache {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PhotoCache").finish_non_exhaustive()
+    }
+}
+
+impl PhotoCache {
+    /// Creates a new `PhotoCache` from any type implementing `CustomPhotoCache`.
+    pub fn new(cache: impl CustomPhotoCache) -> Self {
+        Self(Rc::new(cache))
+    }
+
+    /// Starts loading `url` so that a [`Photo`] showing it appears immediately.
+    pub fn prefetch(&self, url: &Url) {
+        self.0.prefetch(url);
+    }
+
+    /// Cancels an unfinished prefetch of `url`.
+    pub fn cancel_prefetch(&self, url: &Url) {
+        self.0.cancel_prefetch(url);
+    }
+}
+
+/// Creates a prefetch hint that warms the photo cache for upcoming rows.
+///
+/// `url_at` returns the image URL of the row at an index, or `None` if the row
+/// has no image. Without a [`PhotoCache`] in the environment, the hint does nothing.
+///
+/// # Examples
+///
+/// ```ignore
+/// let posts = feed.posts();
+/// List::for_each(posts.clone(), post_row).prefetch_with(prefetch_photos(move |index| {
+///     posts.get(index).map(|post| post.image_url)
+/// }))
+/// ```
+pub fn prefetch_photos(url_at: impl Fn(usize) -> Option<Url> + 'static) -> Prefetch {
+    Prefetch::new(move |env, range: Range<usize>| {
+        if let Some(cache) = env.get::<PhotoCache>() {
+            for url in range.filter_map(&url_at) {
+                cache.prefetch(&url);
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prefetch_photos_uses_installed_cache() {
+        use core::cell::RefCell;
+        use waterui_core::Environment;
+
+        #[derive(Clone, Default)]
+        struct RecordingCache(Rc<RefCell<Vec<Url>>>);
+
+        impl CustomPhotoCache for RecordingCache {
+            fn prefetch(&self, url: &Url) {
+                self.0.borrow_mut().push(url.clone());
+            }
+        }
+
+        let prefetch = prefetch_photos(|index| {
+            (index % 2 == 0).then(|| Url::from(alloc::format!("https://example.com/{index}.jpg")))
+        });
+        let recorded = RecordingCache::default();
+        let mut env = Environment::new();
+        env.insert(PhotoCache::new(recorded.clone()));
+        prefetch.call(&env, 3..7);
+
+        assert_eq!(
+            *recorded.0.borrow(),
+            [
+                Url::from("https://example.com/4.jpg"),
+                Url::from("https://example.com/6.jpg")
+            ]
+        );
+        prefetch.call(&Environment::new(), 0..2);
+    }
+
+    #[test]
+    fn selects_lowest_covering_scale() {
+        let sources = PhotoSources::scaled("https://example.com/a.png?v=1", 3);
+        let pick = |scale| sources.select(scale, ColorScheme::Light).unwrap().as_str();
+        assert_eq!(pick(1.0), "https://example.com/a.png?v=1");
+        assert_eq!(pick(2.0), "https://example.com/a@2x.png?v=1");
+        assert_eq!(pick(2.5), "https://example.com/a@3x.png?v=1");
+        assert_eq!(pick(4.0), "https://example.com/a@3x.png?v=1");
+    }
+
+    #[test]
+    fn prefers_dark_variants_in_dark_mode() {
+        let sources =
+            PhotoSources::from([(1.0, "light.png"), (2.0, "light@2x.png")]).dark(1.0, "dark.png");
+        assert_eq!(
+            sources.select(2.0, ColorScheme::Dark).unwrap().as_str(),
+            "dark.png"
+        );
+        assert_eq!(
+            sources.select(2.0, ColorScheme::Light).unwrap().as_str(),
+            "light@2x.png"
+        );
+    }
+
+    #[test]
+    fn width_descriptors_use_display_width() {
+        let sources = PhotoSources::from([(320_u32, "small.jpg"), (960_u32, "large.jpg")])
+            .display_width(320.0);
+        assert_eq!(
+            sources.select(1.0, ColorScheme::Light).unwrap().as_str(),
+            "small.jpg"
+        );
+        assert_eq!(
+            sources.select(3.0, ColorScheme::Light).unwrap().as_str(),
+            "large.jpg"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Reading the bytes behind local and `data:` URLs off the main thread.
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Cursor, Read, Seek, SeekFrom},
+    path::PathBuf,
+};
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+
+use crate::Url;
+
+/// A URL resolved into something that can be read on a background thread.
+///
+/// [`Url`] is not `Send`, so it is resolved on the calling thread first.
+pub enum LocalSource {
+    /// Base64 payload of a `data:` URL.
+    Data(String),
+    /// Path of a local file.
+    File(PathBuf),
+}
+
+impl LocalSource {
+    /// Resolves `url`, returning `None` for sources that cannot be read locally.
+    pub fn new(url: &Url) -> Option<Self> {
+        if url.is_data() {
+            let (header, payload) = url.as_str().split_once(',')?;
+            header
+                .ends_with(";base64")
+                .then(|| Self::Data(payload.to_owned()))
+        } else {
+            url.to_file_path().map(Self::File)
+        }
+    }
+
+    /// Opens the source for buffered, seekable reading.
+    pub fn open(self) -> io::Result<SourceReader> {
+        match self {
+            Self::Data(payload) => {
+                decode_base64(&payload).map(|data| SourceReader::Memory(Cursor::new(data)))
+            }
+            Self::File(path) => {
+                File::open(path).map(|file| SourceReader::File(BufReader::new(file)))
+            }
+        }
+    }
+
+    /// Reads the whole source into memory.
+    pub fn read(self) -> io::Result<Vec<u8>> {
+        match self {
+            Self::Data(payload) => decode_base64(&payload),
+            Self::File(path) => std::fs::read(path),
+        }
+    }
+}
+
+fn decode_base64(payload: &str) -> io::Result<Vec<u8>> {
+    BASE64_STANDARD
+        .decode(payload)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+/// A buffered, seekable reader over a [`LocalSource`].
+pub enum SourceReader {
+    Memory(Cursor<Vec<u8>>),
+    File(BufReader<File>),
+}
+
+impl Read for SourceReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Memory(reader) => reader.read(buf),
+            Self::File(reader) => reader.read(buf),
+        }
+    }
+}
+
+impl BufRead for SourceReader {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        match self {
+            Self::Memory(reader) => reader.fill_buf(),
+            Self::File(reader) => reader.fill_buf(),
+        }
+    }
+
+    fn consume(&mut self, amount: usize) {
+        match self {
+            Self::Memory(reader) => reader.consume(amount),
+            Self::File(reader) => reader.consume(amount),
+        }
+    }
+}
+
+impl Seek for SourceReader {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match self {
+            Self::Memory(reader) => reader.seek(pos),
+            Self::File(reader) => reader.seek(pos),
+        }
+    }
+}
@@ -1,2 +1,2 @@
-// Re-export the URL type from waterui-url crate
-pub use waterui_url::Url;
+// Re-export the URL types from waterui-url crate
+pub use waterui_url::{Url, UrlKind};
@@ -0,0 +1,174 @@
+//! Ambient activity shown in the navigation bar.
+//!
+//! Every [`NavigationStack`](crate::NavigationStack) installs an [`Activity`]
+//! for its content, and backends show a progress indicator in its navigation
+//! bar while any activity runs. Work that should be reflected there, such as
+//! loading the destination of a navigation link, begins an activity and keeps
+//! the returned [`ActivityToken`] until it is done:
+//!
+//! ```ignore
+//! use_env(|env: Environment| {
+//!     let token = env.begin_activity("Loading messages");
+//!     spawn_local(async move {
+//!         load_messages().await;
+//!         drop(token);
+//!     })
+//!     .detach();
+//! })
+//! ```
+//!
+//! Concurrent activities are combined: the indicator stays visible until the
+//! last token is dropped, and shows the label of the most recent one.
+
+use alloc::{rc::Rc, vec::Vec};
+use core::cell::Cell;
+
+use nami::{Binding, Computed, SignalExt};
+use waterui_core::{Environment, Str, impl_extractor};
+
+/// The activities running in a navigation stack.
+///
+/// Clones share the same set of activities.
+#[derive(Clone)]
+pub struct Activity {
+    running: Binding<Vec<(u64, Str)>>,
+    next_id: Rc<Cell<u64>>,
+}
+
+impl_extractor!(Activity);
+
+impl core::fmt::Debug for Activity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Activity")
+            .field("running", &self.running.get().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for Activity {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Activity {
+    /// Creates a set without running activities.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            running: Binding::container(Vec::new()),
+            next_id: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Begins an activity, which runs until the returned token is dropped.
+    pub fn begin(&self, label: impl Into<Str>) -> ActivityToken {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        let label = label.into();
+        self.running.with_mut(|running| running.push((id, label)));
+        ActivityToken {
+            activity: self.clone(),
+            id,
+        }
+    }
+
+    /// Returns whether any activity is running.
+    #[must_use]
+    pub fn is_active(&self) -> Computed<bool> {
+        self.running
+            .clone()
+            .map(|running| !running.is_empty())
+            .computed()
+    }
+
+    /// Returns the label of the most recently begun activity that is still
+    /// running, or an empty string.
+    #[must_use]
+    pub fn label(&self) -> Computed<Str> {
+        self.running
+            .clone()
+            .map(|running| {
+                running
+                    .last()
+                    .map(|(_, label)| label.clone())
+                    .unwrap_or_default()
+            })
+            .computed()
+    }
+}
+
+/// Keeps an activity running until dropped.
+#[must_use = "the activity ends as soon as the token is dropped"]
+#[derive(Debug)]
+pub struct ActivityToken {
+    activity: Activity,
+    id: u64,
+}
+
+impl ActivityToken {
+    /// Ends the activity.
+    pub fn end(self) {}
+}
+
+impl Drop for ActivityToken {
+    fn drop(&mut self) {
+        let id = self.id;
+        self.activity
+            .running
+            .with_mut(|running| running.retain(|(other, _)| *other != id));
+    }
+}
+
+/// Begins activities from an [`Environment`].
+pub trait ActivityExt {
+    /// Begins an activity in the enclosing navigation stack.
+    ///
+    /// Outside of a navigation stack nothing is shown, but the token can be
+    /// used the same way.
+    fn begin_activity(&self, label: impl Into<Str>) -> ActivityToken;
+}
+
+impl ActivityExt for Environment {
+    fn begin_activity(&self, label: impl Into<Str>) -> ActivityToken {
+        self.get::<Activity>()
+            .cloned()
+            .unwrap_or_default()
+            .begin(label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nami::Signal;
+
+    #[test]
+    fn tokens_aggregate_into_one_indicator() {
+        let activity = Activity::new();
+        let active = activity.is_active();
+        let label = activity.label();
+        assert!(!active.get());
+
+        let loading = activity.begin("Loading");
+        let saving = activity.begin("Saving");
+        assert!(active.get());
+        assert_eq!(label.get(), "Saving");
+
+        saving.end();
+        assert_eq!(label.get(), "Loading");
+        drop(loading);
+        assert!(!active.get());
+        assert_eq!(label.get(), "");
+    }
+
+    #[test]
+    fn begins_in_the_installed_activity() {
+        let activity = Activity::new();
+        let mut env = Environment::new();
+        env.insert(activity.clone());
+
+        let _token = env.begin_activity("Loading");
+        assert!(activity.is_active().get());
+    }
+}
@@ -0,0 +1,354 @@
+//! Interception of navigation before it happens.
+//!
+//! Every [`NavigationStack`](crate::NavigationStack) installs
+//! [`NavigationGuards`] for its content. A guard is consulted before a view
+//! is pushed or popped and answers with a [`GuardDecision`] through the
+//! [`GuardResponder`] it receives. The answer may come later, so a form can
+//! ask whether unsaved changes should be discarded before leaving:
+//!
+//! ```ignore
+//! use_env(move |env: Environment| {
+//!     let dirty = dirty.clone();
+//!     let guard = env.on_before_pop(move |responder: GuardResponder| {
+//!         if !dirty.get() {
+//!             return responder.allow();
+//!         }
+//!         spawn_local(async move {
+//!             if confirm("Discard unsaved changes?").await {
+//!                 responder.allow();
+//!             } else {
+//!                 responder.deny();
+//!             }
+//!         })
+//!         .detach();
+//!     });
+//!     Metadata::new(form(), Retain::new(guard))
+//! })
+//! ```
+//!
+//! Guards run from the most recently registered one, and the first one that
+//! does not allow the navigation decides. Pops started by the user, such as
+//! a back button, swipe or the browser's back button, are checked by the
+//! backend through [`NavigationGuards::check_pop`] whenever
+//! [`NavigationGuards::is_guarding_pop`] is true.
+
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+use core::cell::{Cell, RefCell};
+
+use nami::{Binding, Computed, SignalExt};
+use waterui_core::{Environment, impl_extractor};
+
+use crate::NavigationView;
+
+/// The answer of a guard to a navigation.
+#[derive(Debug)]
+pub enum GuardDecision {
+    /// Let the navigation happen.
+    Allow,
+    /// Cancel the navigation.
+    Deny,
+    /// Cancel the navigation and push this view instead.
+    Redirect(NavigationView),
+}
+
+/// Answers a guard's navigation once.
+///
+/// Dropping the responder without answering denies the navigation.
+#[must_use = "dropping the responder denies the navigation"]
+pub struct GuardResponder(Option<Box<dyn FnOnce(GuardDecision)>>);
+
+impl core::fmt::Debug for GuardResponder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GuardResponder")
+            .field("answered", &self.0.is_none())
+            .finish()
+    }
+}
+
+impl GuardResponder {
+    /// Creates a responder that passes the decision to `respond`.
+    pub fn new(respond: impl FnOnce(GuardDecision) + 'static) -> Self {
+        Self(Some(Box::new(respond)))
+    }
+
+    /// Answers with `decision`.
+    pub fn respond(mut self, decision: GuardDecision) {
+        if let Some(respond) = self.0.take() {
+            respond(decision);
+        }
+    }
+
+    /// Lets the navigation happen.
+    pub fn allow(self) {
+        self.respond(GuardDecision::Allow);
+    }
+
+    /// Cancels the navigation.
+    pub fn deny(self) {
+        self.respond(GuardDecision::Deny);
+    }
+
+    /// Cancels the navigation and pushes `view` instead.
+    pub fn redirect(self, view: NavigationView) {
+        self.respond(GuardDecision::Redirect(view));
+    }
+}
+
+impl Drop for GuardResponder {
+    fn drop(&mut self) {
+        if let Some(respond) = self.0.take() {
+            respond(GuardDecision::Deny);
+        }
+    }
+}
+
+type Guard = Rc<dyn Fn(GuardResponder)>;
+
+#[derive(Clone)]
+struct GuardList {
+    guards: Rc<RefCell<Vec<(u64, Guard)>>>,
+    guarded: Binding<bool>,
+}
+
+impl GuardList {
+    fn new() -> Self {
+        Self {
+            guards: Rc::default(),
+            guarded: Binding::container(false),
+        }
+    }
+
+    fn insert(&self, id: u64, guard: Guard) {
+        self.guards.borrow_mut().push((id, guard));
+        if !self.guarded.get() {
+            self.guarded.set(true);
+        }
+    }
+
+    fn remove(&self, id: u64) {
+        let mut guards = self.guards.borrow_mut();
+        guards.retain(|(other, _)| *other != id);
+        let guarded = !guards.is_empty();
+        drop(guards);
+        if self.guarded.get() != guarded {
+            self.guarded.set(guarded);
+        }
+    }
+
+    fn check(&self, on_decision: impl FnOnce(GuardDecision) + 'static) {
+        let guards = self
+            .guards
+            .borrow()
+            .iter()
+            .map(|(_, guard)| guard.clone())
+            .collect();
+        run_guards(guards, Box::new(on_decision));
+    }
+}
+
+/// Asks the last guard first and continues with the next one while they allow.
+fn run_guards(mut guards: Vec<Guard>, on_decision: Box<dyn FnOnce(GuardDecision)>) {
+    let Some(guard) = guards.pop() else {
+        on_decision(GuardDecision::Allow);
+        return;
+    };
+    guard(GuardResponder::new(move |decision| match decision {
+        GuardDecision::Allow => run_guards(guards, on_decision),
+        decision => on_decision(decision),
+    }));
+}
+
+/// The guards of a navigation stack.
+///
+/// Clones share the same guards.
+#[derive(Clone)]
+pub struct NavigationGuards {
+    push: GuardList,
+    pop: GuardList,
+    next_id: Rc<Cell<u64>>,
+}
+
+impl_extractor!(NavigationGuards);
+
+impl core::fmt::Debug for NavigationGuards {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("NavigationGuards")
+            .field("push", &self.push.guards.borrow().len())
+            .field("pop", &self.pop.guards.borrow().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for NavigationGuards {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NavigationGuards {
+    /// Creates a set without guards.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            push: GuardList::new(),
+            pop: GuardList::new(),
+            next_id: Rc::new(Cell::new(0)),
+        }
+    }
+
+    fn register(&self, list: &GuardList, guard: Guard) -> GuardToken {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        list.insert(id, guard);
+        GuardToken {
+            list: Some(list.clone()),
+            id,
+        }
+    }
+
+    /// Registers a guard consulted before a view is pushed.
+    ///
+    /// The guard is removed when the returned token is dropped.
+    pub fn on_before_push(&self, guard: impl Fn(GuardResponder) + 'static) -> GuardToken {
+        self.register(&self.push, Rc::new(guard))
+    }
+
+    /// Registers a guard consulted before the top view is popped.
+    ///
+    /// The guard is removed when the returned token is dropped.
+    pub fn on_before_pop(&self, guard: impl Fn(GuardResponder) + 'static) -> GuardToken {
+        self.register(&self.pop, Rc::new(guard))
+    }
+
+    /// Asks the push guards, then calls `on_decision` with their answer.
+    pub fn check_push(&self, on_decision: impl FnOnce(GuardDecision) + 'static) {
+        self.push.check(on_decision);
+    }
+
+    /// Asks the pop guards, then calls `on_decision` with their answer.
+    pub fn check_pop(&self, on_decision: impl FnOnce(GuardDecision) + 'static) {
+        self.pop.check(on_decision);
+    }
+
+    /// Returns whether any pop guard is registered.
+    ///
+    /// Backends only need to intercept back navigation while this is true.
+    #[must_use]
+    pub fn is_guarding_pop(&self) -> Computed<bool> {
+        self.pop.guarded.clone().computed()
+    }
+}
+
+/// Keeps a guard registered until dropped.
+#[must_use = "the guard is removed as soon as the token is dropped"]
+pub struct GuardToken {
+    list: Option<GuardList>,
+    id: u64,
+}
+
+impl core::fmt::Debug for GuardToken {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("GuardToken")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GuardToken {
+    /// Keeps the guard registered for as long as its navigation stack lives.
+    pub fn detach(mut self) {
+        self.list = None;
+    }
+}
+
+impl Drop for GuardToken {
+    fn drop(&mut self) {
+        if let Some(list) = self.list.take() {
+            list.remove(self.id);
+        }
+    }
+}
+
+/// Registers navigation guards from an [`Environment`].
+pub trait NavigationGuardExt {
+    /// Registers a guard in the enclosing navigation stack that is consulted
+    /// before a view is pushed.
+    ///
+    /// Outside of a navigation stack the guard is never consulted.
+    fn on_before_push(&self, guard: impl Fn(GuardResponder) + 'static) -> GuardToken;
+
+    /// Registers a guard in the enclosing navigation stack that is consulted
+    /// before the top view is popped.
+    ///
+    /// Outside of a navigation stack the guard is never consulted.
+    fn on_before_pop(&self, guard: impl Fn(GuardResponder) + 'static) -> GuardToken;
+}
+
+impl NavigationGuardExt for Environment {
+    fn on_before_push(&self, guard: impl Fn(GuardResponder) + 'static) -> GuardToken {
+        self.get::<NavigationGuards>()
+            .cloned()
+            .unwrap_or_default()
+            .on_before_push(guard)
+    }
+
+    fn on_before_pop(&self, guard: impl Fn(GuardResponder) + 'static) -> GuardToken {
+        self.get::<NavigationGuards>()
+            .cloned()
+            .unwrap_or_default()
+            .on_before_pop(guard)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nami::Signal;
+
+    fn decide(guards: &NavigationGuards) -> Rc<RefCell<Option<GuardDecision>>> {
+        let decision = Rc::new(RefCell::new(None));
+        let slot = decision.clone();
+        guards.check_pop(move |decision| *slot.borrow_mut() = Some(decision));
+        decision
+    }
+
+    #[test]
+    fn first_refusing_guard_decides() {
+        let guards = NavigationGuards::new();
+        let guarding = guards.is_guarding_pop();
+        assert!(matches!(
+            *decide(&guards).borrow(),
+            Some(GuardDecision::Allow)
+        ));
+
+        let _allow = guards.on_before_pop(GuardResponder::allow);
+        let deny = guards.on_before_pop(GuardResponder::deny);
+        assert!(guarding.get());
+        assert!(matches!(
+            *decide(&guards).borrow(),
+            Some(GuardDecision::Deny)
+        ));
+
+        drop(deny);
+        assert!(matches!(
+            *decide(&guards).borrow(),
+            Some(GuardDecision::Allow)
+        ));
+    }
+
+    #[test]
+    fn waits_for_a_later_answer() {
+        let guards = NavigationGuards::new();
+        let pending = Rc::new(RefCell::new(None));
+        let slot = pending.clone();
+        let token = guards.on_before_pop(move |responder| *slot.borrow_mut() = Some(responder));
+
+        let decision = decide(&guards);
+        assert!(decision.borrow().is_none());
+        pending.borrow_mut().take().unwrap().allow();
+        assert!(matches!(*decision.borrow(), Some(GuardDecision::Allow)));
+
+        drop(token);
+        assert!(!guards.is_guarding_pop().get());
+    }
+}
@@ -6,6 +6,8 @@
 //! hierarchical user interfaces with navigation bars and links.
 extern crate alloc;
 
+pub mod activity;
+pub mod guard;
 /// Provides search functionality for navigation.
 pub mod search;
 pub mod tab;
@@ -23,11 +25,18 @@ use nami::{
 use waterui_color::Color;
 use waterui_controls::button;
 use waterui_core::{
-    AnyView, Environment, Metadata, Retain, View, env::use_env, handler::ViewBuilder,
-    impl_extractor, layout::StretchAxis, raw_view,
+    AnyView, Environment, Metadata, Retain, View,
+    env::{use_env, with},
+    handler::ViewBuilder,
+    impl_extractor,
+    layout::StretchAxis,
+    raw_view,
 };
 use waterui_text::Text;
 
+pub use activity::{Activity, ActivityExt, ActivityToken};
+pub use guard::{GuardDecision, GuardResponder, GuardToken, NavigationGuardExt, NavigationGuards};
+
 /// A view that combines a navigation bar with content.
 ///
 /// The `NavigationView` contains a navigation bar with a title and other
@@ -54,8 +63,13 @@ pub trait CustomNavigationController: 'static {
 
 /// A receiver that handles navigation actions.
 /// For renderers to implement navigation handling.
+///
+/// Pushes and pops go through the controller's [`NavigationGuards`] first.
 #[derive(Clone)]
-pub struct NavigationController(Rc<RefCell<dyn CustomNavigationController>>);
+pub struct NavigationController {
+    inner: Rc<RefCell<dyn CustomNavigationController>>,
+    guards: NavigationGuards,
+}
 
 impl_extractor!(NavigationController);
 
@@ -72,20 +86,68 @@ impl NavigationController {
     ///
     /// * `receiver` - An implementation of `CustomNavigationController`
     pub fn new(receiver: impl CustomNavigationController) -> Self {
-        Self(Rc::new(RefCell::new(receiver)))
+        Self {
+            inner: Rc::new(RefCell::new(receiver)),
+            guards: NavigationGuards::new(),
+        }
     }
 
-    /// Pushes a new navigation view onto the stack.
+    /// Uses the guards of a navigation stack.
+    ///
+    /// Renderers pass the [`NavigationStack::guards`] of the stack the
+    /// controller drives.
+    #[must_use]
+    pub fn with_guards(mut self, guards: NavigationGuards) -> Self {
+        self.guards = guards;
+        self
+    }
+
+    /// Returns the guards consulted before navigating.
+    #[must_use]
+    pub const fn guards(&self) -> &NavigationGuards {
+        &self.guards
+    }
+
+    /// Registers a guard consulted before a view is pushed.
+    pub fn on_before_push(&self, guard: impl Fn(GuardResponder) + 'static) -> GuardToken {
+        self.guards.on_before_push(guard)
+    }
+
+    /// Registers a guard consulted before the top view is popped.
+    pub fn on_before_pop(&self, guard: impl Fn(GuardResponder) + 'static) -> GuardToken {
+        self.guards.on_before_pop(guard)
+    }
+
+    /// Pushes a new navigation view onto the stack, unless a guard denies it.
     ///
     /// # Arguments
     ///
     /// * `content` - The navigation view to push
     pub fn push(&self, content: NavigationView) {
-        self.0.borrow_mut().push(content);
+        let this = self.clone();
+        self.guards.check_push(move |decision| match decision {
+            GuardDecision::Allow => this.push_unguarded(content),
+            GuardDecision::Redirect(view) => this.push_unguarded(view),
+            GuardDecision::Deny => {}
+        });
     }
-    /// Pops the top navigation view off the stack.
+
+    /// Pops the top navigation view off the stack, unless a guard denies it.
     pub fn pop(&self) {
-        self.0.borrow_mut().pop();
+        let this = self.clone();
+        self.guards.check_pop(move |decision| match decision {
+            GuardDecision::Allow => this.pop_unguarded(),
+            GuardDecision::Redirect(view) => this.push_unguarded(view),
+            GuardDecision::Deny => {}
+        });
+    }
+
+    fn push_unguarded(&self, content: NavigationView) {
+        self.inner.borrow_mut().push(content);
+    }
+
+    fn pop_unguarded(&self) {
+        self.inner.borrow_mut().pop();
     }
 }
 
@@ -134,12 +196,42 @@ where
 }
 
 /// A stack of navigation views.
+///
+/// The stack installs an [`Activity`] for its content. Backends show a
+/// progress indicator in the navigation bar while it is active.
+///
+/// It also installs its [`NavigationGuards`]. Renderers pass them to the
+/// stack's [`NavigationController`] and check the pop guards before a back
+/// navigation started by the user.
 #[must_use]
 #[derive(Debug)]
 pub struct NavigationStack<T, F> {
     root: AnyView, // Renderer requires to inject `NavigationController` to the root view's environment
     path: T,
     destination: F,
+    activity: Activity,
+    guards: NavigationGuards,
+}
+
+impl<T, F> NavigationStack<T, F> {
+    /// Registers a guard consulted before a view is pushed, for as long as
+    /// the stack lives.
+    pub fn on_before_push(self, guard: impl Fn(GuardResponder) + 'static) -> Self {
+        self.guards.on_before_push(guard).detach();
+        self
+    }
+
+    /// Registers a guard consulted before the top view is popped, for as
+    /// long as the stack lives.
+    pub fn on_before_pop(self, guard: impl Fn(GuardResponder) + 'static) -> Self {
+        self.guards.on_before_pop(guard).detach();
+        self
+    }
+
+    /// Returns the guards consulted before navigating.
+    pub const fn guards(&self) -> &NavigationGuards {
+        &self.guards
+    }
 }
 
 impl NavigationStack<(), ()> {
@@ -148,13 +240,25 @@ impl NavigationStack<(), ()> {
     /// # Arguments
     /// * `root` - The root view of the navigation stack
     pub fn new(root: impl View) -> Self {
+        Self::with_context(root, Activity::new(), NavigationGuards::new())
+    }
+
+    fn with_context(root: impl View, activity: Activity, guards: NavigationGuards) -> Self {
         Self {
-            root: AnyView::new(root),
+            root: AnyView::new(with(with(root, activity.clone()), guards.clone())),
             path: (),
             destination: (),
+            activity,
+            guards,
         }
     }
 
+    /// Returns the activities running in the stack.
+    #[must_use]
+    pub const fn activity(&self) -> &Activity {
+        &self.activity
+    }
+
     /// Consumes the navigation stack and returns its root view.
     pub fn into_inner(self) -> AnyView {
         self.root
@@ -172,6 +276,8 @@ impl<T> NavigationStack<NavigationPath<T>, ()> {
             root: AnyView::new(root),
             path,
             destination: (),
+            activity: Activity::new(),
+            guards: NavigationGuards::new(),
         }
     }
 
@@ -187,6 +293,8 @@ impl<T> NavigationStack<NavigationPath<T>, ()> {
             root: self.root,
             path: self.path,
             destination,
+            activity: self.activity,
+            guards: self.guards,
         }
     }
 }
@@ -202,38 +310,45 @@ where
         let path: NavigationPath<T> = self.path;
         let destination = self.destination;
         let root = self.root;
-        NavigationStack::new(use_env(move |receiver: NavigationController| {
-            let path = path.inner;
-            for component in &path {
-                receiver.push(destination(component));
-            }
-
-            let old_len = Cell::new(path.len());
-            #[allow(clippy::cast_possible_wrap)]
-            let guard = path.watch(.., move |slice| {
-                // list is a stack, only pop or push. So we only watch its length change
-                let slice = slice.into_value();
-                let len = slice.len();
-                let change = len as isize - old_len.get() as isize;
-                if change > 0 {
-                    // length increase, it has been pushed
-                    for item in slice.iter().skip(old_len.get()).take(len - old_len.get()) {
-                        receiver.push(destination(item.clone()));
-                    }
-                }
-                #[allow(clippy::cast_sign_loss)]
-                if change < 0 {
-                    //length decrease, it has been popped
-                    let pop_count = (-change) as usize;
-                    for _ in 0..pop_count {
-                        receiver.pop();
-                    }
+        let activity = self.activity;
+        let guards = self.guards;
+        NavigationStack::with_context(
+            use_env(move |receiver: NavigationController| {
+                let path = path.inner;
+                // The path is the source of truth, so mirroring it bypasses the guards
+                for component in &path {
+                    receiver.push_unguarded(destination(component));
                 }
-                old_len.set(len);
-            });
 
-            Metadata::new(root, Retain::new(guard))
-        }))
+                let old_len = Cell::new(path.len());
+                #[allow(clippy::cast_possible_wrap)]
+                let guard = path.watch(.., move |slice| {
+                    // list is a stack, only pop or push. So we only watch its length change
+                    let slice = slice.into_value();
+                    let len = slice.len();
+                    let change = len as isize - old_len.get() as isize;
+                    if change > 0 {
+                        // length increase, it has been pushed
+                        for item in slice.iter().skip(old_len.get()).take(len - old_len.get()) {
+                            receiver.push_unguarded(destination(item.clone()));
+                        }
+                    }
+                    #[allow(clippy::cast_sign_loss)]
+                    if change < 0 {
+                        //length decrease, it has been popped
+                        let pop_count = (-change) as usize;
+                        for _ in 0..pop_count {
+                            receiver.pop_unguarded();
+                        }
+                    }
+                    old_len.set(len);
+                });
+
+                Metadata::new(root, Retain::new(guard))
+            }),
+            activity,
+            guards,
+        )
     }
 }
 
@@ -22,6 +22,7 @@ impl Default for Font {
 
 /// A resolved font with specific size and weight.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[non_exhaustive]
 pub struct ResolvedFont {
     /// Font size in points.
@@ -40,6 +41,7 @@ impl ResolvedFont {
 
 /// Font weight enumeration.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontWeight {
     /// Thin weight (100).
     Thin,
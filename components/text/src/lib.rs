@@ -17,6 +17,9 @@ pub mod highlight;
 pub mod locale;
 /// Styled text support for rich text formatting.
 pub mod styled;
+/// Translation tables for localized strings.
+pub mod translate;
+pub mod update;
 /// Macros for convenient text creation.
 #[macro_use]
 pub mod macros;
@@ -24,6 +27,6 @@ extern crate alloc;
 
 /// Core text component.
 pub mod text;
-pub use text::{Text, TextConfig, text};
+pub use text::{Text, TextAlignment, TextConfig, TruncationMode, text};
 
 pub use nami as __nami;
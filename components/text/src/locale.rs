@@ -1,9 +1,12 @@
-use alloc::string::ToString;
-use nami::impl_constant;
-use time::Date;
-use waterui_core::Str;
+use alloc::{format, rc::Rc, string::String};
+use core::fmt::Write;
+use nami::{Computed, SignalExt, impl_constant, signal::IntoComputed};
+use waterui_core::time::{Date, Duration, Month, OffsetDateTime, Time};
+use waterui_core::{Environment, Str, View};
 use waterui_core::{Error, extract::Extractor};
 
+use crate::Text;
+
 /// Trait for formatting values into locale-aware strings.
 ///
 /// This trait allows different types to be formatted according to
@@ -13,6 +16,20 @@ pub trait Formatter<T>: Clone {
     fn format(&self, value: &T) -> Str;
 }
 
+/// The amount of detail used when formatting a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateStyle {
+    /// Numeric date, such as `1/5/2024` or `05.01.2024`.
+    Short,
+    /// Abbreviated month name, such as `Jan 5, 2024`.
+    #[default]
+    Medium,
+    /// Full month name, such as `January 5, 2024`.
+    Long,
+    /// Locale-independent ISO 8601 date, such as `2024-01-05`.
+    Iso,
+}
+
 /// A formatter for dates that respects locale settings.
 ///
 /// This formatter can convert date values into locale-appropriate
@@ -20,19 +37,32 @@ pub trait Formatter<T>: Clone {
 #[derive(Debug, Clone)]
 pub struct DateFormatter {
     locale: Locale,
+    style: DateStyle,
 }
 
 impl DateFormatter {
+    /// Creates a date formatter for the given locale and style.
+    #[must_use]
+    pub const fn new(locale: Locale, style: DateStyle) -> Self {
+        Self { locale, style }
+    }
+
     /// Returns a reference to the locale used by this formatter.
     #[must_use]
     pub const fn get_locale(&self) -> &Locale {
         &self.locale
     }
+
+    /// Returns the style used by this formatter.
+    #[must_use]
+    pub const fn style(&self) -> DateStyle {
+        self.style
+    }
 }
 
 impl Formatter<Date> for DateFormatter {
     fn format(&self, value: &Date) -> Str {
-        value.to_string().into()
+        format_date_with(&self.locale, self.style, *value).into()
     }
 }
 
@@ -42,7 +72,65 @@ impl Extractor for DateFormatter {
             .get::<Locale>()
             .ok_or_else(|| Error::msg("Locale not found"))?
             .clone();
-        Ok(Self { locale })
+        Ok(Self {
+            locale,
+            style: DateStyle::default(),
+        })
+    }
+}
+
+/// A formatter for decimal numbers using locale-specific separators.
+#[derive(Debug, Clone)]
+pub struct NumberFormatter {
+    locale: Locale,
+    fraction_digits: usize,
+}
+
+impl NumberFormatter {
+    /// Creates a number formatter that prints two fraction digits.
+    #[must_use]
+    pub const fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            fraction_digits: 2,
+        }
+    }
+
+    /// Sets the number of digits printed after the decimal separator.
+    #[must_use]
+    pub const fn fraction_digits(mut self, digits: usize) -> Self {
+        self.fraction_digits = digits;
+        self
+    }
+}
+
+impl Formatter<f64> for NumberFormatter {
+    fn format(&self, value: &f64) -> Str {
+        format_number_with(&self.locale, *value, self.fraction_digits).into()
+    }
+}
+
+/// A formatter for monetary amounts in a given ISO 4217 currency.
+#[derive(Debug, Clone)]
+pub struct CurrencyFormatter {
+    locale: Locale,
+    currency: Str,
+}
+
+impl CurrencyFormatter {
+    /// Creates a currency formatter for the given locale and currency code (e.g. `"EUR"`).
+    #[must_use]
+    pub fn new(locale: Locale, currency: impl Into<Str>) -> Self {
+        Self {
+            locale,
+            currency: currency.into(),
+        }
+    }
+}
+
+impl Formatter<f64> for CurrencyFormatter {
+    fn format(&self, value: &f64) -> Str {
+        format_currency_with(&self.locale, &self.currency, *value).into()
     }
 }
 
@@ -55,6 +143,23 @@ pub struct Locale(pub Str);
 
 impl_constant!(Locale);
 
+impl Locale {
+    /// Returns the language subtag, such as `en` for `en-US`.
+    #[must_use]
+    pub fn language(&self) -> &str {
+        self.0.split(['-', '_']).next().unwrap_or_default()
+    }
+
+    /// Returns the region subtag, such as `US` for `en-US`, if present.
+    #[must_use]
+    pub fn region(&self) -> Option<&str> {
+        self.0
+            .split(['-', '_'])
+            .skip(1)
+            .find(|part| part.len() == 2 || part.chars().all(|c| c.is_ascii_digit()))
+    }
+}
+
 impl Default for Locale {
     fn default() -> Self {
         Self("en-US".into())
@@ -68,3 +173,690 @@ impl Extractor for Locale {
             .cloned()
     }
 }
+
+/// Storage for a reactive locale installed by the host or the app.
+#[derive(Debug, Clone)]
+struct LocaleSignal(Computed<Locale>);
+
+/// Installs a reactive locale into the environment.
+///
+/// Localized text created by [`format_date`], [`format_number`] and
+/// [`format_currency`] re-formats whenever this signal changes.
+pub fn install_locale(env: &mut Environment, locale: impl IntoComputed<Locale>) {
+    env.insert(LocaleSignal(locale.into_computed()));
+}
+
+/// Returns the current locale signal from the environment.
+///
+/// Falls back to a static [`Locale`] stored in the environment, then to `en-US`.
+#[must_use]
+pub fn current_locale(env: &Environment) -> Computed<Locale> {
+    env.get::<LocaleSignal>().map_or_else(
+        || Computed::constant(env.get::<Locale>().cloned().unwrap_or_default()),
+        |signal| signal.0.clone(),
+    )
+}
+
+type LocalizedFormat<T> = Rc<dyn Fn(&Locale, &T) -> Str>;
+
+/// Text whose content is formatted using the locale from the environment.
+///
+/// Created by [`format_date`], [`format_time`], [`format_duration`],
+/// [`format_number`] and [`format_currency`].
+pub struct LocalizedText<T: 'static> {
+    value: Computed<T>,
+    format: LocalizedFormat<T>,
+}
+
+impl<T: 'static> core::fmt::Debug for LocalizedText<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("LocalizedText(..)")
+    }
+}
+
+impl<T: Clone + 'static> LocalizedText<T> {
+    /// Creates localized text from a value and a locale-aware format function.
+    pub fn new(value: impl IntoComputed<T>, format: impl Fn(&Locale, &T) -> Str + 'static) -> Self {
+        Self {
+            value: value.into_computed(),
+            format: Rc::new(format),
+        }
+    }
+}
+
+impl<T: Clone + 'static> View for LocalizedText<T> {
+    fn body(self, env: &Environment) -> impl View {
+        let format = self.format;
+        Text::new(
+            self.value
+                .zip(current_locale(env))
+                .map(move |(value, locale)| format(&locale, &value)),
+        )
+    }
+}
+
+/// Creates text showing a date formatted for the current locale.
+///
+/// # Example
+///
+/// ```ignore
+/// let due = binding(Date::from_calendar_date(2024, Month::January, 5)?);
+/// format_date(due, DateStyle::Long) // "January 5, 2024" in en-US
+/// ```
+pub fn format_date(date: impl IntoComputed<Date>, style: DateStyle) -> LocalizedText<Date> {
+    LocalizedText::new(date, move |locale, date| {
+        format_date_with(locale, style, *date).into()
+    })
+}
+
+/// Creates text showing a time of day formatted for the current locale.
+///
+/// # Example
+///
+/// ```ignore
+/// let alarm = binding(Time::from_hms(7, 30, 0)?);
+/// format_time(alarm) // "7:30 AM" in en-US, "07:30" in de-DE
+/// ```
+pub fn format_time(time: impl IntoComputed<Time>) -> LocalizedText<Time> {
+    LocalizedText::new(time, |locale, time| format_time_with(locale, *time).into())
+}
+
+/// Creates text showing a duration as a clock reading, such as `4:05` or `1:02:03`.
+///
+/// Suited to media lengths, timers and countdowns.
+pub fn format_duration(duration: impl IntoComputed<Duration>) -> LocalizedText<Duration> {
+    LocalizedText::new(duration, |_locale, duration| {
+        format_duration_clock(*duration).into()
+    })
+}
+
+/// Creates text showing a number with two fraction digits and locale separators.
+pub fn format_number(value: impl IntoComputed<f64>) -> LocalizedText<f64> {
+    LocalizedText::new(value, |locale, value| {
+        format_number_with(locale, *value, 2).into()
+    })
+}
+
+/// Creates text showing a monetary amount in the given ISO 4217 currency.
+pub fn format_currency(
+    value: impl IntoComputed<f64>,
+    currency: impl Into<Str>,
+) -> LocalizedText<f64> {
+    let currency = currency.into();
+    LocalizedText::new(value, move |locale, value| {
+        format_currency_with(locale, &currency, *value).into()
+    })
+}
+
+/// Storage for the clock that drives relative times.
+#[derive(Debug, Clone)]
+struct ClockSignal(Computed<OffsetDateTime>);
+
+/// Installs the clock used by [`relative_time`] into the environment.
+///
+/// The signal only needs to tick coarsely (every few seconds or so): relative
+/// times are shown at minute granularity. `waterui::App` installs a shared
+/// clock automatically.
+pub fn install_clock(env: &mut Environment, now: impl IntoComputed<OffsetDateTime>) {
+    env.insert(ClockSignal(now.into_computed()));
+}
+
+/// Returns the clock signal from the environment, if one is installed.
+#[must_use]
+pub fn current_time(env: &Environment) -> Option<Computed<OffsetDateTime>> {
+    env.get::<ClockSignal>().map(|clock| clock.0.clone())
+}
+
+/// Text describing a point in time relative to now, such as "3 minutes ago".
+///
+/// Created by [`relative_time`].
+#[derive(Debug)]
+pub struct RelativeTime {
+    timestamp: Computed<OffsetDateTime>,
+}
+
+impl View for RelativeTime {
+    fn body(self, env: &Environment) -> impl View {
+        // Without a clock, the timestamp itself is treated as the current time
+        let now = current_time(env).unwrap_or_else(|| self.timestamp.clone());
+        Text::new(self.timestamp.zip(now).zip(current_locale(env)).map(
+            |((timestamp, now), locale)| {
+                Str::from(format_relative_with(
+                    &locale,
+                    (now - timestamp).whole_seconds(),
+                ))
+            },
+        ))
+    }
+}
+
+/// Creates text showing how long ago (or how far ahead) `timestamp` is.
+///
+/// The text updates as time passes, driven by the clock installed with
+/// [`install_clock`], and uses the units of the current locale.
+///
+/// # Example
+///
+/// ```ignore
+/// let sent = binding(OffsetDateTime::now_utc() - Duration::minutes(3));
+/// relative_time(sent) // "3 minutes ago", later "4 minutes ago"
+/// ```
+pub fn relative_time(timestamp: impl IntoComputed<OffsetDateTime>) -> RelativeTime {
+    RelativeTime {
+        timestamp: timestamp.into_computed(),
+    }
+}
+
+// ============================================================================
+// Minimal locale conventions
+// ============================================================================
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DateOrder {
+    MonthDayYear,
+    DayMonthYear,
+    YearMonthDay,
+}
+
+struct Conventions {
+    decimal: char,
+    group: &'static str,
+    order: DateOrder,
+    date_separator: char,
+    symbol_first: bool,
+    hour12: bool,
+}
+
+fn conventions(locale: &Locale) -> Conventions {
+    let language = locale.language().to_ascii_lowercase();
+    let region = locale.region().map(str::to_ascii_uppercase);
+    match (language.as_str(), region.as_deref()) {
+        ("en", Some("US" | "PH") | None) => Conventions {
+            decimal: '.',
+            group: ",",
+            order: DateOrder::MonthDayYear,
+            date_separator: '/',
+            symbol_first: true,
+            hour12: true,
+        },
+        ("en", Some(_)) => Conventions {
+            decimal: '.',
+            group: ",",
+            order: DateOrder::DayMonthYear,
+            date_separator: '/',
+            symbol_first: true,
+            hour12: false,
+        },
+        ("de" | "da" | "nb" | "fi" | "ru" | "pl" | "tr", _) => Conventions {
+            decimal: ',',
+            group: if matches!(language.as_str(), "de" | "da" | "tr") {
+                "."
+            } else {
+                "\u{a0}"
+            },
+            order: DateOrder::DayMonthYear,
+            date_separator: '.',
+            symbol_first: false,
+            hour12: false,
+        },
+        ("fr", _) => Conventions {
+            decimal: ',',
+            group: "\u{202f}",
+            order: DateOrder::DayMonthYear,
+            date_separator: '/',
+            symbol_first: false,
+            hour12: false,
+        },
+        ("es" | "it" | "pt" | "nl", _) => Conventions {
+            decimal: ',',
+            group: ".",
+            order: DateOrder::DayMonthYear,
+            date_separator: '/',
+            symbol_first: false,
+            hour12: false,
+        },
+        ("ja" | "zh" | "ko", _) => Conventions {
+            decimal: '.',
+            group: ",",
+            order: DateOrder::YearMonthDay,
+            date_separator: '/',
+            symbol_first: true,
+            hour12: false,
+        },
+        _ => Conventions {
+            decimal: '.',
+            group: ",",
+            order: DateOrder::YearMonthDay,
+            date_separator: '-',
+            symbol_first: true,
+            hour12: false,
+        },
+    }
+}
+
+fn month_names(language: &str) -> Option<[&'static str; 12]> {
+    Some(match language {
+        "en" => [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ],
+        "de" => [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        "fr" => [
+            "janvier",
+            "février",
+            "mars",
+            "avril",
+            "mai",
+            "juin",
+            "juillet",
+            "août",
+            "septembre",
+            "octobre",
+            "novembre",
+            "décembre",
+        ],
+        "es" => [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+        _ => return None,
+    })
+}
+
+fn abbreviate(name: &str) -> String {
+    let short: String = name.chars().take(3).collect();
+    if name.chars().count() > 3 {
+        short
+    } else {
+        name.into()
+    }
+}
+
+fn format_date_with(locale: &Locale, style: DateStyle, date: Date) -> String {
+    let (year, month, day) = (date.year(), u8::from(date.month()), date.day());
+    if style == DateStyle::Iso {
+        return format!("{year:04}-{month:02}-{day:02}");
+    }
+
+    let conventions = conventions(locale);
+    let language = locale.language().to_ascii_lowercase();
+    let names = month_names(&language).filter(|_| style != DateStyle::Short);
+    let Some(names) = names else {
+        let sep = conventions.date_separator;
+        return match conventions.order {
+            DateOrder::MonthDayYear => format!("{month}{sep}{day}{sep}{year}"),
+            DateOrder::DayMonthYear => format!("{day:02}{sep}{month:02}{sep}{year}"),
+            DateOrder::YearMonthDay => format!("{year}{sep}{month:02}{sep}{day:02}"),
+        };
+    };
+
+    let full = names[usize::from(month_index(date.month()))];
+    let name = if style == DateStyle::Long {
+        String::from(full)
+    } else {
+        abbreviate(full)
+    };
+    match (language.as_str(), conventions.order) {
+        ("en", DateOrder::MonthDayYear) => format!("{name} {day}, {year}"),
+        ("de", _) if style == DateStyle::Long => format!("{day}. {name} {year}"),
+        ("de", _) => format!("{day}. {name}. {year}"),
+        ("es", _) if style == DateStyle::Long => format!("{day} de {name} de {year}"),
+        _ => format!("{day} {name} {year}"),
+    }
+}
+
+fn format_time_with(locale: &Locale, time: Time) -> String {
+    let (hour, minute) = (time.hour(), time.minute());
+    if conventions(locale).hour12 {
+        let period = if hour < 12 { "AM" } else { "PM" };
+        let hour = match hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+        format!("{hour}:{minute:02} {period}")
+    } else {
+        format!("{hour:02}:{minute:02}")
+    }
+}
+
+fn format_duration_clock(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+const fn month_index(month: Month) -> u8 {
+    month as u8 - 1
+}
+
+fn format_number_with(locale: &Locale, value: f64, fraction_digits: usize) -> String {
+    let conventions = conventions(locale);
+    let negative = value < 0.0;
+    let magnitude = if negative { -value } else { value };
+    let plain = format!("{magnitude:.fraction_digits$}");
+    let (integer, fraction) = plain.split_once('.').unwrap_or((&plain, ""));
+
+    let mut out = String::new();
+    if negative {
+        out.push('-');
+    }
+    for (index, digit) in integer.chars().enumerate() {
+        if index > 0 && (integer.len() - index) % 3 == 0 {
+            out.push_str(conventions.group);
+        }
+        out.push(digit);
+    }
+    if !fraction.is_empty() {
+        out.push(conventions.decimal);
+        out.push_str(fraction);
+    }
+    out
+}
+
+fn format_currency_with(locale: &Locale, currency: &str, value: f64) -> String {
+    let (symbol, digits) = match currency {
+        "USD" => ("$", 2),
+        "EUR" => ("€", 2),
+        "GBP" => ("£", 2),
+        "JPY" => ("¥", 0),
+        "CNY" => ("¥", 2),
+        "KRW" => ("₩", 0),
+        "INR" => ("₹", 2),
+        other => (other, 2),
+    };
+    let amount = format_number_with(locale, value, digits);
+    let mut out = String::new();
+    if conventions(locale).symbol_first {
+        let (sign, digits) = amount
+            .strip_prefix('-')
+            .map_or(("", amount.as_str()), |rest| ("-", rest));
+        let _ = write!(out, "{sign}{symbol}");
+        if symbol.len() == 3 && symbol.is_ascii() {
+            out.push('\u{a0}');
+        }
+        out.push_str(digits);
+    } else {
+        let _ = write!(out, "{amount}\u{a0}{symbol}");
+    }
+    out
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum TimeUnit {
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// Picks the largest fitting unit, or `None` for less than a minute.
+const fn relative_unit(seconds: u64) -> Option<(u64, TimeUnit)> {
+    const MINUTE: u64 = 60;
+    const HOUR: u64 = 60 * MINUTE;
+    const DAY: u64 = 24 * HOUR;
+    Some(match seconds {
+        0..MINUTE => return None,
+        MINUTE..HOUR => (seconds / MINUTE, TimeUnit::Minute),
+        HOUR..DAY => (seconds / HOUR, TimeUnit::Hour),
+        _ if seconds < 7 * DAY => (seconds / DAY, TimeUnit::Day),
+        _ if seconds < 30 * DAY => (seconds / (7 * DAY), TimeUnit::Week),
+        _ if seconds < 365 * DAY => (seconds / (30 * DAY), TimeUnit::Month),
+        _ => (seconds / (365 * DAY), TimeUnit::Year),
+    })
+}
+
+fn unit_name(language: &str, unit: TimeUnit, count: u64) -> &'static str {
+    let one = count == 1;
+    match language {
+        "de" => match unit {
+            TimeUnit::Minute if one => "Minute",
+            TimeUnit::Minute => "Minuten",
+            TimeUnit::Hour if one => "Stunde",
+            TimeUnit::Hour => "Stunden",
+            TimeUnit::Day if one => "Tag",
+            TimeUnit::Day => "Tagen",
+            TimeUnit::Week if one => "Woche",
+            TimeUnit::Week => "Wochen",
+            TimeUnit::Month if one => "Monat",
+            TimeUnit::Month => "Monaten",
+            TimeUnit::Year if one => "Jahr",
+            TimeUnit::Year => "Jahren",
+        },
+        // French uses the singular for 0 and 1
+        "fr" => match (unit, count <= 1) {
+            (TimeUnit::Minute, true) => "minute",
+            (TimeUnit::Minute, false) => "minutes",
+            (TimeUnit::Hour, true) => "heure",
+            (TimeUnit::Hour, false) => "heures",
+            (TimeUnit::Day, true) => "jour",
+            (TimeUnit::Day, false) => "jours",
+            (TimeUnit::Week, true) => "semaine",
+            (TimeUnit::Week, false) => "semaines",
+            (TimeUnit::Month, _) => "mois",
+            (TimeUnit::Year, true) => "an",
+            (TimeUnit::Year, false) => "ans",
+        },
+        "es" => match unit {
+            TimeUnit::Minute if one => "minuto",
+            TimeUnit::Minute => "minutos",
+            TimeUnit::Hour if one => "hora",
+            TimeUnit::Hour => "horas",
+            TimeUnit::Day if one => "día",
+            TimeUnit::Day => "días",
+            TimeUnit::Week if one => "semana",
+            TimeUnit::Week => "semanas",
+            TimeUnit::Month if one => "mes",
+            TimeUnit::Month => "meses",
+            TimeUnit::Year if one => "año",
+            TimeUnit::Year => "años",
+        },
+        "ja" => match unit {
+            TimeUnit::Minute => "分",
+            TimeUnit::Hour => "時間",
+            TimeUnit::Day => "日",
+            TimeUnit::Week => "週間",
+            TimeUnit::Month => "か月",
+            TimeUnit::Year => "年",
+        },
+        "zh" => match unit {
+            TimeUnit::Minute => "分钟",
+            TimeUnit::Hour => "小时",
+            TimeUnit::Day => "天",
+            TimeUnit::Week => "周",
+            TimeUnit::Month => "个月",
+            TimeUnit::Year => "年",
+        },
+        _ => match unit {
+            TimeUnit::Minute if one => "minute",
+            TimeUnit::Minute => "minutes",
+            TimeUnit::Hour if one => "hour",
+            TimeUnit::Hour => "hours",
+            TimeUnit::Day if one => "day",
+            TimeUnit::Day => "days",
+            TimeUnit::Week if one => "week",
+            TimeUnit::Week => "weeks",
+            TimeUnit::Month if one => "month",
+            TimeUnit::Month => "months",
+            TimeUnit::Year if one => "year",
+            TimeUnit::Year => "years",
+        },
+    }
+}
+
+/// Formats `elapsed` seconds since a point in time; negative values lie in the future.
+fn format_relative_with(locale: &Locale, elapsed: i64) -> String {
+    let language = locale.language().to_ascii_lowercase();
+    let past = elapsed >= 0;
+    let Some((count, unit)) = relative_unit(elapsed.unsigned_abs()) else {
+        return String::from(match language.as_str() {
+            "de" => "jetzt",
+            "fr" => "maintenant",
+            "es" => "ahora",
+            "ja" => "今",
+            "zh" => "现在",
+            _ => "now",
+        });
+    };
+
+    let name = unit_name(&language, unit, count);
+    match (language.as_str(), past) {
+        ("de", true) => format!("vor {count} {name}"),
+        ("fr", true) => format!("il y a {count} {name}"),
+        ("fr", false) => format!("dans {count} {name}"),
+        ("es", true) => format!("hace {count} {name}"),
+        ("es", false) => format!("dentro de {count} {name}"),
+        ("ja" | "zh", true) => format!("{count}{name}前"),
+        ("ja", false) => format!("{count}{name}後"),
+        ("zh", false) => format!("{count}{name}后"),
+        (_, true) => format!("{count} {name} ago"),
+        (_, false) => format!("in {count} {name}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nami::Signal;
+
+    fn locale(tag: &'static str) -> Locale {
+        Locale(tag.into())
+    }
+
+    #[test]
+    fn formats_dates_per_locale() {
+        let day = Date::from_calendar_date(2024, Month::January, 5).unwrap();
+        assert_eq!(
+            format_date_with(&locale("en-US"), DateStyle::Medium, day),
+            "Jan 5, 2024"
+        );
+        assert_eq!(
+            format_date_with(&locale("en-GB"), DateStyle::Long, day),
+            "5 January 2024"
+        );
+        assert_eq!(
+            format_date_with(&locale("de-DE"), DateStyle::Short, day),
+            "05.01.2024"
+        );
+        assert_eq!(
+            format_date_with(&locale("ja-JP"), DateStyle::Long, day),
+            "2024/01/05"
+        );
+        assert_eq!(
+            format_date_with(&locale("fr"), DateStyle::Iso, day),
+            "2024-01-05"
+        );
+    }
+
+    #[test]
+    fn formats_times_and_durations() {
+        let evening = Time::from_hms(19, 5, 0).unwrap();
+        assert_eq!(format_time_with(&locale("en-US"), evening), "7:05 PM");
+        assert_eq!(
+            format_time_with(&locale("en-US"), Time::MIDNIGHT),
+            "12:00 AM"
+        );
+        assert_eq!(format_time_with(&locale("de-DE"), evening), "19:05");
+        assert_eq!(format_duration_clock(Duration::from_secs(245)), "4:05");
+        assert_eq!(format_duration_clock(Duration::from_secs(3723)), "1:02:03");
+    }
+
+    #[test]
+    fn formats_numbers_and_currency() {
+        assert_eq!(
+            format_number_with(&locale("en-US"), 1_234_567.891, 2),
+            "1,234,567.89"
+        );
+        assert_eq!(format_number_with(&locale("de-DE"), -1234.5, 1), "-1.234,5");
+        assert_eq!(
+            format_currency_with(&locale("en-US"), "USD", -42.5),
+            "-$42.50"
+        );
+        assert_eq!(
+            format_currency_with(&locale("fr-FR"), "EUR", 1234.5),
+            "1\u{202f}234,50\u{a0}€"
+        );
+        assert_eq!(format_currency_with(&locale("ja"), "JPY", 1234.4), "¥1,234");
+    }
+
+    #[test]
+    fn formats_relative_times_per_locale() {
+        assert_eq!(format_relative_with(&locale("en-US"), 30), "now");
+        assert_eq!(format_relative_with(&locale("en-US"), 60), "1 minute ago");
+        assert_eq!(
+            format_relative_with(&locale("en-GB"), 3 * 60 * 60),
+            "3 hours ago"
+        );
+        assert_eq!(
+            format_relative_with(&locale("en"), -2 * 24 * 60 * 60),
+            "in 2 days"
+        );
+        assert_eq!(
+            format_relative_with(&locale("de-DE"), 3 * 24 * 60 * 60),
+            "vor 3 Tagen"
+        );
+        assert_eq!(
+            format_relative_with(&locale("fr"), 14 * 24 * 60 * 60),
+            "il y a 2 semaines"
+        );
+        assert_eq!(
+            format_relative_with(&locale("es"), -60 * 24 * 60 * 60),
+            "dentro de 2 meses"
+        );
+        assert_eq!(
+            format_relative_with(&locale("ja-JP"), 400 * 24 * 60 * 60),
+            "1年前"
+        );
+    }
+
+    #[test]
+    fn current_locale_prefers_installed_signal() {
+        let mut env = Environment::new();
+        assert_eq!(current_locale(&env).get().0, "en-US");
+        env.insert(locale("de-DE"));
+        assert_eq!(current_locale(&env).get().0, "de-DE");
+        let selected = nami::binding(locale("fr-FR"));
+        install_locale(&mut env, selected.clone());
+        assert_eq!(current_locale(&env).get().0, "fr-FR");
+        selected.set(locale("es"));
+        assert_eq!(current_locale(&env).get().0, "es");
+    }
+}
@@ -1,17 +1,57 @@
-use core::{fmt::Display, mem::take, ops::Add};
+use core::{
+    cell::RefCell,
+    fmt::{Debug, Display},
+    mem::take,
+    ops::Add,
+};
 
 use crate::{
     font::{Font, FontWeight},
     text,
 };
-use alloc::{string::String, vec, vec::Vec};
+use alloc::{boxed::Box, rc::Rc, string::String, vec, vec::Vec};
 use core::ops::AddAssign;
 use nami::impl_constant;
 use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag};
 use waterui_color::Color;
-use waterui_core::{Str, View};
+use waterui_core::{
+    Environment, Str, View,
+    handler::{BoxHandler, Handler, HandlerFn, into_handler},
+};
+
+/// A tap handler attached to a run of styled text.
+///
+/// Backends turn chunks carrying a `TapAction` into tappable ranges, which is
+/// how inline links and mentions are built. Cloning shares the same handler.
+#[derive(Clone)]
+pub struct TapAction(Rc<RefCell<BoxHandler<()>>>);
+
+impl TapAction {
+    /// Creates a tap action from a handler function.
+    pub fn new<P: 'static>(action: impl HandlerFn<P, ()> + 'static) -> Self {
+        Self(Rc::new(RefCell::new(Box::new(into_handler(action)))))
+    }
+
+    /// Invokes the action with the given environment.
+    pub fn call(&self, env: &Environment) {
+        self.0.borrow_mut().handle(env);
+    }
+}
+
+impl Handler<()> for TapAction {
+    fn handle(&mut self, env: &Environment) {
+        self.call(env);
+    }
+}
+
+impl Debug for TapAction {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("TapAction(..)")
+    }
+}
 
 /// A set of text attributes for rich text formatting.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, Default)]
 pub struct Style {
     /// The font to use.
@@ -26,6 +66,12 @@ pub struct Style {
     pub underline: bool,
     /// Whether the text has a strikethrough.
     pub strikethrough: bool,
+    /// The underline color. Falls back to the foreground color when `None`.
+    pub underline_color: Option<Color>,
+    /// Whether the background is drawn as a rounded capsule (e.g. mention chips).
+    pub pill: bool,
+    /// The action invoked when this run of text is tapped.
+    pub on_tap: Option<TapAction>,
 }
 
 impl Style {
@@ -119,6 +165,44 @@ impl Style {
         self.strikethrough = false;
         self
     }
+
+    /// Sets the underline color.
+    #[must_use]
+    pub fn underline_color(mut self, color: impl Into<Color>) -> Self {
+        self.underline_color = Some(color.into());
+        self
+    }
+
+    /// Draws the background as a rounded capsule.
+    #[must_use]
+    pub const fn pill(mut self) -> Self {
+        self.pill = true;
+        self
+    }
+
+    /// Sets the action invoked when the text is tapped.
+    #[must_use]
+    pub fn on_tap<P: 'static>(mut self, action: impl HandlerFn<P, ()> + 'static) -> Self {
+        self.on_tap = Some(TapAction::new(action));
+        self
+    }
+}
+
+/// Creates a single styled span, ready to be decorated and concatenated.
+///
+/// # Example
+///
+/// ```ignore
+/// let message = span("Ping ")
+///     + span("@alice")
+///         .foreground(Blue)
+///         .background_color(Blue.with_opacity(0.15))
+///         .pill()
+///         .on_tap(|| open_profile("alice"));
+/// ```
+#[must_use]
+pub fn span(text: impl Into<Str>) -> StyledStr {
+    StyledStr::plain(text)
 }
 
 /// A string with associated text attributes for rich text formatting.
@@ -364,6 +448,32 @@ impl StyledStr {
     pub fn strikethrough(self, strikethrough: bool) -> Self {
         self.apply_style(|s| s.strikethrough = strikethrough)
     }
+
+    /// Sets the underline color for all chunks.
+    #[must_use]
+    pub fn underline_color(self, color: impl Into<Color>) -> Self {
+        let color = color.into();
+        self.apply_style(|s| s.underline_color = Some(color.clone()))
+    }
+
+    /// Draws the background of all chunks as a rounded capsule.
+    #[must_use]
+    pub fn pill(self) -> Self {
+        self.apply_style(|s| s.pill = true)
+    }
+
+    /// Makes all chunks tappable, invoking `action` when any of them is tapped.
+    #[must_use]
+    pub fn on_tap<P: 'static>(self, action: impl HandlerFn<P, ()> + 'static) -> Self {
+        let action = TapAction::new(action);
+        self.apply_style(|s| s.on_tap = Some(action.clone()))
+    }
+
+    /// Returns `true` if any chunk carries a tap action.
+    #[must_use]
+    pub fn is_tappable(&self) -> bool {
+        self.chunks.iter().any(|(_, style)| style.on_tap.is_some())
+    }
 }
 
 /// Utility builder that incrementally constructs a [`StyledStr`] from Markdown
@@ -588,6 +698,33 @@ mod tests {
         assert_eq!(chunks[2].0.as_str(), "!");
     }
 
+    #[test]
+    fn span_tap_action_is_shared_across_chunks() {
+        use core::cell::Cell;
+
+        let taps = Rc::new(Cell::new(0));
+        let styled = span("Hi ")
+            + span("@alice")
+                .underline_color(Color::srgb(0, 0, 255))
+                .pill()
+                .on_tap({
+                    let taps = taps.clone();
+                    move || taps.set(taps.get() + 1)
+                });
+        assert!(styled.is_tappable());
+
+        let chunks = styled.into_chunks();
+        assert!(chunks[0].1.on_tap.is_none());
+        assert!(chunks[1].1.pill);
+        assert!(chunks[1].1.underline_color.is_some());
+
+        let env = Environment::new();
+        let action = chunks[1].1.on_tap.clone().unwrap();
+        action.call(&env);
+        chunks[1].1.on_tap.as_ref().unwrap().call(&env);
+        assert_eq!(taps.get(), 2);
+    }
+
     #[test]
     fn parses_heading_markdown() {
         let styled = StyledStr::from_markdown("# Title");
@@ -3,9 +3,10 @@ use crate::locale::Formatter;
 use crate::{font::Font, styled::StyledStr};
 use alloc::string::ToString;
 use core::fmt::Display;
+use core::num::NonZeroUsize;
 use nami::impl_constant;
 use nami::signal::IntoSignal;
-use nami::{Computed, Signal, SignalExt, signal::IntoComputed};
+use nami::{Binding, Computed, Signal, SignalExt, signal::IntoComputed};
 use waterui_color::Color;
 use waterui_core::configurable;
 
@@ -36,6 +37,13 @@ configurable!(
     /// // Enable wrapping with fixed width
     /// text("Long paragraph...").frame().width(200.0)
     ///
+    /// // At most two lines, eliding the middle
+    /// text("Long paragraph...").line_limit(2).truncation_mode(TruncationMode::Middle)
+    ///
+    /// // Centered display type with tight tracking that shrinks to fit
+    /// text("Headline").title().tracking(-0.5).minimum_scale_factor(0.7)
+    ///     .multiline_alignment(TextAlignment::Center)
+    ///
     /// // Push text apart in a row
     /// hstack((text("Name"), spacer(), text("Value")))
     /// ```
@@ -67,6 +75,51 @@ configurable!(
 pub struct TextConfig {
     /// The rich text content to be displayed.
     pub content: Computed<StyledStr>,
+    /// The maximum number of lines, or `None` for no limit.
+    pub line_limit: Option<NonZeroUsize>,
+    /// Where the ellipsis goes when the content does not fit.
+    pub truncation: TruncationMode,
+    /// Set by the backend to whether the content is currently truncated.
+    pub truncated: Option<Binding<bool>>,
+    /// Extra space between lines, in points.
+    pub line_spacing: f32,
+    /// Extra space between paragraphs, in points.
+    pub paragraph_spacing: f32,
+    /// Extra space between characters, in points. Negative values tighten.
+    pub tracking: f32,
+    /// How far the font may shrink to fit, from 0.0 to 1.0. `1.0` disables shrinking.
+    pub minimum_scale_factor: f32,
+    /// How lines are aligned relative to each other in multi-line text.
+    pub alignment: TextAlignment,
+    /// Whether backends receive changes as [`TextUpdate`](crate::update::TextUpdate)s
+    /// instead of the whole content.
+    pub incremental: bool,
+}
+
+/// Horizontal alignment of the lines in multi-line text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextAlignment {
+    /// Align lines to the leading edge.
+    #[default]
+    Leading,
+    /// Center each line.
+    Center,
+    /// Align lines to the trailing edge.
+    Trailing,
+}
+
+/// Where text is elided when it does not fit in the available space.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TruncationMode {
+    /// Elide the start: "...end of text".
+    Head,
+    /// Elide the middle: "start...end".
+    Middle,
+    /// Elide the end: "start of text...".
+    #[default]
+    Tail,
 }
 
 impl Clone for Text {
@@ -98,6 +151,15 @@ impl Text {
     pub fn new(content: impl IntoComputed<StyledStr>) -> Self {
         Self(TextConfig {
             content: content.into_signal().map(StyledStr::from).computed(),
+            line_limit: None,
+            truncation: TruncationMode::Tail,
+            truncated: None,
+            line_spacing: 0.0,
+            paragraph_spacing: 0.0,
+            tracking: 0.0,
+            minimum_scale_factor: 1.0,
+            alignment: TextAlignment::Leading,
+            incremental: false,
         })
     }
 
@@ -230,6 +292,100 @@ impl Text {
     }
 }
 
+impl Text {
+    /// Limits the text to at most `line_limit` lines.
+    ///
+    /// Text that needs more lines is truncated according to the
+    /// [`truncation_mode`](Self::truncation_mode). By default, there is no limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `line_limit` is 0.
+    #[must_use]
+    pub fn line_limit(mut self, line_limit: usize) -> Self {
+        assert!(line_limit > 0, "Line limit must be greater than 0");
+        self.0.line_limit = NonZeroUsize::new(line_limit);
+        self
+    }
+
+    /// Disables the line limit.
+    #[must_use]
+    pub const fn disable_line_limit(mut self) -> Self {
+        self.0.line_limit = None;
+        self
+    }
+
+    /// Sets where the ellipsis goes when the text is truncated.
+    ///
+    /// Defaults to [`TruncationMode::Tail`].
+    #[must_use]
+    pub const fn truncation_mode(mut self, mode: TruncationMode) -> Self {
+        self.0.truncation = mode;
+        self
+    }
+
+    /// Adds `spacing` points between lines.
+    #[must_use]
+    pub const fn line_spacing(mut self, spacing: f32) -> Self {
+        self.0.line_spacing = spacing;
+        self
+    }
+
+    /// Adds `spacing` points between paragraphs.
+    #[must_use]
+    pub const fn paragraph_spacing(mut self, spacing: f32) -> Self {
+        self.0.paragraph_spacing = spacing;
+        self
+    }
+
+    /// Adjusts the space between characters by `tracking` points.
+    ///
+    /// Positive values spread the text out, which suits small caps and labels;
+    /// negative values tighten large display type.
+    #[must_use]
+    pub const fn tracking(mut self, tracking: f32) -> Self {
+        self.0.tracking = tracking;
+        self
+    }
+
+    /// Lets the font shrink down to `factor` of its size before truncating.
+    ///
+    /// The factor is clamped to `0.0..=1.0`; `1.0` (the default) disables shrinking.
+    #[must_use]
+    pub const fn minimum_scale_factor(mut self, factor: f32) -> Self {
+        self.0.minimum_scale_factor = factor.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets how lines are aligned when the text wraps.
+    #[must_use]
+    pub const fn multiline_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.0.alignment = alignment;
+        self
+    }
+
+    /// Reports whether the text is truncated into `truncated`.
+    ///
+    /// The backend updates the binding whenever layout changes whether the
+    /// full content fits, which is how "read more" affordances know when to appear.
+    #[must_use]
+    pub fn report_truncation(mut self, truncated: &Binding<bool>) -> Self {
+        self.0.truncated = Some(truncated.clone());
+        self
+    }
+
+    /// Sends backends only what changed each time the content changes.
+    ///
+    /// Use it for text that changes many times a second or keeps growing,
+    /// like timers and logs. Only the plain text is diffed, so the whole
+    /// text keeps the style of its initial content.
+    #[must_use]
+    pub const fn incremental(mut self) -> Self {
+        self.0.incremental = true;
+        self
+    }
+}
+
 macro_rules! impl_text_font {
     ($(($name:ident, $value:expr)),+) => {
         $(
@@ -273,3 +429,44 @@ where
 }
 
 impl_constant!(Text, TextConfig);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use waterui_core::view::ConfigurableView;
+
+    #[test]
+    fn line_limit_is_unset_by_default() {
+        let config = text("Hello").config();
+        assert_eq!(config.line_limit, None);
+        assert_eq!(config.truncation, TruncationMode::Tail);
+        assert!(config.truncated.is_none());
+    }
+
+    #[test]
+    fn line_limit_can_be_set_and_disabled() {
+        let limited = text("Hello").line_limit(2);
+        assert_eq!(limited.clone().config().line_limit, NonZeroUsize::new(2));
+        assert_eq!(limited.disable_line_limit().config().line_limit, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Line limit must be greater than 0")]
+    fn zero_line_limit_panics() {
+        let _ = text("Hello").line_limit(0);
+    }
+
+    #[test]
+    fn truncation_settings_reach_the_config() {
+        let truncated = Binding::bool(false);
+        let config = text("Hello")
+            .line_limit(1)
+            .truncation_mode(TruncationMode::Middle)
+            .report_truncation(&truncated)
+            .config();
+        assert_eq!(config.truncation, TruncationMode::Middle);
+
+        config.truncated.unwrap().set(true);
+        assert!(truncated.get());
+    }
+}
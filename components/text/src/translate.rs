@@ -0,0 +1,300 @@
+//! Translation tables for localized UI strings.
+//!
+//! Strings are looked up by key in a table for the current locale, which is
+//! read from [`current_locale`]. Tables are flat TOML files, one per
+//! language, that `water i18n extract` keeps in sync with the `t!` calls in
+//! the project:
+//!
+//! ```toml
+//! # locales/de.toml
+//! "Hello, {name}!" = "Hallo, {name}!"
+//! "Settings" = "Einstellungen"
+//! ```
+//!
+//! A key with no table entry, or with an empty one, is shown as the key
+//! itself, so the source language needs no table.
+
+use alloc::{collections::BTreeMap, rc::Rc, string::String, vec::Vec};
+use core::fmt::{self, Display};
+
+use nami::{Computed, Signal, SignalExt};
+use waterui_core::{Environment, Str, View};
+
+use crate::{Text, locale::Locale, locale::current_locale};
+
+/// Translation tables for every supported language.
+///
+/// Install them with [`install_translations`]. Cloning is cheap; clones
+/// share the tables.
+#[derive(Debug, Clone, Default)]
+pub struct Translations(Rc<BTreeMap<Str, BTreeMap<Str, Str>>>);
+
+impl Translations {
+    /// Creates an empty set of tables.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a translation of `key` for `locale`.
+    ///
+    /// `locale` is either a language (`de`) or a full tag (`de-AT`).
+    #[must_use]
+    pub fn with(
+        mut self,
+        locale: impl Into<Str>,
+        key: impl Into<Str>,
+        value: impl Into<Str>,
+    ) -> Self {
+        Rc::make_mut(&mut self.0)
+            .entry(locale.into())
+            .or_default()
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Adds the entries of a TOML table for `locale`.
+    ///
+    /// Only the flat `"key" = "value"` form written by `water i18n extract`
+    /// is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first line that is not a comment, a blank line or a
+    /// string entry.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let translations = Translations::new()
+    ///     .with_table("de", include_str!("../locales/de.toml"))?
+    ///     .with_table("fr", include_str!("../locales/fr.toml"))?;
+    /// ```
+    pub fn with_table(mut self, locale: impl Into<Str>, source: &str) -> Result<Self, TableError> {
+        let table = Rc::make_mut(&mut self.0).entry(locale.into()).or_default();
+        for (index, line) in source.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = parse_entry(line).ok_or(TableError { line: index + 1 })?;
+            table.insert(key.into(), value.into());
+        }
+        Ok(self)
+    }
+
+    /// Returns the translation of `key` for `locale`.
+    ///
+    /// The full tag is tried first, then its language. Empty entries are
+    /// stubs that have not been translated yet and are skipped.
+    #[must_use]
+    pub fn get(&self, locale: &Locale, key: &str) -> Option<&str> {
+        [&*locale.0, locale.language()]
+            .into_iter()
+            .filter_map(|tag| self.0.get(tag)?.get(key))
+            .map(|value| &**value)
+            .find(|value| !value.is_empty())
+    }
+}
+
+/// A translation table line that could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableError {
+    /// The one-based line number.
+    pub line: usize,
+}
+
+impl Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid translation entry on line {}", self.line)
+    }
+}
+
+impl core::error::Error for TableError {}
+
+/// Parses `"key" = "value"` or `key = "value"`.
+fn parse_entry(line: &str) -> Option<(String, String)> {
+    let (key, rest) = if line.starts_with('"') {
+        parse_string(line)?
+    } else {
+        let end = line.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))?;
+        (String::from(&line[..end]), &line[end..])
+    };
+    let rest = rest.trim_start().strip_prefix('=')?.trim_start();
+    let (value, rest) = parse_string(rest)?;
+    let rest = rest.trim_start();
+    (rest.is_empty() || rest.starts_with('#')).then_some((key, value))
+}
+
+/// Parses a basic TOML string at the start of `input`, returning it and the rest.
+fn parse_string(input: &str) -> Option<(String, &str)> {
+    let mut chars = input.strip_prefix('"')?.char_indices();
+    let mut out = String::new();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => return Some((out, &input[index + 2..])),
+            '\\' => {
+                let escaped = match chars.next()?.1 {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    '"' => '"',
+                    '\\' => '\\',
+                    kind @ ('u' | 'U') => {
+                        let len = if kind == 'u' { 4 } else { 8 };
+                        let mut code = 0;
+                        for _ in 0..len {
+                            code = code * 16 + chars.next()?.1.to_digit(16)?;
+                        }
+                        char::from_u32(code)?
+                    }
+                    _ => return None,
+                };
+                out.push(escaped);
+            }
+            c => out.push(c),
+        }
+    }
+    None
+}
+
+/// Installs the translation tables used by [`translate`] and the `t!` macro.
+pub fn install_translations(env: &mut Environment, translations: Translations) {
+    env.insert(translations);
+}
+
+/// Text looked up by key in the translation table of the current locale.
+///
+/// Created by [`translate`] or the `t!` macro.
+#[derive(Debug)]
+pub struct TranslatedText {
+    key: &'static str,
+    args: Computed<Vec<(&'static str, Str)>>,
+}
+
+/// Creates text showing the translation of `key` for the current locale.
+///
+/// Prefer the `t!` macro, which `water i18n extract` can find in the source.
+#[must_use]
+pub fn translate(key: &'static str) -> TranslatedText {
+    TranslatedText {
+        key,
+        args: Computed::constant(Vec::new()),
+    }
+}
+
+impl TranslatedText {
+    /// Fills the `{name}` placeholder with a reactive value.
+    #[must_use]
+    pub fn arg<T: Display + Clone + 'static>(
+        self,
+        name: &'static str,
+        value: impl Signal<Output = T> + 'static,
+    ) -> Self {
+        let args = self
+            .args
+            .zip(value)
+            .map(move |(mut args, value)| {
+                args.push((name, alloc::format!("{value}").into()));
+                args
+            })
+            .computed();
+        Self {
+            key: self.key,
+            args,
+        }
+    }
+}
+
+impl View for TranslatedText {
+    fn body(self, env: &Environment) -> impl View {
+        let translations = env.get::<Translations>().cloned().unwrap_or_default();
+        let key = self.key;
+        Text::new(
+            self.args
+                .zip(current_locale(env))
+                .map(move |(args, locale)| {
+                    let template = translations.get(&locale, key).unwrap_or(key);
+                    Str::from(substitute(template, &args))
+                }),
+        )
+    }
+}
+
+/// Replaces `{name}` placeholders with their values; `{{` and `}}` are literal braces.
+fn substitute(template: &str, args: &[(&str, Str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        let value = tail.strip_prefix('{').and_then(|inner| {
+            let end = inner.find('}')?;
+            let (_, value) = args.iter().find(|(name, _)| *name == &inner[..end])?;
+            Some((value, end + 2))
+        });
+        if let Some((value, len)) = value {
+            out.push_str(value);
+            rest = &tail[len..];
+        } else {
+            out.push_str(&tail[..1]);
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locale(tag: &'static str) -> Locale {
+        Locale(tag.into())
+    }
+
+    #[test]
+    fn parses_tables_and_falls_back_to_language() {
+        let translations = Translations::new()
+            .with_table(
+                "de",
+                "# German\n\"Hello, {name}!\" = \"Hallo, {name}!\"\nSettings = \"Einstellungen\" # menu\n\"Quit\" = \"\"\n",
+            )
+            .unwrap()
+            .with("de-AT", "Settings", "Einstöllungen");
+
+        assert_eq!(
+            translations.get(&locale("de-DE"), "Hello, {name}!"),
+            Some("Hallo, {name}!")
+        );
+        assert_eq!(
+            translations.get(&locale("de-AT"), "Settings"),
+            Some("Einstöllungen")
+        );
+        assert_eq!(translations.get(&locale("de"), "Quit"), None);
+        assert_eq!(translations.get(&locale("fr"), "Settings"), None);
+    }
+
+    #[test]
+    fn reports_invalid_lines() {
+        let error = Translations::new()
+            .with_table("de", "\"ok\" = \"gut\"\nbroken\n")
+            .unwrap_err();
+        assert_eq!(error, TableError { line: 2 });
+        assert!(parse_entry(r#""a\"b" = "é\n""#).is_some_and(|(k, v)| k == "a\"b" && v == "é\n"));
+    }
+
+    #[test]
+    fn substitutes_named_arguments() {
+        let args = [("name", Str::from("Ada")), ("count", Str::from("3"))];
+        assert_eq!(
+            substitute("{name} has {count} {{items}} {missing}", &args),
+            "Ada has 3 {items} {missing}"
+        );
+    }
+}
@@ -0,0 +1,162 @@
+//! Incremental updates for text that changes often.
+//!
+//! A text view normally hands backends its whole content on every change,
+//! which for a ticking timer or a growing log means a new string and a full
+//! re-layout many times a second. [`Text::incremental`](crate::Text::incremental)
+//! makes backends receive a [`TextUpdate`] describing only what changed
+//! instead, which they apply to the text they already show.
+//!
+//! Ranges are counted in UTF-16 code units, the unit native text storage on
+//! Apple platforms, Android and the web is indexed by.
+
+use alloc::string::String;
+
+use waterui_core::Str;
+
+/// A change to the plain text of a text view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextUpdate {
+    /// Replaces the whole text.
+    Replace(Str),
+    /// Adds text at the end.
+    Append(Str),
+    /// Replaces the UTF-16 code units in `start..end` with `text`.
+    ReplaceRange {
+        /// The first replaced code unit.
+        start: usize,
+        /// The code unit after the last replaced one.
+        end: usize,
+        /// The replacement, which may be empty to delete the range.
+        text: Str,
+    },
+}
+
+impl TextUpdate {
+    /// Returns the smallest update turning `old` into `new`, or `None` if they
+    /// are equal.
+    ///
+    /// Text that shares neither a prefix nor a suffix with `old` is replaced
+    /// as a whole.
+    #[must_use]
+    pub fn diff(old: &str, new: &str) -> Option<Self> {
+        if old == new {
+            return None;
+        }
+        let prefix = common_prefix(old, new);
+        if prefix == old.len() {
+            return Some(Self::Append(Str::from(String::from(&new[prefix..]))));
+        }
+        let suffix = common_suffix(&old[prefix..], &new[prefix..]);
+        if prefix == 0 && suffix == 0 {
+            return Some(Self::Replace(Str::from(String::from(new))));
+        }
+        let start = utf16_len(&old[..prefix]);
+        Some(Self::ReplaceRange {
+            start,
+            end: start + utf16_len(&old[prefix..old.len() - suffix]),
+            text: Str::from(String::from(&new[prefix..new.len() - suffix])),
+        })
+    }
+
+    /// Applies the update to `text`.
+    ///
+    /// Ranges past the end of `text` are clamped.
+    pub fn apply(&self, text: &mut String) {
+        match self {
+            Self::Replace(new) => {
+                text.clear();
+                text.push_str(new);
+            }
+            Self::Append(tail) => text.push_str(tail),
+            Self::ReplaceRange {
+                start,
+                end,
+                text: replacement,
+            } => {
+                let start = byte_offset(text, *start);
+                let end = byte_offset(text, *end).max(start);
+                text.replace_range(start..end, replacement);
+            }
+        }
+    }
+}
+
+/// Returns the length in bytes of the longest common prefix, on a character
+/// boundary.
+fn common_prefix(a: &str, b: &str) -> usize {
+    a.char_indices()
+        .zip(b.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or_else(|| a.len().min(b.len()), |((index, _), _)| index)
+}
+
+/// Returns the length in bytes of the longest common suffix, on a character
+/// boundary.
+fn common_suffix(a: &str, b: &str) -> usize {
+    a.chars()
+        .rev()
+        .zip(b.chars().rev())
+        .take_while(|(a, b)| a == b)
+        .map(|(c, _)| c.len_utf8())
+        .sum()
+}
+
+fn utf16_len(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+/// Converts an offset in UTF-16 code units into a byte offset in `text`.
+fn byte_offset(text: &str, utf16_offset: usize) -> usize {
+    let mut units = 0;
+    for (index, c) in text.char_indices() {
+        if units >= utf16_offset {
+            return index;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(old: &str, new: &str) -> TextUpdate {
+        let update = TextUpdate::diff(old, new).unwrap();
+        let mut text = String::from(old);
+        update.apply(&mut text);
+        assert_eq!(text, new);
+        update
+    }
+
+    #[test]
+    fn diffs_describe_only_the_change() {
+        assert_eq!(TextUpdate::diff("log", "log"), None);
+        assert_eq!(
+            round_trip("line 1\n", "line 1\nline 2\n"),
+            TextUpdate::Append("line 2\n".into())
+        );
+        assert_eq!(
+            round_trip("00:09", "00:10"),
+            TextUpdate::ReplaceRange {
+                start: 3,
+                end: 5,
+                text: "10".into()
+            }
+        );
+        assert_eq!(round_trip("old", "new"), TextUpdate::Replace("new".into()));
+        // Ranges count UTF-16 code units, so the emoji is two
+        assert_eq!(
+            round_trip("🌊 water", "🌊 waterui"),
+            TextUpdate::Append("ui".into())
+        );
+        assert_eq!(
+            round_trip("🌊 wave 🌊", "🌊 tide 🌊"),
+            TextUpdate::ReplaceRange {
+                start: 3,
+                end: 6,
+                text: "tid".into()
+            }
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Easing curves.
+
+use core::f64::consts::PI;
+
+/// An easing curve mapping elapsed time to animation progress.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Curve {
+    /// Constant speed from start to finish.
+    Linear,
+    /// Starts slow and accelerates.
+    EaseIn,
+    /// Starts fast and decelerates.
+    EaseOut,
+    /// Starts and ends slowly.
+    EaseInOut,
+    /// Overshoots the target and settles like a spring.
+    Spring {
+        /// How quickly oscillation dies out, from 0.0 (bouncy) to 1.0 (no overshoot).
+        damping: f64,
+    },
+}
+
+impl Curve {
+    /// A spring with a little overshoot.
+    #[must_use]
+    pub const fn spring() -> Self {
+        Self::Spring { damping: 0.5 }
+    }
+
+    /// Returns the progress at `time`, where both run from 0.0 to 1.0.
+    ///
+    /// Every curve starts at 0.0 and ends exactly at 1.0.
+    #[must_use]
+    pub fn progress(self, time: f64) -> f64 {
+        let t = time.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::EaseIn => t * t * t,
+            Self::EaseOut => 1.0 - (1.0 - t).powi(3),
+            Self::EaseInOut => (1.0 - (PI * t).cos()) / 2.0,
+            Self::Spring { .. } if t >= 1.0 => 1.0,
+            Self::Spring { damping } => spring(damping, t),
+        }
+    }
+}
+
+/// A damped spring whose oscillation has decayed to 0.1% by `t = 1.0`.
+fn spring(damping: f64, t: f64) -> f64 {
+    const SETTLED: f64 = 6.9; // -ln(0.001)
+
+    let damping = damping.clamp(0.05, 1.0);
+    let frequency = SETTLED / damping;
+    let decay = (-damping * frequency * t).exp();
+    if damping >= 1.0 {
+        // Critically damped
+        return 1.0 - decay * frequency.mul_add(t, 1.0);
+    }
+    let damped = frequency * damping.mul_add(-damping, 1.0).sqrt();
+    let phase = damped * t;
+    1.0 - decay * (damping * frequency / damped).mul_add(phase.sin(), phase.cos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CURVES: [Curve; 6] = [
+        Curve::Linear,
+        Curve::EaseIn,
+        Curve::EaseOut,
+        Curve::EaseInOut,
+        Curve::Spring { damping: 0.3 },
+        Curve::Spring { damping: 1.0 },
+    ];
+
+    #[test]
+    fn curves_start_at_zero_and_end_at_one() {
+        for curve in CURVES {
+            assert!(curve.progress(0.0).abs() < 1e-9, "{curve:?}");
+            assert!((curve.progress(1.0) - 1.0).abs() < 1e-9, "{curve:?}");
+            assert!((curve.progress(0.999) - 1.0).abs() < 0.01, "{curve:?}");
+        }
+    }
+
+    #[test]
+    fn only_underdamped_springs_overshoot() {
+        let peak = |curve: Curve| {
+            (0..=100)
+                .map(|step| curve.progress(f64::from(step) / 100.0))
+                .fold(0.0, f64::max)
+        };
+        assert!(peak(Curve::spring()) > 1.0);
+        assert!(peak(Curve::Spring { damping: 1.0 }) <= 1.0);
+        assert!(peak(Curve::EaseInOut) <= 1.0);
+    }
+}
@@ -0,0 +1,147 @@
+//! Multi-stop animations.
+
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use super::{Curve, Lerp};
+
+/// A single stop of [`Keyframes`].
+#[derive(Debug, Clone, PartialEq)]
+struct Keyframe<T> {
+    at: Duration,
+    value: T,
+    curve: Curve,
+}
+
+/// A value over time, described by timed keyframes.
+///
+/// Each keyframe is reached at its time from the previous one (or from the
+/// starting value for the first) along its own [`Curve`]. Keyframes can be
+/// added in any order.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use waterui_core::animation::{Curve, Keyframes};
+///
+/// let ms = Duration::from_millis;
+/// // Pop: grow past the target, then settle back
+/// let scale = Keyframes::new()
+///     .keyframe(ms(150), 1.2, Curve::EaseOut)
+///     .keyframe(ms(400), 1.0, Curve::spring());
+///
+/// assert_eq!(scale.duration(), ms(400));
+/// assert_eq!(scale.value_at(&1.0, ms(150)), 1.2);
+/// assert_eq!(scale.value_at(&1.0, ms(500)), 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes<T> {
+    frames: Vec<Keyframe<T>>,
+}
+
+impl<T> Default for Keyframes<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Keyframes<T> {
+    /// Creates an empty set of keyframes.
+    ///
+    /// Without a keyframe at [`Duration::ZERO`], playback starts from the
+    /// current value of the animated binding.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { frames: Vec::new() }
+    }
+
+    /// Adds a keyframe reaching `value` at `at` along `curve`.
+    ///
+    /// A keyframe at the same time as an existing one is placed after it,
+    /// which makes the value jump.
+    #[must_use]
+    pub fn keyframe(mut self, at: Duration, value: T, curve: Curve) -> Self {
+        let index = self.frames.partition_point(|frame| frame.at <= at);
+        self.frames.insert(index, Keyframe { at, value, curve });
+        self
+    }
+
+    /// Returns the time of the last keyframe.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.frames.last().map_or(Duration::ZERO, |frame| frame.at)
+    }
+
+    /// Returns `true` if no keyframes were added.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl<T: Lerp> Keyframes<T> {
+    /// Returns the value at `elapsed`, starting from `from`.
+    ///
+    /// After the last keyframe the value stays at that keyframe.
+    #[must_use]
+    pub fn value_at(&self, from: &T, elapsed: Duration) -> T {
+        let index = self.frames.partition_point(|frame| frame.at <= elapsed);
+        let Some(next) = self.frames.get(index) else {
+            return self
+                .frames
+                .last()
+                .map_or_else(|| from.clone(), |frame| frame.value.clone());
+        };
+        let (start, value) = index
+            .checked_sub(1)
+            .map_or((Duration::ZERO, from), |previous| {
+                let previous = &self.frames[previous];
+                (previous.at, &previous.value)
+            });
+        // `next.at > elapsed >= start`, so the segment is never empty
+        let time = elapsed.saturating_sub(start).as_secs_f64()
+            / next.at.saturating_sub(start).as_secs_f64();
+        value.lerp(&next.value, next.curve.progress(time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn segments_use_their_own_curves() {
+        let keyframes = Keyframes::new()
+            .keyframe(ms(200), 10.0_f64, Curve::EaseIn)
+            .keyframe(ms(100), 4.0, Curve::Linear);
+
+        assert!(keyframes.value_at(&0.0, ms(0)).abs() < 1e-9);
+        assert!((keyframes.value_at(&0.0, ms(50)) - 2.0).abs() < 1e-9);
+        assert!((keyframes.value_at(&0.0, ms(100)) - 4.0).abs() < 1e-9);
+        // Ease-in reaches 1/8 of the way at half time
+        assert!((keyframes.value_at(&0.0, ms(150)) - 4.75).abs() < 1e-9);
+        assert!((keyframes.value_at(&0.0, ms(900)) - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn keyframe_at_zero_overrides_the_start() {
+        let keyframes = Keyframes::new()
+            .keyframe(Duration::ZERO, 1.0_f32, Curve::Linear)
+            .keyframe(ms(100), 0.0, Curve::Linear);
+
+        assert!((keyframes.value_at(&5.0, ms(0)) - 1.0).abs() < 1e-6);
+        assert!((keyframes.value_at(&5.0, ms(50)) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn empty_keyframes_keep_the_start() {
+        let keyframes = Keyframes::<f64>::new();
+        assert_eq!(keyframes.duration(), Duration::ZERO);
+        assert!((keyframes.value_at(&3.0, ms(10)) - 3.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,52 @@
+//! Linear interpolation between values.
+
+use crate::layout::Point;
+
+/// A value that can be blended between two endpoints.
+///
+/// Implemented for numbers and geometry here, and for colors and edge insets
+/// in their own crates.
+pub trait Lerp: Clone + 'static {
+    /// Returns the value at `t` between `self` (0.0) and `to` (1.0).
+    ///
+    /// `t` may leave the `0.0..=1.0` range for curves that overshoot, such as
+    /// a spring.
+    #[must_use]
+    fn lerp(&self, to: &Self, t: f64) -> Self;
+}
+
+// `mul_add` is not available without `std`
+#[allow(clippy::suboptimal_flops)]
+impl Lerp for f64 {
+    fn lerp(&self, to: &Self, t: f64) -> Self {
+        self + (to - self) * t
+    }
+}
+
+#[allow(clippy::suboptimal_flops, clippy::cast_possible_truncation)]
+impl Lerp for f32 {
+    fn lerp(&self, to: &Self, t: f64) -> Self {
+        self + (to - self) * t as Self
+    }
+}
+
+impl Lerp for Point {
+    fn lerp(&self, to: &Self, t: f64) -> Self {
+        Self::new(self.x.lerp(&to.x, t), self.y.lerp(&to.y, t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lerps_numbers_and_points() {
+        assert!((2.0_f64.lerp(&4.0, 0.25) - 2.5).abs() < f64::EPSILON);
+        assert!((1.0_f32.lerp(&0.0, 1.2) + 0.2).abs() < 1e-6);
+        assert_eq!(
+            Point::new(0.0, 10.0).lerp(&Point::new(10.0, 20.0), 0.5),
+            Point::new(5.0, 15.0)
+        );
+    }
+}
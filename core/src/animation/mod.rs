@@ -134,6 +134,33 @@
 //! drop((opacity, combined)); // Prevent unused variable warnings
 //! ```
 //!
+//! ### Keyframes
+//!
+//! For motion with several stops, `Keyframes` (with the `std` feature)
+//! describes the value over time, with its own `Curve` for every segment.
+//! Values are blended through the [`Lerp`] trait.
+//!
+//! ### Springs
+//!
+//! `Spring` (with the `std` feature) simulates a damped spring from a
+//! `SpringConfig` of mass, stiffness and damping. Retargeting a running spring
+//! keeps its velocity, so interrupted motion stays smooth.
+
+#[cfg(feature = "std")]
+mod curve;
+#[cfg(feature = "std")]
+mod keyframes;
+mod lerp;
+#[cfg(feature = "std")]
+mod spring;
+
+#[cfg(feature = "std")]
+pub use curve::Curve;
+#[cfg(feature = "std")]
+pub use keyframes::Keyframes;
+pub use lerp::Lerp;
+#[cfg(feature = "std")]
+pub use spring::{Spring, SpringConfig};
 
 use core::time::Duration;
 
@@ -259,6 +286,30 @@ impl Animation {
     }
 }
 
+/// A transition applied when a view's content is replaced.
+///
+/// Transitions travel as watcher metadata alongside the new content, so the
+/// renderer can animate between the old and new view instead of swapping them
+/// instantly. See [`Dynamic::transition`](crate::Dynamic::transition).
+///
+/// # Examples
+///
+/// ```
+/// use waterui_core::animation::{CrossFade, Transition};
+/// use core::time::Duration;
+///
+/// let transition = CrossFade(Duration::from_millis(200));
+/// assert_eq!(transition, Transition::CrossFade(Duration::from_millis(200)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Transition {
+    /// Fades the old content out while the new content fades in.
+    CrossFade(Duration),
+}
+
+pub use Transition::CrossFade;
+
 use nami::signal::WithMetadata;
 
 /// Extension trait providing animation methods for reactive values
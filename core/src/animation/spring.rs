@@ -0,0 +1,218 @@
+//! Damped spring physics.
+
+use core::time::Duration;
+
+use super::Animation;
+
+/// Physical parameters of a spring.
+///
+/// The defaults settle in about half a second without visible overshoot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpringConfig {
+    /// Mass of the moving object; heavier springs are slower.
+    pub mass: f64,
+    /// Stiffness of the spring; stiffer springs are faster.
+    pub stiffness: f64,
+    /// Damping force per unit of velocity; more damping means less bounce.
+    pub damping: f64,
+}
+
+impl Default for SpringConfig {
+    fn default() -> Self {
+        Self::new(1.0, 170.0, 26.0)
+    }
+}
+
+impl SpringConfig {
+    /// Creates a spring configuration.
+    #[must_use]
+    pub const fn new(mass: f64, stiffness: f64, damping: f64) -> Self {
+        Self {
+            mass,
+            stiffness,
+            damping,
+        }
+    }
+
+    /// A soft spring that overshoots noticeably.
+    #[must_use]
+    pub const fn bouncy() -> Self {
+        Self::new(1.0, 180.0, 12.0)
+    }
+
+    /// A fast spring without overshoot.
+    #[must_use]
+    pub const fn stiff() -> Self {
+        Self::new(1.0, 400.0, 40.0)
+    }
+}
+
+impl From<SpringConfig> for Animation {
+    /// Renderers use a unit mass, so stiffness and damping are scaled by it.
+    #[allow(clippy::cast_possible_truncation)]
+    fn from(config: SpringConfig) -> Self {
+        Self::spring(
+            (config.stiffness / config.mass) as f32,
+            (config.damping / config.mass) as f32,
+        )
+    }
+}
+
+/// A damped spring moving a position towards a target.
+///
+/// The spring keeps its velocity when [retargeted](Self::retarget), so an
+/// animation interrupted mid-flight bends smoothly towards the new target.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use waterui_core::animation::{Spring, SpringConfig};
+///
+/// let mut spring = Spring::new(0.0, 1.0, SpringConfig::default());
+/// while !spring.is_settled() {
+///     spring.step(Duration::from_millis(16));
+/// }
+/// assert_eq!(spring.position(), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Spring {
+    config: SpringConfig,
+    position: f64,
+    velocity: f64,
+    target: f64,
+}
+
+impl Spring {
+    /// The largest integration step, keeping stiff springs stable.
+    const MAX_STEP: Duration = Duration::from_millis(1);
+    /// Distance and speed below which the spring counts as settled.
+    const REST: f64 = 0.001;
+
+    /// Creates a spring at rest at `position`, pulled towards `target`.
+    #[must_use]
+    pub const fn new(position: f64, target: f64, config: SpringConfig) -> Self {
+        Self {
+            config,
+            position,
+            velocity: 0.0,
+            target,
+        }
+    }
+
+    /// Returns the current position.
+    #[must_use]
+    pub const fn position(&self) -> f64 {
+        self.position
+    }
+
+    /// Returns the current velocity, in units per second.
+    #[must_use]
+    pub const fn velocity(&self) -> f64 {
+        self.velocity
+    }
+
+    /// Returns the target position.
+    #[must_use]
+    pub const fn target(&self) -> f64 {
+        self.target
+    }
+
+    /// Moves the target without changing position or velocity.
+    pub const fn retarget(&mut self, target: f64) {
+        self.target = target;
+    }
+
+    /// Replaces the physical parameters, keeping position and velocity.
+    pub const fn set_config(&mut self, config: SpringConfig) {
+        self.config = config;
+    }
+
+    /// Advances the simulation by `elapsed`.
+    ///
+    /// Once settled, the spring snaps exactly onto its target.
+    #[allow(clippy::suboptimal_flops)] // `mul_add` is not available without `std`
+    pub fn step(&mut self, elapsed: Duration) {
+        let SpringConfig {
+            mass,
+            stiffness,
+            damping,
+        } = self.config;
+        let steps = elapsed.as_nanos().div_ceil(Self::MAX_STEP.as_nanos());
+        #[allow(clippy::cast_precision_loss)]
+        let dt = elapsed.as_secs_f64() / steps as f64;
+        for _ in 0..steps {
+            let force = -stiffness * (self.position - self.target) - damping * self.velocity;
+            // Semi-implicit Euler: update velocity first, then move with it
+            self.velocity += force / mass * dt;
+            self.position += self.velocity * dt;
+        }
+        if self.is_settled() {
+            self.position = self.target;
+            self.velocity = 0.0;
+        }
+    }
+
+    /// Returns `true` once the spring is at rest on its target.
+    #[must_use]
+    pub fn is_settled(&self) -> bool {
+        (self.position - self.target).abs() < Self::REST && self.velocity.abs() < Self::REST
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FRAME: Duration = Duration::from_millis(16);
+
+    /// Runs `spring` until it settles, returning the peak position and the frames taken.
+    fn settle(spring: &mut Spring) -> (f64, usize) {
+        let mut peak = spring.position();
+        for frame in 1..1000 {
+            spring.step(FRAME);
+            peak = peak.max(spring.position());
+            if spring.is_settled() {
+                return (peak, frame);
+            }
+        }
+        panic!("spring did not settle: {spring:?}");
+    }
+
+    #[test]
+    fn settles_on_target() {
+        let mut spring = Spring::new(0.0, 100.0, SpringConfig::default());
+        let (_, frames) = settle(&mut spring);
+        assert!((spring.position() - 100.0).abs() < f64::EPSILON);
+        assert!(spring.velocity().abs() < f64::EPSILON);
+        assert!(frames < 120, "{frames}");
+    }
+
+    #[test]
+    fn damping_controls_overshoot() {
+        let (bouncy, _) = settle(&mut Spring::new(0.0, 1.0, SpringConfig::bouncy()));
+        let (stiff, _) = settle(&mut Spring::new(0.0, 1.0, SpringConfig::stiff()));
+        assert!(bouncy > 1.1, "{bouncy}");
+        assert!(stiff <= 1.0 + Spring::REST, "{stiff}");
+    }
+
+    #[test]
+    fn retarget_keeps_velocity() {
+        let mut spring = Spring::new(0.0, 1.0, SpringConfig::default());
+        for _ in 0..5 {
+            spring.step(FRAME);
+        }
+        let (position, velocity) = (spring.position(), spring.velocity());
+        assert!(velocity > 0.0);
+
+        spring.retarget(-1.0);
+        assert!((spring.position() - position).abs() < f64::EPSILON);
+        assert!((spring.velocity() - velocity).abs() < f64::EPSILON);
+        // Momentum carries it further before it turns around
+        spring.step(Duration::from_millis(1));
+        assert!(spring.position() > position);
+        settle(&mut spring);
+        assert!((spring.position() + 1.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,205 @@
+//! Runtime availability of components that need a newer operating system.
+//!
+//! A component backed by a native control that only exists from some OS
+//! version on declares that floor by implementing [`Available`]. Wrapping it
+//! in [`if_available`] shows a fallback instead when the app runs on an older
+//! version, rather than letting the backend fail to create the control.
+//!
+//! Backends report the running OS version with [`install_os_version`]. When
+//! no version was reported, every component is assumed to be available.
+
+use core::fmt;
+
+use crate::{
+    AnyView, Environment, View,
+    platform::{Platform, current_platform},
+};
+
+/// An operating system version.
+///
+/// On Android the major version is the API level, e.g. `33` for Android 13.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OsVersion {
+    /// The major version.
+    pub major: u32,
+    /// The minor version.
+    pub minor: u32,
+    /// The patch version.
+    pub patch: u32,
+}
+
+impl OsVersion {
+    /// Creates a version with a zero patch version.
+    #[must_use]
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch: 0,
+        }
+    }
+
+    /// Sets the patch version.
+    #[must_use]
+    pub const fn patch(mut self, patch: u32) -> Self {
+        self.patch = patch;
+        self
+    }
+}
+
+impl fmt::Display for OsVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if self.patch != 0 {
+            write!(f, ".{}", self.patch)?;
+        }
+        Ok(())
+    }
+}
+
+/// The minimum OS version a component needs on each platform.
+///
+/// Platforms without a floor are always supported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Availability {
+    ios: Option<OsVersion>,
+    macos: Option<OsVersion>,
+    android: Option<OsVersion>,
+}
+
+impl Availability {
+    /// Available on every version of every platform.
+    pub const ALL: Self = Self {
+        ios: None,
+        macos: None,
+        android: None,
+    };
+
+    /// Requires at least iOS `major.minor`.
+    #[must_use]
+    pub const fn ios(mut self, major: u32, minor: u32) -> Self {
+        self.ios = Some(OsVersion::new(major, minor));
+        self
+    }
+
+    /// Requires at least macOS `major.minor`.
+    #[must_use]
+    pub const fn macos(mut self, major: u32, minor: u32) -> Self {
+        self.macos = Some(OsVersion::new(major, minor));
+        self
+    }
+
+    /// Requires at least the Android API level `api_level`.
+    #[must_use]
+    pub const fn android(mut self, api_level: u32) -> Self {
+        self.android = Some(OsVersion::new(api_level, 0));
+        self
+    }
+
+    /// Returns the minimum version required on `platform`, if any.
+    #[must_use]
+    pub const fn floor(&self, platform: Platform) -> Option<OsVersion> {
+        match platform {
+            Platform::Ios => self.ios,
+            Platform::Macos => self.macos,
+            Platform::Android => self.android,
+            _ => None,
+        }
+    }
+
+    /// Returns true if the component can be shown on `version` of `platform`.
+    ///
+    /// An unknown version is assumed to be supported.
+    #[must_use]
+    pub fn is_met(&self, platform: Platform, version: Option<OsVersion>) -> bool {
+        match (self.floor(platform), version) {
+            (Some(floor), Some(version)) => version >= floor,
+            _ => true,
+        }
+    }
+
+    /// Returns true if the component can be shown where `env` is rendered.
+    #[must_use]
+    pub fn check(&self, env: &Environment) -> bool {
+        self.is_met(current_platform(env), current_os_version(env))
+    }
+}
+
+/// A component that needs a minimum OS version.
+///
+/// The floors must match the native controls the backends use for the
+/// component. `water build` warns when a project targets an older version;
+/// keep its table in `waterui-cli` in sync.
+pub trait Available {
+    /// The minimum OS version on each platform.
+    const AVAILABILITY: Availability;
+}
+
+/// Returns the OS version reported by the backend, if any.
+#[must_use]
+pub fn current_os_version(env: &Environment) -> Option<OsVersion> {
+    env.get::<OsVersion>().copied()
+}
+
+/// Installs the version of the OS the app is running on.
+pub fn install_os_version(env: &mut Environment, version: OsVersion) {
+    env.insert(version);
+}
+
+/// A view that shows a component only where it is available.
+///
+/// Created by [`if_available`].
+#[derive(Debug)]
+pub struct IfAvailable<V, F> {
+    content: V,
+    fallback: F,
+}
+
+/// Shows `content` if the running OS supports it, and `fallback` otherwise.
+///
+/// ```ignore
+/// if_available(
+///     Table::new(columns),
+///     text("Upgrade your OS to see this table"),
+/// )
+/// ```
+pub const fn if_available<V: Available + View, F: View>(
+    content: V,
+    fallback: F,
+) -> IfAvailable<V, F> {
+    IfAvailable { content, fallback }
+}
+
+impl<V: Available + View, F: View> View for IfAvailable<V, F> {
+    fn body(self, env: &Environment) -> impl View {
+        if V::AVAILABILITY.check(env) {
+            AnyView::new(self.content)
+        } else {
+            AnyView::new(self.fallback)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn compares_against_the_platform_floor() {
+        let availability = Availability::ALL.ios(16, 0).android(33);
+
+        assert!(!availability.is_met(Platform::Ios, Some(OsVersion::new(15, 4))));
+        assert!(availability.is_met(Platform::Ios, Some(OsVersion::new(16, 0).patch(1))));
+        assert!(!availability.is_met(Platform::Android, Some(OsVersion::new(31, 0))));
+        assert!(availability.is_met(Platform::Macos, Some(OsVersion::new(10, 15))));
+        assert!(availability.is_met(Platform::Ios, None));
+
+        let mut env = Environment::new();
+        crate::platform::install_platform(&mut env, Platform::Ios);
+        install_os_version(&mut env, OsVersion::new(15, 0));
+        assert!(!availability.check(&env));
+        assert_eq!(OsVersion::new(16, 0).patch(2).to_string(), "16.0.2");
+    }
+}
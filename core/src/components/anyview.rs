@@ -11,7 +11,11 @@ use core::{
 
 use alloc::boxed::Box;
 
-use crate::{Environment, View, layout::StretchAxis};
+use crate::{
+    Environment, View,
+    layout::StretchAxis,
+    stats::{Kind, Live},
+};
 
 trait AnyViewImpl: 'static {
     fn body(self: Box<Self>, env: Environment) -> AnyView;
@@ -26,6 +30,8 @@ trait AnyViewImpl: 'static {
 
 impl<T: View> AnyViewImpl for T {
     fn body(self: Box<Self>, env: Environment) -> AnyView {
+        #[cfg(feature = "std")]
+        let _scope = crate::strict::body_scope(type_name::<T>());
         AnyView::new(View::body(*self, &env))
     }
     fn stretch_axis(&self) -> StretchAxis {
@@ -37,7 +43,11 @@ impl<T: View> AnyViewImpl for T {
 ///
 /// This allows storing and passing around different view types uniformly.
 #[must_use]
-pub struct AnyView(Box<dyn AnyViewImpl>);
+pub struct AnyView {
+    view: Box<dyn AnyViewImpl>,
+    /// Counts this view in [`stats`](crate::stats) while it is alive.
+    _live: Live,
+}
 
 impl Debug for AnyView {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -67,7 +77,10 @@ impl AnyView {
                 .expect("option should contain a value"); // TODO: use downcast_mut_unchecked when it's stable
         }
 
-        Self(Box::new(view))
+        Self {
+            view: Box::new(view),
+            _live: Live::of::<V>(Kind::View),
+        }
     }
 
     /// Checks if the contained view is of type `T`.
@@ -79,13 +92,13 @@ impl AnyView {
     /// Returns the `TypeId` of the contained view.
     #[must_use]
     pub fn type_id(&self) -> TypeId {
-        AnyViewImpl::type_id(&*self.0)
+        AnyViewImpl::type_id(&*self.view)
     }
 
     /// Returns the type name of the contained view.
     #[must_use]
     pub fn name(&self) -> &'static str {
-        AnyViewImpl::name(&*self.0)
+        AnyViewImpl::name(&*self.view)
     }
 
     /// Returns the stretch axis of the contained view.
@@ -94,7 +107,7 @@ impl AnyView {
     /// which for native views returns their layout stretch behavior.
     #[must_use]
     pub fn stretch_axis(&self) -> StretchAxis {
-        AnyViewImpl::stretch_axis(&*self.0)
+        AnyViewImpl::stretch_axis(&*self.view)
     }
 
     /// Downcasts `AnyView` to a concrete view type without any runtime checks.
@@ -103,7 +116,7 @@ impl AnyView {
     /// Calling this method with the incorrect type is undefined behavior.
     #[must_use]
     pub unsafe fn downcast_unchecked<T: 'static>(self) -> Box<T> {
-        unsafe { Box::from_raw(Box::into_raw(self.0).cast::<T>()) }
+        unsafe { Box::from_raw(Box::into_raw(self.view).cast::<T>()) }
     }
 
     /// Returns a reference to the contained view without any runtime checks.
@@ -112,7 +125,7 @@ impl AnyView {
     /// Calling this method with the incorrect type is undefined behavior.
     #[must_use]
     pub const unsafe fn downcast_ref_unchecked<T: 'static>(&self) -> &T {
-        unsafe { &*(&raw const *self.0).cast::<T>() }
+        unsafe { &*(&raw const *self.view).cast::<T>() }
     }
 
     /// Returns a mutable reference to the contained view without any runtime checks.
@@ -120,7 +133,7 @@ impl AnyView {
     /// # Safety
     /// Calling this method with the incorrect type is undefined behavior.
     pub const unsafe fn downcast_mut_unchecked<T: 'static>(&mut self) -> &mut T {
-        unsafe { &mut *(&raw mut *self.0).cast::<T>() }
+        unsafe { &mut *(&raw mut *self.view).cast::<T>() }
     }
 
     /// Attempts to downcast `AnyView` to a concrete view type.
@@ -157,7 +170,7 @@ impl AnyView {
 
 impl View for AnyView {
     fn body(self, env: &Environment) -> impl View {
-        self.0.body(env.clone())
+        self.view.body(env.clone())
     }
 }
 
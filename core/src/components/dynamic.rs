@@ -6,6 +6,9 @@
 //! - `Dynamic` - A view that can be updated through a `DynamicHandler`
 //! - `watch` - Helper function to create views that respond to reactive state changes
 //!
+//! Content swaps are instant by default. Attach a [`Transition`] with
+//! [`Watch::transition`] or [`Dynamic::transition`] to animate them.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -18,8 +21,9 @@
 //! // Create a view that watches a reactive value
 //! let count = Binding::container(0);
 //! let counter_view = watch(count, |value| format!("Count: {}", value));
+use crate::animation::Transition;
 use crate::components::metadata::Retain;
-use crate::{AnyView, Metadata, View};
+use crate::{AnyView, Environment, Metadata, View, notify};
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use core::cell::RefCell;
@@ -40,7 +44,13 @@ raw_view!(Dynamic);
 ///
 /// Provides methods to set new content for the associated Dynamic view.
 #[derive(Clone)]
-pub struct DynamicHandler(Rc<RefCell<DynamicHandlerState>>);
+pub struct DynamicHandler(Rc<RefCell<DynamicHandlerInner>>);
+
+struct DynamicHandlerInner {
+    state: DynamicHandlerState,
+    /// Transition attached to every update that does not carry its own.
+    transition: Option<Transition>,
+}
 
 enum DynamicHandlerState {
     /// Connected to a receiver (Swift/native side).
@@ -61,10 +71,15 @@ impl DynamicHandler {
     ///
     /// * `view` - The new view to display
     /// * `metadata` - Additional metadata associated with the update
-    pub fn set_with_metadata(&self, view: impl View, metadata: WatcherMetadata) {
-        let mut state = self.0.borrow_mut();
+    pub fn set_with_metadata(&self, view: impl View, mut metadata: WatcherMetadata) {
+        let mut inner = self.0.borrow_mut();
         let view = AnyView::new(view);
-        match &mut *state {
+        if let Some(transition) = inner.transition {
+            if metadata.try_get::<Transition>().is_none() {
+                metadata = metadata.with(transition);
+            }
+        }
+        match &mut inner.state {
             DynamicHandlerState::Connected(receiver) => {
                 receiver(Context::new(view, metadata));
             }
@@ -82,6 +97,13 @@ impl DynamicHandler {
     pub fn set(&self, view: impl View) {
         self.set_with_metadata(view, WatcherMetadata::new());
     }
+
+    /// Sets the transition used for subsequent content updates.
+    ///
+    /// Updates whose metadata already carries a [`Transition`] keep their own.
+    pub fn set_transition(&self, transition: Transition) {
+        self.0.borrow_mut().transition = Some(transition);
+    }
 }
 
 impl Dynamic {
@@ -95,12 +117,33 @@ impl Dynamic {
     /// A tuple containing the [`DynamicHandler`] and Dynamic view
     #[must_use]
     pub fn new() -> (DynamicHandler, Self) {
-        let handler = DynamicHandler(Rc::new(RefCell::new(DynamicHandlerState::Unconnected(
-            None,
-        ))));
+        let handler = DynamicHandler(Rc::new(RefCell::new(DynamicHandlerInner {
+            state: DynamicHandlerState::Unconnected(None),
+            transition: None,
+        })));
         (handler.clone(), Self(handler))
     }
 
+    /// Animates content updates with the given transition.
+    ///
+    /// The initial content is shown without a transition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use waterui_core::{Dynamic, animation::CrossFade};
+    ///
+    /// let (handler, view) = Dynamic::new();
+    /// let view = view.transition(CrossFade(Duration::from_millis(200)));
+    /// handler.set("Loaded");
+    /// ```
+    #[must_use]
+    pub fn transition(self, transition: Transition) -> Self {
+        self.0.set_transition(transition);
+        self
+    }
+
     /// Creates a Dynamic view that watches a reactive value.
     ///
     /// The provided function is used to convert the value to a view.
@@ -113,18 +156,80 @@ impl Dynamic {
     ///
     /// # Returns
     ///
-    /// A Dynamic view that updates when the value changes
-    pub fn watch<T, S, V: View>(value: S, f: impl 'static + Fn(T) -> V) -> impl View
+    /// A [`Watch`] view that updates when the value changes
+    ///
+    /// Updates are delivered through [`notify::dispatch`](crate::notify::dispatch),
+    /// so a write made while another update is applied is deferred until it finishes.
+    pub fn watch<T, S, V: View>(value: S, f: impl 'static + Fn(T) -> V) -> Watch
     where
         S: Signal<Output = T>,
+        T: 'static,
     {
         let (handle, dynamic) = Self::new();
         handle.set(f(value.get()));
 
-        let guard = value.watch(move |value| handle.set(f(value.into_value())));
+        let name = core::any::type_name::<V>();
+        let guard = value.watch(notify::deferred(name, move |value: Context<T>| {
+            #[cfg(feature = "std")]
+            let _scope = crate::strict::watcher_scope(name);
+            let metadata = value.metadata().clone();
+            handle.set_with_metadata(f(value.into_value()), metadata);
+        }));
 
-        // Use Metadata<Retain> to keep the guard and value alive
-        Metadata::new(dynamic, Retain::new((guard, value)))
+        Watch {
+            dynamic,
+            retain: Retain::new((guard, value)),
+        }
+    }
+
+    /// Creates a Dynamic view that rebuilds only when the value's identity changes.
+    ///
+    /// Like [`Dynamic::watch`], but `id` is evaluated for every new value and
+    /// the view is rebuilt only when it differs from the previous identity, so
+    /// the current view is kept across updates that map to the same identity.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The reactive value to watch
+    /// * `id` - A function that extracts the identity of a value
+    /// * `f` - A function that converts the value to a view
+    ///
+    /// # Returns
+    ///
+    /// A [`Watch`] view that updates when the identity changes
+    pub fn watch_by_id<T, S, K, V: View>(
+        value: S,
+        id: impl 'static + Fn(&T) -> K,
+        f: impl 'static + Fn(T) -> V,
+    ) -> Watch
+    where
+        S: Signal<Output = T>,
+        T: 'static,
+        K: PartialEq + 'static,
+    {
+        let (handle, dynamic) = Self::new();
+        let initial = value.get();
+        let current = RefCell::new(id(&initial));
+        handle.set(f(initial));
+
+        let name = core::any::type_name::<V>();
+        let guard = value.watch(notify::deferred(name, move |value: Context<T>| {
+            #[cfg(feature = "std")]
+            let _scope = crate::strict::watcher_scope(name);
+            let metadata = value.metadata().clone();
+            let value = value.into_value();
+            let new_id = id(&value);
+            if *current.borrow() == new_id {
+                return;
+            }
+            *current.borrow_mut() = new_id;
+            handle.set_with_metadata(f(value), metadata);
+        }));
+
+        Watch {
+            dynamic,
+            retain: Retain::new((guard, value)),
+        }
     }
 
     /// Connects the Dynamic view to a receiver function.
@@ -139,20 +244,63 @@ impl Dynamic {
     ///
     /// * `receiver` - A function that receives view updates
     pub fn connect(self, receiver: impl Fn(Context<AnyView>) + 'static) {
-        let mut state = self.0.0.borrow_mut();
+        let mut inner = self.0.0.borrow_mut();
 
-        match &mut *state {
+        match &mut inner.state {
             DynamicHandlerState::Unconnected(temp_view) => {
                 if let Some(view) = temp_view.take() {
                     receiver(Context::new(view, WatcherMetadata::new()));
                 }
-                *state = DynamicHandlerState::Connected(Box::new(receiver));
+                inner.state = DynamicHandlerState::Connected(Box::new(receiver));
             }
             DynamicHandlerState::Connected(_) => unreachable!("Dynamic already connected"),
         }
     }
 }
 
+/// A [`Dynamic`] view driven by a reactive value.
+///
+/// Created by [`Dynamic::watch`] or [`watch`]. Keeps the watcher alive for as
+/// long as the view exists.
+pub struct Watch {
+    dynamic: Dynamic,
+    retain: Retain,
+}
+
+impl_debug!(Watch);
+
+impl Watch {
+    /// Animates content swaps with the given transition.
+    ///
+    /// Useful for async content, where the switch from a loading state to the
+    /// loaded view would otherwise pop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use waterui_core::{Binding, animation::CrossFade, dynamic::watch};
+    ///
+    /// let loaded = Binding::container(false);
+    /// let view = watch(loaded, |loaded| if loaded { "Done" } else { "Loading..." })
+    ///     .transition(CrossFade(Duration::from_millis(200)));
+    /// ```
+    #[must_use]
+    pub fn transition(self, transition: Transition) -> Self {
+        Self {
+            dynamic: self.dynamic.transition(transition),
+            retain: self.retain,
+        }
+    }
+}
+
+impl View for Watch {
+    fn body(self, _env: &Environment) -> impl View {
+        // Use Metadata<Retain> to keep the guard and value alive
+        Metadata::new(self.dynamic, self.retain)
+    }
+}
+
 /// Creates a view that watches a reactive value.
 ///
 /// A convenience function that calls [`Dynamic::watch`].
@@ -164,10 +312,11 @@ impl Dynamic {
 ///
 /// # Returns
 ///
-/// A view that updates when the value changes
-pub fn watch<T, S, V: View>(value: S, f: impl Fn(T) -> V + 'static) -> impl View
+/// A [`Watch`] view that updates when the value changes
+pub fn watch<T, S, V: View>(value: S, f: impl Fn(T) -> V + 'static) -> Watch
 where
     S: Signal<Output = T>,
+    T: 'static,
 {
     Dynamic::watch(value, f)
 }
@@ -180,3 +329,52 @@ where
         Dynamic::watch(self, |view| view)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::CrossFade;
+    use alloc::vec::Vec;
+    use core::time::Duration;
+    use nami::Binding;
+
+    #[test]
+    fn transition_is_attached_to_updates() {
+        let fade = CrossFade(Duration::from_millis(200));
+        let (handler, dynamic) = Dynamic::new();
+        handler.set("initial");
+        let dynamic = dynamic.transition(fade);
+
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let recorder = seen.clone();
+        dynamic.connect(move |ctx| {
+            recorder
+                .borrow_mut()
+                .push(ctx.metadata().try_get::<Transition>());
+        });
+        handler.set("loaded");
+
+        assert_eq!(*seen.borrow(), [None, Some(fade)]);
+    }
+
+    #[test]
+    fn watch_by_id_skips_updates_with_same_identity() {
+        let value = Binding::container(1);
+        let builds = Rc::new(RefCell::new(Vec::new()));
+        let recorder = builds.clone();
+        let _view = Dynamic::watch_by_id(
+            value.clone(),
+            |value| *value % 2,
+            move |value: i32| {
+                recorder.borrow_mut().push(value);
+            },
+        );
+
+        value.set(3);
+        value.set(4);
+        value.set(6);
+        value.set(7);
+
+        assert_eq!(*builds.borrow(), [1, 4, 7]);
+    }
+}
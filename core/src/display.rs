@@ -0,0 +1,336 @@
+//! Properties of the display a view is rendered on.
+//!
+//! Native backends install these as signals so views can adapt when a window
+//! moves between screens. A backend that owns the values can install all of
+//! them at once with [`DisplayReporter::install`] and update them as the
+//! window's screen changes.
+//!
+//! [`current_display_capabilities`] combines the headroom and gamut into a
+//! [`DisplayCapabilities`] for HDR-aware views, and colors clamp their
+//! headroom against it through [`clamp_headroom`].
+
+use nami::{Binding, Computed, SignalExt, signal::IntoComputed};
+
+use crate::{Environment, layout::Size};
+
+/// The range of colors a display can reproduce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorGamut {
+    /// The standard sRGB gamut.
+    #[default]
+    Srgb,
+    /// The wide Display P3 gamut of most modern phones and laptops.
+    DisplayP3,
+}
+
+/// What the current display can show beyond standard dynamic range and sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DisplayCapabilities {
+    /// The most headroom a color can use right now.
+    ///
+    /// Uses the units of a resolved color's headroom: `0.0` is SDR white,
+    /// `1.0` twice as bright. On Apple platforms this is the maximum EDR
+    /// color component value minus one, and it changes with the brightness
+    /// of the screen.
+    pub max_headroom: f32,
+    /// The color gamut of the display.
+    pub color_gamut: ColorGamut,
+}
+
+impl DisplayCapabilities {
+    /// Returns true if colors brighter than SDR white can be shown right now.
+    #[must_use]
+    pub fn has_headroom(&self) -> bool {
+        self.max_headroom > 0.0
+    }
+
+    /// Returns true if the display shows colors outside of sRGB.
+    #[must_use]
+    pub const fn is_wide_gamut(&self) -> bool {
+        matches!(self.color_gamut, ColorGamut::DisplayP3)
+    }
+}
+
+/// Storage for the display scale signal.
+#[derive(Clone)]
+struct DisplayScaleSignal(Computed<f32>);
+
+/// Storage for the refresh rate signal.
+#[derive(Clone)]
+struct RefreshRateSignal(Computed<f32>);
+
+/// Storage for the color gamut signal.
+#[derive(Clone)]
+struct ColorGamutSignal(Computed<ColorGamut>);
+
+/// Storage for the HDR support signal.
+#[derive(Clone)]
+struct HdrSignal(Computed<bool>);
+
+/// Storage for the maximum headroom signal.
+#[derive(Clone)]
+struct MaxHeadroomSignal(Computed<f32>);
+
+/// Storage for the screen size signal.
+#[derive(Clone)]
+struct ScreenSizeSignal(Computed<Size>);
+
+/// Returns the backing scale factor of the current display.
+///
+/// This is the number of physical pixels per point (e.g. `2.0` on a Retina
+/// display). If no scale is installed, returns a constant `1.0` signal.
+#[must_use]
+pub fn current_display_scale(env: &Environment) -> Computed<f32> {
+    env.get::<DisplayScaleSignal>()
+        .map_or_else(|| Computed::constant(1.0), |s| s.0.clone())
+}
+
+/// Installs the display scale signal.
+///
+/// This is used by native backends to report the backing scale factor of the
+/// screen hosting the view hierarchy.
+pub fn install_display_scale(env: &mut Environment, scale: impl IntoComputed<f32>) {
+    env.insert(DisplayScaleSignal(scale.into_computed()));
+}
+
+/// Returns the maximum refresh rate of the current display, in hertz.
+///
+/// Animations and GPU surfaces can use it to pace their frames. If no rate is
+/// installed, returns a constant `60.0` signal.
+#[must_use]
+pub fn current_refresh_rate(env: &Environment) -> Computed<f32> {
+    env.get::<RefreshRateSignal>()
+        .map_or_else(|| Computed::constant(60.0), |s| s.0.clone())
+}
+
+/// Installs the refresh rate signal.
+pub fn install_refresh_rate(env: &mut Environment, hertz: impl IntoComputed<f32>) {
+    env.insert(RefreshRateSignal(hertz.into_computed()));
+}
+
+/// Returns the color gamut of the current display.
+///
+/// If no gamut is installed, returns a constant [`ColorGamut::Srgb`] signal.
+#[must_use]
+pub fn current_color_gamut(env: &Environment) -> Computed<ColorGamut> {
+    env.get::<ColorGamutSignal>()
+        .map_or_else(|| Computed::constant(ColorGamut::Srgb), |s| s.0.clone())
+}
+
+/// Installs the color gamut signal.
+pub fn install_color_gamut(env: &mut Environment, gamut: impl IntoComputed<ColorGamut>) {
+    env.insert(ColorGamutSignal(gamut.into_computed()));
+}
+
+/// Returns whether the current display can show high dynamic range content.
+///
+/// If no capability is installed, returns a constant `false` signal.
+#[must_use]
+pub fn current_supports_hdr(env: &Environment) -> Computed<bool> {
+    env.get::<HdrSignal>()
+        .map_or_else(|| Computed::constant(false), |s| s.0.clone())
+}
+
+/// Installs the HDR support signal.
+pub fn install_supports_hdr(env: &mut Environment, supported: impl IntoComputed<bool>) {
+    env.insert(HdrSignal(supported.into_computed()));
+}
+
+/// Returns the most headroom a color can use on the current display.
+///
+/// See [`DisplayCapabilities::max_headroom`]. If no headroom is installed,
+/// returns a constant `0.0` signal.
+#[must_use]
+pub fn current_max_headroom(env: &Environment) -> Computed<f32> {
+    env.get::<MaxHeadroomSignal>()
+        .map_or_else(|| Computed::constant(0.0), |s| s.0.clone())
+}
+
+/// Installs the maximum headroom signal.
+pub fn install_max_headroom(env: &mut Environment, headroom: impl IntoComputed<f32>) {
+    env.insert(MaxHeadroomSignal(headroom.into_computed()));
+}
+
+/// Returns the HDR and wide color capabilities of the current display.
+#[must_use]
+pub fn current_display_capabilities(env: &Environment) -> Computed<DisplayCapabilities> {
+    current_max_headroom(env)
+        .zip(current_color_gamut(env))
+        .map(|(max_headroom, color_gamut)| DisplayCapabilities {
+            max_headroom,
+            color_gamut,
+        })
+        .computed()
+}
+
+/// Limits `headroom` to what the current display can show.
+///
+/// If the backend has not installed a maximum headroom, the display is
+/// unknown and `headroom` is kept as requested.
+#[must_use]
+pub fn clamp_headroom(env: &Environment, headroom: f32) -> Computed<f32> {
+    env.get::<MaxHeadroomSignal>().map_or_else(
+        || Computed::constant(headroom),
+        |s| {
+            s.0.clone()
+                .map(move |max: f32| headroom.min(max.max(0.0)))
+                .computed()
+        },
+    )
+}
+
+/// Returns the size of the current screen, in points.
+///
+/// This is the whole screen, not the window; use it to choose layouts that
+/// depend on the device class. If no size is installed, returns a constant
+/// zero size.
+#[must_use]
+pub fn current_screen_size(env: &Environment) -> Computed<Size> {
+    env.get::<ScreenSizeSignal>()
+        .map_or_else(|| Computed::constant(Size::zero()), |s| s.0.clone())
+}
+
+/// Installs the screen size signal.
+pub fn install_screen_size(env: &mut Environment, size: impl IntoComputed<Size>) {
+    env.insert(ScreenSizeSignal(size.into_computed()));
+}
+
+/// Writes the display properties that a native backend observes.
+///
+/// [`install`](Self::install) puts a signal for every property into the
+/// environment, starting from the same defaults the `current_*` functions
+/// fall back to. Setting a property updates every view reading it.
+#[derive(Clone)]
+pub struct DisplayReporter {
+    scale: Binding<f32>,
+    refresh_rate: Binding<f32>,
+    color_gamut: Binding<ColorGamut>,
+    supports_hdr: Binding<bool>,
+    max_headroom: Binding<f32>,
+    screen_size: Binding<Size>,
+}
+
+impl core::fmt::Debug for DisplayReporter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DisplayReporter")
+            .field("scale", &self.scale.get())
+            .field("refresh_rate", &self.refresh_rate.get())
+            .field("color_gamut", &self.color_gamut.get())
+            .field("supports_hdr", &self.supports_hdr.get())
+            .field("max_headroom", &self.max_headroom.get())
+            .field("screen_size", &self.screen_size.get())
+            .finish()
+    }
+}
+
+impl DisplayReporter {
+    /// Installs signals for every display property into `env`.
+    #[must_use]
+    pub fn install(env: &mut Environment) -> Self {
+        let reporter = Self {
+            scale: Binding::container(1.0),
+            refresh_rate: Binding::container(60.0),
+            color_gamut: Binding::container(ColorGamut::Srgb),
+            supports_hdr: Binding::container(false),
+            max_headroom: Binding::container(0.0),
+            screen_size: Binding::container(Size::zero()),
+        };
+        install_display_scale(env, reporter.scale.clone().computed());
+        install_refresh_rate(env, reporter.refresh_rate.clone().computed());
+        install_color_gamut(env, reporter.color_gamut.clone().computed());
+        install_supports_hdr(env, reporter.supports_hdr.clone().computed());
+        install_max_headroom(env, reporter.max_headroom.clone().computed());
+        install_screen_size(env, reporter.screen_size.clone().computed());
+        reporter
+    }
+
+    /// Sets the backing scale factor.
+    pub fn set_scale(&self, scale: f32) {
+        update(&self.scale, scale);
+    }
+
+    /// Sets the refresh rate, in hertz.
+    pub fn set_refresh_rate(&self, hertz: f32) {
+        update(&self.refresh_rate, hertz);
+    }
+
+    /// Sets the color gamut.
+    pub fn set_color_gamut(&self, gamut: ColorGamut) {
+        update(&self.color_gamut, gamut);
+    }
+
+    /// Sets whether the display can show HDR content.
+    pub fn set_supports_hdr(&self, supported: bool) {
+        update(&self.supports_hdr, supported);
+    }
+
+    /// Sets the most headroom a color can use right now.
+    ///
+    /// Report it again whenever it changes, such as when the screen's
+    /// brightness changes.
+    pub fn set_max_headroom(&self, headroom: f32) {
+        update(&self.max_headroom, headroom);
+    }
+
+    /// Sets the screen size, in points.
+    pub fn set_screen_size(&self, size: Size) {
+        update(&self.screen_size, size);
+    }
+}
+
+/// Sets `value` only if it changed, so moving between identical screens does
+/// not rebuild views.
+fn update<T: Clone + PartialEq + 'static>(binding: &Binding<T>, value: T) {
+    if binding.get() != value {
+        binding.set(value);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use nami::Signal;
+
+    #[test]
+    fn reporter_updates_installed_signals() {
+        let mut env = Environment::new();
+        assert_eq!(current_refresh_rate(&env).get(), 60.0);
+
+        let reporter = DisplayReporter::install(&mut env);
+        let scale = current_display_scale(&env);
+        let gamut = current_color_gamut(&env);
+        let screen = current_screen_size(&env);
+        assert_eq!(scale.get(), 1.0);
+
+        reporter.set_scale(3.0);
+        reporter.set_refresh_rate(120.0);
+        reporter.set_color_gamut(ColorGamut::DisplayP3);
+        reporter.set_supports_hdr(true);
+        reporter.set_screen_size(Size::new(393.0, 852.0));
+
+        assert_eq!(scale.get(), 3.0);
+        assert_eq!(current_refresh_rate(&env).get(), 120.0);
+        assert_eq!(gamut.get(), ColorGamut::DisplayP3);
+        assert!(current_supports_hdr(&env).get());
+        assert_eq!(screen.get(), Size::new(393.0, 852.0));
+    }
+
+    #[test]
+    fn headroom_is_clamped_once_reported() {
+        let mut env = Environment::new();
+        assert_eq!(clamp_headroom(&env, 3.0).get(), 3.0);
+
+        let reporter = DisplayReporter::install(&mut env);
+        let clamped = clamp_headroom(&env, 3.0);
+        let capabilities = current_display_capabilities(&env);
+        assert_eq!(clamped.get(), 0.0);
+        assert!(!capabilities.get().has_headroom());
+
+        reporter.set_max_headroom(1.5);
+        reporter.set_color_gamut(ColorGamut::DisplayP3);
+        assert_eq!(clamped.get(), 1.5);
+        assert!(capabilities.get().has_headroom());
+        assert!(capabilities.get().is_wide_gamut());
+    }
+}
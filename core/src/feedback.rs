@@ -0,0 +1,328 @@
+//! Haptic and sound feedback played by built-in controls.
+//!
+//! Native backends install a [`FeedbackPlayer`] that drives the platform's
+//! haptics engine and system sounds. Apps describe which feedback each kind of
+//! control gives with [`FeedbackSettings`], usually through the theme. Buttons,
+//! toggles and sliders then play it whenever the user operates them, so apps
+//! get consistent feedback without decorating every control.
+//!
+//! Without installed settings, controls stay silent.
+
+use alloc::rc::Rc;
+
+use nami::{Computed, Signal, signal::IntoComputed};
+
+use crate::Environment;
+
+/// A haptic pattern, mapped to the closest one the platform offers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HapticStyle {
+    /// A light impact.
+    Light,
+    /// A medium impact.
+    Medium,
+    /// A heavy impact.
+    Heavy,
+    /// The tick of a changed selection.
+    Selection,
+    /// A completed task.
+    Success,
+    /// A warning.
+    Warning,
+    /// A failed task.
+    Error,
+}
+
+/// A system sound played as feedback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedbackSound {
+    /// The click of a tapped button.
+    Tap,
+    /// The click of a switched toggle.
+    Toggle,
+    /// The tick of a slider or picker.
+    Tick,
+}
+
+/// The feedback a kind of control gives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ControlFeedback {
+    /// The haptic played, if any.
+    pub haptic: Option<HapticStyle>,
+    /// The sound played, if any.
+    pub sound: Option<FeedbackSound>,
+}
+
+impl ControlFeedback {
+    /// No feedback.
+    pub const NONE: Self = Self {
+        haptic: None,
+        sound: None,
+    };
+
+    /// Creates feedback that plays `haptic` and `sound`.
+    #[must_use]
+    pub const fn new(haptic: Option<HapticStyle>, sound: Option<FeedbackSound>) -> Self {
+        Self { haptic, sound }
+    }
+}
+
+/// The built-in controls that play feedback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ControlKind {
+    /// A button, when tapped.
+    Button,
+    /// A toggle, when switched.
+    Toggle,
+    /// A slider, when dragged to either end of its range.
+    Slider,
+}
+
+/// The feedback of each kind of control.
+///
+/// The defaults follow common platform behavior: haptics are on and sounds
+/// are off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FeedbackSettings {
+    /// Whether haptics are played.
+    pub haptics: bool,
+    /// Whether sounds are played.
+    pub sounds: bool,
+    /// Feedback of buttons.
+    pub button: ControlFeedback,
+    /// Feedback of toggles.
+    pub toggle: ControlFeedback,
+    /// Feedback of sliders.
+    pub slider: ControlFeedback,
+}
+
+impl Default for FeedbackSettings {
+    fn default() -> Self {
+        Self {
+            haptics: true,
+            sounds: false,
+            button: ControlFeedback::new(Some(HapticStyle::Light), Some(FeedbackSound::Tap)),
+            toggle: ControlFeedback::new(Some(HapticStyle::Light), Some(FeedbackSound::Toggle)),
+            slider: ControlFeedback::new(Some(HapticStyle::Selection), Some(FeedbackSound::Tick)),
+        }
+    }
+}
+
+nami::impl_constant!(FeedbackSettings);
+
+impl FeedbackSettings {
+    /// Creates the default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turns haptics on or off.
+    #[must_use]
+    pub const fn haptics(mut self, enabled: bool) -> Self {
+        self.haptics = enabled;
+        self
+    }
+
+    /// Turns sounds on or off.
+    #[must_use]
+    pub const fn sounds(mut self, enabled: bool) -> Self {
+        self.sounds = enabled;
+        self
+    }
+
+    /// Sets the feedback of buttons.
+    #[must_use]
+    pub const fn button(mut self, feedback: ControlFeedback) -> Self {
+        self.button = feedback;
+        self
+    }
+
+    /// Sets the feedback of toggles.
+    #[must_use]
+    pub const fn toggle(mut self, feedback: ControlFeedback) -> Self {
+        self.toggle = feedback;
+        self
+    }
+
+    /// Sets the feedback of sliders.
+    #[must_use]
+    pub const fn slider(mut self, feedback: ControlFeedback) -> Self {
+        self.slider = feedback;
+        self
+    }
+
+    /// Returns the feedback `kind` plays, with switched-off parts removed.
+    #[must_use]
+    pub const fn feedback_for(&self, kind: ControlKind) -> ControlFeedback {
+        let feedback = match kind {
+            ControlKind::Button => self.button,
+            ControlKind::Toggle => self.toggle,
+            ControlKind::Slider => self.slider,
+        };
+        ControlFeedback {
+            haptic: if self.haptics { feedback.haptic } else { None },
+            sound: if self.sounds { feedback.sound } else { None },
+        }
+    }
+}
+
+/// Plays haptics and sounds on a platform.
+pub trait CustomFeedbackPlayer: 'static {
+    /// Plays a haptic pattern.
+    fn play_haptic(&self, style: HapticStyle);
+    /// Plays a system sound.
+    fn play_sound(&self, sound: FeedbackSound);
+}
+
+/// Type-erased feedback player stored in the environment.
+///
+/// Installed by native backends whose platform has haptics or sounds.
+#[derive(Clone)]
+pub struct FeedbackPlayer(Rc<dyn CustomFeedbackPlayer>);
+
+impl core::fmt::Debug for FeedbackPlayer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FeedbackPlayer").finish_non_exhaustive()
+    }
+}
+
+impl FeedbackPlayer {
+    /// Creates a new `FeedbackPlayer` from any type implementing `CustomFeedbackPlayer`.
+    pub fn new(player: impl CustomFeedbackPlayer) -> Self {
+        Self(Rc::new(player))
+    }
+
+    /// Plays a haptic pattern.
+    pub fn play_haptic(&self, style: HapticStyle) {
+        self.0.play_haptic(style);
+    }
+
+    /// Plays a system sound.
+    pub fn play_sound(&self, sound: FeedbackSound) {
+        self.0.play_sound(sound);
+    }
+
+    /// Plays both parts of `feedback`.
+    pub fn play(&self, feedback: ControlFeedback) {
+        if let Some(style) = feedback.haptic {
+            self.play_haptic(style);
+        }
+        if let Some(sound) = feedback.sound {
+            self.play_sound(sound);
+        }
+    }
+}
+
+/// Storage for the feedback settings signal.
+#[derive(Clone)]
+struct FeedbackSettingsSignal(Computed<FeedbackSettings>);
+
+/// Installs the feedback settings consulted by built-in controls.
+pub fn install_feedback_settings(
+    env: &mut Environment,
+    settings: impl IntoComputed<FeedbackSettings>,
+) {
+    env.insert(FeedbackSettingsSignal(settings.into_computed()));
+}
+
+/// Plays the feedback of one control when asked to.
+///
+/// Controls create it while building their body and play it when the user
+/// operates them. The settings are read at that time, so switching feedback
+/// off takes effect immediately.
+#[derive(Clone)]
+pub struct FeedbackTrigger {
+    player: FeedbackPlayer,
+    settings: Computed<FeedbackSettings>,
+    kind: ControlKind,
+}
+
+impl core::fmt::Debug for FeedbackTrigger {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FeedbackTrigger")
+            .field("kind", &self.kind)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FeedbackTrigger {
+    /// Returns a trigger for a control of `kind` in `env`.
+    ///
+    /// Returns `None` without a [`FeedbackPlayer`] or installed settings.
+    #[must_use]
+    pub fn new(env: &Environment, kind: ControlKind) -> Option<Self> {
+        let player = env.get::<FeedbackPlayer>()?.clone();
+        let settings = env.get::<FeedbackSettingsSignal>()?.0.clone();
+        Some(Self {
+            player,
+            settings,
+            kind,
+        })
+    }
+
+    /// Plays the feedback currently configured for the control.
+    pub fn play(&self) {
+        self.player
+            .play(self.settings.get().feedback_for(self.kind));
+    }
+}
+
+/// Plays the feedback configured for a control of `kind` in `env`.
+pub fn play_control_feedback(env: &Environment, kind: ControlKind) {
+    if let Some(trigger) = FeedbackTrigger::new(env, kind) {
+        trigger.play();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+
+    use nami::Binding;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder(RefCell<Vec<ControlFeedback>>);
+
+    impl CustomFeedbackPlayer for Rc<Recorder> {
+        fn play_haptic(&self, style: HapticStyle) {
+            self.0
+                .borrow_mut()
+                .push(ControlFeedback::new(Some(style), None));
+        }
+
+        fn play_sound(&self, sound: FeedbackSound) {
+            self.0
+                .borrow_mut()
+                .push(ControlFeedback::new(None, Some(sound)));
+        }
+    }
+
+    #[test]
+    fn plays_enabled_feedback_of_the_control() {
+        let recorder = Rc::new(Recorder::default());
+        let mut env = Environment::new();
+        env.insert(FeedbackPlayer::new(recorder.clone()));
+        play_control_feedback(&env, ControlKind::Button);
+        assert!(recorder.0.borrow().is_empty());
+
+        let settings = Binding::container(FeedbackSettings::new());
+        install_feedback_settings(&mut env, settings.clone());
+        let trigger = FeedbackTrigger::new(&env, ControlKind::Toggle).unwrap();
+        trigger.play();
+        assert_eq!(
+            *recorder.0.borrow(),
+            [ControlFeedback::new(Some(HapticStyle::Light), None)]
+        );
+
+        settings.set(FeedbackSettings::new().haptics(false).sounds(true));
+        trigger.play();
+        assert_eq!(
+            recorder.0.borrow()[1],
+            ControlFeedback::new(None, Some(FeedbackSound::Toggle))
+        );
+    }
+}
@@ -10,12 +10,18 @@
 //! - `Identifiable`: A trait for types that can be uniquely identified
 //! - `TaggedView`: A view wrapper that includes an identifying tag
 //! - `Mapping`: A bidirectional mapping between values and numeric IDs
+//! - `ViewId`: The identity that decides whether backends keep or rebuild a view
 //! - `UseId` and `SelfId`: Wrappers that implement different ID strategies
 
 use core::num::NonZeroI32;
-use core::{hash::Hash, ops::Deref};
+use core::{
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
 
-use crate::{AnyView, View};
+use nami::Signal;
+
+use crate::{AnyView, Dynamic, Metadata, View, dynamic::Watch, metadata::MetadataKey};
 
 /// A non-zero i32 value used for identification purposes throughout the crate.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -198,6 +204,73 @@ impl<T, V: View> TaggedView<T, V> {
     }
 }
 
+/// The identity of a view, set with `ViewExt::id`.
+///
+/// Backends compare identities when a [`Dynamic`](crate::Dynamic) swaps its
+/// content. If the new content has the same identity as the old one, the
+/// native view and its state (such as the scroll position) are kept and only
+/// updated. If the identity differs, the native view is torn down and built
+/// again, even if both contents are of the same type.
+///
+/// The identity is the FNV-1a hash of a value, so equal values always give
+/// equal identities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ViewId(pub u64);
+
+impl ViewId {
+    /// Creates the identity of `value`.
+    pub fn new(value: &impl Hash) -> Self {
+        let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+        value.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
+impl MetadataKey for ViewId {}
+
+/// FNV-1a, which needs no random state and is the same on every platform.
+struct Fnv1a(u64);
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+/// Creates a view that is built again whenever `id` changes.
+///
+/// `content` is called for the initial identity and again for every new one,
+/// so bindings and other state created inside it start over. The result
+/// carries a [`ViewId`], which makes backends rebuild the native view too.
+/// Updates that keep the identity leave the view untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use waterui_core::{Binding, id::identified};
+///
+/// let document = Binding::container(1);
+/// // Switching documents resets the editor's local state
+/// let editor = identified(document, |id| format!("Document {id}"));
+/// ```
+pub fn identified<K, S, V>(id: S, content: impl Fn(K) -> V + 'static) -> Watch
+where
+    S: Signal<Output = K>,
+    K: Hash + PartialEq + Clone + 'static,
+    V: View,
+{
+    Dynamic::watch_by_id(id, Clone::clone, move |key: K| {
+        let id = ViewId::new(&key);
+        Metadata::new(content(key), id)
+    })
+}
+
 use core::cell::RefCell;
 
 use alloc::{collections::btree_map::BTreeMap, rc::Rc};
@@ -333,3 +406,16 @@ impl<T: Ord + Clone> Mapping<T> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_id_is_stable_fnv1a() {
+        assert_eq!(ViewId::new(&"a"), ViewId::new(&"a"));
+        assert_ne!(ViewId::new(&1_u32), ViewId::new(&2_u32));
+        // FNV-1a of the single byte 0x61
+        assert_eq!(ViewId::new(&0x61_u8), ViewId(0xaf63_dc4c_8601_ec8c));
+    }
+}
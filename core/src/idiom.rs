@@ -0,0 +1,163 @@
+//! Device idioms that only some Apple device classes have.
+//!
+//! Backends opt in to each idiom for the devices that have it, so shared view
+//! code can use them unconditionally:
+//!
+//! - Ornaments are views attached to the outside of a window on visionOS.
+//!   Backends that render them call [`install_ornament_support`]; elsewhere
+//!   [`WithOrnament`] shows only its content.
+//! - The digital crown of watchOS reports its rotation through a
+//!   [`DigitalCrown`] installed by the backend; elsewhere
+//!   [`crown_rotation`] stays at zero.
+
+use nami::{Binding, Computed, SignalExt};
+
+use crate::{AnyView, Environment, Metadata, View, metadata::MetadataKey};
+
+/// Where an ornament is attached to its window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OrnamentPlacement {
+    /// Above the top edge.
+    Top,
+    /// Below the bottom edge.
+    #[default]
+    Bottom,
+    /// Beside the leading edge.
+    Leading,
+    /// Beside the trailing edge.
+    Trailing,
+}
+
+/// A view attached to the outside of the window that shows the content.
+#[derive(Debug)]
+pub struct Ornament {
+    /// Where the ornament is attached.
+    pub placement: OrnamentPlacement,
+    /// The view shown in the ornament.
+    pub content: AnyView,
+}
+
+impl MetadataKey for Ornament {}
+
+/// Marks an environment whose backend renders ornaments.
+#[derive(Debug, Clone, Copy)]
+struct OrnamentSupport;
+
+/// Declares that the backend renders [`Ornament`] metadata.
+pub fn install_ornament_support(env: &mut Environment) {
+    env.insert(OrnamentSupport);
+}
+
+/// Returns true if the backend renders ornaments.
+#[must_use]
+pub fn supports_ornaments(env: &Environment) -> bool {
+    env.get::<OrnamentSupport>().is_some()
+}
+
+/// A view with an ornament, where the backend supports them.
+///
+/// Created by `ViewExt::ornament`.
+#[derive(Debug)]
+pub struct WithOrnament<V> {
+    content: V,
+    ornament: Ornament,
+}
+
+impl<V: View> WithOrnament<V> {
+    /// Attaches `ornament` at `placement` to the window showing `content`.
+    pub fn new(content: V, placement: OrnamentPlacement, ornament: impl View) -> Self {
+        Self {
+            content,
+            ornament: Ornament {
+                placement,
+                content: AnyView::new(ornament),
+            },
+        }
+    }
+}
+
+impl<V: View> View for WithOrnament<V> {
+    fn body(self, env: &Environment) -> impl View {
+        if supports_ornaments(env) {
+            AnyView::new(Metadata::new(self.content, self.ornament))
+        } else {
+            AnyView::new(self.content)
+        }
+    }
+}
+
+/// Reports the rotation of a watch's digital crown.
+///
+/// Backends with a crown install one and report every rotation.
+#[derive(Clone)]
+pub struct DigitalCrown(Binding<f64>);
+
+impl core::fmt::Debug for DigitalCrown {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("DigitalCrown").field(&self.0.get()).finish()
+    }
+}
+
+impl DigitalCrown {
+    /// Installs a crown at zero rotation into `env`.
+    pub fn install(env: &mut Environment) -> Self {
+        let crown = Self(Binding::container(0.0));
+        env.insert(crown.clone());
+        crown
+    }
+
+    /// Reports the accumulated rotation, in full turns.
+    ///
+    /// Turning up increases the rotation; turning down decreases it.
+    #[allow(clippy::float_cmp)]
+    pub fn set(&self, rotation: f64) {
+        if self.0.get() != rotation {
+            self.0.set(rotation);
+        }
+    }
+}
+
+/// Returns true if the device has a digital crown.
+#[must_use]
+pub fn has_digital_crown(env: &Environment) -> bool {
+    env.get::<DigitalCrown>().is_some()
+}
+
+/// Returns the accumulated rotation of the digital crown, in full turns.
+///
+/// Without a crown the rotation is always zero.
+#[must_use]
+pub fn crown_rotation(env: &Environment) -> Computed<f64> {
+    env.get::<DigitalCrown>().map_or_else(
+        || Computed::constant(0.0),
+        |crown| crown.0.clone().computed(),
+    )
+}
+
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+    use nami::Signal;
+
+    #[test]
+    fn crown_rotation_is_zero_without_a_crown() {
+        let mut env = Environment::new();
+        assert!(!has_digital_crown(&env));
+        assert_eq!(crown_rotation(&env).get(), 0.0);
+
+        let crown = DigitalCrown::install(&mut env);
+        let rotation = crown_rotation(&env);
+        crown.set(1.5);
+        assert!(has_digital_crown(&env));
+        assert_eq!(rotation.get(), 1.5);
+    }
+
+    #[test]
+    fn ornaments_need_backend_support() {
+        let mut env = Environment::new();
+        assert!(!supports_ornaments(&env));
+        install_ornament_support(&mut env);
+        assert!(supports_ornaments(&env));
+    }
+}
@@ -176,6 +176,11 @@ pub trait Layout: Debug {
     fn stretch_axis(&self) -> StretchAxis {
         StretchAxis::None
     }
+
+    /// Returns the type name of this layout, for diagnostics.
+    fn name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
 }
 
 // ============================================================================
@@ -94,8 +94,13 @@ mod macros;
 mod components;
 pub use anyview::AnyView;
 pub use components::*;
+pub mod availability;
+pub mod display;
 pub mod env;
 pub mod event;
+pub mod feedback;
+pub mod idiom;
+pub mod platform;
 pub mod view;
 pub mod views;
 pub use env::Environment;
@@ -111,5 +116,11 @@ pub use nami::{Binding, Computed, Signal, SignalExt, binding, constant};
 pub use waterui_str::Str;
 pub mod id;
 pub mod layout;
+pub mod network;
+pub mod notify;
 /// Module for resolving reactive values in different environments.
 pub mod resolve;
+pub mod stats;
+#[cfg(feature = "std")]
+pub mod strict;
+pub mod time;
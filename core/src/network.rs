@@ -0,0 +1,94 @@
+//! Network reachability reported by the backend.
+//!
+//! Backends that can observe the network install a [`NetworkMonitor`] and
+//! report every change. Views and tasks read it with [`current_reachability`],
+//! for example to retry a failed download as soon as the device is back
+//! online. Without a monitor, reachability stays [`Reachability::Unknown`].
+
+use nami::{Binding, Computed, SignalExt};
+
+use crate::Environment;
+
+/// Whether the network can currently be reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Reachability {
+    /// The backend does not report reachability.
+    #[default]
+    Unknown,
+    /// No network connection is available.
+    Offline,
+    /// A network connection is available.
+    Online,
+}
+
+nami::impl_constant!(Reachability);
+
+impl Reachability {
+    /// Returns false only if the network is known to be unreachable.
+    ///
+    /// Requests are worth trying while reachability is unknown.
+    #[must_use]
+    pub const fn may_connect(self) -> bool {
+        !matches!(self, Self::Offline)
+    }
+}
+
+/// Reports network reachability into the environment.
+///
+/// Installed by backends that can observe the network.
+#[derive(Clone)]
+pub struct NetworkMonitor(Binding<Reachability>);
+
+impl core::fmt::Debug for NetworkMonitor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("NetworkMonitor")
+            .field(&self.0.get())
+            .finish()
+    }
+}
+
+impl NetworkMonitor {
+    /// Installs a monitor with unknown reachability into `env`.
+    pub fn install(env: &mut Environment) -> Self {
+        let monitor = Self(Binding::container(Reachability::Unknown));
+        env.insert(monitor.clone());
+        monitor
+    }
+
+    /// Reports the current reachability.
+    pub fn set(&self, reachability: Reachability) {
+        if self.0.get() != reachability {
+            self.0.set(reachability);
+        }
+    }
+}
+
+/// Returns the network reachability reported by the backend.
+///
+/// Without a [`NetworkMonitor`] this is always [`Reachability::Unknown`].
+#[must_use]
+pub fn current_reachability(env: &Environment) -> Computed<Reachability> {
+    env.get::<NetworkMonitor>().map_or_else(
+        || Computed::constant(Reachability::Unknown),
+        |monitor| monitor.0.clone().computed(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nami::Signal;
+
+    #[test]
+    fn follows_the_installed_monitor() {
+        let mut env = Environment::new();
+        assert_eq!(current_reachability(&env).get(), Reachability::Unknown);
+        assert!(Reachability::Unknown.may_connect());
+
+        let monitor = NetworkMonitor::install(&mut env);
+        let reachability = current_reachability(&env);
+        monitor.set(Reachability::Offline);
+        assert_eq!(reachability.get(), Reachability::Offline);
+        assert!(!reachability.get().may_connect());
+    }
+}
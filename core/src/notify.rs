@@ -0,0 +1,406 @@
+//! Ordered delivery of watcher notifications.
+//!
+//! Writing to a binding notifies its watchers synchronously, so a watcher that
+//! writes to another binding would otherwise run the next watcher inside its
+//! own callback, and two watchers updating each other recurse until the stack
+//! overflows.
+//!
+//! Watchers owned by `WaterUI` — those behind
+//! [`Dynamic::watch`](crate::Dynamic::watch) and the ones native backends
+//! register through the FFI — deliver through [`dispatch`] instead:
+//!
+//! - Notifications run first-in, first-out. A notification triggered while
+//!   another one is running is deferred until it returns, then delivered in
+//!   the order the writes were made.
+//! - A chain of notifications, each raised by the callback of the one before
+//!   it, that grows past [`NotifyConfig::loop_limit`] is treated as an
+//!   infinite update loop: the remaining notifications are dropped and an
+//!   [`UpdateLoop`] naming the repeating chain of watchers is reported. A
+//!   single write fanning out to any number of watchers is not a loop.
+//!
+//! [`Order::Reentrant`] restores immediate, nested delivery; the loop limit
+//! then bounds the nesting depth.
+//!
+//! Without the `std` feature notifications are always delivered immediately.
+
+#[cfg(feature = "std")]
+pub use queue::{NotifyConfig, Order, UpdateLoop, configure};
+
+/// Delivers a notification for the watcher `watcher`, running `f`.
+///
+/// Runs `f` right away unless another notification is being delivered on this
+/// thread, in which case it runs after that one, in FIFO order.
+pub fn dispatch(watcher: &'static str, f: impl FnOnce() + 'static) {
+    #[cfg(feature = "std")]
+    queue::dispatch(watcher, alloc::boxed::Box::new(f));
+    #[cfg(not(feature = "std"))]
+    {
+        let _ = watcher;
+        f();
+    }
+}
+
+/// Wraps a watcher callback so that every call goes through [`dispatch`].
+pub fn deferred<T: 'static>(watcher: &'static str, f: impl Fn(T) + 'static) -> impl Fn(T) {
+    let f = alloc::rc::Rc::new(f);
+    move |value| {
+        let f = f.clone();
+        dispatch(watcher, move || f(value));
+    }
+}
+
+#[cfg(feature = "std")]
+mod queue {
+    use alloc::{boxed::Box, collections::VecDeque, rc::Rc, vec::Vec};
+    use core::{cell::RefCell, fmt, mem};
+
+    type Notification = Box<dyn FnOnce()>;
+
+    /// How notifications raised inside a watcher callback are delivered.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+    pub enum Order {
+        /// After the running callback returns, first-in, first-out.
+        #[default]
+        Fifo,
+        /// Immediately, nested inside the running callback.
+        Reentrant,
+    }
+
+    /// An infinite update loop between watchers.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct UpdateLoop {
+        /// The watchers that kept notifying each other, in delivery order.
+        pub chain: Vec<&'static str>,
+    }
+
+    impl fmt::Display for UpdateLoop {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("infinite update loop between watchers: ")?;
+            for watcher in &self.chain {
+                write!(f, "`{watcher}` -> ")?;
+            }
+            match self.chain.first() {
+                Some(first) => write!(f, "`{first}`"),
+                None => f.write_str("(unknown)"),
+            }
+        }
+    }
+
+    /// Configuration for watcher notification delivery.
+    #[derive(Clone)]
+    pub struct NotifyConfig {
+        order: Order,
+        loop_limit: usize,
+        on_update_loop: Rc<dyn Fn(&UpdateLoop)>,
+    }
+
+    impl fmt::Debug for NotifyConfig {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("NotifyConfig")
+                .field("order", &self.order)
+                .field("loop_limit", &self.loop_limit)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl Default for NotifyConfig {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl NotifyConfig {
+        /// How long a chain of notifications raising each other may grow before
+        /// it counts as a loop.
+        pub const DEFAULT_LOOP_LIMIT: usize = 10_000;
+
+        /// Creates the default configuration: FIFO delivery, with update loops
+        /// logged as warnings.
+        #[must_use]
+        pub fn new() -> Self {
+            Self {
+                order: Order::Fifo,
+                loop_limit: Self::DEFAULT_LOOP_LIMIT,
+                on_update_loop: Rc::new(|update_loop| tracing::warn!("{update_loop}")),
+            }
+        }
+
+        /// Sets how nested notifications are delivered.
+        #[must_use]
+        pub const fn order(mut self, order: Order) -> Self {
+            self.order = order;
+            self
+        }
+
+        /// Sets how long a chain of notifications, each raised by the callback
+        /// of the one before it, may grow before it counts as a loop.
+        #[must_use]
+        pub const fn loop_limit(mut self, limit: usize) -> Self {
+            self.loop_limit = limit;
+            self
+        }
+
+        /// Calls `handler` when an update loop is broken instead of printing it.
+        #[must_use]
+        pub fn on_update_loop(mut self, handler: impl Fn(&UpdateLoop) + 'static) -> Self {
+            self.on_update_loop = Rc::new(handler);
+            self
+        }
+    }
+
+    /// A notification and the one whose callback raised it.
+    struct Cause {
+        watcher: &'static str,
+        /// Length of the chain of causes ending here.
+        depth: usize,
+        parent: Option<Rc<Self>>,
+    }
+
+    impl Cause {
+        /// Returns the watchers that led up to this one, oldest first, as far
+        /// back as loop detection looks.
+        fn chain(&self) -> Vec<&'static str> {
+            let mut chain = Vec::new();
+            let mut cause = Some(self);
+            while let Some(current) = cause {
+                if chain.len() == 2 * MAX_PERIOD {
+                    break;
+                }
+                chain.push(current.watcher);
+                cause = current.parent.as_deref();
+            }
+            chain.reverse();
+            chain
+        }
+    }
+
+    impl Drop for Cause {
+        // Unlinks the chain iteratively, as a loop can make it as long as
+        // the loop limit
+        fn drop(&mut self) {
+            let mut parent = self.parent.take();
+            while let Some(cause) = parent {
+                parent = Rc::try_unwrap(cause)
+                    .ok()
+                    .and_then(|mut cause| cause.parent.take());
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct State {
+        config: NotifyConfig,
+        /// Whether a notification is being delivered.
+        running: bool,
+        queue: VecDeque<(Rc<Cause>, Notification)>,
+        /// The notification being delivered.
+        current: Option<Rc<Cause>>,
+        /// Set once a loop is reported, so the rest of the flush is dropped.
+        broken: bool,
+    }
+
+    std::thread_local! {
+        static STATE: RefCell<State> = RefCell::default();
+    }
+
+    /// Configures notification delivery on the current thread.
+    pub fn configure(config: NotifyConfig) {
+        STATE.with_borrow_mut(|state| state.config = config);
+    }
+
+    enum Action {
+        /// Start a flush with this notification.
+        Flush,
+        /// Run the notification nested in the current one.
+        Run(Rc<Cause>),
+        /// Deliver the notification after the current one.
+        Queue(Rc<Cause>),
+        /// Drop the notification, the current flush is broken.
+        Done,
+        /// The notification closes a loop, which is now broken. Carries the
+        /// dropped notifications, released outside the state borrow.
+        Loop(UpdateLoop, VecDeque<(Rc<Cause>, Notification)>),
+    }
+
+    pub(super) fn dispatch(watcher: &'static str, notification: Notification) {
+        let action = STATE.with_borrow_mut(|state| {
+            if state.broken {
+                return Action::Done;
+            }
+            let parent = state.current.clone();
+            let depth = parent.as_ref().map_or(1, |parent| parent.depth + 1);
+            if depth > state.config.loop_limit {
+                state.broken = true;
+                let chain = parent.map(|parent| parent.chain()).unwrap_or_default();
+                let chain = repeating_tail(&chain).to_vec();
+                return Action::Loop(UpdateLoop { chain }, mem::take(&mut state.queue));
+            }
+            let cause = Rc::new(Cause {
+                watcher,
+                depth,
+                parent,
+            });
+            if !state.running {
+                state.running = true;
+                state.current = Some(cause);
+                return Action::Flush;
+            }
+            match state.config.order {
+                Order::Fifo => Action::Queue(cause),
+                Order::Reentrant => Action::Run(cause),
+            }
+        });
+
+        match action {
+            Action::Flush => {
+                let _flush = Flush;
+                notification();
+                while let Some(next) = STATE.with_borrow_mut(|state| {
+                    let (cause, next) = state.queue.pop_front()?;
+                    state.current = Some(cause);
+                    Some(next)
+                }) {
+                    next();
+                }
+            }
+            Action::Run(cause) => {
+                let previous = STATE.with_borrow_mut(|state| state.current.replace(cause));
+                notification();
+                STATE.with_borrow_mut(|state| state.current = previous);
+            }
+            Action::Queue(cause) => {
+                STATE.with_borrow_mut(|state| state.queue.push_back((cause, notification)));
+            }
+            Action::Done => {}
+            Action::Loop(update_loop, dropped) => {
+                drop(dropped);
+                let handler = STATE.with_borrow(|state| state.config.on_update_loop.clone());
+                handler(&update_loop);
+            }
+        }
+    }
+
+    /// Ends a flush when dropped, even if a watcher panicked.
+    struct Flush;
+
+    impl Drop for Flush {
+        fn drop(&mut self) {
+            let dropped = STATE.with_borrow_mut(|state| {
+                state.running = false;
+                state.broken = false;
+                state.current = None;
+                mem::take(&mut state.queue)
+            });
+            drop(dropped);
+        }
+    }
+
+    /// Longest repeating sequence of watchers an [`UpdateLoop`] reports.
+    const MAX_PERIOD: usize = 64;
+
+    /// Returns the shortest sequence repeated at the end of `chain`, or its
+    /// last few entries if nothing repeats.
+    fn repeating_tail<'a>(chain: &'a [&'static str]) -> &'a [&'static str] {
+        let len = chain.len();
+        (1..=MAX_PERIOD.min(len / 2))
+            .find(|&period| chain[len - period..] == chain[len - 2 * period..len - period])
+            .map_or_else(
+                || &chain[len.saturating_sub(16)..],
+                |period| &chain[len - period..],
+            )
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use alloc::{rc::Rc, vec, vec::Vec};
+    use core::cell::{Cell, RefCell};
+
+    use super::*;
+
+    fn recording(config: NotifyConfig) -> Rc<RefCell<Vec<UpdateLoop>>> {
+        let loops = Rc::new(RefCell::new(Vec::new()));
+        let recorded = loops.clone();
+        configure(config.on_update_loop(move |update_loop| {
+            recorded.borrow_mut().push(update_loop.clone());
+        }));
+        loops
+    }
+
+    fn log(log: &Rc<RefCell<Vec<&'static str>>>, entry: &'static str) -> impl FnOnce() + 'static {
+        let log = log.clone();
+        move || log.borrow_mut().push(entry)
+    }
+
+    #[test]
+    fn nested_notifications_are_delivered_in_order() {
+        configure(NotifyConfig::new());
+        let order = Rc::new(RefCell::new(Vec::new()));
+        let inner = order.clone();
+        dispatch("Outer", move || {
+            dispatch("A", log(&inner, "a"));
+            dispatch("B", log(&inner, "b"));
+            inner.borrow_mut().push("outer");
+        });
+        assert_eq!(*order.borrow(), ["outer", "a", "b"]);
+    }
+
+    #[test]
+    fn reentrant_notifications_are_nested() {
+        configure(NotifyConfig::new().order(Order::Reentrant));
+        let order = Rc::new(RefCell::new(Vec::new()));
+        let inner = order.clone();
+        dispatch("Outer", move || {
+            dispatch("A", log(&inner, "a"));
+            inner.borrow_mut().push("outer");
+        });
+        assert_eq!(*order.borrow(), ["a", "outer"]);
+    }
+
+    fn ping(count: Rc<Cell<usize>>) {
+        dispatch("Ping", move || {
+            count.set(count.get() + 1);
+            pong(count);
+        });
+    }
+
+    fn pong(count: Rc<Cell<usize>>) {
+        dispatch("Pong", move || ping(count));
+    }
+
+    #[test]
+    fn update_loops_are_broken_and_reported() {
+        for order in [Order::Fifo, Order::Reentrant] {
+            let loops = recording(NotifyConfig::new().order(order).loop_limit(20));
+            let count = Rc::new(Cell::new(0));
+            ping(count.clone());
+
+            assert_eq!(count.get(), 10, "{order:?}");
+            assert_eq!(
+                *loops.borrow(),
+                [UpdateLoop {
+                    chain: vec!["Ping", "Pong"]
+                }],
+                "{order:?}"
+            );
+        }
+        configure(NotifyConfig::new());
+    }
+
+    #[test]
+    fn large_fan_outs_are_not_loops() {
+        let loops = recording(NotifyConfig::new());
+        let delivered = Rc::new(Cell::new(0));
+        let inner = delivered.clone();
+        dispatch("Reload", move || {
+            for _ in 0..NotifyConfig::DEFAULT_LOOP_LIMIT * 2 {
+                let delivered = inner.clone();
+                dispatch("Row", move || delivered.set(delivered.get() + 1));
+            }
+        });
+
+        assert_eq!(delivered.get(), NotifyConfig::DEFAULT_LOOP_LIMIT * 2);
+        assert!(loops.borrow().is_empty());
+        configure(NotifyConfig::new());
+    }
+}
@@ -0,0 +1,150 @@
+//! The platform a view tree is rendered on.
+//!
+//! Native backends install the [`Platform`] they render for, so shared code
+//! can adapt at runtime instead of through `cfg` attributes. The same binary
+//! may run under more than one backend, and code behind a `cfg` for another
+//! target is not even type-checked on the current one.
+//!
+//! Without an installed value, [`current_platform`] falls back to the
+//! platform the crate was compiled for.
+
+use crate::{Environment, View, extract::Extractor};
+
+/// A platform that a backend renders for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    /// iOS and iPadOS.
+    Ios,
+    /// macOS.
+    Macos,
+    /// Android.
+    Android,
+    /// A web browser.
+    Web,
+    /// Windows.
+    Windows,
+    /// Linux and other Unix desktops.
+    Linux,
+    /// A platform without a dedicated variant.
+    Other,
+}
+
+impl Platform {
+    /// Returns the platform the crate was compiled for.
+    #[must_use]
+    pub const fn compiled() -> Self {
+        if cfg!(target_os = "ios") {
+            Self::Ios
+        } else if cfg!(target_os = "macos") {
+            Self::Macos
+        } else if cfg!(target_os = "android") {
+            Self::Android
+        } else if cfg!(target_arch = "wasm32") {
+            Self::Web
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else if cfg!(target_os = "linux") {
+            Self::Linux
+        } else {
+            Self::Other
+        }
+    }
+
+    /// Returns true for Apple platforms.
+    #[must_use]
+    pub const fn is_apple(self) -> bool {
+        matches!(self, Self::Ios | Self::Macos)
+    }
+
+    /// Returns true for phone and tablet platforms.
+    #[must_use]
+    pub const fn is_mobile(self) -> bool {
+        matches!(self, Self::Ios | Self::Android)
+    }
+
+    /// Returns true for desktop platforms.
+    #[must_use]
+    pub const fn is_desktop(self) -> bool {
+        matches!(self, Self::Macos | Self::Windows | Self::Linux)
+    }
+}
+
+impl Extractor for Platform {
+    /// Extracts the current platform; this never fails.
+    fn extract(env: &Environment) -> Result<Self, crate::Error> {
+        Ok(current_platform(env))
+    }
+}
+
+/// Returns the platform installed by the backend, or the compiled platform.
+#[must_use]
+pub fn current_platform(env: &Environment) -> Platform {
+    env.get::<Platform>()
+        .copied()
+        .unwrap_or_else(Platform::compiled)
+}
+
+/// Installs the platform the backend renders for.
+pub fn install_platform(env: &mut Environment, platform: Platform) {
+    env.insert(platform);
+}
+
+/// A view modified only on one platform.
+///
+/// Created by the platform modifiers of `ViewExt`, such as `.ios(..)`.
+pub struct OnPlatform<V, F> {
+    platform: Platform,
+    content: V,
+    modifier: F,
+}
+
+impl<V, F> core::fmt::Debug for OnPlatform<V, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("OnPlatform")
+            .field("platform", &self.platform)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<V, F> OnPlatform<V, F> {
+    /// Applies `modifier` to `content` when rendered on `platform`.
+    pub const fn new(platform: Platform, content: V, modifier: F) -> Self {
+        Self {
+            platform,
+            content,
+            modifier,
+        }
+    }
+}
+
+impl<V, F, R> View for OnPlatform<V, F>
+where
+    V: View,
+    F: FnOnce(V) -> R + 'static,
+    R: View,
+{
+    fn body(self, env: &Environment) -> impl View {
+        if current_platform(env) == self.platform {
+            crate::AnyView::new((self.modifier)(self.content))
+        } else {
+            crate::AnyView::new(self.content)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn installed_platform_overrides_compiled_one() {
+        let mut env = Environment::new();
+        assert_eq!(current_platform(&env), Platform::compiled());
+
+        install_platform(&mut env, Platform::Android);
+        assert_eq!(current_platform(&env), Platform::Android);
+        assert_eq!(Platform::extract(&env).unwrap(), Platform::Android);
+        assert!(Platform::Android.is_mobile());
+        assert!(!Platform::Web.is_desktop());
+    }
+}
@@ -0,0 +1,206 @@
+//! Live object counters for tracking down leaks.
+//!
+//! In debug builds every [`AnyView`](crate::AnyView) and every handle passed to
+//! native code carries a [`Live`] token that is counted while it exists. A
+//! count that keeps growing, or stays non-zero after the app has torn down its
+//! windows, usually points at a retain cycle between a binding and a closure
+//! that captures it.
+//!
+//! In release builds the tokens are zero-sized and nothing is counted.
+
+use alloc::vec::Vec;
+
+/// The category of a counted object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Kind {
+    /// A type-erased view.
+    View,
+    /// A binding or computed value held by native code.
+    Binding,
+    /// A native watcher subscribed to a signal.
+    Watcher,
+    /// Any other handle passed to native code.
+    FfiHandle,
+}
+
+/// Totals of live objects per [`Kind`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Live type-erased views.
+    pub views: usize,
+    /// Live bindings and computed values held by native code.
+    pub bindings: usize,
+    /// Live native watchers.
+    pub watchers: usize,
+    /// Other live handles held by native code.
+    pub ffi_handles: usize,
+}
+
+/// The number of live objects of one type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiveCount {
+    /// The category of the objects.
+    pub kind: Kind,
+    /// The type name of the objects.
+    pub name: &'static str,
+    /// How many of them are alive.
+    pub count: usize,
+}
+
+/// A token that counts as one live object until it is dropped.
+///
+/// Embed it in the object to be counted.
+#[derive(Debug)]
+pub struct Live {
+    #[cfg(debug_assertions)]
+    kind: Kind,
+    #[cfg(debug_assertions)]
+    name: &'static str,
+}
+
+impl Live {
+    /// Counts a new live object of type `name`.
+    #[must_use]
+    #[cfg_attr(not(debug_assertions), allow(clippy::missing_const_for_fn))]
+    pub fn new(kind: Kind, name: &'static str) -> Self {
+        #[cfg(debug_assertions)]
+        {
+            registry::update(kind, name, |count| count + 1);
+            Self { kind, name }
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            let _ = (kind, name);
+            Self {}
+        }
+    }
+
+    /// Counts a new live object of type `T`.
+    #[must_use]
+    pub fn of<T: ?Sized>(kind: Kind) -> Self {
+        Self::new(kind, core::any::type_name::<T>())
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for Live {
+    fn drop(&mut self) {
+        registry::update(self.kind, self.name, |count| count.saturating_sub(1));
+    }
+}
+
+/// Returns the totals of live objects.
+///
+/// Always zero in release builds.
+#[must_use]
+pub fn stats() -> Stats {
+    let mut stats = Stats::default();
+    for live in live_counts() {
+        let total = match live.kind {
+            Kind::View => &mut stats.views,
+            Kind::Binding => &mut stats.bindings,
+            Kind::Watcher => &mut stats.watchers,
+            Kind::FfiHandle => &mut stats.ffi_handles,
+        };
+        *total += live.count;
+    }
+    stats
+}
+
+/// Returns the number of live objects per type, skipping types with none alive.
+///
+/// Always empty in release builds.
+#[must_use]
+#[cfg_attr(not(debug_assertions), allow(clippy::missing_const_for_fn))]
+pub fn live_counts() -> Vec<LiveCount> {
+    #[cfg(debug_assertions)]
+    {
+        registry::snapshot()
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg(debug_assertions)]
+mod registry {
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
+    use core::cell::UnsafeCell;
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    use super::{Kind, LiveCount};
+
+    type Counts = BTreeMap<(Kind, &'static str), usize>;
+
+    /// A spin-locked map; `core` has no mutex and the lock is held only for a lookup.
+    struct Registry {
+        locked: AtomicBool,
+        counts: UnsafeCell<Counts>,
+    }
+
+    // SAFETY: `counts` is only accessed while `locked` is held.
+    unsafe impl Sync for Registry {}
+
+    static REGISTRY: Registry = Registry {
+        locked: AtomicBool::new(false),
+        counts: UnsafeCell::new(BTreeMap::new()),
+    };
+
+    fn with_counts<R>(f: impl FnOnce(&mut Counts) -> R) -> R {
+        while REGISTRY
+            .locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        // SAFETY: the lock is held until the end of this function.
+        let result = f(unsafe { &mut *REGISTRY.counts.get() });
+        REGISTRY.locked.store(false, Ordering::Release);
+        result
+    }
+
+    pub fn update(kind: Kind, name: &'static str, f: impl FnOnce(usize) -> usize) {
+        with_counts(|counts| {
+            let count = counts.entry((kind, name)).or_default();
+            *count = f(*count);
+        });
+    }
+
+    pub fn snapshot() -> Vec<LiveCount> {
+        with_counts(|counts| {
+            counts
+                .iter()
+                .filter(|(_, count)| **count > 0)
+                .map(|(&(kind, name), &count)| LiveCount { kind, name, count })
+                .collect()
+        })
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use super::*;
+
+    struct Probe;
+
+    #[test]
+    fn counts_live_tokens_per_type() {
+        let count = || {
+            live_counts()
+                .into_iter()
+                .find(|live| live.name == core::any::type_name::<Probe>())
+                .map_or(0, |live| live.count)
+        };
+
+        let first = Live::of::<Probe>(Kind::Watcher);
+        let second = Live::of::<Probe>(Kind::Watcher);
+        assert_eq!(count(), 2);
+        drop(first);
+        assert_eq!(count(), 1);
+        drop(second);
+        assert_eq!(count(), 0);
+    }
+}
@@ -0,0 +1,390 @@
+//! Runtime checks for reactive anti-patterns.
+//!
+//! These bugs rarely fail loudly; they show up as views that update in a loop
+//! or never settle. Strict mode is off by default. Once [`enable`]d on a
+//! thread, it reports a [`Violation`] when:
+//!
+//! - a watcher is notified while a view's `body()` is running, which means a
+//!   signal was written during body evaluation
+//! - a binding is handed to native code from inside a watcher callback, which
+//!   usually leaks a new binding on every update
+//! - a watcher callback runs longer than [`StrictMode::slow_watcher`]
+//! - a signal is peeked while a view's `body()` is running, outside of an
+//!   [`untracked_scope`], so the view shows a value that never updates
+//!
+//! Only watchers owned by `WaterUI` are observed: those behind
+//! [`Dynamic::watch`](crate::Dynamic::watch) and the ones native backends
+//! register through the FFI.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::time::Duration;
+//! use waterui_core::strict::{self, StrictMode};
+//!
+//! strict::enable(
+//!     StrictMode::new()
+//!         .slow_watcher(Duration::from_millis(4))
+//!         .on_violation(|violation| panic!("{violation}")),
+//! );
+//! ```
+
+use alloc::{rc::Rc, vec::Vec};
+use core::{cell::RefCell, fmt, time::Duration};
+use std::time::Instant;
+
+/// A reactive anti-pattern detected by strict mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// A watcher was notified while a view's body was being computed.
+    WriteDuringBody {
+        /// The view whose body was running.
+        view: &'static str,
+        /// The watcher that was notified.
+        watcher: &'static str,
+    },
+    /// A binding was handed to native code from inside a watcher callback.
+    BindingInWatcher {
+        /// The type of the binding.
+        binding: &'static str,
+        /// The watcher that was running.
+        watcher: &'static str,
+    },
+    /// A signal was peeked while a view's body was being computed.
+    PeekDuringBody {
+        /// The view whose body was running.
+        view: &'static str,
+        /// The type of the signal.
+        signal: &'static str,
+    },
+    /// A watcher callback took longer than the configured threshold.
+    SlowWatcher {
+        /// The watcher that was running.
+        watcher: &'static str,
+        /// How long the callback took.
+        elapsed: Duration,
+    },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WriteDuringBody { view, watcher } => write!(
+                f,
+                "a signal was written while computing the body of `{view}` (notified `{watcher}`); \
+                 move the write into an event handler or task"
+            ),
+            Self::BindingInWatcher { binding, watcher } => write!(
+                f,
+                "`{binding}` was created inside the watcher `{watcher}`; \
+                 create it once outside the watcher and reuse it"
+            ),
+            Self::PeekDuringBody { view, signal } => write!(
+                f,
+                "`{signal}` was peeked while computing the body of `{view}`, which will not \
+                 update when it changes; pass the signal to the view, or read it in \
+                 `untracked` if a snapshot is intended"
+            ),
+            Self::SlowWatcher { watcher, elapsed } => write!(
+                f,
+                "the watcher `{watcher}` took {elapsed:?}; move heavy work off the update path"
+            ),
+        }
+    }
+}
+
+/// Configuration for strict mode.
+#[derive(Clone)]
+pub struct StrictMode {
+    slow_watcher: Duration,
+    handler: Rc<dyn Fn(&Violation)>,
+}
+
+impl fmt::Debug for StrictMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StrictMode")
+            .field("slow_watcher", &self.slow_watcher)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for StrictMode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StrictMode {
+    /// Watchers taking longer than this by default are reported: half a 60 Hz frame.
+    pub const DEFAULT_SLOW_WATCHER: Duration = Duration::from_millis(8);
+
+    /// Creates the default configuration, which logs violations as warnings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            slow_watcher: Self::DEFAULT_SLOW_WATCHER,
+            handler: Rc::new(|violation| tracing::warn!("strict mode: {violation}")),
+        }
+    }
+
+    /// Reports watcher callbacks that take longer than `threshold`.
+    #[must_use]
+    pub const fn slow_watcher(mut self, threshold: Duration) -> Self {
+        self.slow_watcher = threshold;
+        self
+    }
+
+    /// Calls `handler` for each violation instead of printing it.
+    #[must_use]
+    pub fn on_violation(mut self, handler: impl Fn(&Violation) + 'static) -> Self {
+        self.handler = Rc::new(handler);
+        self
+    }
+}
+
+#[derive(Default)]
+struct State {
+    config: Option<StrictMode>,
+    bodies: Vec<&'static str>,
+    watchers: Vec<&'static str>,
+    untracked: usize,
+}
+
+std::thread_local! {
+    static STATE: RefCell<State> = RefCell::default();
+}
+
+/// Enables strict mode on the current thread.
+pub fn enable(config: StrictMode) {
+    STATE.with_borrow_mut(|state| state.config = Some(config));
+}
+
+/// Disables strict mode on the current thread.
+pub fn disable() {
+    STATE.with_borrow_mut(|state| state.config = None);
+}
+
+/// Returns `true` if strict mode is enabled on the current thread.
+#[must_use]
+pub fn is_enabled() -> bool {
+    STATE.with_borrow(|state| state.config.is_some())
+}
+
+fn report(violation: &Violation) {
+    // Release the state before calling out, the handler may use strict mode too
+    let handler = STATE.with_borrow(|state| state.config.as_ref().map(|c| c.handler.clone()));
+    if let Some(handler) = handler {
+        handler(violation);
+    }
+}
+
+/// What a [`Scope`] tracks.
+#[derive(Debug)]
+enum ScopeKind {
+    Inactive,
+    Body,
+    Untracked,
+    Watcher {
+        name: &'static str,
+        started: Instant,
+    },
+}
+
+/// Marks a body evaluation or watcher callback until it is dropped.
+///
+/// Created by [`body_scope`], [`watcher_scope`] and [`untracked_scope`].
+#[derive(Debug)]
+#[must_use]
+pub struct Scope(ScopeKind);
+
+/// Marks the evaluation of the body of `view`.
+///
+/// Renderers call this around `View::body`.
+pub fn body_scope(view: &'static str) -> Scope {
+    STATE.with_borrow_mut(|state| {
+        if state.config.is_none() {
+            return Scope(ScopeKind::Inactive);
+        }
+        state.bodies.push(view);
+        Scope(ScopeKind::Body)
+    })
+}
+
+/// Marks the callback of the watcher `name`.
+///
+/// Reports a violation if a body is being evaluated, and another one on drop if
+/// the callback was slow.
+pub fn watcher_scope(name: &'static str) -> Scope {
+    let view = STATE.with_borrow_mut(|state| {
+        state.config.as_ref()?;
+        state.watchers.push(name);
+        Some(state.bodies.last().copied())
+    });
+    let Some(view) = view else {
+        return Scope(ScopeKind::Inactive);
+    };
+    if let Some(view) = view {
+        report(&Violation::WriteDuringBody {
+            view,
+            watcher: name,
+        });
+    }
+    Scope(ScopeKind::Watcher {
+        name,
+        started: Instant::now(),
+    })
+}
+
+/// Records that a binding of type `binding` is being handed to native code.
+pub fn binding_created(binding: &'static str) {
+    let watcher = STATE.with_borrow(|state| {
+        state
+            .config
+            .as_ref()
+            .and_then(|_| state.watchers.last().copied())
+    });
+    if let Some(watcher) = watcher {
+        report(&Violation::BindingInWatcher { binding, watcher });
+    }
+}
+
+/// Marks code that reads snapshots of signals on purpose.
+///
+/// Peeking a signal inside this scope is not reported, even during a body.
+pub fn untracked_scope() -> Scope {
+    STATE.with_borrow_mut(|state| {
+        if state.config.is_none() {
+            return Scope(ScopeKind::Inactive);
+        }
+        state.untracked += 1;
+        Scope(ScopeKind::Untracked)
+    })
+}
+
+/// Records that a signal of type `signal` is being read as a snapshot.
+///
+/// Reports a violation if a body is being evaluated outside of an
+/// [`untracked_scope`].
+pub fn peeked(signal: &'static str) {
+    let view = STATE.with_borrow(|state| {
+        state.config.as_ref()?;
+        if state.untracked > 0 {
+            return None;
+        }
+        state.bodies.last().copied()
+    });
+    if let Some(view) = view {
+        report(&Violation::PeekDuringBody { view, signal });
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        match self.0 {
+            ScopeKind::Inactive => {}
+            ScopeKind::Body => STATE.with_borrow_mut(|state| {
+                state.bodies.pop();
+            }),
+            ScopeKind::Untracked => STATE.with_borrow_mut(|state| {
+                state.untracked -= 1;
+            }),
+            ScopeKind::Watcher { name, started } => {
+                let threshold = STATE.with_borrow_mut(|state| {
+                    state.watchers.pop();
+                    state.config.as_ref().map(|config| config.slow_watcher)
+                });
+                let elapsed = started.elapsed();
+                if threshold.is_some_and(|threshold| elapsed > threshold) {
+                    report(&Violation::SlowWatcher {
+                        watcher: name,
+                        elapsed,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording() -> Rc<RefCell<Vec<Violation>>> {
+        let violations = Rc::new(RefCell::new(Vec::new()));
+        let recorded = violations.clone();
+        enable(
+            StrictMode::new()
+                .slow_watcher(Duration::from_secs(10))
+                .on_violation(move |violation| recorded.borrow_mut().push(violation.clone())),
+        );
+        violations
+    }
+
+    #[test]
+    fn reports_watchers_notified_during_body() {
+        let violations = recording();
+        {
+            let _body = body_scope("Counter");
+            let _watcher = watcher_scope("Text");
+        }
+        let _watcher = watcher_scope("Text");
+        disable();
+
+        assert_eq!(
+            *violations.borrow(),
+            [Violation::WriteDuringBody {
+                view: "Counter",
+                watcher: "Text"
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_bindings_created_in_watchers() {
+        let violations = recording();
+        binding_created("Binding<i32>");
+        {
+            let _watcher = watcher_scope("List");
+            binding_created("Binding<i32>");
+        }
+        disable();
+
+        assert_eq!(
+            *violations.borrow(),
+            [Violation::BindingInWatcher {
+                binding: "Binding<i32>",
+                watcher: "List"
+            }]
+        );
+    }
+
+    #[test]
+    fn reports_peeks_during_body_outside_untracked() {
+        let violations = recording();
+        peeked("Binding<i32>");
+        {
+            let _body = body_scope("Counter");
+            {
+                let _untracked = untracked_scope();
+                peeked("Binding<i32>");
+            }
+            peeked("Binding<i32>");
+        }
+        disable();
+
+        assert_eq!(
+            *violations.borrow(),
+            [Violation::PeekDuringBody {
+                view: "Counter",
+                signal: "Binding<i32>"
+            }]
+        );
+    }
+
+    #[test]
+    fn does_nothing_when_disabled() {
+        let _body = body_scope("Counter");
+        let _watcher = watcher_scope("Text");
+        assert!(!is_enabled());
+    }
+}
@@ -0,0 +1,149 @@
+//! Dates, times and durations shared by all of `WaterUI`.
+//!
+//! Components use these types instead of their own millisecond integers or
+//! floating-point seconds:
+//!
+//! - [`Duration`] is a non-negative span of time, used for animations,
+//!   delays, timers and gestures. It is [`core::time::Duration`].
+//! - [`SignedDuration`] is the difference between two points in time, which
+//!   is negative when the second one comes first.
+//! - [`Date`], [`Time`], [`PrimitiveDateTime`] and [`OffsetDateTime`] are
+//!   calendar values from the [`time`](::time) crate, used by pickers, clocks
+//!   and relative-time text.
+//!
+//! All of them work without `std` and are serializable with the `serde`
+//! feature. [`TimeSignalExt`] does arithmetic on signals of them, and the
+//! locale module of `waterui-text` formats them for display.
+//!
+//! Backends receive durations as whole milliseconds through
+//! [`saturating_millis`] and report them as seconds, read back with
+//! [`duration_from_secs_f32`].
+
+use core::ops::{Add, Sub};
+
+pub use ::time::{
+    Date, Duration as SignedDuration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset,
+    Weekday,
+};
+pub use core::time::Duration;
+
+use nami::{Computed, Signal, SignalExt, signal::IntoComputed};
+
+/// Returns `duration` in whole milliseconds, saturating at `u64::MAX`.
+#[must_use]
+pub fn saturating_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Returns the duration of `seconds`, reported by a backend.
+///
+/// Negative and NaN values become zero; values too large for a [`Duration`]
+/// saturate.
+#[must_use]
+pub fn duration_from_secs_f32(seconds: f32) -> Duration {
+    if seconds.is_nan() || seconds <= 0.0 {
+        return Duration::ZERO;
+    }
+    Duration::try_from_secs_f32(seconds).unwrap_or(Duration::MAX)
+}
+
+/// Returns `duration` as a [`SignedDuration`], saturating at its maximum.
+#[must_use]
+pub fn signed(duration: Duration) -> SignedDuration {
+    SignedDuration::try_from(duration).unwrap_or(SignedDuration::MAX)
+}
+
+/// Arithmetic on signals of dates, times and durations.
+///
+/// Each method returns a signal that is recomputed when either operand
+/// changes:
+///
+/// ```ignore
+/// let start = binding(OffsetDateTime::now_utc());
+/// let length = binding(Duration::from_secs(25 * 60));
+/// let end = start.clone().add_duration(length);
+/// let remaining = end.duration_since(coarse_clock());
+/// ```
+pub trait TimeSignalExt: Signal + Clone + 'static
+where
+    Self::Output: Clone + 'static,
+{
+    /// Returns this value moved forward by `duration`.
+    fn add_duration(self, duration: impl IntoComputed<Duration>) -> Computed<Self::Output>
+    where
+        Self::Output: Add<Duration, Output = Self::Output>,
+    {
+        self.zip(duration.into_computed())
+            .map(|(value, duration)| value + duration)
+            .computed()
+    }
+
+    /// Returns this value moved back by `duration`.
+    fn sub_duration(self, duration: impl IntoComputed<Duration>) -> Computed<Self::Output>
+    where
+        Self::Output: Sub<Duration, Output = Self::Output>,
+    {
+        self.zip(duration.into_computed())
+            .map(|(value, duration)| value - duration)
+            .computed()
+    }
+
+    /// Returns how much later this value is than `earlier`.
+    ///
+    /// The result is negative when `earlier` is actually later.
+    fn duration_since(self, earlier: impl IntoComputed<Self::Output>) -> Computed<SignedDuration>
+    where
+        Self::Output: Sub<Output = SignedDuration>,
+    {
+        self.zip(earlier.into_computed())
+            .map(|(value, earlier)| value - earlier)
+            .computed()
+    }
+}
+
+impl<S> TimeSignalExt for S
+where
+    S: Signal + Clone + 'static,
+    S::Output: Clone + 'static,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use nami::Binding;
+
+    use super::*;
+
+    #[test]
+    fn arithmetic_follows_both_operands() {
+        let day = Date::from_calendar_date(2024, Month::January, 30).unwrap();
+        let date = Binding::container(day);
+        let offset = Binding::container(Duration::from_secs(2 * 24 * 60 * 60));
+        let later = date.clone().add_duration(offset.clone());
+        assert_eq!(
+            later.get(),
+            Date::from_calendar_date(2024, Month::February, 1).unwrap()
+        );
+
+        offset.set(Duration::ZERO);
+        assert_eq!(later.get(), day);
+        date.set(day.next_day().unwrap());
+        assert_eq!(
+            later.clone().duration_since(Computed::constant(day)).get(),
+            SignedDuration::days(1)
+        );
+        assert_eq!(
+            Computed::constant(day).duration_since(later).get(),
+            SignedDuration::days(-1)
+        );
+    }
+
+    #[test]
+    fn converts_backend_durations() {
+        assert_eq!(duration_from_secs_f32(f32::NAN), Duration::ZERO);
+        assert_eq!(duration_from_secs_f32(-1.0), Duration::ZERO);
+        assert_eq!(duration_from_secs_f32(0.5), Duration::from_millis(500));
+        assert_eq!(saturating_millis(Duration::MAX), u64::MAX);
+        assert_eq!(signed(Duration::from_secs(3)), SignedDuration::seconds(3));
+    }
+}
@@ -1,6 +1,8 @@
 use std::time::Instant;
 
 use waterui::app::App;
+use waterui::display::current_display_capabilities;
+use waterui::env::use_env;
 use waterui::graphics::{GpuContext, GpuFrame, GpuRenderer, GpuSurface, bytemuck, wgpu};
 use waterui::prelude::*;
 
@@ -8,7 +10,11 @@ fn main() -> impl View {
     vstack((
         text("Cinematic HDR Flame (GpuSurface)").size(24),
         text("HDR film buffer + bloom + ACES tonemap").size(14),
-        GpuSurface::new(FlameRenderer::default()).size(400.0, 500.0),
+        use_env(|env: Environment| {
+            // Push highlights as far as the display can currently show
+            let max_headroom = current_display_capabilities(&env).get().max_headroom;
+            GpuSurface::new(FlameRenderer::new(max_headroom)).size(400.0, 500.0)
+        }),
         text("Rendered at 120fps").size(12),
     ))
     .padding()
@@ -376,6 +382,7 @@ struct FlameRenderer {
     blur_y_bind_group: Option<wgpu::BindGroup>,
 
     size: (u32, u32),
+    max_headroom: f32,
 }
 
 impl Default for FlameRenderer {
@@ -408,11 +415,19 @@ impl Default for FlameRenderer {
             blur_y_bind_group: None,
 
             size: (0, 0),
+            max_headroom: 0.0,
         }
     }
 }
 
 impl FlameRenderer {
+    fn new(max_headroom: f32) -> Self {
+        Self {
+            max_headroom,
+            ..Self::default()
+        }
+    }
+
     const FILM_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
     const GLOBALS_SIZE: u64 = std::mem::size_of::<[f32; 12]>() as u64;
     const BLUR_PARAMS_SIZE: u64 = std::mem::size_of::<[f32; 4]>() as u64;
@@ -990,7 +1005,7 @@ impl GpuRenderer for FlameRenderer {
         let (w, h) = (frame.width as f32, frame.height as f32);
 
         // HDR tuning: bigger highlight range + tighter bloom (to keep detail).
-        let edr_gain = if is_hdr { 6.0 } else { 1.0 };
+        let edr_gain = if is_hdr { 1.0 + self.max_headroom } else { 1.0 };
         let bloom_intensity = if is_hdr { 2.2 } else { 1.0 };
         let bloom_threshold = if is_hdr { 2.2 } else { 1.0 };
         let bloom_radius = if is_hdr { 2.2 } else { 1.6 };
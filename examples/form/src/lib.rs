@@ -4,6 +4,7 @@
 //! - The `#[form]` derive macro for automatic form generation
 //! - Various form field types (text, bool, numeric, slider)
 //! - Reactive data binding with live preview
+//! - Settings persisted across launches with `#[form(persist = "...")]`
 //! - Manual form control composition
 use waterui::app::App;
 use waterui::prelude::*;
@@ -27,8 +28,8 @@ struct RegistrationForm {
     volume: f64,
 }
 
-// Settings form demonstrating different field types
-#[form]
+// Settings form demonstrating different field types, persisted across launches
+#[form(persist = "app_settings")]
 struct AppSettings {
     /// Application theme brightness
     brightness: f64,
@@ -137,7 +138,7 @@ fn main(settings: &Binding<AppSettings>) -> impl View {
 }
 
 pub fn app(mut env: Environment) -> App {
-    let settings = AppSettings::binding();
+    let settings = AppSettings::stored(&env);
 
     // Install theme before creating App
     let theme =
@@ -156,7 +157,7 @@ pub fn app(mut env: Environment) -> App {
 
     env.install(theme);
 
-    App::new(main(&settings), env)
+    App::new(main(&settings).retain(settings.clone()), env)
 }
 
 waterui_ffi::export!();
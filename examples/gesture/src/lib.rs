@@ -11,6 +11,7 @@ use waterui::app::App;
 use waterui::gesture::{DragGesture, LongPressGesture, TapGesture};
 use waterui::prelude::*;
 use waterui::reactive::Binding;
+use waterui::time::Duration;
 
 /// Section displaying tap gesture demos
 fn tap_section(tap_count: Binding<i32>) -> impl View {
@@ -60,9 +61,12 @@ fn long_press_section(long_press_count: Binding<i32>) -> impl View {
             text("Long Press Me!")
                 .padding()
                 .background(Color::srgb_hex("#FF9800").with_opacity(0.3))
-                .gesture(LongPressGesture::new(500), move || {
-                    long_press_count.set(long_press_count.get() + 1);
-                })
+                .gesture(
+                    LongPressGesture::new(Duration::from_millis(500)),
+                    move || {
+                        long_press_count.set(long_press_count.get() + 1);
+                    },
+                )
         },
         hstack(("Long press count: ", waterui::text!("{}", long_press_count))),
     ))
@@ -101,7 +105,8 @@ fn chained_section(chained_status: Binding<String>) -> impl View {
                 .padding()
                 .background(Color::srgb_hex("#F44336").with_opacity(0.3))
                 .gesture(
-                    TapGesture::new().then(LongPressGesture::new(300).into()),
+                    TapGesture::new()
+                        .then(LongPressGesture::new(Duration::from_millis(300)).into()),
                     move || {
                         chained_status.set("Chained gesture completed!".to_string());
                     },
@@ -13,9 +13,10 @@ use waterui::media::media_picker::{MediaFilter, MediaPicker, Selected};
 use waterui::prelude::*;
 use waterui::reactive::binding;
 use waterui::task::spawn_local;
+use waterui::widget::condition::match_signal;
 
 /// Combined state for the media display area
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Project)]
 enum DisplayState {
     Empty,
     Loading,
@@ -116,10 +117,18 @@ fn picker_button(
         })
 }
 
-/// Displays the loaded media or a placeholder - single Dynamic::watch
+/// Displays the loaded media or a placeholder.
+///
+/// The view is rebuilt only when the variant changes; the payload bindings
+/// update the loaded media and error message in place.
 fn media_display_area(display_state: Binding<DisplayState>) -> impl View {
-    Dynamic::watch(display_state, move |state| match state {
-        DisplayState::Empty => vstack((
+    let DisplayStateProjected {
+        kind,
+        loaded,
+        error,
+    } = display_state.project();
+    match_signal(kind, move |kind| match kind {
+        DisplayStateKind::Empty => vstack((
             text("No media selected")
                 .size(18.0)
                 .foreground(theme_color::MutedForeground),
@@ -130,23 +139,30 @@ fn media_display_area(display_state: Binding<DisplayState>) -> impl View {
         .spacing(8.0)
         .anyview(),
 
-        DisplayState::Loading => vstack((
+        DisplayStateKind::Loading => vstack((
             loading(),
             text("Loading media...").foreground(theme_color::MutedForeground),
         ))
         .spacing(12.0)
         .anyview(),
 
-        DisplayState::Loaded(media) => media_view(media),
+        DisplayStateKind::Loaded => Dynamic::watch(loaded.clone(), |media: Option<Media>| {
+            media.map_or_else(AnyView::default, media_view)
+        })
+        .anyview(),
 
-        DisplayState::Error(message) => vstack((
+        DisplayStateKind::Error => vstack((
             text("Error")
                 .size(18.0)
                 .bold()
                 .foreground(theme_color::Accent),
-            text(message)
-                .size(14.0)
-                .foreground(theme_color::MutedForeground),
+            text(
+                error
+                    .clone()
+                    .map(|message| Str::from(message.unwrap_or_default())),
+            )
+            .size(14.0)
+            .foreground(theme_color::MutedForeground),
         ))
         .spacing(8.0)
         .padding_with(16.0)
@@ -0,0 +1,89 @@
+//! Theme Editor Example - Demonstrates runtime theme editing and serialization
+//!
+//! This example showcases:
+//! - A `ThemeSnapshot` binding driving the installed theme via `Theme::bind`
+//! - The built-in `ThemeEditor` widget
+//! - Live JSON output suitable for persisting a customized theme
+use waterui::app::App;
+use waterui::color::{ResolvedColor, Srgb};
+use waterui::prelude::*;
+use waterui::reactive::binding;
+use waterui::text::font::{FontWeight, ResolvedFont};
+use waterui::theme::{ColorSnapshot, FontSnapshot, ThemeSnapshot, theme_editor};
+
+fn resolved(hex: &str) -> ResolvedColor {
+    Srgb::from_hex(hex).into()
+}
+
+/// Every slot is set up front so `Theme::bind` makes all of them editable.
+fn initial_snapshot() -> ThemeSnapshot {
+    ThemeSnapshot {
+        color_scheme: Some(ColorScheme::Light),
+        colors: ColorSnapshot {
+            background: Some(resolved("#FFFFFF")),
+            surface: Some(resolved("#F5F5F5")),
+            surface_variant: Some(resolved("#EEEEEE")),
+            border: Some(resolved("#E0E0E0")),
+            foreground: Some(resolved("#212121")),
+            muted_foreground: Some(resolved("#757575")),
+            accent: Some(resolved("#2196F3")),
+            accent_foreground: Some(resolved("#FFFFFF")),
+            destructive: Some(resolved("#D32F2F")),
+        },
+        fonts: FontSnapshot {
+            body: Some(ResolvedFont::new(17.0, FontWeight::Normal)),
+            title: Some(ResolvedFont::new(28.0, FontWeight::Bold)),
+            headline: Some(ResolvedFont::new(17.0, FontWeight::SemiBold)),
+            subheadline: Some(ResolvedFont::new(15.0, FontWeight::Normal)),
+            caption: Some(ResolvedFont::new(12.0, FontWeight::Normal)),
+            footnote: Some(ResolvedFont::new(13.0, FontWeight::Normal)),
+        },
+        spacing: Some(10.0),
+    }
+}
+
+fn preview() -> impl View {
+    card(vstack((
+        text("The quick brown fox").font(font::Body),
+        text("jumps over the lazy dog").foreground(theme_color::MutedForeground),
+        text("Accent")
+            .foreground(theme_color::AccentForeground)
+            .padding()
+            .background(Color::new(theme_color::Accent)),
+    )))
+    .title("Preview")
+    .padding()
+    .background(Color::new(theme_color::Surface))
+}
+
+fn main(snapshot: &Binding<ThemeSnapshot>) -> impl View {
+    let json = snapshot
+        .clone()
+        .map(|s: ThemeSnapshot| s.to_json().unwrap_or_default());
+
+    scroll(
+        vstack((
+            text("Theme Editor").size(28.0),
+            "Edit the theme below; the whole app restyles as you go.",
+            Divider,
+            preview(),
+            Divider,
+            theme_editor(snapshot),
+            Divider,
+            text("JSON").bold(),
+            text(json).size(12.0),
+        ))
+        .padding_with(EdgeInsets::all(16.0)),
+    )
+}
+
+pub fn app(mut env: Environment) -> App {
+    let snapshot = binding(initial_snapshot());
+
+    // Install theme before creating App
+    env.install(Theme::bind(&snapshot));
+
+    App::new(main(&snapshot), env)
+}
+
+waterui_ffi::export!();
@@ -1,4 +1,5 @@
-use waterui::animation::Animation;
+use waterui::animation::{Animation, Transition};
+use waterui::time::saturating_millis;
 
 use crate::reactive::WuiWatcherMetadata;
 
@@ -8,12 +9,17 @@ use super::IntoFFI;
 pub enum WuiAnimation {
     Default,
     None,
+    /// A spring; read its parameters with `waterui_get_spring`.
+    Spring,
 }
 
 impl IntoFFI for Animation {
     type FFI = WuiAnimation;
     fn into_ffi(self) -> Self::FFI {
-        WuiAnimation::Default
+        match self {
+            Animation::Spring { .. } => WuiAnimation::Spring,
+            _ => WuiAnimation::Default,
+        }
     }
 }
 
@@ -26,3 +32,77 @@ unsafe extern "C" fn waterui_get_animation(metadata: *const WuiWatcherMetadata)
             .unwrap_or(WuiAnimation::None)
     }
 }
+
+/// Spring parameters, matching the mass/stiffness/damping initializers of
+/// native spring APIs.
+#[repr(C)]
+pub struct WuiSpring {
+    /// Mass of the moving object; always 1, stiffness and damping are scaled to it.
+    pub mass: f32,
+    /// Stiffness of the spring.
+    pub stiffness: f32,
+    /// Damping force per unit of velocity.
+    pub damping: f32,
+}
+
+/// Returns the spring attached to an update, or all zeros if the update is
+/// not animated with a spring.
+#[unsafe(no_mangle)]
+unsafe extern "C" fn waterui_get_spring(metadata: *const WuiWatcherMetadata) -> WuiSpring {
+    let spring = unsafe { (*metadata).try_get::<Animation>() };
+    match spring {
+        Some(Animation::Spring { stiffness, damping }) => WuiSpring {
+            mass: 1.0,
+            stiffness,
+            damping,
+        },
+        _ => WuiSpring {
+            mass: 0.0,
+            stiffness: 0.0,
+            damping: 0.0,
+        },
+    }
+}
+
+/// Kind of transition attached to a view update.
+#[repr(C)]
+pub enum WuiTransitionKind {
+    /// Swap the content instantly.
+    None,
+    /// Fade the old content out while the new content fades in.
+    CrossFade,
+}
+
+/// Transition the native backend should apply when swapping dynamic content.
+#[repr(C)]
+pub struct WuiTransition {
+    /// The kind of transition.
+    pub kind: WuiTransitionKind,
+    /// Duration of the transition in milliseconds.
+    pub duration_ms: u64,
+}
+
+impl IntoFFI for Transition {
+    type FFI = WuiTransition;
+    fn into_ffi(self) -> Self::FFI {
+        match self {
+            Transition::CrossFade(duration) => WuiTransition {
+                kind: WuiTransitionKind::CrossFade,
+                duration_ms: saturating_millis(duration),
+            },
+        }
+    }
+}
+
+#[unsafe(no_mangle)]
+unsafe extern "C" fn waterui_get_transition(metadata: *const WuiWatcherMetadata) -> WuiTransition {
+    unsafe {
+        (*metadata)
+            .try_get::<Transition>()
+            .map(IntoFFI::into_ffi)
+            .unwrap_or(WuiTransition {
+                kind: WuiTransitionKind::None,
+                duration_ms: 0,
+            })
+    }
+}
@@ -6,6 +6,7 @@ use core::{
 use alloc::{boxed::Box, vec::Vec};
 
 use super::{IntoFFI, IntoRust};
+use crate::error::{VALIDATE, WuiConversionError};
 
 /// A type alias representing binary data as a byte array.
 pub type WuiData = WuiArray<u8>;
@@ -34,6 +35,37 @@ pub struct WuiArraySlice<T> {
     len: usize,
 }
 
+impl<T> WuiArraySlice<T> {
+    /// Checks that the pointer and length describe a slice Rust can borrow.
+    fn validate(&self) -> Result<(), WuiConversionError> {
+        if self.len == 0 {
+            return Ok(());
+        }
+        if self.head.is_null() {
+            return Err(WuiConversionError::NullPointer { len: self.len });
+        }
+        if !self.head.is_aligned() {
+            return Err(WuiConversionError::Misaligned {
+                align: core::mem::align_of::<T>(),
+            });
+        }
+        let max_len = isize::MAX as usize / core::mem::size_of::<T>().max(1);
+        if self.len > max_len {
+            return Err(WuiConversionError::TooLong { len: self.len });
+        }
+        Ok(())
+    }
+
+    /// Returns the slice descriptor, panicking on malformed data when validation is enabled.
+    fn checked(self) -> Self {
+        if VALIDATE {
+            self.validate()
+                .unwrap_or_else(|error| panic!("malformed WuiArray from native code: {error}"));
+        }
+        self
+    }
+}
+
 impl<T> WuiArrayVTable<T> {
     pub const fn from_raw(
         drop: unsafe extern "C" fn(*mut ()),
@@ -106,17 +138,39 @@ impl<T> WuiArray<T> {
 
     pub fn as_slice(&self) -> &[T] {
         unsafe {
-            let slice = (self.vtable.slice)(self.data.as_ptr());
+            let slice = (self.vtable.slice)(self.data.as_ptr()).checked();
+            if slice.len == 0 {
+                return &[];
+            }
             &*slice_from_raw_parts(slice.head, slice.len)
         }
     }
 
     pub fn as_mut_slice(&mut self) -> &mut [T] {
         unsafe {
-            let slice = (self.vtable.slice)(self.data.as_ptr());
+            let slice = (self.vtable.slice)(self.data.as_ptr()).checked();
+            if slice.len == 0 {
+                return &mut [];
+            }
             &mut *slice_from_raw_parts_mut(slice.head, slice.len)
         }
     }
+
+    /// Borrows the array after checking its pointer and length, regardless of
+    /// whether validation is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WuiConversionError`] if the native side reported a null or
+    /// misaligned pointer, or a length no allocation can have.
+    pub fn try_as_slice(&self) -> Result<&[T], WuiConversionError> {
+        let slice = unsafe { (self.vtable.slice)(self.data.as_ptr()) };
+        slice.validate()?;
+        if slice.len == 0 {
+            return Ok(&[]);
+        }
+        Ok(unsafe { &*slice_from_raw_parts(slice.head, slice.len) })
+    }
 }
 
 impl<T: IntoRust + Default> IntoIterator for WuiArray<T> {
@@ -170,3 +224,59 @@ impl<T: Default + IntoRust> IntoRust for WuiArray<T> {
             .collect::<Vec<_>>()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::WuiStr;
+    use alloc::vec;
+
+    unsafe extern "C" fn noop_drop(_: *mut ()) {}
+
+    unsafe extern "C" fn null_slice(_: *const ()) -> WuiArraySlice<u8> {
+        WuiArraySlice {
+            head: core::ptr::null_mut(),
+            len: 4,
+        }
+    }
+
+    unsafe extern "C" fn empty_null_slice(_: *const ()) -> WuiArraySlice<u8> {
+        WuiArraySlice {
+            head: core::ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    fn foreign_array(slice: unsafe extern "C" fn(*const ()) -> WuiArraySlice<u8>) -> WuiArray<u8> {
+        let vtable = WuiArrayVTable::from_raw(noop_drop, slice);
+        unsafe { WuiArray::from_raw(NonNull::<u8>::dangling().as_ptr().cast(), vtable) }
+    }
+
+    #[test]
+    fn null_array_with_length_is_rejected() {
+        let array = foreign_array(null_slice);
+        assert_eq!(
+            array.try_as_slice(),
+            Err(WuiConversionError::NullPointer { len: 4 })
+        );
+    }
+
+    #[test]
+    fn empty_null_array_is_empty_slice() {
+        let array = foreign_array(empty_null_slice);
+        assert_eq!(array.try_as_slice(), Ok(&[][..]));
+        assert!(array.is_empty());
+    }
+
+    #[test]
+    fn invalid_utf8_is_rejected() {
+        let string = WuiStr(WuiArray::new(vec![b'o', b'k', 0xff]));
+        assert_eq!(
+            unsafe { string.try_into_rust() },
+            Err(WuiConversionError::InvalidUtf8 { valid_up_to: 2 })
+        );
+
+        let string = WuiStr(WuiArray::new(vec![b'o', b'k']));
+        assert_eq!(unsafe { string.try_into_rust() }.unwrap(), "ok");
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{
-    IntoFFI, IntoRust, WuiEnv, ffi_computed, ffi_computed_ctor, ffi_reactive, reactive::WuiComputed,
+    IntoFFI, IntoRust, WuiEnv, array::WuiArray, ffi_computed, ffi_computed_ctor, ffi_reactive,
+    reactive::WuiComputed,
 };
 
 use waterui::Color;
-use waterui_color::ResolvedColor;
+use waterui_color::{
+    Gradient, GradientGeometry, ResolvedColor, ResolvedGradient, ResolvedGradientStop, UnitPoint,
+};
 
 opaque!(WuiColor, Color);
 
@@ -55,3 +58,104 @@ pub unsafe extern "C" fn waterui_resolve_color(
         resolved.into_ffi()
     }
 }
+
+opaque!(WuiGradient, Gradient, gradient);
+
+into_ffi!(
+    UnitPoint,
+    pub struct WuiUnitPoint {
+        x: f32,
+        y: f32,
+    }
+);
+
+/// FFI-safe representation of the shape of a gradient.
+///
+/// Points are relative to the bounds of the view the gradient fills.
+#[repr(C)]
+pub enum WuiGradientGeometry {
+    /// Colors change along the line from `start` to `end`.
+    Linear {
+        start: WuiUnitPoint,
+        end: WuiUnitPoint,
+    },
+    /// Colors change along circles around `center`; radii are in points.
+    Radial {
+        center: WuiUnitPoint,
+        start_radius: f32,
+        end_radius: f32,
+    },
+    /// Colors change with the angle around `center`; angles are in radians.
+    Angular {
+        center: WuiUnitPoint,
+        start_angle: f32,
+        end_angle: f32,
+    },
+}
+
+impl IntoFFI for GradientGeometry {
+    type FFI = WuiGradientGeometry;
+    fn into_ffi(self) -> Self::FFI {
+        match self {
+            Self::Linear { start, end } => WuiGradientGeometry::Linear {
+                start: start.into_ffi(),
+                end: end.into_ffi(),
+            },
+            Self::Radial {
+                center,
+                start_radius,
+                end_radius,
+            } => WuiGradientGeometry::Radial {
+                center: center.into_ffi(),
+                start_radius,
+                end_radius,
+            },
+            Self::Angular {
+                center,
+                start_angle,
+                end_angle,
+            } => WuiGradientGeometry::Angular {
+                center: center.into_ffi(),
+                start_angle,
+                end_angle,
+            },
+        }
+    }
+}
+
+into_ffi!(
+    ResolvedGradientStop,
+    pub struct WuiGradientStop {
+        offset: f32,
+        color: WuiResolvedColor,
+    }
+);
+
+into_ffi!(
+    ResolvedGradient,
+    pub struct WuiResolvedGradient {
+        geometry: WuiGradientGeometry,
+        stops: WuiArray<WuiGradientStop>,
+    }
+);
+
+ffi_computed!(Gradient, *mut WuiGradient);
+ffi_computed!(ResolvedGradient, WuiResolvedGradient);
+
+/// Resolves a gradient in the given environment.
+///
+/// # Safety
+///
+/// Both `gradient` and `env` must be valid, non-null pointers to their respective types.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_resolve_gradient(
+    gradient: *const WuiGradient,
+    env: *const WuiEnv,
+) -> *mut WuiComputed<ResolvedGradient> {
+    unsafe {
+        let gradient = &*gradient;
+        let env = &*env;
+        let resolved = gradient.resolve(env);
+        resolved.into_ffi()
+    }
+}
@@ -1,5 +1,6 @@
 use crate::WuiAnyView;
 use crate::action::WuiAction;
+use crate::reactive::WuiComputed;
 use waterui::component::button::{ButtonConfig, ButtonStyle};
 
 into_ffi! {ButtonStyle, Automatic,
@@ -19,6 +20,7 @@ into_ffi! {
         label: *mut WuiAnyView,
         action: *mut WuiAction,
         style: WuiButtonStyle,
+        disabled: *mut WuiComputed<bool>,
     }
 }
 
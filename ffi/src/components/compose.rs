@@ -0,0 +1,14 @@
+use waterui::{Str, component::compose::ComposeConfig};
+
+use crate::{WuiStr, reactive::WuiComputed};
+
+into_ffi! {
+    ComposeConfig,
+    /// A Jetpack Compose Composable hosted by the Android backend.
+    pub struct WuiComposeView {
+        factory: WuiStr,
+        params: *mut WuiComputed<Str>,
+    }
+}
+
+ffi_view!(ComposeConfig, WuiComposeView, compose_view);
@@ -1,3 +1,4 @@
+use crate::closure::WuiFn;
 use crate::components::text::WuiText;
 use crate::id::WuiId;
 use crate::reactive::{WuiBinding, WuiComputed};
@@ -8,12 +9,13 @@ use waterui::{
     component::{
         slider::SliderConfig,
         stepper::StepperConfig,
-        text_field::{KeyboardType, TextFieldConfig},
+        text_field::{CompositionEvent, KeyboardType, TextFieldConfig},
         toggle::ToggleConfig,
     },
 };
 use waterui_core::id::Id;
 use waterui_form::picker::color::ColorPickerConfig;
+use waterui_form::picker::emoji::EmojiPickerConfig;
 use waterui_form::picker::{PickerConfig, PickerItem};
 use waterui_form::secure::{Secure, SecureFieldConfig};
 
@@ -25,12 +27,66 @@ into_ffi! {KeyboardType, Text, pub enum WuiKeyboardType {
     PhoneNumber
 }}
 
-into_ffi! {TextFieldConfig,
-    pub struct WuiTextField {
-        label: *mut WuiAnyView,
-        value: *mut WuiBinding<Str>,
-        prompt: WuiText,
-        keyboard: WuiKeyboardType,
+/// FFI representation of input method composition event types.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum WuiCompositionEventType {
+    Began = 0,
+    Updated = 1,
+    Committed = 2,
+    Cancelled = 3,
+}
+
+/// FFI representation of an input method composition event.
+#[repr(C)]
+pub struct WuiCompositionEvent {
+    pub event_type: WuiCompositionEventType,
+    /// The marked text for `Updated` events, or the committed text for
+    /// `Committed` events. Ignored, and never read, for other events.
+    pub text: WuiStr,
+}
+
+/// FFI representation of the `TextField` component.
+///
+/// While an input method composes, native code keeps the marked text out of
+/// `value` and reports it through `on_composition`. Changes to `value` made
+/// during a composition are applied once it is cancelled; after a commit,
+/// native code writes its text to `value` instead.
+#[repr(C)]
+pub struct WuiTextField {
+    pub label: *mut WuiAnyView,
+    pub value: *mut WuiBinding<Str>,
+    pub prompt: WuiText,
+    pub keyboard: WuiKeyboardType,
+    pub on_composition: WuiFn<WuiCompositionEvent>,
+}
+
+impl IntoFFI for TextFieldConfig {
+    type FFI = WuiTextField;
+    fn into_ffi(self) -> Self::FFI {
+        let on_composition = self.on_composition;
+        let on_composition_fn = WuiFn::from(move |ffi_event: WuiCompositionEvent| {
+            // `text` is only valid for the events that carry it
+            let event = match ffi_event.event_type {
+                WuiCompositionEventType::Began => CompositionEvent::Began,
+                WuiCompositionEventType::Updated => CompositionEvent::Updated {
+                    marked_text: unsafe { ffi_event.text.into_rust() },
+                },
+                WuiCompositionEventType::Committed => CompositionEvent::Committed {
+                    text: unsafe { ffi_event.text.into_rust() },
+                },
+                WuiCompositionEventType::Cancelled => CompositionEvent::Cancelled,
+            };
+            on_composition(event);
+        });
+
+        WuiTextField {
+            label: self.label.into_ffi(),
+            value: self.value.into_ffi(),
+            prompt: self.prompt.into_ffi(),
+            keyboard: self.keyboard.into_ffi(),
+            on_composition: on_composition_fn,
+        }
     }
 }
 
@@ -70,7 +126,7 @@ into_ffi! {StepperConfig,
 }
 
 // Implement RangeInclusive conversions
-use crate::IntoFFI;
+use crate::{IntoFFI, IntoRust};
 use core::ops::RangeInclusive;
 
 impl IntoFFI for RangeInclusive<f64> {
@@ -104,6 +160,8 @@ ffi_view!(StepperConfig, WuiStepper, stepper);
 
 ffi_view!(ColorPickerConfig, WuiColorPicker, color_picker);
 
+ffi_view!(EmojiPickerConfig, WuiEmojiPicker, emoji_picker);
+
 ffi_view!(PickerConfig, WuiPicker, picker);
 
 ffi_view!(SecureFieldConfig, WuiSecureField, secure_field);
@@ -129,6 +187,13 @@ into_ffi! {ColorPickerConfig,
     }
 }
 
+into_ffi! {EmojiPickerConfig,
+    pub struct WuiEmojiPicker {
+        label: *mut WuiAnyView,
+        value: *mut WuiBinding<Str>,
+    }
+}
+
 // Secure type FFI - uses WuiStr representation
 // The Secure type is treated as a string at the FFI boundary
 impl IntoFFI for Secure {
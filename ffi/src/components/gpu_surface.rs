@@ -6,6 +6,13 @@
 //! 2. Calling `waterui_gpu_surface_init` with the layer pointer
 //! 3. Calling `waterui_gpu_surface_render` each frame from a display-sync callback
 //! 4. Calling `waterui_gpu_surface_drop` when the view is destroyed
+//!
+//! Surfaces size themselves through `WuiSurfaceSizing`. Backends measure them
+//! with `waterui_surface_sizing_size_that_fits` during the layout pass.
+//!
+//! Frames can be captured offscreen, either from Rust via `GpuSurface::snapshot`
+//! (fulfilled during the next `waterui_gpu_surface_render`) or on demand by the
+//! backend via `waterui_gpu_surface_snapshot`.
 
 use core::ffi::c_void;
 
@@ -13,9 +20,13 @@ use alloc::boxed::Box;
 use alloc::vec;
 use alloc::vec::Vec;
 
-use waterui_graphics::gpu_surface::{GpuContext, GpuFrame, GpuRenderer, GpuSurface};
+use waterui_graphics::gpu_surface::{
+    GpuContext, GpuFrame, GpuRenderer, GpuSnapshot, GpuSurface, SnapshotRequests, SurfaceSizing,
+};
 
-use crate::IntoFFI;
+use crate::array::WuiArray;
+use crate::components::layout::{WuiProposalSize, WuiSize};
+use crate::{IntoFFI, IntoRust};
 
 /// FFI representation of a GpuSurface view.
 ///
@@ -27,6 +38,59 @@ pub struct WuiGpuSurface {
     /// Opaque pointer to the boxed GpuRenderer trait object.
     /// This is consumed during init and should not be used after.
     pub renderer: *mut c_void,
+    /// Opaque pointer to the boxed snapshot request queue.
+    /// This is consumed during init and should not be used after.
+    pub snapshots: *mut c_void,
+    /// How the surface is sized by the layout system.
+    pub sizing: WuiSurfaceSizing,
+}
+
+/// FFI representation of how a GpuSurface is sized.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub enum WuiSurfaceSizing {
+    /// Fills all the space proposed by the parent.
+    Fill,
+    /// Takes the largest size with this aspect ratio (width / height) that fits.
+    FitContent { aspect_ratio: f32 },
+    /// Reports a preferred size in points, shrunk to fit the proposal.
+    Preferred { width: f32, height: f32 },
+}
+
+impl IntoFFI for SurfaceSizing {
+    type FFI = WuiSurfaceSizing;
+    fn into_ffi(self) -> Self::FFI {
+        match self {
+            Self::Fill => WuiSurfaceSizing::Fill,
+            Self::FitContent { aspect_ratio } => WuiSurfaceSizing::FitContent { aspect_ratio },
+            Self::Preferred { width, height } => WuiSurfaceSizing::Preferred { width, height },
+        }
+    }
+}
+
+impl IntoRust for WuiSurfaceSizing {
+    type Rust = SurfaceSizing;
+    unsafe fn into_rust(self) -> Self::Rust {
+        match self {
+            Self::Fill => SurfaceSizing::Fill,
+            Self::FitContent { aspect_ratio } => SurfaceSizing::FitContent { aspect_ratio },
+            Self::Preferred { width, height } => SurfaceSizing::Preferred { width, height },
+        }
+    }
+}
+
+/// Measures a GpuSurface during the layout pass.
+///
+/// Returns the size of a surface with `sizing` for `proposal`, so every
+/// backend sizes surfaces the same way.
+#[unsafe(no_mangle)]
+pub extern "C" fn waterui_surface_sizing_size_that_fits(
+    sizing: WuiSurfaceSizing,
+    proposal: WuiProposalSize,
+) -> WuiSize {
+    let sizing = unsafe { sizing.into_rust() };
+    let proposal = unsafe { proposal.into_rust() };
+    sizing.size_that_fits(proposal).into_ffi()
 }
 
 impl IntoFFI for GpuSurface {
@@ -39,8 +103,11 @@ impl IntoFFI for GpuSurface {
         // where Box::into_raw returns a thin *mut Box<dyn GpuRenderer>.
         let boxed_renderer: Box<Box<dyn GpuRenderer>> = Box::new(self.renderer);
         let renderer_ptr = Box::into_raw(boxed_renderer) as *mut c_void;
+        let snapshots_ptr = Box::into_raw(Box::new(self.snapshots)) as *mut c_void;
         WuiGpuSurface {
             renderer: renderer_ptr,
+            snapshots: snapshots_ptr,
+            sizing: self.sizing.into_ffi(),
         }
     }
 }
@@ -59,6 +126,7 @@ pub struct WuiGpuSurfaceState {
     surface: wgpu::Surface<'static>,
     config: wgpu::SurfaceConfiguration,
     renderer: Box<dyn GpuRenderer>,
+    snapshots: SnapshotRequests,
     initialized: bool,
     current_width: u32,
     current_height: u32,
@@ -120,6 +188,13 @@ pub unsafe extern "C" fn waterui_gpu_surface_init(
         // Null out the pointer to prevent double-free
         wui_surface.renderer = core::ptr::null_mut();
 
+        let snapshots = if wui_surface.snapshots.is_null() {
+            SnapshotRequests::default()
+        } else {
+            unsafe { *Box::from_raw(wui_surface.snapshots as *mut SnapshotRequests) }
+        };
+        wui_surface.snapshots = core::ptr::null_mut();
+
         // On Android, a Surface can only be connected to one GPU API at a time. When a wgpu
         // `Surface` is created with multiple backends enabled, wgpu-core creates per-backend
         // surfaces internally (e.g. Vulkan + GLES), which can cause the underlying
@@ -323,6 +398,7 @@ pub unsafe extern "C" fn waterui_gpu_surface_init(
                 surface: wgpu_surface,
                 config,
                 renderer,
+                snapshots: snapshots.clone(),
                 initialized: false,
                 current_width: width,
                 current_height: height,
@@ -331,7 +407,9 @@ pub unsafe extern "C" fn waterui_gpu_surface_init(
             return Box::into_raw(state);
         }
 
-        tracing::error!("[GpuSurface] init failed: no compatible backend could configure the surface");
+        tracing::error!(
+            "[GpuSurface] init failed: no compatible backend could configure the surface"
+        );
         core::ptr::null_mut()
     }));
 
@@ -395,15 +473,7 @@ pub unsafe extern "C" fn waterui_gpu_surface_render(
         }
 
         // Call setup on first render
-        if !state.initialized {
-            let ctx = GpuContext {
-                device: &state.device,
-                queue: &state.queue,
-                surface_format: state.config.format,
-            };
-            state.renderer.setup(&ctx);
-            state.initialized = true;
-        }
+        ensure_setup(state);
 
         // Get next frame texture (guard against wgpu panics so we don't abort across the FFI boundary).
         let output = match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
@@ -467,6 +537,14 @@ pub unsafe extern "C" fn waterui_gpu_surface_render(
         // Present
         output.present();
 
+        // Fulfill pending `GpuSurface::snapshot` requests with an offscreen copy of this frame
+        if state.snapshots.is_pending() {
+            match capture_frame(state) {
+                Some(snapshot) => state.snapshots.fulfill(&snapshot),
+                None => state.snapshots.cancel(),
+            }
+        }
+
         true
     }));
 
@@ -479,6 +557,100 @@ pub unsafe extern "C" fn waterui_gpu_surface_render(
     }
 }
 
+/// FFI representation of a captured GpuSurface frame.
+///
+/// Pixels are tightly packed rows, top row first. When `hdr` is false they are
+/// 8-bit sRGB RGBA; when true they are linear RGBA16F (little-endian half floats).
+#[repr(C)]
+pub struct WuiGpuSnapshot {
+    /// Width in pixels, or 0 if the capture failed.
+    pub width: u32,
+    /// Height in pixels, or 0 if the capture failed.
+    pub height: u32,
+    /// Whether `data` holds RGBA16F instead of RGBA8 pixels.
+    pub hdr: bool,
+    /// Pixel data.
+    pub data: WuiArray<u8>,
+}
+
+impl WuiGpuSnapshot {
+    fn failed() -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            hdr: false,
+            data: WuiArray::new(Vec::new()),
+        }
+    }
+}
+
+/// Capture the current frame of a GpuSurface offscreen.
+///
+/// Renders a frame at the current surface size into an offscreen texture and
+/// reads it back. Use this for golden-image tests or screenshot sharing without
+/// platform screenshot APIs.
+///
+/// # Arguments
+///
+/// * `state` - Pointer to the initialized state from `waterui_gpu_surface_init`
+/// * `hdr` - Return RGBA16F pixels if the surface renders in HDR; RGBA8 otherwise
+///
+/// # Returns
+///
+/// The captured frame, with zero width and height on failure.
+///
+/// # Safety
+///
+/// `state` must be a valid pointer from `waterui_gpu_surface_init`, and must not
+/// be used concurrently with `waterui_gpu_surface_render`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_gpu_surface_snapshot(
+    state: *mut WuiGpuSurfaceState,
+    hdr: bool,
+) -> WuiGpuSnapshot {
+    let snapshot_result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        if state.is_null() {
+            return None;
+        }
+
+        let state = unsafe { &mut *state };
+        ensure_setup(state);
+        let snapshot = capture_frame(state)?;
+
+        if hdr && snapshot.is_hdr() {
+            return Some(WuiGpuSnapshot {
+                width: snapshot.width,
+                height: snapshot.height,
+                hdr: true,
+                data: WuiArray::new(snapshot.data),
+            });
+        }
+
+        let Some(image) = snapshot.to_rgba8() else {
+            tracing::error!(
+                "[GpuSurface] snapshot failed: unsupported format {:?}",
+                snapshot.format
+            );
+            return None;
+        };
+        Some(WuiGpuSnapshot {
+            width: image.width(),
+            height: image.height(),
+            hdr: false,
+            data: WuiArray::new(image.into_raw()),
+        })
+    }));
+
+    match snapshot_result {
+        Ok(Some(snapshot)) => snapshot,
+        Ok(None) => WuiGpuSnapshot::failed(),
+        Err(_) => {
+            tracing::error!("[GpuSurface] snapshot panicked");
+            WuiGpuSnapshot::failed()
+        }
+    }
+}
+
 /// Clean up GPU resources.
 ///
 /// This function should be called when the GpuSurface view is destroyed.
@@ -496,6 +668,123 @@ pub unsafe extern "C" fn waterui_gpu_surface_drop(state: *mut WuiGpuSurfaceState
     }
 }
 
+/// Call the renderer's `setup()` if it has not run yet.
+fn ensure_setup(state: &mut WuiGpuSurfaceState) {
+    if !state.initialized {
+        let ctx = GpuContext {
+            device: &state.device,
+            queue: &state.queue,
+            surface_format: state.config.format,
+        };
+        state.renderer.setup(&ctx);
+        state.initialized = true;
+    }
+}
+
+/// Render a frame into an offscreen texture and read its pixels back.
+fn capture_frame(state: &mut WuiGpuSurfaceState) -> Option<GpuSnapshot> {
+    let (width, height) = (state.current_width, state.current_height);
+    let format = state.config.format;
+    let Some(bytes_per_pixel) = format.block_copy_size(None) else {
+        tracing::error!("[GpuSurface] snapshot failed: format {format:?} cannot be copied");
+        return None;
+    };
+
+    let size = wgpu::Extent3d {
+        width,
+        height,
+        depth_or_array_layers: 1,
+    };
+    let texture = state.device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("GpuSurface Snapshot Texture"),
+        size,
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+        view_formats: &[],
+    });
+    let view = texture.create_view(&wgpu::TextureViewDescriptor {
+        label: Some("GpuSurface Snapshot View"),
+        format: Some(format),
+        ..Default::default()
+    });
+
+    state.renderer.render(&GpuFrame {
+        device: &state.device,
+        queue: &state.queue,
+        texture: &texture,
+        view,
+        format,
+        width,
+        height,
+    });
+
+    // Buffer rows must be aligned to COPY_BYTES_PER_ROW_ALIGNMENT; the padding is stripped below.
+    let row_bytes = width * bytes_per_pixel;
+    let padded_row_bytes = row_bytes.next_multiple_of(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+    let buffer = state.device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("GpuSurface Snapshot Buffer"),
+        size: u64::from(padded_row_bytes) * u64::from(height),
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+
+    let mut encoder = state
+        .device
+        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("GpuSurface Snapshot Encoder"),
+        });
+    encoder.copy_texture_to_buffer(
+        texture.as_image_copy(),
+        wgpu::TexelCopyBufferInfo {
+            buffer: &buffer,
+            layout: wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(padded_row_bytes),
+                rows_per_image: Some(height),
+            },
+        },
+        size,
+    );
+    state.queue.submit([encoder.finish()]);
+
+    let slice = buffer.slice(..);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    slice.map_async(wgpu::MapMode::Read, move |result| {
+        let _ = sender.send(result);
+    });
+    let _ = state.device.poll(wgpu::PollType::wait_indefinitely());
+    match receiver.recv() {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => {
+            tracing::error!("[GpuSurface] snapshot failed: could not map readback buffer: {e}");
+            return None;
+        }
+        Err(_) => {
+            tracing::error!("[GpuSurface] snapshot failed: readback buffer was never mapped");
+            return None;
+        }
+    }
+
+    let mut data = Vec::with_capacity(row_bytes as usize * height as usize);
+    {
+        let mapped = slice.get_mapped_range();
+        for row in mapped.chunks_exact(padded_row_bytes as usize) {
+            data.extend_from_slice(&row[..row_bytes as usize]);
+        }
+    }
+    buffer.unmap();
+
+    Some(GpuSnapshot {
+        width,
+        height,
+        format,
+        data,
+    })
+}
+
 /// Create a wgpu Surface from a platform-specific layer pointer.
 #[cfg(target_os = "macos")]
 fn create_surface_from_layer(
@@ -1,12 +1,18 @@
 use alloc::{boxed::Box, vec::Vec};
+use waterui::task::spawn_local;
 use waterui_layout::{
-    Layout, Point, ProposalSize, Rect, ScrollView, Size, StretchAxis, SubView,
-    container::{FixedContainer, LazyContainer},
-    scroll::Axis,
+    Layout, Point, ProposalSize, Rect, Reorderable, ScrollView, Size, StretchAxis, SubView,
+    container::{FixedContainer, LazyContainer, LazyList, LazyWindow},
+    scroll::{
+        Axis, Prefetch, ReachEnd, Refresh, ScrollBehavior, ScrollDestination, ScrollPosition,
+        ScrollRequest,
+    },
+    spacer::Spacer,
+    stack::Alignment,
 };
 
-use crate::{IntoFFI, IntoRust, WuiAnyView, array::WuiArray};
-use crate::{WuiTypeId, views::WuiAnyViews};
+use crate::{IntoFFI, IntoRust, WuiAnyView, WuiEnv, WuiMetadata, array::WuiArray};
+use crate::{id::WuiId, reactive::WuiComputed, views::WuiAnyViews};
 
 opaque!(WuiLayout, Box<dyn Layout>, layout);
 
@@ -16,13 +22,27 @@ pub struct WuiFixedContainer {
     contents: WuiArray<*mut WuiAnyView>,
 }
 
-/// Returns the type ID for Spacer views as a 128-bit value.
-/// `Spacer` is a raw view that stretches to fill available space.
-#[unsafe(no_mangle)]
-pub extern "C" fn waterui_spacer_id() -> WuiTypeId {
-    WuiTypeId::of::<waterui::component::spacer::Spacer>()
+/// A spacer stretching along its stack's main axis between two lengths.
+#[repr(C)]
+pub struct WuiSpacer {
+    /// Length the spacer never shrinks below.
+    min_length: f32,
+    /// Length the spacer never grows beyond; infinite if unbounded.
+    max_length: f32,
+}
+
+impl IntoFFI for Spacer {
+    type FFI = WuiSpacer;
+    fn into_ffi(self) -> Self::FFI {
+        WuiSpacer {
+            min_length: self.min_length(),
+            max_length: self.max_length(),
+        }
+    }
 }
 
+ffi_view!(Spacer, WuiSpacer, spacer);
+
 ffi_view!(FixedContainer, WuiFixedContainer, fixed_container);
 
 impl IntoFFI for FixedContainer {
@@ -55,6 +75,103 @@ impl IntoFFI for LazyContainer {
     }
 }
 
+opaque!(WuiLazyWindow, LazyWindow, lazy_window);
+
+#[repr(C)]
+pub struct WuiLazyList {
+    /// Spacing between rows, in points.
+    spacing: f32,
+    window: *mut WuiLazyWindow,
+}
+
+ffi_view!(LazyList, WuiLazyList, lazy_list);
+
+impl IntoFFI for LazyList {
+    type FFI = WuiLazyList;
+    fn into_ffi(self) -> Self::FFI {
+        WuiLazyList {
+            spacing: self.spacing,
+            window: self.window.into_ffi(),
+        }
+    }
+}
+
+/// Callback told which rows of a lazy list to request again.
+///
+/// `call` receives the new number of rows and the start and end of the range
+/// to request again. `drop` is called once the callback is replaced or the
+/// window is dropped.
+#[repr(C)]
+pub struct WuiLazyInvalidate {
+    /// Opaque pointer to the callback data.
+    pub data: *mut (),
+    /// Function to call when rows change.
+    pub call: unsafe extern "C" fn(*const (), usize, usize, usize),
+    /// Function to release the callback data.
+    pub drop: unsafe extern "C" fn(*mut ()),
+}
+
+impl Drop for WuiLazyInvalidate {
+    fn drop(&mut self) {
+        unsafe { (self.drop)(self.data) }
+    }
+}
+
+/// Gets the number of rows of a lazy list.
+///
+/// # Safety
+/// The caller must ensure that `window` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_lazy_window_len(window: *const WuiLazyWindow) -> usize {
+    unsafe { &*window }.len()
+}
+
+/// Gets the ID of the row at the specified index.
+///
+/// # Safety
+/// The caller must ensure that `window` is a valid pointer and `index` is within bounds.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_lazy_window_get_id(
+    window: *const WuiLazyWindow,
+    index: usize,
+) -> WuiId {
+    unsafe { &*window }
+        .id(index)
+        .expect("Out of bound")
+        .into_inner()
+        .into_ffi()
+}
+
+/// Builds the views of the rows from `start` to `end`, clamped to the number of rows.
+///
+/// Only the rows of the latest request are watched for changes.
+///
+/// # Safety
+/// The caller must ensure that `window` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_lazy_window_request(
+    window: *const WuiLazyWindow,
+    start: usize,
+    end: usize,
+) -> WuiArray<*mut WuiAnyView> {
+    unsafe { &*window }.request(start..end).into_ffi()
+}
+
+/// Sets the callback told about changes to the requested rows.
+///
+/// # Safety
+/// The caller must ensure that `window` is a valid pointer and that `callback`
+/// can be called on the main thread until it is dropped.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_lazy_window_on_invalidate(
+    window: *const WuiLazyWindow,
+    callback: WuiLazyInvalidate,
+) {
+    unsafe { &*window }.on_invalidate(move |len, range| unsafe {
+        (callback.call)(callback.data, len, range.start, range.end);
+    });
+}
+
 // ============================================================================
 // ProposalSize FFI
 // ============================================================================
@@ -289,6 +406,7 @@ pub unsafe extern "C" fn waterui_layout_size_that_fits(
     mut children: WuiArray<WuiSubView>,
 ) -> WuiSize {
     let layout: &dyn Layout = unsafe { &*(*layout).0 };
+    instrument!("size_that_fits", component = layout.name());
     let proposal = unsafe { proposal.into_rust() };
 
     // Get slice of WuiSubView and create trait object references
@@ -318,6 +436,7 @@ pub unsafe extern "C" fn waterui_layout_place(
     mut children: WuiArray<WuiSubView>,
 ) -> WuiArray<WuiRect> {
     let layout: &dyn Layout = unsafe { &*(*layout).0 };
+    instrument!("place", component = layout.name());
     let bounds = unsafe { bounds.into_rust() };
 
     // Get slice of WuiSubView and create trait object references
@@ -359,21 +478,291 @@ into_ffi! {Axis,All,
     }
 }
 
+opaque!(WuiPrefetch, Prefetch, prefetch);
+
+opaque!(WuiRefresh, Refresh, refresh);
+
+opaque!(WuiReachEnd, ReachEnd, reach_end);
+
+opaque!(WuiScrollPosition, ScrollPosition, scroll_position);
+
 #[repr(C)]
 pub struct WuiScrollView {
     axis: WuiAxis,
-    content: *mut WuiAnyView, // Pointer to the content view
+    content: *mut WuiAnyView,   // Pointer to the content view
+    prefetch: *mut WuiPrefetch, // Null if no prefetch hint is set
+    behavior: WuiScrollBehavior,
+    refresh: *mut WuiRefresh, // Null if the scroll view is not refreshable
+    reach_end: *mut WuiReachEnd, // Null if no reach-end callback is set
+    position: *mut WuiScrollPosition, // Null if no scroll position is attached
+}
+
+into_ffi! {ScrollBehavior,
+    pub struct WuiScrollBehavior {
+        indicators: bool,
+        paging: bool,
+        bounces: bool,
+    }
 }
 
 impl IntoFFI for ScrollView {
     type FFI = WuiScrollView;
     fn into_ffi(self) -> Self::FFI {
-        let (axis, content) = self.into_inner();
+        let behavior = self.behavior();
+        let refresh = self.refresh().cloned();
+        let reach_end = self.reach_end().cloned();
+        let position = self.position().cloned();
+        let (axis, content, prefetch) = self.into_parts();
         WuiScrollView {
             axis: axis.into_ffi(),
             content: content.into_ffi(),
+            prefetch: prefetch.into_ffi(),
+            behavior: behavior.into_ffi(),
+            refresh: refresh.into_ffi(),
+            reach_end: reach_end.into_ffi(),
+            position: position.into_ffi(),
         }
     }
 }
 
+/// Returns how many items past the visible window should be prefetched.
+///
+/// # Safety
+/// The caller must ensure that `prefetch` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_prefetch_distance(prefetch: *const WuiPrefetch) -> usize {
+    unsafe { (&*prefetch).distance() }
+}
+
+/// Reports that the items in `start..end` are about to scroll into view.
+///
+/// # Safety
+/// The caller must ensure that `prefetch` and `env` are valid pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_prefetch_call(
+    prefetch: *const WuiPrefetch,
+    env: *const WuiEnv,
+    start: usize,
+    end: usize,
+) {
+    unsafe { (&*prefetch).call(&*env, start..end) }
+}
+
+/// Starts a refresh after the user pulled the refresh control.
+///
+/// The action runs on the local executor. Does nothing if a refresh is
+/// already in progress.
+///
+/// # Safety
+/// The caller must ensure that `refresh` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_refresh_begin(refresh: *const WuiRefresh) {
+    if let Some(future) = unsafe { (&*refresh).begin() } {
+        spawn_local(future).detach();
+    }
+}
+
+/// Returns whether a refresh is in progress.
+///
+/// The refresh control should spin while the value is `true`.
+///
+/// # Safety
+/// The caller must ensure that `refresh` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_refresh_is_refreshing(
+    refresh: *const WuiRefresh,
+) -> *mut WuiComputed<bool> {
+    unsafe { (&*refresh).is_refreshing().into_ffi() }
+}
+
+/// Returns the distance from the end, in points, at which the reach-end callback fires.
+///
+/// # Safety
+/// The caller must ensure that `reach_end` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_reach_end_threshold(reach_end: *const WuiReachEnd) -> f32 {
+    unsafe { (&*reach_end).threshold() }
+}
+
+/// Reports the scroll position along the scroll axis, in points.
+///
+/// Call it after each scroll update and whenever the content size changes;
+/// the callback fires when the end of the content is near.
+///
+/// # Safety
+/// The caller must ensure that `reach_end` and `env` are valid pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_reach_end_report(
+    reach_end: *const WuiReachEnd,
+    env: *const WuiEnv,
+    offset: f32,
+    content_length: f32,
+    viewport_length: f32,
+) {
+    unsafe { (&*reach_end).report(&*env, offset, content_length, viewport_length) }
+}
+
+into_ffi! {Alignment,
+    pub enum WuiAlignment {
+        Top,
+        TopLeading,
+        TopTrailing,
+        Center,
+        Bottom,
+        BottomLeading,
+        BottomTrailing,
+        Leading,
+        Trailing,
+    }
+}
+
+#[repr(C)]
+pub enum WuiScrollDestinationKind {
+    Offset,
+    Item,
+    Start,
+    End,
+}
+
+/// A programmatic scroll request.
+///
+/// `offset` is only meaningful for `Offset` destinations, and `index` and
+/// `anchor` only for `Item` destinations.
+#[repr(C)]
+pub struct WuiScrollRequest {
+    kind: WuiScrollDestinationKind,
+    offset: WuiPoint,
+    index: usize,
+    anchor: WuiAlignment,
+    animated: bool,
+}
+
+impl IntoFFI for ScrollRequest {
+    type FFI = WuiScrollRequest;
+    fn into_ffi(self) -> Self::FFI {
+        let mut request = WuiScrollRequest {
+            kind: WuiScrollDestinationKind::Start,
+            offset: Point::zero().into_ffi(),
+            index: 0,
+            anchor: WuiAlignment::Center,
+            animated: self.animated,
+        };
+        match self.destination {
+            ScrollDestination::Offset(offset) => {
+                request.kind = WuiScrollDestinationKind::Offset;
+                request.offset = offset.into_ffi();
+            }
+            ScrollDestination::Item { index, anchor } => {
+                request.kind = WuiScrollDestinationKind::Item;
+                request.index = index;
+                request.anchor = anchor.into_ffi();
+            }
+            ScrollDestination::Start => {}
+            ScrollDestination::End => request.kind = WuiScrollDestinationKind::End,
+        }
+        request
+    }
+}
+
+/// Callback that performs programmatic scroll requests.
+///
+/// `drop` is called once the callback is replaced or the scroll position is
+/// dropped.
+#[repr(C)]
+pub struct WuiScrollRequestHandler {
+    /// Opaque pointer to the callback data.
+    pub data: *mut (),
+    /// Function to call with each request.
+    pub call: unsafe extern "C" fn(*const (), WuiScrollRequest),
+    /// Function to release the callback data.
+    pub drop: unsafe extern "C" fn(*mut ()),
+}
+
+impl Drop for WuiScrollRequestHandler {
+    fn drop(&mut self) {
+        unsafe { (self.drop)(self.data) }
+    }
+}
+
+/// Sets the callback that performs scroll requests.
+///
+/// A request made before the scroll view appeared is delivered immediately.
+///
+/// # Safety
+/// The caller must ensure that `position` is a valid pointer and that
+/// `handler` can be called on the main thread until it is dropped.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_scroll_position_on_request(
+    position: *const WuiScrollPosition,
+    handler: WuiScrollRequestHandler,
+) {
+    unsafe { &*position }.on_request(move |request| unsafe {
+        (handler.call)(handler.data, request.into_ffi());
+    });
+}
+
+/// Reports the current content offset of the scroll view.
+///
+/// Call it after every scroll update, including ones caused by requests.
+///
+/// # Safety
+/// The caller must ensure that `position` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_scroll_position_report(
+    position: *const WuiScrollPosition,
+    offset: WuiPoint,
+) {
+    unsafe { (&*position).report(offset.into_rust()) }
+}
+
 ffi_view!(ScrollView, WuiScrollView, scroll_view);
+
+// Reorderable
+// ============================================================================
+
+opaque!(WuiReorderable, Reorderable, reorderable);
+
+/// Type alias for Metadata<Reorderable> FFI struct
+pub type WuiMetadataReorderable = WuiMetadata<*mut WuiReorderable>;
+
+// Generate waterui_metadata_reorderable_id() and waterui_force_as_metadata_reorderable()
+ffi_metadata!(Reorderable, WuiMetadataReorderable, reorderable);
+
+/// Starts dragging the child at `index` of a reorderable stack or grid.
+///
+/// Returns `false` if the drag should not start.
+///
+/// # Safety
+/// The caller must ensure that `reorderable` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_reorderable_begin(
+    reorderable: *const WuiReorderable,
+    index: usize,
+) -> bool {
+    unsafe { (&*reorderable).begin(index) }
+}
+
+/// Drops the dragged child so it ends up at `destination`.
+///
+/// Returns whether the order changed. The stack re-renders with the new
+/// order, so backends should settle the child into its gap without
+/// rearranging the children themselves.
+///
+/// # Safety
+/// The caller must ensure that `reorderable` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_reorderable_finish(
+    reorderable: *const WuiReorderable,
+    destination: usize,
+) -> bool {
+    unsafe { (&*reorderable).finish(destination) }
+}
+
+/// Abandons the current drag, keeping the order.
+///
+/// # Safety
+/// The caller must ensure that `reorderable` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_reorderable_cancel(reorderable: *const WuiReorderable) {
+    unsafe { (&*reorderable).cancel() }
+}
@@ -1,6 +1,13 @@
+use alloc::boxed::Box;
+
+use crate::components::layout::{WuiPrefetch, WuiReachEnd, WuiRefresh, WuiScrollPosition};
+use crate::reactive::WuiWatcherGuard;
 use crate::views::WuiAnyViews;
 use crate::{IntoFFI, WuiAnyView, WuiEnv};
-use waterui::component::list::{ListConfig, ListItem};
+use waterui::Environment;
+use waterui::component::list::{
+    ListConfig, ListItem, ListSelection, RowRecycler, RowSlot, SelectionMode,
+};
 use waterui::views::ViewsExt;
 
 into_ffi! {
@@ -11,9 +18,66 @@ into_ffi! {
 
 ffi_view!(ListItem, WuiListItem, list_item);
 
+opaque!(WuiRowRecycler, RowRecycler, row_recycler);
+
+opaque!(WuiRowSlot, RowSlot, row_slot);
+
+opaque!(WuiRowDelete, Box<dyn Fn(&Environment, usize)>, row_delete);
+
+opaque!(WuiListSelection, ListSelection, list_selection);
+
+into_ffi! {SelectionMode,
+    /// How many rows of a list can be selected at once.
+    pub enum WuiSelectionMode {
+        Single,
+        Multiple,
+    }
+}
+
+/// A callback called every time a list selection changes.
+///
+/// `drop` is called once the watcher guard returned with it is dropped.
+#[repr(C)]
+pub struct WuiListSelectionWatcher {
+    /// Opaque pointer to the callback data.
+    pub data: *mut (),
+    /// Function to call when the selection changes.
+    pub call: unsafe extern "C" fn(*const ()),
+    /// Function to release the callback data.
+    pub drop: unsafe extern "C" fn(*mut ()),
+}
+
+impl Drop for WuiListSelectionWatcher {
+    fn drop(&mut self) {
+        unsafe { (self.drop)(self.data) }
+    }
+}
+
+/// A recyclable list row: its view, the slot used to rebind it and its
+/// delete callback.
+#[repr(C)]
+pub struct WuiRecycledRow {
+    content: *mut WuiAnyView,
+    slot: *mut WuiRowSlot,
+    /// Null if the row cannot be deleted.
+    on_delete: *mut WuiRowDelete,
+}
+
 #[repr(C)]
 pub struct WuiList {
     contents: *mut WuiAnyViews,
+    /// Null if the list does not support row recycling.
+    recycler: *mut WuiRowRecycler,
+    /// Null if no prefetch hint is set.
+    prefetch: *mut WuiPrefetch,
+    /// Null if rows cannot be selected.
+    selection: *mut WuiListSelection,
+    /// Null if the list is not refreshable.
+    refresh: *mut WuiRefresh,
+    /// Null if no reach-end callback is set.
+    reach_end: *mut WuiReachEnd,
+    /// Null if no scroll position is attached.
+    position: *mut WuiScrollPosition,
 }
 
 impl IntoFFI for ListConfig {
@@ -22,6 +86,12 @@ impl IntoFFI for ListConfig {
     fn into_ffi(self) -> Self::FFI {
         WuiList {
             contents: self.contents.erase().into_ffi(),
+            recycler: self.recycler.into_ffi(),
+            prefetch: self.prefetch.into_ffi(),
+            selection: self.selection.into_ffi(),
+            refresh: self.refresh.into_ffi(),
+            reach_end: self.reach_end.into_ffi(),
+            position: self.position.into_ffi(),
         }
     }
 }
@@ -41,3 +111,126 @@ pub unsafe extern "C" fn waterui_list_item_call_delete(
     let _ = (item, env, index);
     // TODO: expose deletion callbacks when backend support is implemented.
 }
+
+/// Builds a reusable row for the item at `index`.
+///
+/// All fields of the result are null if `index` is out of bounds. The backend
+/// owns the returned slot and delete callback and must release them with
+/// `waterui_drop_row_slot` and `waterui_drop_row_delete`.
+///
+/// # Safety
+/// The caller must ensure that `recycler` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_row_recycler_make_row(
+    recycler: *const WuiRowRecycler,
+    index: usize,
+) -> WuiRecycledRow {
+    match unsafe { (&*recycler).make_row(index) } {
+        Some(row) => WuiRecycledRow {
+            content: row.content.into_ffi(),
+            slot: row.slot.into_ffi(),
+            on_delete: row.on_delete.into_ffi(),
+        },
+        None => WuiRecycledRow {
+            content: core::ptr::null_mut(),
+            slot: core::ptr::null_mut(),
+            on_delete: core::ptr::null_mut(),
+        },
+    }
+}
+
+/// Shows the item at `index` in a recycled row, updating its views in place.
+///
+/// Returns `false` and leaves the row unchanged if `index` is out of bounds.
+///
+/// # Safety
+/// The caller must ensure that `slot` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_row_slot_rebind(slot: *const WuiRowSlot, index: usize) -> bool {
+    unsafe { (&*slot).rebind(index) }
+}
+
+/// Deletes the item at `index` from a recycled row.
+///
+/// Pass the index of the item the row currently shows.
+///
+/// # Safety
+/// The caller must ensure that `on_delete` and `env` are valid pointers.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_row_delete_call(
+    on_delete: *const WuiRowDelete,
+    env: *const WuiEnv,
+    index: usize,
+) {
+    unsafe { ((*on_delete).0)(&*env, index) }
+}
+
+/// Returns how many rows of the list can be selected at once.
+///
+/// # Safety
+/// The caller must ensure that `selection` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_list_selection_mode(
+    selection: *const WuiListSelection,
+) -> WuiSelectionMode {
+    unsafe { (&*selection).mode().into_ffi() }
+}
+
+/// Returns `true` if the row at `index` is selected.
+///
+/// # Safety
+/// The caller must ensure that `selection` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_list_selection_is_selected(
+    selection: *const WuiListSelection,
+    index: usize,
+) -> bool {
+    unsafe { (&*selection).is_selected(index) }
+}
+
+/// Selects or deselects the row at `index`, updating the selection binding.
+///
+/// In single selection mode, selecting a row replaces the selected row.
+///
+/// # Safety
+/// The caller must ensure that `selection` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_list_selection_set_selected(
+    selection: *const WuiListSelection,
+    index: usize,
+    selected: bool,
+) {
+    let selection = unsafe { &*selection };
+    if selected {
+        selection.select(index);
+    } else {
+        selection.deselect(index);
+    }
+}
+
+/// Toggles the selection of the row at `index`.
+///
+/// # Safety
+/// The caller must ensure that `selection` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_list_selection_toggle(
+    selection: *const WuiListSelection,
+    index: usize,
+) {
+    unsafe { (&*selection).toggle(index) }
+}
+
+/// Calls `watcher` every time the selection changes, including changes made
+/// from Rust, so the backend can refresh the highlight of visible rows.
+///
+/// # Safety
+/// The caller must ensure that `selection` is a valid pointer and that
+/// `watcher` can be called until the returned guard is dropped.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_list_selection_watch(
+    selection: *const WuiListSelection,
+    watcher: WuiListSelectionWatcher,
+) -> *mut WuiWatcherGuard {
+    let guard = unsafe { &*selection }.watch(move || unsafe { (watcher.call)(watcher.data) });
+    guard.into_ffi()
+}
@@ -1,12 +1,18 @@
 use crate::WuiStr;
 use crate::closure::WuiFn;
+use crate::components::layout::WuiSize;
 use crate::reactive::{WuiBinding, WuiComputed};
-use crate::{IntoFFI, IntoRust};
+use crate::{IntoFFI, IntoRust, WuiAnyView};
 use alloc::string::String;
-use nami::SignalExt;
+use core::time::Duration;
 use nami::signal::IntoComputed;
+use nami::{Binding, SignalExt};
+use waterui_core::time::duration_from_secs_f32;
 use waterui_media::{
     AspectRatio, Url,
+    animated_vector::{AnimatedVectorConfig, Event as AnimatedVectorEvent},
+    async_image::{AsyncImageConfig, CachePolicy, ContentMode},
+    audio::{AudioPlayerConfig, Event as AudioEvent},
     live::{LivePhotoConfig, LivePhotoSource},
     photo::{Event as PhotoEvent, PhotoConfig},
     video::{Event as VideoEvent, VideoConfig, VideoPlayerConfig},
@@ -71,28 +77,78 @@ pub struct WuiPhoto {
     pub on_event: WuiFn<WuiPhotoEvent>,
 }
 
+/// Wraps a photo event handler into a function native code calls with FFI events.
+fn photo_event_handler(on_event: impl Fn(PhotoEvent) + 'static) -> WuiFn<WuiPhotoEvent> {
+    WuiFn::from(move |ffi_event: WuiPhotoEvent| {
+        // Convert FFI event to Rust event
+        let rust_event = match ffi_event.event_type {
+            WuiPhotoEventType::Loaded => PhotoEvent::Loaded,
+            WuiPhotoEventType::Error => {
+                let message_str = unsafe { ffi_event.error_message.into_rust() };
+                PhotoEvent::Error(String::from(message_str))
+            }
+        };
+
+        // Call the user's closure
+        on_event(rust_event);
+    })
+}
+
 impl IntoFFI for PhotoConfig {
     type FFI = WuiPhoto;
     fn into_ffi(self) -> Self::FFI {
-        // Convert the Rust closure to a WuiFn
-        // Native code will call this with WuiPhotoEvent, we convert to Rust Event and call the closure
-        let on_event_fn = WuiFn::from(move |ffi_event: WuiPhotoEvent| {
-            // Convert FFI event to Rust event
-            let rust_event = match ffi_event.event_type {
-                WuiPhotoEventType::Loaded => PhotoEvent::Loaded,
-                WuiPhotoEventType::Error => {
-                    let message_str = unsafe { ffi_event.error_message.into_rust() };
-                    PhotoEvent::Error(String::from(message_str))
-                }
-            };
+        WuiPhoto {
+            source: self.source.into_ffi(),
+            on_event: photo_event_handler(self.on_event),
+        }
+    }
+}
 
-            // Call the user's closure
-            (self.on_event)(rust_event);
-        });
+into_ffi! {ContentMode,
+    pub enum WuiContentMode {
+        Fit,
+        Fill,
+        Tile,
+    }
+}
 
-        WuiPhoto {
+into_ffi! {CachePolicy,
+    pub enum WuiImageCachePolicy {
+        MemoryAndDisk,
+        MemoryOnly,
+        Disabled,
+    }
+}
+
+/// FFI representation of an image loaded in the background.
+#[repr(C)]
+pub struct WuiAsyncImage {
+    pub source: WuiStr,
+    /// Normalized URL that identifies the image in the shared cache.
+    pub cache_key: WuiStr,
+    pub cache_policy: WuiImageCachePolicy,
+    pub content_mode: WuiContentMode,
+    /// Display size in points to decode the image at; zero if unknown.
+    pub target_size: WuiSize,
+    /// Shown while the image is loading.
+    pub placeholder: *mut WuiAnyView,
+    /// Shown if the image fails to load.
+    pub error: *mut WuiAnyView,
+    pub on_event: WuiFn<WuiPhotoEvent>,
+}
+
+impl IntoFFI for AsyncImageConfig {
+    type FFI = WuiAsyncImage;
+    fn into_ffi(self) -> Self::FFI {
+        WuiAsyncImage {
             source: self.source.into_ffi(),
-            on_event: on_event_fn,
+            cache_key: self.cache_key.into_ffi(),
+            cache_policy: self.cache_policy.into_ffi(),
+            content_mode: self.content_mode.into_ffi(),
+            target_size: self.target_size.unwrap_or_default().into_ffi(),
+            placeholder: self.placeholder.into_ffi(),
+            error: self.error.into_ffi(),
+            on_event: photo_event_handler(self.on_event),
         }
     }
 }
@@ -251,6 +307,93 @@ impl IntoFFI for VideoPlayerConfig {
     }
 }
 
+// =============================================================================
+// AudioPlayer - Invisible audio playback
+// =============================================================================
+
+/// FFI representation of audio event types.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum WuiAudioEventType {
+    ReadyToPlay = 0,
+    Buffering = 1,
+    BufferingEnded = 2,
+    Ended = 3,
+    Error = 4,
+}
+
+/// FFI representation of an audio event.
+#[repr(C)]
+pub struct WuiAudioEvent {
+    pub event_type: WuiAudioEventType,
+    /// Length of the audio in seconds, for `ReadyToPlay` events.
+    pub duration: f32,
+    pub error_message: WuiStr,
+}
+
+/// FFI representation of the AudioPlayer component.
+#[repr(C)]
+pub struct WuiAudioPlayer {
+    /// The audio source URL as a string (reactive).
+    pub source: *mut WuiComputed<waterui::Str>,
+    /// Whether the audio is playing; set to false when playback ends.
+    pub playing: *mut WuiBinding<bool>,
+    /// Playback position in seconds; written while playing, seeks when set.
+    pub position: *mut WuiBinding<f64>,
+    pub volume: *mut WuiBinding<Volume>,
+    /// Playback rate, where 1 is normal speed.
+    pub rate: *mut WuiBinding<f32>,
+    /// Whether playback continues while the app is in the background.
+    pub background: bool,
+    pub loops: bool,
+    pub on_event: WuiFn<WuiAudioEvent>,
+}
+
+impl IntoFFI for AudioPlayerConfig {
+    type FFI = WuiAudioPlayer;
+    fn into_ffi(self) -> Self::FFI {
+        let on_event = self.on_event;
+        let on_event_fn = WuiFn::from(move |ffi_event: WuiAudioEvent| {
+            let rust_event = match ffi_event.event_type {
+                WuiAudioEventType::ReadyToPlay => AudioEvent::ReadyToPlay {
+                    duration: duration_from_secs_f32(ffi_event.duration),
+                },
+                WuiAudioEventType::Buffering => AudioEvent::Buffering,
+                WuiAudioEventType::BufferingEnded => AudioEvent::BufferingEnded,
+                WuiAudioEventType::Ended => AudioEvent::Ended,
+                WuiAudioEventType::Error => {
+                    let message_str = unsafe { ffi_event.error_message.into_rust() };
+                    AudioEvent::Error {
+                        message: String::from(message_str),
+                    }
+                }
+            };
+            on_event(rust_event);
+        });
+
+        let source_str = self.source.map(|url: Url| url.inner()).into_computed();
+        // Native players report and seek in seconds
+        let position = Binding::mapping(
+            &self.position,
+            |position: Duration| position.as_secs_f64(),
+            |binding, seconds: f64| {
+                binding.set(Duration::try_from_secs_f64(seconds.max(0.0)).unwrap_or_default());
+            },
+        );
+
+        WuiAudioPlayer {
+            source: source_str.into_ffi(),
+            playing: self.playing.into_ffi(),
+            position: position.into_ffi(),
+            volume: self.volume.into_ffi(),
+            rate: self.rate.into_ffi(),
+            background: self.background,
+            loops: self.loops,
+            on_event: on_event_fn,
+        }
+    }
+}
+
 // =============================================================================
 // LivePhoto
 // =============================================================================
@@ -269,6 +412,75 @@ into_ffi! {
     }
 }
 
+// =============================================================================
+// AnimatedVector - Lottie and dotLottie animations
+// =============================================================================
+
+/// FFI representation of animated vector event types.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub enum WuiAnimatedVectorEventType {
+    Loaded = 0,
+    Completed = 1,
+    Error = 2,
+}
+
+/// FFI representation of an animated vector event.
+#[repr(C)]
+pub struct WuiAnimatedVectorEvent {
+    pub event_type: WuiAnimatedVectorEventType,
+    /// Length of the animation in seconds, for `Loaded` events.
+    pub duration: f32,
+    pub error_message: WuiStr,
+}
+
+/// FFI representation of the AnimatedVector component.
+#[repr(C)]
+pub struct WuiAnimatedVector {
+    /// The animation source URL as a string (reactive).
+    pub source: *mut WuiComputed<waterui::Str>,
+    /// Whether the animation is playing; set to false when it completes.
+    pub playing: *mut WuiBinding<bool>,
+    /// Playback position from 0 to 1; written while playing, seeks when set.
+    pub progress: *mut WuiBinding<f32>,
+    pub loops: bool,
+    pub speed: f32,
+    pub content_mode: WuiContentMode,
+    pub on_event: WuiFn<WuiAnimatedVectorEvent>,
+}
+
+impl IntoFFI for AnimatedVectorConfig {
+    type FFI = WuiAnimatedVector;
+    fn into_ffi(self) -> Self::FFI {
+        let on_event = self.on_event;
+        let on_event_fn = WuiFn::from(move |ffi_event: WuiAnimatedVectorEvent| {
+            let rust_event = match ffi_event.event_type {
+                WuiAnimatedVectorEventType::Loaded => AnimatedVectorEvent::Loaded {
+                    duration: duration_from_secs_f32(ffi_event.duration),
+                },
+                WuiAnimatedVectorEventType::Completed => AnimatedVectorEvent::Completed,
+                WuiAnimatedVectorEventType::Error => {
+                    let message_str = unsafe { ffi_event.error_message.into_rust() };
+                    AnimatedVectorEvent::Error(String::from(message_str))
+                }
+            };
+            on_event(rust_event);
+        });
+
+        let source_str = self.source.map(|url: Url| url.inner()).into_computed();
+
+        WuiAnimatedVector {
+            source: source_str.into_ffi(),
+            playing: self.playing.into_ffi(),
+            progress: self.progress.into_ffi(),
+            loops: self.loops,
+            speed: self.speed,
+            content_mode: self.content_mode.into_ffi(),
+            on_event: on_event_fn,
+        }
+    }
+}
+
 impl IntoFFI for waterui_media::Url {
     type FFI = WuiStr;
     fn into_ffi(self) -> Self::FFI {
@@ -282,14 +494,20 @@ impl IntoFFI for waterui_media::Url {
 
 ffi_view!(PhotoConfig, WuiPhoto, photo);
 
+ffi_view!(AsyncImageConfig, WuiAsyncImage, async_image);
+
 // Video - raw video view without controls
 ffi_view!(VideoConfig, WuiVideo, video);
 
 // VideoPlayer - full-featured player with native controls
 ffi_view!(VideoPlayerConfig, WuiVideoPlayer, video_player);
 
+ffi_view!(AudioPlayerConfig, WuiAudioPlayer, audio_player);
+
 ffi_view!(LivePhotoConfig, WuiLivePhoto, live_photo);
 
+ffi_view!(AnimatedVectorConfig, WuiAnimatedVector, animated_vector);
+
 // Note: Media enum has complex tuple variants that need special FFI handling
 // - leaving for future implementation with manual IntoFFI implementation
 
@@ -402,7 +620,7 @@ impl IntoFFI for MediaFilter {
 // =============================================================================
 
 use crate::WuiEnv;
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec, vec::Vec};
 use waterui_media::Media;
 
 /// A callback for receiving selected media ID when user picks media.
@@ -420,6 +638,23 @@ pub struct MediaPickerPresentCallback {
 pub type MediaPickerPresentFn =
     unsafe extern "C" fn(WuiMediaFilterType, MediaPickerPresentCallback);
 
+/// A callback for receiving the selected media IDs of a multi-selection picker.
+///
+/// Native code calls it once with the IDs in selection order.
+#[repr(C)]
+pub struct MediaPickerPresentMultipleCallback {
+    /// Opaque pointer to the callback data.
+    pub data: *mut (),
+    /// Function to call with a pointer to `len` selected IDs. This consumes the callback.
+    pub call: unsafe extern "C" fn(*mut (), *const SelectedId, usize),
+}
+
+/// Type alias for the native multi-selection media picker present function.
+///
+/// The `usize` argument is the selection limit, where `0` means no limit.
+pub type MediaPickerPresentMultipleFn =
+    unsafe extern "C" fn(WuiMediaFilterType, usize, MediaPickerPresentMultipleCallback);
+
 /// FFI representation of the result from loading media.
 ///
 /// For Live Photos / Motion Photos, both `url_ptr` (image) and `video_url_ptr` (video)
@@ -465,6 +700,7 @@ pub type MediaLoadFn = unsafe extern "C" fn(u32, MediaLoadCallback);
 /// FFI-compatible MediaPickerManager implementation.
 struct FFIMediaPickerManager {
     present_fn: MediaPickerPresentFn,
+    present_multiple_fn: Option<MediaPickerPresentMultipleFn>,
     load_fn: MediaLoadFn,
 }
 
@@ -490,6 +726,45 @@ impl CustomMediaPickerManager for FFIMediaPickerManager {
         }
     }
 
+    fn present_multiple(
+        &self,
+        filter: MediaFilter,
+        limit: Option<usize>,
+        callback: impl FnOnce(Vec<SelectedId>) + 'static,
+    ) {
+        let Some(present_multiple_fn) = self.present_multiple_fn else {
+            self.present(filter, move |selected| callback(vec![selected]));
+            return;
+        };
+
+        let callback_box: Box<Box<dyn FnOnce(Vec<SelectedId>)>> = Box::new(Box::new(callback));
+        let callback_data = Box::into_raw(callback_box).cast::<()>();
+
+        unsafe extern "C" fn present_multiple_trampoline(
+            data: *mut (),
+            selected: *const SelectedId,
+            len: usize,
+        ) {
+            let callback =
+                unsafe { Box::from_raw(data.cast::<Box<dyn FnOnce(Vec<SelectedId>)>>()) };
+            let selected = if selected.is_null() {
+                Vec::new()
+            } else {
+                unsafe { core::slice::from_raw_parts(selected, len) }.to_vec()
+            };
+            callback(selected);
+        }
+
+        let ffi_callback = MediaPickerPresentMultipleCallback {
+            data: callback_data,
+            call: present_multiple_trampoline,
+        };
+
+        unsafe {
+            present_multiple_fn(filter.into_ffi(), limit.unwrap_or(0), ffi_callback);
+        }
+    }
+
     fn load(&self, selected: SelectedId, callback: impl FnOnce(Media) + 'static) {
         let callback_box: Box<Box<dyn FnOnce(Media)>> = Box::new(Box::new(callback));
         let callback_data = Box::into_raw(callback_box).cast::<()>();
@@ -515,6 +790,8 @@ impl CustomMediaPickerManager for FFIMediaPickerManager {
 ///
 /// Native backends call this during initialization to register their media picker
 /// implementation. This unified manager handles both presenting the picker and loading media.
+/// Multi-selection pickers fall back to single selection; use
+/// [`waterui_env_install_media_picker_multiple`] to support them natively.
 ///
 /// # Safety
 ///
@@ -527,6 +804,38 @@ pub unsafe extern "C" fn waterui_env_install_media_picker_manager(
     env: *mut WuiEnv,
     present_fn: MediaPickerPresentFn,
     load_fn: MediaLoadFn,
+) {
+    unsafe { install_media_picker_manager(env, present_fn, load_fn, None) };
+}
+
+/// Installs a MediaPickerManager that also presents multi-selection pickers.
+///
+/// Use this instead of [`waterui_env_install_media_picker_manager`] on platforms
+/// whose picker can select several items at once.
+///
+/// # Safety
+///
+/// The caller must ensure that:
+/// - `env` is a valid pointer to a `WuiEnv`
+/// - `present_fn` is a valid function pointer to the native media picker presentation
+/// - `load_fn` is a valid function pointer to the native media loader implementation
+/// - `present_multiple_fn` is a valid function pointer to the native
+///   multi-selection picker presentation
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_media_picker_multiple(
+    env: *mut WuiEnv,
+    present_fn: MediaPickerPresentFn,
+    load_fn: MediaLoadFn,
+    present_multiple_fn: MediaPickerPresentMultipleFn,
+) {
+    unsafe { install_media_picker_manager(env, present_fn, load_fn, Some(present_multiple_fn)) };
+}
+
+unsafe fn install_media_picker_manager(
+    env: *mut WuiEnv,
+    present_fn: MediaPickerPresentFn,
+    load_fn: MediaLoadFn,
+    present_multiple_fn: Option<MediaPickerPresentMultipleFn>,
 ) {
     if env.is_null() {
         return;
@@ -535,6 +844,7 @@ pub unsafe extern "C" fn waterui_env_install_media_picker_manager(
 
     let manager = MediaPickerManager::new(FFIMediaPickerManager {
         present_fn,
+        present_multiple_fn,
         load_fn,
     });
 
@@ -574,3 +884,441 @@ unsafe fn media_load_result_to_media(result: MediaLoadResult) -> Media {
         }
     }
 }
+
+// =============================================================================
+// PhotoLibrary FFI - Environment Service Installation
+// =============================================================================
+
+use waterui::Str;
+use waterui_media::library::{
+    Album, Asset, AssetKind, AssetQuery, CustomPhotoLibrary, PhotoLibrary, PhotoLibraryPermission,
+};
+
+/// FFI representation of the photo library authorization state.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WuiPhotoLibraryPermission {
+    /// The user has not been asked yet.
+    NotDetermined = 0,
+    /// Access is blocked by system policy.
+    Restricted = 1,
+    /// The user declined access.
+    Denied = 2,
+    /// The user granted access to a subset of the library.
+    Limited = 3,
+    /// The user granted access to the whole library.
+    Authorized = 4,
+}
+
+impl IntoRust for WuiPhotoLibraryPermission {
+    type Rust = PhotoLibraryPermission;
+    unsafe fn into_rust(self) -> Self::Rust {
+        match self {
+            Self::NotDetermined => PhotoLibraryPermission::NotDetermined,
+            Self::Restricted => PhotoLibraryPermission::Restricted,
+            Self::Denied => PhotoLibraryPermission::Denied,
+            Self::Limited => PhotoLibraryPermission::Limited,
+            Self::Authorized => PhotoLibraryPermission::Authorized,
+        }
+    }
+}
+
+/// FFI representation of an album. Strings are borrowed for the duration of the callback.
+#[repr(C)]
+pub struct WuiPhotoAlbum {
+    /// Pointer to the UTF-8 encoded album identifier.
+    pub id_ptr: *const u8,
+    /// Length of the identifier in bytes.
+    pub id_len: usize,
+    /// Pointer to the UTF-8 encoded album title.
+    pub title_ptr: *const u8,
+    /// Length of the title in bytes.
+    pub title_len: usize,
+    /// Number of assets in the album.
+    pub count: usize,
+}
+
+/// FFI representation of an asset. Strings are borrowed for the duration of the callback.
+#[repr(C)]
+pub struct WuiPhotoAsset {
+    /// Pointer to the UTF-8 encoded asset identifier.
+    pub id_ptr: *const u8,
+    /// Length of the identifier in bytes.
+    pub id_len: usize,
+    /// Pointer to the UTF-8 encoded thumbnail URL.
+    pub thumbnail_ptr: *const u8,
+    /// Length of the thumbnail URL in bytes.
+    pub thumbnail_len: usize,
+    /// Media type, using the [`media_type`] constants.
+    pub media_type: u8,
+}
+
+/// FFI representation of an asset query.
+#[repr(C)]
+pub struct WuiPhotoAssetQuery {
+    /// Pointer to the UTF-8 encoded album identifier, or null for the whole library.
+    pub album_ptr: *const u8,
+    /// Length of the album identifier in bytes.
+    pub album_len: usize,
+    /// Kinds of media to include.
+    pub filter: WuiMediaFilterType,
+    /// Index of the first asset to return.
+    pub offset: usize,
+    /// Maximum number of assets to return, where `0` means no limit.
+    pub limit: usize,
+}
+
+/// A callback for receiving the authorization state after a permission request.
+#[repr(C)]
+pub struct PhotoLibraryPermissionCallback {
+    /// Opaque pointer to the callback data.
+    pub data: *mut (),
+    /// Function to call with the authorization state. This consumes the callback.
+    pub call: unsafe extern "C" fn(*mut (), WuiPhotoLibraryPermission),
+}
+
+/// A callback for receiving the albums of the library.
+#[repr(C)]
+pub struct PhotoLibraryAlbumsCallback {
+    /// Opaque pointer to the callback data.
+    pub data: *mut (),
+    /// Function to call with a pointer to `len` albums. This consumes the callback.
+    pub call: unsafe extern "C" fn(*mut (), *const WuiPhotoAlbum, usize),
+}
+
+/// A callback for receiving a page of assets.
+#[repr(C)]
+pub struct PhotoLibraryAssetsCallback {
+    /// Opaque pointer to the callback data.
+    pub data: *mut (),
+    /// Function to call with a pointer to `len` assets. This consumes the callback.
+    pub call: unsafe extern "C" fn(*mut (), *const WuiPhotoAsset, usize),
+}
+
+/// A callback native code calls every time the library content changes.
+///
+/// Unlike the other callbacks it may be called many times; native code must call
+/// `drop` once it stops observing.
+#[repr(C)]
+pub struct PhotoLibraryChangeCallback {
+    /// Opaque pointer to the callback data.
+    pub data: *mut (),
+    /// Function to call when the library changes.
+    pub call: unsafe extern "C" fn(*const ()),
+    /// Function to release the callback data.
+    pub drop: unsafe extern "C" fn(*mut ()),
+}
+
+/// Type alias for the native function returning the current authorization state.
+pub type PhotoLibraryPermissionFn = unsafe extern "C" fn() -> WuiPhotoLibraryPermission;
+
+/// Type alias for the native function prompting the user for library access.
+pub type PhotoLibraryRequestPermissionFn = unsafe extern "C" fn(PhotoLibraryPermissionCallback);
+
+/// Type alias for the native function fetching albums.
+pub type PhotoLibraryAlbumsFn = unsafe extern "C" fn(PhotoLibraryAlbumsCallback);
+
+/// Type alias for the native function fetching assets.
+pub type PhotoLibraryAssetsFn =
+    unsafe extern "C" fn(WuiPhotoAssetQuery, PhotoLibraryAssetsCallback);
+
+/// Type alias for the native function loading the full-size media of an asset.
+pub type PhotoLibraryLoadFn = unsafe extern "C" fn(*const u8, usize, MediaLoadCallback);
+
+/// Type alias for the native function registering a library change observer.
+pub type PhotoLibraryObserveFn = unsafe extern "C" fn(PhotoLibraryChangeCallback);
+
+/// FFI-compatible photo library implementation.
+struct FFIPhotoLibrary {
+    permission_fn: PhotoLibraryPermissionFn,
+    request_permission_fn: PhotoLibraryRequestPermissionFn,
+    albums_fn: PhotoLibraryAlbumsFn,
+    assets_fn: PhotoLibraryAssetsFn,
+    load_fn: PhotoLibraryLoadFn,
+    observe_fn: PhotoLibraryObserveFn,
+}
+
+/// Copies a borrowed UTF-8 string from native code.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to `len` bytes of valid UTF-8.
+unsafe fn str_from_raw(ptr: *const u8, len: usize) -> Str {
+    if ptr.is_null() {
+        return Str::default();
+    }
+    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+    Str::from(String::from(unsafe {
+        core::str::from_utf8_unchecked(bytes)
+    }))
+}
+
+impl CustomPhotoLibrary for FFIPhotoLibrary {
+    fn permission(&self) -> PhotoLibraryPermission {
+        unsafe { (self.permission_fn)().into_rust() }
+    }
+
+    fn request_permission(&self, callback: impl FnOnce(PhotoLibraryPermission) + 'static) {
+        let callback_box: Box<Box<dyn FnOnce(PhotoLibraryPermission)>> =
+            Box::new(Box::new(callback));
+        let callback_data = Box::into_raw(callback_box).cast::<()>();
+
+        unsafe extern "C" fn permission_trampoline(
+            data: *mut (),
+            permission: WuiPhotoLibraryPermission,
+        ) {
+            let callback =
+                unsafe { Box::from_raw(data.cast::<Box<dyn FnOnce(PhotoLibraryPermission)>>()) };
+            callback(unsafe { permission.into_rust() });
+        }
+
+        unsafe {
+            (self.request_permission_fn)(PhotoLibraryPermissionCallback {
+                data: callback_data,
+                call: permission_trampoline,
+            });
+        }
+    }
+
+    fn albums(&self, callback: impl FnOnce(Vec<Album>) + 'static) {
+        let callback_box: Box<Box<dyn FnOnce(Vec<Album>)>> = Box::new(Box::new(callback));
+        let callback_data = Box::into_raw(callback_box).cast::<()>();
+
+        unsafe extern "C" fn albums_trampoline(
+            data: *mut (),
+            albums: *const WuiPhotoAlbum,
+            len: usize,
+        ) {
+            let callback = unsafe { Box::from_raw(data.cast::<Box<dyn FnOnce(Vec<Album>)>>()) };
+            let albums = if albums.is_null() {
+                &[]
+            } else {
+                unsafe { core::slice::from_raw_parts(albums, len) }
+            };
+            let albums = albums
+                .iter()
+                .map(|album| unsafe {
+                    Album {
+                        id: str_from_raw(album.id_ptr, album.id_len),
+                        title: str_from_raw(album.title_ptr, album.title_len),
+                        count: album.count,
+                    }
+                })
+                .collect();
+            callback(albums);
+        }
+
+        unsafe {
+            (self.albums_fn)(PhotoLibraryAlbumsCallback {
+                data: callback_data,
+                call: albums_trampoline,
+            });
+        }
+    }
+
+    fn assets(&self, query: AssetQuery, callback: impl FnOnce(Vec<Asset>) + 'static) {
+        let callback_box: Box<Box<dyn FnOnce(Vec<Asset>)>> = Box::new(Box::new(callback));
+        let callback_data = Box::into_raw(callback_box).cast::<()>();
+
+        unsafe extern "C" fn assets_trampoline(
+            data: *mut (),
+            assets: *const WuiPhotoAsset,
+            len: usize,
+        ) {
+            let callback = unsafe { Box::from_raw(data.cast::<Box<dyn FnOnce(Vec<Asset>)>>()) };
+            let assets = if assets.is_null() {
+                &[]
+            } else {
+                unsafe { core::slice::from_raw_parts(assets, len) }
+            };
+            let assets = assets
+                .iter()
+                .filter_map(|asset| {
+                    let thumbnail =
+                        unsafe { str_from_raw(asset.thumbnail_ptr, asset.thumbnail_len) };
+                    let Ok(thumbnail) = thumbnail.parse::<Url>() else {
+                        tracing::warn!("Invalid thumbnail URL from native: {thumbnail}");
+                        return None;
+                    };
+                    let kind = match asset.media_type {
+                        media_type::VIDEO => AssetKind::Video,
+                        media_type::LIVE_PHOTO => AssetKind::LivePhoto,
+                        _ => AssetKind::Image,
+                    };
+                    Some(Asset {
+                        id: unsafe { str_from_raw(asset.id_ptr, asset.id_len) },
+                        kind,
+                        thumbnail,
+                    })
+                })
+                .collect();
+            callback(assets);
+        }
+
+        let (album_ptr, album_len) = query
+            .album
+            .as_deref()
+            .map_or((core::ptr::null(), 0), |album| {
+                (album.as_ptr(), album.len())
+            });
+        let ffi_query = WuiPhotoAssetQuery {
+            album_ptr,
+            album_len,
+            filter: query.filter.clone().into_ffi(),
+            offset: query.offset,
+            limit: query.limit.unwrap_or(0),
+        };
+
+        // `query` keeps the album identifier alive until native code returns.
+        unsafe {
+            (self.assets_fn)(
+                ffi_query,
+                PhotoLibraryAssetsCallback {
+                    data: callback_data,
+                    call: assets_trampoline,
+                },
+            );
+        }
+    }
+
+    fn load(&self, id: Str, callback: impl FnOnce(Media) + 'static) {
+        let callback_box: Box<Box<dyn FnOnce(Media)>> = Box::new(Box::new(callback));
+        let callback_data = Box::into_raw(callback_box).cast::<()>();
+
+        unsafe extern "C" fn load_trampoline(data: *mut (), result: MediaLoadResult) {
+            let callback = unsafe { Box::from_raw(data.cast::<Box<dyn FnOnce(Media)>>()) };
+            let media = unsafe { media_load_result_to_media(result) };
+            callback(media);
+        }
+
+        unsafe {
+            (self.load_fn)(
+                id.as_ptr(),
+                id.len(),
+                MediaLoadCallback {
+                    data: callback_data,
+                    call: load_trampoline,
+                },
+            );
+        }
+    }
+
+    fn observe(&self, on_change: impl Fn() + 'static) {
+        let callback_box: Box<Box<dyn Fn()>> = Box::new(Box::new(on_change));
+        let callback_data = Box::into_raw(callback_box).cast::<()>();
+
+        unsafe extern "C" fn change_trampoline(data: *const ()) {
+            let callback = unsafe { &*data.cast::<Box<dyn Fn()>>() };
+            callback();
+        }
+
+        unsafe extern "C" fn change_drop(data: *mut ()) {
+            drop(unsafe { Box::from_raw(data.cast::<Box<dyn Fn()>>()) });
+        }
+
+        unsafe {
+            (self.observe_fn)(PhotoLibraryChangeCallback {
+                data: callback_data,
+                call: change_trampoline,
+                drop: change_drop,
+            });
+        }
+    }
+}
+
+/// Installs a PhotoLibrary into the environment from native function pointers.
+///
+/// Native backends call this during initialization so apps can query albums and
+/// assets to build custom gallery UIs.
+///
+/// # Safety
+///
+/// The caller must ensure that:
+/// - `env` is a valid pointer to a `WuiEnv`
+/// - all function pointers are valid native photo library implementations
+/// - the change callback passed to `observe_fn` is only called on the main thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_photo_library(
+    env: *mut WuiEnv,
+    permission_fn: PhotoLibraryPermissionFn,
+    request_permission_fn: PhotoLibraryRequestPermissionFn,
+    albums_fn: PhotoLibraryAlbumsFn,
+    assets_fn: PhotoLibraryAssetsFn,
+    load_fn: PhotoLibraryLoadFn,
+    observe_fn: PhotoLibraryObserveFn,
+) {
+    if env.is_null() {
+        return;
+    }
+    let env = unsafe { &mut *env };
+
+    let library = PhotoLibrary::new(FFIPhotoLibrary {
+        permission_fn,
+        request_permission_fn,
+        albums_fn,
+        assets_fn,
+        load_fn,
+        observe_fn,
+    });
+
+    env.insert(library);
+}
+
+// =============================================================================
+// PhotoCache FFI - Environment Service Installation
+// =============================================================================
+
+use waterui_media::photo::{CustomPhotoCache, PhotoCache};
+
+/// Type alias for the native function starting or cancelling a prefetch of a URL.
+///
+/// Receives the URL as UTF-8 bytes that are only valid for the duration of the call.
+pub type PhotoCacheUrlFn = unsafe extern "C" fn(*const u8, usize);
+
+/// FFI-compatible photo cache implementation.
+struct FFIPhotoCache {
+    prefetch_fn: PhotoCacheUrlFn,
+    cancel_fn: Option<PhotoCacheUrlFn>,
+}
+
+impl CustomPhotoCache for FFIPhotoCache {
+    fn prefetch(&self, url: &Url) {
+        let url = url.as_str();
+        unsafe { (self.prefetch_fn)(url.as_ptr(), url.len()) }
+    }
+
+    fn cancel_prefetch(&self, url: &Url) {
+        if let Some(cancel_fn) = self.cancel_fn {
+            let url = url.as_str();
+            unsafe { cancel_fn(url.as_ptr(), url.len()) }
+        }
+    }
+}
+
+/// Installs a PhotoCache into the environment from native function pointers.
+///
+/// Native backends call this during initialization so that prefetch hints from
+/// scrolling lists can warm the image cache used by `Photo`.
+///
+/// # Safety
+///
+/// The caller must ensure that:
+/// - `env` is a valid pointer to a `WuiEnv`
+/// - `prefetch_fn` is a valid function pointer to the native image prefetcher
+/// - `cancel_fn` is either null or a valid function pointer cancelling a prefetch
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_photo_cache(
+    env: *mut WuiEnv,
+    prefetch_fn: PhotoCacheUrlFn,
+    cancel_fn: Option<PhotoCacheUrlFn>,
+) {
+    if env.is_null() {
+        return;
+    }
+    let env = unsafe { &mut *env };
+
+    env.insert(PhotoCache::new(FFIPhotoCache {
+        prefetch_fn,
+        cancel_fn,
+    }));
+}
@@ -41,3 +41,9 @@ pub mod progress;
 
 /// GPU surface FFI bindings for high-performance wgpu rendering
 pub mod gpu_surface;
+
+/// Jetpack Compose interop FFI bindings
+pub mod compose;
+
+/// SwiftUI interop FFI bindings
+pub mod swiftui;
@@ -1,13 +1,13 @@
 use crate::array::WuiArray;
-use crate::closure::WuiFn;
+use crate::closure::{WuiFn, WuiFnOnce};
 use crate::components::text::WuiText;
 use crate::reactive::{WuiBinding, WuiComputed};
-use crate::{IntoFFI, WuiAnyView};
-use waterui::Color;
+use crate::{IntoFFI, IntoRust, WuiAnyView};
+use waterui::{Color, Str};
 use waterui_core::handler::AnyViewBuilder;
 use waterui_core::id::Id;
 use waterui_navigation::tab::{Tab, TabPosition, Tabs};
-use waterui_navigation::{Bar, NavigationStack, NavigationView};
+use waterui_navigation::{Bar, GuardDecision, NavigationGuards, NavigationStack, NavigationView};
 
 into_ffi! {
     NavigationView,
@@ -38,17 +38,85 @@ ffi_view!(NavigationView, WuiNavigationView, navigation_view);
 pub struct WuiNavigationStack {
     /// The root view of the navigation stack.
     pub root: *mut WuiAnyView,
+    /// Whether an activity is running; show a progress indicator in the
+    /// navigation bar while it is true.
+    pub loading: *mut WuiComputed<bool>,
+    /// Label of the most recent running activity, for accessibility.
+    pub activity: *mut WuiComputed<Str>,
+    /// Guards consulted before navigating.
+    pub guards: *mut WuiNavigationGuards,
+    /// Whether a pop guard is registered; while it is true, check back
+    /// navigation started by the user with
+    /// `waterui_navigation_guards_check_pop` before popping.
+    pub pop_guarded: *mut WuiComputed<bool>,
 }
 
 impl IntoFFI for NavigationStack<(), ()> {
     type FFI = WuiNavigationStack;
     fn into_ffi(self) -> Self::FFI {
+        let activity = self.activity();
+        let loading = activity.is_active().into_ffi();
+        let label = activity.label().into_ffi();
+        let guards = self.guards().clone();
+        let pop_guarded = guards.is_guarding_pop().into_ffi();
         WuiNavigationStack {
             root: self.into_inner().into_ffi(),
+            loading,
+            activity: label,
+            guards: guards.into_ffi(),
+            pop_guarded,
         }
     }
 }
 
+opaque!(WuiNavigationGuards, NavigationGuards, navigation_guards);
+
+/// FFI-safe answer of the navigation guards.
+#[repr(C)]
+pub enum WuiGuardDecision {
+    /// Perform the navigation.
+    Allow,
+    /// Cancel the navigation.
+    Deny,
+    /// Cancel the navigation and push `view` instead.
+    Redirect { view: WuiNavigationView },
+}
+
+impl IntoFFI for GuardDecision {
+    type FFI = WuiGuardDecision;
+    fn into_ffi(self) -> Self::FFI {
+        match self {
+            Self::Allow => WuiGuardDecision::Allow,
+            Self::Deny => WuiGuardDecision::Deny,
+            Self::Redirect(view) => WuiGuardDecision::Redirect {
+                view: view.into_ffi(),
+            },
+        }
+    }
+}
+
+/// Asks the pop guards whether the top view may be popped.
+///
+/// Backends call this before a back navigation started by the user, such as
+/// a back button, swipe gesture or the browser's back button. `callback` is
+/// called exactly once, possibly after this function returns, for example
+/// when a guard waits for the user to confirm.
+///
+/// # Safety
+///
+/// `guards` must be a valid pointer to a `WuiNavigationGuards`, and
+/// `callback` must be a valid callback.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_navigation_guards_check_pop(
+    guards: *const WuiNavigationGuards,
+    callback: WuiFnOnce<WuiGuardDecision>,
+) {
+    unsafe {
+        let callback = callback.into_rust();
+        (*guards).check_pop(move |decision| callback(decision.into_ffi()));
+    }
+}
+
 ffi_view!(NavigationStack<(),()>, WuiNavigationStack, navigation_stack);
 
 /// Position of the tab bar within the tab container.
@@ -0,0 +1,28 @@
+use waterui::{Str, component::swiftui::SwiftUIConfig};
+
+use crate::{IntoRust, WuiStr, reactive::WuiComputed};
+
+into_ffi! {
+    SwiftUIConfig,
+    /// A SwiftUI view hosted by the Apple backend.
+    pub struct WuiSwiftUIView {
+        identifier: WuiStr,
+        params: *mut WuiComputed<Str>,
+    }
+}
+
+ffi_view!(SwiftUIConfig, WuiSwiftUIView, swiftui_view);
+
+/// Registers `identifier` as a SwiftUI view the Swift side can build.
+///
+/// Call this on the main thread for every registered factory before building
+/// the app's views; unregistered identifiers render their fallback.
+///
+/// # Safety
+///
+/// `identifier` must be a valid `WuiStr`, which is consumed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_register_swiftui_view(identifier: WuiStr) {
+    let identifier = unsafe { identifier.into_rust() };
+    waterui::component::swiftui::register(&identifier);
+}
@@ -1,12 +1,22 @@
+use crate::action::WuiAction;
 use crate::array::WuiArray;
 use crate::color::WuiColor;
-use crate::reactive::WuiComputed;
-use crate::{IntoFFI, IntoRust, WuiEnv, WuiStr, ffi_computed, ffi_computed_ctor, ffi_reactive};
+use crate::reactive::{WuiBinding, WuiComputed, WuiWatcher, WuiWatcherGuard, WuiWatcherMetadata};
+use crate::{
+    IntoFFI, IntoNullableFFI, IntoRust, WuiEnv, WuiStr, ffi_computed, ffi_computed_ctor,
+    ffi_reactive,
+};
+use alloc::boxed::Box;
 use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::num::NonZeroUsize;
+use core::ptr::null_mut;
+use waterui::Signal;
 use waterui::view::ConfigurableView;
 use waterui_text::font::{Font, FontWeight, ResolvedFont};
-use waterui_text::styled::{Style, StyledStr};
-use waterui_text::{Text, TextConfig};
+use waterui_text::styled::{Style, StyledStr, TapAction};
+use waterui_text::update::TextUpdate;
+use waterui_text::{Text, TextAlignment, TextConfig, TruncationMode};
 
 into_ffi! {
     ResolvedFont,
@@ -50,6 +60,22 @@ into_ffi! {
         strikethrough: bool,
         foreground: *mut WuiColor,
         background: *mut WuiColor,
+        underline_color: *mut WuiColor,
+        pill: bool,
+        on_tap: *mut WuiAction,
+    }
+}
+
+// A tappable chunk hands the backend its own action; call it with
+// `waterui_call_action` and release it with `waterui_drop_action`.
+impl IntoNullableFFI for TapAction {
+    type FFI = *mut WuiAction;
+    fn into_ffi(self) -> Self::FFI {
+        let action: waterui_core::handler::BoxHandler<()> = Box::new(self);
+        action.into_ffi()
+    }
+    fn null() -> Self::FFI {
+        null_mut()
     }
 }
 
@@ -85,10 +111,58 @@ impl IntoFFI for StyledStr {
 
 ffi_computed!(StyledStr, WuiStyledStr);
 
-into_ffi! {
-    TextConfig,
-    pub struct WuiText {
-        content: *mut WuiComputed<StyledStr>,
+into_ffi!(
+    TruncationMode,
+    pub enum WuiTruncationMode {
+        Head,
+        Middle,
+        Tail,
+    }
+);
+
+into_ffi!(
+    TextAlignment,
+    pub enum WuiTextAlignment {
+        Leading,
+        Center,
+        Trailing,
+    }
+);
+
+#[repr(C)]
+pub struct WuiText {
+    pub content: *mut WuiComputed<StyledStr>,
+    /// Maximum number of lines, or 0 for no limit.
+    pub line_limit: usize,
+    pub truncation: WuiTruncationMode,
+    /// Write whether the content is truncated here; null when nobody observes it.
+    pub truncated: *mut WuiBinding<bool>,
+    pub line_spacing: f32,
+    pub paragraph_spacing: f32,
+    pub tracking: f32,
+    /// Smallest fraction of the font size to shrink to; 1.0 disables shrinking.
+    pub minimum_scale_factor: f32,
+    pub alignment: WuiTextAlignment,
+    /// Watch the content with `waterui_watch_computed_styled_str_updates`
+    /// instead of re-reading it on every change.
+    pub incremental: bool,
+}
+
+impl IntoFFI for TextConfig {
+    type FFI = WuiText;
+    fn into_ffi(self) -> Self::FFI {
+        WuiText {
+            content: self.content.into_ffi(),
+            line_limit: self.line_limit.map_or(0, NonZeroUsize::get),
+            truncation: self.truncation.into_ffi(),
+            truncated: self.truncated.map_or(null_mut(), IntoFFI::into_ffi),
+            line_spacing: self.line_spacing,
+            paragraph_spacing: self.paragraph_spacing,
+            tracking: self.tracking,
+            minimum_scale_factor: self.minimum_scale_factor,
+            alignment: self.alignment.into_ffi(),
+            incremental: self.incremental,
+        }
     }
 }
 
@@ -117,3 +191,84 @@ unsafe extern "C" fn waterui_resolve_font(
     let resolved = font.resolve(env);
     resolved.into_ffi()
 }
+
+/// The kind of a text update.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WuiTextUpdateKind {
+    Replace = 0,
+    Append = 1,
+    ReplaceRange = 2,
+}
+
+/// FFI-safe representation of a change to the plain text of a text view.
+#[repr(C)]
+pub struct WuiTextUpdate {
+    pub kind: WuiTextUpdateKind,
+    /// The replaced UTF-16 code units of `ReplaceRange` updates. Zero otherwise.
+    pub start: usize,
+    pub end: usize,
+    /// The new text, appended text or replacement.
+    pub text: WuiStr,
+}
+
+impl IntoFFI for TextUpdate {
+    type FFI = WuiTextUpdate;
+    fn into_ffi(self) -> Self::FFI {
+        let (kind, start, end, text) = match self {
+            Self::Replace(text) => (WuiTextUpdateKind::Replace, 0, 0, text),
+            Self::Append(text) => (WuiTextUpdateKind::Append, 0, 0, text),
+            Self::ReplaceRange { start, end, text } => {
+                (WuiTextUpdateKind::ReplaceRange, start, end, text)
+            }
+        };
+        WuiTextUpdate {
+            kind,
+            start,
+            end,
+            text: text.into_ffi(),
+        }
+    }
+}
+
+/// Creates a watcher receiving text updates from native callbacks.
+///
+/// # Safety
+/// All function pointers must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_new_watcher_text_update(
+    data: *mut (),
+    call: unsafe extern "C" fn(*mut (), WuiTextUpdate, *mut WuiWatcherMetadata),
+    drop: unsafe extern "C" fn(*mut ()),
+) -> *mut WuiWatcher<TextUpdate> {
+    Box::into_raw(Box::new(unsafe { WuiWatcher::new(data, call, drop) }))
+}
+
+/// Watches the content of an incremental text view.
+///
+/// Read the content once with `waterui_read_computed_styled_str`; the watcher
+/// then receives each change to its plain text as an update relative to the
+/// previous one, so appending a line to a log only sends that line.
+///
+/// # Safety
+/// The caller must ensure that `computed` and `watcher` are valid pointers.
+/// The watcher is consumed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_watch_computed_styled_str_updates(
+    computed: *const WuiComputed<StyledStr>,
+    watcher: *mut WuiWatcher<TextUpdate>,
+) -> *mut WuiWatcherGuard {
+    let computed = unsafe { &*computed };
+    let watcher = unsafe { Box::from_raw(watcher) };
+    let shown = RefCell::new(computed.get().to_plain());
+    let guard = computed.watch(move |ctx| {
+        let metadata = ctx.metadata().clone();
+        let content = ctx.into_value().to_plain();
+        let mut shown = shown.borrow_mut();
+        if let Some(update) = TextUpdate::diff(&shown, &content) {
+            *shown = content;
+            watcher.call(update, metadata);
+        }
+    });
+    guard.into_ffi()
+}
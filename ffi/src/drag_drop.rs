@@ -0,0 +1,167 @@
+//! FFI bindings for drag and drop.
+//!
+//! Native backends read `Metadata<DragSource>` and `Metadata<DropTarget>` from
+//! the view tree. When a drag starts they ask the source for its items, and
+//! when items are dropped they hand them to the target, converting between
+//! [`WuiDragItem`]s and the platform's pasteboard types.
+
+use alloc::vec::Vec;
+
+use waterui::drag_drop::{DragItem, DragSource, DragType, DropTarget};
+use waterui::media::Url;
+
+use crate::array::WuiArray;
+use crate::gesture::WuiGesturePoint;
+use crate::{IntoFFI, IntoRust, WuiMetadata, WuiStr};
+
+/// The kind of a dragged item.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WuiDragItemKind {
+    Text = 0,
+    Url = 1,
+    Image = 2,
+    Data = 3,
+}
+
+/// FFI-safe representation of a dragged item.
+#[repr(C)]
+pub struct WuiDragItem {
+    pub kind: WuiDragItemKind,
+    /// The MIME type of `Image` items, or the uniform type identifier or MIME
+    /// type of `Data` items. Empty otherwise.
+    pub type_id: WuiStr,
+    /// The text of `Text` items, or the URL of `Url` items. Empty otherwise.
+    pub text: WuiStr,
+    /// The bytes of `Image` and `Data` items. Empty otherwise.
+    pub data: WuiArray<u8>,
+}
+
+impl IntoFFI for DragItem {
+    type FFI = WuiDragItem;
+    fn into_ffi(self) -> Self::FFI {
+        let (kind, type_id, text, data) = match self {
+            Self::Text(text) => (WuiDragItemKind::Text, "".into(), text, Vec::new()),
+            Self::Url(url) => (WuiDragItemKind::Url, "".into(), url.inner(), Vec::new()),
+            Self::Image { mime, data } => (WuiDragItemKind::Image, mime, "".into(), data),
+            Self::Data { type_id, data } => (WuiDragItemKind::Data, type_id, "".into(), data),
+        };
+        WuiDragItem {
+            kind,
+            type_id: type_id.into_ffi(),
+            text: text.into_ffi(),
+            data: data.into_ffi(),
+        }
+    }
+}
+
+impl IntoRust for WuiDragItem {
+    type Rust = DragItem;
+    unsafe fn into_rust(self) -> Self::Rust {
+        let (type_id, text, data) = unsafe {
+            (
+                self.type_id.into_rust(),
+                self.text.into_rust(),
+                self.data.into_rust(),
+            )
+        };
+        match self.kind {
+            WuiDragItemKind::Text => DragItem::Text(text),
+            WuiDragItemKind::Url => DragItem::Url(Url::from(text)),
+            WuiDragItemKind::Image => DragItem::Image {
+                mime: type_id,
+                data,
+            },
+            WuiDragItemKind::Data => DragItem::Data { type_id, data },
+        }
+    }
+}
+
+/// FFI-safe representation of a type a drop target accepts.
+#[repr(C)]
+pub struct WuiDragType {
+    pub kind: WuiDragItemKind,
+    /// The uniform type identifier or MIME type for `Data`. Empty otherwise.
+    pub type_id: WuiStr,
+}
+
+impl IntoFFI for DragType {
+    type FFI = WuiDragType;
+    fn into_ffi(self) -> Self::FFI {
+        let (kind, type_id) = match self {
+            Self::Text => (WuiDragItemKind::Text, "".into()),
+            Self::Url => (WuiDragItemKind::Url, "".into()),
+            Self::Image => (WuiDragItemKind::Image, "".into()),
+            Self::Custom(type_id) => (WuiDragItemKind::Data, type_id),
+        };
+        WuiDragType {
+            kind,
+            type_id: type_id.into_ffi(),
+        }
+    }
+}
+
+opaque!(WuiDragSource, DragSource, drag_source);
+
+/// Type alias for Metadata<DragSource> FFI struct
+pub type WuiMetadataDragSource = WuiMetadata<*mut WuiDragSource>;
+
+// Generate waterui_metadata_drag_source_id() and waterui_force_as_metadata_drag_source()
+ffi_metadata!(DragSource, WuiMetadataDragSource, drag_source);
+
+/// Returns the items of a drag that is starting.
+///
+/// An empty array means the view should not be dragged.
+///
+/// # Safety
+/// The caller must ensure that `source` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_drag_source_items(
+    source: *const WuiDragSource,
+) -> WuiArray<WuiDragItem> {
+    unsafe { (*source).items() }.into_ffi()
+}
+
+opaque!(WuiDropTarget, DropTarget, drop_target);
+
+/// Type alias for Metadata<DropTarget> FFI struct
+pub type WuiMetadataDropTarget = WuiMetadata<*mut WuiDropTarget>;
+
+// Generate waterui_metadata_drop_target_id() and waterui_force_as_metadata_drop_target()
+ffi_metadata!(DropTarget, WuiMetadataDropTarget, drop_target);
+
+/// Returns the types of items a drop target accepts.
+///
+/// Backends register these with the platform so only matching drags
+/// highlight the view.
+///
+/// # Safety
+/// The caller must ensure that `target` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_drop_target_accepts(
+    target: *const WuiDropTarget,
+) -> WuiArray<WuiDragType> {
+    unsafe { (*target).accepts() }.to_vec().into_ffi()
+}
+
+/// Delivers dropped items to a drop target.
+///
+/// Returns whether the target took the items.
+///
+/// # Safety
+/// The caller must ensure that:
+/// - `target` is a valid pointer
+/// - `items` points to `len` initialized items, which are moved into Rust;
+///   the memory holding them stays owned by the caller
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_drop_target_perform(
+    target: *mut WuiDropTarget,
+    items: *mut WuiDragItem,
+    len: usize,
+    location: WuiGesturePoint,
+) -> bool {
+    let items = (0..len)
+        .map(|index| unsafe { items.add(index).read().into_rust() })
+        .collect();
+    unsafe { (*target).perform(items, location.into_rust()) }
+}
@@ -0,0 +1,59 @@
+//! # FFI Conversion Errors
+//!
+//! Strings and arrays received from native backends are trusted by default in
+//! release builds. Debug builds, or builds with the `validate` feature, check
+//! them on every conversion and panic with a [`WuiConversionError`] instead of
+//! silently invoking undefined behavior.
+//!
+//! The checked conversions ([`WuiStr::try_into_rust`](crate::WuiStr::try_into_rust),
+//! [`WuiArray::try_as_slice`](crate::array::WuiArray::try_as_slice)) are always
+//! available for call sites that want to recover from malformed data.
+
+use core::fmt::{self, Display};
+
+/// Whether data received from native code is validated on conversion.
+///
+/// Enabled in debug builds and by the `validate` feature.
+pub const VALIDATE: bool = cfg!(any(debug_assertions, feature = "validate"));
+
+/// Errors that can occur while converting native strings and arrays to Rust.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WuiConversionError {
+    /// A non-empty array has a null data pointer.
+    NullPointer {
+        /// Number of elements the array claims to hold.
+        len: usize,
+    },
+    /// The data pointer is not aligned for the element type.
+    Misaligned {
+        /// Required alignment of the element type in bytes.
+        align: usize,
+    },
+    /// The array is larger than any allocation can be.
+    TooLong {
+        /// Number of elements the array claims to hold.
+        len: usize,
+    },
+    /// A string is not valid UTF-8.
+    InvalidUtf8 {
+        /// Number of leading bytes that form valid UTF-8.
+        valid_up_to: usize,
+    },
+}
+
+impl Display for WuiConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NullPointer { len } => write!(f, "null pointer for array of {len} elements"),
+            Self::Misaligned { align } => {
+                write!(f, "array pointer is not aligned to {align} bytes")
+            }
+            Self::TooLong { len } => write!(f, "array length {len} exceeds isize::MAX bytes"),
+            Self::InvalidUtf8 { valid_up_to } => {
+                write!(f, "invalid UTF-8 after byte {valid_up_to}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for WuiConversionError {}
@@ -0,0 +1,83 @@
+//! FFI bindings for haptic and sound feedback.
+//!
+//! Native backends install a feedback player during initialization. Built-in
+//! controls call it with the feedback configured in the theme.
+
+use waterui_core::feedback::{CustomFeedbackPlayer, FeedbackPlayer, FeedbackSound, HapticStyle};
+
+use crate::{IntoFFI, WuiEnv};
+
+into_ffi! {HapticStyle,
+    pub enum WuiHapticStyle {
+        Light,
+        Medium,
+        Heavy,
+        Selection,
+        Success,
+        Warning,
+        Error,
+    }
+}
+
+into_ffi! {FeedbackSound,
+    pub enum WuiFeedbackSound {
+        Tap,
+        Toggle,
+        Tick,
+    }
+}
+
+/// Type alias for the native function playing a haptic pattern.
+pub type FeedbackHapticFn = unsafe extern "C" fn(WuiHapticStyle);
+
+/// Type alias for the native function playing a system sound.
+pub type FeedbackSoundFn = unsafe extern "C" fn(WuiFeedbackSound);
+
+/// FFI-compatible feedback player implementation.
+struct FFIFeedbackPlayer {
+    haptic_fn: Option<FeedbackHapticFn>,
+    sound_fn: Option<FeedbackSoundFn>,
+}
+
+impl CustomFeedbackPlayer for FFIFeedbackPlayer {
+    fn play_haptic(&self, style: HapticStyle) {
+        if let Some(haptic_fn) = self.haptic_fn {
+            unsafe { haptic_fn(style.into_ffi()) }
+        }
+    }
+
+    fn play_sound(&self, sound: FeedbackSound) {
+        if let Some(sound_fn) = self.sound_fn {
+            unsafe { sound_fn(sound.into_ffi()) }
+        }
+    }
+}
+
+/// Installs a FeedbackPlayer into the environment from native function pointers.
+///
+/// Native backends call this during initialization so built-in controls can
+/// play the haptics and sounds configured in the theme.
+///
+/// # Safety
+///
+/// The caller must ensure that:
+/// - `env` is a valid pointer to a `WuiEnv`
+/// - `haptic_fn` is either null, on platforms without haptics, or a valid
+///   function pointer playing a haptic pattern
+/// - `sound_fn` is either null or a valid function pointer playing a system sound
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_feedback_player(
+    env: *mut WuiEnv,
+    haptic_fn: Option<FeedbackHapticFn>,
+    sound_fn: Option<FeedbackSoundFn>,
+) {
+    if env.is_null() {
+        return;
+    }
+    let env = unsafe { &mut *env };
+
+    env.insert(FeedbackPlayer::new(FFIFeedbackPlayer {
+        haptic_fn,
+        sound_fn,
+    }));
+}
@@ -1,16 +1,24 @@
 //! FFI bindings for gesture types.
 
-use crate::IntoFFI;
 use crate::action::WuiAction;
+use crate::{IntoFFI, IntoRust, WuiEnv};
 use alloc::boxed::Box;
-use waterui::gesture::{Gesture, GestureObserver};
+use waterui::gesture::{
+    ClickEvent, DragEvent, Gesture, GestureEvent, GestureObserver, GesturePhase, GesturePoint,
+    HoverEvent, HoverPhase, LongPressEvent, MagnificationEvent, ModifierKeys, PressEvent,
+    RotationEvent, SecondaryClickEvent, TapEvent,
+};
+use waterui_core::{
+    handler::Handler,
+    time::{duration_from_secs_f32, saturating_millis},
+};
 
 /// FFI-safe representation of a gesture type.
 #[repr(C)]
 pub enum WuiGesture {
     /// A tap gesture requiring a specific number of taps.
     Tap { count: u32 },
-    /// A long-press gesture requiring a minimum duration.
+    /// A long-press gesture requiring a minimum duration, in milliseconds.
     LongPress { duration: u32 },
     /// A drag gesture with minimum distance threshold.
     Drag { min_distance: f32 },
@@ -25,6 +33,14 @@ pub enum WuiGesture {
         /// The gesture that runs after the first completes.
         then: *mut WuiGesture,
     },
+    /// A pointer hovering over the view.
+    Hover,
+    /// A pointer held down on the view.
+    Press,
+    /// A primary-button click requiring a specific number of clicks.
+    Click { count: u32 },
+    /// A secondary-button click.
+    SecondaryClick,
 }
 
 impl IntoFFI for Gesture {
@@ -33,7 +49,7 @@ impl IntoFFI for Gesture {
         match self {
             Gesture::Tap(tap) => WuiGesture::Tap { count: tap.count },
             Gesture::LongPress(lp) => WuiGesture::LongPress {
-                duration: lp.duration,
+                duration: u32::try_from(saturating_millis(lp.duration)).unwrap_or(u32::MAX),
             },
             Gesture::Drag(drag) => WuiGesture::Drag {
                 min_distance: drag.min_distance,
@@ -52,6 +68,10 @@ impl IntoFFI for Gesture {
                     then: then_gesture,
                 }
             }
+            Gesture::Hover(_) => WuiGesture::Hover,
+            Gesture::Press(_) => WuiGesture::Press,
+            Gesture::Click(click) => WuiGesture::Click { count: click.count },
+            Gesture::SecondaryClick(_) => WuiGesture::SecondaryClick,
             // Handle any future gesture variants
             _ => WuiGesture::Tap { count: 1 },
         }
@@ -95,3 +115,239 @@ impl IntoFFI for GestureObserver {
         }
     }
 }
+
+into_ffi! {GesturePhase,
+    /// Phase of a gesture interaction.
+    pub enum WuiGesturePhase {
+        Started,
+        Updated,
+        Ended,
+        Cancelled,
+    }
+}
+
+into_ffi! {HoverPhase,
+    /// How the pointer moved relative to a hovered view.
+    pub enum WuiHoverPhase {
+        Entered,
+        Moved,
+        Exited,
+    }
+}
+
+/// FFI-safe point in a gesture's coordinate space.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct WuiGesturePoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl IntoRust for WuiGesturePoint {
+    type Rust = GesturePoint;
+    unsafe fn into_rust(self) -> Self::Rust {
+        GesturePoint::new(self.x, self.y)
+    }
+}
+
+/// FFI-safe keyboard modifiers held during a pointer interaction.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct WuiModifierKeys {
+    pub shift: bool,
+    pub control: bool,
+    /// Option on Apple platforms, Alt elsewhere.
+    pub alt: bool,
+    /// Command on Apple platforms, the Windows or Super key elsewhere.
+    pub command: bool,
+}
+
+impl IntoRust for WuiModifierKeys {
+    type Rust = ModifierKeys;
+    unsafe fn into_rust(self) -> Self::Rust {
+        ModifierKeys {
+            shift: self.shift,
+            control: self.control,
+            alt: self.alt,
+            command: self.command,
+        }
+    }
+}
+
+/// FFI-safe payload of a recognised gesture, reported by the backend.
+#[repr(C)]
+pub enum WuiGestureEvent {
+    /// A tap at `location`, the `count`th in succession.
+    Tap {
+        location: WuiGesturePoint,
+        count: u32,
+    },
+    /// A long press held for `duration` seconds, `progress` of the way to firing.
+    LongPress {
+        phase: WuiGesturePhase,
+        location: WuiGesturePoint,
+        duration: f32,
+        progress: f32,
+    },
+    /// A drag, with translation since it started and velocity in points per second.
+    Drag {
+        phase: WuiGesturePhase,
+        location: WuiGesturePoint,
+        translation: WuiGesturePoint,
+        velocity: WuiGesturePoint,
+    },
+    /// A pinch around `center`, with scale relative to its start.
+    Magnification {
+        phase: WuiGesturePhase,
+        center: WuiGesturePoint,
+        scale: f32,
+        velocity: f32,
+    },
+    /// A rotation around `center`, with angle in radians relative to its start.
+    Rotation {
+        phase: WuiGesturePhase,
+        center: WuiGesturePoint,
+        angle: f32,
+        velocity: f32,
+    },
+    /// A pointer entered, moved within or left the view.
+    Hover {
+        phase: WuiHoverPhase,
+        location: WuiGesturePoint,
+    },
+    /// A pointer went down on the view (`Started`) or was released (`Ended`, `Cancelled`).
+    Press {
+        phase: WuiGesturePhase,
+        location: WuiGesturePoint,
+    },
+    /// The `count`th primary-button click in succession at `location`.
+    Click {
+        location: WuiGesturePoint,
+        count: u32,
+        modifiers: WuiModifierKeys,
+    },
+    /// A secondary-button click at `location`.
+    SecondaryClick {
+        location: WuiGesturePoint,
+        modifiers: WuiModifierKeys,
+    },
+}
+
+impl IntoRust for WuiGestureEvent {
+    type Rust = GestureEvent;
+    unsafe fn into_rust(self) -> Self::Rust {
+        unsafe {
+            match self {
+                Self::Tap { location, count } => GestureEvent::Tap(TapEvent {
+                    location: location.into_rust(),
+                    count,
+                }),
+                Self::LongPress {
+                    phase,
+                    location,
+                    duration,
+                    progress,
+                } => GestureEvent::LongPress(LongPressEvent {
+                    phase: phase.into_rust(),
+                    location: location.into_rust(),
+                    duration: duration_from_secs_f32(duration),
+                    progress,
+                }),
+                Self::Drag {
+                    phase,
+                    location,
+                    translation,
+                    velocity,
+                } => GestureEvent::Drag(DragEvent {
+                    phase: phase.into_rust(),
+                    location: location.into_rust(),
+                    translation: translation.into_rust(),
+                    velocity: velocity.into_rust(),
+                }),
+                Self::Magnification {
+                    phase,
+                    center,
+                    scale,
+                    velocity,
+                } => GestureEvent::Magnification(MagnificationEvent {
+                    phase: phase.into_rust(),
+                    center: center.into_rust(),
+                    scale,
+                    velocity,
+                }),
+                Self::Rotation {
+                    phase,
+                    center,
+                    angle,
+                    velocity,
+                } => GestureEvent::Rotation(RotationEvent {
+                    phase: phase.into_rust(),
+                    center: center.into_rust(),
+                    angle,
+                    velocity,
+                }),
+                Self::Hover { phase, location } => GestureEvent::Hover(HoverEvent {
+                    phase: phase.into_rust(),
+                    location: location.into_rust(),
+                }),
+                Self::Press { phase, location } => GestureEvent::Press(PressEvent {
+                    phase: phase.into_rust(),
+                    location: location.into_rust(),
+                }),
+                Self::Click {
+                    location,
+                    count,
+                    modifiers,
+                } => GestureEvent::Click(ClickEvent {
+                    location: location.into_rust(),
+                    count,
+                    modifiers: modifiers.into_rust(),
+                }),
+                Self::SecondaryClick {
+                    location,
+                    modifiers,
+                } => GestureEvent::SecondaryClick(SecondaryClickEvent {
+                    location: location.into_rust(),
+                    modifiers: modifiers.into_rust(),
+                }),
+            }
+        }
+    }
+}
+
+/// Calls a gesture observer's action with the event that triggered it.
+///
+/// The event is inserted into a copy of `env`, both as a `GestureEvent` and
+/// as its specific payload type, so typed handlers and `Use<DragEvent>`-style
+/// extractors both see it. Backends call this for every change of a
+/// continuous gesture, not just when it ends.
+///
+/// # Safety
+///
+/// * `action` must be a valid pointer to a `WuiAction` from a `WuiGestureObserver`.
+/// * `env` must be a valid pointer to a `WuiEnv`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_call_gesture_action(
+    action: *mut WuiAction,
+    env: *const WuiEnv,
+    event: WuiGestureEvent,
+) {
+    unsafe {
+        let event = event.into_rust();
+        let mut env = (*env).clone();
+        match &event {
+            GestureEvent::Tap(tap) => env.insert(tap.clone()),
+            GestureEvent::LongPress(press) => env.insert(press.clone()),
+            GestureEvent::Drag(drag) => env.insert(drag.clone()),
+            GestureEvent::Magnification(magnification) => env.insert(magnification.clone()),
+            GestureEvent::Rotation(rotation) => env.insert(rotation.clone()),
+            GestureEvent::Hover(hover) => env.insert(hover.clone()),
+            GestureEvent::Press(press) => env.insert(press.clone()),
+            GestureEvent::Click(click) => env.insert(click.clone()),
+            GestureEvent::SecondaryClick(click) => env.insert(click.clone()),
+            _ => {}
+        }
+        env.insert(event);
+        (*action).handle(&env);
+    }
+}
@@ -25,16 +25,25 @@ pub mod array;
 pub mod closure;
 pub mod color;
 pub mod components;
+pub mod drag_drop;
+pub mod error;
 pub mod event;
+pub mod feedback;
 pub mod gesture;
 mod type_id;
+#[cfg(any(target_os = "android", target_vendor = "apple"))]
 use tracing_subscriber::layer::SubscriberExt;
+#[cfg(any(target_os = "android", target_vendor = "apple"))]
 use tracing_subscriber::util::SubscriberInitExt;
 pub use type_id::WuiTypeId;
 pub mod id;
 pub mod reactive;
+pub mod share;
+pub mod stats;
+pub mod storage;
 pub mod theme;
 mod ty;
+pub mod undo;
 pub mod views;
 use core::ptr::null_mut;
 
@@ -48,6 +57,7 @@ pub mod window;
 use waterui_core::metadata::MetadataKey;
 
 use crate::array::WuiArray;
+use crate::error::{VALIDATE, WuiConversionError};
 #[macro_export]
 macro_rules! export {
     () => {
@@ -107,6 +117,9 @@ macro_rules! export {
     };
 }
 
+#[doc(hidden)]
+pub use tracing as __tracing;
+
 /// # Safety
 /// You have to ensure this is only called once, and on main thread.
 #[doc(hidden)]
@@ -139,9 +152,13 @@ pub unsafe fn __init() {
 
     #[cfg(not(any(target_os = "android", target_vendor = "apple")))]
     {
-        tracing_subscriber::fmt()
-            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
-            .init();
+        let subscriber = tracing_subscriber::fmt()
+            .with_env_filter(tracing_subscriber::EnvFilter::from_default_env());
+        // Report how long each instrumented span took when it closes
+        #[cfg(feature = "instrument")]
+        let subscriber =
+            subscriber.with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE);
+        subscriber.init();
     }
 
     init_global_executor(native_executor::NativeExecutor::new());
@@ -304,6 +321,295 @@ pub extern "C" fn waterui_env_new() -> *mut WuiEnv {
     env.into_ffi()
 }
 
+/// Installs the display scale signal (physical pixels per point) into the environment.
+///
+/// # Safety
+/// Both pointers must be valid. The signal pointer is consumed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_display_scale(
+    env: *mut WuiEnv,
+    scale: *mut WuiComputed<f32>,
+) {
+    if env.is_null() || scale.is_null() {
+        return;
+    }
+    let env = unsafe { &mut *env };
+    let computed = unsafe { Box::from_raw(scale) }.0;
+    waterui_core::display::install_display_scale(env, computed);
+}
+
+use crate::components::layout::WuiSize;
+use waterui_core::display::{ColorGamut, DisplayReporter};
+
+opaque!(WuiDisplayReporter, DisplayReporter, display_reporter);
+
+into_ffi! {ColorGamut,
+    pub enum WuiColorGamut {
+        Srgb,
+        DisplayP3,
+    }
+}
+
+/// Installs signals for every display property into the environment.
+///
+/// The returned reporter updates them; keep it alive for as long as the
+/// window exists, and set new values whenever the window moves to another
+/// screen or the screen's mode changes.
+///
+/// # Safety
+/// The caller must ensure that `env` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_display_reporter(
+    env: *mut WuiEnv,
+) -> *mut WuiDisplayReporter {
+    DisplayReporter::install(unsafe { &mut *env }).into_ffi()
+}
+
+/// Sets the backing scale factor (physical pixels per point).
+///
+/// # Safety
+/// The caller must ensure that `reporter` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_display_reporter_set_scale(
+    reporter: *const WuiDisplayReporter,
+    scale: f32,
+) {
+    unsafe { (&*reporter).set_scale(scale) }
+}
+
+/// Sets the maximum refresh rate of the display, in hertz.
+///
+/// # Safety
+/// The caller must ensure that `reporter` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_display_reporter_set_refresh_rate(
+    reporter: *const WuiDisplayReporter,
+    hertz: f32,
+) {
+    unsafe { (&*reporter).set_refresh_rate(hertz) }
+}
+
+/// Sets the color gamut of the display.
+///
+/// # Safety
+/// The caller must ensure that `reporter` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_display_reporter_set_color_gamut(
+    reporter: *const WuiDisplayReporter,
+    gamut: WuiColorGamut,
+) {
+    unsafe { (&*reporter).set_color_gamut(gamut.into_rust()) }
+}
+
+/// Sets whether the display can show high dynamic range content.
+///
+/// # Safety
+/// The caller must ensure that `reporter` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_display_reporter_set_supports_hdr(
+    reporter: *const WuiDisplayReporter,
+    supported: bool,
+) {
+    unsafe { (&*reporter).set_supports_hdr(supported) }
+}
+
+/// Sets the most headroom a color can use on the display right now.
+///
+/// `0.0` is SDR white; on Apple platforms pass the maximum EDR color
+/// component value minus one, and report it again when it changes.
+///
+/// # Safety
+/// The caller must ensure that `reporter` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_display_reporter_set_max_headroom(
+    reporter: *const WuiDisplayReporter,
+    headroom: f32,
+) {
+    unsafe { (&*reporter).set_max_headroom(headroom) }
+}
+
+/// Sets the size of the screen, in points.
+///
+/// # Safety
+/// The caller must ensure that `reporter` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_display_reporter_set_screen_size(
+    reporter: *const WuiDisplayReporter,
+    size: WuiSize,
+) {
+    unsafe { (&*reporter).set_screen_size(size.into_rust()) }
+}
+
+use waterui_core::platform::Platform;
+
+into_ffi! {Platform,
+    pub enum WuiPlatform {
+        Ios,
+        Macos,
+        Android,
+        Web,
+        Windows,
+        Linux,
+        Other,
+    }
+}
+
+/// Installs the platform the backend renders for into the environment.
+///
+/// # Safety
+/// The caller must ensure that `env` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_platform(env: *mut WuiEnv, platform: WuiPlatform) {
+    waterui_core::platform::install_platform(unsafe { &mut *env }, unsafe { platform.into_rust() });
+}
+
+use waterui_core::availability::OsVersion;
+
+/// FFI-safe representation of an operating system version.
+///
+/// On Android the major version is the API level.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct WuiOsVersion {
+    /// The major version.
+    pub major: u32,
+    /// The minor version.
+    pub minor: u32,
+    /// The patch version.
+    pub patch: u32,
+}
+
+impl IntoRust for WuiOsVersion {
+    type Rust = OsVersion;
+    unsafe fn into_rust(self) -> Self::Rust {
+        OsVersion::new(self.major, self.minor).patch(self.patch)
+    }
+}
+
+/// Installs the version of the OS the app is running on into the environment.
+///
+/// Components that need a newer version show their fallback when wrapped in
+/// `if_available`. Without it, every component is assumed to be available.
+///
+/// # Safety
+/// The caller must ensure that `env` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_os_version(env: *mut WuiEnv, version: WuiOsVersion) {
+    waterui_core::availability::install_os_version(unsafe { &mut *env }, unsafe {
+        version.into_rust()
+    });
+}
+
+// ========== Device idioms ==========
+// Ornaments (visionOS) and the digital crown (watchOS). Backends opt in for
+// the devices that have them; elsewhere both are no-ops.
+
+use waterui_core::idiom::{DigitalCrown, Ornament, OrnamentPlacement};
+
+into_ffi! {
+    OrnamentPlacement,
+    pub enum WuiOrnamentPlacement {
+        Top,
+        Bottom,
+        Leading,
+        Trailing,
+    }
+}
+
+into_ffi! {
+    Ornament,
+    /// FFI-safe representation of an ornament.
+    pub struct WuiOrnament {
+        placement: WuiOrnamentPlacement,
+        content: *mut WuiAnyView,
+    }
+}
+
+/// Type alias for Metadata<Ornament> FFI struct
+pub type WuiMetadataOrnament = WuiMetadata<WuiOrnament>;
+
+// Generate waterui_metadata_ornament_id() and waterui_force_as_metadata_ornament()
+ffi_metadata!(Ornament, WuiMetadataOrnament, ornament);
+
+/// Declares that the backend renders ornament metadata.
+///
+/// Only backends that can attach views outside a window, such as visionOS,
+/// should call this. Without it, ornaments are dropped from the view tree.
+///
+/// # Safety
+/// The caller must ensure that `env` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_ornament_support(env: *mut WuiEnv) {
+    waterui_core::idiom::install_ornament_support(unsafe { &mut *env });
+}
+
+opaque!(WuiDigitalCrown, DigitalCrown, digital_crown);
+
+/// Installs a digital crown into the environment and returns its reporter.
+///
+/// Only backends for devices with a crown, such as watchOS, should call
+/// this. Report rotations with `waterui_digital_crown_set`.
+///
+/// # Safety
+/// The caller must ensure that `env` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_digital_crown(
+    env: *mut WuiEnv,
+) -> *mut WuiDigitalCrown {
+    DigitalCrown::install(unsafe { &mut *env }).into_ffi()
+}
+
+/// Reports the accumulated rotation of the digital crown, in full turns.
+///
+/// # Safety
+/// The caller must ensure that `crown` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_digital_crown_set(crown: *const WuiDigitalCrown, rotation: f64) {
+    unsafe { (*crown).set(rotation) };
+}
+
+// ========== Network reachability ==========
+// Backends that observe the network report reachability so failed requests
+// can be retried as soon as the device is back online.
+
+use waterui_core::network::{NetworkMonitor, Reachability};
+
+into_ffi! {
+    Reachability,
+    pub enum WuiReachability {
+        Unknown,
+        Offline,
+        Online,
+    }
+}
+
+opaque!(WuiNetworkMonitor, NetworkMonitor, network_monitor);
+
+/// Installs a network monitor into the environment and returns its reporter.
+///
+/// Report every reachability change with `waterui_network_monitor_set`.
+///
+/// # Safety
+/// The caller must ensure that `env` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_network_monitor(
+    env: *mut WuiEnv,
+) -> *mut WuiNetworkMonitor {
+    NetworkMonitor::install(unsafe { &mut *env }).into_ffi()
+}
+
+/// Reports the current network reachability.
+///
+/// # Safety
+/// The caller must ensure that `monitor` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_network_monitor_set(
+    monitor: *const WuiNetworkMonitor,
+    reachability: WuiReachability,
+) {
+    unsafe { (*monitor).set(reachability.into_rust()) };
+}
+
 /// Gets the id of the anyview type as a 128-bit value for O(1) comparison.
 #[unsafe(no_mangle)]
 pub extern "C" fn waterui_anyview_id() -> WuiTypeId {
@@ -334,6 +640,7 @@ pub unsafe extern "C" fn waterui_view_body(
 ) -> *mut WuiAnyView {
     unsafe {
         let view = view.into_rust();
+        instrument!("body", component = view.name());
         let body = view.body(&*env);
 
         let body = AnyView::new(body);
@@ -400,11 +707,36 @@ impl IntoFFI for &'static str {
     }
 }
 
+impl WuiStr {
+    /// Converts the string to Rust, checking that it is valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WuiConversionError`] if the underlying array is malformed or
+    /// the bytes are not valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The string must have been produced by `IntoFFI` or a native backend following
+    /// the `WuiArray` contract.
+    pub unsafe fn try_into_rust(self) -> Result<Str, WuiConversionError> {
+        self.0.try_as_slice()?;
+        let bytes = unsafe { self.0.into_rust() };
+        Str::from_utf8(bytes).map_err(|error| WuiConversionError::InvalidUtf8 {
+            valid_up_to: error.utf8_error().valid_up_to(),
+        })
+    }
+}
+
 impl IntoRust for WuiStr {
     type Rust = Str;
     unsafe fn into_rust(self) -> Self::Rust {
+        if VALIDATE {
+            return unsafe { self.try_into_rust() }
+                .unwrap_or_else(|error| panic!("malformed WuiStr from native code: {error}"));
+        }
         let bytes = unsafe { self.0.into_rust() };
-        // Safety: We assume the input bytes are valid UTF-8
+        // Safety: validation is disabled, so we trust native code to pass valid UTF-8
         unsafe { Str::from_utf8_unchecked(bytes) }
     }
 }
@@ -493,18 +825,23 @@ pub type WuiMetadataOnEvent = WuiMetadata<WuiOnEvent>;
 ffi_metadata!(OnEvent, WuiMetadataOnEvent, on_event);
 
 // ========== Metadata<Background> FFI ==========
-// Used to apply background colors or images to views
+// Used to apply background colors, gradients or images to views
 
 use crate::color::WuiColor;
 use crate::reactive::WuiComputed;
 use waterui::Color;
 use waterui::background::Background;
+use waterui::color::Gradient;
 
 /// FFI-safe representation of a background.
 #[repr(C)]
 pub enum WuiBackground {
     /// A solid color background.
     Color { color: *mut WuiComputed<Color> },
+    /// A gradient background, resolved with `waterui_resolve_gradient`.
+    Gradient {
+        gradient: *mut WuiComputed<Gradient>,
+    },
     /// An image background.
     Image { image: *mut WuiComputed<Str> },
 }
@@ -516,6 +853,9 @@ impl IntoFFI for Background {
             Background::Color(color) => WuiBackground::Color {
                 color: color.into_ffi(),
             },
+            Background::Gradient(gradient) => WuiBackground::Gradient {
+                gradient: gradient.into_ffi(),
+            },
             Background::Image(image) => WuiBackground::Image {
                 image: image.into_ffi(),
             },
@@ -593,6 +933,36 @@ pub type WuiMetadataShadow = WuiMetadata<WuiShadow>;
 // Generate waterui_metadata_shadow_id() and waterui_force_as_metadata_shadow()
 ffi_metadata!(Shadow, WuiMetadataShadow, shadow);
 
+// ========== Metadata<ColorFilter> FFI ==========
+// Used to apply a color matrix to a view and its subtree
+
+use waterui::filter::ColorFilter;
+
+/// FFI-safe representation of a color filter.
+///
+/// Every filter is lowered to a row-major 4x5 RGBA color matrix, so renderers
+/// only need to implement a single color-matrix effect.
+#[repr(C)]
+pub struct WuiColorFilter {
+    /// Row-major 4x5 color matrix (the fifth column holds offsets).
+    pub matrix: [f32; 20],
+}
+
+impl IntoFFI for ColorFilter {
+    type FFI = WuiColorFilter;
+    fn into_ffi(self) -> Self::FFI {
+        WuiColorFilter {
+            matrix: self.matrix().values,
+        }
+    }
+}
+
+/// Type alias for Metadata<ColorFilter> FFI struct
+pub type WuiMetadataColorFilter = WuiMetadata<WuiColorFilter>;
+
+// Generate waterui_metadata_color_filter_id() and waterui_force_as_metadata_color_filter()
+ffi_metadata!(ColorFilter, WuiMetadataColorFilter, color_filter);
+
 // ========== Metadata<Focused> FFI ==========
 // Used to track focus state for views
 
@@ -673,6 +1043,90 @@ pub type WuiMetadataIgnoreSafeArea = WuiMetadata<WuiIgnoreSafeArea>;
 // Generate waterui_metadata_ignore_safe_area_id() and waterui_force_as_metadata_ignore_safe_area()
 ffi_metadata!(IgnoreSafeArea, WuiMetadataIgnoreSafeArea, ignore_safe_area);
 
+// ========== Metadata<ZIndex> FFI ==========
+// Used to reorder overlapping siblings
+
+use waterui_layout::stack::ZIndex;
+
+/// FFI-safe representation of a z-index.
+#[repr(C)]
+pub struct WuiZIndex {
+    /// Pointer to the computed index; higher values are drawn above.
+    pub index: *mut WuiComputed<i32>,
+}
+
+impl IntoFFI for ZIndex {
+    type FFI = WuiZIndex;
+    fn into_ffi(self) -> Self::FFI {
+        WuiZIndex {
+            index: self.0.into_ffi(),
+        }
+    }
+}
+
+/// Type alias for Metadata<ZIndex> FFI struct
+pub type WuiMetadataZIndex = WuiMetadata<WuiZIndex>;
+
+// Generate waterui_metadata_z_index_id() and waterui_force_as_metadata_z_index()
+ffi_metadata!(ZIndex, WuiMetadataZIndex, z_index);
+
+// ========== Metadata<PreferredColorScheme> FFI ==========
+// Used to force light or dark appearance on a subtree
+
+use waterui::theme::{ColorScheme, PreferredColorScheme};
+
+/// FFI-safe representation of a forced color scheme.
+#[repr(C)]
+pub struct WuiPreferredColorScheme {
+    /// Pointer to the computed scheme, always `Light` or `Dark`.
+    pub scheme: *mut WuiComputed<ColorScheme>,
+}
+
+impl IntoFFI for PreferredColorScheme {
+    type FFI = WuiPreferredColorScheme;
+    fn into_ffi(self) -> Self::FFI {
+        WuiPreferredColorScheme {
+            scheme: self.0.into_ffi(),
+        }
+    }
+}
+
+/// Type alias for Metadata<PreferredColorScheme> FFI struct
+pub type WuiMetadataPreferredColorScheme = WuiMetadata<WuiPreferredColorScheme>;
+
+// Generate waterui_metadata_preferred_color_scheme_id() and waterui_force_as_metadata_preferred_color_scheme()
+ffi_metadata!(
+    PreferredColorScheme,
+    WuiMetadataPreferredColorScheme,
+    preferred_color_scheme
+);
+
+// ========== Metadata<ViewId> FFI ==========
+// Used to keep or rebuild native views when a Dynamic swaps its content
+
+use waterui_core::id::ViewId;
+
+/// FFI-safe representation of a view identity.
+#[repr(C)]
+pub struct WuiViewId {
+    /// Keep the native view if this matches the previous content's identity,
+    /// rebuild it otherwise.
+    pub id: u64,
+}
+
+impl IntoFFI for ViewId {
+    type FFI = WuiViewId;
+    fn into_ffi(self) -> Self::FFI {
+        WuiViewId { id: self.0 }
+    }
+}
+
+/// Type alias for Metadata<ViewId> FFI struct
+pub type WuiMetadataViewId = WuiMetadata<WuiViewId>;
+
+// Generate waterui_metadata_view_id_id() and waterui_force_as_metadata_view_id()
+ffi_metadata!(ViewId, WuiMetadataViewId, view_id);
+
 // ========== Metadata<Retain> FFI ==========
 // Used to keep values alive for the lifetime of a view (e.g., watcher guards)
 
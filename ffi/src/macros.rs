@@ -1,3 +1,28 @@
+/// Enters a tracing span until the end of the enclosing scope.
+///
+/// Expands to nothing unless the `instrument` feature is enabled, so the
+/// render path pays nothing for it by default. The feature is checked here
+/// rather than in the expansion, so `ffi_view!` and `ffi_metadata!` users
+/// follow this crate's feature instead of their own.
+#[cfg(feature = "instrument")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! instrument {
+    ($name:literal, $($fields:tt)*) => {
+        let _span = $crate::__tracing::info_span!(target: "waterui::frame", $name, $($fields)*).entered();
+    };
+}
+
+/// Enters a tracing span until the end of the enclosing scope.
+///
+/// Expands to nothing unless the `instrument` feature is enabled.
+#[cfg(not(feature = "instrument"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! instrument {
+    ($name:literal, $($fields:tt)*) => {};
+}
+
 #[macro_export]
 /// Declares types as FFI-safe by implementing `IntoFFI` and `IntoRust` traits.
 ///
@@ -44,6 +69,7 @@ macro_rules! ffi_view {
         /// The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
         #[unsafe(no_mangle)]
         pub unsafe extern "C" fn [<waterui_force_as_ $ident>](view: *mut $crate::WuiAnyView) -> $ffi {
+            $crate::instrument!("into_ffi", component = core::any::type_name::<$view>());
             unsafe {
                 let any: waterui::AnyView = $crate::IntoRust::into_rust(view);
                 let view = (*any.downcast_unchecked::<waterui_core::Native<$view>>());
@@ -90,6 +116,7 @@ macro_rules! ffi_metadata {
             pub unsafe extern "C" fn [<waterui_force_as_metadata_ $ident>](
                 view: *mut $crate::WuiAnyView
             ) -> $ffi {
+                $crate::instrument!("into_ffi", component = core::any::type_name::<$ty>());
                 unsafe {
                     let any: waterui::AnyView = $crate::IntoRust::into_rust(view);
                     // Metadata<T> is stored directly, not wrapped in Native<T>
@@ -105,14 +132,25 @@ macro_rules! ffi_metadata {
 macro_rules! opaque {
     ($name:ident,$ty:ty,$ident:tt) => {
         #[allow(nonstandard_style)]
-        pub struct $name(pub(crate) $ty);
+        pub struct $name(
+            pub(crate) $ty,
+            // Only held to count the handle while it is alive
+            #[allow(dead_code)] waterui_core::stats::Live,
+        );
 
         $crate::impl_deref!($name, $ty);
 
+        impl From<$ty> for $name {
+            fn from(value: $ty) -> Self {
+                use waterui_core::stats::{Kind, Live};
+                Self(value, Live::of::<$ty>(Kind::FfiHandle))
+            }
+        }
+
         impl $crate::IntoFFI for $ty {
             type FFI = *mut $name;
             fn into_ffi(self) -> Self::FFI {
-                alloc::boxed::Box::into_raw(alloc::boxed::Box::new($name(self)))
+                alloc::boxed::Box::into_raw(alloc::boxed::Box::new($name::from(self)))
             }
         }
 
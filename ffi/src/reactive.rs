@@ -13,14 +13,23 @@ use waterui::reactive::watcher::BoxWatcherGuard;
 use waterui::reactive::watcher::Metadata;
 use waterui::{AnyView, Str};
 use waterui_core::id::Id;
+use waterui_core::notify;
+use waterui_core::stats::{Kind, Live};
 use waterui_form::picker::PickerItem;
 use waterui_media::live::LivePhotoSource;
 opaque!(WuiWatcherMetadata, Metadata, watcher_metadata);
 
 opaque!(WuiWatcherGuard, BoxWatcherGuard);
 
-#[repr(transparent)]
-pub struct WuiComputed<T>(pub(crate) waterui::Computed<T>);
+pub struct WuiComputed<T>(pub(crate) waterui::Computed<T>, #[allow(dead_code)] Live);
+
+impl<T> From<waterui::Computed<T>> for WuiComputed<T> {
+    fn from(computed: waterui::Computed<T>) -> Self {
+        #[cfg(feature = "std")]
+        waterui_core::strict::binding_created(core::any::type_name::<waterui::Computed<T>>());
+        Self(computed, Live::of::<waterui::Computed<T>>(Kind::Binding))
+    }
+}
 
 impl<T> WuiComputed<T>
 where
@@ -40,7 +49,7 @@ where
     where
         T: IntoFFI + 'static,
     {
-        unsafe { WuiComputed(Computed::new(FFIComputed::new(data, get, watch, drop))) }
+        unsafe { WuiComputed::from(Computed::new(FFIComputed::new(data, get, watch, drop))) }
     }
 }
 
@@ -135,7 +144,7 @@ impl<T: 'static> IntoFFI for waterui::Computed<T> {
     type FFI = *mut WuiComputed<T>;
 
     fn into_ffi(self) -> Self::FFI {
-        Box::into_raw(Box::new(WuiComputed(self)))
+        Box::into_raw(Box::new(WuiComputed::from(self)))
     }
 }
 
@@ -143,7 +152,7 @@ impl<T> IntoFFI for waterui::Binding<T> {
     type FFI = *mut WuiBinding<T>;
 
     fn into_ffi(self) -> Self::FFI {
-        Box::into_raw(Box::new(WuiBinding(self)))
+        Box::into_raw(Box::new(WuiBinding::from(self)))
     }
 }
 
@@ -165,8 +174,15 @@ impl<T> Deref for WuiBinding<T> {
     }
 }
 
-#[repr(transparent)]
-pub struct WuiBinding<T: 'static>(pub(crate) waterui::Binding<T>);
+pub struct WuiBinding<T: 'static>(pub(crate) waterui::Binding<T>, #[allow(dead_code)] Live);
+
+impl<T> From<waterui::Binding<T>> for WuiBinding<T> {
+    fn from(binding: waterui::Binding<T>) -> Self {
+        #[cfg(feature = "std")]
+        waterui_core::strict::binding_created(core::any::type_name::<waterui::Binding<T>>());
+        Self(binding, Live::of::<waterui::Binding<T>>(Kind::Binding))
+    }
+}
 
 impl<T> OpaqueType for WuiBinding<T> {}
 
@@ -419,6 +435,7 @@ impl<T: IntoFFI> WuiWatcher<T> {
         struct Cleaner {
             data: *mut (),
             drop: unsafe extern "C" fn(*mut ()),
+            _live: Live,
         }
 
         impl Drop for Cleaner {
@@ -426,7 +443,11 @@ impl<T: IntoFFI> WuiWatcher<T> {
                 unsafe { (self.drop)(self.data) }
             }
         }
-        let cleaner = Cleaner { data, drop };
+        let cleaner = Cleaner {
+            data,
+            drop,
+            _live: Live::of::<T>(Kind::Watcher),
+        };
         WuiWatcher(Rc::new(move |ctx| {
             let _ = &cleaner; // Closure captures cleaner to ensure it lives as long as the watcher.
             let metadata = ctx.metadata().clone();
@@ -437,8 +458,19 @@ impl<T: IntoFFI> WuiWatcher<T> {
         }))
     }
 
-    pub fn call(&self, value: T, metadata: Metadata) {
-        (self.0)(Context::new(value, metadata));
+    /// Delivers `value` to the native watcher through [`notify::dispatch`],
+    /// so writes made by native code from inside the callback are deferred.
+    pub fn call(&self, value: T, metadata: Metadata)
+    where
+        T: 'static,
+    {
+        let name = core::any::type_name::<T>();
+        let watcher = self.0.clone();
+        notify::dispatch(name, move || {
+            #[cfg(feature = "std")]
+            let _scope = waterui_core::strict::watcher_scope(name);
+            watcher(Context::new(value, metadata));
+        });
     }
 }
 
@@ -471,7 +503,9 @@ pub extern "C" fn waterui_new_watcher_guard(
 
     let cleaner = Cleaner { data, drop };
     impl WatcherGuard for Cleaner {}
-    Box::into_raw(Box::new(WuiWatcherGuard(Box::new(cleaner))))
+    Box::into_raw(Box::new(WuiWatcherGuard::from(
+        Box::new(cleaner) as BoxWatcherGuard
+    )))
 }
 
 // Custom Secure binding implementation
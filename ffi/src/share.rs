@@ -0,0 +1,122 @@
+//! FFI bindings for the system share sheet.
+//!
+//! Native backends install a sharer during initialization that presents
+//! `UIActivityViewController`, the Android Sharesheet or the Web Share API
+//! with the items apps pass to `waterui::share::share`.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use waterui::share::{CustomSharer, ShareItem, ShareOutcome, Sharer};
+
+use crate::array::WuiArray;
+use crate::{IntoFFI, IntoRust, WuiEnv, WuiStr};
+
+/// The kind of a shared item.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WuiShareItemKind {
+    Text = 0,
+    Url = 1,
+    File = 2,
+}
+
+/// FFI-safe representation of a shared item.
+#[repr(C)]
+pub struct WuiShareItem {
+    pub kind: WuiShareItemKind,
+    /// The text of `Text` items, the URL of `Url` items, or the file name of
+    /// `File` items.
+    pub text: WuiStr,
+    /// The MIME type of `File` items. Empty otherwise.
+    pub mime: WuiStr,
+    /// The contents of `File` items. Empty otherwise.
+    pub data: WuiArray<u8>,
+}
+
+impl IntoFFI for ShareItem {
+    type FFI = WuiShareItem;
+    fn into_ffi(self) -> Self::FFI {
+        let (kind, text, mime, data) = match self {
+            Self::Text(text) => (WuiShareItemKind::Text, text, "".into(), Vec::new()),
+            Self::Url(url) => (WuiShareItemKind::Url, url.inner(), "".into(), Vec::new()),
+            Self::File { name, mime, data } => (WuiShareItemKind::File, name, mime, data),
+        };
+        WuiShareItem {
+            kind,
+            text: text.into_ffi(),
+            mime: mime.into_ffi(),
+            data: data.into_ffi(),
+        }
+    }
+}
+
+into_ffi! {ShareOutcome,
+    pub enum WuiShareOutcome {
+        Completed,
+        Cancelled,
+        Unavailable,
+    }
+}
+
+/// A callback for receiving the outcome of a share.
+///
+/// Native code calls it once, after the share sheet is dismissed.
+#[repr(C)]
+pub struct ShareCompletion {
+    /// Opaque pointer to the callback data.
+    pub data: *mut (),
+    /// Function to call with the outcome. This consumes the callback.
+    pub call: unsafe extern "C" fn(*mut (), WuiShareOutcome),
+}
+
+/// Type alias for the native function presenting the share sheet.
+///
+/// The items array is owned by native code, which must drop it.
+pub type ShareFn = unsafe extern "C" fn(WuiArray<WuiShareItem>, ShareCompletion);
+
+/// FFI-compatible sharer implementation.
+struct FFISharer {
+    share_fn: ShareFn,
+}
+
+impl CustomSharer for FFISharer {
+    fn share(&self, items: Vec<ShareItem>, completion: impl FnOnce(ShareOutcome) + 'static) {
+        let completion_box: Box<Box<dyn FnOnce(ShareOutcome)>> = Box::new(Box::new(completion));
+        let completion_data = Box::into_raw(completion_box).cast::<()>();
+
+        unsafe extern "C" fn completion_trampoline(data: *mut (), outcome: WuiShareOutcome) {
+            let completion = unsafe { Box::from_raw(data.cast::<Box<dyn FnOnce(ShareOutcome)>>()) };
+            completion(unsafe { outcome.into_rust() });
+        }
+
+        let ffi_completion = ShareCompletion {
+            data: completion_data,
+            call: completion_trampoline,
+        };
+
+        unsafe {
+            (self.share_fn)(items.into_ffi(), ffi_completion);
+        }
+    }
+}
+
+/// Installs a Sharer into the environment from a native function pointer.
+///
+/// Native backends call this during initialization so apps can present the
+/// system share sheet.
+///
+/// # Safety
+///
+/// The caller must ensure that:
+/// - `env` is a valid pointer to a `WuiEnv`
+/// - `share_fn` is a valid function pointer presenting the native share sheet
+///   that calls its completion exactly once, on the main thread
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_sharer(env: *mut WuiEnv, share_fn: ShareFn) {
+    if env.is_null() {
+        return;
+    }
+    let env = unsafe { &mut *env };
+
+    env.insert(Sharer::new(FFISharer { share_fn }));
+}
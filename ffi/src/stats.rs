@@ -0,0 +1,53 @@
+//! Live object counters for leak diagnostics.
+//!
+//! Counts are only collected in debug builds; release builds report zero.
+
+use waterui_core::stats::{self, Stats};
+
+use crate::IntoFFI;
+
+/// Totals of live objects, see `waterui_core::stats::Stats`.
+#[repr(C)]
+pub struct WuiStats {
+    /// Live views.
+    pub views: usize,
+    /// Live bindings.
+    pub bindings: usize,
+    /// Live watchers.
+    pub watchers: usize,
+    /// Live FFI handles.
+    pub ffi_handles: usize,
+}
+
+impl IntoFFI for Stats {
+    type FFI = WuiStats;
+    fn into_ffi(self) -> Self::FFI {
+        WuiStats {
+            views: self.views,
+            bindings: self.bindings,
+            watchers: self.watchers,
+            ffi_handles: self.ffi_handles,
+        }
+    }
+}
+
+/// Returns the number of live views, bindings, watchers and FFI handles.
+#[unsafe(no_mangle)]
+pub extern "C" fn waterui_debug_stats() -> WuiStats {
+    stats::stats().into_ffi()
+}
+
+/// Logs every type that still has live objects and returns whether there were any.
+///
+/// Native should call this when the app is about to exit, after releasing its views.
+#[unsafe(no_mangle)]
+pub extern "C" fn waterui_debug_report_leaks() -> bool {
+    #[cfg(debug_assertions)]
+    {
+        waterui::debug::report_leaks()
+    }
+    #[cfg(not(debug_assertions))]
+    {
+        false
+    }
+}
@@ -0,0 +1,104 @@
+//! FFI bindings for persistent key-value storage.
+//!
+//! Native backends install a storage backed by the platform's preferences
+//! store during initialization. App settings are persisted through it.
+
+use alloc::string::String;
+
+use waterui::storage::{CustomStorage, Storage};
+
+use crate::WuiEnv;
+
+/// Receives a stored value from native code.
+///
+/// Native code calls `call` with `data` and the value as UTF-8 bytes, which
+/// only need to stay valid for the duration of the call.
+#[repr(C)]
+pub struct StorageValueCallback {
+    /// Opaque pointer to the receiving slot.
+    pub data: *mut (),
+    /// Function receiving the value.
+    pub call: unsafe extern "C" fn(*mut (), *const u8, usize),
+}
+
+/// Type alias for the native function loading a value.
+///
+/// Receives the key as UTF-8 bytes and calls the callback before returning if
+/// a value is stored under it.
+pub type StorageLoadFn = unsafe extern "C" fn(*const u8, usize, StorageValueCallback);
+
+/// Type alias for the native function storing a value.
+///
+/// Receives the key and the value as UTF-8 bytes that are only valid for the
+/// duration of the call.
+pub type StorageStoreFn = unsafe extern "C" fn(*const u8, usize, *const u8, usize);
+
+/// Type alias for the native function removing a value.
+pub type StorageRemoveFn = unsafe extern "C" fn(*const u8, usize);
+
+/// FFI-compatible storage implementation.
+struct FFIStorage {
+    load_fn: StorageLoadFn,
+    store_fn: StorageStoreFn,
+    remove_fn: StorageRemoveFn,
+}
+
+unsafe extern "C" fn receive_value(data: *mut (), ptr: *const u8, len: usize) {
+    if ptr.is_null() {
+        return;
+    }
+    let slot = unsafe { &mut *data.cast::<Option<String>>() };
+    let bytes = unsafe { core::slice::from_raw_parts(ptr, len) };
+    *slot = Some(String::from_utf8_lossy(bytes).into_owned());
+}
+
+impl CustomStorage for FFIStorage {
+    fn load(&self, key: &str) -> Option<String> {
+        let mut value: Option<String> = None;
+        let callback = StorageValueCallback {
+            data: (&raw mut value).cast(),
+            call: receive_value,
+        };
+        unsafe { (self.load_fn)(key.as_ptr(), key.len(), callback) };
+        value
+    }
+
+    fn store(&self, key: &str, value: &str) {
+        unsafe { (self.store_fn)(key.as_ptr(), key.len(), value.as_ptr(), value.len()) };
+    }
+
+    fn remove(&self, key: &str) {
+        unsafe { (self.remove_fn)(key.as_ptr(), key.len()) };
+    }
+}
+
+/// Installs a Storage into the environment from native function pointers.
+///
+/// Native backends call this during initialization so app settings persist
+/// across launches.
+///
+/// # Safety
+///
+/// The caller must ensure that:
+/// - `env` is a valid pointer to a `WuiEnv`
+/// - `load_fn`, `store_fn` and `remove_fn` are valid function pointers to
+///   the native preferences store
+/// - `load_fn` only calls its callback before returning
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_install_storage(
+    env: *mut WuiEnv,
+    load_fn: StorageLoadFn,
+    store_fn: StorageStoreFn,
+    remove_fn: StorageRemoveFn,
+) {
+    if env.is_null() {
+        return;
+    }
+    let env = unsafe { &mut *env };
+
+    env.insert(Storage::new(FFIStorage {
+        load_fn,
+        store_fn,
+        remove_fn,
+    }));
+}
@@ -22,6 +22,22 @@
 //! waterui_theme_install_color_scheme(env, scheme);
 //! ```
 //!
+//! To let apps follow the system, report the OS appearance separately. It is
+//! used for `WuiColorScheme_System` and when the app sets no color scheme:
+//!
+//! ```c
+//! waterui_theme_install_system_color_scheme(env, os_appearance_signal);
+//! ```
+//!
+//! Backends notified through callbacks, such as an Android configuration
+//! change or a web `prefers-color-scheme` media query listener, can install a
+//! reporter instead and set the appearance as it changes:
+//!
+//! ```c
+//! WuiSystemColorScheme* system = waterui_theme_install_system_color_scheme_reporter(env, WuiColorScheme_Dark);
+//! waterui_system_color_scheme_set(system, WuiColorScheme_Light);
+//! ```
+//!
 //! ## Installing Theme Slots
 //!
 //! Use the slot enums to install colors and fonts:
@@ -53,7 +69,9 @@ use crate::components::text::WuiResolvedFont;
 use crate::{IntoFFI, IntoRust, WuiEnv, ffi_computed, ffi_computed_ctor, reactive::WuiComputed};
 use nami::SignalExt;
 use waterui::theme::{
-    self, color, install_color_scheme, install_color_signal, install_font_signal,
+    self, SystemColorScheme, color, install_color_scheme, install_color_signal,
+    install_color_signals, install_destructive_color, install_destructive_colors,
+    install_font_signal, install_system_color_scheme,
 };
 use waterui_color::ResolvedColor;
 use waterui_core::resolve::Resolvable;
@@ -73,6 +91,8 @@ pub enum WuiColorScheme {
     Light = 0,
     /// Dark appearance.
     Dark = 1,
+    /// Follows the operating system appearance.
+    System = 2,
 }
 
 impl From<WuiColorScheme> for theme::ColorScheme {
@@ -80,6 +100,7 @@ impl From<WuiColorScheme> for theme::ColorScheme {
         match value {
             WuiColorScheme::Light => theme::ColorScheme::Light,
             WuiColorScheme::Dark => theme::ColorScheme::Dark,
+            WuiColorScheme::System => theme::ColorScheme::System,
         }
     }
 }
@@ -89,6 +110,7 @@ impl From<theme::ColorScheme> for WuiColorScheme {
         match value {
             theme::ColorScheme::Light => WuiColorScheme::Light,
             theme::ColorScheme::Dark => WuiColorScheme::Dark,
+            theme::ColorScheme::System => WuiColorScheme::System,
         }
     }
 }
@@ -138,8 +160,59 @@ pub unsafe extern "C" fn waterui_theme_install_color_scheme(
     install_color_scheme(env, computed);
 }
 
+/// Installs the operating system appearance signal into the environment.
+///
+/// It drives `WuiColorScheme_System` and is used when the app sets no color
+/// scheme. The signal should only report `Light` or `Dark`.
+///
+/// # Safety
+/// The signal pointer must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_theme_install_system_color_scheme(
+    env: *mut WuiEnv,
+    signal: *mut WuiComputed<theme::ColorScheme>,
+) {
+    if env.is_null() || signal.is_null() {
+        return;
+    }
+    let env = unsafe { &mut *env };
+    let computed = unsafe { Box::from_raw(signal) }.0;
+    install_system_color_scheme(env, computed);
+}
+
+opaque!(WuiSystemColorScheme, SystemColorScheme, system_color_scheme);
+
+/// Installs the operating system appearance and returns a reporter for it.
+///
+/// Keep the reporter alive for as long as the environment and set the new
+/// appearance whenever the OS reports a change.
+///
+/// # Safety
+/// The caller must ensure that `env` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_theme_install_system_color_scheme_reporter(
+    env: *mut WuiEnv,
+    initial: WuiColorScheme,
+) -> *mut WuiSystemColorScheme {
+    SystemColorScheme::install(unsafe { &mut *env }, initial.into()).into_ffi()
+}
+
+/// Sets the operating system appearance.
+///
+/// # Safety
+/// The caller must ensure that `reporter` is a valid pointer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_system_color_scheme_set(
+    reporter: *const WuiSystemColorScheme,
+    scheme: WuiColorScheme,
+) {
+    unsafe { (&*reporter).set(scheme.into()) }
+}
+
 /// Returns the current color scheme signal from the environment.
 ///
+/// The signal always reports `Light` or `Dark`.
+///
 /// # Safety
 /// The returned pointer must be dropped by the caller when no longer needed.
 #[unsafe(no_mangle)]
@@ -180,6 +253,8 @@ pub enum WuiColorSlot {
     Accent = 6,
     /// Foreground color on accent backgrounds.
     AccentForeground = 7,
+    /// Color for errors and destructive actions.
+    Destructive = 8,
 }
 
 /// Installs a color signal for a specific slot.
@@ -215,6 +290,47 @@ pub unsafe extern "C" fn waterui_theme_install_color(
         WuiColorSlot::AccentForeground => {
             install_color_signal::<color::AccentForeground>(env, computed)
         }
+        WuiColorSlot::Destructive => install_destructive_color(env, computed),
+    }
+}
+
+/// Installs separate light and dark color signals for a specific slot.
+///
+/// The slot follows the color scheme of each subtree, including subtrees that
+/// force a scheme. Takes ownership of both signal pointers.
+///
+/// # Safety
+/// The signal pointers must be valid.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_theme_install_color_variants(
+    env: *mut WuiEnv,
+    slot: WuiColorSlot,
+    light: *mut WuiComputed<ResolvedColor>,
+    dark: *mut WuiComputed<ResolvedColor>,
+) {
+    if env.is_null() || light.is_null() || dark.is_null() {
+        return;
+    }
+    let env = unsafe { &mut *env };
+    let light = unsafe { Box::from_raw(light) }.0;
+    let dark = unsafe { Box::from_raw(dark) }.0;
+
+    match slot {
+        WuiColorSlot::Background => install_color_signals::<color::Background>(env, light, dark),
+        WuiColorSlot::Surface => install_color_signals::<color::Surface>(env, light, dark),
+        WuiColorSlot::SurfaceVariant => {
+            install_color_signals::<color::SurfaceVariant>(env, light, dark)
+        }
+        WuiColorSlot::Border => install_color_signals::<color::Border>(env, light, dark),
+        WuiColorSlot::Foreground => install_color_signals::<color::Foreground>(env, light, dark),
+        WuiColorSlot::MutedForeground => {
+            install_color_signals::<color::MutedForeground>(env, light, dark)
+        }
+        WuiColorSlot::Accent => install_color_signals::<color::Accent>(env, light, dark),
+        WuiColorSlot::AccentForeground => {
+            install_color_signals::<color::AccentForeground>(env, light, dark)
+        }
+        WuiColorSlot::Destructive => install_destructive_colors(env, light, dark),
     }
 }
 
@@ -243,6 +359,7 @@ pub unsafe extern "C" fn waterui_theme_color(
         WuiColorSlot::MutedForeground => color::MutedForeground.resolve(env).computed(),
         WuiColorSlot::Accent => color::Accent.resolve(env).computed(),
         WuiColorSlot::AccentForeground => color::AccentForeground.resolve(env).computed(),
+        WuiColorSlot::Destructive => color::Destructive.resolve(env).computed(),
     };
 
     computed.into_ffi()
@@ -574,7 +691,7 @@ mod tests {
 
     #[test]
     fn background_color_computed_is_readable() {
-        let env = WuiEnv(waterui::Environment::new());
+        let env = WuiEnv::from(waterui::Environment::new());
         let ptr = unsafe { waterui_theme_color_background(&env) };
         assert!(!ptr.is_null());
         let value = unsafe { crate::color::waterui_read_computed_resolved_color(ptr) };
@@ -597,7 +714,7 @@ mod tests {
 
     #[test]
     fn slot_based_color_install_and_query() {
-        let mut env = WuiEnv(waterui::Environment::new());
+        let mut env = WuiEnv::from(waterui::Environment::new());
 
         // Create and install a foreground color
         let fg_signal = waterui::Computed::constant(ResolvedColor {
@@ -0,0 +1,121 @@
+//! FFI bindings for undo and redo.
+//!
+//! Native backends call these from the platform's undo gestures, such as the
+//! Edit menu's Cmd+Z and Shift+Cmd+Z on desktop or shaking the device on iOS,
+//! and enable those commands from the returned signals. They act on the
+//! `UndoManager` installed in the environment and do nothing without one.
+//!
+//! The step names are meant for the menu items, as in "Undo Reset Style".
+
+use waterui::{Computed, Str, undo::UndoManager};
+
+use crate::{IntoFFI, WuiEnv, WuiStr, ffi_computed, reactive::WuiComputed};
+
+/// A string that may be absent.
+///
+/// `value` is empty when `present` is `false`.
+#[repr(C)]
+pub struct WuiOptionalStr {
+    pub present: bool,
+    pub value: WuiStr,
+}
+
+impl IntoFFI for Option<Str> {
+    type FFI = WuiOptionalStr;
+    fn into_ffi(self) -> Self::FFI {
+        WuiOptionalStr {
+            present: self.is_some(),
+            value: self.unwrap_or_default().into_ffi(),
+        }
+    }
+}
+
+ffi_computed!(Option<Str>, WuiOptionalStr, optional_str);
+
+/// Undoes the last step of the environment's undo manager.
+///
+/// Returns `false` if there was nothing to undo.
+///
+/// # Safety
+///
+/// `env` must be a valid pointer to a `WuiEnv`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_undo(env: *const WuiEnv) -> bool {
+    let env = unsafe { &*env };
+    UndoManager::from_env(env).is_some_and(|manager| manager.undo())
+}
+
+/// Redoes the last undone step of the environment's undo manager.
+///
+/// Returns `false` if there was nothing to redo.
+///
+/// # Safety
+///
+/// `env` must be a valid pointer to a `WuiEnv`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_redo(env: *const WuiEnv) -> bool {
+    let env = unsafe { &*env };
+    UndoManager::from_env(env).is_some_and(|manager| manager.redo())
+}
+
+/// Returns whether the environment's undo manager has a step to undo.
+///
+/// # Safety
+///
+/// `env` must be a valid pointer to a `WuiEnv`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_can_undo(env: *const WuiEnv) -> *mut WuiComputed<bool> {
+    let env = unsafe { &*env };
+    UndoManager::from_env(env)
+        .map_or_else(|| Computed::constant(false), |manager| manager.can_undo())
+        .into_ffi()
+}
+
+/// Returns whether the environment's undo manager has a step to redo.
+///
+/// # Safety
+///
+/// `env` must be a valid pointer to a `WuiEnv`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_can_redo(env: *const WuiEnv) -> *mut WuiComputed<bool> {
+    let env = unsafe { &*env };
+    UndoManager::from_env(env)
+        .map_or_else(|| Computed::constant(false), |manager| manager.can_redo())
+        .into_ffi()
+}
+
+/// Returns the name of the step the environment's undo manager would undo.
+///
+/// The name is absent when there is nothing to undo or the step was not made
+/// in a named transaction.
+///
+/// # Safety
+///
+/// `env` must be a valid pointer to a `WuiEnv`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_undo_name(
+    env: *const WuiEnv,
+) -> *mut WuiComputed<Option<Str>> {
+    let env = unsafe { &*env };
+    UndoManager::from_env(env)
+        .map_or_else(|| Computed::constant(None), |manager| manager.undo_name())
+        .into_ffi()
+}
+
+/// Returns the name of the step the environment's undo manager would redo.
+///
+/// The name is absent when there is nothing to redo or the step was not made
+/// in a named transaction.
+///
+/// # Safety
+///
+/// `env` must be a valid pointer to a `WuiEnv`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn waterui_env_redo_name(
+    env: *const WuiEnv,
+) -> *mut WuiComputed<Option<Str>> {
+    let env = unsafe { &*env };
+    UndoManager::from_env(env)
+        .map_or_else(|| Computed::constant(None), |manager| manager.redo_name())
+        .into_ffi()
+}
@@ -83,17 +83,98 @@ typedef enum WuiEvent {
   WuiEvent_Disappear,
 } WuiEvent;
 
+typedef enum WuiHapticStyle {
+  WuiHapticStyle_Light,
+  WuiHapticStyle_Medium,
+  WuiHapticStyle_Heavy,
+  WuiHapticStyle_Selection,
+  WuiHapticStyle_Success,
+  WuiHapticStyle_Warning,
+  WuiHapticStyle_Error,
+} WuiHapticStyle;
+
+typedef enum WuiFeedbackSound {
+  WuiFeedbackSound_Tap,
+  WuiFeedbackSound_Toggle,
+  WuiFeedbackSound_Tick,
+} WuiFeedbackSound;
+
 typedef enum WuiAnimation {
   WuiAnimation_Default,
   WuiAnimation_None,
+  /**
+   * A spring; read its parameters with `waterui_get_spring`.
+   */
+  WuiAnimation_Spring,
 } WuiAnimation;
 
+/**
+ * Kind of transition attached to a view update.
+ */
+typedef enum WuiTransitionKind {
+  /**
+   * Swap the content instantly.
+   */
+  WuiTransitionKind_None,
+  /**
+   * Fade the old content out while the new content fades in.
+   */
+  WuiTransitionKind_CrossFade,
+} WuiTransitionKind;
+
 typedef enum WuiAxis {
   WuiAxis_Horizontal,
   WuiAxis_Vertical,
   WuiAxis_All,
 } WuiAxis;
 
+typedef enum WuiColorGamut {
+  WuiColorGamut_Srgb,
+  WuiColorGamut_DisplayP3,
+} WuiColorGamut;
+
+typedef enum WuiPlatform {
+  WuiPlatform_Ios,
+  WuiPlatform_Macos,
+  WuiPlatform_Android,
+  WuiPlatform_Web,
+  WuiPlatform_Windows,
+  WuiPlatform_Linux,
+  WuiPlatform_Other,
+} WuiPlatform;
+
+typedef enum WuiOrnamentPlacement {
+  WuiOrnamentPlacement_Top,
+  WuiOrnamentPlacement_Bottom,
+  WuiOrnamentPlacement_Leading,
+  WuiOrnamentPlacement_Trailing,
+} WuiOrnamentPlacement;
+
+typedef enum WuiReachability {
+  WuiReachability_Unknown,
+  WuiReachability_Offline,
+  WuiReachability_Online,
+} WuiReachability;
+
+typedef enum WuiAlignment {
+  WuiAlignment_Top,
+  WuiAlignment_TopLeading,
+  WuiAlignment_TopTrailing,
+  WuiAlignment_Center,
+  WuiAlignment_Bottom,
+  WuiAlignment_BottomLeading,
+  WuiAlignment_BottomTrailing,
+  WuiAlignment_Leading,
+  WuiAlignment_Trailing,
+} WuiAlignment;
+
+typedef enum WuiScrollDestinationKind {
+  WuiScrollDestinationKind_Offset,
+  WuiScrollDestinationKind_Item,
+  WuiScrollDestinationKind_Start,
+  WuiScrollDestinationKind_End,
+} WuiScrollDestinationKind;
+
 typedef enum WuiButtonStyle {
   WuiButtonStyle_Automatic,
   WuiButtonStyle_Plain,
@@ -115,6 +196,27 @@ typedef enum WuiFontWeight {
   WuiFontWeight_Black,
 } WuiFontWeight;
 
+typedef enum WuiTruncationMode {
+  WuiTruncationMode_Head,
+  WuiTruncationMode_Middle,
+  WuiTruncationMode_Tail,
+} WuiTruncationMode;
+
+typedef enum WuiTextAlignment {
+  WuiTextAlignment_Leading,
+  WuiTextAlignment_Center,
+  WuiTextAlignment_Trailing,
+} WuiTextAlignment;
+
+/**
+ * The kind of a text update.
+ */
+typedef enum WuiTextUpdateKind {
+  WuiTextUpdateKind_Replace = 0,
+  WuiTextUpdateKind_Append = 1,
+  WuiTextUpdateKind_ReplaceRange = 2,
+} WuiTextUpdateKind;
+
 typedef enum WuiKeyboardType {
   WuiKeyboardType_Text,
   WuiKeyboardType_Email,
@@ -123,6 +225,41 @@ typedef enum WuiKeyboardType {
   WuiKeyboardType_PhoneNumber,
 } WuiKeyboardType;
 
+/**
+ * FFI representation of input method composition event types.
+ */
+typedef enum WuiCompositionEventType {
+  WuiCompositionEventType_Began = 0,
+  WuiCompositionEventType_Updated = 1,
+  WuiCompositionEventType_Committed = 2,
+  WuiCompositionEventType_Cancelled = 3,
+} WuiCompositionEventType;
+
+/**
+ * The kind of a dragged item.
+ */
+typedef enum WuiDragItemKind {
+  WuiDragItemKind_Text = 0,
+  WuiDragItemKind_Url = 1,
+  WuiDragItemKind_Image = 2,
+  WuiDragItemKind_Data = 3,
+} WuiDragItemKind;
+
+/**
+ * The kind of a shared item.
+ */
+typedef enum WuiShareItemKind {
+  WuiShareItemKind_Text = 0,
+  WuiShareItemKind_Url = 1,
+  WuiShareItemKind_File = 2,
+} WuiShareItemKind;
+
+typedef enum WuiShareOutcome {
+  WuiShareOutcome_Completed,
+  WuiShareOutcome_Cancelled,
+  WuiShareOutcome_Unavailable,
+} WuiShareOutcome;
+
 /**
  * Position of the tab bar within the tab container.
  */
@@ -151,6 +288,18 @@ typedef enum WuiAspectRatio {
   WuiAspectRatio_Stretch = 2,
 } WuiAspectRatio;
 
+typedef enum WuiContentMode {
+  WuiContentMode_Fit,
+  WuiContentMode_Fill,
+  WuiContentMode_Tile,
+} WuiContentMode;
+
+typedef enum WuiImageCachePolicy {
+  WuiImageCachePolicy_MemoryAndDisk,
+  WuiImageCachePolicy_MemoryOnly,
+  WuiImageCachePolicy_Disabled,
+} WuiImageCachePolicy;
+
 /**
  * FFI representation of video events.
  */
@@ -162,6 +311,26 @@ typedef enum WuiVideoEventType {
   WuiVideoEventType_BufferingEnded = 4,
 } WuiVideoEventType;
 
+/**
+ * FFI representation of animated vector event types.
+ */
+typedef enum WuiAnimatedVectorEventType {
+  WuiAnimatedVectorEventType_Loaded = 0,
+  WuiAnimatedVectorEventType_Completed = 1,
+  WuiAnimatedVectorEventType_Error = 2,
+} WuiAnimatedVectorEventType;
+
+/**
+ * FFI representation of audio event types.
+ */
+typedef enum WuiAudioEventType {
+  WuiAudioEventType_ReadyToPlay = 0,
+  WuiAudioEventType_Buffering = 1,
+  WuiAudioEventType_BufferingEnded = 2,
+  WuiAudioEventType_Ended = 3,
+  WuiAudioEventType_Error = 4,
+} WuiAudioEventType;
+
 /**
  * FFI representation of a simple media filter type.
  * Complex nested filters (All, Not, Any) are not supported via FFI.
@@ -185,6 +354,40 @@ typedef enum WuiMediaFilterType {
   WuiMediaFilterType_All = 3,
 } WuiMediaFilterType;
 
+/**
+ * FFI representation of the photo library authorization state.
+ */
+typedef enum WuiPhotoLibraryPermission {
+  /**
+   * The user has not been asked yet.
+   */
+  WuiPhotoLibraryPermission_NotDetermined = 0,
+  /**
+   * Access is blocked by system policy.
+   */
+  WuiPhotoLibraryPermission_Restricted = 1,
+  /**
+   * The user declined access.
+   */
+  WuiPhotoLibraryPermission_Denied = 2,
+  /**
+   * The user granted access to a subset of the library.
+   */
+  WuiPhotoLibraryPermission_Limited = 3,
+  /**
+   * The user granted access to the whole library.
+   */
+  WuiPhotoLibraryPermission_Authorized = 4,
+} WuiPhotoLibraryPermission;
+
+/**
+ * How many rows of a list can be selected at once.
+ */
+typedef enum WuiSelectionMode {
+  WuiSelectionMode_Single,
+  WuiSelectionMode_Multiple,
+} WuiSelectionMode;
+
 typedef enum WuiProgressStyle {
   WuiProgressStyle_Linear,
   WuiProgressStyle_Circular,
@@ -204,6 +407,10 @@ typedef enum WuiColorScheme {
    * Dark appearance.
    */
   WuiColorScheme_Dark = 1,
+  /**
+   * Follows the operating system appearance.
+   */
+  WuiColorScheme_System = 2,
 } WuiColorScheme;
 
 /**
@@ -244,6 +451,10 @@ typedef enum WuiColorSlot {
    * Foreground color on accent backgrounds.
    */
   WuiColorSlot_AccentForeground = 7,
+  /**
+   * Color for errors and destructive actions.
+   */
+  WuiColorSlot_Destructive = 8,
 } WuiColorSlot;
 
 /**
@@ -462,6 +673,14 @@ typedef struct Computed_ColorScheme Computed_ColorScheme;
  */
 typedef struct Computed_Font Computed_Font;
 
+/**
+ * A wrapper around a boxed implementation of the `ComputedImpl` trait.
+ *
+ * This type represents a computation that can be evaluated to produce a result of type `T`.
+ * The computation is stored as a boxed trait object, allowing for dynamic dispatch.
+ */
+typedef struct Computed_Gradient Computed_Gradient;
+
 /**
  * A wrapper around a boxed implementation of the `ComputedImpl` trait.
  *
@@ -486,6 +705,14 @@ typedef struct Computed_LivePhotoSource Computed_LivePhotoSource;
  */
 typedef struct Computed_ResolvedColor Computed_ResolvedColor;
 
+/**
+ * A wrapper around a boxed implementation of the `ComputedImpl` trait.
+ *
+ * This type represents a computation that can be evaluated to produce a result of type `T`.
+ * The computation is stored as a boxed trait object, allowing for dynamic dispatch.
+ */
+typedef struct Computed_Option_Str Computed_Option_Str;
+
 /**
  * A wrapper around a boxed implementation of the `ComputedImpl` trait.
  *
@@ -494,6 +721,14 @@ typedef struct Computed_ResolvedColor Computed_ResolvedColor;
  */
 typedef struct Computed_ResolvedFont Computed_ResolvedFont;
 
+/**
+ * A wrapper around a boxed implementation of the `ComputedImpl` trait.
+ *
+ * This type represents a computation that can be evaluated to produce a result of type `T`.
+ * The computation is stored as a boxed trait object, allowing for dynamic dispatch.
+ */
+typedef struct Computed_ResolvedGradient Computed_ResolvedGradient;
+
 /**
  * A wrapper around a boxed implementation of the `ComputedImpl` trait.
  *
@@ -595,6 +830,14 @@ typedef struct WuiAnyViews WuiAnyViews;
 
 typedef struct WuiColor WuiColor;
 
+typedef struct WuiDigitalCrown WuiDigitalCrown;
+
+typedef struct WuiDisplayReporter WuiDisplayReporter;
+
+typedef struct WuiDragSource WuiDragSource;
+
+typedef struct WuiDropTarget WuiDropTarget;
+
 typedef struct WuiDynamic WuiDynamic;
 
 typedef struct WuiEnv WuiEnv;
@@ -610,13 +853,41 @@ typedef struct WuiFont WuiFont;
  */
 typedef struct WuiGpuSurfaceState WuiGpuSurfaceState;
 
+typedef struct WuiGradient WuiGradient;
+
 typedef struct WuiLayout WuiLayout;
 
+typedef struct WuiLazyWindow WuiLazyWindow;
+
+typedef struct WuiListSelection WuiListSelection;
+
+typedef struct WuiNavigationGuards WuiNavigationGuards;
+
+typedef struct WuiNetworkMonitor WuiNetworkMonitor;
+
 /**
  * Wrapper for OnEvent to avoid orphan rule issues.
  */
 typedef struct WuiOnEventHandler WuiOnEventHandler;
 
+typedef struct WuiPrefetch WuiPrefetch;
+
+typedef struct WuiReachEnd WuiReachEnd;
+
+typedef struct WuiRefresh WuiRefresh;
+
+typedef struct WuiReorderable WuiReorderable;
+
+typedef struct WuiRowDelete WuiRowDelete;
+
+typedef struct WuiRowRecycler WuiRowRecycler;
+
+typedef struct WuiRowSlot WuiRowSlot;
+
+typedef struct WuiScrollPosition WuiScrollPosition;
+
+typedef struct WuiSystemColorScheme WuiSystemColorScheme;
+
 typedef struct WuiTabContent WuiTabContent;
 
 typedef struct WuiWatcherGuard WuiWatcherGuard;
@@ -633,20 +904,28 @@ typedef struct WuiWatcher_ColorScheme WuiWatcher_ColorScheme;
 
 typedef struct WuiWatcher_Font WuiWatcher_Font;
 
+typedef struct WuiWatcher_Gradient WuiWatcher_Gradient;
+
 typedef struct WuiWatcher_Id WuiWatcher_Id;
 
 typedef struct WuiWatcher_LivePhotoSource WuiWatcher_LivePhotoSource;
 
+typedef struct WuiWatcher_Option_Str WuiWatcher_Option_Str;
+
 typedef struct WuiWatcher_ResolvedColor WuiWatcher_ResolvedColor;
 
 typedef struct WuiWatcher_ResolvedFont WuiWatcher_ResolvedFont;
 
+typedef struct WuiWatcher_ResolvedGradient WuiWatcher_ResolvedGradient;
+
 typedef struct WuiWatcher_Secure WuiWatcher_Secure;
 
 typedef struct WuiWatcher_Str WuiWatcher_Str;
 
 typedef struct WuiWatcher_StyledStr WuiWatcher_StyledStr;
 
+typedef struct WuiWatcher_TextUpdate WuiWatcher_TextUpdate;
+
 typedef struct WuiWatcher_Vec_PickerItem_Id WuiWatcher_Vec_PickerItem_Id;
 
 typedef struct WuiWatcher_Vec_TableColumn WuiWatcher_Vec_TableColumn;
@@ -661,6 +940,18 @@ typedef struct WuiWatcher_f64 WuiWatcher_f64;
 
 typedef struct WuiWatcher_i32 WuiWatcher_i32;
 
+typedef struct Computed_f32 WuiComputed_f32;
+
+/**
+ * Totals of live objects, see `waterui_core::stats::Stats`.
+ */
+typedef struct WuiStats {
+  uintptr_t views;
+  uintptr_t bindings;
+  uintptr_t watchers;
+  uintptr_t ffi_handles;
+} WuiStats;
+
 /**
  * Type ID as a 128-bit value for O(1) comparison.
  *
@@ -715,7 +1006,7 @@ typedef enum WuiGesture_Tag {
    */
   WuiGesture_Tap,
   /**
-   * A long-press gesture requiring a minimum duration.
+   * A long-press gesture requiring a minimum duration, in milliseconds.
    */
   WuiGesture_LongPress,
   /**
@@ -734,6 +1025,22 @@ typedef enum WuiGesture_Tag {
    * A sequential composition of two gestures.
    */
   WuiGesture_Then,
+  /**
+   * A pointer hovering over the view.
+   */
+  WuiGesture_Hover,
+  /**
+   * A pointer held down on the view.
+   */
+  WuiGesture_Press,
+  /**
+   * A primary-button click requiring a specific number of clicks.
+   */
+  WuiGesture_Click,
+  /**
+   * A secondary-button click.
+   */
+  WuiGesture_SecondaryClick,
 } WuiGesture_Tag;
 
 typedef struct WuiGesture_Tap_Body {
@@ -767,6 +1074,10 @@ typedef struct WuiGesture_Then_Body {
   struct WuiGesture *then;
 } WuiGesture_Then_Body;
 
+typedef struct WuiGesture_Click_Body {
+  uint32_t count;
+} WuiGesture_Click_Body;
+
 typedef struct WuiGesture {
   WuiGesture_Tag tag;
   union {
@@ -776,6 +1087,7 @@ typedef struct WuiGesture {
     WuiGesture_Magnification_Body magnification;
     WuiGesture_Rotation_Body rotation;
     WuiGesture_Then_Body then;
+    WuiGesture_Click_Body click;
   };
 } WuiGesture;
 
@@ -803,6 +1115,160 @@ typedef struct WuiMetadata_WuiGestureObserver {
  */
 typedef struct WuiMetadata_WuiGestureObserver WuiMetadataGesture;
 
+/**
+ * Phase of a gesture interaction.
+ */
+typedef enum WuiGesturePhase {
+  WuiGesturePhase_Started,
+  WuiGesturePhase_Updated,
+  WuiGesturePhase_Ended,
+  WuiGesturePhase_Cancelled,
+} WuiGesturePhase;
+
+/**
+ * How the pointer moved relative to a hovered view.
+ */
+typedef enum WuiHoverPhase {
+  WuiHoverPhase_Entered,
+  WuiHoverPhase_Moved,
+  WuiHoverPhase_Exited,
+} WuiHoverPhase;
+
+/**
+ * FFI-safe point in a gesture's coordinate space.
+ */
+typedef struct WuiGesturePoint {
+  float x;
+  float y;
+} WuiGesturePoint;
+
+/**
+ * FFI-safe keyboard modifiers held during a pointer interaction.
+ */
+typedef struct WuiModifierKeys {
+  bool shift;
+  bool control;
+  /**
+   * Option on Apple platforms, Alt elsewhere.
+   */
+  bool alt;
+  /**
+   * Command on Apple platforms, the Windows or Super key elsewhere.
+   */
+  bool command;
+} WuiModifierKeys;
+
+/**
+ * FFI-safe payload of a recognised gesture, reported by the backend.
+ */
+typedef enum WuiGestureEvent_Tag {
+  /**
+   * A tap at `location`, the `count`th in succession.
+   */
+  WuiGestureEvent_Tap,
+  /**
+   * A long press held for `duration` seconds, `progress` of the way to firing.
+   */
+  WuiGestureEvent_LongPress,
+  /**
+   * A drag, with translation since it started and velocity in points per second.
+   */
+  WuiGestureEvent_Drag,
+  /**
+   * A pinch around `center`, with scale relative to its start.
+   */
+  WuiGestureEvent_Magnification,
+  /**
+   * A rotation around `center`, with angle in radians relative to its start.
+   */
+  WuiGestureEvent_Rotation,
+  /**
+   * A pointer entered, moved within or left the view.
+   */
+  WuiGestureEvent_Hover,
+  /**
+   * A pointer went down on the view (`Started`) or was released (`Ended`, `Cancelled`).
+   */
+  WuiGestureEvent_Press,
+  /**
+   * The `count`th primary-button click in succession at `location`.
+   */
+  WuiGestureEvent_Click,
+  /**
+   * A secondary-button click at `location`.
+   */
+  WuiGestureEvent_SecondaryClick,
+} WuiGestureEvent_Tag;
+
+typedef struct WuiGestureEvent_Tap_Body {
+  struct WuiGesturePoint location;
+  uint32_t count;
+} WuiGestureEvent_Tap_Body;
+
+typedef struct WuiGestureEvent_LongPress_Body {
+  enum WuiGesturePhase phase;
+  struct WuiGesturePoint location;
+  float duration;
+  float progress;
+} WuiGestureEvent_LongPress_Body;
+
+typedef struct WuiGestureEvent_Drag_Body {
+  enum WuiGesturePhase phase;
+  struct WuiGesturePoint location;
+  struct WuiGesturePoint translation;
+  struct WuiGesturePoint velocity;
+} WuiGestureEvent_Drag_Body;
+
+typedef struct WuiGestureEvent_Magnification_Body {
+  enum WuiGesturePhase phase;
+  struct WuiGesturePoint center;
+  float scale;
+  float velocity;
+} WuiGestureEvent_Magnification_Body;
+
+typedef struct WuiGestureEvent_Rotation_Body {
+  enum WuiGesturePhase phase;
+  struct WuiGesturePoint center;
+  float angle;
+  float velocity;
+} WuiGestureEvent_Rotation_Body;
+
+typedef struct WuiGestureEvent_Hover_Body {
+  enum WuiHoverPhase phase;
+  struct WuiGesturePoint location;
+} WuiGestureEvent_Hover_Body;
+
+typedef struct WuiGestureEvent_Press_Body {
+  enum WuiGesturePhase phase;
+  struct WuiGesturePoint location;
+} WuiGestureEvent_Press_Body;
+
+typedef struct WuiGestureEvent_Click_Body {
+  struct WuiGesturePoint location;
+  uint32_t count;
+  struct WuiModifierKeys modifiers;
+} WuiGestureEvent_Click_Body;
+
+typedef struct WuiGestureEvent_SecondaryClick_Body {
+  struct WuiGesturePoint location;
+  struct WuiModifierKeys modifiers;
+} WuiGestureEvent_SecondaryClick_Body;
+
+typedef struct WuiGestureEvent {
+  WuiGestureEvent_Tag tag;
+  union {
+    WuiGestureEvent_Tap_Body tap;
+    WuiGestureEvent_LongPress_Body long_press;
+    WuiGestureEvent_Drag_Body drag;
+    WuiGestureEvent_Magnification_Body magnification;
+    WuiGestureEvent_Rotation_Body rotation;
+    WuiGestureEvent_Hover_Body hover;
+    WuiGestureEvent_Press_Body press;
+    WuiGestureEvent_Click_Body click;
+    WuiGestureEvent_SecondaryClick_Body secondary_click;
+  };
+} WuiGestureEvent;
+
 /**
  * FFI-safe representation of an event handler.
  */
@@ -831,6 +1297,8 @@ typedef struct Computed_Color WuiComputed_Color;
 
 typedef struct Computed_Str WuiComputed_Str;
 
+typedef struct Computed_Gradient WuiComputed_Gradient;
+
 /**
  * FFI-safe representation of a background.
  */
@@ -839,6 +1307,10 @@ typedef enum WuiBackground_Tag {
    * A solid color background.
    */
   WuiBackground_Color,
+  /**
+   * A gradient background, resolved with `waterui_resolve_gradient`.
+   */
+  WuiBackground_Gradient,
   /**
    * An image background.
    */
@@ -849,6 +1321,10 @@ typedef struct WuiBackground_Color_Body {
   WuiComputed_Color *color;
 } WuiBackground_Color_Body;
 
+typedef struct WuiBackground_Gradient_Body {
+  WuiComputed_Gradient *gradient;
+} WuiBackground_Gradient_Body;
+
 typedef struct WuiBackground_Image_Body {
   WuiComputed_Str *image;
 } WuiBackground_Image_Body;
@@ -857,6 +1333,7 @@ typedef struct WuiBackground {
   WuiBackground_Tag tag;
   union {
     WuiBackground_Color_Body color;
+    WuiBackground_Gradient_Body gradient;
     WuiBackground_Image_Body image;
   };
 } WuiBackground;
@@ -923,6 +1400,29 @@ typedef struct WuiMetadata_WuiShadow {
  */
 typedef struct WuiMetadata_WuiShadow WuiMetadataShadow;
 
+/**
+ * FFI-safe representation of a color filter.
+ *
+ * Every filter is lowered to a row-major 4x5 RGBA color matrix, so renderers
+ * only need to implement a single color-matrix effect.
+ */
+typedef struct WuiColorFilter {
+  /**
+   * Row-major 4x5 color matrix (the fifth column holds offsets).
+   */
+  float matrix[20];
+} WuiColorFilter;
+
+typedef struct WuiMetadata_WuiColorFilter {
+  struct WuiAnyView *content;
+  struct WuiColorFilter value;
+} WuiMetadata_WuiColorFilter;
+
+/**
+ * Type alias for Metadata<ColorFilter> FFI struct
+ */
+typedef struct WuiMetadata_WuiColorFilter WuiMetadataColorFilter;
+
 typedef struct Binding_bool WuiBinding_bool;
 
 /**
@@ -987,6 +1487,109 @@ typedef struct WuiMetadata_WuiIgnoreSafeArea {
  */
 typedef struct WuiMetadata_WuiIgnoreSafeArea WuiMetadataIgnoreSafeArea;
 
+typedef struct Computed_i32 WuiComputed_i32;
+
+/**
+ * FFI-safe representation of a z-index.
+ */
+typedef struct WuiZIndex {
+  /**
+   * Pointer to the computed index; higher values are drawn above.
+   */
+  WuiComputed_i32 *index;
+} WuiZIndex;
+
+typedef struct WuiMetadata_WuiZIndex {
+  struct WuiAnyView *content;
+  struct WuiZIndex value;
+} WuiMetadata_WuiZIndex;
+
+/**
+ * Type alias for Metadata<ZIndex> FFI struct
+ */
+typedef struct WuiMetadata_WuiZIndex WuiMetadataZIndex;
+
+typedef struct Computed_ColorScheme WuiComputed_ColorScheme;
+
+/**
+ * FFI-safe representation of a forced color scheme.
+ */
+typedef struct WuiPreferredColorScheme {
+  /**
+   * Pointer to the computed scheme, always `Light` or `Dark`.
+   */
+  WuiComputed_ColorScheme *scheme;
+} WuiPreferredColorScheme;
+
+typedef struct WuiMetadata_WuiPreferredColorScheme {
+  struct WuiAnyView *content;
+  struct WuiPreferredColorScheme value;
+} WuiMetadata_WuiPreferredColorScheme;
+
+/**
+ * Type alias for Metadata<PreferredColorScheme> FFI struct
+ */
+typedef struct WuiMetadata_WuiPreferredColorScheme WuiMetadataPreferredColorScheme;
+
+/**
+ * FFI-safe representation of a view identity.
+ */
+typedef struct WuiViewId {
+  /**
+   * Keep the native view if this matches the previous content's identity,
+   * rebuild it otherwise.
+   */
+  uint64_t id;
+} WuiViewId;
+
+typedef struct WuiMetadata_WuiViewId {
+  struct WuiAnyView *content;
+  struct WuiViewId value;
+} WuiMetadata_WuiViewId;
+
+/**
+ * Type alias for Metadata<ViewId> FFI struct
+ */
+typedef struct WuiMetadata_WuiViewId WuiMetadataViewId;
+
+/**
+ * FFI-safe representation of an operating system version.
+ *
+ * On Android the major version is the API level.
+ */
+typedef struct WuiOsVersion {
+  /**
+   * The major version.
+   */
+  uint32_t major;
+  /**
+   * The minor version.
+   */
+  uint32_t minor;
+  /**
+   * The patch version.
+   */
+  uint32_t patch;
+} WuiOsVersion;
+
+/**
+ * FFI-safe representation of an ornament.
+ */
+typedef struct WuiOrnament {
+  enum WuiOrnamentPlacement placement;
+  struct WuiAnyView *content;
+} WuiOrnament;
+
+typedef struct WuiMetadata_WuiOrnament {
+  struct WuiAnyView *content;
+  struct WuiOrnament value;
+} WuiMetadata_WuiOrnament;
+
+/**
+ * Type alias for Metadata<Ornament> FFI struct
+ */
+typedef struct WuiMetadata_WuiOrnament WuiMetadataOrnament;
+
 /**
  * FFI-safe representation of Retain metadata.
  * The actual retained value is opaque - renderers just need to keep it alive.
@@ -1021,15 +1624,71 @@ typedef struct Computed_ResolvedColor WuiComputed_ResolvedColor;
 
 typedef struct Binding_Color WuiBinding_Color;
 
-typedef struct WuiArraySlice_u8 {
-  uint8_t *head;
+typedef struct WuiUnitPoint {
+  float x;
+  float y;
+} WuiUnitPoint;
+
+/**
+ * FFI-safe representation of the shape of a gradient.
+ *
+ * Points are relative to the bounds of the view the gradient fills.
+ */
+typedef enum WuiGradientGeometry_Tag {
+  /**
+   * Colors change along the line from `start` to `end`.
+   */
+  WuiGradientGeometry_Linear,
+  /**
+   * Colors change along circles around `center`; radii are in points.
+   */
+  WuiGradientGeometry_Radial,
+  /**
+   * Colors change with the angle around `center`; angles are in radians.
+   */
+  WuiGradientGeometry_Angular,
+} WuiGradientGeometry_Tag;
+
+typedef struct WuiGradientGeometry_Linear_Body {
+  struct WuiUnitPoint start;
+  struct WuiUnitPoint end;
+} WuiGradientGeometry_Linear_Body;
+
+typedef struct WuiGradientGeometry_Radial_Body {
+  struct WuiUnitPoint center;
+  float start_radius;
+  float end_radius;
+} WuiGradientGeometry_Radial_Body;
+
+typedef struct WuiGradientGeometry_Angular_Body {
+  struct WuiUnitPoint center;
+  float start_angle;
+  float end_angle;
+} WuiGradientGeometry_Angular_Body;
+
+typedef struct WuiGradientGeometry {
+  WuiGradientGeometry_Tag tag;
+  union {
+    WuiGradientGeometry_Linear_Body linear;
+    WuiGradientGeometry_Radial_Body radial;
+    WuiGradientGeometry_Angular_Body angular;
+  };
+} WuiGradientGeometry;
+
+typedef struct WuiGradientStop {
+  float offset;
+  struct WuiResolvedColor color;
+} WuiGradientStop;
+
+typedef struct WuiArraySlice_WuiGradientStop {
+  struct WuiGradientStop *head;
   uintptr_t len;
-} WuiArraySlice_u8;
+} WuiArraySlice_WuiGradientStop;
 
-typedef struct WuiArrayVTable_u8 {
+typedef struct WuiArrayVTable_WuiGradientStop {
   void (*drop)(void*);
-  struct WuiArraySlice_u8 (*slice)(const void*);
-} WuiArrayVTable_u8;
+  struct WuiArraySlice_WuiGradientStop (*slice)(const void*);
+} WuiArrayVTable_WuiGradientStop;
 
 /**
  * A generic array structure for FFI, representing a contiguous sequence of elements.
@@ -1038,16 +1697,150 @@ typedef struct WuiArrayVTable_u8 {
  * For a value type, `WuiArray` contains a destructor function pointer to free the array buffer, whatever it is allocated by Rust side or foreign side.
  * We assume `T` does not contain any non-trivial drop logic, and `WuiArray` will not call `drop` on each element when it is dropped.
  */
-typedef struct WuiArray_u8 {
+typedef struct WuiArray_WuiGradientStop {
   NonNull data;
-  struct WuiArrayVTable_u8 vtable;
-} WuiArray_u8;
+  struct WuiArrayVTable_WuiGradientStop vtable;
+} WuiArray_WuiGradientStop;
 
-typedef struct WuiStr {
-  struct WuiArray_u8 _0;
-} WuiStr;
+typedef struct WuiResolvedGradient {
+  struct WuiGradientGeometry geometry;
+  struct WuiArray_WuiGradientStop stops;
+} WuiResolvedGradient;
 
-typedef struct WuiArraySlice_____WuiAnyView {
+typedef struct Computed_ResolvedGradient WuiComputed_ResolvedGradient;
+
+typedef struct WuiArraySlice_u8 {
+  uint8_t *head;
+  uintptr_t len;
+} WuiArraySlice_u8;
+
+typedef struct WuiArrayVTable_u8 {
+  void (*drop)(void*);
+  struct WuiArraySlice_u8 (*slice)(const void*);
+} WuiArrayVTable_u8;
+
+/**
+ * A generic array structure for FFI, representing a contiguous sequence of elements.
+ * `WuiArray` can represent multiple types of arrays, for instance, a `&[T]` (in this case, the lifetime of WuiArray is bound to the caller's scope),
+ * or a value type having a static lifetime like `Vec<T>`, `Box<[T]>`, `Bytes`, or even a foreign allocated array.
+ * For a value type, `WuiArray` contains a destructor function pointer to free the array buffer, whatever it is allocated by Rust side or foreign side.
+ * We assume `T` does not contain any non-trivial drop logic, and `WuiArray` will not call `drop` on each element when it is dropped.
+ */
+typedef struct WuiArray_u8 {
+  NonNull data;
+  struct WuiArrayVTable_u8 vtable;
+} WuiArray_u8;
+
+typedef struct WuiStr {
+  struct WuiArray_u8 _0;
+} WuiStr;
+
+typedef struct WuiMetadata_____WuiDragSource {
+  struct WuiAnyView *content;
+  struct WuiDragSource *value;
+} WuiMetadata_____WuiDragSource;
+
+/**
+ * Type alias for Metadata<DragSource> FFI struct
+ */
+typedef struct WuiMetadata_____WuiDragSource WuiMetadataDragSource;
+
+/**
+ * FFI-safe representation of a dragged item.
+ */
+typedef struct WuiDragItem {
+  enum WuiDragItemKind kind;
+  /**
+   * The MIME type of `Image` items, or the uniform type identifier or MIME
+   * type of `Data` items. Empty otherwise.
+   */
+  struct WuiStr type_id;
+  /**
+   * The text of `Text` items, or the URL of `Url` items. Empty otherwise.
+   */
+  struct WuiStr text;
+  /**
+   * The bytes of `Image` and `Data` items. Empty otherwise.
+   */
+  struct WuiArray_u8 data;
+} WuiDragItem;
+
+typedef struct WuiArraySlice_WuiDragItem {
+  struct WuiDragItem *head;
+  uintptr_t len;
+} WuiArraySlice_WuiDragItem;
+
+typedef struct WuiArrayVTable_WuiDragItem {
+  void (*drop)(void*);
+  struct WuiArraySlice_WuiDragItem (*slice)(const void*);
+} WuiArrayVTable_WuiDragItem;
+
+/**
+ * A generic array structure for FFI, representing a contiguous sequence of elements.
+ * `WuiArray` can represent multiple types of arrays, for instance, a `&[T]` (in this case, the lifetime of WuiArray is bound to the caller's scope),
+ * or a value type having a static lifetime like `Vec<T>`, `Box<[T]>`, `Bytes`, or even a foreign allocated array.
+ * For a value type, `WuiArray` contains a destructor function pointer to free the array buffer, whatever it is allocated by Rust side or foreign side.
+ * We assume `T` does not contain any non-trivial drop logic, and `WuiArray` will not call `drop` on each element when it is dropped.
+ */
+typedef struct WuiArray_WuiDragItem {
+  NonNull data;
+  struct WuiArrayVTable_WuiDragItem vtable;
+} WuiArray_WuiDragItem;
+
+typedef struct WuiMetadata_____WuiDropTarget {
+  struct WuiAnyView *content;
+  struct WuiDropTarget *value;
+} WuiMetadata_____WuiDropTarget;
+
+/**
+ * Type alias for Metadata<DropTarget> FFI struct
+ */
+typedef struct WuiMetadata_____WuiDropTarget WuiMetadataDropTarget;
+
+typedef struct WuiMetadata_____WuiReorderable {
+  struct WuiAnyView *content;
+  struct WuiReorderable *value;
+} WuiMetadata_____WuiReorderable;
+
+/**
+ * Type alias for Metadata<Reorderable> FFI struct
+ */
+typedef struct WuiMetadata_____WuiReorderable WuiMetadataReorderable;
+
+/**
+ * FFI-safe representation of a type a drop target accepts.
+ */
+typedef struct WuiDragType {
+  enum WuiDragItemKind kind;
+  /**
+   * The uniform type identifier or MIME type for `Data`. Empty otherwise.
+   */
+  struct WuiStr type_id;
+} WuiDragType;
+
+typedef struct WuiArraySlice_WuiDragType {
+  struct WuiDragType *head;
+  uintptr_t len;
+} WuiArraySlice_WuiDragType;
+
+typedef struct WuiArrayVTable_WuiDragType {
+  void (*drop)(void*);
+  struct WuiArraySlice_WuiDragType (*slice)(const void*);
+} WuiArrayVTable_WuiDragType;
+
+/**
+ * A generic array structure for FFI, representing a contiguous sequence of elements.
+ * `WuiArray` can represent multiple types of arrays, for instance, a `&[T]` (in this case, the lifetime of WuiArray is bound to the caller's scope),
+ * or a value type having a static lifetime like `Vec<T>`, `Box<[T]>`, `Bytes`, or even a foreign allocated array.
+ * For a value type, `WuiArray` contains a destructor function pointer to free the array buffer, whatever it is allocated by Rust side or foreign side.
+ * We assume `T` does not contain any non-trivial drop logic, and `WuiArray` will not call `drop` on each element when it is dropped.
+ */
+typedef struct WuiArray_WuiDragType {
+  NonNull data;
+  struct WuiArrayVTable_WuiDragType vtable;
+} WuiArray_WuiDragType;
+
+typedef struct WuiArraySlice_____WuiAnyView {
   struct WuiAnyView **head;
   uintptr_t len;
 } WuiArraySlice_____WuiAnyView;
@@ -1069,6 +1862,20 @@ typedef struct WuiArray_____WuiAnyView {
   struct WuiArrayVTable_____WuiAnyView vtable;
 } WuiArray_____WuiAnyView;
 
+/**
+ * A spacer stretching along its stack's main axis between two lengths.
+ */
+typedef struct WuiSpacer {
+  /**
+   * Length the spacer never shrinks below.
+   */
+  float min_length;
+  /**
+   * Length the spacer never grows beyond; infinite if unbounded.
+   */
+  float max_length;
+} WuiSpacer;
+
 typedef struct WuiFixedContainer {
   struct WuiLayout *layout;
   struct WuiArray_____WuiAnyView contents;
@@ -1079,6 +1886,36 @@ typedef struct WuiContainer {
   struct WuiAnyViews *contents;
 } WuiContainer;
 
+typedef struct WuiLazyList {
+  /**
+   * Spacing between rows, in points.
+   */
+  float spacing;
+  struct WuiLazyWindow *window;
+} WuiLazyList;
+
+/**
+ * Callback told which rows of a lazy list to request again.
+ *
+ * `call` receives the new number of rows and the start and end of the range
+ * to request again. `drop` is called once the callback is replaced or the
+ * window is dropped.
+ */
+typedef struct WuiLazyInvalidate {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call when rows change.
+   */
+  void (*call)(const void*, uintptr_t, uintptr_t, uintptr_t);
+  /**
+   * Function to release the callback data.
+   */
+  void (*drop)(void*);
+} WuiLazyInvalidate;
+
 typedef struct WuiSize {
   float width;
   float height;
@@ -1193,15 +2030,64 @@ typedef struct WuiArray_WuiRect {
   struct WuiArrayVTable_WuiRect vtable;
 } WuiArray_WuiRect;
 
+typedef struct WuiScrollBehavior {
+  bool indicators;
+  bool paging;
+  bool bounces;
+} WuiScrollBehavior;
+
 typedef struct WuiScrollView {
   enum WuiAxis axis;
   struct WuiAnyView *content;
+  struct WuiPrefetch *prefetch;
+  struct WuiScrollBehavior behavior;
+  struct WuiRefresh *refresh;
+  struct WuiReachEnd *reach_end;
+  struct WuiScrollPosition *position;
 } WuiScrollView;
 
+/**
+ * A programmatic scroll request.
+ *
+ * `offset` is only meaningful for `Offset` destinations, and `index` and
+ * `anchor` only for `Item` destinations.
+ */
+typedef struct WuiScrollRequest {
+  enum WuiScrollDestinationKind kind;
+  struct WuiPoint offset;
+  uintptr_t index;
+  enum WuiAlignment anchor;
+  bool animated;
+} WuiScrollRequest;
+
+/**
+ * Callback that performs programmatic scroll requests.
+ *
+ * `drop` is called once the callback is replaced or the scroll position is
+ * dropped.
+ */
+typedef struct WuiScrollRequestHandler {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call with each request.
+   */
+  void (*call)(const void*, struct WuiScrollRequest);
+  /**
+   * Function to release the callback data.
+   */
+  void (*drop)(void*);
+} WuiScrollRequestHandler;
+
+typedef struct Computed_bool WuiComputed_bool;
+
 typedef struct WuiButton {
   struct WuiAnyView *label;
   struct WuiAction *action;
   enum WuiButtonStyle style;
+  WuiComputed_bool *disabled;
 } WuiButton;
 
 typedef struct WuiTextStyle {
@@ -1211,6 +2097,9 @@ typedef struct WuiTextStyle {
   bool strikethrough;
   struct WuiColor *foreground;
   struct WuiColor *background;
+  struct WuiColor *underline_color;
+  bool pill;
+  struct WuiAction *on_tap;
 } WuiTextStyle;
 
 typedef struct WuiStyledChunk {
@@ -1252,8 +2141,46 @@ typedef struct Computed_Font WuiComputed_Font;
 
 typedef struct WuiText {
   WuiComputed_StyledStr *content;
+  /**
+   * Maximum number of lines, or 0 for no limit.
+   */
+  uintptr_t line_limit;
+  enum WuiTruncationMode truncation;
+  /**
+   * Write whether the content is truncated here; null when nobody observes it.
+   */
+  WuiBinding_bool *truncated;
+  float line_spacing;
+  float paragraph_spacing;
+  float tracking;
+  /**
+   * Smallest fraction of the font size to shrink to; 1.0 disables shrinking.
+   */
+  float minimum_scale_factor;
+  enum WuiTextAlignment alignment;
+  /**
+   * Watch the content with `waterui_watch_computed_styled_str_updates`
+   * instead of re-reading it on every change.
+   */
+  bool incremental;
 } WuiText;
 
+/**
+ * FFI-safe representation of a change to the plain text of a text view.
+ */
+typedef struct WuiTextUpdate {
+  enum WuiTextUpdateKind kind;
+  /**
+   * The replaced UTF-16 code units of `ReplaceRange` updates. Zero otherwise.
+   */
+  uintptr_t start;
+  uintptr_t end;
+  /**
+   * The new text, appended text or replacement.
+   */
+  struct WuiStr text;
+} WuiTextUpdate;
+
 typedef struct WuiResolvedFont {
   float size;
   enum WuiFontWeight weight;
@@ -1261,13 +2188,58 @@ typedef struct WuiResolvedFont {
 
 typedef struct Computed_ResolvedFont WuiComputed_ResolvedFont;
 
+/**
+ * A string that may be absent.
+ *
+ * `value` is empty when `present` is `false`.
+ */
+typedef struct WuiOptionalStr {
+  bool present;
+  struct WuiStr value;
+} WuiOptionalStr;
+
+typedef struct Computed_Option_Str WuiComputed_Option_Str;
+
 typedef struct Binding_Str WuiBinding_Str;
 
+/**
+ * FFI representation of an input method composition event.
+ */
+typedef struct WuiCompositionEvent {
+  enum WuiCompositionEventType event_type;
+  /**
+   * The marked text for `Updated` events, or the committed text for
+   * `Committed` events. Ignored, and never read, for other events.
+   */
+  struct WuiStr text;
+} WuiCompositionEvent;
+
+/**
+ * A C-compatible function wrapper that can be called multiple times.
+ *
+ * This structure wraps a Rust `Fn` closure to allow it to be passed across
+ * the FFI boundary while maintaining proper memory management.
+ */
+typedef struct WuiFn_WuiCompositionEvent {
+  void *data;
+  void (*call)(const void*, struct WuiCompositionEvent);
+  void (*drop)(void*);
+} WuiFn_WuiCompositionEvent;
+
+/**
+ * FFI representation of the `TextField` component.
+ *
+ * While an input method composes, native code keeps the marked text out of
+ * `value` and reports it through `on_composition`. Changes to `value` made
+ * during a composition are applied once it is cancelled; after a commit,
+ * native code writes its text to `value` instead.
+ */
 typedef struct WuiTextField {
   struct WuiAnyView *label;
   WuiBinding_Str *value;
   struct WuiText prompt;
   enum WuiKeyboardType keyboard;
+  struct WuiFn_WuiCompositionEvent on_composition;
 } WuiTextField;
 
 typedef struct WuiToggle {
@@ -1301,8 +2273,6 @@ typedef struct WuiSlider {
 
 typedef struct Binding_i32 WuiBinding_i32;
 
-typedef struct Computed_i32 WuiComputed_i32;
-
 /**
  * C representation of a range
  */
@@ -1329,6 +2299,11 @@ typedef struct WuiColorPicker {
   WuiBinding_Color *value;
 } WuiColorPicker;
 
+typedef struct WuiEmojiPicker {
+  struct WuiAnyView *label;
+  WuiBinding_Str *value;
+} WuiEmojiPicker;
+
 typedef struct Computed_Vec_PickerItem_Id WuiComputed_Vec_PickerItem_Id;
 
 typedef struct Binding_Id WuiBinding_Id;
@@ -1345,8 +2320,6 @@ typedef struct WuiSecureField {
   WuiBinding_Secure *value;
 } WuiSecureField;
 
-typedef struct Computed_bool WuiComputed_bool;
-
 typedef struct WuiBar {
   struct WuiText title;
   WuiComputed_Color *color;
@@ -1366,8 +2339,67 @@ typedef struct WuiNavigationStack {
    * The root view of the navigation stack.
    */
   struct WuiAnyView *root;
+  /**
+   * Whether an activity is running; show a progress indicator in the
+   * navigation bar while it is true.
+   */
+  WuiComputed_bool *loading;
+  /**
+   * Label of the most recent running activity, for accessibility.
+   */
+  WuiComputed_Str *activity;
+  /**
+   * Guards consulted before navigating.
+   */
+  struct WuiNavigationGuards *guards;
+  /**
+   * Whether a pop guard is registered; while it is true, check back
+   * navigation started by the user with
+   * `waterui_navigation_guards_check_pop` before popping.
+   */
+  WuiComputed_bool *pop_guarded;
 } WuiNavigationStack;
 
+/**
+ * FFI-safe answer of the navigation guards.
+ */
+typedef enum WuiGuardDecision_Tag {
+  /**
+   * Perform the navigation.
+   */
+  WuiGuardDecision_Allow,
+  /**
+   * Cancel the navigation.
+   */
+  WuiGuardDecision_Deny,
+  /**
+   * Cancel the navigation and push `view` instead.
+   */
+  WuiGuardDecision_Redirect,
+} WuiGuardDecision_Tag;
+
+typedef struct WuiGuardDecision_Redirect_Body {
+  struct WuiNavigationView view;
+} WuiGuardDecision_Redirect_Body;
+
+typedef struct WuiGuardDecision {
+  WuiGuardDecision_Tag tag;
+  union {
+    WuiGuardDecision_Redirect_Body redirect;
+  };
+} WuiGuardDecision;
+
+/**
+ * A C-compatible function wrapper that can be called only once.
+ *
+ * This structure wraps a Rust `FnOnce` closure to allow it to be passed across
+ * the FFI boundary while maintaining proper memory management.
+ */
+typedef struct WuiFnOnce_WuiGuardDecision {
+  void *data;
+  void (*call)(void*, struct WuiGuardDecision);
+} WuiFnOnce_WuiGuardDecision;
+
 typedef struct WuiTab {
   /**
    * The unique identifier for the tab (raw u64 for FFI compatibility).
@@ -1445,6 +2477,32 @@ typedef struct WuiPhoto {
   struct WuiFn_WuiPhotoEvent on_event;
 } WuiPhoto;
 
+/**
+ * FFI representation of an image loaded in the background.
+ */
+typedef struct WuiAsyncImage {
+  struct WuiStr source;
+  /**
+   * Normalized URL that identifies the image in the shared cache.
+   */
+  struct WuiStr cache_key;
+  enum WuiImageCachePolicy cache_policy;
+  enum WuiContentMode content_mode;
+  /**
+   * Display size in points to decode the image at; zero if unknown.
+   */
+  struct WuiSize target_size;
+  /**
+   * Shown while the image is loading.
+   */
+  struct WuiAnyView *placeholder;
+  /**
+   * Shown if the image fails to load.
+   */
+  struct WuiAnyView *error;
+  struct WuiFn_WuiPhotoEvent on_event;
+} WuiAsyncImage;
+
 typedef struct Binding_Volume WuiBinding_Volume;
 
 /**
@@ -1521,52 +2579,148 @@ typedef struct WuiVideoPlayer {
   struct WuiFn_WuiVideoEvent on_event;
 } WuiVideoPlayer;
 
-typedef struct Computed_LivePhotoSource WuiComputed_LivePhotoSource;
-
-typedef struct WuiLivePhoto {
-  WuiComputed_LivePhotoSource *source;
-} WuiLivePhoto;
-
 /**
- * FFI representation of a Video source for Computed signals.
- * This is used by Android to observe video source changes reactively.
+ * FFI representation of an audio event.
  */
-typedef struct WuiComputedVideo {
+typedef struct WuiAudioEvent {
+  enum WuiAudioEventType event_type;
   /**
-   * The URL of the video source.
+   * Length of the audio in seconds, for `ReadyToPlay` events.
    */
-  struct WuiStr url;
-} WuiComputedVideo;
-
-typedef struct Computed_Video WuiComputed_Video;
+  float duration;
+  struct WuiStr error_message;
+} WuiAudioEvent;
 
 /**
- * FFI representation of a selected media item.
+ * A C-compatible function wrapper that can be called multiple times.
+ *
+ * This structure wraps a Rust `Fn` closure to allow it to be passed across
+ * the FFI boundary while maintaining proper memory management.
  */
-typedef struct WuiSelected {
-  /**
-   * The unique identifier of the selected media item.
-   */
-  uint32_t id;
-} WuiSelected;
+typedef struct WuiFn_WuiAudioEvent {
+  void *data;
+  void (*call)(const void*, struct WuiAudioEvent);
+  void (*drop)(void*);
+} WuiFn_WuiAudioEvent;
+
+typedef struct Binding_f32 WuiBinding_f32;
 
 /**
- * A callback for receiving selected media ID when user picks media.
- *
- * This is a C-compatible closure that native code calls when picker completes.
+ * FFI representation of the AudioPlayer component.
  */
-typedef struct MediaPickerPresentCallback {
+typedef struct WuiAudioPlayer {
   /**
-   * Opaque pointer to the callback data.
+   * The audio source URL as a string (reactive).
    */
-  void *data;
+  WuiComputed_Str *source;
   /**
-   * Function to call with the selected media. This consumes the callback.
+   * Whether the audio is playing; set to false when playback ends.
    */
-  void (*call)(void*, struct WuiSelected);
-} MediaPickerPresentCallback;
+  WuiBinding_bool *playing;
+  /**
+   * Playback position in seconds; written while playing, seeks when set.
+   */
+  WuiBinding_f64 *position;
+  WuiBinding_Volume *volume;
+  /**
+   * Playback rate, where 1 is normal speed.
+   */
+  WuiBinding_f32 *rate;
+  /**
+   * Whether playback continues while the app is in the background.
+   */
+  bool background;
+  bool loops;
+  struct WuiFn_WuiAudioEvent on_event;
+} WuiAudioPlayer;
 
-/**
+typedef struct Computed_LivePhotoSource WuiComputed_LivePhotoSource;
+
+typedef struct WuiLivePhoto {
+  WuiComputed_LivePhotoSource *source;
+} WuiLivePhoto;
+
+/**
+ * FFI representation of an animated vector event.
+ */
+typedef struct WuiAnimatedVectorEvent {
+  enum WuiAnimatedVectorEventType event_type;
+  /**
+   * Length of the animation in seconds, for `Loaded` events.
+   */
+  float duration;
+  struct WuiStr error_message;
+} WuiAnimatedVectorEvent;
+
+/**
+ * A C-compatible function wrapper that can be called multiple times.
+ *
+ * This structure wraps a Rust `Fn` closure to allow it to be passed across
+ * the FFI boundary while maintaining proper memory management.
+ */
+typedef struct WuiFn_WuiAnimatedVectorEvent {
+  void *data;
+  void (*call)(const void*, struct WuiAnimatedVectorEvent);
+  void (*drop)(void*);
+} WuiFn_WuiAnimatedVectorEvent;
+
+/**
+ * FFI representation of the AnimatedVector component.
+ */
+typedef struct WuiAnimatedVector {
+  /**
+   * The animation source URL as a string (reactive).
+   */
+  WuiComputed_Str *source;
+  /**
+   * Whether the animation is playing; set to false when it completes.
+   */
+  WuiBinding_bool *playing;
+  /**
+   * Playback position from 0 to 1; written while playing, seeks when set.
+   */
+  WuiBinding_f32 *progress;
+  bool loops;
+  float speed;
+  enum WuiContentMode content_mode;
+  struct WuiFn_WuiAnimatedVectorEvent on_event;
+} WuiAnimatedVector;
+
+/**
+ * FFI representation of a Video source for Computed signals.
+ * This is used by Android to observe video source changes reactively.
+ */
+typedef struct WuiComputedVideo {
+  /**
+   * The URL of the video source.
+   */
+  struct WuiStr url;
+} WuiComputedVideo;
+
+typedef struct Computed_Video WuiComputed_Video;
+
+/**
+ * Unique identifier for selected media items.
+ */
+typedef uint32_t SelectedId;
+
+/**
+ * A callback for receiving selected media ID when user picks media.
+ *
+ * This is a C-compatible closure that native code calls when picker completes.
+ */
+typedef struct MediaPickerPresentCallback {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call with the selected media. This consumes the callback.
+   */
+  void (*call)(void*, SelectedId);
+} MediaPickerPresentCallback;
+
+/**
  * Type alias for the native media picker present function.
  */
 typedef void (*MediaPickerPresentFn)(enum WuiMediaFilterType, struct MediaPickerPresentCallback);
@@ -1621,12 +2775,385 @@ typedef struct MediaLoadCallback {
  */
 typedef void (*MediaLoadFn)(uint32_t, struct MediaLoadCallback);
 
+/**
+ * A callback for receiving the selected media IDs of a multi-selection picker.
+ *
+ * Native code calls it once with the IDs in selection order.
+ */
+typedef struct MediaPickerPresentMultipleCallback {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call with a pointer to `len` selected IDs. This consumes the callback.
+   */
+  void (*call)(void*, const SelectedId*, uintptr_t);
+} MediaPickerPresentMultipleCallback;
+
+/**
+ * Type alias for the native multi-selection media picker present function.
+ *
+ * The `usize` argument is the selection limit, where `0` means no limit.
+ */
+typedef void (*MediaPickerPresentMultipleFn)(enum WuiMediaFilterType,
+                                             uintptr_t,
+                                             struct MediaPickerPresentMultipleCallback);
+
+/**
+ * Type alias for the native function returning the current authorization state.
+ */
+typedef enum WuiPhotoLibraryPermission (*PhotoLibraryPermissionFn)(void);
+
+/**
+ * FFI representation of an album. Strings are borrowed for the duration of the callback.
+ */
+typedef struct WuiPhotoAlbum {
+  /**
+   * Pointer to the UTF-8 encoded album identifier.
+   */
+  const uint8_t *id_ptr;
+  /**
+   * Length of the identifier in bytes.
+   */
+  uintptr_t id_len;
+  /**
+   * Pointer to the UTF-8 encoded album title.
+   */
+  const uint8_t *title_ptr;
+  /**
+   * Length of the title in bytes.
+   */
+  uintptr_t title_len;
+  /**
+   * Number of assets in the album.
+   */
+  uintptr_t count;
+} WuiPhotoAlbum;
+
+/**
+ * A callback for receiving the authorization state after a permission request.
+ */
+typedef struct PhotoLibraryPermissionCallback {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call with the authorization state. This consumes the callback.
+   */
+  void (*call)(void*, enum WuiPhotoLibraryPermission);
+} PhotoLibraryPermissionCallback;
+
+/**
+ * Type alias for the native function prompting the user for library access.
+ */
+typedef void (*PhotoLibraryRequestPermissionFn)(struct PhotoLibraryPermissionCallback);
+
+/**
+ * A callback for receiving the albums of the library.
+ */
+typedef struct PhotoLibraryAlbumsCallback {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call with a pointer to `len` albums. This consumes the callback.
+   */
+  void (*call)(void*, const struct WuiPhotoAlbum*, uintptr_t);
+} PhotoLibraryAlbumsCallback;
+
+/**
+ * Type alias for the native function fetching albums.
+ */
+typedef void (*PhotoLibraryAlbumsFn)(struct PhotoLibraryAlbumsCallback);
+
+/**
+ * FFI representation of an asset query.
+ */
+typedef struct WuiPhotoAssetQuery {
+  /**
+   * Pointer to the UTF-8 encoded album identifier, or null for the whole library.
+   */
+  const uint8_t *album_ptr;
+  /**
+   * Length of the album identifier in bytes.
+   */
+  uintptr_t album_len;
+  /**
+   * Kinds of media to include.
+   */
+  enum WuiMediaFilterType filter;
+  /**
+   * Index of the first asset to return.
+   */
+  uintptr_t offset;
+  /**
+   * Maximum number of assets to return, where `0` means no limit.
+   */
+  uintptr_t limit;
+} WuiPhotoAssetQuery;
+
+/**
+ * FFI representation of an asset. Strings are borrowed for the duration of the callback.
+ */
+typedef struct WuiPhotoAsset {
+  /**
+   * Pointer to the UTF-8 encoded asset identifier.
+   */
+  const uint8_t *id_ptr;
+  /**
+   * Length of the identifier in bytes.
+   */
+  uintptr_t id_len;
+  /**
+   * Pointer to the UTF-8 encoded thumbnail URL.
+   */
+  const uint8_t *thumbnail_ptr;
+  /**
+   * Length of the thumbnail URL in bytes.
+   */
+  uintptr_t thumbnail_len;
+  /**
+   * Media type, using the [`media_type`] constants.
+   */
+  uint8_t media_type;
+} WuiPhotoAsset;
+
+/**
+ * A callback for receiving a page of assets.
+ */
+typedef struct PhotoLibraryAssetsCallback {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call with a pointer to `len` assets. This consumes the callback.
+   */
+  void (*call)(void*, const struct WuiPhotoAsset*, uintptr_t);
+} PhotoLibraryAssetsCallback;
+
+/**
+ * Type alias for the native function fetching assets.
+ */
+typedef void (*PhotoLibraryAssetsFn)(struct WuiPhotoAssetQuery, struct PhotoLibraryAssetsCallback);
+
+/**
+ * Type alias for the native function loading the full-size media of an asset.
+ */
+typedef void (*PhotoLibraryLoadFn)(const uint8_t*, uintptr_t, struct MediaLoadCallback);
+
+/**
+ * A callback native code calls every time the library content changes.
+ *
+ * Unlike the other callbacks it may be called many times; native code must call
+ * `drop` once it stops observing.
+ */
+typedef struct PhotoLibraryChangeCallback {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call when the library changes.
+   */
+  void (*call)(const void*);
+  /**
+   * Function to release the callback data.
+   */
+  void (*drop)(void*);
+} PhotoLibraryChangeCallback;
+
+/**
+ * Type alias for the native function registering a library change observer.
+ */
+typedef void (*PhotoLibraryObserveFn)(struct PhotoLibraryChangeCallback);
+
+/**
+ * Type alias for the native function starting or cancelling a prefetch of a URL.
+ *
+ * Receives the URL as UTF-8 bytes that are only valid for the duration of the call.
+ */
+typedef void (*PhotoCacheUrlFn)(const uint8_t*, uintptr_t);
+
+/**
+ * Type alias for the native function playing a haptic pattern.
+ */
+typedef void (*FeedbackHapticFn)(enum WuiHapticStyle);
+
+/**
+ * Type alias for the native function playing a system sound.
+ */
+typedef void (*FeedbackSoundFn)(enum WuiFeedbackSound);
+
+/**
+ * Receives a stored value from native code.
+ *
+ * Native code calls `call` with `data` and the value as UTF-8 bytes, which
+ * only need to stay valid for the duration of the call.
+ */
+typedef struct StorageValueCallback {
+  /**
+   * Opaque pointer to the receiving slot.
+   */
+  void *data;
+  /**
+   * Function receiving the value.
+   */
+  void (*call)(void*, const uint8_t*, uintptr_t);
+} StorageValueCallback;
+
+/**
+ * Type alias for the native function loading a value.
+ *
+ * Receives the key as UTF-8 bytes and calls the callback before returning if
+ * a value is stored under it.
+ */
+typedef void (*StorageLoadFn)(const uint8_t*, uintptr_t, struct StorageValueCallback);
+
+/**
+ * Type alias for the native function storing a value.
+ *
+ * Receives the key and the value as UTF-8 bytes that are only valid for the
+ * duration of the call.
+ */
+typedef void (*StorageStoreFn)(const uint8_t*, uintptr_t, const uint8_t*, uintptr_t);
+
+/**
+ * Type alias for the native function removing a value.
+ */
+typedef void (*StorageRemoveFn)(const uint8_t*, uintptr_t);
+
+/**
+ * FFI-safe representation of a shared item.
+ */
+typedef struct WuiShareItem {
+  enum WuiShareItemKind kind;
+  /**
+   * The text of `Text` items, the URL of `Url` items, or the file name of
+   * `File` items.
+   */
+  struct WuiStr text;
+  /**
+   * The MIME type of `File` items. Empty otherwise.
+   */
+  struct WuiStr mime;
+  /**
+   * The contents of `File` items. Empty otherwise.
+   */
+  struct WuiArray_u8 data;
+} WuiShareItem;
+
+typedef struct WuiArraySlice_WuiShareItem {
+  struct WuiShareItem *head;
+  uintptr_t len;
+} WuiArraySlice_WuiShareItem;
+
+typedef struct WuiArrayVTable_WuiShareItem {
+  void (*drop)(void*);
+  struct WuiArraySlice_WuiShareItem (*slice)(const void*);
+} WuiArrayVTable_WuiShareItem;
+
+/**
+ * A generic array structure for FFI, representing a contiguous sequence of elements.
+ * `WuiArray` can represent multiple types of arrays, for instance, a `&[T]` (in this case, the lifetime of WuiArray is bound to the caller's scope),
+ * or a value type having a static lifetime like `Vec<T>`, `Box<[T]>`, `Bytes`, or even a foreign allocated array.
+ * For a value type, `WuiArray` contains a destructor function pointer to free the array buffer, whatever it is allocated by Rust side or foreign side.
+ * We assume `T` does not contain any non-trivial drop logic, and `WuiArray` will not call `drop` on each element when it is dropped.
+ */
+typedef struct WuiArray_WuiShareItem {
+  NonNull data;
+  struct WuiArrayVTable_WuiShareItem vtable;
+} WuiArray_WuiShareItem;
+
+/**
+ * A callback for receiving the outcome of a share.
+ *
+ * Native code calls it once, after the share sheet is dismissed.
+ */
+typedef struct ShareCompletion {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call with the outcome. This consumes the callback.
+   */
+  void (*call)(void*, enum WuiShareOutcome);
+} ShareCompletion;
+
+/**
+ * Type alias for the native function presenting the share sheet.
+ *
+ * The items array is owned by native code, which must drop it.
+ */
+typedef void (*ShareFn)(struct WuiArray_WuiShareItem, struct ShareCompletion);
+
 typedef struct WuiListItem {
   struct WuiAnyView *content;
 } WuiListItem;
 
+/**
+ * A callback called every time a list selection changes.
+ *
+ * `drop` is called once the watcher guard returned with it is dropped.
+ */
+typedef struct WuiListSelectionWatcher {
+  /**
+   * Opaque pointer to the callback data.
+   */
+  void *data;
+  /**
+   * Function to call when the selection changes.
+   */
+  void (*call)(const void*);
+  /**
+   * Function to release the callback data.
+   */
+  void (*drop)(void*);
+} WuiListSelectionWatcher;
+
+/**
+ * A recyclable list row: its view, the slot used to rebind it and its
+ * delete callback.
+ */
+typedef struct WuiRecycledRow {
+  struct WuiAnyView *content;
+  struct WuiRowSlot *slot;
+  /**
+   * Null if the row cannot be deleted.
+   */
+  struct WuiRowDelete *on_delete;
+} WuiRecycledRow;
+
 typedef struct WuiList {
   struct WuiAnyViews *contents;
+  /**
+   * Null if the list does not support row recycling.
+   */
+  struct WuiRowRecycler *recycler;
+  /**
+   * Null if no prefetch hint is set.
+   */
+  struct WuiPrefetch *prefetch;
+  /**
+   * Null if rows cannot be selected.
+   */
+  struct WuiListSelection *selection;
+  /**
+   * Null if the list is not refreshable.
+   */
+  struct WuiRefresh *refresh;
+  /**
+   * Null if no reach-end callback is set.
+   */
+  struct WuiReachEnd *reach_end;
+  /**
+   * Null if no scroll position is attached.
+   */
+  struct WuiScrollPosition *position;
 } WuiList;
 
 typedef struct WuiTableColumn {
@@ -1662,14 +3189,82 @@ typedef struct WuiTable {
   WuiComputed_Vec_TableColumn *columns;
 } WuiTable;
 
-typedef struct Computed_f64 WuiComputed_f64;
+typedef struct Computed_f64 WuiComputed_f64;
+
+typedef struct WuiProgress {
+  struct WuiAnyView *label;
+  struct WuiAnyView *value_label;
+  WuiComputed_f64 *value;
+  enum WuiProgressStyle style;
+} WuiProgress;
+
+/**
+ * Spring parameters, matching the mass/stiffness/damping initializers of
+ * native spring APIs.
+ */
+typedef struct WuiSpring {
+  /**
+   * Mass of the moving object; always 1, stiffness and damping are scaled to it.
+   */
+  float mass;
+  /**
+   * Stiffness of the spring.
+   */
+  float stiffness;
+  /**
+   * Damping force per unit of velocity.
+   */
+  float damping;
+} WuiSpring;
+
+/**
+ * Transition the native backend should apply when swapping dynamic content.
+ */
+typedef struct WuiTransition {
+  /**
+   * The kind of transition.
+   */
+  enum WuiTransitionKind kind;
+  /**
+   * Duration of the transition in milliseconds.
+   */
+  uint64_t duration_ms;
+} WuiTransition;
+
+/**
+ * FFI representation of how a GpuSurface is sized.
+ */
+typedef enum WuiSurfaceSizing_Tag {
+  /**
+   * Fills all the space proposed by the parent.
+   */
+  WuiSurfaceSizing_Fill,
+  /**
+   * Takes the largest size with this aspect ratio (width / height) that fits.
+   */
+  WuiSurfaceSizing_FitContent,
+  /**
+   * Reports a preferred size in points, shrunk to fit the proposal.
+   */
+  WuiSurfaceSizing_Preferred,
+} WuiSurfaceSizing_Tag;
+
+typedef struct WuiSurfaceSizing_FitContent_Body {
+  float aspect_ratio;
+} WuiSurfaceSizing_FitContent_Body;
+
+typedef struct WuiSurfaceSizing_Preferred_Body {
+  float width;
+  float height;
+} WuiSurfaceSizing_Preferred_Body;
 
-typedef struct WuiProgress {
-  struct WuiAnyView *label;
-  struct WuiAnyView *value_label;
-  WuiComputed_f64 *value;
-  enum WuiProgressStyle style;
-} WuiProgress;
+typedef struct WuiSurfaceSizing {
+  WuiSurfaceSizing_Tag tag;
+  union {
+    WuiSurfaceSizing_FitContent_Body fit_content;
+    WuiSurfaceSizing_Preferred_Body preferred;
+  };
+} WuiSurfaceSizing;
 
 /**
  * FFI representation of a GpuSurface view.
@@ -1684,8 +3279,58 @@ typedef struct WuiGpuSurface {
    * This is consumed during init and should not be used after.
    */
   void *renderer;
+  /**
+   * Opaque pointer to the boxed snapshot request queue.
+   * This is consumed during init and should not be used after.
+   */
+  void *snapshots;
+  /**
+   * How the surface is sized by the layout system.
+   */
+  struct WuiSurfaceSizing sizing;
 } WuiGpuSurface;
 
+/**
+ * A Jetpack Compose Composable hosted by the Android backend.
+ */
+typedef struct WuiComposeView {
+  struct WuiStr factory;
+  WuiComputed_Str *params;
+} WuiComposeView;
+
+/**
+ * A SwiftUI view hosted by the Apple backend.
+ */
+typedef struct WuiSwiftUIView {
+  struct WuiStr identifier;
+  WuiComputed_Str *params;
+} WuiSwiftUIView;
+
+/**
+ * FFI representation of a captured GpuSurface frame.
+ *
+ * Pixels are tightly packed rows, top row first. When `hdr` is false they are
+ * 8-bit sRGB RGBA; when true they are linear RGBA16F (little-endian half floats).
+ */
+typedef struct WuiGpuSnapshot {
+  /**
+   * Width in pixels, or 0 if the capture failed.
+   */
+  uint32_t width;
+  /**
+   * Height in pixels, or 0 if the capture failed.
+   */
+  uint32_t height;
+  /**
+   * Whether `data` holds RGBA16F instead of RGBA8 pixels.
+   */
+  bool hdr;
+  /**
+   * Pixel data.
+   */
+  struct WuiArray_u8 data;
+} WuiGpuSnapshot;
+
 typedef struct WuiId {
   int32_t inner;
 } WuiId;
@@ -1696,10 +3341,6 @@ typedef struct Binding_AnyView WuiBinding_AnyView;
 
 typedef struct Computed_AnyView WuiComputed_AnyView;
 
-typedef struct Binding_f32 WuiBinding_f32;
-
-typedef struct Computed_f32 WuiComputed_f32;
-
 typedef struct WuiPickerItem {
   struct WuiId tag;
   struct WuiText content;
@@ -1732,8 +3373,6 @@ typedef struct WuiLivePhotoSource {
   struct WuiStr video;
 } WuiLivePhotoSource;
 
-typedef struct Computed_ColorScheme WuiComputed_ColorScheme;
-
 typedef struct Computed_AnyViews_AnyView WuiComputed_AnyViews_AnyView;
 
 typedef struct Binding_Rect WuiBinding_Rect;
@@ -1838,80 +3477,447 @@ typedef struct WuiApp {
 
 /**
  * # Safety
- * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_env(struct WuiEnv *value);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_anyview(struct WuiAnyView *value);
+
+/**
+ * Creates a new environment instance
+ */
+struct WuiEnv *waterui_env_new(void);
+
+/**
+ * Installs the display scale signal (physical pixels per point) into the environment.
+ *
+ * # Safety
+ * Both pointers must be valid. The signal pointer is consumed.
+ */
+void waterui_env_install_display_scale(struct WuiEnv *env, WuiComputed_f32 *scale);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_display_reporter(struct WuiDisplayReporter *value);
+
+/**
+ * Installs signals for every display property into the environment.
+ *
+ * The returned reporter updates them; keep it alive for as long as the
+ * window exists, and set new values whenever the window moves to another
+ * screen or the screen's mode changes.
+ *
+ * # Safety
+ * The caller must ensure that `env` is a valid pointer.
+ */
+struct WuiDisplayReporter *waterui_env_install_display_reporter(struct WuiEnv *env);
+
+/**
+ * Sets the backing scale factor (physical pixels per point).
+ *
+ * # Safety
+ * The caller must ensure that `reporter` is a valid pointer.
+ */
+void waterui_display_reporter_set_scale(const struct WuiDisplayReporter *reporter, float scale);
+
+/**
+ * Sets the maximum refresh rate of the display, in hertz.
+ *
+ * # Safety
+ * The caller must ensure that `reporter` is a valid pointer.
+ */
+void waterui_display_reporter_set_refresh_rate(const struct WuiDisplayReporter *reporter,
+                                               float hertz);
+
+/**
+ * Sets the color gamut of the display.
+ *
+ * # Safety
+ * The caller must ensure that `reporter` is a valid pointer.
+ */
+void waterui_display_reporter_set_color_gamut(const struct WuiDisplayReporter *reporter,
+                                              enum WuiColorGamut gamut);
+
+/**
+ * Sets whether the display can show high dynamic range content.
+ *
+ * # Safety
+ * The caller must ensure that `reporter` is a valid pointer.
+ */
+void waterui_display_reporter_set_supports_hdr(const struct WuiDisplayReporter *reporter,
+                                               bool supported);
+
+/**
+ * Sets the most headroom a color can use on the display right now.
+ *
+ * `0.0` is SDR white; on Apple platforms pass the maximum EDR color
+ * component value minus one, and report it again when it changes.
+ *
+ * # Safety
+ * The caller must ensure that `reporter` is a valid pointer.
+ */
+void waterui_display_reporter_set_max_headroom(const struct WuiDisplayReporter *reporter,
+                                               float headroom);
+
+/**
+ * Sets the size of the screen, in points.
+ *
+ * # Safety
+ * The caller must ensure that `reporter` is a valid pointer.
+ */
+void waterui_display_reporter_set_screen_size(const struct WuiDisplayReporter *reporter,
+                                              struct WuiSize size);
+
+/**
+ * Installs the platform the backend renders for into the environment.
+ *
+ * # Safety
+ * The caller must ensure that `env` is a valid pointer.
+ */
+void waterui_env_install_platform(struct WuiEnv *env, enum WuiPlatform platform);
+
+/**
+ * Installs the version of the OS the app is running on into the environment.
+ *
+ * Components that need a newer version show their fallback when wrapped in
+ * `if_available`. Without it, every component is assumed to be available.
+ *
+ * # Safety
+ * The caller must ensure that `env` is a valid pointer.
+ */
+void waterui_env_install_os_version(struct WuiEnv *env, struct WuiOsVersion version);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_ornament_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataOrnament waterui_force_as_metadata_ornament(struct WuiAnyView *view);
+
+/**
+ * Declares that the backend renders ornament metadata.
+ *
+ * Only backends that can attach views outside a window, such as visionOS,
+ * should call this. Without it, ornaments are dropped from the view tree.
+ *
+ * # Safety
+ * The caller must ensure that `env` is a valid pointer.
+ */
+void waterui_env_install_ornament_support(struct WuiEnv *env);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_digital_crown(struct WuiDigitalCrown *value);
+
+/**
+ * Installs a digital crown into the environment and returns its reporter.
+ *
+ * Only backends for devices with a crown, such as watchOS, should call
+ * this. Report rotations with `waterui_digital_crown_set`.
+ *
+ * # Safety
+ * The caller must ensure that `env` is a valid pointer.
+ */
+struct WuiDigitalCrown *waterui_env_install_digital_crown(struct WuiEnv *env);
+
+/**
+ * Reports the accumulated rotation of the digital crown, in full turns.
+ *
+ * # Safety
+ * The caller must ensure that `crown` is a valid pointer.
+ */
+void waterui_digital_crown_set(const struct WuiDigitalCrown *crown, double rotation);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_network_monitor(struct WuiNetworkMonitor *value);
+
+/**
+ * Installs a network monitor into the environment and returns its reporter.
+ *
+ * Report every reachability change with `waterui_network_monitor_set`.
+ *
+ * # Safety
+ * The caller must ensure that `env` is a valid pointer.
+ */
+struct WuiNetworkMonitor *waterui_env_install_network_monitor(struct WuiEnv *env);
+
+/**
+ * Reports the current network reachability.
+ *
+ * # Safety
+ * The caller must ensure that `monitor` is a valid pointer.
+ */
+void waterui_network_monitor_set(const struct WuiNetworkMonitor *monitor,
+                                 enum WuiReachability reachability);
+
+/**
+ * Gets the id of the anyview type as a 128-bit value for O(1) comparison.
+ */
+struct WuiTypeId waterui_anyview_id(void);
+
+/**
+ * Clones an existing environment instance
+ *
+ * # Safety
+ * The caller must ensure that `env` is a valid pointer to a properly initialized
+ * `waterui::Environment` instance and that the environment remains valid for the
+ * duration of this function call.
+ */
+struct WuiEnv *waterui_clone_env(const struct WuiEnv *env);
+
+/**
+ * Gets the body of a view given the environment
+ *
+ * # Safety
+ * The caller must ensure that both `view` and `env` are valid pointers to properly
+ * initialized instances and that they remain valid for the duration of this function call.
+ * The `view` pointer will be consumed and should not be used after this call.
+ */
+struct WuiAnyView *waterui_view_body(struct WuiAnyView *view, struct WuiEnv *env);
+
+/**
+ * Gets the id of a view as a 128-bit value for O(1) comparison.
+ *
+ * - Normal build: Returns the view's `TypeId` (guaranteed unique)
+ * - Hot reload: Returns 128-bit hash of `type_name()` (stable across dylibs)
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to a properly
+ * initialized `WuiAnyView` instance and that it remains valid for the
+ * duration of this function call.
+ */
+struct WuiTypeId waterui_view_id(const struct WuiAnyView *view);
+
+/**
+ * Gets the stretch axis of a view.
+ *
+ * Returns the `StretchAxis` that indicates how this view stretches to fill
+ * available space. For native views, this returns the layout behavior defined
+ * by the `NativeView` trait. For non-native views, this will panic.
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to a properly
+ * initialized `WuiAnyView` instance and that it remains valid for the
+ * duration of this function call.
+ */
+enum WuiStretchAxis waterui_view_stretch_axis(const struct WuiAnyView *view);
+
+struct WuiAnyView *waterui_empty_anyview(void);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_env_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataEnv waterui_force_as_metadata_env(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_secure_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataSecure waterui_force_as_metadata_secure(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_gesture_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataGesture waterui_force_as_metadata_gesture(struct WuiAnyView *view);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_drag_source(struct WuiDragSource *value);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_drag_source_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataDragSource waterui_force_as_metadata_drag_source(struct WuiAnyView *view);
+
+/**
+ * Returns the items of a drag that is starting.
+ *
+ * An empty array means the view should not be dragged.
+ *
+ * # Safety
+ * The caller must ensure that `source` is a valid pointer.
+ */
+struct WuiArray_WuiDragItem waterui_drag_source_items(const struct WuiDragSource *source);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_drop_target(struct WuiDropTarget *value);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_drop_target_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataDropTarget waterui_force_as_metadata_drop_target(struct WuiAnyView *view);
+
+/**
+ * Returns the types of items a drop target accepts.
+ *
+ * Backends register these with the platform so only matching drags
+ * highlight the view.
+ *
+ * # Safety
+ * The caller must ensure that `target` is a valid pointer.
  */
-void waterui_drop_env(struct WuiEnv *value);
+struct WuiArray_WuiDragType waterui_drop_target_accepts(const struct WuiDropTarget *target);
 
 /**
+ * Delivers dropped items to a drop target.
+ *
+ * Returns whether the target took the items.
+ *
  * # Safety
- * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ * The caller must ensure that:
+ * - `target` is a valid pointer
+ * - `items` points to `len` initialized items, which are moved into Rust;
+ *   the memory holding them stays owned by the caller
  */
-void waterui_drop_anyview(struct WuiAnyView *value);
+bool waterui_drop_target_perform(struct WuiDropTarget *target,
+                                 struct WuiDragItem *items,
+                                 uintptr_t len,
+                                 struct WuiGesturePoint location);
 
 /**
- * Creates a new environment instance
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
  */
-struct WuiEnv *waterui_env_new(void);
+void waterui_drop_reorderable(struct WuiReorderable *value);
 
 /**
- * Gets the id of the anyview type as a 128-bit value for O(1) comparison.
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_anyview_id(void);
+struct WuiTypeId waterui_metadata_reorderable_id(void);
 
 /**
- * Clones an existing environment instance
+ * Force-casts an AnyView to this metadata type
  *
  * # Safety
- * The caller must ensure that `env` is a valid pointer to a properly initialized
- * `waterui::Environment` instance and that the environment remains valid for the
- * duration of this function call.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
  */
-struct WuiEnv *waterui_clone_env(const struct WuiEnv *env);
+WuiMetadataReorderable waterui_force_as_metadata_reorderable(struct WuiAnyView *view);
 
 /**
- * Gets the body of a view given the environment
+ * Starts dragging the child at `index` of a reorderable stack or grid.
+ *
+ * Returns `false` if the drag should not start.
  *
  * # Safety
- * The caller must ensure that both `view` and `env` are valid pointers to properly
- * initialized instances and that they remain valid for the duration of this function call.
- * The `view` pointer will be consumed and should not be used after this call.
+ * The caller must ensure that `reorderable` is a valid pointer.
  */
-struct WuiAnyView *waterui_view_body(struct WuiAnyView *view, struct WuiEnv *env);
+bool waterui_reorderable_begin(const struct WuiReorderable *reorderable, uintptr_t index);
 
 /**
- * Gets the id of a view as a 128-bit value for O(1) comparison.
+ * Drops the dragged child so it ends up at `destination`.
  *
- * - Normal build: Returns the view's `TypeId` (guaranteed unique)
- * - Hot reload: Returns 128-bit hash of `type_name()` (stable across dylibs)
+ * Returns whether the order changed. The stack re-renders with the new
+ * order, so backends should settle the child into its gap without
+ * rearranging the children themselves.
  *
  * # Safety
- * The caller must ensure that `view` is a valid pointer to a properly
- * initialized `WuiAnyView` instance and that it remains valid for the
- * duration of this function call.
+ * The caller must ensure that `reorderable` is a valid pointer.
  */
-struct WuiTypeId waterui_view_id(const struct WuiAnyView *view);
+bool waterui_reorderable_finish(const struct WuiReorderable *reorderable, uintptr_t destination);
 
 /**
- * Gets the stretch axis of a view.
- *
- * Returns the `StretchAxis` that indicates how this view stretches to fill
- * available space. For native views, this returns the layout behavior defined
- * by the `NativeView` trait. For non-native views, this will panic.
+ * Abandons the current drag, keeping the order.
  *
  * # Safety
- * The caller must ensure that `view` is a valid pointer to a properly
- * initialized `WuiAnyView` instance and that it remains valid for the
- * duration of this function call.
+ * The caller must ensure that `reorderable` is a valid pointer.
  */
-enum WuiStretchAxis waterui_view_stretch_axis(const struct WuiAnyView *view);
+void waterui_reorderable_cancel(const struct WuiReorderable *reorderable);
 
-struct WuiAnyView *waterui_empty_anyview(void);
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_metadata_on_event_id(void);
+
+/**
+ * Force-casts an AnyView to this metadata type
+ *
+ * # Safety
+ * The caller must ensure that `view` is a valid pointer to an `AnyView`
+ * that contains a `Metadata<$ty>`.
+ */
+WuiMetadataOnEvent waterui_force_as_metadata_on_event(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_metadata_env_id(void);
+struct WuiTypeId waterui_metadata_background_id(void);
 
 /**
  * Force-casts an AnyView to this metadata type
@@ -1920,13 +3926,13 @@ struct WuiTypeId waterui_metadata_env_id(void);
  * The caller must ensure that `view` is a valid pointer to an `AnyView`
  * that contains a `Metadata<$ty>`.
  */
-WuiMetadataEnv waterui_force_as_metadata_env(struct WuiAnyView *view);
+WuiMetadataBackground waterui_force_as_metadata_background(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_metadata_secure_id(void);
+struct WuiTypeId waterui_metadata_foreground_id(void);
 
 /**
  * Force-casts an AnyView to this metadata type
@@ -1935,13 +3941,13 @@ struct WuiTypeId waterui_metadata_secure_id(void);
  * The caller must ensure that `view` is a valid pointer to an `AnyView`
  * that contains a `Metadata<$ty>`.
  */
-WuiMetadataSecure waterui_force_as_metadata_secure(struct WuiAnyView *view);
+WuiMetadataForeground waterui_force_as_metadata_foreground(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_metadata_gesture_id(void);
+struct WuiTypeId waterui_metadata_shadow_id(void);
 
 /**
  * Force-casts an AnyView to this metadata type
@@ -1950,13 +3956,13 @@ struct WuiTypeId waterui_metadata_gesture_id(void);
  * The caller must ensure that `view` is a valid pointer to an `AnyView`
  * that contains a `Metadata<$ty>`.
  */
-WuiMetadataGesture waterui_force_as_metadata_gesture(struct WuiAnyView *view);
+WuiMetadataShadow waterui_force_as_metadata_shadow(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_metadata_on_event_id(void);
+struct WuiTypeId waterui_metadata_color_filter_id(void);
 
 /**
  * Force-casts an AnyView to this metadata type
@@ -1965,13 +3971,13 @@ struct WuiTypeId waterui_metadata_on_event_id(void);
  * The caller must ensure that `view` is a valid pointer to an `AnyView`
  * that contains a `Metadata<$ty>`.
  */
-WuiMetadataOnEvent waterui_force_as_metadata_on_event(struct WuiAnyView *view);
+WuiMetadataColorFilter waterui_force_as_metadata_color_filter(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_metadata_background_id(void);
+struct WuiTypeId waterui_metadata_focused_id(void);
 
 /**
  * Force-casts an AnyView to this metadata type
@@ -1980,13 +3986,13 @@ struct WuiTypeId waterui_metadata_background_id(void);
  * The caller must ensure that `view` is a valid pointer to an `AnyView`
  * that contains a `Metadata<$ty>`.
  */
-WuiMetadataBackground waterui_force_as_metadata_background(struct WuiAnyView *view);
+WuiMetadataFocused waterui_force_as_metadata_focused(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_metadata_foreground_id(void);
+struct WuiTypeId waterui_metadata_ignore_safe_area_id(void);
 
 /**
  * Force-casts an AnyView to this metadata type
@@ -1995,13 +4001,13 @@ struct WuiTypeId waterui_metadata_foreground_id(void);
  * The caller must ensure that `view` is a valid pointer to an `AnyView`
  * that contains a `Metadata<$ty>`.
  */
-WuiMetadataForeground waterui_force_as_metadata_foreground(struct WuiAnyView *view);
+WuiMetadataIgnoreSafeArea waterui_force_as_metadata_ignore_safe_area(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_metadata_shadow_id(void);
+struct WuiTypeId waterui_metadata_z_index_id(void);
 
 /**
  * Force-casts an AnyView to this metadata type
@@ -2010,13 +4016,13 @@ struct WuiTypeId waterui_metadata_shadow_id(void);
  * The caller must ensure that `view` is a valid pointer to an `AnyView`
  * that contains a `Metadata<$ty>`.
  */
-WuiMetadataShadow waterui_force_as_metadata_shadow(struct WuiAnyView *view);
+WuiMetadataZIndex waterui_force_as_metadata_z_index(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_metadata_focused_id(void);
+struct WuiTypeId waterui_metadata_preferred_color_scheme_id(void);
 
 /**
  * Force-casts an AnyView to this metadata type
@@ -2025,13 +4031,13 @@ struct WuiTypeId waterui_metadata_focused_id(void);
  * The caller must ensure that `view` is a valid pointer to an `AnyView`
  * that contains a `Metadata<$ty>`.
  */
-WuiMetadataFocused waterui_force_as_metadata_focused(struct WuiAnyView *view);
+WuiMetadataPreferredColorScheme waterui_force_as_metadata_preferred_color_scheme(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_metadata_ignore_safe_area_id(void);
+struct WuiTypeId waterui_metadata_view_id_id(void);
 
 /**
  * Force-casts an AnyView to this metadata type
@@ -2040,7 +4046,7 @@ struct WuiTypeId waterui_metadata_ignore_safe_area_id(void);
  * The caller must ensure that `view` is a valid pointer to an `AnyView`
  * that contains a `Metadata<$ty>`.
  */
-WuiMetadataIgnoreSafeArea waterui_force_as_metadata_ignore_safe_area(struct WuiAnyView *view);
+WuiMetadataViewId waterui_force_as_metadata_view_id(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
@@ -2084,6 +4090,14 @@ void waterui_call_action(struct WuiAction *action, const struct WuiEnv *env);
 
 enum WuiAnimation waterui_get_animation(const struct WuiWatcherMetadata *metadata);
 
+/**
+ * Returns the spring attached to an update, or all zeros if the update is
+ * not animated with a spring.
+ */
+struct WuiSpring waterui_get_spring(const struct WuiWatcherMetadata *metadata);
+
+struct WuiTransition waterui_get_transition(const struct WuiWatcherMetadata *metadata);
+
 /**
  * # Safety
  * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
@@ -2234,6 +4248,102 @@ struct WuiWatcher_Color *waterui_new_watcher_color(void *data,
 WuiComputed_ResolvedColor *waterui_resolve_color(const struct WuiColor *color,
                                                  const struct WuiEnv *env);
 
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_gradient(struct WuiGradient *value);
+
+/**
+ * Reads the current value from a computed
+ * # Safety
+ * The computed pointer must be valid and point to a properly initialized computed object.
+ */
+struct WuiGradient *waterui_read_computed_gradient(const WuiComputed_Gradient *computed);
+
+/**
+ * Watches for changes in a computed
+ * # Safety
+ * The computed pointer must be valid and point to a properly initialized computed object.
+ */
+struct WuiWatcherGuard *waterui_watch_computed_gradient(const WuiComputed_Gradient *computed,
+                                                        struct WuiWatcher_Gradient *watcher);
+
+/**
+ * Drops a computed
+ * # Safety
+ * The caller must ensure that `computed` is a valid pointer.
+ */
+void waterui_drop_computed_gradient(WuiComputed_Gradient *computed);
+
+/**
+ * Clones a computed
+ * # Safety
+ * The caller must ensure that `computed` is a valid pointer.
+ */
+WuiComputed_Gradient *waterui_clone_computed_gradient(const WuiComputed_Gradient *computed);
+
+/**
+ * Creates a watcher from native callbacks.
+ * # Safety
+ * All function pointers must be valid.
+ */
+struct WuiWatcher_Gradient *waterui_new_watcher_gradient(void *data,
+                                                         void (*call)(void*,
+                                                                      struct WuiGradient*,
+                                                                      struct WuiWatcherMetadata*),
+                                                         void (*drop)(void*));
+
+/**
+ * Reads the current value from a computed
+ * # Safety
+ * The computed pointer must be valid and point to a properly initialized computed object.
+ */
+struct WuiResolvedGradient waterui_read_computed_resolved_gradient(const WuiComputed_ResolvedGradient *computed);
+
+/**
+ * Watches for changes in a computed
+ * # Safety
+ * The computed pointer must be valid and point to a properly initialized computed object.
+ */
+struct WuiWatcherGuard *waterui_watch_computed_resolved_gradient(const WuiComputed_ResolvedGradient *computed,
+                                                                 struct WuiWatcher_ResolvedGradient *watcher);
+
+/**
+ * Drops a computed
+ * # Safety
+ * The caller must ensure that `computed` is a valid pointer.
+ */
+void waterui_drop_computed_resolved_gradient(WuiComputed_ResolvedGradient *computed);
+
+/**
+ * Clones a computed
+ * # Safety
+ * The caller must ensure that `computed` is a valid pointer.
+ */
+WuiComputed_ResolvedGradient *waterui_clone_computed_resolved_gradient(const WuiComputed_ResolvedGradient *computed);
+
+/**
+ * Creates a watcher from native callbacks.
+ * # Safety
+ * All function pointers must be valid.
+ */
+struct WuiWatcher_ResolvedGradient *waterui_new_watcher_resolved_gradient(void *data,
+                                                                          void (*call)(void*,
+                                                                                       struct WuiResolvedGradient,
+                                                                                       struct WuiWatcherMetadata*),
+                                                                          void (*drop)(void*));
+
+/**
+ * Resolves a gradient in the given environment.
+ *
+ * # Safety
+ *
+ * Both `gradient` and `env` must be valid, non-null pointers to their respective types.
+ */
+WuiComputed_ResolvedGradient *waterui_resolve_gradient(const struct WuiGradient *gradient,
+                                                       const struct WuiEnv *env);
+
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
@@ -2259,36 +4369,100 @@ struct WuiTypeId waterui_empty_id(void);
 void waterui_drop_layout(struct WuiLayout *value);
 
 /**
- * Returns the type ID for Spacer views as a 128-bit value.
- * `Spacer` is a raw view that stretches to fill available space.
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiSpacer waterui_force_as_spacer(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_spacer_id(void);
+
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiFixedContainer waterui_force_as_fixed_container(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_fixed_container_id(void);
+
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiContainer waterui_force_as_layout_container(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_layout_container_id(void);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
  */
-struct WuiTypeId waterui_spacer_id(void);
+void waterui_drop_lazy_window(struct WuiLazyWindow *value);
 
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
  * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
  */
-struct WuiFixedContainer waterui_force_as_fixed_container(struct WuiAnyView *view);
+struct WuiLazyList waterui_force_as_lazy_list(struct WuiAnyView *view);
 
 /**
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_fixed_container_id(void);
+struct WuiTypeId waterui_lazy_list_id(void);
 
 /**
+ * Gets the number of rows of a lazy list.
+ *
  * # Safety
- * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
- * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ * The caller must ensure that `window` is a valid pointer.
  */
-struct WuiContainer waterui_force_as_layout_container(struct WuiAnyView *view);
+uintptr_t waterui_lazy_window_len(const struct WuiLazyWindow *window);
 
 /**
- * Returns the type ID as a 128-bit value for O(1) comparison.
- * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ * Gets the ID of the row at the specified index.
+ *
+ * # Safety
+ * The caller must ensure that `window` is a valid pointer and `index` is within bounds.
  */
-struct WuiTypeId waterui_layout_container_id(void);
+struct WuiId waterui_lazy_window_get_id(const struct WuiLazyWindow *window, uintptr_t index);
+
+/**
+ * Builds the views of the rows from `start` to `end`, clamped to the number of rows.
+ *
+ * Only the rows of the latest request are watched for changes.
+ *
+ * # Safety
+ * The caller must ensure that `window` is a valid pointer.
+ */
+struct WuiArray_____WuiAnyView waterui_lazy_window_request(const struct WuiLazyWindow *window,
+                                                           uintptr_t start,
+                                                           uintptr_t end);
+
+/**
+ * Sets the callback told about changes to the requested rows.
+ *
+ * # Safety
+ * The caller must ensure that `window` is a valid pointer and that `callback`
+ * can be called on the main thread until it is dropped.
+ */
+void waterui_lazy_window_on_invalidate(const struct WuiLazyWindow *window,
+                                       struct WuiLazyInvalidate callback);
 
 /**
  * Calculates the size required by the layout given a proposal and child proxies.
@@ -2323,6 +4497,116 @@ struct WuiArray_WuiRect waterui_layout_place(struct WuiLayout *layout,
                                              struct WuiRect bounds,
                                              struct WuiArray_WuiSubView children);
 
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_prefetch(struct WuiPrefetch *value);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_refresh(struct WuiRefresh *value);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_reach_end(struct WuiReachEnd *value);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_scroll_position(struct WuiScrollPosition *value);
+
+/**
+ * Returns how many items past the visible window should be prefetched.
+ *
+ * # Safety
+ * The caller must ensure that `prefetch` is a valid pointer.
+ */
+uintptr_t waterui_prefetch_distance(const struct WuiPrefetch *prefetch);
+
+/**
+ * Reports that the items in `start..end` are about to scroll into view.
+ *
+ * # Safety
+ * The caller must ensure that `prefetch` and `env` are valid pointers.
+ */
+void waterui_prefetch_call(const struct WuiPrefetch *prefetch,
+                           const struct WuiEnv *env,
+                           uintptr_t start,
+                           uintptr_t end);
+
+/**
+ * Starts a refresh after the user pulled the refresh control.
+ *
+ * The action runs on the local executor. Does nothing if a refresh is
+ * already in progress.
+ *
+ * # Safety
+ * The caller must ensure that `refresh` is a valid pointer.
+ */
+void waterui_refresh_begin(const struct WuiRefresh *refresh);
+
+/**
+ * Returns whether a refresh is in progress.
+ *
+ * The refresh control should spin while the value is `true`.
+ *
+ * # Safety
+ * The caller must ensure that `refresh` is a valid pointer.
+ */
+WuiComputed_bool *waterui_refresh_is_refreshing(const struct WuiRefresh *refresh);
+
+/**
+ * Returns the distance from the end, in points, at which the reach-end callback fires.
+ *
+ * # Safety
+ * The caller must ensure that `reach_end` is a valid pointer.
+ */
+float waterui_reach_end_threshold(const struct WuiReachEnd *reach_end);
+
+/**
+ * Reports the scroll position along the scroll axis, in points.
+ *
+ * Call it after each scroll update and whenever the content size changes;
+ * the callback fires when the end of the content is near.
+ *
+ * # Safety
+ * The caller must ensure that `reach_end` and `env` are valid pointers.
+ */
+void waterui_reach_end_report(const struct WuiReachEnd *reach_end,
+                              const struct WuiEnv *env,
+                              float offset,
+                              float content_length,
+                              float viewport_length);
+
+/**
+ * Sets the callback that performs scroll requests.
+ *
+ * A request made before the scroll view appeared is delivered immediately.
+ *
+ * # Safety
+ * The caller must ensure that `position` is a valid pointer and that
+ * `handler` can be called on the main thread until it is dropped.
+ */
+void waterui_scroll_position_on_request(const struct WuiScrollPosition *position,
+                                        struct WuiScrollRequestHandler handler);
+
+/**
+ * Reports the current content offset of the scroll view.
+ *
+ * Call it after every scroll update, including ones caused by requests.
+ *
+ * # Safety
+ * The caller must ensure that `position` is a valid pointer.
+ */
+void waterui_scroll_position_report(const struct WuiScrollPosition *position,
+                                    struct WuiPoint offset);
+
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
@@ -2395,6 +4679,32 @@ struct WuiWatcher_StyledStr *waterui_new_watcher_styled_str(void *data,
                                                                          struct WuiWatcherMetadata*),
                                                             void (*drop)(void*));
 
+/**
+ * Creates a watcher receiving text updates from native callbacks.
+ *
+ * # Safety
+ * All function pointers must be valid.
+ */
+struct WuiWatcher_TextUpdate *waterui_new_watcher_text_update(void *data,
+                                                              void (*call)(void*,
+                                                                           struct WuiTextUpdate,
+                                                                           struct WuiWatcherMetadata*),
+                                                              void (*drop)(void*));
+
+/**
+ * Watches the content of an incremental text view.
+ *
+ * Read the content once with `waterui_read_computed_styled_str`; the watcher
+ * then receives each change to its plain text as an update relative to the
+ * previous one, so appending a line to a log only sends that line.
+ *
+ * # Safety
+ * The caller must ensure that `computed` and `watcher` are valid pointers.
+ * The watcher is consumed.
+ */
+struct WuiWatcherGuard *waterui_watch_computed_styled_str_updates(const WuiComputed_StyledStr *computed,
+                                                                  struct WuiWatcher_TextUpdate *watcher);
+
 /**
  * Reads the current value from a binding
  * # Safety
@@ -2597,6 +4907,19 @@ struct WuiColorPicker waterui_force_as_color_picker(struct WuiAnyView *view);
  */
 struct WuiTypeId waterui_color_picker_id(void);
 
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiEmojiPicker waterui_force_as_emoji_picker(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_emoji_picker_id(void);
+
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
@@ -2649,6 +4972,28 @@ struct WuiNavigationStack waterui_force_as_navigation_stack(struct WuiAnyView *v
  */
 struct WuiTypeId waterui_navigation_stack_id(void);
 
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_navigation_guards(struct WuiNavigationGuards *value);
+
+/**
+ * Asks the pop guards whether the top view may be popped.
+ *
+ * Backends call this before a back navigation started by the user, such as
+ * a back button, swipe gesture or the browser's back button. `callback` is
+ * called exactly once, possibly after this function returns, for example
+ * when a guard waits for the user to confirm.
+ *
+ * # Safety
+ *
+ * `guards` must be a valid pointer to a `WuiNavigationGuards`, and
+ * `callback` must be a valid callback.
+ */
+void waterui_navigation_guards_check_pop(const struct WuiNavigationGuards *guards,
+                                         struct WuiFnOnce_WuiGuardDecision callback);
+
 /**
  * # Safety
  * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
@@ -2693,6 +5038,19 @@ struct WuiPhoto waterui_force_as_photo(struct WuiAnyView *view);
  */
 struct WuiTypeId waterui_photo_id(void);
 
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiAsyncImage waterui_force_as_async_image(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_async_image_id(void);
+
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
@@ -2719,6 +5077,19 @@ struct WuiVideoPlayer waterui_force_as_video_player(struct WuiAnyView *view);
  */
 struct WuiTypeId waterui_video_player_id(void);
 
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiAudioPlayer waterui_force_as_audio_player(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_audio_player_id(void);
+
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
@@ -2732,6 +5103,19 @@ struct WuiLivePhoto waterui_force_as_live_photo(struct WuiAnyView *view);
  */
 struct WuiTypeId waterui_live_photo_id(void);
 
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiAnimatedVector waterui_force_as_animated_vector(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_animated_vector_id(void);
+
 /**
  * Reads the current value from a computed
  * # Safety
@@ -2777,6 +5161,8 @@ struct WuiWatcher_Video *waterui_new_watcher_video(void *data,
  *
  * Native backends call this during initialization to register their media picker
  * implementation. This unified manager handles both presenting the picker and loading media.
+ * Multi-selection pickers fall back to single selection; use
+ * [`waterui_env_install_media_picker_multiple`] to support them natively.
  *
  * # Safety
  *
@@ -2789,6 +5175,64 @@ void waterui_env_install_media_picker_manager(struct WuiEnv *env,
                                               MediaPickerPresentFn present_fn,
                                               MediaLoadFn load_fn);
 
+/**
+ * Installs a MediaPickerManager that also presents multi-selection pickers.
+ *
+ * Use this instead of [`waterui_env_install_media_picker_manager`] on platforms
+ * whose picker can select several items at once.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `present_fn` is a valid function pointer to the native media picker presentation
+ * - `load_fn` is a valid function pointer to the native media loader implementation
+ * - `present_multiple_fn` is a valid function pointer to the native
+ *   multi-selection picker presentation
+ */
+void waterui_env_install_media_picker_multiple(struct WuiEnv *env,
+                                               MediaPickerPresentFn present_fn,
+                                               MediaLoadFn load_fn,
+                                               MediaPickerPresentMultipleFn present_multiple_fn);
+
+/**
+ * Installs a PhotoLibrary into the environment from native function pointers.
+ *
+ * Native backends call this during initialization so apps can query albums and
+ * assets to build custom gallery UIs.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - all function pointers are valid native photo library implementations
+ * - the change callback passed to `observe_fn` is only called on the main thread
+ */
+void waterui_env_install_photo_library(struct WuiEnv *env,
+                                       PhotoLibraryPermissionFn permission_fn,
+                                       PhotoLibraryRequestPermissionFn request_permission_fn,
+                                       PhotoLibraryAlbumsFn albums_fn,
+                                       PhotoLibraryAssetsFn assets_fn,
+                                       PhotoLibraryLoadFn load_fn,
+                                       PhotoLibraryObserveFn observe_fn);
+
+/**
+ * Installs a PhotoCache into the environment from native function pointers.
+ *
+ * Native backends call this during initialization so that prefetch hints from
+ * scrolling lists can warm the image cache used by `Photo`.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `prefetch_fn` is a valid function pointer to the native image prefetcher
+ * - `cancel_fn` is either null or a valid function pointer cancelling a prefetch
+ */
+void waterui_env_install_photo_cache(struct WuiEnv *env,
+                                     PhotoCacheUrlFn prefetch_fn,
+                                     PhotoCacheUrlFn cancel_fn);
+
 /**
  * # Safety
  * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
@@ -2823,6 +5267,30 @@ struct WuiListItem waterui_force_as_list_item(struct WuiAnyView *view);
  */
 struct WuiTypeId waterui_list_item_id(void);
 
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_row_recycler(struct WuiRowRecycler *value);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_row_slot(struct WuiRowSlot *value);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_row_delete(struct WuiRowDelete *value);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_list_selection(struct WuiListSelection *value);
+
 /**
  * # Safety
  * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
@@ -2846,6 +5314,88 @@ void waterui_list_item_call_delete(struct WuiListItem *item,
                                    const struct WuiEnv *env,
                                    uintptr_t index);
 
+/**
+ * Builds a reusable row for the item at `index`.
+ *
+ * All fields of the result are null if `index` is out of bounds. The backend
+ * owns the returned slot and delete callback and must release them with
+ * `waterui_drop_row_slot` and `waterui_drop_row_delete`.
+ *
+ * # Safety
+ * The caller must ensure that `recycler` is a valid pointer.
+ */
+struct WuiRecycledRow waterui_row_recycler_make_row(const struct WuiRowRecycler *recycler,
+                                                    uintptr_t index);
+
+/**
+ * Shows the item at `index` in a recycled row, updating its views in place.
+ *
+ * Returns `false` and leaves the row unchanged if `index` is out of bounds.
+ *
+ * # Safety
+ * The caller must ensure that `slot` is a valid pointer.
+ */
+bool waterui_row_slot_rebind(const struct WuiRowSlot *slot, uintptr_t index);
+
+/**
+ * Deletes the item at `index` from a recycled row.
+ *
+ * Pass the index of the item the row currently shows.
+ *
+ * # Safety
+ * The caller must ensure that `on_delete` and `env` are valid pointers.
+ */
+void waterui_row_delete_call(const struct WuiRowDelete *on_delete,
+                             const struct WuiEnv *env,
+                             uintptr_t index);
+
+/**
+ * Returns how many rows of the list can be selected at once.
+ *
+ * # Safety
+ * The caller must ensure that `selection` is a valid pointer.
+ */
+enum WuiSelectionMode waterui_list_selection_mode(const struct WuiListSelection *selection);
+
+/**
+ * Returns `true` if the row at `index` is selected.
+ *
+ * # Safety
+ * The caller must ensure that `selection` is a valid pointer.
+ */
+bool waterui_list_selection_is_selected(const struct WuiListSelection *selection, uintptr_t index);
+
+/**
+ * Selects or deselects the row at `index`, updating the selection binding.
+ *
+ * In single selection mode, selecting a row replaces the selected row.
+ *
+ * # Safety
+ * The caller must ensure that `selection` is a valid pointer.
+ */
+void waterui_list_selection_set_selected(const struct WuiListSelection *selection,
+                                         uintptr_t index,
+                                         bool selected);
+
+/**
+ * Toggles the selection of the row at `index`.
+ *
+ * # Safety
+ * The caller must ensure that `selection` is a valid pointer.
+ */
+void waterui_list_selection_toggle(const struct WuiListSelection *selection, uintptr_t index);
+
+/**
+ * Calls `watcher` every time the selection changes, including changes made
+ * from Rust, so the backend can refresh the highlight of visible rows.
+ *
+ * # Safety
+ * The caller must ensure that `selection` is a valid pointer and that
+ * `watcher` can be called until the returned guard is dropped.
+ */
+struct WuiWatcherGuard *waterui_list_selection_watch(const struct WuiListSelection *selection,
+                                                     struct WuiListSelectionWatcher watcher);
+
 /**
  * Reads the current value from a computed
  * # Safety
@@ -2923,7 +5473,16 @@ struct WuiProgress waterui_force_as_progress(struct WuiAnyView *view);
  * Returns the type ID as a 128-bit value for O(1) comparison.
  * Uses TypeId in normal builds, type_name hash in hot reload builds.
  */
-struct WuiTypeId waterui_progress_id(void);
+struct WuiTypeId waterui_progress_id(void);
+
+/**
+ * Measures a GpuSurface during the layout pass.
+ *
+ * Returns the size of a surface with `sizing` for `proposal`, so every
+ * backend sizes surfaces the same way.
+ */
+struct WuiSize waterui_surface_sizing_size_that_fits(struct WuiSurfaceSizing sizing,
+                                                     struct WuiProposalSize proposal);
 
 /**
  * # Safety
@@ -2990,6 +5549,29 @@ struct WuiGpuSurfaceState *waterui_gpu_surface_init(struct WuiGpuSurface *surfac
  */
 bool waterui_gpu_surface_render(struct WuiGpuSurfaceState *state, uint32_t width, uint32_t height);
 
+/**
+ * Capture the current frame of a GpuSurface offscreen.
+ *
+ * Renders a frame at the current surface size into an offscreen texture and
+ * reads it back. Use this for golden-image tests or screenshot sharing without
+ * platform screenshot APIs.
+ *
+ * # Arguments
+ *
+ * * `state` - Pointer to the initialized state from `waterui_gpu_surface_init`
+ * * `hdr` - Return RGBA16F pixels if the surface renders in HDR; RGBA8 otherwise
+ *
+ * # Returns
+ *
+ * The captured frame, with zero width and height on failure.
+ *
+ * # Safety
+ *
+ * `state` must be a valid pointer from `waterui_gpu_surface_init`, and must not
+ * be used concurrently with `waterui_gpu_surface_render`.
+ */
+struct WuiGpuSnapshot waterui_gpu_surface_snapshot(struct WuiGpuSurfaceState *state, bool hdr);
+
 /**
  * Clean up GPU resources.
  *
@@ -3002,6 +5584,44 @@ bool waterui_gpu_surface_render(struct WuiGpuSurfaceState *state, uint32_t width
  */
 void waterui_gpu_surface_drop(struct WuiGpuSurfaceState *state);
 
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiComposeView waterui_force_as_compose_view(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_compose_view_id(void);
+
+/**
+ * # Safety
+ * This function is unsafe because it dereferences a raw pointer and performs unchecked downcasting.
+ * The caller must ensure that `view` is a valid pointer to an `AnyView` that contains the expected view type.
+ */
+struct WuiSwiftUIView waterui_force_as_swiftui_view(struct WuiAnyView *view);
+
+/**
+ * Returns the type ID as a 128-bit value for O(1) comparison.
+ * Uses TypeId in normal builds, type_name hash in hot reload builds.
+ */
+struct WuiTypeId waterui_swiftui_view_id(void);
+
+/**
+ * Registers `identifier` as a SwiftUI view the Swift side can build.
+ *
+ * Call this on the main thread for every registered factory before building
+ * the app's views; unregistered identifiers render their fallback.
+ *
+ * # Safety
+ *
+ * `identifier` must be a valid `WuiStr`, which is consumed.
+ */
+void waterui_register_swiftui_view(struct WuiStr identifier);
+
 /**
  * Calls an OnEvent handler with the given environment.
  *
@@ -3022,6 +5642,162 @@ void waterui_call_on_event(struct WuiOnEventHandler *handler, const struct WuiEn
  */
 void waterui_drop_on_event(struct WuiOnEventHandler *handler);
 
+/**
+ * Installs a FeedbackPlayer into the environment from native function pointers.
+ *
+ * Native backends call this during initialization so built-in controls can
+ * play the haptics and sounds configured in the theme.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `haptic_fn` is either null, on platforms without haptics, or a valid
+ *   function pointer playing a haptic pattern
+ * - `sound_fn` is either null or a valid function pointer playing a system sound
+ */
+void waterui_env_install_feedback_player(struct WuiEnv *env,
+                                         FeedbackHapticFn haptic_fn,
+                                         FeedbackSoundFn sound_fn);
+
+/**
+ * Installs a Storage into the environment from native function pointers.
+ *
+ * Native backends call this during initialization so app settings persist
+ * across launches.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `load_fn`, `store_fn` and `remove_fn` are valid function pointers to
+ *   the native preferences store
+ * - `load_fn` only calls its callback before returning
+ */
+void waterui_env_install_storage(struct WuiEnv *env,
+                                 StorageLoadFn load_fn,
+                                 StorageStoreFn store_fn,
+                                 StorageRemoveFn remove_fn);
+
+/**
+ * Installs a Sharer into the environment from a native function pointer.
+ *
+ * Native backends call this during initialization so apps can present the
+ * system share sheet.
+ *
+ * # Safety
+ *
+ * The caller must ensure that:
+ * - `env` is a valid pointer to a `WuiEnv`
+ * - `share_fn` is a valid function pointer presenting the native share sheet
+ *   that calls its completion exactly once, on the main thread
+ */
+void waterui_env_install_sharer(struct WuiEnv *env, ShareFn share_fn);
+
+/**
+ * Reads the current value from a computed
+ * # Safety
+ * The computed pointer must be valid and point to a properly initialized computed object.
+ */
+struct WuiOptionalStr waterui_read_computed_optional_str(const WuiComputed_Option_Str *computed);
+
+/**
+ * Watches for changes in a computed
+ * # Safety
+ * The computed pointer must be valid and point to a properly initialized computed object.
+ */
+struct WuiWatcherGuard *waterui_watch_computed_optional_str(const WuiComputed_Option_Str *computed,
+                                                            struct WuiWatcher_Option_Str *watcher);
+
+/**
+ * Drops a computed
+ * # Safety
+ * The caller must ensure that `computed` is a valid pointer.
+ */
+void waterui_drop_computed_optional_str(WuiComputed_Option_Str *computed);
+
+/**
+ * Clones a computed
+ * # Safety
+ * The caller must ensure that `computed` is a valid pointer.
+ */
+WuiComputed_Option_Str *waterui_clone_computed_optional_str(const WuiComputed_Option_Str *computed);
+
+/**
+ * Creates a watcher from native callbacks.
+ * # Safety
+ * All function pointers must be valid.
+ */
+struct WuiWatcher_Option_Str *waterui_new_watcher_optional_str(void *data,
+                                                               void (*call)(void*,
+                                                                            struct WuiOptionalStr,
+                                                                            struct WuiWatcherMetadata*),
+                                                               void (*drop)(void*));
+
+/**
+ * Undoes the last step of the environment's undo manager.
+ *
+ * Returns `false` if there was nothing to undo.
+ *
+ * # Safety
+ *
+ * `env` must be a valid pointer to a `WuiEnv`.
+ */
+bool waterui_env_undo(const struct WuiEnv *env);
+
+/**
+ * Redoes the last undone step of the environment's undo manager.
+ *
+ * Returns `false` if there was nothing to redo.
+ *
+ * # Safety
+ *
+ * `env` must be a valid pointer to a `WuiEnv`.
+ */
+bool waterui_env_redo(const struct WuiEnv *env);
+
+/**
+ * Returns whether the environment's undo manager has a step to undo.
+ *
+ * # Safety
+ *
+ * `env` must be a valid pointer to a `WuiEnv`.
+ */
+WuiComputed_bool *waterui_env_can_undo(const struct WuiEnv *env);
+
+/**
+ * Returns whether the environment's undo manager has a step to redo.
+ *
+ * # Safety
+ *
+ * `env` must be a valid pointer to a `WuiEnv`.
+ */
+WuiComputed_bool *waterui_env_can_redo(const struct WuiEnv *env);
+
+/**
+ * Returns the name of the step the environment's undo manager would undo.
+ *
+ * The name is absent when there is nothing to undo or the step was not made
+ * in a named transaction.
+ *
+ * # Safety
+ *
+ * `env` must be a valid pointer to a `WuiEnv`.
+ */
+WuiComputed_Option_Str *waterui_env_undo_name(const struct WuiEnv *env);
+
+/**
+ * Returns the name of the step the environment's undo manager would redo.
+ *
+ * The name is absent when there is nothing to redo or the step was not made
+ * in a named transaction.
+ *
+ * # Safety
+ *
+ * `env` must be a valid pointer to a `WuiEnv`.
+ */
+WuiComputed_Option_Str *waterui_env_redo_name(const struct WuiEnv *env);
+
 /**
  * Drops a WuiGesture, recursively freeing any Then variants.
  *
@@ -3031,6 +5807,23 @@ void waterui_drop_on_event(struct WuiOnEventHandler *handler);
  */
 void waterui_drop_gesture(struct WuiGesture *gesture);
 
+/**
+ * Calls a gesture observer's action with the event that triggered it.
+ *
+ * The event is inserted into a copy of `env`, both as a `GestureEvent` and
+ * as its specific payload type, so typed handlers and `Use<DragEvent>`-style
+ * extractors both see it. Backends call this for every change of a
+ * continuous gesture, not just when it ends.
+ *
+ * # Safety
+ *
+ * * `action` must be a valid pointer to a `WuiAction` from a `WuiGestureObserver`.
+ * * `env` must be a valid pointer to a `WuiEnv`.
+ */
+void waterui_call_gesture_action(struct WuiAction *action,
+                                 const struct WuiEnv *env,
+                                 struct WuiGestureEvent event);
+
 /**
  * Reads the current value from a binding
  * # Safety
@@ -3660,6 +6453,18 @@ struct WuiWatcher_Secure *waterui_new_watcher_secure(void *data,
                                                                   struct WuiWatcherMetadata*),
                                                      void (*drop)(void*));
 
+/**
+ * Returns the number of live views, bindings, watchers and FFI handles.
+ */
+struct WuiStats waterui_debug_stats(void);
+
+/**
+ * Logs every type that still has live objects and returns whether there were any.
+ *
+ * Native should call this when the app is about to exit, after releasing its views.
+ */
+bool waterui_debug_report_leaks(void);
+
 /**
  * Reads the current value from a computed
  * # Safety
@@ -3718,11 +6523,58 @@ WuiComputed_ColorScheme *waterui_computed_color_scheme_constant(enum WuiColorSch
 
 /**
  * Installs a color scheme signal into the environment.
+ *
+ * # Safety
+ * The signal pointer must be valid.
  */
 void waterui_theme_install_color_scheme(struct WuiEnv *env, WuiComputed_ColorScheme *signal);
 
+/**
+ * Installs the operating system appearance signal into the environment.
+ *
+ * It drives `WuiColorScheme_System` and is used when the app sets no color
+ * scheme. The signal should only report `Light` or `Dark`.
+ *
+ * # Safety
+ * The signal pointer must be valid.
+ */
+void waterui_theme_install_system_color_scheme(struct WuiEnv *env,
+                                               WuiComputed_ColorScheme *signal);
+
+/**
+ * # Safety
+ * The caller must ensure that `value` is a valid pointer obtained from the corresponding FFI function.
+ */
+void waterui_drop_system_color_scheme(struct WuiSystemColorScheme *value);
+
+/**
+ * Installs the operating system appearance and returns a reporter for it.
+ *
+ * Keep the reporter alive for as long as the environment and set the new
+ * appearance whenever the OS reports a change.
+ *
+ * # Safety
+ * The caller must ensure that `env` is a valid pointer.
+ */
+struct WuiSystemColorScheme *waterui_theme_install_system_color_scheme_reporter(struct WuiEnv *env,
+                                                                                enum WuiColorScheme initial);
+
+/**
+ * Sets the operating system appearance.
+ *
+ * # Safety
+ * The caller must ensure that `reporter` is a valid pointer.
+ */
+void waterui_system_color_scheme_set(const struct WuiSystemColorScheme *reporter,
+                                     enum WuiColorScheme scheme);
+
 /**
  * Returns the current color scheme signal from the environment.
+ *
+ * The signal always reports `Light` or `Dark`.
+ *
+ * # Safety
+ * The returned pointer must be dropped by the caller when no longer needed.
  */
 WuiComputed_ColorScheme *waterui_theme_color_scheme(const struct WuiEnv *env);
 
@@ -3730,15 +6582,35 @@ WuiComputed_ColorScheme *waterui_theme_color_scheme(const struct WuiEnv *env);
  * Installs a color signal for a specific slot.
  *
  * Takes ownership of the signal pointer.
+ *
+ * # Safety
+ * The signal pointer must be valid.
  */
 void waterui_theme_install_color(struct WuiEnv *env,
                                  enum WuiColorSlot slot,
                                  WuiComputed_ResolvedColor *signal);
 
+/**
+ * Installs separate light and dark color signals for a specific slot.
+ *
+ * The slot follows the color scheme of each subtree, including subtrees that
+ * force a scheme. Takes ownership of both signal pointers.
+ *
+ * # Safety
+ * The signal pointers must be valid.
+ */
+void waterui_theme_install_color_variants(struct WuiEnv *env,
+                                          enum WuiColorSlot slot,
+                                          WuiComputed_ResolvedColor *light,
+                                          WuiComputed_ResolvedColor *dark);
+
 /**
  * Returns the color signal for a specific slot.
  *
  * Returns a new reference to the signal. Caller must drop it when done.
+ *
+ * # Safety
+ * The env pointer must be valid.
  */
 WuiComputed_ResolvedColor *waterui_theme_color(const struct WuiEnv *env, enum WuiColorSlot slot);
 
@@ -3746,6 +6618,9 @@ WuiComputed_ResolvedColor *waterui_theme_color(const struct WuiEnv *env, enum Wu
  * Installs a font signal for a specific slot.
  *
  * Takes ownership of the signal pointer.
+ *
+ * # Safety
+ * The env pointer must be valid.
  */
 void waterui_theme_install_font(struct WuiEnv *env,
                                 enum WuiFontSlot slot,
@@ -3755,6 +6630,9 @@ void waterui_theme_install_font(struct WuiEnv *env,
  * Returns the font signal for a specific slot.
  *
  * Returns a new reference to the signal. Caller must drop it when done.
+ *
+ * # Safety
+ * The env pointer must be valid.
  */
 WuiComputed_ResolvedFont *waterui_theme_font(const struct WuiEnv *env, enum WuiFontSlot slot);
 
@@ -3765,6 +6643,11 @@ WuiComputed_ResolvedFont *waterui_theme_font(const struct WuiEnv *env, enum WuiF
  * - `waterui_theme_install_color_scheme()`
  * - `waterui_theme_install_color()`
  * - `waterui_theme_install_font()`
+ *
+ * # Safety
+ * - `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ * - Each `WuiComputed<...>` pointer may be null; non-null pointers must be valid and were
+ *   allocated by WaterUI FFI constructors and are transferred to Rust (consumed).
  */
 void waterui_env_install_theme(struct WuiEnv *env,
                                WuiComputed_ResolvedColor *background,
@@ -3781,32 +6664,116 @@ void waterui_env_install_theme(struct WuiEnv *env,
                                WuiComputed_ResolvedFont *subheadline,
                                WuiComputed_ResolvedFont *caption);
 
+/**
+ * Returns the theme color signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedColor *waterui_theme_color_background(const struct WuiEnv *env);
 
+/**
+ * Returns the theme color signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedColor *waterui_theme_color_surface(const struct WuiEnv *env);
 
+/**
+ * Returns the theme color signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedColor *waterui_theme_color_surface_variant(const struct WuiEnv *env);
 
+/**
+ * Returns the theme color signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedColor *waterui_theme_color_border(const struct WuiEnv *env);
 
+/**
+ * Returns the theme color signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedColor *waterui_theme_color_foreground(const struct WuiEnv *env);
 
+/**
+ * Returns the theme color signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedColor *waterui_theme_color_muted_foreground(const struct WuiEnv *env);
 
+/**
+ * Returns the theme color signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedColor *waterui_theme_color_accent(const struct WuiEnv *env);
 
+/**
+ * Returns the theme color signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedColor *waterui_theme_color_accent_foreground(const struct WuiEnv *env);
 
+/**
+ * Returns the theme font signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedFont *waterui_theme_font_body(const struct WuiEnv *env);
 
+/**
+ * Returns the theme font signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedFont *waterui_theme_font_title(const struct WuiEnv *env);
 
+/**
+ * Returns the theme font signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedFont *waterui_theme_font_headline(const struct WuiEnv *env);
 
+/**
+ * Returns the theme font signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedFont *waterui_theme_font_subheadline(const struct WuiEnv *env);
 
+/**
+ * Returns the theme font signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedFont *waterui_theme_font_caption(const struct WuiEnv *env);
 
+/**
+ * Returns the theme font signal for a specific token.
+ *
+ * # Safety
+ * `env` must be a valid pointer returned by `waterui_init()`/`waterui_env_new()`.
+ */
 WuiComputed_ResolvedFont *waterui_theme_font_footnote(const struct WuiEnv *env);
 
 /**
@@ -0,0 +1,89 @@
+//! Implementation of the `asset!` macro.
+//!
+//! The file name hashing here must stay in sync with `waterui_cli::assets`,
+//! which copies the files into each platform bundle.
+
+use std::path::{Component, Path, PathBuf};
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::LitStr;
+
+/// Directory, relative to the crate root, that holds bundled assets.
+const ASSETS_DIR: &str = "assets";
+
+/// FNV-1a 64-bit hash of the file contents, as 16 lowercase hex digits.
+fn content_hash(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+/// Inserts `hash` before the extension: `images/logo.png` -> `images/logo.<hash>.png`.
+fn hashed_path(path: &str, hash: &str) -> String {
+    let (dir, file) = path
+        .rsplit_once('/')
+        .map_or(("", path), |(dir, file)| (&path[..=dir.len()], file));
+    match file.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => format!("{dir}{stem}.{hash}.{ext}"),
+        _ => format!("{dir}{file}.{hash}"),
+    }
+}
+
+/// Checks that `path` is a plain relative path inside the assets directory.
+fn is_valid_asset_path(path: &str) -> bool {
+    !path.is_empty()
+        && !path.contains('\\')
+        && Path::new(path)
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+}
+
+pub fn expand(path: &LitStr) -> syn::Result<TokenStream> {
+    let relative = path.value();
+    if !is_valid_asset_path(&relative) {
+        return Err(syn::Error::new_spanned(
+            path,
+            "asset path must be relative to the `assets` directory and must not contain `..`",
+        ));
+    }
+
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| syn::Error::new_spanned(path, "CARGO_MANIFEST_DIR is not set"))?;
+    let file: PathBuf = [manifest_dir.as_str(), ASSETS_DIR, relative.as_str()]
+        .iter()
+        .collect();
+
+    let bytes = std::fs::read(&file).map_err(|error| {
+        syn::Error::new_spanned(
+            path,
+            format!("could not read asset `{}`: {error}", file.display()),
+        )
+    })?;
+    let file = file
+        .to_str()
+        .ok_or_else(|| syn::Error::new_spanned(path, "asset path must be valid UTF-8"))?;
+
+    let web = format!(
+        "/{ASSETS_DIR}/{}",
+        hashed_path(&relative, &content_hash(&bytes))
+    );
+    let android = format!("file:///android_asset/{relative}");
+    let bundle = format!("{ASSETS_DIR}/{relative}");
+
+    Ok(quote! {
+        {
+            // Rebuild when the asset changes; the bytes are not embedded in the binary.
+            const _: &[u8] = include_bytes!(#file);
+            ::waterui::media::Url::new(if cfg!(target_arch = "wasm32") {
+                #web
+            } else if cfg!(target_os = "android") {
+                #android
+            } else {
+                #bundle
+            })
+        }
+    }
+    .into())
+}
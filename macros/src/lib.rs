@@ -1,12 +1,14 @@
 //! Procedural macros for `WaterUI` framework.
 //!
 //! This crate provides derive macros and procedural macros for the `WaterUI` framework,
-//! including form generation, reactive signal formatting, and view builder patterns.
+//! including form generation, reactive signal formatting, view builder patterns, and asset bundling.
 
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Fields, ItemFn, Meta, parse_macro_input};
 
+mod asset;
+
 /// Derives the `FormBuilder` trait for structs, enabling automatic form generation.
 ///
 /// This macro generates a complete `FormBuilder` implementation that creates a vertical
@@ -54,8 +56,21 @@ pub fn derive_form_builder(input: TokenStream) -> TokenStream {
         }
     };
 
+    let validators = vec![None; fields.len()];
+    expand_form_builder(name, fields, &validators)
+}
+
+/// Generates the `FormBuilder` implementation for a struct with named fields.
+///
+/// Fields with a validator are wrapped in a `ValidatedField` that shows the
+/// validation error under the control.
+fn expand_form_builder(
+    name: &syn::Ident,
+    fields: &Punctuated<syn::Field, Token![,]>,
+    validators: &[Option<Expr>],
+) -> TokenStream {
     // Collect field information
-    let field_views = fields.iter().map(|field| {
+    let field_views = fields.iter().zip(validators).map(|(field, validator)| {
         let field_name = field
             .ident
             .as_ref()
@@ -90,12 +105,21 @@ pub fn derive_form_builder(input: TokenStream) -> TokenStream {
 
         // Use FormBuilder trait for all types
         // The FormBuilder::view method will handle whether to use the placeholder or not
-        quote! {
+        let view = quote! {
             <#field_type as crate::FormBuilder>::view(
                 &projected.#field_name,
                 ::waterui::AnyView::new(#label_text),
                 ::waterui::Str::from(#placeholder)
             )
+        };
+        match validator {
+            Some(validator) => quote! {
+                ::waterui::form::valid::ValidatedField::new(
+                    #view,
+                    ::waterui::form::valid::field_error(&projected.#field_name, #validator)
+                )
+            },
+            None => view,
         }
     });
 
@@ -119,12 +143,20 @@ pub fn derive_form_builder(input: TokenStream) -> TokenStream {
         }
     };
 
-    let field_types = fields.iter().map(|field| &field.ty);
+    let field_view_types = fields.iter().zip(validators).map(|(field, validator)| {
+        let field_type = &field.ty;
+        let view = quote!(<#field_type as crate::FormBuilder>::View);
+        if validator.is_some() {
+            quote!(::waterui::form::valid::ValidatedField<#view>)
+        } else {
+            view
+        }
+    });
 
     // Generate the implementation
     let expanded = quote! {
         impl crate::FormBuilder for #name {
-            type View = ::waterui::component::stack::VStack<((#(<#field_types as crate::FormBuilder>::View),*),)>;
+            type View = ::waterui::component::stack::VStack<((#(#field_view_types),*),)>;
 
             fn view(binding: &::waterui::Binding<Self>, _label: ::waterui::AnyView, _placeholder: ::waterui::Str) -> Self::View {
                 #view_body
@@ -135,6 +167,123 @@ pub fn derive_form_builder(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Generates the errors struct and `FormValidation` implementation for a `#[form]` struct.
+fn expand_form_validation(
+    input: &DeriveInput,
+    fields: &Punctuated<syn::Field, Token![,]>,
+    validators: &[Option<Expr>],
+) -> TokenStream {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let errors_name = syn::Ident::new(&format!("{name}Errors"), name.span());
+    let errors_doc = format!("Validation error signals for the fields of [`{name}`].");
+    let field_names = fields
+        .iter()
+        .map(|field| {
+            field
+                .ident
+                .as_ref()
+                .expect("field should have an identifier")
+        })
+        .collect::<Vec<_>>();
+
+    let errors = field_names.iter().zip(validators).map(|(field_name, validator)| {
+        validator.as_ref().map_or_else(
+            || quote!(#field_name: ::waterui::reactive::Computed::constant(None)),
+            |validator| {
+                quote! {
+                    #field_name: ::waterui::form::valid::field_error(&projected.#field_name, #validator)
+                }
+            },
+        )
+    });
+
+    let checks = field_names
+        .iter()
+        .zip(validators)
+        .filter_map(|(field_name, validator)| {
+            validator
+                .as_ref()
+                .map(|validator| quote!((#validator)(&value.#field_name).is_ok()))
+        })
+        .collect::<Vec<_>>();
+    let is_valid = if let Some((first, rest)) = checks.split_first() {
+        quote! {
+            ::waterui::reactive::SignalExt::computed(::waterui::reactive::SignalExt::map(
+                binding.clone(),
+                |value: Self| #first #(&& #rest)*,
+            ))
+        }
+    } else {
+        quote!(::waterui::reactive::Computed::constant(true))
+    };
+
+    let projected = if validators.iter().any(Option::is_some) {
+        quote!(let projected = <Self as ::waterui::reactive::project::Project>::project(binding);)
+    } else {
+        quote!()
+    };
+
+    let expanded = quote! {
+        #[doc = #errors_doc]
+        #[derive(Debug, Clone)]
+        #vis struct #errors_name {
+            #(
+                #[allow(missing_docs)]
+                pub #field_names: ::waterui::reactive::Computed<Option<::waterui::Str>>,
+            )*
+        }
+
+        impl ::waterui::form::valid::FormValidation for #name {
+            type Errors = #errors_name;
+
+            fn errors(binding: &::waterui::Binding<Self>) -> Self::Errors {
+                #projected
+                #errors_name {
+                    #(#errors,)*
+                }
+            }
+
+            fn is_valid(binding: &::waterui::Binding<Self>) -> ::waterui::reactive::Computed<bool> {
+                #is_valid
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Removes `#[form(validate = ...)]` attributes from the fields, returning each field's validator.
+fn take_field_validators(
+    fields: &mut Punctuated<syn::Field, Token![,]>,
+) -> syn::Result<Vec<Option<Expr>>> {
+    fields
+        .iter_mut()
+        .map(|field| {
+            let mut validator = None;
+            let mut result = Ok(());
+            field.attrs.retain(|attr| {
+                if !attr.path().is_ident("form") {
+                    return true;
+                }
+                let parsed = attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("validate") {
+                        validator = Some(meta.value()?.parse::<Expr>()?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("unsupported form attribute, expected `validate`"))
+                    }
+                });
+                if let Err(error) = parsed {
+                    result = Err(error);
+                }
+                false
+            });
+            result.map(|()| validator)
+        })
+        .collect()
+}
+
 /// Converts `snake_case` to "Title Case"
 fn snake_to_title_case(s: &str) -> String {
     s.split('_')
@@ -158,8 +307,9 @@ fn snake_to_title_case(s: &str) -> String {
 /// - `Clone`
 /// - `Debug`
 /// - `FormBuilder`
+/// - `FormValidation` (from `waterui::form::valid`)
 /// - `Project` (from `waterui::reactive` for reactive state management)
-/// - `Serialize` and `Deserialize` (from serde, if available)
+/// - `Serialize` and `Deserialize` (from serde, with `persist`)
 ///
 /// # Example
 ///
@@ -197,16 +347,68 @@ fn snake_to_title_case(s: &str) -> String {
 ///     // ... implementation provided by waterui::reactive derive
 /// }
 /// ```
+///
+/// # Validation
+///
+/// Mark a field with `#[form(validate = path::to::fn)]` to validate it. The
+/// function takes `&FieldType` and returns `Result<(), E>` where `E: Display`;
+/// the error message is shown under the field's control.
+///
+/// ```text
+/// fn valid_email(email: &String) -> Result<(), &'static str> {
+///     if email.contains('@') { Ok(()) } else { Err("Enter a valid email address.") }
+/// }
+///
+/// #[form]
+/// pub struct Signup {
+///     #[form(validate = valid_email)]
+///     pub email: String,
+/// }
+///
+/// let data = Signup::binding();
+/// let email_error = Signup::errors(&data).email; // Computed<Option<Str>>
+/// button("Sign Up").disabled(Signup::is_valid(&data).map(|valid| !valid));
+/// ```
+///
+/// The macro also generates a `{Name}Errors` struct holding one error signal
+/// per field, returned by `FormValidation::errors`.
+///
+/// # Persistence
+///
+/// `#[form(persist = "key")]` also derives `Serialize` and `Deserialize` and
+/// generates a `SETTING` key and a `stored` constructor, which loads the form
+/// from platform storage and saves every change to it:
+///
+/// ```text
+/// #[form(persist = "app_settings")]
+/// pub struct AppSettings {
+///     pub dark_mode: bool,
+/// }
+///
+/// let settings = AppSettings::stored(&env); // waterui::settings::Stored<AppSettings>
+/// form(&settings)
+/// ```
+///
+/// Fields missing from the stored value, such as ones added in a later
+/// version, take their default values.
 #[proc_macro_attribute]
-pub fn form(_args: TokenStream, input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
-    let _name = &input.ident;
-    let (_impl_generics, _ty_generics, _where_clause) = input.generics.split_for_impl();
+pub fn form(args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut persist = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("persist") {
+            persist = Some(meta.value()?.parse::<LitStr>()?);
+            Ok(())
+        } else {
+            Err(meta.error("unsupported form attribute, expected `persist`"))
+        }
+    });
+    parse_macro_input!(args with parser);
+    let mut input = parse_macro_input!(input as DeriveInput);
 
     // Check if it's a struct with named fields
-    let _fields = match &input.data {
-        Data::Struct(data_struct) => match &data_struct.fields {
-            Fields::Named(fields) => fields,
+    let fields = match &mut input.data {
+        Data::Struct(data_struct) => match &mut data_struct.fields {
+            Fields::Named(fields) => &mut fields.named,
             _ => {
                 return syn::Error::new_spanned(
                     input,
@@ -226,21 +428,77 @@ pub fn form(_args: TokenStream, input: TokenStream) -> TokenStream {
         }
     };
 
-    let expanded = quote! {
-        #[derive(Default, Clone, Debug, ::waterui::FormBuilder, ::waterui::Project)]
-        #input
+    let validators = match take_field_validators(fields) {
+        Ok(validators) => validators,
+        Err(error) => return error.to_compile_error().into(),
     };
+    let fields = fields.clone();
 
-    TokenStream::from(expanded)
+    let mut expanded = TokenStream::from(if persist.is_some() {
+        quote! {
+            #[derive(
+                Default,
+                Clone,
+                Debug,
+                ::waterui::Project,
+                ::waterui::__serde::Serialize,
+                ::waterui::__serde::Deserialize,
+            )]
+            #[serde(crate = "::waterui::__serde", default)]
+            #input
+        }
+    } else {
+        quote! {
+            #[derive(Default, Clone, Debug, ::waterui::Project)]
+            #input
+        }
+    });
+    expanded.extend(expand_form_builder(&input.ident, &fields, &validators));
+    expanded.extend(expand_form_validation(&input, &fields, &validators));
+    if let Some(name) = persist {
+        expanded.extend(expand_form_persistence(&input, &name));
+    }
+    expanded
+}
+
+/// Generates the setting key and `stored` constructor of a persisted form.
+fn expand_form_persistence(input: &DeriveInput, name: &LitStr) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// The setting this form is persisted under.
+            pub const SETTING: ::waterui::settings::SettingKey<Self> =
+                ::waterui::settings::SettingKey::new(#name, <Self as ::core::default::Default>::default);
+
+            /// Returns the values of this form, loaded from storage the first
+            /// time and saved on every change.
+            #[must_use]
+            pub fn stored(env: &::waterui::Environment) -> ::waterui::settings::Stored<Self> {
+                ::waterui::settings::Stored::from_key(env, &Self::SETTING)
+            }
+        }
+    }
+    .into()
 }
 
 use syn::{Expr, LitStr, Token, Type, parse::Parse, punctuated::Punctuated};
 
-/// Derive macro for implementing the `Project` trait on structs.
+/// Derive macro for implementing the `Project` trait on structs and enums.
 ///
 /// This macro automatically generates a `Project` implementation that allows
 /// decomposing a struct binding into separate bindings for each field.
 ///
+/// For an enum `State`, the projection is a `StateProjected` struct with:
+/// - `kind`: a `Binding<StateKind>` of the active variant, where `StateKind` is a
+///   generated field-less copy of the enum. Setting it to a variant without fields
+///   switches the enum to that variant; setting it to a variant with fields does
+///   nothing, since there are no values to build it from
+/// - one `Binding<Option<Payload>>` per variant with fields, named after the variant
+///   in `snake_case`. It is `Some` while that variant is active. Setting it to
+///   `Some` switches the enum to that variant; setting it to `None` does nothing.
+///   Variants with several fields use a tuple of the fields as payload.
+///
 /// # Examples
 ///
 /// ```rust,ignore
@@ -266,6 +524,26 @@ use syn::{Expr, LitStr, Token, Type, parse::Parse, punctuated::Punctuated};
 /// assert_eq!(person.name, "Bob");
 /// assert_eq!(person.age, 25);
 /// ```
+///
+/// Enums pair well with `match_signal`, which only rebuilds the view when the
+/// variant changes while payload bindings update it in place:
+///
+/// ```rust,ignore
+/// #[derive(Project, Clone)]
+/// enum Upload {
+///     Idle,
+///     Sending { sent: u64, total: u64 },
+///     Failed(String),
+/// }
+///
+/// let upload = binding(Upload::Idle);
+/// let projected = upload.project();
+/// let failed = projected.failed.clone();
+/// match_signal(projected.kind, move |kind| match kind {
+///     UploadKind::Failed => text(failed.clone().map(Option::unwrap_or_default)).anyview(),
+///     _ => AnyView::default(),
+/// });
+/// ```
 #[proc_macro_derive(Project)]
 pub fn derive_project(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -276,11 +554,7 @@ pub fn derive_project(input: TokenStream) -> TokenStream {
             Fields::Unnamed(fields_unnamed) => derive_project_tuple_struct(&input, fields_unnamed),
             Fields::Unit => derive_project_unit_struct(&input),
         },
-        Data::Enum(_) => {
-            syn::Error::new_spanned(input, "Project derive macro does not support enums")
-                .to_compile_error()
-                .into()
-        }
+        Data::Enum(data_enum) => derive_project_enum(&input, data_enum),
         Data::Union(_) => {
             syn::Error::new_spanned(input, "Project derive macro does not support unions")
                 .to_compile_error()
@@ -410,6 +684,176 @@ fn derive_project_tuple_struct(input: &DeriveInput, fields: &syn::FieldsUnnamed)
     TokenStream::from(expanded)
 }
 
+#[allow(clippy::too_many_lines)]
+fn derive_project_enum(input: &DeriveInput, data: &syn::DataEnum) -> TokenStream {
+    let enum_name = &input.ident;
+    let vis = &input.vis;
+    let (_impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let kind_name = syn::Ident::new(&format!("{enum_name}Kind"), enum_name.span());
+    let projected_name = syn::Ident::new(&format!("{enum_name}Projected"), enum_name.span());
+    let kind_doc = format!("The active variant of [`{enum_name}`], without its fields.");
+    let projected_doc =
+        format!("Projected version of [`{enum_name}`] with a binding for each variant's fields.");
+
+    let variant_names = data.variants.iter().map(|variant| &variant.ident);
+    let kind_arms = data.variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        quote!(Self::#variant_name { .. } => #kind_name::#variant_name)
+    });
+    // Only variants without fields can be switched to through `kind`
+    let unit_arms = data
+        .variants
+        .iter()
+        .filter(|variant| variant.fields.is_empty())
+        .map(|variant| {
+            let variant_name = &variant.ident;
+            let value = match &variant.fields {
+                Fields::Named(_) => quote!(Self::#variant_name {}),
+                Fields::Unnamed(_) => quote!(Self::#variant_name()),
+                Fields::Unit => quote!(Self::#variant_name),
+            };
+            quote!(#kind_name::#variant_name => *binding.get_mut() = #value)
+        });
+
+    let mut projected_fields = Vec::new();
+    let mut field_projections = Vec::new();
+    for variant in data
+        .variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+    {
+        let variant_name = &variant.ident;
+        let field_name = variant_field_name(variant_name);
+        let field_doc = format!("Fields of [`{enum_name}::{variant_name}`] while it is active.");
+        let types = variant
+            .fields
+            .iter()
+            .map(|field| &field.ty)
+            .collect::<Vec<_>>();
+        let bindings = (0..types.len())
+            .map(|index| syn::Ident::new(&format!("field_{index}"), variant_name.span()))
+            .collect::<Vec<_>>();
+
+        let (payload_type, payload, cloned) = if let [ty] = types.as_slice() {
+            (
+                quote!(#ty),
+                quote!(#(#bindings)*),
+                quote!(#(#bindings.clone())*),
+            )
+        } else {
+            (
+                quote!((#(#types,)*)),
+                quote!((#(#bindings,)*)),
+                quote!((#(#bindings.clone(),)*)),
+            )
+        };
+        let pattern = if let Fields::Named(fields) = &variant.fields {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!(Self::#variant_name { #(#names: #bindings),* })
+        } else {
+            quote!(Self::#variant_name(#(#bindings),*))
+        };
+
+        projected_fields.push(quote! {
+            #[doc = #field_doc]
+            pub #field_name: ::waterui::reactive::Binding<Option<#payload_type>>
+        });
+        field_projections.push(quote! {
+            #field_name: ::waterui::reactive::Binding::mapping(
+                source,
+                |value| match value {
+                    #pattern => Some(#cloned),
+                    #[allow(unreachable_patterns)]
+                    _ => None,
+                },
+                move |binding, value| {
+                    if let Some(#payload) = value {
+                        *binding.get_mut() = #pattern;
+                    }
+                },
+            )
+        });
+    }
+
+    // Add lifetime bounds to generic parameters
+    let mut generics_with_static = input.generics.clone();
+    for param in &mut generics_with_static.params {
+        if let syn::GenericParam::Type(type_param) = param {
+            type_param.bounds.push(syn::parse_quote!('static));
+        }
+    }
+    let (impl_generics_with_static, _, _) = generics_with_static.split_for_impl();
+
+    let expanded = quote! {
+        #[doc = #kind_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #kind_name {
+            #(
+                #[allow(missing_docs)]
+                #variant_names,
+            )*
+        }
+
+        #[doc = #projected_doc]
+        #[derive(Debug)]
+        #vis struct #projected_name #ty_generics #where_clause {
+            /// The active variant. Setting it switches to variants without fields.
+            pub kind: ::waterui::reactive::Binding<#kind_name>,
+            #(#projected_fields,)*
+        }
+
+        impl #impl_generics_with_static ::waterui::reactive::project::Project for #enum_name #ty_generics #where_clause {
+            type Projected = #projected_name #ty_generics;
+
+            fn project(source: &::waterui::reactive::Binding<Self>) -> Self::Projected {
+                #projected_name {
+                    kind: ::waterui::reactive::Binding::mapping(
+                        source,
+                        |value| match value {
+                            #(#kind_arms,)*
+                        },
+                        |binding, kind| match kind {
+                            #(#unit_arms,)*
+                            #[allow(unreachable_patterns)]
+                            _ => {}
+                        },
+                    ),
+                    #(#field_projections,)*
+                }
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Returns the `snake_case` field name for an enum variant, as a raw identifier if it is a keyword.
+fn variant_field_name(variant: &syn::Ident) -> syn::Ident {
+    let name = variant.to_string();
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+
+    if syn::parse_str::<syn::Ident>(&snake).is_ok() {
+        syn::Ident::new(&snake, variant.span())
+    } else {
+        syn::Ident::new_raw(&snake, variant.span())
+    }
+}
+
 fn derive_project_unit_struct(input: &DeriveInput) -> TokenStream {
     let struct_name = &input.ident;
     let (_impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
@@ -472,11 +916,19 @@ impl Parse for SInput {
 /// // Automatic variable capture from format string
 /// let msg = s!("Hello {name}, you are {age} years old");
 ///
+/// // Fields and methods without arguments can be captured too
+/// let profile = binding(Profile::default()).project();
+/// let msg3 = s!("{profile.name} has {profile.followers} followers");
+///
 /// // Positional arguments still work
 /// let msg2 = s!("Hello {}, you are {}", name, age);
+///
+/// // Any number of signals can be combined
+/// let (cpu, memory, disk) = (constant(12), constant(48), constant(71));
+/// let (network, temperature) = (constant(3), constant(55));
+/// let status = s!("CPU {cpu}% RAM {memory}% disk {disk}% net {network} MB/s {temperature}°C");
 /// ```
 #[proc_macro]
-#[allow(clippy::similar_names, clippy::too_many_lines)]
 pub fn s(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as SInput);
     let format_str = input.format_str;
@@ -516,67 +968,10 @@ pub fn s(input: TokenStream) -> TokenStream {
             .into();
         }
         let args: Vec<_> = input.args.iter().collect();
-        return match args.len() {
-            1 => {
-                let arg = &args[0];
-                quote! {
-                    {
-                        use ::waterui::reactive::SignalExt;
-                        SignalExt::map(#arg.clone(), |arg| waterui::reactive::__format!(#format_str, arg))
-                    }
-                }
-                .into()
-            }
-            2 => {
-                let arg1 = &args[0];
-                let arg2 = &args[1];
-                quote! {
-                    {
-                        use waterui::reactive::{SignalExt, zip::zip};
-                        SignalExt::map(zip(#arg1.clone(), #arg2.clone()), |(arg1, arg2)| {
-                            waterui::reactive::__format!(#format_str, arg1, arg2)
-                        })
-                    }
-                }
-                .into()
-            }
-            3 => {
-                let arg1 = &args[0];
-                let arg2 = &args[1];
-                let arg3 = &args[2];
-                quote! {
-                    {
-                        use ::waterui::reactive::{SignalExt, zip::zip};
-                        SignalExt::map(
-                            zip(zip(#arg1.clone(), #arg2.clone()), #arg3.clone()),
-                            |((arg1, arg2), arg3)| waterui::reactive::__format!(#format_str, arg1, arg2, arg3)
-                        )
-                    }
-                }
-                .into()
-            }
-            4 => {
-                let arg1 = &args[0];
-                let arg2 = &args[1];
-                let arg3 = &args[2];
-                let arg4 = &args[3];
-                quote! {
-                    {
-                        use ::waterui::reactive::{SignalExt, zip::zip};
-                        SignalExt::map(
-                            zip(
-                                zip(#arg1.clone(), #arg2.clone()),
-                                zip(#arg3.clone(), #arg4.clone())
-                            ),
-                            |((arg1, arg2), (arg3, arg4))| waterui::reactive::__format!(#format_str, arg1, arg2, arg3, arg4)
-                        )
-                    }
-                }.into()
-            }
-            _ => syn::Error::new_spanned(format_str, "Too many arguments, maximum 4 supported")
-                .to_compile_error()
-                .into(),
-        };
+        let bindings: Vec<syn::Ident> = (1..=args.len())
+            .map(|index| syn::Ident::new(&format!("arg{index}"), format_str.span()))
+            .collect();
+        return format_signals(&format_str, &args, &bindings, true);
     }
 
     // Check for mixed placeholders when no explicit arguments
@@ -603,94 +998,137 @@ pub fn s(input: TokenStream) -> TokenStream {
         .into();
     }
 
-    // Parse format string to extract variable names for automatic capture
-    let var_names = named_vars;
+    // Generate code for named variable capture; without variables this is a constant.
+    // Plain names are captured as-is, while expressions like `{model.count}` are bound
+    // to a generated name that replaces them in the format string.
+    let mut signals = Vec::with_capacity(named_vars.len());
+    let mut bindings = Vec::with_capacity(named_vars.len());
+    let mut renamed = Vec::new();
+    for (index, name) in named_vars.iter().enumerate() {
+        if let Ok(ident) = syn::parse_str::<syn::Ident>(name) {
+            let ident = syn::Ident::new(&ident.to_string(), format_str.span());
+            signals.push(quote!(#ident));
+            bindings.push(ident);
+            continue;
+        }
 
-    // If no variables found, return constant
-    if var_names.is_empty() {
-        return quote! {
-            {
-                use ::waterui::reactive::constant;
-                constant(waterui::reactive::__format!(#format_str))
+        let expr = match syn::parse_str::<Expr>(name) {
+            Ok(expr) if is_capture_expr(&expr) => expr,
+            _ => {
+                return syn::Error::new_spanned(
+                    &format_str,
+                    format!(
+                        "Unsupported placeholder {{{name}}}: only names, field accesses \
+                        and method calls without arguments can be captured, like {{model.count}}"
+                    ),
+                )
+                .to_compile_error()
+                .into();
             }
-        }
-        .into();
+        };
+        let binding = syn::Ident::new(&format!("__capture{index}"), format_str.span());
+        renamed.push((name.as_str(), binding.to_string()));
+        signals.push(quote!((#expr)));
+        bindings.push(binding);
     }
 
-    // Generate code for named variable capture
-    let var_idents: Vec<syn::Ident> = var_names
-        .iter()
-        .map(|name| syn::Ident::new(name, format_str.span()))
-        .collect();
+    let format_str = if renamed.is_empty() {
+        format_str
+    } else {
+        LitStr::new(
+            &rename_placeholders(&format_value, &renamed),
+            format_str.span(),
+        )
+    };
+    format_signals(&format_str, &signals, &bindings, false)
+}
 
-    match var_names.len() {
-        1 => {
-            let var = &var_idents[0];
-            quote! {
-                {
-                    use ::waterui::reactive::SignalExt;
-                    SignalExt::map(#var.clone(), |#var| {
-                        waterui::reactive::__format!(#format_str)
-                    })
-                }
-            }
-            .into()
+/// Returns whether `expr` can be captured by a named `s!` placeholder.
+///
+/// Accepts a name followed by field accesses and method calls without arguments,
+/// such as `model.count` or `settings.project().volume`.
+fn is_capture_expr(expr: &Expr) -> bool {
+    match expr {
+        Expr::Path(path) => path.qself.is_none() && path.path.get_ident().is_some(),
+        Expr::Field(field) => is_capture_expr(&field.base),
+        Expr::MethodCall(call) => {
+            call.args.is_empty() && call.turbofish.is_none() && is_capture_expr(&call.receiver)
         }
-        2 => {
-            let var1 = &var_idents[0];
-            let var2 = &var_idents[1];
-            quote! {
-                {
-                    use ::waterui::reactive::{SignalExt, zip::zip};
-                    SignalExt::map(zip(#var1.clone(), #var2.clone()), |(#var1, #var2)| {
-                        waterui::reactive::__format!(#format_str)
-                    })
-                }
-            }
-            .into()
+        _ => false,
+    }
+}
+
+/// Replaces the argument of each placeholder in `renamed` by its new name,
+/// keeping escaped braces and format specs like `{model.count:>4}` intact.
+fn rename_placeholders(format_str: &str, renamed: &[(&str, String)]) -> String {
+    let mut result = String::with_capacity(format_str.len());
+    let mut rest = format_str;
+    while let Some(start) = rest.find(['{', '}']) {
+        result.push_str(&rest[..=start]);
+        let after = &rest[start + 1..];
+        if rest[start..].starts_with("{{") || rest[start..].starts_with("}}") {
+            result.push_str(&after[..1]);
+            rest = &after[1..];
+        } else if rest.as_bytes()[start] == b'{' {
+            let end = after.find([':', '}']).unwrap_or(after.len());
+            let argument = &after[..end];
+            let name = renamed
+                .iter()
+                .find(|(original, _)| *original == argument)
+                .map_or(argument, |(_, name)| name.as_str());
+            result.push_str(name);
+            rest = &after[end..];
+        } else {
+            rest = after;
         }
-        3 => {
-            let var1 = &var_idents[0];
-            let var2 = &var_idents[1];
-            let var3 = &var_idents[2];
-            quote! {
-                {
-                    use ::waterui::reactive::{SignalExt, zip::zip};
-                    SignalExt::map(
-                        zip(zip(#var1.clone(), #var2.clone()), #var3.clone()),
-                        |((#var1, #var2), #var3)| {
-                            ::waterui::reactive::__format!(#format_str)
-                        }
-                    )
-                }
-            }
-            .into()
-        }
-        4 => {
-            let var1 = &var_idents[0];
-            let var2 = &var_idents[1];
-            let var3 = &var_idents[2];
-            let var4 = &var_idents[3];
-            quote! {
-                {
-                    use ::waterui::reactive::{SignalExt, zip::zip};
-                    SignalExt::map(
-                        zip(
-                            zip(#var1.clone(), #var2.clone()),
-                            zip(#var3.clone(), #var4.clone())
-                        ),
-                        |((#var1, #var2), (#var3, #var4))| {
-                            ::waterui::reactive::__format!(#format_str)
-                        }
-                    )
-                }
-            }
-            .into()
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Zips `signals` together and maps them through `format!`.
+///
+/// Without signals the formatted string is a constant.
+/// Signals are zipped pairwise from the left, so any number of arguments is
+/// supported: `zip(zip(a, b), c)` is destructured as `((a, b), c)`. Each value is
+/// bound to the matching ident in `bindings`, which are passed to `format!` as
+/// positional arguments if `positional` is set.
+fn format_signals<S: quote::ToTokens>(
+    format_str: &LitStr,
+    signals: &[S],
+    bindings: &[syn::Ident],
+    positional: bool,
+) -> TokenStream {
+    let (Some(first), Some(first_binding)) = (signals.first(), bindings.first()) else {
+        return quote!(::waterui::reactive::constant(
+            ::waterui::reactive::__format!(#format_str)
+        ))
+        .into();
+    };
+
+    let mut signal = quote!(#first.clone());
+    let mut pattern = quote!(#first_binding);
+    for (next, binding) in signals.iter().zip(bindings).skip(1) {
+        signal = quote!(zip(#signal, #next.clone()));
+        pattern = quote!((#pattern, #binding));
+    }
+
+    let zip_import = (signals.len() > 1).then(|| {
+        quote!(
+            use ::waterui::reactive::zip::zip;
+        )
+    });
+    let format_args = positional.then(|| quote!(, #(#bindings),*));
+    quote! {
+        {
+            use ::waterui::reactive::SignalExt;
+            #zip_import
+            SignalExt::map(#signal, |#pattern| {
+                ::waterui::reactive::__format!(#format_str #format_args)
+            })
         }
-        _ => syn::Error::new_spanned(format_str, "Too many named variables, maximum 4 supported")
-            .to_compile_error()
-            .into(),
     }
+    .into()
 }
 
 /// Analyze a format string to detect placeholder types and extract variable names
@@ -848,3 +1286,39 @@ pub fn hot_reload(_args: TokenStream, input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Registers a file from the crate's `assets/` directory for bundling and
+/// returns a `Url` that locates it at runtime.
+///
+/// The file must exist at compile time, and the crate is rebuilt when it
+/// changes. `water build` copies the `assets/` directory into each platform
+/// bundle, so the same call works everywhere:
+///
+/// | Target | Url |
+/// |--------|-----|
+/// | Apple | `assets/images/logo.png` (relative to the bundle resources) |
+/// | Android | `file:///android_asset/images/logo.png` |
+/// | Web (`wasm32`) | `/assets/images/logo.<content hash>.png` |
+///
+/// Other desktop targets get the same relative path as Apple, but no bundle
+/// is produced for them: it resolves against the working directory, so run
+/// the app from the project root.
+///
+/// The expansion is a constant expression, so it can initialize a `const`.
+///
+/// # Example
+///
+/// ```ignore
+/// use waterui::prelude::*;
+///
+/// const LOGO: Url = asset!("images/logo.png");
+///
+/// fn header() -> impl View {
+///     Photo::new(LOGO)
+/// }
+/// ```
+#[proc_macro]
+pub fn asset(input: TokenStream) -> TokenStream {
+    let path = parse_macro_input!(input as LitStr);
+    asset::expand(&path).unwrap_or_else(|error| error.to_compile_error().into())
+}
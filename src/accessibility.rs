@@ -7,6 +7,11 @@
 //! composite widget or exposing platform-specific affordances). Prefer the
 //! defaults whenever possible and use these helpers as the final step to ensure
 //! assistive technologies convey the intended experience.
+//!
+//! Screen readers also let users jump between headings and landmarks, such as
+//! the VoiceOver rotor or the landmark lists of TalkBack and web screen
+//! readers. Mark section titles with [`AccessibilityHeading`] and the major
+//! regions of a screen with [`AccessibilityLandmark`] so those shortcuts work.
 
 use waterui_core::metadata::MetadataKey;
 use waterui_str::Str;
@@ -102,6 +107,75 @@ pub enum AccessibilityRole {
 
 impl MetadataKey for AccessibilityRole {}
 
+/// The level of a heading in the outline of a screen, from the screen title
+/// at [`HeadingLevel::H1`] down to [`HeadingLevel::H6`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HeadingLevel {
+    /// A top-level heading, usually the title of the screen.
+    H1,
+    /// A section heading.
+    H2,
+    /// A subsection heading.
+    H3,
+    /// A fourth-level heading.
+    H4,
+    /// A fifth-level heading.
+    H5,
+    /// A sixth-level heading.
+    H6,
+}
+
+/// Marks a view as a heading so screen-reader users can jump between
+/// sections.
+///
+/// Platforms without heading levels, such as Android, only expose that the
+/// view is a heading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccessibilityHeading(HeadingLevel);
+
+impl MetadataKey for AccessibilityHeading {}
+
+impl AccessibilityHeading {
+    /// Creates a heading annotation of the given level.
+    #[must_use]
+    pub const fn new(level: HeadingLevel) -> Self {
+        Self(level)
+    }
+
+    /// Returns the level of the heading.
+    #[must_use]
+    pub const fn level(&self) -> HeadingLevel {
+        self.0
+    }
+}
+
+/// A major region of a screen that screen-reader users can jump to.
+///
+/// The contents of a landmark are grouped, so assistive technologies announce
+/// the region when focus enters it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AccessibilityLandmark {
+    /// Links or controls for moving between sections or screens.
+    Navigation,
+    /// The primary content of the screen.
+    Main,
+    /// Search inputs and their results.
+    Search,
+    /// Site- or app-wide content at the top, such as a logo and title.
+    Banner,
+    /// Supporting content, such as a sidebar.
+    Complementary,
+    /// Information about the content at the bottom, such as copyright notes.
+    ContentInfo,
+    /// A form collecting input.
+    Form,
+    /// Any other region, announced with the given name.
+    Region(Str),
+}
+
+impl MetadataKey for AccessibilityLandmark {}
+
 /// Describes nuanced state transitions that assistive technologies use to keep
 /// users in sync with complex widgets.
 #[allow(clippy::struct_excessive_bools)]
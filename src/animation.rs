@@ -0,0 +1,353 @@
+//! Animations for reactive values.
+//!
+//! Besides the declarative animation metadata from [`waterui_core::animation`],
+//! this module can drive a binding through intermediate values itself with
+//! [`animate`]. Use it for values that the backend does not animate on its
+//! own, or to animate from Rust without backend-specific code:
+//!
+//! ```rust,no_run
+//! use core::time::Duration;
+//! use waterui::animation::{Curve, animate};
+//! use waterui::reactive::binding;
+//!
+//! let opacity = binding(0.0_f64);
+//! let fade_in = animate(&opacity, 1.0, Duration::from_millis(300), Curve::EaseInOut);
+//!
+//! // Stop early, leaving the binding at its current value
+//! fade_in.cancel();
+//! ```
+//!
+//! For several stops with their own easing, use [`Keyframes`] with
+//! [`animate_keyframes`]. Both work with any [`Lerp`] value.
+//!
+//! Numbers can also follow spring physics with [`SpringExt::animate_spring`],
+//! which has no fixed duration and can be retargeted mid-flight.
+
+use alloc::rc::Rc;
+use core::{cell::Cell, time::Duration};
+
+use nami::Binding;
+
+use crate::task::{now, sleep, spawn_local};
+
+#[doc(inline)]
+pub use waterui_core::animation::*;
+
+/// How often a running animation updates its binding.
+pub const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+
+/// A handle to a running animation started with [`animate`].
+///
+/// Dropping the handle lets the animation run to completion.
+#[derive(Debug, Clone)]
+pub struct AnimationHandle {
+    cancelled: Rc<Cell<bool>>,
+    finished: Rc<Cell<bool>>,
+}
+
+impl AnimationHandle {
+    /// Stops the animation, leaving the binding at its current value.
+    pub fn cancel(&self) {
+        self.cancelled.set(true);
+    }
+
+    /// Returns `true` once the animation has reached its target or was cancelled.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finished.get() || self.cancelled.get()
+    }
+}
+
+/// Animates `binding` from its current value to `to` over `duration`.
+///
+/// The binding is updated every [`FRAME_INTERVAL`] on the local executor and
+/// set exactly to `to` at the end. Animations on the same binding do not
+/// cancel each other; cancel the previous one through its handle first.
+pub fn animate<T: Lerp>(
+    binding: &Binding<T>,
+    to: T,
+    duration: Duration,
+    curve: Curve,
+) -> AnimationHandle {
+    let from = binding.get();
+    let target = to.clone();
+    play(binding, duration, to, move |elapsed| {
+        let time = elapsed.as_secs_f64() / duration.as_secs_f64();
+        from.lerp(&target, curve.progress(time))
+    })
+}
+
+/// Plays `keyframes` on `binding`, starting from its current value.
+///
+/// Like [`animate`], the binding is updated every [`FRAME_INTERVAL`] and ends
+/// exactly at the last keyframe.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use core::time::Duration;
+/// use waterui::animation::{Curve, Keyframes, animate_keyframes};
+/// use waterui::reactive::binding;
+///
+/// let ms = Duration::from_millis;
+/// let offset = binding(0.0_f32);
+/// // Shake left and right, then come back to rest
+/// animate_keyframes(
+///     &offset,
+///     Keyframes::new()
+///         .keyframe(ms(60), -8.0, Curve::EaseOut)
+///         .keyframe(ms(180), 8.0, Curve::EaseInOut)
+///         .keyframe(ms(240), 0.0, Curve::EaseIn),
+/// );
+/// ```
+// Like `animate`, the handle may be dropped to let the animation finish
+#[allow(clippy::must_use_candidate)]
+pub fn animate_keyframes<T: Lerp>(
+    binding: &Binding<T>,
+    keyframes: Keyframes<T>,
+) -> AnimationHandle {
+    let from = binding.get();
+    let duration = keyframes.duration();
+    let end = keyframes.value_at(&from, duration);
+    play(binding, duration, end, move |elapsed| {
+        keyframes.value_at(&from, elapsed)
+    })
+}
+
+/// Sets `binding` to `sample(elapsed)` every frame until `duration`, then to `end`.
+fn play<T: 'static>(
+    binding: &Binding<T>,
+    duration: Duration,
+    end: T,
+    sample: impl Fn(Duration) -> T + 'static,
+) -> AnimationHandle {
+    let handle = AnimationHandle {
+        cancelled: Rc::default(),
+        finished: Rc::default(),
+    };
+    let binding = binding.clone();
+    let task_handle = handle.clone();
+    spawn_local(async move {
+        let start = now();
+        loop {
+            if task_handle.cancelled.get() {
+                return;
+            }
+            let elapsed = now() - start;
+            if elapsed >= duration {
+                break;
+            }
+            binding.set(sample(elapsed));
+            sleep(FRAME_INTERVAL).await;
+        }
+        binding.set(end);
+        task_handle.finished.set(true);
+    })
+    .detach();
+    handle
+}
+
+/// A value that a [`Spring`] can drive.
+pub trait SpringValue: Copy + 'static {
+    /// Converts the value to the spring's position.
+    fn to_f64(self) -> f64;
+    /// Converts the spring's position back to a value.
+    fn from_f64(position: f64) -> Self;
+}
+
+impl SpringValue for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(position: f64) -> Self {
+        position
+    }
+}
+
+impl SpringValue for f32 {
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_f64(position: f64) -> Self {
+        position as Self
+    }
+}
+
+/// Extension trait animating bindings with spring physics.
+pub trait SpringExt<T: SpringValue> {
+    /// Springs this binding from its current value towards `target`.
+    ///
+    /// Unlike [`animate`], there is no fixed duration: the binding follows a
+    /// [`Spring`] until it settles. Retarget the returned handle to interrupt
+    /// the motion without losing its velocity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use waterui::animation::{SpringConfig, SpringExt};
+    /// use waterui::reactive::binding;
+    ///
+    /// let offset = binding(0.0_f32);
+    /// let slide = offset.animate_spring(300.0, SpringConfig::bouncy());
+    ///
+    /// // The user changed their mind halfway: head back, keeping momentum
+    /// slide.retarget(0.0);
+    /// ```
+    fn animate_spring(&self, target: T, config: SpringConfig) -> SpringHandle<T>;
+}
+
+impl<T: SpringValue> SpringExt<T> for Binding<T> {
+    fn animate_spring(&self, target: T, config: SpringConfig) -> SpringHandle<T> {
+        let handle = SpringHandle {
+            binding: self.clone(),
+            state: Rc::new(SpringState {
+                spring: Cell::new(Spring::new(self.get().to_f64(), target.to_f64(), config)),
+                running: Cell::new(false),
+                cancelled: Cell::new(false),
+            }),
+        };
+        handle.start();
+        handle
+    }
+}
+
+struct SpringState {
+    spring: Cell<Spring>,
+    running: Cell<bool>,
+    cancelled: Cell<bool>,
+}
+
+/// A handle to a spring animation started with [`SpringExt::animate_spring`].
+///
+/// Dropping the handle lets the spring settle on its own.
+#[derive(Clone)]
+pub struct SpringHandle<T: 'static> {
+    binding: Binding<T>,
+    state: Rc<SpringState>,
+}
+
+impl<T: 'static> core::fmt::Debug for SpringHandle<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SpringHandle")
+            .field("spring", &self.state.spring.get())
+            .field("running", &self.state.running.get())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: SpringValue> SpringHandle<T> {
+    /// Sends the spring towards `target`, keeping its current velocity.
+    ///
+    /// Restarts the spring if it had settled or was cancelled.
+    pub fn retarget(&self, target: T) {
+        let mut spring = self.state.spring.get();
+        spring.retarget(target.to_f64());
+        self.state.spring.set(spring);
+        self.state.cancelled.set(false);
+        self.start();
+    }
+
+    /// Stops the spring, leaving the binding at its current value.
+    pub fn cancel(&self) {
+        self.state.cancelled.set(true);
+    }
+
+    /// Returns `true` once the spring has settled or was cancelled.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        !self.state.running.get() || self.state.cancelled.get()
+    }
+
+    /// Returns the current velocity, in units per second.
+    #[must_use]
+    pub fn velocity(&self) -> f64 {
+        self.state.spring.get().velocity()
+    }
+
+    fn start(&self) {
+        if self.state.running.replace(true) {
+            return;
+        }
+        let binding = self.binding.clone();
+        let state = self.state.clone();
+        spawn_local(async move {
+            let mut last = now();
+            loop {
+                sleep(FRAME_INTERVAL).await;
+                if state.cancelled.get() {
+                    break;
+                }
+                let current = now();
+                let mut spring = state.spring.get();
+                spring.step(current.saturating_duration_since(last));
+                last = current;
+                state.spring.set(spring);
+                binding.set(T::from_f64(spring.position()));
+                if spring.is_settled() {
+                    break;
+                }
+            }
+            state.running.set(false);
+        })
+        .detach();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animate_reaches_target_on_virtual_time() {
+        let executor = crate::task::TestExecutor::new();
+        let opacity = Binding::container(0.0_f64);
+        let fade = animate(&opacity, 1.0, Duration::from_millis(100), Curve::Linear);
+
+        executor.advance(Duration::from_millis(48));
+        assert!((opacity.get() - 0.48).abs() < 1e-9);
+        assert!(!fade.is_finished());
+        executor.advance(Duration::from_millis(100));
+        assert!((opacity.get() - 1.0).abs() < f64::EPSILON);
+        assert!(fade.is_finished());
+    }
+
+    #[test]
+    fn keyframes_play_on_virtual_time() {
+        let executor = crate::task::TestExecutor::new();
+        let scale = Binding::container(1.0_f64);
+        let pop = animate_keyframes(
+            &scale,
+            Keyframes::new()
+                .keyframe(Duration::from_millis(64), 2.0, Curve::Linear)
+                .keyframe(Duration::from_millis(128), 1.5, Curve::Linear),
+        );
+
+        executor.advance(Duration::from_millis(32));
+        assert!((scale.get() - 1.5).abs() < 1e-9);
+        executor.advance(Duration::from_millis(64));
+        assert!((scale.get() - 1.75).abs() < 1e-9);
+        executor.advance(Duration::from_millis(64));
+        assert!((scale.get() - 1.5).abs() < f64::EPSILON);
+        assert!(pop.is_finished());
+    }
+
+    #[test]
+    fn springs_settle_and_retarget_with_momentum() {
+        let executor = crate::task::TestExecutor::new();
+        let offset = Binding::container(0.0_f64);
+        let slide = offset.animate_spring(100.0, SpringConfig::default());
+
+        executor.advance(Duration::from_millis(80));
+        let velocity = slide.velocity();
+        assert!(velocity > 0.0);
+        slide.retarget(0.0);
+        assert!((slide.velocity() - velocity).abs() < f64::EPSILON);
+
+        executor.advance(Duration::from_secs(2));
+        assert!(slide.is_finished());
+        assert!(offset.get().abs() < f64::EPSILON);
+    }
+}
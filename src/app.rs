@@ -4,7 +4,9 @@ use nami::signal::IntoComputed;
 use waterui_core::{AnyView, Environment, View};
 use waterui_layout::stack::zstack;
 use waterui_str::Str;
+use waterui_text::locale::{current_time, install_clock};
 
+use crate::clock::coarse_clock;
 use crate::fullscreen::FullScreenOverlayManager;
 use crate::window::Window;
 
@@ -21,7 +23,8 @@ impl App {
     /// Create a new application with the given main content view and environment.
     ///
     /// This injects a `FullScreenOverlayManager` into the environment and wraps
-    /// the content with a [`ZStack`] overlay layer.
+    /// the content with a [`ZStack`] overlay layer. Unless the environment already
+    /// has a clock, the shared [`coarse_clock`] is installed for relative times.
     pub fn new(content: impl View, env: Environment) -> Self {
         // Create overlay manager and view
         let (manager, overlay_view) = FullScreenOverlayManager::new();
@@ -29,6 +32,9 @@ impl App {
         // Install the manager into the environment
         let mut env = env;
         env.install(manager);
+        if current_time(&env).is_none() {
+            install_clock(&mut env, coarse_clock());
+        }
 
         // Wrap content with overlay using ZStack
         let wrapped = zstack((content, overlay_view));
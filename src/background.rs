@@ -1,7 +1,7 @@
 //! This module provides types for defining background and foreground colors in a UI.
 
 use nami::signal::IntoComputed;
-use waterui_color::{Color, Srgb};
+use waterui_color::{AngularGradient, Color, Gradient, LinearGradient, RadialGradient, Srgb};
 use waterui_core::{Computed, metadata::MetadataKey};
 use waterui_str::Str;
 
@@ -10,6 +10,8 @@ use waterui_str::Str;
 pub enum Background {
     /// A solid color background.
     Color(Computed<Color>),
+    /// A linear, radial or angular gradient background.
+    Gradient(Computed<Gradient>),
     /// An image background.
     Image(Computed<Str>),
 
@@ -47,6 +49,26 @@ impl From<Srgb> for Background {
     }
 }
 
+impl From<Gradient> for Background {
+    fn from(gradient: Gradient) -> Self {
+        Self::Gradient(Computed::new(gradient))
+    }
+}
+
+macro_rules! impl_gradient_background {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Background {
+                fn from(gradient: $ty) -> Self {
+                    Self::from(Gradient::from(gradient))
+                }
+            }
+        )*
+    };
+}
+
+impl_gradient_background!(LinearGradient, RadialGradient, AngularGradient);
+
 impl Background {
     /// Creates a new background with a solid color.
     ///
@@ -60,6 +82,19 @@ impl Background {
     pub fn color(color: impl IntoComputed<Color>) -> Self {
         Self::Color(color.into_computed())
     }
+
+    /// Creates a new background with a gradient.
+    ///
+    /// # Arguments
+    ///
+    /// * `gradient` - A value that can be converted into a computed gradient.
+    ///
+    /// # Returns
+    ///
+    /// A new `Background` instance with the specified gradient.
+    pub fn gradient(gradient: impl IntoComputed<Gradient>) -> Self {
+        Self::Gradient(gradient.into_computed())
+    }
 }
 
 /// Represents the color of text or other foreground elements in a UI.
@@ -0,0 +1,194 @@
+//! Signals fed from other threads.
+//!
+//! Bindings and signals live on the thread that created them. A
+//! [`channel_signal`] pairs a [`SignalSender`], which can be moved to any
+//! thread, with a [`ChannelSignal`] on the current thread. Sent values are
+//! applied on the local executor, so background workers, sockets and native
+//! callbacks can feed the UI without touching the binding themselves.
+//!
+//! ```no_run
+//! use waterui::channel::channel_signal;
+//! use waterui::text;
+//!
+//! let (sender, progress) = channel_signal(0.0_f32);
+//! std::thread::spawn(move || {
+//!     for step in 1..=100 {
+//!         // ... do some work ...
+//!         let _ = sender.send(step as f32 / 100.0);
+//!     }
+//! });
+//! let label = text!("{:.0}%", progress.map(|progress| progress * 100.0));
+//! ```
+
+use alloc::rc::Rc;
+use core::fmt;
+
+use futures::{
+    StreamExt,
+    channel::mpsc::{self, UnboundedSender},
+};
+use nami::{Binding, Signal, watcher::Context};
+
+use crate::task::{AnyLocalExecutorTask, spawn_local};
+
+/// Creates a signal starting at `initial` that takes every value sent
+/// through the returned sender.
+///
+/// Must be called on the UI thread: values are applied by a task on its
+/// local executor, which stops once every clone of the signal is dropped.
+/// When values arrive faster than the executor runs, only the latest one is
+/// applied.
+#[must_use]
+pub fn channel_signal<T>(initial: T) -> (SignalSender<T>, ChannelSignal<T>)
+where
+    T: Send + Clone + 'static,
+{
+    let (sender, mut receiver) = mpsc::unbounded();
+    let state = Binding::container(initial);
+    let target = state.clone();
+    let task = spawn_local(async move {
+        while let Some(mut value) = receiver.next().await {
+            // Skip values that were superseded before we got to run
+            while let Ok(next) = receiver.try_recv() {
+                value = next;
+            }
+            target.set(value);
+        }
+    });
+    (
+        SignalSender { sender },
+        ChannelSignal {
+            state,
+            task: Rc::new(task),
+        },
+    )
+}
+
+/// The sending half of a [`channel_signal`].
+///
+/// Can be cloned and moved to any thread.
+pub struct SignalSender<T> {
+    sender: UnboundedSender<T>,
+}
+
+impl<T> SignalSender<T> {
+    /// Sends a new value for the signal.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if the signal was dropped.
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        self.sender
+            .unbounded_send(value)
+            .map_err(|error| SendError(error.into_inner()))
+    }
+
+    /// Returns `true` once the signal was dropped and sending has no effect.
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+impl<T> Clone for SignalSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for SignalSender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignalSender")
+            .field("closed", &self.is_closed())
+            .finish()
+    }
+}
+
+/// The error returned by [`SignalSender::send`] when the signal was dropped.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SendError(..)")
+    }
+}
+
+impl<T> fmt::Display for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("sending on a dropped channel signal")
+    }
+}
+
+impl<T> core::error::Error for SendError<T> {}
+
+/// The receiving half of a [`channel_signal`].
+///
+/// Every clone observes the same values.
+pub struct ChannelSignal<T: 'static> {
+    state: Binding<T>,
+    /// Applies sent values; cancelled with the last clone.
+    task: Rc<AnyLocalExecutorTask<()>>,
+}
+
+impl<T: 'static> Clone for ChannelSignal<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            task: self.task.clone(),
+        }
+    }
+}
+
+impl<T: 'static> fmt::Debug for ChannelSignal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChannelSignal").finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone + 'static> Signal for ChannelSignal<T> {
+    type Output = T;
+    type Guard = <Binding<T> as Signal>::Guard;
+
+    fn get(&self) -> Self::Output {
+        self.state.get()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.state.watch(watcher)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::task::TestExecutor;
+
+    #[test]
+    fn values_from_other_threads_are_applied_on_the_executor() {
+        let executor = TestExecutor::new();
+        let (sender, signal) = channel_signal(0);
+
+        let worker = sender.clone();
+        thread::spawn(move || {
+            for value in 1..=3 {
+                worker.send(value).unwrap();
+            }
+        })
+        .join()
+        .unwrap();
+        assert_eq!(signal.get(), 0);
+
+        executor.run_until_idle();
+        assert_eq!(signal.get(), 3);
+
+        drop(signal);
+        executor.run_until_idle();
+        assert!(sender.is_closed());
+        assert_eq!(sender.send(4), Err(SendError(4)));
+    }
+}
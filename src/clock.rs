@@ -0,0 +1,45 @@
+//! A shared, coarse wall clock for time-relative views.
+//!
+//! [`coarse_clock`] ticks every [`TICK`] on the local executor. [`App`](crate::app::App)
+//! installs it into the environment so that
+//! [`relative_time`](crate::text::locale::relative_time) stays current
+//! without a timer per view.
+
+use core::cell::OnceCell;
+
+use nami::{Binding, Computed, SignalExt};
+
+use crate::{
+    task::{sleep, spawn_local},
+    time::{Duration, OffsetDateTime},
+};
+
+/// How often the shared clock updates.
+pub const TICK: Duration = Duration::from_secs(30);
+
+thread_local! {
+    static CLOCK: OnceCell<Computed<OffsetDateTime>> = const { OnceCell::new() };
+}
+
+/// Returns the shared clock for the current thread, starting it on first use.
+///
+/// The clock reports the current UTC time, updated every [`TICK`].
+#[must_use]
+pub fn coarse_clock() -> Computed<OffsetDateTime> {
+    CLOCK.with(|clock| {
+        clock
+            .get_or_init(|| {
+                let now = Binding::container(OffsetDateTime::now_utc());
+                let ticker = now.clone();
+                spawn_local(async move {
+                    loop {
+                        sleep(TICK).await;
+                        ticker.set(OffsetDateTime::now_utc());
+                    }
+                })
+                .detach();
+                now.computed()
+            })
+            .clone()
+    })
+}
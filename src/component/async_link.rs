@@ -0,0 +1,92 @@
+//! Navigation links whose destination is loaded asynchronously.
+//!
+//! An [`AsyncNavigationLink`] pushes its destination once the builder's
+//! future completes. While it runs, the enclosing navigation stack shows its
+//! activity indicator, so screens that fetch data before they can be shown
+//! get the same loading affordance everywhere.
+//!
+//! # Example
+//!
+//! ```ignore
+//! AsyncNavigationLink::new(text("Inbox"), || async {
+//!     let messages = fetch_messages().await;
+//!     navigation("Inbox", inbox(messages))
+//! })
+//! .activity_label("Loading inbox")
+//! ```
+
+use alloc::rc::Rc;
+use core::{cell::Cell, future::Future};
+
+use waterui_controls::button;
+use waterui_core::{Environment, Str, View};
+use waterui_navigation::{Activity, NavigationController, NavigationView};
+
+use crate::task::spawn_local;
+
+/// A link that loads its destination before navigating to it.
+#[must_use]
+#[derive(Debug)]
+pub struct AsyncNavigationLink<Label, F> {
+    label: Label,
+    destination: F,
+    activity_label: Str,
+}
+
+impl<Label, F, Fut> AsyncNavigationLink<Label, F>
+where
+    Label: View,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = NavigationView> + 'static,
+{
+    /// Creates a link that pushes the view returned by `destination`'s future.
+    pub const fn new(label: Label, destination: F) -> Self {
+        Self {
+            label,
+            destination,
+            activity_label: Str::new(),
+        }
+    }
+
+    /// Sets the label of the activity shown while the destination loads.
+    pub fn activity_label(mut self, label: impl Into<Str>) -> Self {
+        self.activity_label = label.into();
+        self
+    }
+}
+
+impl<Label, F, Fut> View for AsyncNavigationLink<Label, F>
+where
+    Label: View,
+    F: Fn() -> Fut + 'static,
+    Fut: Future<Output = NavigationView> + 'static,
+{
+    fn body(self, env: &Environment) -> impl View {
+        debug_assert!(
+            env.get::<NavigationController>().is_some(),
+            "AsyncNavigationLink used outside of a navigation context"
+        );
+
+        let activity = env.get::<Activity>().cloned().unwrap_or_default();
+        let loading = Rc::new(Cell::new(false));
+        let destination = self.destination;
+        let activity_label = self.activity_label;
+
+        button(self.label).action(move |receiver: NavigationController| {
+            // Ignore taps while the destination is still loading
+            if loading.replace(true) {
+                return;
+            }
+            let token = activity.begin(activity_label.clone());
+            let future = destination();
+            let loading = loading.clone();
+            spawn_local(async move {
+                let view = future.await;
+                receiver.push(view);
+                loading.set(false);
+                drop(token);
+            })
+            .detach();
+        })
+    }
+}
@@ -0,0 +1,83 @@
+//! Hosting Jetpack Compose content on Android.
+//!
+//! [`ComposeView`] embeds a Composable supplied by the app's Kotlin code, so
+//! screens written in Compose can be reused while migrating to `WaterUI`. The
+//! Kotlin side registers a factory under an id; the Android backend looks the
+//! factory up and hands it the view's parameters, a string that is usually
+//! JSON. Parameters are reactive: the Composable is recomposed with the new
+//! payload whenever they change.
+//!
+//! Other backends render the view's [fallback](ComposeView::fallback), or
+//! nothing if none was set.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use waterui::component::compose::ComposeView;
+//! use waterui::prelude::*;
+//!
+//! let user_id = Binding::container(42);
+//! let profile = ComposeView::new("com.example.ProfileScreen")
+//!     .params(user_id.map(|id| Str::from(format!(r#"{{"userId":{id}}}"#))))
+//!     .fallback(text!("Profile is only available on Android"));
+//! ```
+
+use nami::{Computed, signal::IntoComputed};
+use waterui_core::{AnyView, Environment, Native, NativeView, View, layout::StretchAxis};
+use waterui_str::Str;
+
+/// Configuration for a [`ComposeView`], read by the Android backend.
+#[derive(Debug)]
+pub struct ComposeConfig {
+    /// Id under which the app's Kotlin code registered the Composable factory.
+    pub factory: Str,
+    /// Parameter payload passed to the factory.
+    pub params: Computed<Str>,
+}
+
+impl NativeView for ComposeConfig {
+    fn stretch_axis(&self) -> StretchAxis {
+        StretchAxis::Both
+    }
+}
+
+/// A Jetpack Compose Composable hosted inside the view tree.
+///
+/// See the [module documentation](self) for how the Kotlin side supplies it.
+#[derive(Debug)]
+#[must_use]
+pub struct ComposeView {
+    config: ComposeConfig,
+    fallback: Option<AnyView>,
+}
+
+impl ComposeView {
+    /// Hosts the Composable registered under `factory`, with empty parameters.
+    pub fn new(factory: impl Into<Str>) -> Self {
+        Self {
+            config: ComposeConfig {
+                factory: factory.into(),
+                params: Computed::constant(Str::new()),
+            },
+            fallback: None,
+        }
+    }
+
+    /// Sets the parameter payload passed to the factory.
+    pub fn params(mut self, params: impl IntoComputed<Str>) -> Self {
+        self.config.params = params.into_computed();
+        self
+    }
+
+    /// Sets the view shown by backends that cannot host Compose content.
+    pub fn fallback(mut self, fallback: impl View) -> Self {
+        self.fallback = Some(AnyView::new(fallback));
+        self
+    }
+}
+
+impl View for ComposeView {
+    fn body(self, _env: &Environment) -> impl View {
+        Native::new(self.config).with_fallback(self.fallback.unwrap_or_else(|| AnyView::new(())))
+    }
+}
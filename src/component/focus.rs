@@ -86,7 +86,7 @@ impl Focused {
             value,
             {
                 let equals = equals.clone();
-                move |value| value.as_ref().filter(|value| **value == equals).is_some()
+                move |value| value.as_ref().is_some_and(|value| *value == equals)
             },
             move |binding, value| {
                 if value {
@@ -17,7 +17,7 @@ use nami::collection::Collection;
 use waterui_core::{View, id::Identifiable};
 use waterui_layout::{
     LazyContainer,
-    scroll::scroll,
+    scroll::{ScrollView, scroll},
     stack::{HStackLayout, VStackLayout},
 };
 
@@ -41,7 +41,7 @@ impl Lazy {
     /// ```ignore
     /// let list = Lazy::vstack((0..1000).map(|i| text(format!("Item {}", i))));
     /// ```
-    pub fn vstack<V: View>(contents: impl Views<View = V> + 'static) -> impl View {
+    pub fn vstack<V: View>(contents: impl Views<View = V> + 'static) -> ScrollView {
         scroll(LazyContainer::new(VStackLayout::default(), contents))
     }
 
@@ -49,7 +49,7 @@ impl Lazy {
     pub fn vstack_spaced<V: View>(
         spacing: f32,
         contents: impl Views<View = V> + 'static,
-    ) -> impl View {
+    ) -> ScrollView {
         scroll(LazyContainer::new(
             VStackLayout {
                 spacing,
@@ -63,7 +63,7 @@ impl Lazy {
     ///
     /// Views are reconstructed on-demand as they become visible,
     /// making this suitable for large collections.
-    pub fn hstack<V: View>(contents: impl Views<View = V> + 'static) -> impl View {
+    pub fn hstack<V: View>(contents: impl Views<View = V> + 'static) -> ScrollView {
         scroll(LazyContainer::new(HStackLayout::default(), contents))
     }
 
@@ -71,7 +71,7 @@ impl Lazy {
     pub fn hstack_spaced<V: View>(
         spacing: f32,
         contents: impl Views<View = V> + 'static,
-    ) -> impl View {
+    ) -> ScrollView {
         scroll(LazyContainer::new(
             HStackLayout {
                 spacing,
@@ -89,7 +89,7 @@ impl Lazy {
     /// let items = vec![Item::new(1, "First"), Item::new(2, "Second")];
     /// let list = Lazy::for_each(items, |item| text(item.name));
     /// ```
-    pub fn for_each<C, F, V>(collection: C, generator: F) -> impl View
+    pub fn for_each<C, F, V>(collection: C, generator: F) -> ScrollView
     where
         C: Collection,
         C::Item: Identifiable,
@@ -2,17 +2,41 @@
 //!
 //! This module provides a Link component that displays clickable text that opens a URL.
 //! Link is implemented as a Button with link style, using `robius-open` to open URLs.
+//!
+//! Besides web URLs, links can target opaque schemes such as `mailto:`, `tel:`,
+//! `sms:` or a custom app scheme; these are handed to the app registered for them.
+
+use alloc::borrow::Cow;
+use alloc::format;
 
 use nami::Computed;
 use nami::Signal;
 use nami::signal::IntoComputed;
 use waterui_controls::button::{Button, ButtonStyle};
 use waterui_core::{Environment, Str, View};
+use waterui_media::url::{Url, UrlKind};
 
-/// Opens a URL in the system's default browser/handler.
-fn open_url(url: &str) {
-    if let Err(e) = robius_open::Uri::new(url).open() {
-        tracing::error!("Failed to open URL '{}': {:?}", url, e);
+/// Opens a URL with the system handler for its kind.
+///
+/// Web URLs open in the default browser and opaque URLs (`mailto:`, `tel:`, `sms:`,
+/// custom app schemes) in the app registered for their scheme. Absolute local paths
+/// are opened as `file://` URLs; relative paths have no handler and are ignored.
+pub fn open_url(url: &Url) {
+    let target: Cow<'_, str> = match url.kind() {
+        UrlKind::Local if url.is_absolute() => {
+            let path = url.path().replace('\\', "/");
+            let separator = if path.starts_with('/') { "" } else { "/" };
+            format!("file://{separator}{path}").into()
+        }
+        UrlKind::Local => {
+            tracing::error!("Cannot open relative path '{}'", url);
+            return;
+        }
+        UrlKind::Web | UrlKind::Data | UrlKind::Blob | UrlKind::Opaque => url.as_str().into(),
+    };
+
+    if let Err(e) = robius_open::Uri::new(&target).open() {
+        tracing::error!("Failed to open URL '{}': {:?}", target, e);
     }
 }
 
@@ -33,6 +57,10 @@ fn open_url(url: &str) {
 ///
 /// // Create a simple link
 /// let my_link = link("Visit website", "https://example.com");
+///
+/// // Contact links open the mail and phone apps
+/// let email = link("Email us", "mailto:hello@example.com");
+/// let call = link("Call us", "tel:+1-555-0100");
 /// ```
 #[derive(Debug)]
 pub struct Link<Label> {
@@ -63,8 +91,7 @@ where
         Button::new(self.label)
             .style(ButtonStyle::Link)
             .action(move || {
-                let url_str = url.get();
-                open_url(&url_str);
+                open_url(&Url::from(url.get()));
             })
     }
 }
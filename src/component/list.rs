@@ -4,21 +4,337 @@
 //! in the `WaterUI` framework. It includes the `List` component for displaying collections
 //! of data, and `ListItem` for configuring individual items in the list.
 //!
+//! Lists created with [`List::recycling`] also carry a [`RowRecycler`], which lets
+//! backends reuse row views for different items instead of building new ones.
+//!
+//! [`List::selectable`] and [`List::multi_selectable`] let the user select rows.
+//! The selected item ids are kept in a binding, so selection can be read and
+//! changed from Rust while backends draw the highlight.
+//!
+//! [`List::refreshable`] adds pull-to-refresh, running an async action while
+//! the platform refresh control spins, and [`List::on_reach_end`] loads more
+//! rows as the user nears the end. [`List::scroll_position`] reads the scroll
+//! offset and scrolls to rows from Rust, such as to the newest message of a chat.
 
 use alloc::boxed::Box;
-use nami::collection::Collection;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Range, RangeBounds};
+use nami::watcher::{BoxWatcherGuard, Context};
+use nami::{Binding, Computed, Signal, SignalExt, collection::Collection};
 
 use crate::views::{AnyViews, ForEach, SharedAnyViews, Views, ViewsExt};
 use waterui_core::view::{ConfigurableView, Hook, ViewConfiguration};
 use waterui_core::{
     AnyView, Environment, Native, NativeView, View, id::Identifiable, layout::StretchAxis,
 };
+use waterui_layout::scroll::{Prefetch, ReachEnd, Refresh, ScrollPosition};
 
 /// Configuration for a list component.
 #[derive(Debug, Clone)]
 pub struct ListConfig {
     /// Content items to be displayed in the list.
     pub contents: SharedAnyViews<ListItem>,
+    /// Rebinds reusable rows to items, if the list supports recycling.
+    pub recycler: Option<RowRecycler>,
+    /// Hint fired for rows about to scroll into view.
+    pub prefetch: Option<Prefetch>,
+    /// Rows selected by the user, if the list is selectable.
+    pub selection: Option<ListSelection>,
+    /// Pull-to-refresh action, if the list is refreshable.
+    pub refresh: Option<Refresh>,
+    /// Callback fired when scrolling nears the last row, if one is set.
+    pub reach_end: Option<ReachEnd>,
+    /// Scroll offset and programmatic scrolling, if a position is attached.
+    pub position: Option<ScrollPosition>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// INTERNAL: Row Recycling Contract for Backend Implementers
+// ═══════════════════════════════════════════════════════════════════════════
+//
+// When `recycler` is set, backends may skip `contents.get_view` and instead:
+// 1. Create rows with `RowRecycler::make_row(index)`, keeping the `RowSlot`
+//    alongside the native row view.
+// 2. When a row scrolls offscreen, put it in a pool instead of dropping it.
+// 3. To show another item in a pooled row, call `RowSlot::rebind(new_index)`.
+//    The row's views update through their signals; no new views are built.
+// 4. When `contents` reports a change, rebind the visible rows to their new
+//    indices (use `contents.get_id` to diff) and drop slots past the end.
+//
+// `contents` always stays valid, so backends without recycling can ignore it.
+//
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Creates reusable rows for a [`List`] and rebinds them to other items.
+///
+/// Created by [`List::recycling`].
+#[derive(Clone)]
+pub struct RowRecycler(Rc<dyn Fn(usize) -> Option<RecycledRow>>);
+
+impl_debug!(RowRecycler);
+
+impl RowRecycler {
+    /// Builds a row showing the item at `index`.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[must_use]
+    pub fn make_row(&self, index: usize) -> Option<RecycledRow> {
+        (self.0)(index)
+    }
+}
+
+/// A row built by a [`RowRecycler`].
+pub struct RecycledRow {
+    /// The row's view, built once and reused for every item it shows.
+    pub content: AnyView,
+    /// Handle for pointing the row at another item.
+    pub slot: RowSlot,
+    /// Callback for deleting the row's item, kept across rebinds.
+    ///
+    /// It receives the index the row shows when the delete happens.
+    pub on_delete: Option<OnDelete>,
+}
+
+impl fmt::Debug for RecycledRow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecycledRow")
+            .field("content", &self.content)
+            .field("slot", &self.slot)
+            .field("on_delete", &self.on_delete.is_some())
+            .finish()
+    }
+}
+
+/// Points a recycled row at a different item.
+pub struct RowSlot(Box<dyn Fn(usize) -> bool>);
+
+impl_debug!(RowSlot);
+
+impl RowSlot {
+    /// Shows the item at `index` in this row.
+    ///
+    /// Returns `false`, leaving the row unchanged, if `index` is out of bounds.
+    #[must_use]
+    pub fn rebind(&self, index: usize) -> bool {
+        (self.0)(index)
+    }
+}
+
+/// How many rows of a [`List`] can be selected at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// At most one row, set up by [`List::selectable`].
+    Single,
+    /// Any number of rows, set up by [`List::multi_selectable`].
+    Multiple,
+}
+
+/// The selected rows of a [`List`], addressed by row index.
+///
+/// Reads and writes go through the binding passed to [`List::selectable`] or
+/// [`List::multi_selectable`], which stores the ids of the selected items.
+/// Indices that are out of bounds are never selected and are ignored when
+/// changing the selection.
+#[derive(Clone)]
+pub struct ListSelection {
+    mode: SelectionMode,
+    state: Rc<dyn SelectionState>,
+}
+
+impl_debug!(ListSelection);
+
+impl ListSelection {
+    /// Returns how many rows can be selected at once.
+    #[must_use]
+    pub const fn mode(&self) -> SelectionMode {
+        self.mode
+    }
+
+    /// Returns `true` if the row at `index` is selected.
+    #[must_use]
+    pub fn is_selected(&self, index: usize) -> bool {
+        self.state.is_selected(index)
+    }
+
+    /// Selects the row at `index`.
+    ///
+    /// In [`SelectionMode::Single`] this replaces the selected row.
+    pub fn select(&self, index: usize) {
+        self.state.set_selected(index, true);
+    }
+
+    /// Deselects the row at `index`.
+    pub fn deselect(&self, index: usize) {
+        self.state.set_selected(index, false);
+    }
+
+    /// Selects the row at `index` if it is not selected, and deselects it otherwise.
+    pub fn toggle(&self, index: usize) {
+        self.state
+            .set_selected(index, !self.state.is_selected(index));
+    }
+
+    /// Deselects every row.
+    pub fn clear(&self) {
+        self.state.clear();
+    }
+
+    /// Calls `watcher` whenever the selection changes.
+    ///
+    /// Backends use it to refresh the highlight of visible rows.
+    #[must_use]
+    pub fn watch(&self, watcher: impl Fn() + 'static) -> BoxWatcherGuard {
+        self.state.watch(Box::new(watcher))
+    }
+}
+
+trait SelectionState {
+    fn is_selected(&self, index: usize) -> bool;
+    fn set_selected(&self, index: usize, selected: bool);
+    fn clear(&self);
+    fn watch(&self, watcher: Box<dyn Fn()>) -> BoxWatcherGuard;
+}
+
+struct SingleSelection<V: Views> {
+    contents: Rc<V>,
+    selection: Binding<Option<V::Id>>,
+}
+
+impl<V: Views> SelectionState for SingleSelection<V> {
+    fn is_selected(&self, index: usize) -> bool {
+        self.contents
+            .get_id(index)
+            .is_some_and(|id| self.selection.get() == Some(id))
+    }
+
+    fn set_selected(&self, index: usize, selected: bool) {
+        let Some(id) = self.contents.get_id(index) else {
+            return;
+        };
+        if selected {
+            self.selection.set(Some(id));
+        } else if self.selection.get() == Some(id) {
+            self.selection.set(None);
+        }
+    }
+
+    fn clear(&self) {
+        self.selection.set(None);
+    }
+
+    fn watch(&self, watcher: Box<dyn Fn()>) -> BoxWatcherGuard {
+        Box::new(self.selection.watch(move |_| watcher()))
+    }
+}
+
+struct MultipleSelection<V: Views> {
+    contents: Rc<V>,
+    selection: Binding<Vec<V::Id>>,
+}
+
+impl<V: Views> SelectionState for MultipleSelection<V> {
+    fn is_selected(&self, index: usize) -> bool {
+        self.contents
+            .get_id(index)
+            .is_some_and(|id| self.selection.get().contains(&id))
+    }
+
+    fn set_selected(&self, index: usize, selected: bool) {
+        let Some(id) = self.contents.get_id(index) else {
+            return;
+        };
+        let mut ids = self.selection.get();
+        match (selected, ids.iter().position(|selected| *selected == id)) {
+            (true, None) => ids.push(id),
+            (false, Some(position)) => {
+                ids.remove(position);
+            }
+            _ => return,
+        }
+        self.selection.set(ids);
+    }
+
+    fn clear(&self) {
+        self.selection.set(Vec::new());
+    }
+
+    fn watch(&self, watcher: Box<dyn Fn()>) -> BoxWatcherGuard {
+        Box::new(self.selection.watch(move |_| watcher()))
+    }
+}
+
+/// Selection of a [`List`] before its contents are type-erased.
+enum Selection<Id: 'static> {
+    Single(Binding<Option<Id>>),
+    Multiple(Binding<Vec<Id>>),
+    /// Already resolved against the contents, when rebuilt from a [`ListConfig`].
+    Bound(ListSelection),
+}
+
+impl<Id> Selection<Id> {
+    fn bind<V>(self, contents: &Rc<V>) -> ListSelection
+    where
+        V: Views<Id = Id> + 'static,
+    {
+        match self {
+            Self::Single(selection) => ListSelection {
+                mode: SelectionMode::Single,
+                state: Rc::new(SingleSelection {
+                    contents: contents.clone(),
+                    selection,
+                }),
+            },
+            Self::Multiple(selection) => ListSelection {
+                mode: SelectionMode::Multiple,
+                state: Rc::new(MultipleSelection {
+                    contents: contents.clone(),
+                    selection,
+                }),
+            },
+            Self::Bound(selection) => selection,
+        }
+    }
+}
+
+impl<Id> fmt::Debug for Selection<Id> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Single(_) => "Single",
+            Self::Multiple(_) => "Multiple",
+            Self::Bound(_) => "Bound",
+        })
+    }
+}
+
+/// Lets the list contents and its selection share one collection.
+struct SharedViews<V>(Rc<V>);
+
+impl<V: Views> Views for SharedViews<V> {
+    type Id = V::Id;
+    type Guard = V::Guard;
+    type View = V::View;
+
+    fn get_id(&self, index: usize) -> Option<Self::Id> {
+        self.0.get_id(index)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn watch(
+        &self,
+        range: impl RangeBounds<usize>,
+        watcher: impl for<'a> Fn(Context<&'a [Self::Id]>) + 'static,
+    ) -> Self::Guard {
+        self.0.watch(range, watcher)
+    }
+
+    fn get_view(&self, index: usize) -> Option<Self::View> {
+        self.0.get_view(index)
+    }
 }
 
 impl NativeView for ListConfig {
@@ -29,7 +345,15 @@ impl NativeView for ListConfig {
 
 /// A component that displays items in a list format.
 #[derive(Debug)]
-pub struct List<V: Views<View = ListItem> = AnyViews<ListItem>>(V);
+pub struct List<V: Views<View = ListItem> = AnyViews<ListItem>> {
+    contents: V,
+    recycler: Option<RowRecycler>,
+    prefetch: Option<Prefetch>,
+    selection: Option<Selection<V::Id>>,
+    refresh: Option<Refresh>,
+    reach_end: Option<ReachEnd>,
+    position: Option<ScrollPosition>,
+}
 
 impl<V> List<V>
 where
@@ -37,7 +361,173 @@ where
 {
     /// Creates a new list with the specified contents.
     pub const fn new(contents: V) -> Self {
-        Self(contents)
+        Self {
+            contents,
+            recycler: None,
+            prefetch: None,
+            selection: None,
+            refresh: None,
+            reach_end: None,
+            position: None,
+        }
+    }
+
+    /// Calls `handler` with the indices of rows that are about to scroll into view.
+    ///
+    /// Use it to load the next page of a feed or warm images before rows appear;
+    /// see [`Prefetch`] for when it fires.
+    ///
+    /// ```rust,ignore
+    /// List::for_each(feed.items(), post_row)
+    ///     .prefetch(move |range| feed.load_until(range.end))
+    /// ```
+    #[must_use]
+    pub fn prefetch(self, handler: impl Fn(Range<usize>) + 'static) -> Self {
+        self.prefetch_with(Prefetch::new(move |_env, range| handler(range)))
+    }
+
+    /// Sets the prefetch hint of this list, such as one created by
+    /// [`prefetch_photos`](crate::media::photo::prefetch_photos).
+    #[must_use]
+    pub fn prefetch_with(mut self, prefetch: Prefetch) -> Self {
+        self.prefetch = Some(prefetch);
+        self
+    }
+
+    /// Lets the user select a single row, keeping the id of its item in `selection`.
+    ///
+    /// The binding is `None` while no row is selected. Ids are the ones the
+    /// contents report, such as [`Identifiable::id`] for [`List::for_each`].
+    ///
+    /// ```rust,ignore
+    /// let selected = binding(None);
+    /// List::for_each(contacts, contact_row).selectable(&selected)
+    /// ```
+    #[must_use]
+    pub fn selectable(mut self, selection: &Binding<Option<V::Id>>) -> Self {
+        self.selection = Some(Selection::Single(selection.clone()));
+        self
+    }
+
+    /// Lets the user select any number of rows, keeping the ids of their items
+    /// in `selection`, in the order they were selected.
+    #[must_use]
+    pub fn multi_selectable(mut self, selection: &Binding<Vec<V::Id>>) -> Self {
+        self.selection = Some(Selection::Multiple(selection.clone()));
+        self
+    }
+
+    /// Lets the user pull to refresh, showing the platform refresh control
+    /// until `action` completes.
+    ///
+    /// The action runs on the local executor; pulls while it is still running
+    /// are ignored.
+    ///
+    /// ```rust,ignore
+    /// List::for_each(feed.items(), post_row)
+    ///     .refreshable(async move || feed.reload().await)
+    /// ```
+    #[must_use]
+    pub fn refreshable(self, action: impl AsyncFn() + 'static) -> Self {
+        self.refresh_with(Refresh::new(action))
+    }
+
+    /// Sets the pull-to-refresh action of this list.
+    #[must_use]
+    pub fn refresh_with(mut self, refresh: Refresh) -> Self {
+        self.refresh = Some(refresh);
+        self
+    }
+
+    /// Calls `handler` when the user scrolls within `threshold` points of the
+    /// last row, to append the next page of a paginated feed.
+    ///
+    /// The handler fires once per content length; see [`ReachEnd`].
+    ///
+    /// ```rust,ignore
+    /// List::for_each(feed.items(), post_row)
+    ///     .on_reach_end(ReachEnd::DEFAULT_THRESHOLD, move || feed.load_next_page())
+    /// ```
+    #[must_use]
+    pub fn on_reach_end(self, threshold: f32, handler: impl Fn() + 'static) -> Self {
+        self.reach_end_with(ReachEnd::new(threshold, move |_env| handler()))
+    }
+
+    /// Sets the reach-end callback of this list.
+    #[must_use]
+    pub fn reach_end_with(mut self, reach_end: ReachEnd) -> Self {
+        self.reach_end = Some(reach_end);
+        self
+    }
+
+    /// Keeps `position` in sync with the list's scroll offset and lets it
+    /// scroll the list programmatically, where indices refer to rows.
+    ///
+    /// ```rust,ignore
+    /// let position = ScrollPosition::new();
+    /// List::for_each(messages, message_row).scroll_position(&position);
+    /// position.scroll_to_end();
+    /// ```
+    #[must_use]
+    pub fn scroll_position(mut self, position: &ScrollPosition) -> Self {
+        self.position = Some(position.clone());
+        self
+    }
+}
+
+impl List {
+    /// Creates a list whose row views are reused as items change.
+    ///
+    /// `row` receives a signal of the item instead of the item itself. Backends
+    /// that support recycling build only enough rows to fill the screen and
+    /// update the signal when a row is reused for another item, which keeps
+    /// fast-scrolling feeds cheap. A row's delete callback stays with the row
+    /// and is called with the index it shows at that time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let messages: nami::collection::List<Message> = load_messages();
+    /// List::recycling(messages.clone(), move |message: Computed<Message>| {
+    ///     let messages = messages.clone();
+    ///     ListItem::new(text(message.map(|message| message.body)))
+    ///         .on_delete(move |_env, index| messages.remove(index))
+    /// })
+    /// ```
+    pub fn recycling<C, F>(data: C, row: F) -> Self
+    where
+        C: Collection + Clone + 'static,
+        C::Item: Identifiable + Clone + 'static,
+        F: 'static + Fn(Computed<C::Item>) -> ListItem,
+    {
+        let row = Rc::new(row);
+        let recycler = {
+            let data = data.clone();
+            let row = row.clone();
+            RowRecycler(Rc::new(move |index| {
+                let item = Binding::container(data.get(index)?);
+                let ListItem { content, on_delete } = row(item.clone().computed());
+                let data = data.clone();
+                let slot = RowSlot(Box::new(move |index| {
+                    data.get(index).map(|value| item.set(value)).is_some()
+                }));
+                Some(RecycledRow {
+                    content,
+                    slot,
+                    on_delete,
+                })
+            }))
+        };
+        let contents = ForEach::new(data, move |item| row(Computed::constant(item)));
+        Self {
+            contents: AnyViews::new(contents),
+            recycler: Some(recycler),
+            prefetch: None,
+            selection: None,
+            refresh: None,
+            reach_end: None,
+            position: None,
+        }
     }
 }
 
@@ -48,8 +538,12 @@ where
     F: 'static + Fn(C::Item) -> ListItem,
 {
     /// Creates a new list by iterating over a collection and generating items.
+    ///
+    /// Backends without a native list build every row up front; for feeds
+    /// with thousands of rows, [`LazyVStack`](waterui_layout::LazyVStack)
+    /// only builds the rows on screen.
     pub const fn for_each(data: C, generator: F) -> Self {
-        Self(ForEach::new(data, generator))
+        Self::new(ForEach::new(data, generator))
     }
 }
 
@@ -60,8 +554,15 @@ where
     type Config = ListConfig;
 
     fn config(self) -> Self::Config {
+        let contents = Rc::new(self.contents);
         ListConfig {
-            contents: SharedAnyViews::new(self.0),
+            contents: SharedAnyViews::new(SharedViews(contents.clone())),
+            recycler: self.recycler,
+            prefetch: self.prefetch,
+            selection: self.selection.map(|selection| selection.bind(&contents)),
+            refresh: self.refresh,
+            reach_end: self.reach_end,
+            position: self.position,
         }
     }
 }
@@ -70,7 +571,15 @@ impl ViewConfiguration for ListConfig {
     type View = List<SharedAnyViews<ListItem>>;
 
     fn render(self) -> Self::View {
-        List::new(self.contents)
+        List {
+            contents: self.contents,
+            recycler: self.recycler,
+            prefetch: self.prefetch,
+            selection: self.selection.map(Selection::Bound),
+            refresh: self.refresh,
+            reach_end: self.reach_end,
+            position: self.position,
+        }
     }
 }
 
@@ -91,8 +600,20 @@ where
             return AnyView::new(hook.apply(env, config));
         }
         // Native backend can catch ListConfig, otherwise falls back to Lazy::vstack
-        let fallback =
+        let mut fallback =
             crate::component::lazy::Lazy::vstack(config.contents.clone().map(|item| item.content));
+        if let Some(prefetch) = config.prefetch.clone() {
+            fallback = fallback.prefetch_with(prefetch);
+        }
+        if let Some(refresh) = config.refresh.clone() {
+            fallback = fallback.refresh_with(refresh);
+        }
+        if let Some(reach_end) = config.reach_end.clone() {
+            fallback = fallback.reach_end_with(reach_end);
+        }
+        if let Some(position) = &config.position {
+            fallback = fallback.scroll_position(position);
+        }
         AnyView::new(Native::new(config).with_fallback(fallback))
     }
 }
@@ -118,6 +639,15 @@ type OnDelete = Box<dyn Fn(&Environment, usize)>;
 impl_debug!(ListItem);
 
 impl ListItem {
+    /// Creates an item showing `content`, without a delete callback.
+    #[must_use]
+    pub fn new(content: impl View) -> Self {
+        Self {
+            content: AnyView::new(content),
+            on_delete: None,
+        }
+    }
+
     /// Sets a callback function to be executed when the item is deleted.
     ///
     /// # Arguments
@@ -135,3 +665,149 @@ impl ListItem {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::cell::{Cell, RefCell};
+
+    use nami::binding;
+    use waterui_core::id::SelfId;
+
+    use super::*;
+
+    type Rows = Rc<RefCell<Vec<Computed<SelfId<i32>>>>>;
+    type Contacts = List<ForEach<Vec<SelfId<i32>>, fn(SelfId<i32>) -> ListItem, ListItem>>;
+
+    fn recycler(values: &[i32], rows: &Rows) -> RowRecycler {
+        let data: Vec<_> = values.iter().copied().map(SelfId::new).collect();
+        let rows = rows.clone();
+        let list = List::recycling(data, move |item| {
+            rows.borrow_mut().push(item);
+            ListItem::new(())
+        });
+        list.recycler.expect("recycling lists carry a recycler")
+    }
+
+    #[test]
+    fn rebind_shows_new_item() {
+        let rows = Rows::default();
+        let row = recycler(&[1, 2, 3], &rows).make_row(0).unwrap();
+        assert_eq!(*rows.borrow()[0].get(), 1);
+
+        assert!(row.slot.rebind(2));
+        assert_eq!(*rows.borrow()[0].get(), 3);
+        assert_eq!(rows.borrow().len(), 1);
+    }
+
+    #[test]
+    fn out_of_bounds_indices_are_rejected() {
+        let rows = Rows::default();
+        let recycler = recycler(&[1, 2], &rows);
+        assert!(recycler.make_row(2).is_none());
+
+        let row = recycler.make_row(1).unwrap();
+        assert!(!row.slot.rebind(2));
+        assert_eq!(*rows.borrow()[0].get(), 2);
+    }
+
+    #[test]
+    fn on_delete_survives_rebind() {
+        let deleted = Rc::new(Cell::new(None));
+        let list = {
+            let deleted = deleted.clone();
+            List::recycling(vec![SelfId::new(1), SelfId::new(2)], move |item| {
+                let deleted = deleted.clone();
+                ListItem::new(()).on_delete(move |_env, _index| deleted.set(Some(item.get())))
+            })
+        };
+        let row = list.recycler.unwrap().make_row(0).unwrap();
+        assert!(row.slot.rebind(1));
+
+        // The callback deletes the item the row shows after rebinding
+        let on_delete = row.on_delete.expect("rows keep their delete callback");
+        on_delete(&Environment::new(), 1);
+        assert_eq!(deleted.get(), Some(SelfId::new(2)));
+    }
+
+    fn contacts() -> Vec<SelfId<i32>> {
+        vec![SelfId::new(10), SelfId::new(20), SelfId::new(30)]
+    }
+
+    fn selection_of(list: Contacts) -> ListSelection {
+        list.config()
+            .selection
+            .expect("selectable lists carry a selection")
+    }
+
+    fn row(_contact: SelfId<i32>) -> ListItem {
+        ListItem::new(())
+    }
+
+    fn list() -> Contacts {
+        List::for_each(contacts(), row as fn(_) -> _)
+    }
+
+    #[test]
+    fn single_selection_replaces_selected_row() {
+        let selected = binding(None);
+        let selection = selection_of(list().selectable(&selected));
+        assert_eq!(selection.mode(), SelectionMode::Single);
+
+        selection.select(0);
+        selection.select(2);
+        assert_eq!(selected.get(), Some(30));
+        assert!(!selection.is_selected(0));
+        assert!(selection.is_selected(2));
+
+        selection.deselect(0);
+        assert_eq!(selected.get(), Some(30));
+        selection.deselect(2);
+        assert_eq!(selected.get(), None);
+    }
+
+    #[test]
+    fn toggle_flips_rows() {
+        let selected = binding(Vec::new());
+        let selection = selection_of(list().multi_selectable(&selected));
+        assert_eq!(selection.mode(), SelectionMode::Multiple);
+
+        selection.toggle(1);
+        selection.toggle(0);
+        assert_eq!(selected.get(), vec![20, 10]);
+
+        selection.toggle(1);
+        assert_eq!(selected.get(), vec![10]);
+        assert!(selection.is_selected(0));
+        assert!(!selection.is_selected(1));
+    }
+
+    #[test]
+    fn clear_deselects_every_row() {
+        let single = binding(Some(20));
+        selection_of(list().selectable(&single)).clear();
+        assert_eq!(single.get(), None);
+
+        let multiple = binding(vec![10, 30]);
+        let selection = selection_of(list().multi_selectable(&multiple));
+        selection.clear();
+        assert!(multiple.get().is_empty());
+        assert!(!selection.is_selected(0));
+    }
+
+    #[test]
+    fn out_of_bounds_selection_is_ignored() {
+        let single = binding(Some(10));
+        let selection = selection_of(list().selectable(&single));
+        selection.select(3);
+        selection.toggle(3);
+        assert_eq!(single.get(), Some(10));
+        assert!(!selection.is_selected(3));
+
+        let multiple = binding(vec![20]);
+        let selection = selection_of(list().multi_selectable(&multiple));
+        selection.select(3);
+        selection.deselect(3);
+        assert_eq!(multiple.get(), vec![20]);
+        assert!(!selection.is_selected(3));
+    }
+}
@@ -5,7 +5,9 @@
 #[doc(inline)]
 pub use waterui_controls::*;
 
+pub mod async_link;
 pub mod badge;
+pub mod compose;
 pub mod focus;
 pub mod lazy;
 pub mod link;
@@ -15,6 +17,7 @@ pub mod progress;
 #[doc(inline)]
 pub use progress::{Progress, loading, progress};
 
+pub mod swiftui;
 pub mod table;
 
 #[doc(inline)]
@@ -24,14 +27,17 @@ pub use media::*;
 pub use text::{Text, text::text};
 //pub use waterui_canvas as canvas;
 #[doc(inline)]
-pub use link::{Link, link};
+pub use async_link::AsyncNavigationLink;
+#[doc(inline)]
+pub use link::{Link, link, open_url};
 #[doc(inline)]
 pub use waterui_form as form;
 #[doc(inline)]
 pub use waterui_layout::{
     scroll::{self, ScrollView, scroll},
     spacer::{self, Spacer, spacer, spacer_min},
-    stack::{self, HStack, VStack, ZStack, hstack, vstack, zstack},
+    spacing::{self, Spacing},
+    stack::{self, FlowStack, HStack, VStack, ZStack, flow_stack, hstack, vstack, zstack},
 };
 #[doc(inline)]
 pub use waterui_media as media;
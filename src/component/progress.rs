@@ -22,9 +22,9 @@
 //! ```
 
 use crate::AnyView;
-use crate::SignalExt;
 use crate::ViewExt;
 use nami::Computed;
+use nami::SignalExt;
 use nami::signal::IntoComputed;
 use nami::zip::FlattenMap;
 use waterui_core::View;
@@ -148,11 +148,11 @@ impl Progress {
     ///
     /// * `value` - The progress value between 0.0 and 1.0.
     pub fn new(value: impl IntoComputed<f64>) -> Self {
-        let value = value.into_signal();
+        let value = value.into_signal().computed();
         Self(ProgressConfig {
             label: text!("Please wait...").anyview(),
-            value_label: text!("{value:.2} %").anyview(),
-            value: value.computed(),
+            value_label: text!("{:.2} %", value.clone()).anyview(),
+            value,
             style: ProgressStyle::Linear,
         })
     }
@@ -0,0 +1,122 @@
+//! Hosting SwiftUI content on Apple platforms.
+//!
+//! [`SwiftUIView`] embeds a SwiftUI view supplied by the app's Swift code, so
+//! apps that already have SwiftUI screens can adopt `WaterUI` incrementally.
+//! At startup the Swift side registers every identifier it can build through
+//! `waterui_register_swiftui_view`, keeping the factory itself in Swift. The
+//! Apple backend then builds the registered view, hands it the parameters (a
+//! string that is usually JSON) and updates it whenever they change.
+//!
+//! When the identifier was never registered, for example on other platforms,
+//! the view's [fallback](SwiftUIView::fallback) is shown instead, or nothing
+//! if none was set.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use waterui::component::swiftui::SwiftUIView;
+//! use waterui::prelude::*;
+//!
+//! let settings = SwiftUIView::new("SettingsScreen")
+//!     .params(r#"{"section":"privacy"}"#)
+//!     .fallback(text!("Settings are only available on Apple platforms"));
+//! ```
+
+use alloc::{collections::BTreeSet, string::String};
+use core::cell::RefCell;
+
+use nami::{Computed, signal::IntoComputed};
+use waterui_core::{AnyView, Environment, Native, NativeView, View, layout::StretchAxis};
+use waterui_str::Str;
+
+thread_local! {
+    static REGISTERED: RefCell<BTreeSet<String>> = const { RefCell::new(BTreeSet::new()) };
+}
+
+/// Records that the Swift side can build the view registered as `identifier`.
+///
+/// Backends call this through `waterui_register_swiftui_view` before the
+/// first [`SwiftUIView`] with that identifier is built, on the same thread.
+pub fn register(identifier: &str) {
+    REGISTERED.with_borrow_mut(|registered| registered.insert(identifier.into()));
+}
+
+/// Returns `true` if a SwiftUI view was registered as `identifier`.
+#[must_use]
+pub fn is_registered(identifier: &str) -> bool {
+    REGISTERED.with_borrow(|registered| registered.contains(identifier))
+}
+
+/// Configuration for a [`SwiftUIView`], read by the Apple backend.
+#[derive(Debug)]
+pub struct SwiftUIConfig {
+    /// Identifier under which the app's Swift code registered the view.
+    pub identifier: Str,
+    /// Parameter payload passed to the view.
+    pub params: Computed<Str>,
+}
+
+impl NativeView for SwiftUIConfig {
+    fn stretch_axis(&self) -> StretchAxis {
+        StretchAxis::Both
+    }
+}
+
+/// A SwiftUI view hosted inside the view tree.
+///
+/// See the [module documentation](self) for how the Swift side supplies it.
+#[derive(Debug)]
+#[must_use]
+pub struct SwiftUIView {
+    config: SwiftUIConfig,
+    fallback: Option<AnyView>,
+}
+
+impl SwiftUIView {
+    /// Hosts the SwiftUI view registered as `identifier`, with empty parameters.
+    pub fn new(identifier: impl Into<Str>) -> Self {
+        Self {
+            config: SwiftUIConfig {
+                identifier: identifier.into(),
+                params: Computed::constant(Str::new()),
+            },
+            fallback: None,
+        }
+    }
+
+    /// Sets the parameter payload passed to the view.
+    pub fn params(mut self, params: impl IntoComputed<Str>) -> Self {
+        self.config.params = params.into_computed();
+        self
+    }
+
+    /// Sets the view shown when no SwiftUI view is registered for the identifier.
+    pub fn fallback(mut self, fallback: impl View) -> Self {
+        self.fallback = Some(AnyView::new(fallback));
+        self
+    }
+}
+
+impl View for SwiftUIView {
+    fn body(self, _env: &Environment) -> impl View {
+        let fallback = self.fallback.unwrap_or_else(|| AnyView::new(()));
+        if is_registered(&self.config.identifier) {
+            AnyView::new(Native::new(self.config).with_fallback(fallback))
+        } else {
+            fallback
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_registered_identifiers_are_hosted() {
+        assert!(!is_registered("Settings"));
+        register("Settings");
+        assert!(is_registered("Settings"));
+        assert!(!is_registered("Profile"));
+    }
+}
@@ -17,6 +17,7 @@ use core::any::type_name;
 use alloc::vec::Vec;
 use nami::{Computed, Signal, SignalExt, impl_constant, signal::IntoSignal};
 use waterui_core::{
+    availability::{Availability, Available},
     view::{ConfigurableView, Hook, ViewConfiguration},
     views::SharedAnyViews,
 };
@@ -92,6 +93,11 @@ where
     }
 }
 
+// SwiftUI's `Table`, used by the Apple backend, needs iOS 16 and macOS 12.
+impl<Col> Available for Table<Col> {
+    const AVAILABILITY: Availability = Availability::ALL.ios(16, 0).macos(12, 0);
+}
+
 // Tip: no reactivity here
 impl FromIterator<TableColumn> for Table<Vec<TableColumn>> {
     fn from_iter<T: IntoIterator<Item = TableColumn>>(iter: T) -> Self {
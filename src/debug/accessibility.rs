@@ -0,0 +1,204 @@
+//! An audit of the accessibility labels in a view tree.
+
+use core::{any::type_name, fmt};
+
+use nami::Signal;
+use waterui_controls::button::ButtonConfig;
+use waterui_core::{AnyView, Environment, IgnorableMetadata, Metadata, Native, Retain, Str, View};
+use waterui_layout::{ScrollView, container::FixedContainer};
+use waterui_media::{async_image::AsyncImageConfig, photo::PhotoConfig};
+use waterui_text::TextConfig;
+
+use crate::accessibility::{
+    AccessibilityHeading, AccessibilityLabel, AccessibilityLandmark, AccessibilityRole,
+};
+
+/// A view that screen readers cannot describe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingLabel {
+    /// The type of the view.
+    pub view: &'static str,
+    /// The index of the view within each container on the way to it.
+    pub path: Vec<usize>,
+}
+
+impl fmt::Display for MissingLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` at {:?} has no accessibility label",
+            self.view, self.path
+        )
+    }
+}
+
+/// Lists the images and buttons in `view` that have neither text nor an
+/// accessibility label.
+///
+/// The audit renders `view` once in `env`, so only the content showing right
+/// now is checked. Views the audit does not know how to look into, such as
+/// dynamic content and views handled by the renderer, are skipped.
+#[must_use]
+pub fn audit_accessibility(env: &Environment, view: impl View) -> Vec<MissingLabel> {
+    let mut missing = Vec::new();
+    audit(env, AnyView::new(view), &mut Vec::new(), &mut missing);
+    missing
+}
+
+/// What the audit found at a view.
+enum Node {
+    /// Nothing to check.
+    Leaf,
+    /// A view with an accessibility label.
+    Labeled,
+    /// Text, which is read out as is.
+    Text { empty: bool },
+    /// A button, described by its label.
+    Button(AnyView),
+    /// An image, which has nothing to read out.
+    Image(&'static str),
+    /// Views rendered in place of this one.
+    Content(Vec<AnyView>, Environment),
+}
+
+fn audit(env: &Environment, view: AnyView, path: &mut Vec<usize>, missing: &mut Vec<MissingLabel>) {
+    let name = view.name();
+    match inspect(env, view) {
+        Node::Button(label) => {
+            if !has_text(env, label) {
+                missing.push(MissingLabel {
+                    view: name,
+                    path: path.clone(),
+                });
+            }
+        }
+        Node::Image(view) => missing.push(MissingLabel {
+            view,
+            path: path.clone(),
+        }),
+        Node::Content(children, env) => {
+            let nested = children.len() > 1;
+            for (index, child) in children.into_iter().enumerate() {
+                if nested {
+                    path.push(index);
+                }
+                audit(&env, child, path, missing);
+                if nested {
+                    path.pop();
+                }
+            }
+        }
+        Node::Leaf | Node::Labeled | Node::Text { .. } => {}
+    }
+}
+
+/// Returns whether screen readers have something to read out for `view`.
+fn has_text(env: &Environment, view: AnyView) -> bool {
+    match inspect(env, view) {
+        Node::Labeled => true,
+        Node::Text { empty } => !empty,
+        Node::Button(label) => has_text(env, label),
+        Node::Content(children, env) => children.into_iter().any(|child| has_text(&env, child)),
+        Node::Leaf | Node::Image(_) => false,
+    }
+}
+
+fn inspect(env: &Environment, view: AnyView) -> Node {
+    if view.is::<()>() {
+        return Node::Leaf;
+    }
+    if view.is::<IgnorableMetadata<AccessibilityLabel>>() {
+        return Node::Labeled;
+    }
+    let view = match view.downcast::<Native<TextConfig>>() {
+        Ok(text) => {
+            let empty = text.into_inner().content.get().to_plain().is_empty();
+            return Node::Text { empty };
+        }
+        Err(view) => view,
+    };
+    let view = match view.downcast::<Native<Str>>() {
+        Ok(label) => {
+            let empty = label.into_inner().is_empty();
+            return Node::Text { empty };
+        }
+        Err(view) => view,
+    };
+    let view = match view.downcast::<Native<ButtonConfig>>() {
+        Ok(button) => return Node::Button(button.into_inner().label),
+        Err(view) => view,
+    };
+    if view.is::<Native<PhotoConfig>>() || view.is::<Native<AsyncImageConfig>>() {
+        return Node::Image(view.name());
+    }
+    let view = match view.downcast::<FixedContainer>() {
+        Ok(container) => return Node::Content(container.into_inner().1, env.clone()),
+        Err(view) => view,
+    };
+    let view = match view.downcast::<ScrollView>() {
+        Ok(scroll) => return Node::Content(vec![scroll.into_inner().1], env.clone()),
+        Err(view) => view,
+    };
+    let view = match view.downcast::<Metadata<Environment>>() {
+        Ok(metadata) => return Node::Content(vec![metadata.content], metadata.value),
+        Err(view) => view,
+    };
+    let view = match unwrap_annotations(view) {
+        Ok(content) => return Node::Content(vec![content], env.clone()),
+        Err(view) => view,
+    };
+    // Rendering other native views and metadata is up to the backend
+    if same_generic(view.name(), type_name::<Native<TextConfig>>())
+        || same_generic(view.name(), type_name::<Metadata<Retain>>())
+    {
+        return Node::Leaf;
+    }
+    Node::Content(vec![AnyView::new(view.body(env))], env.clone())
+}
+
+/// Returns the content of accessibility annotations that do not label it.
+fn unwrap_annotations(view: AnyView) -> Result<AnyView, AnyView> {
+    let view = match view.downcast::<IgnorableMetadata<AccessibilityRole>>() {
+        Ok(metadata) => return Ok(metadata.content),
+        Err(view) => view,
+    };
+    let view = match view.downcast::<IgnorableMetadata<AccessibilityHeading>>() {
+        Ok(metadata) => return Ok(metadata.content),
+        Err(view) => view,
+    };
+    match view.downcast::<IgnorableMetadata<AccessibilityLandmark>>() {
+        Ok(metadata) => Ok(metadata.content),
+        Err(view) => Err(view),
+    }
+}
+
+/// Returns whether two type names are instances of the same generic type.
+fn same_generic(a: &str, b: &str) -> bool {
+    a.split('<').next() == b.split('<').next()
+}
+
+#[cfg(test)]
+mod tests {
+    use waterui_controls::button::button;
+    use waterui_layout::stack::vstack;
+    use waterui_media::photo::Photo;
+
+    use super::*;
+    use crate::{ViewExt, accessibility::HeadingLevel};
+
+    #[test]
+    fn reports_unlabeled_images_and_icon_buttons() {
+        let env = Environment::new();
+        let photo = || Photo::new("https://example.com/cat.png");
+        let view = vstack((
+            button("Save"),
+            button(photo()),
+            button(photo()).a11y_label("Share"),
+            photo().accessibility_heading(HeadingLevel::H1),
+        ));
+
+        let missing = audit_accessibility(&env, view);
+        let paths: Vec<_> = missing.iter().map(|issue| issue.path.clone()).collect();
+        assert_eq!(paths, [vec![1], vec![3]]);
+    }
+}
@@ -1,6 +1,6 @@
 //! WebSocket connection to the CLI hot reload server.
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use futures::FutureExt;
 use serde::{Deserialize, Serialize};
@@ -96,6 +96,11 @@ impl CliReceiver {
                     if text == "building" {
                         return Some(CliEvent::Building);
                     }
+                    if let Some(error) = text.strip_prefix("build_failed\n") {
+                        return Some(CliEvent::BuildFailed {
+                            error: error.to_string(),
+                        });
+                    }
                     // Ignore other text messages
                 }
                 Ok(Some(
@@ -119,6 +124,12 @@ pub enum CliEvent {
     /// Used to show immediate feedback to the user.
     Building,
 
+    /// The rebuild failed; the app keeps running the previous library.
+    BuildFailed {
+        /// Compiler errors, formatted for display.
+        error: String,
+    },
+
     /// A new hot reload library binary is ready.
     HotReload {
         /// The raw binary data of the dynamic library.
@@ -166,6 +166,12 @@ async fn run_hot_reload_loop(
                     mgr.show(StatusOverlay::reloading());
                 }
             }
+            CliEvent::BuildFailed { error } => {
+                // Keep the current views running and show what went wrong
+                if let Some(ref mgr) = overlay_manager {
+                    mgr.show(StatusOverlay::build_failed(error, mgr.clone()));
+                }
+            }
             CliEvent::HotReload { binary } => {
                 // Create a temp file with the library data
                 let path = library::create_library(&binary).await;
@@ -378,6 +384,12 @@ impl<V: View> View for Hotreload<V> {
                             mgr.show(StatusOverlay::reloading());
                         }
                     }
+                    CliEvent::BuildFailed { error } => {
+                        // Keep the current view running and show what went wrong
+                        if let Some(ref mgr) = overlay_manager {
+                            mgr.show(StatusOverlay::build_failed(error, mgr.clone()));
+                        }
+                    }
                     CliEvent::HotReload { binary } => {
                         // Create a temp file with the library data
                         let path = library::create_library(&binary).await;
@@ -466,6 +478,21 @@ impl StatusOverlay {
         .background(Color::srgb_f32(0.6, 0.1, 0.1).with_opacity(0.95))
     }
 
+    /// Compiler errors of a failed rebuild, shown over the still running app.
+    fn build_failed(errors: String, overlay_manager: FullScreenOverlayManager) -> impl View {
+        vstack((
+            text("Build Failed"),
+            text("The app keeps running the last successful build."),
+            scroll(text(errors).size(12.0)).max_height(360.0),
+            button(text("Dismiss")).action(move || {
+                overlay_manager.hide();
+            }),
+        ))
+        .spacing(12.0)
+        .padding_with(16.0)
+        .background(Color::srgb_f32(0.6, 0.1, 0.1).with_opacity(0.95))
+    }
+
     /// Common container for status overlays - centered with semi-transparent background
     fn overlay_container(content: impl View) -> impl View {
         zstack((
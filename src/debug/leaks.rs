@@ -0,0 +1,21 @@
+//! Leak reporting on top of the live object counters.
+
+use waterui_core::stats::live_counts;
+
+/// Logs a warning for every type that still has live objects.
+///
+/// Call this after the app has torn down its windows: anything still alive at
+/// that point was leaked, usually by a binding captured in one of its own
+/// watchers. Returns `true` if anything leaked.
+pub fn report_leaks() -> bool {
+    let leaks = live_counts();
+    for leak in &leaks {
+        tracing::warn!(
+            "Leaked {} live {:?} object(s) of type `{}`",
+            leak.count,
+            leak.kind,
+            leak.name
+        );
+    }
+    !leaks.is_empty()
+}
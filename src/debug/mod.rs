@@ -22,18 +22,40 @@
 //! - [`CliConnection`] - WebSocket connection to CLI, implements `Stream<Item = CliEvent>`
 //! - [`CliEvent`] - Events from CLI: library updates, config changes, connection status
 //! - [`Hotreload`] - View wrapper that handles hot reload lifecycle
+//! - [`stats`] and [`report_leaks`] - Live view, binding, watcher and FFI handle counters
+//! - [`enable_strict_mode`] - Runtime checks for writes during `body()` and slow watchers
+//! - [`retained_bindings`] - Bindings kept alive for weak references, per view
+//! - [`TimeTravel`] - Binding history with a timeline scrubber to restore earlier states
+//! - [`audit_accessibility`] - Images and buttons screen readers cannot describe
 
+mod accessibility;
 pub mod connection;
 pub mod event;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod hot_reload;
+mod leaks;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod library;
+mod time_travel;
 
+pub use accessibility::{MissingLabel, audit_accessibility};
 pub use connection::CliConnection;
 pub use event::{CliEvent, ConnectionError};
 #[cfg(not(target_arch = "wasm32"))]
 pub use hot_reload::{HotReloadView, Hotreload};
+pub use leaks::report_leaks;
+pub use time_travel::{Snapshot, TimeTravel};
+pub use waterui_core::stats::{Kind, LiveCount, Stats, live_counts, stats};
+pub use waterui_core::strict;
+
+pub use crate::weak::{RetainedBindings, retained_bindings};
+
+/// Enables [`strict`] mode on the current thread, logging violations as warnings.
+pub fn enable_strict_mode() {
+    strict::enable(
+        strict::StrictMode::new().on_violation(|violation| tracing::warn!("{violation}")),
+    );
+}
 
 /// Entry point macro for hot-reloadable views.
 #[macro_export]
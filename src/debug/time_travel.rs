@@ -0,0 +1,356 @@
+//! Time-travel debugging for binding values.
+//!
+//! A [`TimeTravel`] recorder snapshots the serialized values of the bindings
+//! it tracks every time one of them changes. Restoring a snapshot writes the
+//! recorded values back, so a UI state reported by a tester can be stepped
+//! through and reproduced. [`TimeTravel::overlay`] adds a timeline scrubber
+//! on top of the app for doing this on the device.
+//!
+//! ```ignore
+//! let recorder = TimeTravel::new();
+//! recorder.track("query", &query);
+//! recorder.track("filters", &filters);
+//! recorder.overlay(search_screen(&query, &filters))
+//! ```
+//!
+//! Tracked values must implement `Serialize` and `Deserialize`. Values that
+//! fail to serialize are left out of the snapshot and are not restored.
+
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    format,
+    rc::{Rc, Weak},
+    vec::Vec,
+};
+use core::{
+    cell::{Cell, RefCell},
+    fmt,
+};
+
+use nami::{Binding, Computed, Signal, SignalExt, watcher::BoxWatcherGuard};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use waterui_core::{Str, View, impl_extractor};
+use waterui_text::styled::StyledStr;
+
+use crate::prelude::*;
+
+/// Recorded values of all tracked bindings after one change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// Name of the binding whose change was recorded.
+    pub changed: Str,
+    /// Serialized value of each tracked binding, by name.
+    pub values: BTreeMap<Str, Value>,
+}
+
+struct Track {
+    name: Str,
+    read: Box<dyn Fn() -> Option<Value>>,
+    write: Box<dyn Fn(&Value)>,
+    _guard: BoxWatcherGuard,
+}
+
+struct Recorder {
+    tracks: RefCell<Vec<Track>>,
+    history: Binding<Vec<Snapshot>>,
+    position: Binding<usize>,
+    restoring: Cell<bool>,
+    capacity: Cell<usize>,
+}
+
+impl Recorder {
+    fn record(&self, changed: Str) {
+        if self.restoring.get() {
+            return;
+        }
+        let values = self
+            .tracks
+            .borrow()
+            .iter()
+            .filter_map(|track| Some((track.name.clone(), (track.read)()?)))
+            .collect();
+        let position = self.position.get();
+        let capacity = self.capacity.get().max(1);
+        let mut len = 0;
+        self.history.with_mut(|history| {
+            // A change after scrubbing back starts a new branch
+            history.truncate(position + 1);
+            history.push(Snapshot { changed, values });
+            if history.len() > capacity {
+                let excess = history.len() - capacity;
+                history.drain(..excess);
+            }
+            len = history.len();
+        });
+        self.position.set(len - 1);
+    }
+}
+
+/// Records binding values over time and restores earlier states.
+///
+/// Clones share the same timeline. Only available in debug builds.
+#[derive(Clone)]
+pub struct TimeTravel(Rc<Recorder>);
+
+impl_extractor!(TimeTravel);
+
+impl fmt::Debug for TimeTravel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TimeTravel")
+            .field("tracks", &self.0.tracks.borrow().len())
+            .field("snapshots", &self.0.history.get().len())
+            .field("position", &self.0.position.get())
+            .finish()
+    }
+}
+
+impl Default for TimeTravel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeTravel {
+    /// Creates a recorder that keeps the last 500 snapshots.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Rc::new(Recorder {
+            tracks: RefCell::new(Vec::new()),
+            history: Binding::container(Vec::new()),
+            position: Binding::container(0),
+            restoring: Cell::new(false),
+            capacity: Cell::new(500),
+        }))
+    }
+
+    /// Sets how many snapshots are kept before the oldest are dropped.
+    #[must_use]
+    pub fn capacity(self, capacity: usize) -> Self {
+        self.0.capacity.set(capacity);
+        self
+    }
+
+    /// Records `binding` under `name` from now on.
+    ///
+    /// Tracking a binding records a snapshot with its current value.
+    pub fn track<T>(&self, name: impl Into<Str>, binding: &Binding<T>)
+    where
+        T: Serialize + DeserializeOwned + Clone + 'static,
+    {
+        let name = name.into();
+        let recorder: Weak<Recorder> = Rc::downgrade(&self.0);
+        let changed = name.clone();
+        let guard = binding.watch(move |_| {
+            if let Some(recorder) = recorder.upgrade() {
+                recorder.record(changed.clone());
+            }
+        });
+
+        let read = {
+            let binding = binding.clone();
+            let name = name.clone();
+            move || match serde_json::to_value(binding.get()) {
+                Ok(value) => Some(value),
+                Err(error) => {
+                    tracing::warn!("Time travel cannot record `{name}`: {error}");
+                    None
+                }
+            }
+        };
+        let write = {
+            let binding = binding.clone();
+            let name = name.clone();
+            move |value: &Value| match serde_json::from_value::<T>(value.clone()) {
+                Ok(value) => binding.set(value),
+                Err(error) => tracing::warn!("Time travel cannot restore `{name}`: {error}"),
+            }
+        };
+
+        self.0.tracks.borrow_mut().push(Track {
+            name: name.clone(),
+            read: Box::new(read),
+            write: Box::new(write),
+            _guard: Box::new(guard),
+        });
+        self.0.record(name);
+    }
+
+    /// Returns all recorded snapshots, oldest first.
+    #[must_use]
+    pub fn snapshots(&self) -> Vec<Snapshot> {
+        self.0.history.get()
+    }
+
+    /// Returns the number of recorded snapshots.
+    #[must_use]
+    pub fn count(&self) -> Computed<usize> {
+        self.0
+            .history
+            .clone()
+            .map(|history| history.len())
+            .computed()
+    }
+
+    /// Returns the index of the snapshot currently shown.
+    #[must_use]
+    pub fn position(&self) -> Computed<usize> {
+        self.0.position.clone().computed()
+    }
+
+    /// Writes the values of the snapshot at `index` back to their bindings.
+    ///
+    /// The restored state is not recorded. The next change after restoring
+    /// discards the snapshots after `index`.
+    pub fn restore(&self, index: usize) {
+        let history = self.0.history.get();
+        let Some(snapshot) = history.as_slice().get(index) else {
+            return;
+        };
+        self.0.restoring.set(true);
+        for track in self.0.tracks.borrow().iter() {
+            if let Some(value) = snapshot.values.get(&track.name) {
+                (track.write)(value);
+            }
+        }
+        self.0.restoring.set(false);
+        self.0.position.set(index);
+    }
+
+    /// Discards all snapshots and records the current state as the first one.
+    pub fn clear(&self) {
+        self.0.history.set(Vec::new());
+        self.0.position.set(0);
+        self.0.record(Str::from("clear"));
+    }
+
+    /// Shows `content` with a timeline scrubber for the recorded snapshots.
+    pub fn overlay(&self, content: impl View) -> impl View {
+        content.overlay(vstack((spacer(), self.scrubber())))
+    }
+
+    fn scrubber(&self) -> impl View {
+        let this = self.clone();
+        // The slider works on a 0..=1 range because the number of snapshots changes
+        let progress = Binding::container(1.0_f64);
+        let syncing = Rc::new(Cell::new(false));
+
+        let from_slider = {
+            let this = this.clone();
+            let syncing = syncing.clone();
+            progress.watch(move |context| {
+                if syncing.get() {
+                    return;
+                }
+                let last = this.0.history.get().len().saturating_sub(1);
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_sign_loss,
+                    clippy::cast_precision_loss
+                )]
+                let index = (context.into_value() * last as f64).round() as usize;
+                if index != this.0.position.get() {
+                    this.restore(index);
+                }
+            })
+        };
+        let to_slider = {
+            let this = this.clone();
+            let progress = progress.clone();
+            self.0.position.watch(move |context| {
+                let last = this.0.history.get().len().saturating_sub(1);
+                #[allow(clippy::cast_precision_loss)]
+                let value = if last == 0 {
+                    1.0
+                } else {
+                    context.into_value() as f64 / last as f64
+                };
+                syncing.set(true);
+                progress.set(value);
+                syncing.set(false);
+            })
+        };
+
+        let label: Computed<StyledStr> = self
+            .0
+            .position
+            .clone()
+            .zip(self.0.history.clone())
+            .map(|(position, history)| {
+                let changed = history
+                    .as_slice()
+                    .get(position)
+                    .map(|snapshot| snapshot.changed.clone())
+                    .unwrap_or_default();
+                StyledStr::from(Str::from(format!(
+                    "Step {} of {} · {changed}",
+                    position + 1,
+                    history.len()
+                )))
+            })
+            .computed();
+
+        vstack((
+            hstack((
+                text("Time Travel"),
+                spacer(),
+                button(text("Latest")).action(move || {
+                    let last = this.0.history.get().len().saturating_sub(1);
+                    this.restore(last);
+                }),
+            )),
+            text(label).size(12.0),
+            waterui_controls::slider::slider(0.0..=1.0, &progress),
+        ))
+        .spacing(8.0)
+        .padding_with(12.0)
+        .background(Color::srgb_f32(0.1, 0.1, 0.1).with_opacity(0.9))
+        .retain((from_slider, to_slider))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restores_recorded_values() {
+        let recorder = TimeTravel::new();
+        let count = Binding::container(0_i32);
+        let name = Binding::container(alloc::string::String::from("a"));
+        recorder.track("count", &count);
+        recorder.track("name", &name);
+
+        count.set(1);
+        name.set("b".into());
+        count.set(2);
+        assert_eq!(recorder.count().get(), 5);
+        assert_eq!(recorder.position().get(), 4);
+
+        recorder.restore(2);
+        assert_eq!(count.get(), 1);
+        assert_eq!(name.get(), "a");
+        assert_eq!(recorder.count().get(), 5);
+
+        // Changing state after scrubbing back drops the later snapshots
+        count.set(7);
+        assert_eq!(recorder.count().get(), 4);
+        assert_eq!(recorder.snapshots()[3].values["count"], 7);
+        assert_eq!(recorder.position().get(), 3);
+    }
+
+    #[test]
+    fn keeps_the_latest_snapshots() {
+        let recorder = TimeTravel::new().capacity(2);
+        let count = Binding::container(0_i32);
+        recorder.track("count", &count);
+        for value in 1..=5 {
+            count.set(value);
+        }
+        let snapshots = recorder.snapshots();
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[0].values["count"], 4);
+        assert_eq!(snapshots[1].values["count"], 5);
+    }
+}
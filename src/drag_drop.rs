@@ -0,0 +1,294 @@
+//! Drag and drop between views and apps.
+//!
+//! [`on_drag`](crate::ViewExt::on_drag) makes a view draggable; its provider
+//! is called when a drag starts and returns the items being dragged.
+//! [`on_drop`](crate::ViewExt::on_drop) makes a view accept drops of the
+//! given [`DragType`]s, whether they come from another view or from another
+//! app on iPadOS, desktop or the web:
+//!
+//! ```ignore
+//! use waterui::drag_drop::{DragItem, DragType};
+//!
+//! let todo = text("Buy milk").on_drag(|| Some(DragItem::text("Buy milk")));
+//!
+//! let done = vstack(done_items).on_drop([DragType::Text], move |event| {
+//!     for item in event.items {
+//!         if let DragItem::Text(title) = item {
+//!             done.push(title);
+//!         }
+//!     }
+//!     true
+//! });
+//! ```
+//!
+//! Items carry text, URLs, images or arbitrary data tagged with a uniform type
+//! identifier or MIME type, which backends map to the platform's pasteboard
+//! types.
+
+use alloc::{boxed::Box, vec::Vec};
+
+use waterui_core::metadata::MetadataKey;
+use waterui_str::Str;
+
+use crate::{gesture::GesturePoint, media::Url};
+
+/// An item being dragged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DragItem {
+    /// Plain text.
+    Text(Str),
+    /// A link or file URL.
+    Url(Url),
+    /// Encoded image data, such as a PNG.
+    Image {
+        /// The MIME type of the image, such as `image/png`.
+        mime: Str,
+        /// The encoded image.
+        data: Vec<u8>,
+    },
+    /// Data of any other type.
+    Data {
+        /// The uniform type identifier or MIME type of the data, such as
+        /// `com.example.todo` or `application/json`.
+        type_id: Str,
+        /// The data.
+        data: Vec<u8>,
+    },
+}
+
+impl DragItem {
+    /// Creates a plain text item.
+    pub fn text(text: impl Into<Str>) -> Self {
+        Self::Text(text.into())
+    }
+
+    /// Creates a URL item.
+    pub fn url(url: impl Into<Url>) -> Self {
+        Self::Url(url.into())
+    }
+
+    /// Creates an image item from encoded image data.
+    pub fn image(mime: impl Into<Str>, data: impl Into<Vec<u8>>) -> Self {
+        Self::Image {
+            mime: mime.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Creates an item of a custom type.
+    pub fn data(type_id: impl Into<Str>, data: impl Into<Vec<u8>>) -> Self {
+        Self::Data {
+            type_id: type_id.into(),
+            data: data.into(),
+        }
+    }
+
+    /// Returns the type of the item.
+    #[must_use]
+    pub fn item_type(&self) -> DragType {
+        match self {
+            Self::Text(_) => DragType::Text,
+            Self::Url(_) => DragType::Url,
+            Self::Image { .. } => DragType::Image,
+            Self::Data { type_id, .. } => DragType::Custom(type_id.clone()),
+        }
+    }
+}
+
+impl From<Str> for DragItem {
+    fn from(text: Str) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&'static str> for DragItem {
+    fn from(text: &'static str) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+impl From<Url> for DragItem {
+    fn from(url: Url) -> Self {
+        Self::Url(url)
+    }
+}
+
+/// A kind of item a drop target accepts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DragType {
+    /// Plain text.
+    Text,
+    /// Link and file URLs.
+    Url,
+    /// Images of any format.
+    Image,
+    /// Items with this uniform type identifier or MIME type.
+    Custom(Str),
+}
+
+impl DragType {
+    /// Returns whether `item` is of this type.
+    ///
+    /// A custom type also matches images with that MIME type.
+    #[must_use]
+    pub fn matches(&self, item: &DragItem) -> bool {
+        match (self, item) {
+            (Self::Text, DragItem::Text(_))
+            | (Self::Url, DragItem::Url(_))
+            | (Self::Image, DragItem::Image { .. }) => true,
+            (Self::Custom(expected), DragItem::Data { type_id, .. }) => expected == type_id,
+            (Self::Custom(expected), DragItem::Image { mime, .. }) => expected == mime,
+            _ => false,
+        }
+    }
+}
+
+/// Items dropped onto a view.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropEvent {
+    /// The dropped items the target accepts, in drag order.
+    pub items: Vec<DragItem>,
+    /// Where the items were dropped, in the view's coordinate space.
+    pub location: GesturePoint,
+}
+
+type DragProvider = Box<dyn Fn() -> Vec<DragItem> + 'static>;
+
+/// Makes a view draggable.
+///
+/// Attach it with [`on_drag`](crate::ViewExt::on_drag).
+pub struct DragSource {
+    provider: DragProvider,
+}
+
+impl core::fmt::Debug for DragSource {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DragSource").finish_non_exhaustive()
+    }
+}
+
+impl MetadataKey for DragSource {}
+
+impl DragSource {
+    /// Creates a drag source whose items are returned by `provider`.
+    ///
+    /// The provider is called each time a drag starts. If it returns no
+    /// items, the view is not dragged.
+    pub fn new<I>(provider: impl Fn() -> I + 'static) -> Self
+    where
+        I: IntoIterator<Item = DragItem>,
+    {
+        Self {
+            provider: Box::new(move || provider().into_iter().collect()),
+        }
+    }
+
+    /// Returns the items for a drag that is starting.
+    #[must_use]
+    pub fn items(&self) -> Vec<DragItem> {
+        (self.provider)()
+    }
+}
+
+type DropHandler = Box<dyn FnMut(DropEvent) -> bool + 'static>;
+
+/// Makes a view accept dropped items.
+///
+/// Attach it with [`on_drop`](crate::ViewExt::on_drop).
+pub struct DropTarget {
+    accepts: Vec<DragType>,
+    handler: DropHandler,
+}
+
+impl core::fmt::Debug for DropTarget {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("DropTarget")
+            .field("accepts", &self.accepts)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MetadataKey for DropTarget {}
+
+impl DropTarget {
+    /// Creates a drop target accepting items of the given types.
+    ///
+    /// The handler returns whether it took the items, which backends use to
+    /// animate the drop or send the items back.
+    pub fn new(
+        accepts: impl IntoIterator<Item = DragType>,
+        handler: impl FnMut(DropEvent) -> bool + 'static,
+    ) -> Self {
+        Self {
+            accepts: accepts.into_iter().collect(),
+            handler: Box::new(handler),
+        }
+    }
+
+    /// Returns the types of items the target accepts.
+    #[must_use]
+    pub fn accepts(&self) -> &[DragType] {
+        &self.accepts
+    }
+
+    /// Returns whether the target accepts `item`.
+    #[must_use]
+    pub fn accepts_item(&self, item: &DragItem) -> bool {
+        self.accepts.iter().any(|ty| ty.matches(item))
+    }
+
+    /// Delivers dropped items to the handler.
+    ///
+    /// Items the target does not accept are left out. Returns `false`
+    /// without calling the handler if none are left.
+    pub fn perform(&mut self, items: Vec<DragItem>, location: GesturePoint) -> bool {
+        let items: Vec<DragItem> = items
+            .into_iter()
+            .filter(|item| self.accepts_item(item))
+            .collect();
+        if items.is_empty() {
+            return false;
+        }
+        (self.handler)(DropEvent { items, location })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, vec};
+    use core::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn drops_only_deliver_accepted_items() {
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let mut target = DropTarget::new([DragType::Text, DragType::Custom("image/png".into())], {
+            let received = received.clone();
+            move |event: DropEvent| {
+                received.borrow_mut().extend(event.items);
+                true
+            }
+        });
+        let origin = GesturePoint::new(0.0, 0.0);
+
+        assert!(!target.perform(vec![DragItem::url("https://example.com")], origin));
+        assert!(received.borrow().is_empty());
+
+        let png = DragItem::image("image/png", vec![1, 2, 3]);
+        let items = vec![
+            DragItem::text("todo"),
+            DragItem::image("image/jpeg", vec![4]),
+            png.clone(),
+        ];
+        assert!(target.perform(items, origin));
+        assert_eq!(*received.borrow(), vec![DragItem::text("todo"), png]);
+    }
+
+    #[test]
+    fn sources_provide_items_when_dragged() {
+        let source = DragSource::new(|| Some(DragItem::text("row")));
+        assert_eq!(source.items(), vec![DragItem::text("row")]);
+        assert_eq!(source.items()[0].item_type(), DragType::Text);
+    }
+}
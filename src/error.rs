@@ -3,12 +3,30 @@
 //! The types in this module let applications convert any `std::error::Error`
 //! into a `View` that can be rendered by the UI, optionally leveraging a
 //! user-supplied builder stored in the [`Environment`].
+//!
+//! # Fallible handlers
+//!
+//! Actions and gesture callbacks are infallible, but wrapping a handler that
+//! returns `Result<(), E>` in [`fallible`] or [`fallible_async`] makes it
+//! accepted anywhere a handler is. Errors go to the nearest [`ErrorPresenter`]
+//! in the environment, such as the one an [`ErrorBoundary`] installs:
+//!
+//! ```ignore
+//! use waterui::error::fallible;
+//!
+//! vstack((
+//!     text("Draft"),
+//!     button("Save").action(fallible(|store: Store| store.save())),
+//! ))
+//! .error_boundary()
+//! ```
 
+use alloc::rc::Rc;
 use core::fmt::Debug;
 
-use waterui_core::{AnyView, Environment, View};
+use waterui_core::{AnyView, Dynamic, Environment, View, env::With, handler::HandlerFn};
 
-use crate::ViewExt;
+use crate::{ViewExt, task::spawn_local};
 
 /// A `View` wrapper that renders a boxed `std::error::Error`.
 #[derive(Debug)]
@@ -63,3 +81,144 @@ impl ErrorViewBuilder {
         Self(Box::new(f))
     }
 }
+
+/// Receives errors returned by [`fallible`] and [`fallible_async`] handlers.
+///
+/// Handlers use the presenter installed closest to them in the view tree, so
+/// an app can install one that shows an alert at the root and let an
+/// [`ErrorBoundary`] take over errors from part of the tree.
+#[derive(Clone)]
+pub struct ErrorPresenter(Rc<dyn Fn(ErrorView)>);
+
+impl Debug for ErrorPresenter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ErrorPresenter").finish_non_exhaustive()
+    }
+}
+
+impl ErrorPresenter {
+    /// Creates a presenter calling `present` with each error.
+    pub fn new(present: impl Fn(ErrorView) + 'static) -> Self {
+        Self(Rc::new(present))
+    }
+
+    /// Presents `error`.
+    pub fn present(&self, error: impl Into<ErrorView>) {
+        (self.0)(error.into());
+    }
+}
+
+/// Presents `error` with the nearest [`ErrorPresenter`] in `env`.
+///
+/// Without a presenter the error is logged.
+pub fn present_error(env: &Environment, error: impl Into<ErrorView>) {
+    let error = error.into();
+    if let Some(presenter) = env.get::<ErrorPresenter>() {
+        presenter.present(error);
+    } else {
+        tracing::error!("unhandled error: {}", error.inner);
+    }
+}
+
+/// Turns a handler returning `Result` into one that presents its errors.
+///
+/// The returned handler extracts the same values from the environment, so it
+/// can be passed to button actions, gestures and any other API taking a
+/// handler.
+pub fn fallible<H, P, E>(mut handler: H) -> impl FnMut(Environment) + 'static
+where
+    H: HandlerFn<P, Result<(), E>>,
+    P: 'static,
+    E: Into<ErrorView> + 'static,
+{
+    move |env: Environment| {
+        if let Err(error) = handler.handle_inner(&env) {
+            present_error(&env, error);
+        }
+    }
+}
+
+/// Turns a handler starting a fallible task into one that presents its errors.
+///
+/// Each call spawns the returned future on the local executor and presents
+/// its error, if any, once it completes.
+pub fn fallible_async<H, P, Fut, E>(mut handler: H) -> impl FnMut(Environment) + 'static
+where
+    H: HandlerFn<P, Fut>,
+    P: 'static,
+    Fut: Future<Output = Result<(), E>> + 'static,
+    E: Into<ErrorView> + 'static,
+{
+    move |env: Environment| {
+        let task = handler.handle_inner(&env);
+        spawn_local(async move {
+            if let Err(error) = task.await {
+                present_error(&env, error);
+            }
+        })
+        .detach();
+    }
+}
+
+/// Replaces its content with the first error presented from inside it.
+///
+/// Attach it with [`error_boundary`](crate::ViewExt::error_boundary).
+#[derive(Debug)]
+pub struct ErrorBoundary<V> {
+    content: V,
+}
+
+impl<V: View> ErrorBoundary<V> {
+    /// Creates a boundary around `content`.
+    pub const fn new(content: V) -> Self {
+        Self { content }
+    }
+}
+
+impl<V: View> View for ErrorBoundary<V> {
+    fn body(self, _env: &Environment) -> impl View {
+        let (handler, dynamic) = Dynamic::new();
+        handler.set(self.content);
+        With::new(
+            dynamic,
+            ErrorPresenter::new(move |error| handler.set(error)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::RefCell;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct SaveFailed;
+
+    impl core::fmt::Display for SaveFailed {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str("save failed")
+        }
+    }
+
+    impl std::error::Error for SaveFailed {}
+
+    #[test]
+    fn fallible_handlers_present_their_errors() {
+        let presented = Rc::new(RefCell::new(Vec::new()));
+        let mut env = Environment::new();
+        env.insert(ErrorPresenter::new({
+            let presented = presented.clone();
+            move |error: ErrorView| presented.borrow_mut().push(error.inner.to_string())
+        }));
+
+        let mut succeed = fallible(|| Ok::<(), SaveFailed>(()));
+        succeed(env.clone());
+        assert!(presented.borrow().is_empty());
+
+        let mut fail = fallible(|| Err(SaveFailed));
+        fail(env.clone());
+        fail(env);
+        assert_eq!(*presented.borrow(), ["save failed", "save failed"]);
+    }
+}
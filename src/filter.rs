@@ -6,6 +6,11 @@
 //!
 //! Each filter is represented by a structure that can be configured and applied to
 //! a view to achieve the desired visual effect.
+//!
+//! [`ColorFilter`] applies a [`ColorMatrix`] to a whole subtree via
+//! [`ViewExt::color_filter`](crate::ViewExt::color_filter).
+
+use waterui_core::metadata::MetadataKey;
 
 /// A structure representing a blur filter operation.
 #[derive(Debug, Clone)]
@@ -156,3 +161,216 @@ impl Invert {
         Self { intensity }
     }
 }
+
+/// A 4×5 color matrix applied to the RGBA components of rendered content.
+///
+/// The matrix is stored row-major: each of the four rows produces one output
+/// component (red, green, blue, alpha) as a weighted sum of the input RGBA
+/// components plus a constant offset in the fifth column. Components are in
+/// the `0.0..=1.0` range, so offsets are expressed in the same units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct ColorMatrix {
+    /// Row-major matrix values.
+    pub values: [f32; 20],
+}
+
+impl Default for ColorMatrix {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl ColorMatrix {
+    /// The identity matrix, which leaves colors unchanged.
+    pub const IDENTITY: Self = Self::rgb([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+
+    /// Creates a color matrix from raw row-major values.
+    #[must_use]
+    pub const fn new(values: [f32; 20]) -> Self {
+        Self { values }
+    }
+
+    /// Creates a matrix that mixes RGB channels and leaves alpha untouched.
+    #[must_use]
+    pub const fn rgb(rows: [[f32; 3]; 3]) -> Self {
+        let [r, g, b] = rows;
+        Self::new([
+            r[0], r[1], r[2], 0.0, 0.0, //
+            g[0], g[1], g[2], 0.0, 0.0, //
+            b[0], b[1], b[2], 0.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0, 0.0,
+        ])
+    }
+
+    /// Applies this matrix to an RGBA color.
+    #[must_use]
+    pub fn apply(&self, rgba: [f32; 4]) -> [f32; 4] {
+        let mut out = [0.0; 4];
+        for (row, value) in out.iter_mut().enumerate() {
+            let m = &self.values[row * 5..row * 5 + 5];
+            *value = m[4]
+                + m.iter()
+                    .zip(rgba)
+                    .map(|(weight, component)| weight * component)
+                    .sum::<f32>();
+        }
+        out
+    }
+
+    /// Returns a matrix equivalent to applying `self` and then `next`.
+    #[must_use]
+    pub fn then(&self, next: &Self) -> Self {
+        let mut values = [0.0; 20];
+        for row in 0..4 {
+            let n = &next.values[row * 5..row * 5 + 5];
+            for col in 0..5 {
+                let mut sum = (0..4).map(|k| n[k] * self.values[k * 5 + col]).sum::<f32>();
+                if col == 4 {
+                    sum += n[4];
+                }
+                values[row * 5 + col] = sum;
+            }
+        }
+        Self::new(values)
+    }
+
+    /// Linearly blends between the identity matrix and this matrix.
+    ///
+    /// An `amount` of 0.0 leaves colors unchanged, 1.0 applies the full matrix.
+    #[must_use]
+    pub fn intensity(&self, amount: f32) -> Self {
+        let mut values = self.values;
+        for (value, identity) in values.iter_mut().zip(Self::IDENTITY.values) {
+            *value = (*value - identity).mul_add(amount, identity);
+        }
+        Self::new(values)
+    }
+}
+
+/// A color filter applied to a view and its entire subtree.
+///
+/// Besides visual effects, the color vision deficiency filters make it easy to
+/// audit an interface for accessibility, e.g. by wrapping the app root in
+/// `.color_filter(ColorFilter::Deuteranopia)` in debug builds.
+///
+/// The simulation matrices follow Machado et al. (2009) at full severity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorFilter {
+    /// Simulates red-blind color vision.
+    Protanopia,
+    /// Simulates green-blind color vision.
+    Deuteranopia,
+    /// Simulates blue-blind color vision.
+    Tritanopia,
+    /// Simulates total color blindness.
+    Achromatopsia,
+    /// Desaturates content; 0.0 means no effect, 1.0 means full grayscale.
+    Grayscale(f32),
+    /// Inverts colors; 0.0 means no effect, 1.0 means full inversion.
+    Invert(f32),
+    /// Applies a custom color matrix.
+    Matrix(ColorMatrix),
+}
+
+/// Rec. 709 luminance weights.
+const LUMINANCE: [f32; 3] = [0.2126, 0.7152, 0.0722];
+
+impl ColorFilter {
+    /// Returns the color matrix that implements this filter.
+    #[must_use]
+    pub fn matrix(&self) -> ColorMatrix {
+        match *self {
+            Self::Protanopia => ColorMatrix::rgb([
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ]),
+            Self::Deuteranopia => ColorMatrix::rgb([
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ]),
+            Self::Tritanopia => ColorMatrix::rgb([
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ]),
+            Self::Achromatopsia => ColorMatrix::rgb([LUMINANCE; 3]),
+            Self::Grayscale(intensity) => ColorMatrix::rgb([LUMINANCE; 3]).intensity(intensity),
+            Self::Invert(intensity) => ColorMatrix::new([
+                -1.0, 0.0, 0.0, 0.0, 1.0, //
+                0.0, -1.0, 0.0, 0.0, 1.0, //
+                0.0, 0.0, -1.0, 0.0, 1.0, //
+                0.0, 0.0, 0.0, 1.0, 0.0,
+            ])
+            .intensity(intensity),
+            Self::Matrix(matrix) => matrix,
+        }
+    }
+}
+
+impl From<ColorMatrix> for ColorFilter {
+    fn from(matrix: ColorMatrix) -> Self {
+        Self::Matrix(matrix)
+    }
+}
+
+impl From<Grayscale> for ColorFilter {
+    fn from(filter: Grayscale) -> Self {
+        Self::Grayscale(filter.intensity)
+    }
+}
+
+impl From<Invert> for ColorFilter {
+    fn from(filter: Invert) -> Self {
+        Self::Invert(filter.intensity)
+    }
+}
+
+impl MetadataKey for ColorFilter {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: [f32; 4], b: [f32; 4]) {
+        for (x, y) in a.iter().zip(b) {
+            assert!((x - y).abs() < 1e-4, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn simulation_matrices_preserve_white() {
+        for filter in [
+            ColorFilter::Protanopia,
+            ColorFilter::Deuteranopia,
+            ColorFilter::Tritanopia,
+            ColorFilter::Achromatopsia,
+        ] {
+            assert_close(filter.matrix().apply([1.0, 1.0, 1.0, 1.0]), [1.0; 4]);
+        }
+    }
+
+    #[test]
+    fn grayscale_and_invert_honor_intensity() {
+        let red = [1.0, 0.0, 0.0, 0.5];
+        assert_close(ColorFilter::Grayscale(0.0).matrix().apply(red), red);
+        assert_close(
+            ColorFilter::Grayscale(1.0).matrix().apply(red),
+            [0.2126, 0.2126, 0.2126, 0.5],
+        );
+        assert_close(
+            ColorFilter::Invert(1.0).matrix().apply(red),
+            [0.0, 1.0, 1.0, 0.5],
+        );
+    }
+
+    #[test]
+    fn composed_matrix_matches_sequential_application() {
+        let a = ColorFilter::Deuteranopia.matrix();
+        let b = ColorFilter::Invert(1.0).matrix();
+        let color = [0.3, 0.6, 0.9, 1.0];
+        assert_close(a.then(&b).apply(color), b.apply(a.apply(color)));
+    }
+}
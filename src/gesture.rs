@@ -50,9 +50,13 @@
 //! [`ZStack`]: crate::prelude::zstack
 //! [`overlay`]: crate::prelude::overlay
 
+use core::marker::PhantomData;
+
 use waterui_core::{
-    handler::{BoxHandler, HandlerFn, into_handler},
+    Environment,
+    handler::{BoxHandler, Handler, HandlerFn, into_handler},
     metadata::MetadataKey,
+    time::Duration,
 };
 
 /// Represents the phase of a gesture interaction.
@@ -85,6 +89,39 @@ impl GesturePoint {
     }
 }
 
+/// Keyboard modifiers held during a pointer interaction.
+///
+/// Desktop backends report them with clicks, so shared code can express
+/// interactions like shift-click range selection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ModifierKeys {
+    /// The Shift key.
+    pub shift: bool,
+    /// The Control key.
+    pub control: bool,
+    /// The Option key on Apple platforms, Alt elsewhere.
+    pub alt: bool,
+    /// The Command key on Apple platforms, the Windows or Super key elsewhere.
+    pub command: bool,
+}
+
+impl ModifierKeys {
+    /// No modifier keys held.
+    pub const NONE: Self = Self {
+        shift: false,
+        control: false,
+        alt: false,
+        command: false,
+    };
+
+    /// Returns whether no modifier key is held.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        !(self.shift || self.control || self.alt || self.command)
+    }
+}
+
 /// Event payload for tap gestures.
 ///
 /// Backends place this structure into the environment when a tap is recognised,
@@ -100,13 +137,38 @@ pub struct TapEvent {
 /// Event payload for long-press gestures.
 ///
 /// Backends insert this into the environment alongside [`Gesture::LongPress`]
-/// whenever a long-press interaction fires.
+/// while the press is held, so handlers can show how close it is to firing.
 #[derive(Debug, Clone, PartialEq)]
 pub struct LongPressEvent {
+    /// Phase of the press: [`Ended`](GesturePhase::Ended) once it was held
+    /// long enough, [`Cancelled`](GesturePhase::Cancelled) if released early.
+    pub phase: GesturePhase,
     /// Location of the press in the widget's coordinate space.
     pub location: GesturePoint,
-    /// Duration, in platform-defined time units, that the press was held.
-    pub duration: f32,
+    /// How long the press has been held.
+    pub duration: Duration,
+    /// Fraction of the required duration held so far, from 0.0 to 1.0.
+    pub progress: f32,
+}
+
+/// Event payload for click gestures.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClickEvent {
+    /// Location of the click in the widget's coordinate space.
+    pub location: GesturePoint,
+    /// Number of clicks that occurred in succession.
+    pub count: u32,
+    /// Modifier keys held while clicking.
+    pub modifiers: ModifierKeys,
+}
+
+/// Event payload for secondary-click gestures.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SecondaryClickEvent {
+    /// Location of the click in the widget's coordinate space.
+    pub location: GesturePoint,
+    /// Modifier keys held while clicking.
+    pub modifiers: ModifierKeys,
 }
 
 /// Event payload for drag gestures.
@@ -141,6 +203,78 @@ pub struct MagnificationEvent {
     pub velocity: f32,
 }
 
+/// Event payload for rotation gestures.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RotationEvent {
+    /// Phase of the rotation gesture.
+    pub phase: GesturePhase,
+    /// Focal point of the gesture.
+    pub center: GesturePoint,
+    /// Rotation, in radians, relative to the gesture start.
+    pub angle: f32,
+    /// Rate of change of the angle, in radians per second.
+    pub velocity: f32,
+}
+
+/// How the pointer moved relative to a hovered view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HoverPhase {
+    /// The pointer entered the view.
+    Entered,
+    /// The pointer moved within the view.
+    Moved,
+    /// The pointer left the view.
+    Exited,
+}
+
+/// Event payload for hover gestures.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HoverEvent {
+    /// Whether the pointer entered, moved within or left the view.
+    pub phase: HoverPhase,
+    /// Location of the pointer in the widget's coordinate space.
+    pub location: GesturePoint,
+}
+
+/// Event payload for press gestures.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PressEvent {
+    /// [`Started`](GesturePhase::Started) when the pointer goes down,
+    /// [`Ended`](GesturePhase::Ended) when it is released over the view and
+    /// [`Cancelled`](GesturePhase::Cancelled) when it leaves the view or the
+    /// system takes over the touch.
+    pub phase: GesturePhase,
+    /// Location of the pointer in the widget's coordinate space.
+    pub location: GesturePoint,
+}
+
+/// The payload of any recognised gesture.
+///
+/// Backends insert the current event into the environment before calling a
+/// [`GestureObserver`]'s action, next to the variant-specific payload type.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum GestureEvent {
+    /// A tap was recognised.
+    Tap(TapEvent),
+    /// A long press progressed, fired, or was released early.
+    LongPress(LongPressEvent),
+    /// A drag moved.
+    Drag(DragEvent),
+    /// A pinch changed its scale.
+    Magnification(MagnificationEvent),
+    /// A rotation changed its angle.
+    Rotation(RotationEvent),
+    /// A pointer entered, moved within or left the view.
+    Hover(HoverEvent),
+    /// A pointer went down on the view or was released.
+    Press(PressEvent),
+    /// The primary pointer button clicked the view.
+    Click(ClickEvent),
+    /// The secondary pointer button clicked the view.
+    SecondaryClick(SecondaryClickEvent),
+}
+
 /// Describes a tap interaction that must occur a specific number of times.
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
@@ -173,17 +307,14 @@ impl Default for TapGesture {
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub struct LongPressGesture {
-    /// The minimum duration (in time units) the press must be held.
-    pub duration: u32,
+    /// The minimum duration the press must be held.
+    pub duration: Duration,
 }
 
 impl LongPressGesture {
-    /// Creates a long-press gesture that activates after holding for `duration` time units.
-    ///
-    /// Backends decide how to interpret the unit (for example milliseconds), allowing
-    /// platform-specific gesture systems to provide consistent behaviour.
+    /// Creates a long-press gesture that activates after holding for `duration`.
     #[must_use]
-    pub const fn new(duration: u32) -> Self {
+    pub const fn new(duration: Duration) -> Self {
         Self { duration }
     }
 }
@@ -220,6 +351,13 @@ impl MagnificationGesture {
     }
 }
 
+impl Default for MagnificationGesture {
+    /// Starts at the view's natural size, a scale of 1.0.
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
 /// Describes a rotation interaction initialized with a starting angle.
 #[derive(Debug, Clone, PartialEq)]
 #[non_exhaustive]
@@ -236,6 +374,94 @@ impl RotationGesture {
     }
 }
 
+impl Default for RotationGesture {
+    /// Starts unrotated, at an angle of 0.0.
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+/// Tracks a pointer hovering over a view, on platforms that have one.
+///
+/// Touch-only platforms never report hover events.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct HoverGesture {}
+
+impl HoverGesture {
+    /// Creates a hover gesture.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Tracks whether a pointer is held down on a view.
+///
+/// Unlike a tap, which is only reported once it completes, a press reports
+/// touch-down immediately, so custom controls can show a pressed state. It
+/// does not prevent other gestures on the same view from being recognised.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct PressGesture {}
+
+impl PressGesture {
+    /// Creates a press gesture.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
+/// Describes a click of the primary pointer button that must occur a
+/// specific number of times.
+///
+/// Unlike a [`TapGesture`], a click reports the modifier keys held. Touch
+/// platforms report taps as clicks without modifiers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ClickGesture {
+    /// The number of consecutive clicks required to trigger this gesture.
+    pub count: u32,
+}
+
+impl ClickGesture {
+    /// Creates a click gesture that requires a single click to activate.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { count: 1 }
+    }
+
+    /// Creates a click gesture that requires `count` consecutive clicks to
+    /// activate, such as 2 for a double-click.
+    #[must_use]
+    pub const fn count(count: u32) -> Self {
+        Self { count }
+    }
+}
+
+impl Default for ClickGesture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Describes a click of the secondary pointer button, usually a right-click
+/// or a Control-click on macOS.
+///
+/// Platforms without a pointer never report secondary clicks.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct SecondaryClickGesture {}
+
+impl SecondaryClickGesture {
+    /// Creates a secondary-click gesture.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {}
+    }
+}
+
 /// High-level gesture descriptions that can be attached to widgets.
 ///
 /// When a backend recognises a gesture it mirrors the interaction by inserting
@@ -256,6 +482,14 @@ pub enum Gesture {
     Rotation(RotationGesture),
     /// A sequential composition of two gestures where the second runs after the first completes.
     Then(Box<Then>),
+    /// A pointer hovering over the view.
+    Hover(HoverGesture),
+    /// A pointer held down on the view.
+    Press(PressGesture),
+    /// A click of the primary pointer button.
+    Click(ClickGesture),
+    /// A click of the secondary pointer button.
+    SecondaryClick(SecondaryClickGesture),
 }
 
 /// Combines two gestures so the second runs only after the first completes.
@@ -306,7 +540,61 @@ impl_gesture! {
     (LongPressGesture, LongPress),
     (DragGesture, Drag),
     (MagnificationGesture, Magnification),
-    (RotationGesture, Rotation)
+    (RotationGesture, Rotation),
+    (HoverGesture, Hover),
+    (PressGesture, Press),
+    (ClickGesture, Click),
+    (SecondaryClickGesture, SecondaryClick)
+}
+
+/// A gesture that reports a typed value each time it changes.
+///
+/// Used by [`ViewExt::on_gesture`](crate::ViewExt::on_gesture) to hand the
+/// payload straight to the handler.
+pub trait TypedGesture: Into<Gesture> {
+    /// The payload delivered while the gesture is recognised.
+    type Value: 'static;
+
+    /// Extracts this gesture's payload from `event`, if it belongs to it.
+    fn value(event: GestureEvent) -> Option<Self::Value>;
+}
+
+macro_rules! impl_typed_gesture {
+    ($(($name:ty, $value:ty, $variant:ident)),*) => {
+        $(
+            impl TypedGesture for $name {
+                type Value = $value;
+
+                fn value(event: GestureEvent) -> Option<Self::Value> {
+                    match event {
+                        GestureEvent::$variant(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_typed_gesture! {
+    (TapGesture, TapEvent, Tap),
+    (LongPressGesture, LongPressEvent, LongPress),
+    (DragGesture, DragEvent, Drag),
+    (MagnificationGesture, MagnificationEvent, Magnification),
+    (RotationGesture, RotationEvent, Rotation),
+    (HoverGesture, HoverEvent, Hover),
+    (PressGesture, PressEvent, Press),
+    (ClickGesture, ClickEvent, Click),
+    (SecondaryClickGesture, SecondaryClickEvent, SecondaryClick)
+}
+
+/// Composed gestures report the events of every gesture they contain.
+impl TypedGesture for Gesture {
+    type Value = GestureEvent;
+
+    fn value(event: GestureEvent) -> Option<Self::Value> {
+        Some(event)
+    }
 }
 
 /// Observes a gesture and executes an action when the gesture is recognized.
@@ -332,4 +620,108 @@ impl GestureObserver {
             action: Box::new(into_handler(action)),
         }
     }
+
+    /// Creates a gesture observer that passes each of the gesture's values to `f`.
+    pub fn with_value<G: TypedGesture + 'static>(
+        gesture: G,
+        f: impl FnMut(G::Value) + 'static,
+    ) -> Self {
+        Self {
+            gesture: gesture.into(),
+            action: Box::new(ValueHandler::<G, _> {
+                f,
+                _marker: PhantomData,
+            }),
+        }
+    }
+}
+
+/// Reads the current [`GestureEvent`] from the environment and hands the
+/// gesture's payload to `f`.
+struct ValueHandler<G, F> {
+    f: F,
+    _marker: PhantomData<fn() -> G>,
+}
+
+impl<G, F> Handler<()> for ValueHandler<G, F>
+where
+    G: TypedGesture + 'static,
+    F: FnMut(G::Value) + 'static,
+{
+    fn handle(&mut self, env: &Environment) {
+        if let Some(value) = env.get::<GestureEvent>().cloned().and_then(G::value) {
+            (self.f)(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, vec::Vec};
+    use core::cell::RefCell;
+
+    use super::*;
+
+    fn drag(x: f32) -> GestureEvent {
+        GestureEvent::Drag(DragEvent {
+            phase: GesturePhase::Updated,
+            location: GesturePoint::new(x, 0.0),
+            translation: GesturePoint::new(x, 0.0),
+            velocity: GesturePoint::new(0.0, 0.0),
+        })
+    }
+
+    #[test]
+    fn typed_observers_receive_matching_payloads() {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let recorded = seen.clone();
+        let mut observer = GestureObserver::with_value(DragGesture::new(5.0), move |drag| {
+            recorded.borrow_mut().push(drag.translation.x);
+        });
+        assert_eq!(observer.gesture, Gesture::Drag(DragGesture::new(5.0)));
+
+        let mut env = Environment::new();
+        observer.action.handle(&env);
+        env.insert(drag(12.0));
+        observer.action.handle(&env);
+        env.insert(GestureEvent::Tap(TapEvent {
+            location: GesturePoint::new(0.0, 0.0),
+            count: 1,
+        }));
+        observer.action.handle(&env);
+
+        assert_eq!(*seen.borrow(), [12.0]);
+    }
+
+    #[test]
+    fn clicks_report_modifier_keys() {
+        let shift_clicks = Rc::new(RefCell::new(0));
+        let recorded = shift_clicks.clone();
+        let mut observer = GestureObserver::with_value(ClickGesture::count(2), move |click| {
+            if click.count == 2 && click.modifiers.shift {
+                *recorded.borrow_mut() += 1;
+            }
+        });
+        assert_eq!(observer.gesture, Gesture::Click(ClickGesture::count(2)));
+
+        let mut env = Environment::new();
+        let modifiers = ModifierKeys {
+            shift: true,
+            ..ModifierKeys::NONE
+        };
+        env.insert(GestureEvent::SecondaryClick(SecondaryClickEvent {
+            location: GesturePoint::new(0.0, 0.0),
+            modifiers,
+        }));
+        observer.action.handle(&env);
+        env.insert(GestureEvent::Click(ClickEvent {
+            location: GesturePoint::new(0.0, 0.0),
+            count: 2,
+            modifiers,
+        }));
+        observer.action.handle(&env);
+
+        assert!(!modifiers.is_empty());
+        assert_eq!(*shift_clicks.borrow(), 1);
+    }
 }
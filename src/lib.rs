@@ -3,14 +3,20 @@
 #![allow(clippy::future_not_send)]
 #![allow(clippy::doc_markdown)]
 extern crate alloc;
+// Lets the derive macros, which expand to `::waterui` paths, be used in this crate's tests.
+#[cfg(test)]
+extern crate self as waterui;
 #[macro_use]
 mod macros;
 pub mod background;
 pub mod component;
+pub mod drag_drop;
 /// Error handling utilities for converting standard errors into renderable views.
 pub mod error;
 pub mod filter;
 pub mod gesture;
+#[cfg(test)]
+mod macro_tests;
 /// Task management utilities and async support.
 pub mod view;
 /// Widget components for building complex UI elements.
@@ -49,13 +55,21 @@ pub mod prelude {
     pub use padding::*;
     pub use style::*;
 
-    pub use theme::{self, ColorScheme, ColorSettings, FontSettings, Theme, color as theme_color};
+    pub use theme::{
+        self, ColorScheme, ColorSettings, FontSettings, Theme, ThemeColor, color as theme_color,
+    };
 
     pub use text::{TextConfig, font, highlight, locale, styled};
 
     pub use component::link::{Link, link};
 
     pub use widget::{Card, Divider, card, suspense};
+
+    pub use animation::SpringExt;
+
+    pub use availability::if_available;
+
+    pub use weak::BindingExt;
 }
 pub use color::Color;
 pub use form::FormBuilder;
@@ -75,10 +89,10 @@ pub mod style;
 
 #[doc(inline)]
 pub use waterui_core::{
-    AnyView, Str, animation,
+    AnyView, Str, availability, display,
     env::{self, Environment},
     id::{self, Identifiable},
-    impl_extractor, raw_view, views,
+    idiom, impl_extractor, network, platform, raw_view, time, views,
 };
 
 /// Creates a reactive text component with formatted content.
@@ -105,23 +119,92 @@ macro_rules! text {
     };
 }
 
+/// Creates text translated for the current locale.
+///
+/// The key is looked up in the tables installed with
+/// [`install_translations`](text::translate::install_translations) and is
+/// shown as-is when no translation exists. Arguments fill `{name}`
+/// placeholders and may be signals; a bare identifier is shorthand for
+/// `name = name`.
+///
+/// Run `water i18n extract` to add every key used with this macro to the
+/// tables in `locales/`.
+///
+/// # Usage
+///
+/// ```rust
+/// use waterui::prelude::*;
+///
+/// let name = Binding::container("Ada");
+/// let count = Binding::container(3);
+/// let greeting = t!("Hello, {name}!", name);
+/// let unread = t!("{user} has {count} unread messages", user = name, count);
+/// let title = t!("Settings");
+/// ```
+#[macro_export]
+macro_rules! t {
+    (@value $name:ident) => {
+        $name.clone()
+    };
+    (@value $name:ident = $value:expr) => {
+        $value
+    };
+    ($key:literal $(, $name:ident $(= $value:expr)?)* $(,)?) => {
+        $crate::text::translate::translate($key)
+            $(.arg(::core::stringify!($name), $crate::t!(@value $name $(= $value)?)))*
+    };
+}
+
+/// Chooses a view by the platform the app is rendered on.
+///
+/// Arms name [`Platform`](platform::Platform) variants, joined with `|` to
+/// share a view. The platform is read from the environment at runtime, so
+/// every arm is compiled on every target; only the view of the matching arm
+/// is built. Without a `_` arm, other platforms show nothing.
+///
+/// # Usage
+///
+/// ```rust
+/// use waterui::prelude::*;
+///
+/// let back = platform! {
+///     Ios | Macos => text("Back"),
+///     Android => text("Navigate up"),
+///     _ => text("Return"),
+/// };
+/// ```
+#[macro_export]
+macro_rules! platform {
+    ($($($platform:ident)|+ => $view:expr),+ , _ => $fallback:expr $(,)?) => {
+        $crate::env::use_env(move |platform: $crate::platform::Platform| match platform {
+            $($($crate::platform::Platform::$platform)|+ => $crate::AnyView::new($view),)+
+            _ => $crate::AnyView::new($fallback),
+        })
+    };
+    ($($($platform:ident)|+ => $view:expr),+ $(,)?) => {
+        $crate::platform!($($($platform)|+ => $view),+, _ => ())
+    };
+}
+
 mod reactive_ext;
 pub(crate) mod view_ext;
 pub use nami as reactive;
 #[doc(inline)]
 pub use reactive::{Binding, Computed, Signal, signal};
-pub use reactive_ext::SignalExt;
+pub use reactive_ext::{SignalExt, untracked};
+pub mod channel;
+pub mod weak;
 
-/// Task management utilities and async support.
-pub mod task {
-    pub use executor_core::{spawn, spawn_local};
-    pub use native_executor::sleep;
-}
+pub mod task;
 
 /// Graphics primitives including GPU rendering surface.
 #[cfg(feature = "graphics")]
 pub use waterui_graphics as graphics;
 
+/// Reactive collections persisted in SQLite.
+#[cfg(feature = "store")]
+pub use waterui_store as store;
+
 #[cfg(debug_assertions)]
 #[macro_use]
 pub mod debug;
@@ -129,8 +212,17 @@ pub mod debug;
 mod entry;
 pub use entry::entry;
 
+pub mod animation;
 pub mod app;
+pub mod clock;
 pub mod fullscreen;
+pub mod settings;
+pub mod share;
+pub mod storage;
+pub mod undo;
 pub mod window;
 
 pub use tracing as log;
+
+#[doc(hidden)]
+pub use serde as __serde;
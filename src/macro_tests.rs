@@ -0,0 +1,133 @@
+//! Tests for the macros of `waterui_macros`.
+//!
+//! They expand to `::waterui` paths, so they can only be exercised from this crate.
+
+use nami::{Signal, binding, project::Project};
+
+use crate::form::valid::{FormValidation, field_error};
+
+#[allow(clippy::ptr_arg)]
+fn non_empty(name: &String) -> Result<(), &'static str> {
+    if name.trim().is_empty() {
+        Err("Name is required.")
+    } else {
+        Ok(())
+    }
+}
+
+// Validators receive the field by reference
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn adult(age: &i32) -> Result<(), &'static str> {
+    if *age >= 18 {
+        Ok(())
+    } else {
+        Err("You must be 18 or older.")
+    }
+}
+
+#[waterui_macros::form]
+struct Signup {
+    #[form(validate = non_empty)]
+    name: String,
+    #[form(validate = adult)]
+    age: i32,
+    newsletter: bool,
+}
+
+#[test]
+fn field_error_reports_the_current_message() {
+    let name = binding(String::new());
+    let error = field_error(&name, non_empty);
+    assert_eq!(error.get().as_deref(), Some("Name is required."));
+
+    name.set(String::from("Ada"));
+    assert_eq!(error.get(), None);
+}
+
+#[test]
+fn form_errors_follow_validated_fields() {
+    let data = binding(Signup::default());
+    let errors = Signup::errors(&data);
+    assert_eq!(errors.name.get().as_deref(), Some("Name is required."));
+    assert_eq!(
+        errors.age.get().as_deref(),
+        Some("You must be 18 or older.")
+    );
+    // Fields without a validator never report errors
+    assert_eq!(errors.newsletter.get(), None);
+
+    data.with_mut(|signup| {
+        signup.name = String::from("Ada");
+        signup.age = 36;
+    });
+    assert_eq!(errors.name.get(), None);
+    assert_eq!(errors.age.get(), None);
+}
+
+#[test]
+fn is_valid_requires_every_validated_field() {
+    let data = binding(Signup::default());
+    let is_valid = Signup::is_valid(&data);
+    assert!(!is_valid.get());
+
+    data.with_mut(|signup| signup.name = String::from("Ada"));
+    assert!(!is_valid.get());
+
+    data.with_mut(|signup| signup.age = 36);
+    assert!(is_valid.get());
+
+    // Unvalidated fields do not affect validity
+    data.with_mut(|signup| signup.newsletter = true);
+    assert!(is_valid.get());
+
+    data.with_mut(|signup| signup.name.clear());
+    assert!(!is_valid.get());
+}
+
+#[derive(crate::Project, Clone, Debug, PartialEq)]
+enum Upload {
+    Idle,
+    Sending { sent: u64, total: u64 },
+    Failed(String),
+}
+
+#[test]
+fn enum_projection_follows_the_active_variant() {
+    let upload = binding(Upload::Idle);
+    let projected = Upload::project(&upload);
+    assert_eq!(projected.kind.get(), UploadKind::Idle);
+    assert_eq!(projected.sending.get(), None);
+
+    upload.set(Upload::Sending { sent: 1, total: 4 });
+    assert_eq!(projected.kind.get(), UploadKind::Sending);
+    assert_eq!(projected.sending.get(), Some((1, 4)));
+    assert_eq!(projected.failed.get(), None);
+}
+
+#[test]
+fn enum_payload_bindings_switch_variants() {
+    let upload = binding(Upload::Idle);
+    let projected = Upload::project(&upload);
+
+    projected.failed.set(Some(String::from("offline")));
+    assert_eq!(upload.get(), Upload::Failed(String::from("offline")));
+    assert_eq!(projected.kind.get(), UploadKind::Failed);
+
+    // Clearing a payload has no variant to switch to
+    projected.failed.set(None);
+    assert_eq!(upload.get(), Upload::Failed(String::from("offline")));
+}
+
+#[test]
+fn enum_kind_is_writable_for_variants_without_fields() {
+    let upload = binding(Upload::Sending { sent: 2, total: 4 });
+    let projected = Upload::project(&upload);
+
+    projected.kind.set(UploadKind::Idle);
+    assert_eq!(upload.get(), Upload::Idle);
+    assert_eq!(projected.kind.get(), UploadKind::Idle);
+
+    // Variants with fields have no values to be built from
+    projected.kind.set(UploadKind::Failed);
+    assert_eq!(upload.get(), Upload::Idle);
+}
@@ -2,9 +2,35 @@
 //!
 //! This module provides additional convenience methods for working with reactive values
 //! and computations in the `WaterUI` framework.
+//!
+//! # Snapshot reads
+//!
+//! Views stay up to date by holding signals, not values: `text(count.map(..))`
+//! re-renders when `count` changes, while `text(count.get().to_string())` shows
+//! the value it was built with forever. Reading a value is still right inside
+//! event handlers, tasks and watcher callbacks, which run at one moment and
+//! need the state as of that moment. [`SignalExt::peek`] makes such reads
+//! explicit, and [`untracked`] marks a block of them:
+//!
+//! ```ignore
+//! let _guard = query.watch({
+//!     let filters = filters.clone();
+//!     move |context| {
+//!         // Runs when `query` changes; `filters` is only read
+//!         search(context.into_value(), filters.peek());
+//!     }
+//! });
+//! ```
+//!
+//! With [strict mode](waterui_core::strict) enabled, peeking while a view's
+//! body is being computed is reported, since the view will not update when
+//! the signal changes. Wrap the read in [`untracked`] when a snapshot is
+//! intended there.
+
+use core::any::type_name;
 
 use nami::{Computed, Signal, map::Map, signal::WithMetadata, zip::Zip};
-use waterui_core::animation::Animation;
+use waterui_core::{animation::Animation, strict};
 
 /// Extension trait providing additional methods for `Signal` types.
 ///
@@ -81,6 +107,70 @@ pub trait SignalExt: Signal + Sized {
     fn animated(self) -> impl Signal<Output = Self::Output> {
         self.with(Animation::Default)
     }
+
+    /// Returns the current value as a snapshot.
+    ///
+    /// This is [`get`](Signal::get) with its intent spelled out: the value is
+    /// read once and nothing is notified when it changes later. Use it in
+    /// handlers, tasks and watcher callbacks to read signals other than the
+    /// one being watched.
+    ///
+    /// Strict mode reports peeks during a view's body outside of
+    /// [`untracked`].
+    fn peek(&self) -> Self::Output {
+        strict::peeked(type_name::<Self>());
+        self.get()
+    }
 }
 
 impl<C: Signal + Sized> SignalExt for C {}
+
+/// Runs `f`, in which signals are read as snapshots on purpose.
+///
+/// Reads inside `f` behave as they always do; what changes is that strict
+/// mode does not report [`peek`](SignalExt::peek)s in `f`, even while a
+/// view's body is being computed. Use it for values a view should show as
+/// they were when it was built, such as the time a screen was opened.
+pub fn untracked<R>(f: impl FnOnce() -> R) -> R {
+    let _scope = strict::untracked_scope();
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, vec::Vec};
+    use core::cell::RefCell;
+
+    use nami::Binding;
+    use waterui_core::strict::{StrictMode, Violation};
+
+    use super::*;
+
+    #[test]
+    fn peeks_in_bodies_are_reported_outside_untracked() {
+        let violations = Rc::new(RefCell::new(Vec::new()));
+        let recorded = violations.clone();
+        strict::enable(
+            StrictMode::new()
+                .on_violation(move |violation| recorded.borrow_mut().push(violation.clone())),
+        );
+
+        let count = Binding::container(1);
+        assert_eq!(count.peek(), 1);
+        {
+            let _body = strict::body_scope("Counter");
+            assert_eq!(untracked(|| count.peek()), 1);
+            count.set(2);
+            assert_eq!(count.peek(), 2);
+        }
+        strict::disable();
+
+        assert!(matches!(
+            violations.borrow().as_slice(),
+            [Violation::PeekDuringBody {
+                view: "Counter",
+                ..
+            }]
+        ));
+    }
+}
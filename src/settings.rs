@@ -0,0 +1,379 @@
+//! Typed, persistent app settings.
+//!
+//! Declare each setting once as a [`SettingKey`] with its default value.
+//! [`Settings::binding`] returns a binding that starts with the stored value
+//! and writes every change back to [`Storage`], so settings are read and
+//! edited like any other state:
+//!
+//! ```ignore
+//! use waterui::settings::{SettingKey, Settings};
+//!
+//! const DARK_MODE: SettingKey<bool> = SettingKey::new("dark_mode", || false);
+//!
+//! fn preferences(env: &Environment) -> impl View {
+//!     let settings = Settings::from_env(env);
+//!     Toggle::new(&settings.binding(&DARK_MODE)).label(text("Dark Mode"))
+//! }
+//! ```
+//!
+//! A struct deriving [`FormBuilder`] can be stored under one key and edited
+//! with a generated form through [`Settings::form`].
+//!
+//! # Migrations
+//!
+//! Stored values remember the version of their key, which is the number of
+//! migrations it declares. When the shape of a setting changes, append a
+//! migration converting the previous JSON; older values are upgraded on
+//! load:
+//!
+//! ```ignore
+//! // Version 0 stored the volume as a percentage
+//! const VOLUME: SettingKey<f64> = SettingKey::new("volume", || 0.5)
+//!     .migrations(&[|percent| json!(percent.as_f64().unwrap_or(50.0) / 100.0)]);
+//! ```
+
+use alloc::{boxed::Box, collections::BTreeMap, rc::Rc};
+use core::{any::Any, cell::RefCell, fmt, ops::Deref};
+
+use nami::{Binding, Signal};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use waterui_core::Environment;
+use waterui_form::FormBuilder;
+
+use crate::storage::{Storage, current_storage};
+
+/// Converts the JSON of a setting from one version to the next.
+pub type Migration = fn(Value) -> Value;
+
+/// A setting's storage key, default value and migrations.
+pub struct SettingKey<T> {
+    name: &'static str,
+    default: fn() -> T,
+    migrations: &'static [Migration],
+}
+
+impl<T> Clone for SettingKey<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SettingKey<T> {}
+
+impl<T> fmt::Debug for SettingKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SettingKey")
+            .field("name", &self.name)
+            .field("version", &self.version())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> SettingKey<T> {
+    /// Declares a setting stored under `name`, which is `default()` until
+    /// it is changed.
+    #[must_use]
+    pub const fn new(name: &'static str, default: fn() -> T) -> Self {
+        Self {
+            name,
+            default,
+            migrations: &[],
+        }
+    }
+
+    /// Sets the migrations upgrading stored values, oldest first.
+    ///
+    /// Migration `n` converts the JSON of version `n` to version `n + 1`.
+    #[must_use]
+    pub const fn migrations(mut self, migrations: &'static [Migration]) -> Self {
+        self.migrations = migrations;
+        self
+    }
+
+    /// Returns the name the setting is stored under.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the current version of the setting's format.
+    #[must_use]
+    pub const fn version(&self) -> usize {
+        self.migrations.len()
+    }
+
+    /// Returns the default value.
+    #[must_use]
+    pub fn default_value(&self) -> T {
+        (self.default)()
+    }
+}
+
+/// A value as written to storage, tagged with its format version.
+#[derive(Serialize, Deserialize)]
+struct Versioned {
+    version: usize,
+    value: Value,
+}
+
+/// A setting's binding, kept alive together with the watcher persisting it.
+struct Entry<T: 'static> {
+    binding: Binding<T>,
+    _guard: <Binding<T> as Signal>::Guard,
+}
+
+/// Typed access to the settings of an app.
+///
+/// Clones share their bindings, so every view editing a setting sees the
+/// same value.
+#[derive(Clone)]
+pub struct Settings {
+    storage: Storage,
+    entries: Rc<RefCell<BTreeMap<&'static str, Box<dyn Any>>>>,
+}
+
+impl fmt::Debug for Settings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Settings")
+            .field("loaded", &self.entries.borrow().keys())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Settings {
+    /// Creates settings persisted to `storage`.
+    #[must_use]
+    pub fn new(storage: Storage) -> Self {
+        Self {
+            storage,
+            entries: Rc::default(),
+        }
+    }
+
+    /// Installs settings persisted to the storage of `env` into `env`.
+    pub fn install(env: &mut Environment) -> Self {
+        let settings = Self::new(current_storage(env));
+        env.insert(settings.clone());
+        settings
+    }
+
+    /// Returns the settings installed in `env`.
+    ///
+    /// Without installed settings, this creates new ones on the storage of
+    /// `env`, which do not share bindings with other calls.
+    #[must_use]
+    pub fn from_env(env: &Environment) -> Self {
+        env.get::<Self>()
+            .cloned()
+            .unwrap_or_else(|| Self::new(current_storage(env)))
+    }
+
+    /// Returns the binding of a setting.
+    ///
+    /// The first call loads and migrates the stored value, falling back to
+    /// the default if there is none or it cannot be read. Later calls return
+    /// the same binding. Every change is written back to storage.
+    ///
+    /// # Panics
+    /// Panics if another key with the same name but a different type was
+    /// used before.
+    #[must_use]
+    pub fn binding<T>(&self, key: &SettingKey<T>) -> Binding<T>
+    where
+        T: Serialize + DeserializeOwned + Clone + 'static,
+    {
+        if let Some(entry) = self.entries.borrow().get(key.name) {
+            let entry = entry
+                .downcast_ref::<Entry<T>>()
+                .unwrap_or_else(|| panic!("setting `{}` used with two types", key.name));
+            return entry.binding.clone();
+        }
+
+        let binding = Binding::container(self.load(key).unwrap_or_else(|| key.default_value()));
+        let storage = self.storage.clone();
+        let name = key.name;
+        let version = key.version();
+        let guard = binding.watch(move |context| {
+            store(&storage, name, version, &context.into_value());
+        });
+        self.entries.borrow_mut().insert(
+            key.name,
+            Box::new(Entry {
+                binding: binding.clone(),
+                _guard: guard,
+            }),
+        );
+        binding
+    }
+
+    /// Returns a form editing a setting, generated by its [`FormBuilder`].
+    #[must_use]
+    pub fn form<T>(&self, key: &SettingKey<T>) -> T::View
+    where
+        T: FormBuilder + Serialize + DeserializeOwned + Clone + 'static,
+    {
+        waterui_form::form(&self.binding(key))
+    }
+
+    /// Sets a setting back to its default value.
+    pub fn reset<T>(&self, key: &SettingKey<T>)
+    where
+        T: Serialize + DeserializeOwned + Clone + 'static,
+    {
+        self.binding(key).set(key.default_value());
+    }
+
+    /// Reads the stored value of `key`, migrating it to the current version.
+    fn load<T: Serialize + DeserializeOwned>(&self, key: &SettingKey<T>) -> Option<T> {
+        let stored: Versioned = self.storage.load_json(key.name)?;
+        // Values written by a newer version of the app cannot be read
+        let migrations = key.migrations.get(stored.version..)?;
+        let value = migrations
+            .iter()
+            .fold(stored.value, |value, migrate| migrate(value));
+        let value = serde_json::from_value(value).ok()?;
+        if !migrations.is_empty() {
+            store(&self.storage, key.name, key.version(), &value);
+        }
+        Some(value)
+    }
+}
+
+/// A binding whose value is persisted to storage.
+///
+/// The value is loaded the first time a name is used and every change is
+/// written back, so it survives restarts. `Stored` dereferences to its
+/// [`Binding`].
+///
+/// Changes are saved while the `Stored` or one of its clones is alive. With
+/// [`Settings`] installed in the environment, they are saved for as long as
+/// the settings are.
+pub struct Stored<T: 'static> {
+    binding: Binding<T>,
+    /// Keeps the storage the binding is saved to alive.
+    settings: Settings,
+}
+
+impl<T> Clone for Stored<T> {
+    fn clone(&self) -> Self {
+        Self {
+            binding: self.binding.clone(),
+            settings: self.settings.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Stored<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stored").finish_non_exhaustive()
+    }
+}
+
+impl<T> Stored<T>
+where
+    T: Serialize + DeserializeOwned + Clone + 'static,
+{
+    /// Returns the value stored under `name` in the settings of `env`, which
+    /// is `default()` until it is changed.
+    ///
+    /// # Panics
+    /// Panics if `name` was used before with a different type.
+    #[must_use]
+    pub fn new(env: &Environment, name: &'static str, default: fn() -> T) -> Self {
+        Self::from_key(env, &SettingKey::new(name, default))
+    }
+
+    /// Returns the value of a setting in the settings of `env`.
+    ///
+    /// # Panics
+    /// Panics if another key with the same name but a different type was
+    /// used before.
+    #[must_use]
+    pub fn from_key(env: &Environment, key: &SettingKey<T>) -> Self {
+        let settings = Settings::from_env(env);
+        Self {
+            binding: settings.binding(key),
+            settings,
+        }
+    }
+}
+
+impl<T> Stored<T> {
+    /// Returns the binding of the stored value.
+    #[must_use]
+    pub const fn binding(&self) -> &Binding<T> {
+        &self.binding
+    }
+}
+
+impl<T> Deref for Stored<T> {
+    type Target = Binding<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.binding
+    }
+}
+
+/// Writes `value` under `name`, tagged with `version`.
+fn store<T: Serialize>(storage: &Storage, name: &str, version: usize, value: &T) {
+    match serde_json::to_value(value) {
+        Ok(value) => storage.store_json(name, &Versioned { version, value }),
+        Err(error) => tracing::warn!("failed to serialize setting `{name}`: {error}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const VOLUME: SettingKey<f64> = SettingKey::new("volume", || 0.5)
+        .migrations(&[|percent| json!(percent.as_f64().unwrap_or(50.0) / 100.0)]);
+
+    #[test]
+    fn bindings_persist_changes() {
+        let storage = Storage::memory();
+        let settings = Settings::new(storage.clone());
+        let volume = settings.binding(&VOLUME);
+        assert!((volume.get() - 0.5).abs() < f64::EPSILON);
+
+        settings.binding(&VOLUME).set(0.8);
+        assert!((volume.get() - 0.8).abs() < f64::EPSILON);
+        let reloaded = Settings::new(storage).binding(&VOLUME);
+        assert!((reloaded.get() - 0.8).abs() < f64::EPSILON);
+
+        settings.reset(&VOLUME);
+        assert!((volume.get() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn old_values_are_migrated() {
+        let storage = Storage::memory();
+        storage.store("volume", r#"{"version":0,"value":30}"#);
+        let volume = Settings::new(storage.clone()).binding(&VOLUME);
+        assert!((volume.get() - 0.3).abs() < f64::EPSILON);
+        assert_eq!(
+            storage.load_json::<Value>("volume"),
+            Some(json!({"version": 1, "value": 0.3}))
+        );
+
+        storage.store("volume", r#"{"version":2,"value":"loud"}"#);
+        let volume = Settings::new(storage).binding(&VOLUME);
+        assert!((volume.get() - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn stored_values_hydrate_from_storage() {
+        let mut env = Environment::new();
+        env.insert(Storage::memory());
+        let name = Stored::new(&env, "name", || String::from("Guest"));
+        assert_eq!(name.get(), "Guest");
+        name.set(String::from("Ada"));
+
+        // Loaded again on the next launch, sharing nothing but the storage
+        let reloaded = Stored::new(&env, "name", String::new);
+        assert_eq!(reloaded.get(), "Ada");
+    }
+}
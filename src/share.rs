@@ -0,0 +1,215 @@
+//! Sharing content through the platform's share sheet.
+//!
+//! [`share`] presents the system share sheet, such as
+//! `UIActivityViewController` on Apple platforms, the Android Sharesheet or the
+//! Web Share API, so users can send text, links and files to other apps:
+//!
+//! ```ignore
+//! use waterui::share::{ShareItem, share};
+//!
+//! button("Share").action(move |env: Environment| {
+//!     share(&env, [ShareItem::url(article.url.clone())], |outcome| {
+//!         tracing::info!("share finished: {outcome:?}");
+//!     });
+//! })
+//! ```
+//!
+//! Native backends install a [`Sharer`] during initialization. Without one,
+//! sharing completes right away with [`ShareOutcome::Unavailable`].
+
+use alloc::{boxed::Box, rc::Rc, vec::Vec};
+
+use waterui_core::Environment;
+use waterui_str::Str;
+
+use crate::media::Url;
+
+/// An item to share.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShareItem {
+    /// Plain text.
+    Text(Str),
+    /// A link.
+    Url(Url),
+    /// A file, which backends write to a temporary location if the platform
+    /// shares files by path.
+    File {
+        /// The file name shown to the user, such as `report.pdf`.
+        name: Str,
+        /// The MIME type of the file, such as `application/pdf`.
+        mime: Str,
+        /// The contents of the file.
+        data: Vec<u8>,
+    },
+}
+
+impl ShareItem {
+    /// Creates a plain text item.
+    pub fn text(text: impl Into<Str>) -> Self {
+        Self::Text(text.into())
+    }
+
+    /// Creates a link item.
+    pub fn url(url: impl Into<Url>) -> Self {
+        Self::Url(url.into())
+    }
+
+    /// Creates a file item.
+    pub fn file(name: impl Into<Str>, mime: impl Into<Str>, data: impl Into<Vec<u8>>) -> Self {
+        Self::File {
+            name: name.into(),
+            mime: mime.into(),
+            data: data.into(),
+        }
+    }
+}
+
+impl From<Str> for ShareItem {
+    fn from(text: Str) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<&'static str> for ShareItem {
+    fn from(text: &'static str) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+impl From<Url> for ShareItem {
+    fn from(url: Url) -> Self {
+        Self::Url(url)
+    }
+}
+
+/// How a share ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShareOutcome {
+    /// The user shared the items.
+    ///
+    /// The Web Share API and some Android targets report this as soon as the
+    /// user picked an app, even if they abandon sharing there.
+    Completed,
+    /// The user dismissed the share sheet.
+    Cancelled,
+    /// Sharing is not supported, nothing was given to share, or the share
+    /// sheet could not be presented.
+    Unavailable,
+}
+
+/// A platform's share sheet.
+///
+/// This trait should be implemented by platform-specific backends.
+pub trait CustomSharer: 'static {
+    /// Presents the share sheet for `items`.
+    ///
+    /// `completion` must be called exactly once, after the sheet is dismissed.
+    fn share(&self, items: Vec<ShareItem>, completion: impl FnOnce(ShareOutcome) + 'static);
+}
+
+trait SharerImpl: 'static {
+    fn share(&self, items: Vec<ShareItem>, completion: Box<dyn FnOnce(ShareOutcome)>);
+}
+
+impl<T: CustomSharer> SharerImpl for T {
+    fn share(&self, items: Vec<ShareItem>, completion: Box<dyn FnOnce(ShareOutcome)>) {
+        CustomSharer::share(self, items, completion);
+    }
+}
+
+/// Type-erased share sheet stored in the environment.
+#[derive(Clone)]
+pub struct Sharer(Rc<dyn SharerImpl>);
+
+impl core::fmt::Debug for Sharer {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Sharer").finish_non_exhaustive()
+    }
+}
+
+impl Sharer {
+    /// Creates a new `Sharer` from any type implementing `CustomSharer`.
+    pub fn new(sharer: impl CustomSharer) -> Self {
+        Self(Rc::new(sharer))
+    }
+
+    /// Presents the share sheet for `items`, calling `completion` once it is
+    /// dismissed.
+    ///
+    /// Completes right away with [`ShareOutcome::Unavailable`] if there is
+    /// nothing to share.
+    pub fn share(
+        &self,
+        items: impl IntoIterator<Item = ShareItem>,
+        completion: impl FnOnce(ShareOutcome) + 'static,
+    ) {
+        let items: Vec<ShareItem> = items.into_iter().collect();
+        if items.is_empty() {
+            completion(ShareOutcome::Unavailable);
+            return;
+        }
+        self.0.share(items, Box::new(completion));
+    }
+}
+
+/// Presents the share sheet installed in `env` for `items`.
+///
+/// `completion` is called once the sheet is dismissed, or right away with
+/// [`ShareOutcome::Unavailable`] if the platform cannot share.
+pub fn share(
+    env: &Environment,
+    items: impl IntoIterator<Item = ShareItem>,
+    completion: impl FnOnce(ShareOutcome) + 'static,
+) {
+    if let Some(sharer) = env.get::<Sharer>() {
+        sharer.share(items, completion);
+    } else {
+        tracing::warn!("no share sheet is installed on this platform");
+        completion(ShareOutcome::Unavailable);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use core::cell::{Cell, RefCell};
+
+    use super::*;
+
+    struct RecordingSharer(Rc<RefCell<Vec<ShareItem>>>);
+
+    impl CustomSharer for RecordingSharer {
+        fn share(&self, items: Vec<ShareItem>, completion: impl FnOnce(ShareOutcome) + 'static) {
+            self.0.borrow_mut().extend(items);
+            completion(ShareOutcome::Completed);
+        }
+    }
+
+    #[test]
+    fn shares_go_through_the_installed_sharer() {
+        let outcome = Rc::new(Cell::new(None));
+        let record = |outcome: &Rc<Cell<Option<ShareOutcome>>>| {
+            let outcome = outcome.clone();
+            move |result| outcome.set(Some(result))
+        };
+
+        let mut env = Environment::new();
+        share(&env, [ShareItem::text("hello")], record(&outcome));
+        assert_eq!(outcome.take(), Some(ShareOutcome::Unavailable));
+
+        let shared = Rc::new(RefCell::new(Vec::new()));
+        env.insert(Sharer::new(RecordingSharer(shared.clone())));
+        share(&env, Vec::new(), record(&outcome));
+        assert_eq!(outcome.take(), Some(ShareOutcome::Unavailable));
+        assert!(shared.borrow().is_empty());
+
+        let file = ShareItem::file("notes.txt", "text/plain", b"hi".to_vec());
+        share(
+            &env,
+            [ShareItem::text("hello"), file.clone()],
+            record(&outcome),
+        );
+        assert_eq!(outcome.take(), Some(ShareOutcome::Completed));
+        assert_eq!(*shared.borrow(), vec![ShareItem::text("hello"), file]);
+    }
+}
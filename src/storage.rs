@@ -0,0 +1,107 @@
+//! Persistent key-value storage.
+//!
+//! Native backends install a [`Storage`] backed by the platform's preferences
+//! store, such as `UserDefaults` or `SharedPreferences`. Values are strings,
+//! usually JSON written through [`Storage::store_json`]. Most apps use the
+//! typed [`settings`](crate::settings) built on top of it instead.
+//!
+//! Without an installed storage, [`current_storage`] returns an in-memory
+//! store, so nothing survives a restart.
+
+use alloc::{collections::BTreeMap, rc::Rc, string::String};
+use core::cell::RefCell;
+
+use serde::{Serialize, de::DeserializeOwned};
+use waterui_core::Environment;
+
+/// A platform's persistent key-value store.
+pub trait CustomStorage: 'static {
+    /// Returns the value stored under `key`, if any.
+    fn load(&self, key: &str) -> Option<String>;
+    /// Stores `value` under `key`, replacing any previous value.
+    fn store(&self, key: &str, value: &str);
+    /// Removes the value stored under `key`.
+    fn remove(&self, key: &str);
+}
+
+/// Type-erased key-value storage stored in the environment.
+#[derive(Clone)]
+pub struct Storage(Rc<dyn CustomStorage>);
+
+impl core::fmt::Debug for Storage {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Storage").finish_non_exhaustive()
+    }
+}
+
+impl Storage {
+    /// Creates a new `Storage` from any type implementing `CustomStorage`.
+    pub fn new(storage: impl CustomStorage) -> Self {
+        Self(Rc::new(storage))
+    }
+
+    /// Creates an empty storage that only lives in memory.
+    #[must_use]
+    pub fn memory() -> Self {
+        Self::new(MemoryStorage::default())
+    }
+
+    /// Returns the value stored under `key`, if any.
+    #[must_use]
+    pub fn load(&self, key: &str) -> Option<String> {
+        self.0.load(key)
+    }
+
+    /// Stores `value` under `key`, replacing any previous value.
+    pub fn store(&self, key: &str, value: &str) {
+        self.0.store(key, value);
+    }
+
+    /// Removes the value stored under `key`.
+    pub fn remove(&self, key: &str) {
+        self.0.remove(key);
+    }
+
+    /// Returns the JSON value stored under `key`, or `None` if it is missing
+    /// or cannot be parsed as `T`.
+    #[must_use]
+    pub fn load_json<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        serde_json::from_str(&self.load(key)?).ok()
+    }
+
+    /// Stores `value` as JSON under `key`.
+    pub fn store_json<T: Serialize + ?Sized>(&self, key: &str, value: &T) {
+        match serde_json::to_string(value) {
+            Ok(json) => self.store(key, &json),
+            Err(error) => tracing::warn!("failed to serialize `{key}` for storage: {error}"),
+        }
+    }
+}
+
+/// Storage kept in memory, used when no platform storage is installed.
+#[derive(Debug, Default)]
+pub struct MemoryStorage(RefCell<BTreeMap<String, String>>);
+
+impl CustomStorage for MemoryStorage {
+    fn load(&self, key: &str) -> Option<String> {
+        self.0.borrow().get(key).cloned()
+    }
+
+    fn store(&self, key: &str, value: &str) {
+        self.0.borrow_mut().insert(key.into(), value.into());
+    }
+
+    fn remove(&self, key: &str) {
+        self.0.borrow_mut().remove(key);
+    }
+}
+
+/// Returns the storage installed in `env`.
+///
+/// Without one this is a new, empty [`Storage::memory`].
+#[must_use]
+pub fn current_storage(env: &Environment) -> Storage {
+    env.get::<Storage>()
+        .cloned()
+        .unwrap_or_else(Storage::memory)
+}
@@ -0,0 +1,53 @@
+//! Task management utilities and async support.
+//!
+//! [`spawn_local`], [`sleep`] and [`now`] normally run on the native executor
+//! and the system clock. While a [`TestExecutor`] exists on the current thread
+//! they run under its manual control instead, so timer-driven logic can be
+//! tested without real sleeps.
+//!
+//! [`retry`] reruns failed operations with exponential backoff, and a
+//! [`Resource`] loads a value in the background as a signal views can watch.
+
+pub mod resource;
+pub mod retry;
+mod test_executor;
+
+use core::{future::Future, time::Duration};
+use std::time::Instant;
+
+pub use executor_core::{AnyLocalExecutorTask, spawn};
+pub use resource::{Resource, ResourceState};
+pub use retry::{RetryPolicy, retry, retry_if};
+pub use test_executor::TestExecutor;
+
+/// Spawns a task on the local executor.
+///
+/// Under a [`TestExecutor`] the task only runs when the executor is driven.
+pub fn spawn_local<Fut>(future: Fut) -> AnyLocalExecutorTask<Fut::Output>
+where
+    Fut: Future + 'static,
+    Fut::Output: 'static,
+{
+    match test_executor::current() {
+        Some(executor) => executor.spawn(future),
+        None => executor_core::spawn_local(future),
+    }
+}
+
+/// Waits until `duration` has passed.
+///
+/// Under a [`TestExecutor`] this waits for virtual time to be advanced.
+pub async fn sleep(duration: Duration) {
+    match test_executor::Timer::new(duration) {
+        Some(timer) => timer.await,
+        None => native_executor::sleep(duration).await,
+    }
+}
+
+/// Returns the current instant.
+///
+/// Under a [`TestExecutor`] this is the virtual time of the executor.
+#[must_use]
+pub fn now() -> Instant {
+    test_executor::current().map_or_else(Instant::now, |executor| executor.now())
+}
@@ -0,0 +1,162 @@
+//! Values loaded asynchronously, with their loading state as a signal.
+//!
+//! A [`Resource`] runs a loader on the local executor and exposes a
+//! [`ResourceState`] signal views can watch. With a [`RetryPolicy`] failed
+//! loads are retried before the resource reports the error:
+//!
+//! ```ignore
+//! let avatar = Resource::new(move || fetch_avatar(user_id))
+//!     .with_retry(RetryPolicy::new().reachability(current_reachability(&env)));
+//! avatar.load();
+//! Dynamic::watch(avatar, |state| match state {
+//!     ResourceState::Loading => AnyView::new(ProgressView::new()),
+//!     ResourceState::Loaded(image) => AnyView::new(image),
+//!     ResourceState::Failed(_) => AnyView::new(text("Offline")),
+//! })
+//! ```
+
+use alloc::{boxed::Box, rc::Rc};
+use core::{cell::RefCell, fmt, pin::Pin};
+
+use nami::{Binding, Signal, watcher::Context};
+
+use super::{AnyLocalExecutorTask, RetryPolicy, retry, spawn_local};
+
+type Loader<T, E> = Rc<dyn Fn() -> Pin<Box<dyn Future<Output = Result<T, E>>>>>;
+
+/// The loading state of a [`Resource`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ResourceState<T, E> {
+    /// The value is being loaded, or loading has not started yet.
+    #[default]
+    Loading,
+    /// The value was loaded.
+    Loaded(T),
+    /// Loading failed, after all retries.
+    Failed(E),
+}
+
+/// A value loaded asynchronously.
+///
+/// Nothing is loaded until [`load`](Self::load) is called. Clones share the
+/// same state and loader.
+pub struct Resource<T: 'static, E: 'static> {
+    state: Binding<ResourceState<T, E>>,
+    loader: Loader<T, E>,
+    policy: Option<RetryPolicy>,
+    /// The running load; dropping it cancels the load.
+    task: Rc<RefCell<Option<AnyLocalExecutorTask<()>>>>,
+}
+
+impl<T: 'static, E: 'static> Clone for Resource<T, E> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+            loader: self.loader.clone(),
+            policy: self.policy.clone(),
+            task: self.task.clone(),
+        }
+    }
+}
+
+impl<T: 'static, E: 'static> fmt::Debug for Resource<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Resource")
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone + 'static, E: Clone + 'static> Resource<T, E> {
+    /// Creates a resource loaded by `loader`.
+    pub fn new<Fut>(loader: impl Fn() -> Fut + 'static) -> Self
+    where
+        Fut: Future<Output = Result<T, E>> + 'static,
+    {
+        Self {
+            state: Binding::container(ResourceState::Loading),
+            loader: Rc::new(move || Box::pin(loader())),
+            policy: None,
+            task: Rc::default(),
+        }
+    }
+
+    /// Retries failed loads according to `policy`.
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Starts loading, cancelling a load that is still running.
+    ///
+    /// The state goes back to [`ResourceState::Loading`] until it finishes.
+    pub fn load(&self) {
+        let state = self.state.clone();
+        let loader = self.loader.clone();
+        let policy = self.policy.clone();
+        state.set(ResourceState::Loading);
+        let task = spawn_local(async move {
+            let result = match &policy {
+                Some(policy) => retry(policy, &*loader).await,
+                None => loader().await,
+            };
+            state.set(match result {
+                Ok(value) => ResourceState::Loaded(value),
+                Err(error) => ResourceState::Failed(error),
+            });
+        });
+        self.task.replace(Some(task));
+    }
+}
+
+impl<T: Clone + 'static, E: Clone + 'static> Signal for Resource<T, E> {
+    type Output = ResourceState<T, E>;
+    type Guard = <Binding<ResourceState<T, E>> as Signal>::Guard;
+
+    fn get(&self) -> Self::Output {
+        self.state.get()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.state.watch(watcher)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{cell::Cell, time::Duration};
+
+    use super::*;
+    use crate::task::{TestExecutor, sleep};
+
+    #[test]
+    fn retries_and_reloads() {
+        let executor = TestExecutor::new();
+        let attempts = Rc::new(Cell::new(0));
+        let counter = attempts.clone();
+        let resource = Resource::new(move || {
+            counter.set(counter.get() + 1);
+            let attempt = counter.get();
+            async move {
+                sleep(Duration::from_millis(10)).await;
+                if attempt >= 3 {
+                    Ok(attempt)
+                } else {
+                    Err(attempt)
+                }
+            }
+        });
+
+        resource.load();
+        executor.advance(Duration::from_millis(10));
+        assert_eq!(resource.get(), ResourceState::Failed(1));
+
+        let resource = resource.with_retry(RetryPolicy::new().jitter(0.0));
+        resource.load();
+        assert_eq!(resource.get(), ResourceState::Loading);
+        executor.advance(Duration::from_millis(520));
+        assert_eq!(resource.get(), ResourceState::Loaded(3));
+        assert_eq!(attempts.get(), 3);
+    }
+}
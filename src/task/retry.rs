@@ -0,0 +1,320 @@
+//! Retrying failed operations with exponential backoff.
+//!
+//! [`retry`] runs an async operation until it succeeds or the
+//! [`RetryPolicy`] gives up, waiting longer after each failure:
+//!
+//! ```ignore
+//! use waterui::network::current_reachability;
+//! use waterui::task::{RetryPolicy, retry};
+//!
+//! let policy = RetryPolicy::new()
+//!     .max_attempts(4)
+//!     .reachability(current_reachability(&env));
+//! let profile = retry(&policy, || fetch_profile(user_id)).await?;
+//! ```
+//!
+//! With a reachability signal, a failure while the device is offline waits
+//! for the network to come back instead, then retries right away.
+
+use core::{
+    cell::RefCell,
+    fmt,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+use std::collections::hash_map::RandomState;
+
+use futures::channel::oneshot;
+use nami::{Computed, Signal};
+use waterui_core::network::Reachability;
+
+use super::sleep;
+
+/// Decides how often and how quickly a failed operation is retried.
+///
+/// The delay before the `n`th retry is `initial_delay * multiplier^n`, capped
+/// at `max_delay` and shortened by a random fraction of up to `jitter`, so
+/// clients that failed together do not retry in lockstep.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: f64,
+    jitter: f64,
+    reachability: Option<Computed<Reachability>>,
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .field("reachability", &self.reachability.as_ref().map(Signal::get))
+            .finish()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy making up to 5 attempts, starting with a 500 ms delay
+    /// that doubles up to 30 seconds, with 20% jitter.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: 0.2,
+            reachability: None,
+        }
+    }
+
+    /// Sets how many times the operation runs in total, including the first
+    /// attempt. Zero is treated as one.
+    #[must_use]
+    pub const fn max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    #[must_use]
+    pub const fn initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Sets the longest delay between two attempts.
+    #[must_use]
+    pub const fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Sets the factor the delay grows by after each retry.
+    #[must_use]
+    pub const fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets the largest fraction, between 0 and 1, randomly taken off each
+    /// delay. Zero makes delays deterministic.
+    #[must_use]
+    pub const fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Waits for `reachability` to leave [`Reachability::Offline`] instead of
+    /// backing off when an attempt fails while offline.
+    ///
+    /// Usually [`current_reachability`](waterui_core::network::current_reachability).
+    #[must_use]
+    pub fn reachability(mut self, reachability: Computed<Reachability>) -> Self {
+        self.reachability = Some(reachability);
+        self
+    }
+
+    /// Returns the delay before retry number `retry`, counted from zero,
+    /// without jitter.
+    #[must_use]
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = i32::try_from(retry).unwrap_or(i32::MAX);
+        let seconds = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::try_from_secs_f64(seconds)
+            .unwrap_or(Duration::MAX)
+            .min(self.max_delay)
+    }
+
+    fn delay(&self, retry: u32) -> Duration {
+        self.backoff(retry)
+            .mul_f64(self.jitter.mul_add(-random_fraction(), 1.0))
+    }
+
+    /// Waits before the next attempt after retry number `retry` failed.
+    async fn wait(&self, retry: u32) {
+        if let Some(reachability) = &self.reachability
+            && !reachability.get().may_connect()
+        {
+            until_reachable(reachability).await;
+            return;
+        }
+        sleep(self.delay(retry)).await;
+    }
+}
+
+/// Runs `operation` until it succeeds or `policy` runs out of attempts.
+///
+/// # Errors
+/// Returns the error of the last attempt.
+pub async fn retry<T, E, F, Fut>(policy: &RetryPolicy, operation: F) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    retry_if(policy, operation, |_| true).await
+}
+
+/// Runs `operation` until it succeeds, `policy` runs out of attempts, or it
+/// fails with an error `should_retry` rejects.
+///
+/// Use it to give up right away on errors retrying cannot fix, such as a
+/// missing resource.
+///
+/// # Errors
+/// Returns the error of the last attempt.
+pub async fn retry_if<T, E, F, Fut>(
+    policy: &RetryPolicy,
+    mut operation: F,
+    should_retry: impl Fn(&E) -> bool,
+) -> Result<T, E>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut retries = 0;
+    loop {
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(error) if retries + 1 >= policy.max_attempts || !should_retry(&error) => {
+                return Err(error);
+            }
+            Err(_) => {
+                policy.wait(retries).await;
+                retries += 1;
+            }
+        }
+    }
+}
+
+/// Waits until `reachability` no longer reports being offline.
+async fn until_reachable(reachability: &Computed<Reachability>) {
+    let (sender, receiver) = oneshot::channel();
+    let sender = RefCell::new(Some(sender));
+    let _guard = reachability.watch(move |context| {
+        if context.into_value().may_connect()
+            && let Some(sender) = sender.borrow_mut().take()
+        {
+            let _ = sender.send(());
+        }
+    });
+    let _ = receiver.await;
+}
+
+/// Returns a random number in `0.0..1.0`, good enough for jitter.
+#[allow(clippy::cast_precision_loss)]
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish() >> 11;
+    bits as f64 / (1_u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    use nami::{Binding, SignalExt};
+
+    use super::*;
+    use crate::task::TestExecutor;
+
+    type Outcome = Rc<RefCell<Option<Result<u32, ()>>>>;
+
+    /// Retries an operation failing `failures` times, counting attempts.
+    fn spawn_retry(
+        executor: &TestExecutor,
+        policy: RetryPolicy,
+        failures: u32,
+        should_retry: fn(&()) -> bool,
+    ) -> (Rc<Cell<u32>>, Outcome) {
+        let attempts = Rc::new(Cell::new(0));
+        let outcome = Outcome::default();
+        let counter = attempts.clone();
+        let result = outcome.clone();
+        executor
+            .spawn(async move {
+                let operation = || {
+                    counter.set(counter.get() + 1);
+                    let attempt = counter.get();
+                    async move {
+                        if attempt > failures {
+                            Ok(attempt)
+                        } else {
+                            Err(())
+                        }
+                    }
+                };
+                *result.borrow_mut() = Some(retry_if(&policy, operation, should_retry).await);
+            })
+            .detach();
+        (attempts, outcome)
+    }
+
+    #[test]
+    fn backoff_grows_up_to_the_limit() {
+        let policy = RetryPolicy::new().max_delay(Duration::from_secs(3));
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(40), Duration::from_secs(3));
+        for _ in 0..16 {
+            let delay = policy.delay(1);
+            assert!(delay > Duration::from_millis(799) && delay <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn retries_with_backoff_until_success() {
+        let executor = TestExecutor::new();
+        let policy = RetryPolicy::new().jitter(0.0);
+        let (attempts, outcome) = spawn_retry(&executor, policy, 2, |()| true);
+
+        executor.run_until_idle();
+        assert_eq!(attempts.get(), 1);
+        executor.advance(Duration::from_millis(500));
+        assert_eq!(attempts.get(), 2);
+        executor.advance(Duration::from_millis(999));
+        assert_eq!(attempts.get(), 2);
+        executor.advance(Duration::from_millis(1));
+        assert_eq!(*outcome.borrow(), Some(Ok(3)));
+    }
+
+    #[test]
+    fn gives_up_after_max_attempts_or_rejected_errors() {
+        let executor = TestExecutor::new();
+        let policy = RetryPolicy::new().max_attempts(3);
+        let (attempts, outcome) = spawn_retry(&executor, policy, 10, |()| true);
+        executor.advance(Duration::from_secs(10));
+        assert_eq!(attempts.get(), 3);
+        assert_eq!(*outcome.borrow(), Some(Err(())));
+
+        let (attempts, outcome) = spawn_retry(&executor, RetryPolicy::new(), 10, |()| false);
+        executor.run_until_idle();
+        assert_eq!(attempts.get(), 1);
+        assert_eq!(*outcome.borrow(), Some(Err(())));
+    }
+
+    #[test]
+    fn waits_for_the_network_while_offline() {
+        let executor = TestExecutor::new();
+        let network = Binding::container(Reachability::Offline);
+        let policy = RetryPolicy::new().reachability(network.clone().computed());
+        let (attempts, outcome) = spawn_retry(&executor, policy, 1, |()| true);
+
+        executor.advance(Duration::from_mins(1));
+        assert_eq!(attempts.get(), 1);
+        network.set(Reachability::Online);
+        executor.run_until_idle();
+        assert_eq!(*outcome.borrow(), Some(Ok(2)));
+    }
+}
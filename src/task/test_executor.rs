@@ -0,0 +1,336 @@
+//! A manually driven executor with a virtual clock.
+
+use alloc::{
+    collections::{BTreeMap, VecDeque, btree_map::OccupiedEntry},
+    rc::Rc,
+    sync::Arc,
+};
+use core::{
+    cell::{Cell, RefCell},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Instant,
+};
+
+use executor_core::{
+    AnyLocalExecutor, AnyLocalExecutorTask, LocalExecutor,
+    async_task::{AsyncTask, Runnable},
+};
+
+type Queue = Arc<Mutex<VecDeque<Runnable>>>;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Rc<Runtime>>> = const { RefCell::new(None) };
+}
+
+/// Returns the runtime of the innermost [`TestExecutor`] on this thread.
+pub(super) fn current() -> Option<Rc<Runtime>> {
+    CURRENT.with_borrow(Clone::clone)
+}
+
+pub(super) struct Runtime {
+    queue: Queue,
+    start: Instant,
+    elapsed: Cell<Duration>,
+    timers: RefCell<BTreeMap<(Duration, u64), Waker>>,
+    next_timer: Cell<u64>,
+}
+
+impl Runtime {
+    pub(super) fn spawn<Fut>(&self, future: Fut) -> AnyLocalExecutorTask<Fut::Output>
+    where
+        Fut: Future + 'static,
+        Fut::Output: 'static,
+    {
+        AnyLocalExecutor::new(Spawner(self.queue.clone())).spawn_local(future)
+    }
+
+    pub(super) fn now(&self) -> Instant {
+        self.start + self.elapsed.get()
+    }
+
+    fn pop(&self) -> Option<Runnable> {
+        self.queue
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop_front()
+    }
+}
+
+/// Queues tasks on a runtime, so they only run when its executor is driven.
+struct Spawner(Queue);
+
+impl LocalExecutor for Spawner {
+    type Task<T: 'static> = AsyncTask<T>;
+
+    fn spawn_local<Fut>(&self, future: Fut) -> AsyncTask<Fut::Output>
+    where
+        Fut: Future + 'static,
+    {
+        let queue = self.0.clone();
+        let schedule = move |runnable| {
+            queue
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push_back(runnable);
+        };
+        let (runnable, task) = executor_core::async_task::spawn_local(future, schedule);
+        runnable.schedule();
+        task
+    }
+}
+
+/// An executor for tests that only makes progress when told to.
+///
+/// While it exists, [`spawn_local`](super::spawn_local) queues tasks on it and
+/// [`sleep`](super::sleep) and [`now`](super::now) follow its virtual clock,
+/// which starts at zero and only moves on [`advance`](Self::advance). This
+/// covers everything built on them, such as
+/// [`animate`](crate::animation::animate) or a debounce written with `sleep`.
+///
+/// The executor belongs to the thread that created it; dropping it restores
+/// the previous executor.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+/// use waterui::reactive::binding;
+/// use waterui::task::{TestExecutor, sleep, spawn_local};
+///
+/// let executor = TestExecutor::new();
+/// let saved = binding(false);
+/// let flag = saved.clone();
+/// spawn_local(async move {
+///     sleep(Duration::from_millis(500)).await;
+///     flag.set(true);
+/// })
+/// .detach();
+///
+/// executor.advance(Duration::from_millis(499));
+/// assert!(!saved.get());
+/// executor.advance(Duration::from_millis(1));
+/// assert!(saved.get());
+/// ```
+#[derive(Debug)]
+pub struct TestExecutor {
+    runtime: Rc<Runtime>,
+    previous: Option<Rc<Runtime>>,
+}
+
+impl core::fmt::Debug for Runtime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Runtime")
+            .field("elapsed", &self.elapsed.get())
+            .field("timers", &self.timers.borrow().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for TestExecutor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestExecutor {
+    /// Creates a test executor and makes it the executor of the current thread.
+    #[must_use]
+    pub fn new() -> Self {
+        let runtime = Rc::new(Runtime {
+            queue: Queue::default(),
+            start: Instant::now(),
+            elapsed: Cell::new(Duration::ZERO),
+            timers: RefCell::default(),
+            next_timer: Cell::new(0),
+        });
+        let previous = CURRENT.with_borrow_mut(|current| current.replace(runtime.clone()));
+        Self { runtime, previous }
+    }
+
+    /// Spawns a task on this executor.
+    pub fn spawn<Fut>(&self, future: Fut) -> AnyLocalExecutorTask<Fut::Output>
+    where
+        Fut: Future + 'static,
+        Fut::Output: 'static,
+    {
+        self.runtime.spawn(future)
+    }
+
+    /// Runs tasks until none can make progress without advancing time.
+    pub fn run_until_idle(&self) {
+        while let Some(runnable) = self.runtime.pop() {
+            runnable.run();
+        }
+    }
+
+    /// Moves the virtual clock forward by `duration`.
+    ///
+    /// Timers fire in deadline order, and tasks woken by each one run before
+    /// the clock moves on, so timers started along the way fire too if they
+    /// fall within `duration`.
+    pub fn advance(&self, duration: Duration) {
+        let runtime = &self.runtime;
+        let target = runtime.elapsed.get() + duration;
+        self.run_until_idle();
+        loop {
+            let next = runtime
+                .timers
+                .borrow_mut()
+                .first_entry()
+                .filter(|timer| timer.key().0 <= target)
+                .map(OccupiedEntry::remove_entry);
+            let Some(((deadline, _), waker)) = next else {
+                break;
+            };
+            runtime.elapsed.set(deadline);
+            waker.wake();
+            self.run_until_idle();
+        }
+        runtime.elapsed.set(target);
+    }
+
+    /// Returns how far the virtual clock has been advanced.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.runtime.elapsed.get()
+    }
+
+    /// Returns the number of sleeping timers.
+    #[must_use]
+    pub fn pending_timers(&self) -> usize {
+        self.runtime.timers.borrow().len()
+    }
+}
+
+impl Drop for TestExecutor {
+    fn drop(&mut self) {
+        // Queued tasks hold the queue through their schedule function; drop them
+        // while this executor is still current so their timers unregister here
+        let queued = core::mem::take(
+            &mut *self
+                .runtime
+                .queue
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        drop(queued);
+        let previous = self.previous.take();
+        CURRENT.with_borrow_mut(|current| *current = previous);
+    }
+}
+
+/// A timer on the virtual clock of the current [`TestExecutor`].
+#[derive(Debug)]
+pub(super) struct Timer {
+    deadline: Duration,
+    id: Option<u64>,
+}
+
+impl Timer {
+    /// Returns `None` when no test executor is running on this thread.
+    pub(super) fn new(duration: Duration) -> Option<Self> {
+        let runtime = current()?;
+        Some(Self {
+            deadline: runtime.elapsed.get() + duration,
+            id: None,
+        })
+    }
+}
+
+impl Future for Timer {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // Without its executor, virtual time never advances
+        let Some(runtime) = current() else {
+            return Poll::Pending;
+        };
+        if runtime.elapsed.get() >= self.deadline {
+            return Poll::Ready(());
+        }
+        let id = *self.id.get_or_insert_with(|| {
+            let id = runtime.next_timer.get();
+            runtime.next_timer.set(id + 1);
+            id
+        });
+        runtime
+            .timers
+            .borrow_mut()
+            .insert((self.deadline, id), cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        if let (Some(id), Some(runtime)) = (self.id, current()) {
+            runtime.timers.borrow_mut().remove(&(self.deadline, id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::task::{now, sleep, spawn_local};
+
+    #[test]
+    fn timers_fire_in_deadline_order() {
+        let executor = TestExecutor::new();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        for (name, millis) in [("slow", 300), ("fast", 100)] {
+            let log = log.clone();
+            spawn_local(async move {
+                sleep(Duration::from_millis(millis)).await;
+                log.borrow_mut().push(name);
+            })
+            .detach();
+        }
+
+        executor.run_until_idle();
+        assert_eq!(executor.pending_timers(), 2);
+        executor.advance(Duration::from_millis(200));
+        assert_eq!(*log.borrow(), ["fast"]);
+        executor.advance(Duration::from_millis(100));
+        assert_eq!(*log.borrow(), ["fast", "slow"]);
+        assert_eq!(executor.pending_timers(), 0);
+    }
+
+    #[test]
+    fn timers_started_while_advancing_fire() {
+        let executor = TestExecutor::new();
+        let ticks = Rc::new(Cell::new(0));
+        let counter = ticks.clone();
+        let start = now();
+        spawn_local(async move {
+            loop {
+                sleep(Duration::from_secs(1)).await;
+                counter.set(counter.get() + 1);
+            }
+        })
+        .detach();
+
+        executor.advance(Duration::from_millis(3500));
+        assert_eq!(ticks.get(), 3);
+        assert_eq!(now() - start, Duration::from_millis(3500));
+    }
+
+    #[test]
+    fn dropped_tasks_cancel_their_timers() {
+        let executor = TestExecutor::new();
+        let task = spawn_local(sleep(Duration::from_secs(1)));
+        executor.run_until_idle();
+        assert_eq!(executor.pending_timers(), 1);
+        drop(task);
+        executor.run_until_idle();
+        assert_eq!(executor.pending_timers(), 0);
+    }
+}
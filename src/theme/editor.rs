@@ -0,0 +1,156 @@
+//! A sample theme editor for iterating on a design system at runtime.
+use crate::color::{ResolvedColor, Srgb};
+use crate::prelude::*;
+use crate::text::font::{FontWeight, ResolvedFont};
+use waterui_layout::stack::{hstack, vstack};
+
+use super::{DEFAULT_SPACING, ThemeSnapshot};
+
+type ColorSlot = fn(&mut ThemeSnapshot) -> &mut Option<ResolvedColor>;
+type FontSlot = fn(&mut ThemeSnapshot) -> &mut Option<ResolvedFont>;
+type Channel = fn(&mut Srgb) -> &mut f32;
+
+/// Font size used for font slots that have not been set yet.
+const DEFAULT_FONT_SIZE: f32 = 17.0;
+
+/// An editor that exposes every slot of a [`ThemeSnapshot`] as a control.
+///
+/// Pair it with [`Theme::bind`] to restyle the app while editing, and persist
+/// the result with [`ThemeSnapshot::to_json`].
+#[derive(Debug, Clone)]
+pub struct ThemeEditor {
+    snapshot: Binding<ThemeSnapshot>,
+}
+
+impl ThemeEditor {
+    /// Creates an editor for the given snapshot binding.
+    #[must_use]
+    pub fn new(snapshot: &Binding<ThemeSnapshot>) -> Self {
+        Self {
+            snapshot: snapshot.clone(),
+        }
+    }
+}
+
+impl View for ThemeEditor {
+    fn body(self, _env: &Environment) -> impl View {
+        let snapshot = self.snapshot;
+        let dark_mode = Binding::mapping(
+            &snapshot,
+            |s| s.color_scheme == Some(ColorScheme::Dark),
+            |binding, dark: bool| {
+                binding.with_mut(|s| {
+                    s.color_scheme = Some(if dark {
+                        ColorScheme::Dark
+                    } else {
+                        ColorScheme::Light
+                    });
+                });
+            },
+        );
+        let spacing = Binding::mapping(
+            &snapshot,
+            |s| f64::from(s.spacing.unwrap_or(DEFAULT_SPACING)),
+            #[allow(clippy::cast_possible_truncation)]
+            |binding, value: f64| binding.with_mut(|s| s.spacing = Some(value as f32)),
+        );
+
+        vstack((
+            Toggle::new(&dark_mode).label(text("Dark Mode")),
+            Slider::new(0.0..=32.0, &spacing).label(text("Spacing")),
+            vstack((
+                color_row(&snapshot, "Background", |s| &mut s.colors.background),
+                color_row(&snapshot, "Surface", |s| &mut s.colors.surface),
+                color_row(&snapshot, "Surface Variant", |s| {
+                    &mut s.colors.surface_variant
+                }),
+                color_row(&snapshot, "Border", |s| &mut s.colors.border),
+                color_row(&snapshot, "Foreground", |s| &mut s.colors.foreground),
+                color_row(&snapshot, "Muted Foreground", |s| {
+                    &mut s.colors.muted_foreground
+                }),
+                color_row(&snapshot, "Accent", |s| &mut s.colors.accent),
+                color_row(&snapshot, "Accent Foreground", |s| {
+                    &mut s.colors.accent_foreground
+                }),
+            )),
+            vstack((
+                font_row(&snapshot, "Body", |s| &mut s.fonts.body),
+                font_row(&snapshot, "Title", |s| &mut s.fonts.title),
+                font_row(&snapshot, "Headline", |s| &mut s.fonts.headline),
+                font_row(&snapshot, "Subheadline", |s| &mut s.fonts.subheadline),
+                font_row(&snapshot, "Caption", |s| &mut s.fonts.caption),
+                font_row(&snapshot, "Footnote", |s| &mut s.fonts.footnote),
+            )),
+        ))
+    }
+}
+
+/// Creates a [`ThemeEditor`] for the given snapshot binding.
+#[must_use]
+pub fn theme_editor(snapshot: &Binding<ThemeSnapshot>) -> ThemeEditor {
+    ThemeEditor::new(snapshot)
+}
+
+fn color_row(
+    snapshot: &Binding<ThemeSnapshot>,
+    name: &'static str,
+    slot: ColorSlot,
+) -> impl View + use<> {
+    let swatch = snapshot.clone().map(move |mut s: ThemeSnapshot| {
+        slot(&mut s).map_or_else(Color::transparent, |color| {
+            Color::from(color.to_srgb()).with_opacity(color.opacity)
+        })
+    });
+
+    hstack((
+        text(name),
+        watch(swatch, |color: Color| color.size(24.0, 24.0)),
+        vstack((
+            channel_slider(snapshot, slot, |c| &mut c.red).label(text("R")),
+            channel_slider(snapshot, slot, |c| &mut c.green).label(text("G")),
+            channel_slider(snapshot, slot, |c| &mut c.blue).label(text("B")),
+        )),
+    ))
+}
+
+/// A slider editing one sRGB channel of a color slot, setting the slot on first edit.
+fn channel_slider(snapshot: &Binding<ThemeSnapshot>, slot: ColorSlot, channel: Channel) -> Slider {
+    let value = Binding::mapping(
+        snapshot,
+        move |mut s| slot(&mut s).map_or(0.0, |color| f64::from(*channel(&mut color.to_srgb()))),
+        move |binding, value: f64| {
+            binding.with_mut(|s| {
+                let color = slot(s).get_or_insert_with(|| Srgb::new(0.0, 0.0, 0.0).into());
+                let mut srgb = color.to_srgb();
+                #[allow(clippy::cast_possible_truncation)]
+                {
+                    *channel(&mut srgb) = value as f32;
+                }
+                *color = ResolvedColor::from_srgb(srgb)
+                    .with_headroom(color.headroom)
+                    .with_opacity(color.opacity);
+            });
+        },
+    );
+    Slider::new(0.0..=1.0, &value)
+}
+
+fn font_row(snapshot: &Binding<ThemeSnapshot>, name: &'static str, slot: FontSlot) -> Slider {
+    let size = Binding::mapping(
+        snapshot,
+        move |mut s| f64::from(slot(&mut s).as_ref().map_or(DEFAULT_FONT_SIZE, |f| f.size)),
+        move |binding, value: f64| {
+            binding.with_mut(|s| {
+                let font = slot(s).get_or_insert_with(|| {
+                    ResolvedFont::new(DEFAULT_FONT_SIZE, FontWeight::Normal)
+                });
+                #[allow(clippy::cast_possible_truncation)]
+                {
+                    font.size = value as f32;
+                }
+            });
+        },
+    );
+    Slider::new(8.0..=64.0, &size).label(text(name))
+}
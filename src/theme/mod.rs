@@ -13,13 +13,15 @@
 //!   installed; others retain their existing values or use native defaults.
 //! - **Composable**: Theme is composed of smaller structs (`ColorSettings`, `FontSettings`)
 //!   for easier maintenance and partial customization.
+//! - **Serializable**: A theme can be captured as a [`ThemeSnapshot`] and persisted as JSON
+//!   with [`Theme::to_json`] / [`Theme::from_json`].
 //!
 //! ## For Users
 //!
 //! ### Quick Start
 //!
 //! ```ignore
-//! use waterui::{Environment, theme::{Theme, ColorScheme, ColorSettings}};
+//! use waterui::{Environment, theme::{Theme, ColorSchedule, ColorScheme, ColorSettings}};
 //! use waterui_core::plugin::Plugin;
 //! use nami::binding;
 //!
@@ -30,10 +32,14 @@
 //!     .color_scheme(ColorScheme::Dark)
 //!     .install(&mut env);
 //!
-//! // Or use a reactive binding that follows system preference
-//! let system_scheme = binding(ColorScheme::Light);
+//! // Follow the system appearance reported by the native backend
 //! Theme::new()
-//!     .color_scheme(system_scheme.clone())
+//!     .color_scheme(ColorScheme::System)
+//!     .install(&mut env);
+//!
+//! // Or go dark from sunset to sunrise
+//! Theme::new()
+//!     .color_schedule(ColorSchedule::sunset_to_sunrise(52.52, 13.40))
 //!     .install(&mut env);
 //!
 //! // Customize specific colors
@@ -60,6 +66,7 @@
 //! **Color Scheme** (`theme::ColorScheme`):
 //! - `Light` - Light appearance
 //! - `Dark` - Dark appearance
+//! - `System` - Follows the operating system appearance
 //!
 //! **Colors** (`theme::color::*`):
 //! - `Background` - Primary background
@@ -70,10 +77,49 @@
 //! - `MutedForeground` - Secondary/dimmed text
 //! - `Accent` - Interactive elements, links
 //! - `AccentForeground` - Text on accent backgrounds
+//! - `Destructive` - Errors and destructive actions
+//!
+//! **Named colors** ([`ThemeColor`]): App-defined tokens with a light and a
+//! dark variant, registered with [`Theme::color_token`]:
+//!
+//! ```ignore
+//! Theme::new()
+//!     .color_token("surface", Srgb::from_hex("#FFFFFF"), Srgb::from_hex("#1C1C1E"))
+//!     .install(&mut env);
+//!
+//! card(content).background(ThemeColor::token("surface"))
+//! ```
 //!
 //! **Fonts**: Use standard font tokens from `waterui::text::font`:
 //! - `Body`, `Title`, `Headline`, `Subheadline`, `Caption`, `Footnote`
 //!
+//! **Feedback** ([`FeedbackSettings`]): Haptics and sounds that buttons,
+//! toggles and sliders play, set with [`Theme::feedback`]:
+//!
+//! ```ignore
+//! Theme::new()
+//!     .feedback(FeedbackSettings::new().sounds(true))
+//!     .install(&mut env);
+//! ```
+//!
+//! **Spacing**: Read the base spacing unit with [`current_spacing`]. Stacks
+//! accept tokens such as `Spacing::M`, which are multiples of this unit.
+//!
+//! ### Persisting and Editing Themes
+//!
+//! ```ignore
+//! use waterui::theme::{Theme, ThemeSnapshot, theme_editor};
+//! use nami::binding;
+//!
+//! // Restore a user-customized theme
+//! let theme = Theme::from_json(&saved_json)?;
+//!
+//! // Or edit a theme live: every slot follows the binding
+//! let snapshot = binding(ThemeSnapshot::default());
+//! Theme::bind(&snapshot).install(&mut env);
+//! let editor = theme_editor(&snapshot);
+//! ```
+//!
 //! ## For Maintainers
 //!
 //! ### How It Works
@@ -88,52 +134,43 @@
 //! ### Native Backend Integration
 //!
 //! Native backends should:
-//! 1. Create a `Binding<ColorScheme>` that tracks system appearance
-//! 2. Create `Computed<ResolvedColor>` signals that react to color scheme changes
-//! 3. Install via `Theme::new().color_scheme(binding).colors(ColorSettings::new()...)`
-
+//! 1. Install a signal that tracks the system appearance with
+//!    [`install_system_color_scheme`]; it drives [`ColorScheme::System`] and
+//!    is used when the app sets no color scheme
+//! 2. Create `Computed<ResolvedColor>` signals that react to
+//!    [`current_color_scheme`]
+//! 3. Install via `Theme::new().colors(ColorSettings::new()...)`
+
+mod editor;
+mod schedule;
+mod snapshot;
+
+pub use editor::{ThemeEditor, theme_editor};
+pub use schedule::ColorSchedule;
+pub use snapshot::{ColorSnapshot, FontSnapshot, ThemeSnapshot};
+
+use alloc::collections::BTreeMap;
 use core::marker::PhantomData;
 
-use nami::{Computed, SignalExt, impl_constant, signal::IntoSignal};
-use waterui_core::{Environment, env::Store, plugin::Plugin};
-
-use crate::{
-    color::ResolvedColor,
-    text::font::{Body, Caption, Footnote, Headline, ResolvedFont, Subheadline, Title},
+use nami::{Binding, Computed, Signal, SignalExt, impl_constant, signal::IntoSignal};
+use waterui_core::{
+    Environment, Str, env::Store, feedback::install_feedback_settings, metadata::MetadataKey,
+    plugin::Plugin, resolve::Resolvable,
 };
+use waterui_layout::spacing::SpacingUnit;
 
-// ============================================================================
-// ColorScheme - Light/Dark appearance preference
-// ============================================================================
+pub use waterui_core::feedback::{ControlFeedback, FeedbackSettings, FeedbackSound, HapticStyle};
 
-/// The color scheme preference for the UI.
-///
-/// This is used to switch between light and dark appearances. Native backends
-/// typically bind this to the system appearance setting.
-///
-/// # Example
-///
-/// ```ignore
-/// use waterui::theme::{Theme, ColorScheme};
-/// use nami::binding;
-///
-/// // Static: always dark
-/// Theme::new().color_scheme(ColorScheme::Dark);
-///
-/// // Reactive: follows system
-/// let system_scheme = binding(ColorScheme::Light);
-/// Theme::new().color_scheme(system_scheme);
-/// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
-pub enum ColorScheme {
-    /// Light appearance (light backgrounds, dark text).
-    #[default]
-    Light,
-    /// Dark appearance (dark backgrounds, light text).
-    Dark,
-}
+pub use crate::color::{
+    ColorScheme, SystemColorScheme, current_color_scheme, current_system_color_scheme,
+    install_color_scheme, install_system_color_scheme,
+};
 
-impl_constant!(ColorScheme);
+use crate::{
+    clock::coarse_clock,
+    color::{Color, DestructiveColors, ResolvedColor},
+    text::font::{Body, Caption, Footnote, Headline, ResolvedFont, Subheadline, Title},
+};
 
 // ============================================================================
 // ColorSettings - All color overrides
@@ -164,6 +201,7 @@ pub struct ColorSettings {
     muted_foreground: Option<Computed<ResolvedColor>>,
     accent: Option<Computed<ResolvedColor>>,
     accent_foreground: Option<Computed<ResolvedColor>>,
+    destructive: Option<Computed<ResolvedColor>>,
 }
 
 impl ColorSettings {
@@ -229,6 +267,13 @@ impl ColorSettings {
         self
     }
 
+    /// Sets the destructive color (errors, destructive actions).
+    #[must_use]
+    pub fn destructive(mut self, color: impl IntoSignal<ResolvedColor>) -> Self {
+        self.destructive = Some(color.into_signal().computed());
+        self
+    }
+
     /// Installs the color settings into the environment.
     /// Only non-None fields are installed.
     fn install(self, env: &mut Environment) {
@@ -256,6 +301,34 @@ impl ColorSettings {
         if let Some(signal) = self.accent_foreground {
             install_color_signal::<color::AccentForeground>(env, signal);
         }
+        if let Some(signal) = self.destructive {
+            install_destructive_color(env, signal);
+        }
+    }
+
+    /// Installs these colors as the dark variants of the slots in the environment.
+    ///
+    /// Slots set here keep their current color in light appearance.
+    fn install_dark(self, env: &mut Environment) {
+        install_dark_slot::<color::Background>(env, self.background);
+        install_dark_slot::<color::Surface>(env, self.surface);
+        install_dark_slot::<color::SurfaceVariant>(env, self.surface_variant);
+        install_dark_slot::<color::Border>(env, self.border);
+        install_dark_slot::<color::Foreground>(env, self.foreground);
+        install_dark_slot::<color::MutedForeground>(env, self.muted_foreground);
+        install_dark_slot::<color::Accent>(env, self.accent);
+        install_dark_slot::<color::AccentForeground>(env, self.accent_foreground);
+        if let Some(dark) = self.destructive {
+            let light = Color::new(color::Destructive).resolve(env);
+            install_destructive_colors(env, light, dark);
+        }
+    }
+}
+
+fn install_dark_slot<T: 'static>(env: &mut Environment, dark: Option<Computed<ResolvedColor>>) {
+    if let Some(dark) = dark {
+        let light = resolve_color_slot::<T>(env);
+        install_color_signals::<T>(env, light, dark);
     }
 }
 
@@ -365,7 +438,7 @@ impl FontSettings {
 // Theme - Composes all settings
 // ============================================================================
 
-/// A theme configuration composed of color scheme, colors, and fonts.
+/// A theme configuration composed of color scheme, colors, fonts, and spacing.
 ///
 /// Use the builder pattern to configure what to override. Only specified
 /// values are installed; others retain existing values.
@@ -388,7 +461,13 @@ impl FontSettings {
 pub struct Theme {
     color_scheme: Option<Computed<ColorScheme>>,
     colors: Option<ColorSettings>,
+    dark_colors: Option<ColorSettings>,
     fonts: Option<FontSettings>,
+    spacing: Option<Computed<f32>>,
+    feedback: Option<Computed<FeedbackSettings>>,
+    color_tokens: BTreeMap<Str, ColorToken>,
+    /// Snapshot every slot follows, set by [`Theme::bind`].
+    bound: Option<Binding<ThemeSnapshot>>,
 }
 
 impl Theme {
@@ -403,12 +482,22 @@ impl Theme {
     /// Accepts any value that implements `IntoSignal<ColorScheme>`:
     /// - Static: `ColorScheme::Dark`
     /// - Reactive: `binding(ColorScheme::Light)`
+    /// - Following the system: `ColorScheme::System`
     #[must_use]
     pub fn color_scheme(mut self, scheme: impl IntoSignal<ColorScheme>) -> Self {
         self.color_scheme = Some(scheme.into_signal().computed());
         self
     }
 
+    /// Switches between light and dark appearance on a schedule.
+    ///
+    /// The schedule is evaluated against the shared [`coarse_clock`].
+    #[must_use]
+    pub fn color_schedule(mut self, schedule: ColorSchedule) -> Self {
+        self.color_scheme = Some(schedule.scheme(coarse_clock()));
+        self
+    }
+
     /// Sets the color settings.
     #[must_use]
     pub fn colors(mut self, colors: ColorSettings) -> Self {
@@ -416,12 +505,66 @@ impl Theme {
         self
     }
 
+    /// Sets the colors used in dark appearance.
+    ///
+    /// Slots set here switch with [`current_color_scheme`], including inside
+    /// subtrees that force a scheme with `preferred_color_scheme`. Their
+    /// light appearance keeps the color from [`colors`](Self::colors) or the
+    /// native default.
+    #[must_use]
+    pub fn dark_colors(mut self, colors: ColorSettings) -> Self {
+        self.dark_colors = Some(colors);
+        self
+    }
+
+    /// Defines the named color `name`, with variants for light and dark
+    /// appearance.
+    ///
+    /// Views use it with [`ThemeColor::token`]. A token defined again,
+    /// here or by a theme installed further down the tree, replaces the
+    /// earlier definition.
+    #[must_use]
+    pub fn color_token(
+        mut self,
+        name: impl Into<Str>,
+        light: impl Into<Color>,
+        dark: impl Into<Color>,
+    ) -> Self {
+        self.color_tokens.insert(
+            name.into(),
+            ColorToken {
+                light: light.into(),
+                dark: dark.into(),
+            },
+        );
+        self
+    }
+
     /// Sets the font settings.
     #[must_use]
     pub fn fonts(mut self, fonts: FontSettings) -> Self {
         self.fonts = Some(fonts);
         self
     }
+
+    /// Sets the base spacing unit, in points.
+    #[must_use]
+    pub fn spacing(mut self, spacing: impl IntoSignal<f64>) -> Self {
+        #[allow(clippy::cast_possible_truncation)]
+        let spacing = spacing.into_signal().map(|s| s as f32);
+        self.spacing = Some(spacing.computed());
+        self
+    }
+
+    /// Sets the haptic and sound feedback of built-in controls.
+    ///
+    /// Buttons, toggles and sliders play it through the feedback player of
+    /// the native backend. Pass a binding to let users switch it off.
+    #[must_use]
+    pub fn feedback(mut self, feedback: impl IntoSignal<FeedbackSettings>) -> Self {
+        self.feedback = Some(feedback.into_signal().computed());
+        self
+    }
 }
 
 impl Plugin for Theme {
@@ -430,9 +573,14 @@ impl Plugin for Theme {
     /// Only non-None fields are installed. Existing values for unspecified
     /// fields remain unchanged.
     fn install(self, env: &mut Environment) {
+        // Follow a bound snapshot; slots set explicitly below take precedence
+        if let Some(bound) = &self.bound {
+            snapshot::install_bound(env, bound);
+        }
+
         // Install color scheme if specified
         if let Some(scheme) = self.color_scheme {
-            env.insert(ColorSchemeSignal(scheme));
+            install_color_scheme(env, scheme);
         }
 
         // Install color settings if specified
@@ -440,13 +588,47 @@ impl Plugin for Theme {
             colors.install(env);
         }
 
+        // Install dark variants on top of the light colors
+        if let Some(colors) = self.dark_colors {
+            colors.install_dark(env);
+        }
+
         // Install font settings if specified
         if let Some(fonts) = self.fonts {
             fonts.install(env);
         }
+
+        // Install spacing if specified
+        if let Some(spacing) = self.spacing {
+            env.insert(SpacingUnit(spacing));
+        }
+
+        // Install control feedback if specified
+        if let Some(feedback) = self.feedback {
+            install_feedback_settings(env, feedback);
+        }
+
+        // Add named colors to the tokens already installed
+        if !self.color_tokens.is_empty() {
+            let mut tokens = env.get::<ColorTokens>().cloned().unwrap_or_default();
+            tokens.0.extend(self.color_tokens);
+            env.insert(tokens);
+        }
     }
 }
 
+/// Metadata forcing the color scheme of a subtree.
+///
+/// Attached by `preferred_color_scheme` together with an environment in which
+/// [`current_color_scheme`] reports the forced scheme, so theme colors and
+/// scheme-aware views already follow it. Native backends use the signal,
+/// which is always `Light` or `Dark`, to switch the appearance of platform
+/// controls in the subtree.
+#[derive(Debug, Clone)]
+pub struct PreferredColorScheme(pub Computed<ColorScheme>);
+
+impl MetadataKey for PreferredColorScheme {}
+
 // ============================================================================
 // Color Tokens - Resolvable types for each color slot
 // ============================================================================
@@ -486,27 +668,88 @@ pub mod color {
     define_color_token!(MutedForeground, "Secondary/dimmed text color.");
     define_color_token!(Accent, "Accent color for interactive elements.");
     define_color_token!(AccentForeground, "Foreground on accent backgrounds.");
+
+    pub use waterui_color::Destructive;
+}
+
+/// A named color defined by the app's theme.
+///
+/// Resolves to the light or dark variant registered with
+/// [`Theme::color_token`], following [`current_color_scheme`]. An undefined
+/// token resolves to transparent.
+#[derive(Debug, Clone)]
+pub struct ThemeColor {
+    name: Str,
+}
+
+impl ThemeColor {
+    /// Refers to the color token `name`.
+    #[must_use]
+    pub fn token(name: impl Into<Str>) -> Self {
+        Self { name: name.into() }
+    }
 }
 
+impl Resolvable for ThemeColor {
+    type Resolved = ResolvedColor;
+
+    fn resolve(&self, env: &Environment) -> impl Signal<Output = Self::Resolved> {
+        let token = env
+            .get::<ColorTokens>()
+            .and_then(|tokens| tokens.0.get(&self.name));
+        token.map_or_else(
+            || Computed::constant(TRANSPARENT),
+            |token| {
+                current_color_scheme(env)
+                    .zip(token.light.resolve(env))
+                    .zip(token.dark.resolve(env))
+                    .map(|((scheme, light), dark)| if scheme.is_dark() { dark } else { light })
+                    .computed()
+            },
+        )
+    }
+}
+
+impl_constant!(ThemeColor);
+
 // ============================================================================
 // Internal: Storage and Resolution
 // ============================================================================
 
-/// Storage for the color scheme signal.
-#[derive(Clone)]
-struct ColorSchemeSignal(Computed<ColorScheme>);
+/// The two variants of a named color.
+#[derive(Debug, Clone)]
+struct ColorToken {
+    light: Color,
+    dark: Color,
+}
+
+/// Named colors installed by themes.
+#[derive(Debug, Clone, Default)]
+struct ColorTokens(BTreeMap<Str, ColorToken>);
+
+const TRANSPARENT: ResolvedColor = ResolvedColor {
+    red: 0.0,
+    green: 0.0,
+    blue: 0.0,
+    headroom: 0.0,
+    opacity: 0.0,
+};
 
 /// Internal storage for a color signal in the environment.
+///
+/// Slots with a dark variant pick one of the two signals by color scheme.
 #[derive(Clone)]
 struct ColorSlotValue<T> {
     signal: Computed<ResolvedColor>,
+    dark: Option<Computed<ResolvedColor>>,
     _marker: PhantomData<T>,
 }
 
 impl<T> ColorSlotValue<T> {
-    const fn new(signal: Computed<ResolvedColor>) -> Self {
+    const fn new(signal: Computed<ResolvedColor>, dark: Option<Computed<ResolvedColor>>) -> Self {
         Self {
             signal,
+            dark,
             _marker: PhantomData,
         }
     }
@@ -514,21 +757,25 @@ impl<T> ColorSlotValue<T> {
 
 /// Resolves a color slot by looking up the stored signal.
 ///
+/// Slots with a dark variant follow [`current_color_scheme`] of `env`.
 /// Returns a transparent fallback if no signal is installed. Native backends
 /// should always install proper defaults.
 fn resolve_color_slot<T: 'static>(env: &Environment) -> Computed<ResolvedColor> {
     env.get::<ColorSlotValue<T>>().map_or_else(
-        || {
-            // Fallback: transparent (native should provide real defaults)
-            Computed::constant(ResolvedColor {
-                red: 0.0,
-                green: 0.0,
-                blue: 0.0,
-                headroom: 0.0,
-                opacity: 0.0,
-            })
+        // Fallback: transparent (native should provide real defaults)
+        || Computed::constant(TRANSPARENT),
+        |v| {
+            v.dark.as_ref().map_or_else(
+                || v.signal.clone(),
+                |dark| {
+                    current_color_scheme(env)
+                        .zip(v.signal.clone())
+                        .zip(dark.clone())
+                        .map(|((scheme, light), dark)| if scheme.is_dark() { dark } else { light })
+                        .computed()
+                },
+            )
         },
-        |v| v.signal.clone(),
     )
 }
 
@@ -536,13 +783,15 @@ fn resolve_color_slot<T: 'static>(env: &Environment) -> Computed<ResolvedColor>
 // Public API for Native Backends (FFI)
 // ============================================================================
 
-/// Returns the current color scheme signal from the environment.
+pub use waterui_layout::spacing::DEFAULT_SPACING;
+
+/// Returns the base spacing signal from the environment.
 ///
-/// If no color scheme is installed, returns a constant `Light` signal.
+/// If no spacing is installed, returns a constant [`DEFAULT_SPACING`] signal.
 #[must_use]
-pub fn current_color_scheme(env: &Environment) -> Computed<ColorScheme> {
-    env.get::<ColorSchemeSignal>()
-        .map_or_else(|| Computed::constant(ColorScheme::Light), |s| s.0.clone())
+pub fn current_spacing(env: &Environment) -> Computed<f32> {
+    env.get::<SpacingUnit>()
+        .map_or_else(|| Computed::constant(DEFAULT_SPACING), |s| s.0.clone())
 }
 
 /// Installs an explicit color signal for a specific slot.
@@ -560,7 +809,45 @@ pub fn current_color_scheme(env: &Environment) -> Computed<ColorScheme> {
 /// install_color_signal::<color::Foreground>(&mut env, dark_mode_color);
 /// ```
 pub fn install_color_signal<T: 'static>(env: &mut Environment, signal: Computed<ResolvedColor>) {
-    env.insert(ColorSlotValue::<T>::new(signal));
+    env.insert(ColorSlotValue::<T>::new(signal, None));
+}
+
+/// Installs separate light and dark color signals for a specific slot.
+///
+/// The slot follows [`current_color_scheme`], so subtrees that force a color
+/// scheme with `preferred_color_scheme` resolve the matching variant.
+pub fn install_color_signals<T: 'static>(
+    env: &mut Environment,
+    light: Computed<ResolvedColor>,
+    dark: Computed<ResolvedColor>,
+) {
+    env.insert(ColorSlotValue::<T>::new(light, Some(dark)));
+}
+
+/// Installs the color signal of the [`color::Destructive`] slot.
+///
+/// The destructive color is defined next to [`Color`] so that component crates
+/// can use it, and is stored as [`DestructiveColors`].
+pub fn install_destructive_color(env: &mut Environment, signal: Computed<ResolvedColor>) {
+    env.insert(DestructiveColors {
+        light: signal,
+        dark: None,
+    });
+}
+
+/// Installs separate light and dark signals for the [`color::Destructive`] slot.
+///
+/// Like the other slots, the variant is picked by the [`current_color_scheme`]
+/// of the environment the color is resolved in.
+pub fn install_destructive_colors(
+    env: &mut Environment,
+    light: Computed<ResolvedColor>,
+    dark: Computed<ResolvedColor>,
+) {
+    env.insert(DestructiveColors {
+        light,
+        dark: Some(dark),
+    });
 }
 
 /// Installs an explicit font signal for a specific slot.
@@ -572,10 +859,78 @@ pub fn install_font_signal<T: 'static>(env: &mut Environment, signal: Computed<R
     env.insert(Store::<T, Computed<ResolvedFont>>::new(signal));
 }
 
-/// Installs a color scheme signal.
-///
-/// This is used by native backends to inject a reactive color scheme that
-/// tracks the system appearance setting.
-pub fn install_color_scheme(env: &mut Environment, signal: Computed<ColorScheme>) {
-    env.insert(ColorSchemeSignal(signal));
+#[cfg(test)]
+#[allow(clippy::float_cmp)]
+mod tests {
+    use nami::Signal;
+    use waterui_core::resolve::Resolvable;
+
+    use super::*;
+    use crate::color::Srgb;
+
+    const fn gray(level: f32) -> ResolvedColor {
+        ResolvedColor {
+            red: level,
+            green: level,
+            blue: level,
+            headroom: 0.0,
+            opacity: 1.0,
+        }
+    }
+
+    #[test]
+    fn dark_colors_follow_color_scheme() {
+        let mut env = Environment::new();
+        Theme::new()
+            .colors(ColorSettings::new().background(gray(1.0)))
+            .dark_colors(ColorSettings::new().background(gray(0.0)))
+            .install(&mut env);
+        assert_eq!(color::Background.resolve(&env).get().red, 1.0);
+
+        // A subtree forcing the dark scheme resolves the dark variant
+        let mut dark = env.clone();
+        install_color_scheme(&mut dark, ColorScheme::Dark);
+        assert_eq!(color::Background.resolve(&dark).get().red, 0.0);
+        assert_eq!(color::Background.resolve(&env).get().red, 1.0);
+    }
+
+    #[test]
+    fn destructive_dark_color_follows_subtree_scheme() {
+        let mut env = Environment::new();
+        Theme::new()
+            .colors(ColorSettings::new().destructive(gray(1.0)))
+            .dark_colors(ColorSettings::new().destructive(gray(0.0)))
+            .install(&mut env);
+        assert_eq!(color::Destructive.resolve(&env).get().red, 1.0);
+
+        // Installing the scheme after the theme still switches the variant
+        let mut dark = env.clone();
+        install_color_scheme(&mut dark, ColorScheme::Dark);
+        assert_eq!(color::Destructive.resolve(&dark).get().red, 0.0);
+        assert_eq!(color::Destructive.resolve(&env).get().red, 1.0);
+    }
+
+    #[test]
+    fn color_tokens_follow_color_scheme() {
+        let white = Srgb::new(1.0, 1.0, 1.0);
+        let black = Srgb::new(0.0, 0.0, 0.0);
+        let mut env = Environment::new();
+        Theme::new()
+            .color_token("surface", white, black)
+            .install(&mut env);
+        Theme::new()
+            .color_token("ink", black, white)
+            .install(&mut env);
+
+        let surface = ThemeColor::token("surface");
+        assert_eq!(surface.resolve(&env).get().red, 1.0);
+        assert_eq!(ThemeColor::token("ink").resolve(&env).get().red, 0.0);
+        assert_eq!(
+            ThemeColor::token("missing").resolve(&env).get().opacity,
+            0.0
+        );
+
+        install_color_scheme(&mut env, ColorScheme::Dark);
+        assert_eq!(surface.resolve(&env).get().red, 0.0);
+    }
 }
@@ -0,0 +1,228 @@
+//! Switching between light and dark appearance on a schedule.
+
+use nami::{Computed, SignalExt, signal::IntoComputed};
+
+use crate::time::{OffsetDateTime, Time, UtcOffset};
+
+use super::ColorScheme;
+
+/// Days between the Unix epoch and the Julian day epoch.
+const UNIX_EPOCH_JULIAN: f64 = 2_440_587.5;
+
+/// Julian date of the J2000 epoch.
+const J2000: f64 = 2_451_545.0;
+
+/// When to use the dark appearance.
+///
+/// A schedule turns a clock into a color scheme signal with
+/// [`scheme`](Self::scheme). [`Theme::color_schedule`](super::Theme::color_schedule)
+/// drives it from the shared [`coarse_clock`](crate::clock::coarse_clock).
+///
+/// # Example
+///
+/// ```ignore
+/// use waterui::time::{Time, UtcOffset};
+/// use waterui::theme::{ColorSchedule, Theme};
+///
+/// // Dark from 22:00 to 07:00 in UTC+2
+/// let offset = UtcOffset::from_hms(2, 0, 0)?;
+/// let schedule = ColorSchedule::between(Time::from_hms(22, 0, 0)?, Time::from_hms(7, 0, 0)?, offset);
+/// Theme::new().color_schedule(schedule).install(&mut env);
+///
+/// // Dark from sunset to sunrise in Berlin
+/// Theme::new()
+///     .color_schedule(ColorSchedule::sunset_to_sunrise(52.52, 13.40))
+///     .install(&mut env);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorSchedule(Schedule);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Schedule {
+    Window {
+        dark_from: Time,
+        light_from: Time,
+        offset: UtcOffset,
+    },
+    Solar {
+        latitude: f64,
+        longitude: f64,
+    },
+}
+
+impl ColorSchedule {
+    /// Uses the dark appearance from `dark_from` until `light_from` every day.
+    ///
+    /// Both times are wall-clock times in `offset`. The window may wrap around
+    /// midnight.
+    #[must_use]
+    pub const fn between(dark_from: Time, light_from: Time, offset: UtcOffset) -> Self {
+        Self(Schedule::Window {
+            dark_from,
+            light_from,
+            offset,
+        })
+    }
+
+    /// Uses the dark appearance from sunset until sunrise.
+    ///
+    /// `latitude` and `longitude` are in degrees, north and east positive.
+    /// During polar night the appearance stays dark, during midnight sun it
+    /// stays light.
+    #[must_use]
+    pub const fn sunset_to_sunrise(latitude: f64, longitude: f64) -> Self {
+        Self(Schedule::Solar {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Returns the color scheme this schedule selects at `now`.
+    #[must_use]
+    pub fn scheme_at(&self, now: OffsetDateTime) -> ColorScheme {
+        let dark = match self.0 {
+            Schedule::Window {
+                dark_from,
+                light_from,
+                offset,
+            } => {
+                let time = now.to_offset(offset).time();
+                if dark_from <= light_from {
+                    dark_from <= time && time < light_from
+                } else {
+                    time >= dark_from || time < light_from
+                }
+            }
+            Schedule::Solar {
+                latitude,
+                longitude,
+            } => is_night(latitude, longitude, now),
+        };
+        if dark {
+            ColorScheme::Dark
+        } else {
+            ColorScheme::Light
+        }
+    }
+
+    /// Returns a color scheme signal that follows `clock`.
+    #[must_use]
+    pub fn scheme(self, clock: impl IntoComputed<OffsetDateTime>) -> Computed<ColorScheme> {
+        clock
+            .into_computed()
+            .map(move |now| self.scheme_at(now))
+            .computed()
+    }
+}
+
+/// Whether the sun is below the horizon at `now`, using the sunrise equation.
+#[allow(clippy::cast_precision_loss)]
+fn is_night(latitude: f64, longitude: f64, now: OffsetDateTime) -> bool {
+    let julian = now.unix_timestamp() as f64 / 86_400.0 + UNIX_EPOCH_JULIAN;
+    // Solar noon closest to `now`, so sunrise and sunset bracket it
+    let day = (julian - J2000 + longitude / 360.0).round();
+    let mean_noon = day - longitude / 360.0;
+
+    let anomaly = 0.985_600_28f64
+        .mul_add(mean_noon, 357.5291)
+        .rem_euclid(360.0);
+    let anomaly_rad = anomaly.to_radians();
+    let center = 0.0003f64.mul_add(
+        (3.0 * anomaly_rad).sin(),
+        1.9148f64.mul_add(anomaly_rad.sin(), 0.02 * (2.0 * anomaly_rad).sin()),
+    );
+    let ecliptic = (anomaly + center + 180.0 + 102.9372)
+        .rem_euclid(360.0)
+        .to_radians();
+    let transit = 0.0069f64.mul_add(
+        -(2.0 * ecliptic).sin(),
+        0.0053f64.mul_add(anomaly_rad.sin(), J2000 + mean_noon),
+    );
+    let declination = (ecliptic.sin() * 23.4397f64.to_radians().sin()).asin();
+
+    let latitude = latitude.to_radians();
+    let cos_hour_angle = latitude
+        .sin()
+        .mul_add(-declination.sin(), (-0.833f64).to_radians().sin())
+        / (latitude.cos() * declination.cos());
+    if cos_hour_angle >= 1.0 {
+        return true;
+    }
+    if cos_hour_angle <= -1.0 {
+        return false;
+    }
+    let half_day = cos_hour_angle.acos().to_degrees() / 360.0;
+    (julian - transit).abs() > half_day
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::time::{Date, Month};
+
+    use super::*;
+
+    fn utc(month: Month, day: u8, hour: u8, minute: u8) -> OffsetDateTime {
+        Date::from_calendar_date(2024, month, day)
+            .unwrap()
+            .with_hms(hour, minute, 0)
+            .unwrap()
+            .assume_utc()
+    }
+
+    #[test]
+    fn window_wraps_around_midnight() {
+        let offset = UtcOffset::from_hms(2, 0, 0).unwrap();
+        let schedule = ColorSchedule::between(
+            Time::from_hms(22, 0, 0).unwrap(),
+            Time::from_hms(7, 0, 0).unwrap(),
+            offset,
+        );
+
+        // 21:30 UTC is 23:30 in UTC+2
+        assert_eq!(
+            schedule.scheme_at(utc(Month::June, 21, 21, 30)),
+            ColorScheme::Dark
+        );
+        assert_eq!(
+            schedule.scheme_at(utc(Month::June, 21, 5, 30)),
+            ColorScheme::Light
+        );
+        assert_eq!(
+            schedule.scheme_at(utc(Month::June, 21, 19, 59)),
+            ColorScheme::Light
+        );
+    }
+
+    #[test]
+    fn solar_schedule_follows_sunrise_and_sunset() {
+        // London on the summer solstice: sunrise 03:43, sunset 20:21 UTC
+        let london = ColorSchedule::sunset_to_sunrise(51.5, -0.13);
+        assert_eq!(
+            london.scheme_at(utc(Month::June, 21, 3, 30)),
+            ColorScheme::Dark
+        );
+        assert_eq!(
+            london.scheme_at(utc(Month::June, 21, 4, 0)),
+            ColorScheme::Light
+        );
+        assert_eq!(
+            london.scheme_at(utc(Month::June, 21, 20, 10)),
+            ColorScheme::Light
+        );
+        assert_eq!(
+            london.scheme_at(utc(Month::June, 21, 20, 35)),
+            ColorScheme::Dark
+        );
+
+        // Tromsø has midnight sun in June and polar night in December
+        let tromso = ColorSchedule::sunset_to_sunrise(69.65, 18.96);
+        assert_eq!(
+            tromso.scheme_at(utc(Month::June, 21, 23, 0)),
+            ColorScheme::Light
+        );
+        assert_eq!(
+            tromso.scheme_at(utc(Month::December, 21, 11, 0)),
+            ColorScheme::Dark
+        );
+    }
+}
@@ -0,0 +1,398 @@
+//! Serializable theme snapshots.
+//!
+//! A [`ThemeSnapshot`] captures every theme slot as plain data, so user-customized
+//! themes can be persisted as JSON and restored later, or edited live through a
+//! [`ThemeEditor`](super::ThemeEditor).
+
+use alloc::string::String;
+
+use nami::{Binding, Signal, SignalExt};
+use serde::{Deserialize, Serialize};
+use waterui_core::Environment;
+use waterui_layout::spacing::SpacingUnit;
+
+use super::{
+    ColorScheme, ColorSettings, FontSettings, Theme, color, current_color_scheme, current_spacing,
+    install_color_scheme, install_color_signal, install_destructive_color, install_font_signal,
+    resolve_color_slot,
+};
+use crate::{
+    color::{Color, ResolvedColor},
+    text::font::{Body, Caption, Font, Footnote, Headline, ResolvedFont, Subheadline, Title},
+};
+
+macro_rules! snapshot_slots {
+    ($(#[$meta:meta])* $name:ident, $ty:ty, [$($slot:ident),*]) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+        #[serde(default)]
+        pub struct $name {
+            $(
+                #[doc = concat!("Value of the `", stringify!($slot), "` slot, if overridden.")]
+                #[serde(skip_serializing_if = "Option::is_none")]
+                pub $slot: Option<$ty>,
+            )*
+        }
+    };
+}
+
+snapshot_slots!(
+    /// Plain-data counterpart of [`ColorSettings`].
+    ColorSnapshot,
+    ResolvedColor,
+    [
+        background,
+        surface,
+        surface_variant,
+        border,
+        foreground,
+        muted_foreground,
+        accent,
+        accent_foreground,
+        destructive
+    ]
+);
+
+snapshot_slots!(
+    /// Plain-data counterpart of [`FontSettings`].
+    FontSnapshot,
+    ResolvedFont,
+    [body, title, headline, subheadline, caption, footnote]
+);
+
+/// Plain-data counterpart of [`Theme`].
+///
+/// Unset slots are omitted when serialized and stay unset when the snapshot is
+/// turned back into a [`Theme`], so native defaults keep applying to them.
+///
+/// # Example
+///
+/// ```ignore
+/// use waterui::theme::{Theme, ThemeSnapshot};
+///
+/// let json = Theme::new().spacing(12.0).to_json()?;
+/// let theme = Theme::from_json(&json)?;
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeSnapshot {
+    /// Color scheme, if overridden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color_scheme: Option<ColorScheme>,
+    /// Color slots.
+    pub colors: ColorSnapshot,
+    /// Font slots.
+    pub fonts: FontSnapshot,
+    /// Base spacing unit, if overridden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spacing: Option<f32>,
+}
+
+impl ThemeSnapshot {
+    /// Parses a snapshot from JSON.
+    ///
+    /// # Errors
+    /// Returns an error if `json` is not a valid theme description.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Serializes this snapshot as pretty-printed JSON.
+    ///
+    /// # Errors
+    /// Returns an error if a value cannot be represented in JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Copies the current value of each slot set in settings into a snapshot.
+macro_rules! capture_slots {
+    ($settings:expr, $snapshot:expr, [$($slot:ident),*]) => {
+        $(
+            if let Some(signal) = &$settings.$slot {
+                $snapshot.$slot = Some(signal.get());
+            }
+        )*
+    };
+}
+
+/// Builds settings with a constant value for each slot set in the snapshot.
+macro_rules! constant_slots {
+    ($settings:expr, $snapshot:expr, [$($slot:ident),*]) => {{
+        let mut settings = $settings;
+        $(
+            if let Some(value) = $snapshot.$slot {
+                settings = settings.$slot(value);
+            }
+        )*
+        settings
+    }};
+}
+
+/// Installs each slot as following the binding, falling back to the value
+/// the environment already had while the slot is unset in the snapshot.
+macro_rules! bound_slots {
+    ($env:expr, $binding:expr, $group:ident, $install:ident, [$($slot:ident => $token:ty: $fallback:expr),*]) => {
+        $(
+            let signal = $binding
+                .clone()
+                .zip($fallback)
+                .map(|(snapshot, fallback): (ThemeSnapshot, _)| {
+                    snapshot.$group.$slot.unwrap_or(fallback)
+                })
+                .computed();
+            $install::<$token>($env, signal);
+        )*
+    };
+}
+
+/// Installs every slot of a theme created by [`Theme::bind`].
+pub(super) fn install_bound(env: &mut Environment, snapshot: &Binding<ThemeSnapshot>) {
+    let scheme = snapshot
+        .clone()
+        .zip(current_color_scheme(env))
+        .map(|(snapshot, fallback): (ThemeSnapshot, _)| snapshot.color_scheme.unwrap_or(fallback))
+        .computed();
+    install_color_scheme(env, scheme);
+
+    let spacing = snapshot
+        .clone()
+        .zip(current_spacing(env))
+        .map(|(snapshot, fallback): (ThemeSnapshot, _)| snapshot.spacing.unwrap_or(fallback))
+        .computed();
+    env.insert(SpacingUnit(spacing));
+
+    // Colors resolve after the scheme so dark variants follow the bound scheme
+    bound_slots!(
+        env,
+        snapshot,
+        colors,
+        install_color_signal,
+        [
+            background => color::Background: resolve_color_slot::<color::Background>(env),
+            surface => color::Surface: resolve_color_slot::<color::Surface>(env),
+            surface_variant => color::SurfaceVariant: resolve_color_slot::<color::SurfaceVariant>(env),
+            border => color::Border: resolve_color_slot::<color::Border>(env),
+            foreground => color::Foreground: resolve_color_slot::<color::Foreground>(env),
+            muted_foreground => color::MutedForeground: resolve_color_slot::<color::MutedForeground>(env),
+            accent => color::Accent: resolve_color_slot::<color::Accent>(env),
+            accent_foreground => color::AccentForeground: resolve_color_slot::<color::AccentForeground>(env)
+        ]
+    );
+    let destructive = snapshot
+        .clone()
+        .zip(Color::new(color::Destructive).resolve(env))
+        .map(|(snapshot, fallback): (ThemeSnapshot, _)| {
+            snapshot.colors.destructive.unwrap_or(fallback)
+        })
+        .computed();
+    install_destructive_color(env, destructive);
+    bound_slots!(
+        env,
+        snapshot,
+        fonts,
+        install_font_signal,
+        [
+            body => Body: Font::from(Body).resolve(env),
+            title => Title: Font::from(Title).resolve(env),
+            headline => Headline: Font::from(Headline).resolve(env),
+            subheadline => Subheadline: Font::from(Subheadline).resolve(env),
+            caption => Caption: Font::from(Caption).resolve(env),
+            footnote => Footnote: Font::from(Footnote).resolve(env)
+        ]
+    );
+}
+
+impl Theme {
+    /// Captures the current value of every slot set on this theme.
+    ///
+    /// For a theme created by [`Theme::bind`], these are the slots set in
+    /// the bound snapshot.
+    #[must_use]
+    pub fn snapshot(&self) -> ThemeSnapshot {
+        let mut snapshot = self.bound.as_ref().map(Signal::get).unwrap_or_default();
+        if let Some(scheme) = &self.color_scheme {
+            snapshot.color_scheme = Some(scheme.get());
+        }
+        if let Some(spacing) = &self.spacing {
+            snapshot.spacing = Some(spacing.get());
+        }
+        if let Some(settings) = &self.colors {
+            capture_slots!(
+                settings,
+                snapshot.colors,
+                [
+                    background,
+                    surface,
+                    surface_variant,
+                    border,
+                    foreground,
+                    muted_foreground,
+                    accent,
+                    accent_foreground,
+                    destructive
+                ]
+            );
+        }
+        if let Some(settings) = &self.fonts {
+            capture_slots!(
+                settings,
+                snapshot.fonts,
+                [body, title, headline, subheadline, caption, footnote]
+            );
+        }
+        snapshot
+    }
+
+    /// Creates a theme that follows a snapshot binding.
+    ///
+    /// Every slot is installed as a reactive signal, so edits to the binding
+    /// restyle the UI immediately, including slots that were unset when the
+    /// theme was created. While a slot is unset in the snapshot it keeps the
+    /// value the environment had when the theme was installed, usually the
+    /// native default.
+    #[must_use]
+    pub fn bind(snapshot: &Binding<ThemeSnapshot>) -> Self {
+        Self {
+            bound: Some(snapshot.clone()),
+            ..Self::new()
+        }
+    }
+
+    /// Parses a theme from JSON produced by [`Theme::to_json`].
+    ///
+    /// # Errors
+    /// Returns an error if `json` is not a valid theme description.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        ThemeSnapshot::from_json(json).map(Self::from)
+    }
+
+    /// Serializes the current value of every slot set on this theme as JSON.
+    ///
+    /// # Errors
+    /// Returns an error if a value cannot be represented in JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        self.snapshot().to_json()
+    }
+}
+
+impl From<ThemeSnapshot> for Theme {
+    fn from(snapshot: ThemeSnapshot) -> Self {
+        let mut theme = Self::new();
+        if let Some(scheme) = snapshot.color_scheme {
+            theme = theme.color_scheme(scheme);
+        }
+        if let Some(spacing) = snapshot.spacing {
+            theme = theme.spacing(f64::from(spacing));
+        }
+
+        let colors = constant_slots!(
+            ColorSettings::new(),
+            snapshot.colors,
+            [
+                background,
+                surface,
+                surface_variant,
+                border,
+                foreground,
+                muted_foreground,
+                accent,
+                accent_foreground,
+                destructive
+            ]
+        );
+        let fonts = constant_slots!(
+            FontSettings::new(),
+            snapshot.fonts,
+            [body, title, headline, subheadline, caption, footnote]
+        );
+
+        theme.colors(colors).fonts(fonts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{text::font::FontWeight, theme::DEFAULT_SPACING};
+    use nami::binding;
+    use waterui_core::plugin::Plugin;
+
+    fn accent() -> ResolvedColor {
+        ResolvedColor {
+            red: 0.2,
+            green: 0.4,
+            blue: 0.6,
+            headroom: 0.0,
+            opacity: 1.0,
+        }
+    }
+
+    #[test]
+    fn json_round_trip_keeps_set_slots() {
+        let theme = Theme::new()
+            .color_scheme(ColorScheme::Dark)
+            .colors(ColorSettings::new().accent(accent()))
+            .fonts(FontSettings::new().title(ResolvedFont::new(28.0, FontWeight::Bold)))
+            .spacing(12.0);
+
+        let json = theme.to_json().unwrap();
+        let snapshot = Theme::from_json(&json).unwrap().snapshot();
+
+        assert_eq!(snapshot.color_scheme, Some(ColorScheme::Dark));
+        assert_eq!(snapshot.spacing, Some(12.0));
+        assert!((snapshot.colors.accent.unwrap().green - 0.4).abs() < f32::EPSILON);
+        assert!(snapshot.colors.background.is_none());
+        let title = snapshot.fonts.title.unwrap();
+        assert!((title.size - 28.0).abs() < f32::EPSILON);
+        assert_eq!(title.weight, FontWeight::Bold);
+        assert!(snapshot.fonts.body.is_none());
+    }
+
+    #[test]
+    fn unset_slots_are_omitted_and_optional() {
+        let json = Theme::new().spacing(4.0).to_json().unwrap();
+        assert!(!json.contains("color_scheme"));
+        assert!(!json.contains("accent"));
+
+        let snapshot = ThemeSnapshot::from_json(r#"{"spacing": 6.0}"#).unwrap();
+        assert_eq!(snapshot.spacing, Some(6.0));
+        assert!(snapshot.color_scheme.is_none());
+    }
+
+    #[test]
+    fn bound_theme_follows_binding() {
+        let snapshot = binding(ThemeSnapshot {
+            spacing: Some(8.0),
+            ..ThemeSnapshot::default()
+        });
+        let theme = Theme::bind(&snapshot);
+
+        snapshot.with_mut(|s| s.spacing = Some(16.0));
+        assert_eq!(theme.snapshot().spacing, Some(16.0));
+        assert!(theme.snapshot().color_scheme.is_none());
+    }
+
+    #[test]
+    fn editing_slots_unset_at_bind_time_reaches_environment() {
+        let snapshot = binding(ThemeSnapshot::default());
+        let mut env = Environment::new();
+        Theme::bind(&snapshot).install(&mut env);
+
+        let accent_slot = resolve_color_slot::<color::Accent>(&env);
+        let spacing = current_spacing(&env);
+        assert!(accent_slot.get().green.abs() < f32::EPSILON);
+        assert!((spacing.get() - DEFAULT_SPACING).abs() < f32::EPSILON);
+
+        snapshot.with_mut(|s| {
+            s.colors.accent = Some(accent());
+            s.spacing = Some(12.0);
+        });
+        assert!((accent_slot.get().green - 0.4).abs() < f32::EPSILON);
+        assert!((spacing.get() - 12.0).abs() < f32::EPSILON);
+
+        snapshot.with_mut(|s| s.colors.accent = None);
+        assert!(accent_slot.get().green.abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,462 @@
+//! Undo and redo for binding changes.
+//!
+//! An [`UndoManager`] records every change to the bindings registered with
+//! it. [`UndoManager::undo`] restores the values from before the last change
+//! and [`UndoManager::redo`] applies it again. Changes made inside
+//! [`UndoManager::transaction`] are undone as one step, under a name
+//! platforms show in their Undo menu item:
+//!
+//! ```ignore
+//! let undo = UndoManager::install(&mut env);
+//! undo.register(&title);
+//! undo.register(&color);
+//!
+//! undo.transaction("Reset Style", || {
+//!     title.set(String::new());
+//!     color.set(Color::default());
+//! });
+//! undo.undo(); // restores both
+//! ```
+//!
+//! Native backends drive the manager installed in the environment from the
+//! platform's undo gestures: Cmd+Z and Shift+Cmd+Z on desktop, and shaking
+//! the device on iOS. Their menu items are enabled by
+//! [`UndoManager::can_undo`] and [`UndoManager::can_redo`].
+
+use alloc::{boxed::Box, rc::Rc, vec, vec::Vec};
+use core::{
+    cell::{Cell, RefCell},
+    fmt,
+};
+
+use nami::{Binding, Computed, Signal, SignalExt, watcher::BoxWatcherGuard};
+use waterui_core::{Environment, Str, impl_extractor};
+
+/// One binding's value before and after a change.
+struct Change {
+    /// Identifies the registered binding.
+    target: usize,
+    undo: Box<dyn Fn()>,
+    redo: Box<dyn Fn()>,
+}
+
+/// Changes undone and redone together.
+struct Transaction {
+    name: Option<Str>,
+    changes: Vec<Change>,
+}
+
+struct Inner {
+    undo_stack: RefCell<Vec<Transaction>>,
+    redo_stack: RefCell<Vec<Transaction>>,
+    /// The outermost transaction being recorded.
+    open: RefCell<Option<Transaction>>,
+    depth: Cell<usize>,
+    /// Set while undoing or redoing, whose writes must not be recorded.
+    replaying: Cell<bool>,
+    next_target: Cell<usize>,
+    capacity: Cell<usize>,
+    guards: RefCell<Vec<BoxWatcherGuard>>,
+    can_undo: Binding<bool>,
+    can_redo: Binding<bool>,
+    undo_name: Binding<Option<Str>>,
+    redo_name: Binding<Option<Str>>,
+}
+
+impl Inner {
+    fn record(&self, change: Change) {
+        if self.replaying.get() {
+            return;
+        }
+        if self.depth.get() > 0 {
+            let mut open = self.open.borrow_mut();
+            let Some(transaction) = open.as_mut() else {
+                return;
+            };
+            // Repeated changes to one binding collapse into one step
+            match transaction
+                .changes
+                .iter_mut()
+                .find(|existing| existing.target == change.target)
+            {
+                Some(existing) => existing.redo = change.redo,
+                None => transaction.changes.push(change),
+            }
+            return;
+        }
+        self.push(Transaction {
+            name: None,
+            changes: vec![change],
+        });
+    }
+
+    fn push(&self, transaction: Transaction) {
+        if transaction.changes.is_empty() {
+            return;
+        }
+        {
+            let mut undo_stack = self.undo_stack.borrow_mut();
+            undo_stack.push(transaction);
+            let capacity = self.capacity.get();
+            if undo_stack.len() > capacity {
+                let excess = undo_stack.len() - capacity;
+                undo_stack.drain(..excess);
+            }
+        }
+        self.redo_stack.borrow_mut().clear();
+        self.sync();
+    }
+
+    /// Runs `f` without recording the writes it makes.
+    fn replay(&self, f: impl FnOnce()) {
+        self.replaying.set(true);
+        let _replaying = Replaying(self);
+        f();
+    }
+
+    /// Updates the signals describing the stacks.
+    fn sync(&self) {
+        let top = |stack: &RefCell<Vec<Transaction>>| {
+            let stack = stack.borrow();
+            (
+                !stack.is_empty(),
+                stack
+                    .last()
+                    .and_then(|transaction| transaction.name.clone()),
+            )
+        };
+        let (can_undo, undo_name) = top(&self.undo_stack);
+        let (can_redo, redo_name) = top(&self.redo_stack);
+        set_changed(&self.can_undo, can_undo);
+        set_changed(&self.can_redo, can_redo);
+        set_changed(&self.undo_name, undo_name);
+        set_changed(&self.redo_name, redo_name);
+    }
+}
+
+/// Sets `binding` only if the value differs, so watchers are not notified
+/// for nothing.
+fn set_changed<T: PartialEq + Clone + 'static>(binding: &Binding<T>, value: T) {
+    if binding.get() != value {
+        binding.set(value);
+    }
+}
+
+/// Closes the outermost transaction when dropped, even if its closure
+/// panicked.
+struct Commit<'a>(&'a Inner);
+
+impl Drop for Commit<'_> {
+    fn drop(&mut self) {
+        let depth = self.0.depth.get() - 1;
+        self.0.depth.set(depth);
+        if depth == 0 {
+            let transaction = self.0.open.borrow_mut().take();
+            if let Some(transaction) = transaction {
+                self.0.push(transaction);
+            }
+        }
+    }
+}
+
+/// Ends a replay when dropped, even if a binding's watcher panicked.
+struct Replaying<'a>(&'a Inner);
+
+impl Drop for Replaying<'_> {
+    fn drop(&mut self) {
+        self.0.replaying.set(false);
+    }
+}
+
+/// Records changes to bindings so they can be undone and redone.
+///
+/// Clones share the same history.
+#[derive(Clone)]
+pub struct UndoManager(Rc<Inner>);
+
+impl_extractor!(UndoManager);
+
+impl fmt::Debug for UndoManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UndoManager")
+            .field("undo", &self.0.undo_stack.borrow().len())
+            .field("redo", &self.0.redo_stack.borrow().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for UndoManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UndoManager {
+    /// Creates a manager that keeps the last 100 undo steps.
+    #[must_use]
+    pub fn new() -> Self {
+        Self(Rc::new(Inner {
+            undo_stack: RefCell::new(Vec::new()),
+            redo_stack: RefCell::new(Vec::new()),
+            open: RefCell::new(None),
+            depth: Cell::new(0),
+            replaying: Cell::new(false),
+            next_target: Cell::new(0),
+            capacity: Cell::new(100),
+            guards: RefCell::new(Vec::new()),
+            can_undo: Binding::container(false),
+            can_redo: Binding::container(false),
+            undo_name: Binding::container(None),
+            redo_name: Binding::container(None),
+        }))
+    }
+
+    /// Sets how many undo steps are kept before the oldest are dropped.
+    ///
+    /// A capacity of `0` keeps no history, so nothing can be undone.
+    #[must_use]
+    pub fn capacity(self, capacity: usize) -> Self {
+        self.0.capacity.set(capacity);
+        self
+    }
+
+    /// Installs a new manager into `env`, where native undo gestures find it.
+    pub fn install(env: &mut Environment) -> Self {
+        let manager = Self::new();
+        env.insert(manager.clone());
+        manager
+    }
+
+    /// Returns the manager installed in `env`, if any.
+    #[must_use]
+    pub fn from_env(env: &Environment) -> Option<Self> {
+        env.get::<Self>().cloned()
+    }
+
+    /// Records changes to `binding` from now on.
+    ///
+    /// The manager keeps the binding alive until it is dropped.
+    pub fn register<T: Clone + 'static>(&self, binding: &Binding<T>) {
+        let target = self.0.next_target.get();
+        self.0.next_target.set(target + 1);
+
+        let previous = RefCell::new(binding.get());
+        let manager = Rc::downgrade(&self.0);
+        let source = binding.clone();
+        let guard = binding.watch(move |context| {
+            let value = context.into_value();
+            let old = previous.replace(value.clone());
+            let Some(manager) = manager.upgrade() else {
+                return;
+            };
+            let undo = {
+                let binding = source.clone();
+                move || binding.set(old.clone())
+            };
+            let redo = {
+                let binding = source.clone();
+                move || binding.set(value.clone())
+            };
+            manager.record(Change {
+                target,
+                undo: Box::new(undo),
+                redo: Box::new(redo),
+            });
+        });
+        self.0.guards.borrow_mut().push(Box::new(guard));
+    }
+
+    /// Runs `f`, recording the changes it makes as one undo step named
+    /// `name`.
+    ///
+    /// Nested transactions are part of the outermost one.
+    pub fn transaction<R>(&self, name: impl Into<Str>, f: impl FnOnce() -> R) -> R {
+        if self.0.depth.get() == 0 {
+            *self.0.open.borrow_mut() = Some(Transaction {
+                name: Some(name.into()),
+                changes: Vec::new(),
+            });
+        }
+        self.0.depth.set(self.0.depth.get() + 1);
+        let _commit = Commit(&self.0);
+        f()
+    }
+
+    /// Restores the values from before the last undo step.
+    ///
+    /// Returns `false` if there is nothing to undo or a transaction is being
+    /// recorded.
+    // Gesture handlers usually ignore whether there was a step to undo
+    #[allow(clippy::must_use_candidate)]
+    pub fn undo(&self) -> bool {
+        if self.0.depth.get() > 0 {
+            return false;
+        }
+        let Some(transaction) = self.0.undo_stack.borrow_mut().pop() else {
+            return false;
+        };
+        self.0.replay(|| {
+            for change in transaction.changes.iter().rev() {
+                (change.undo)();
+            }
+        });
+        self.0.redo_stack.borrow_mut().push(transaction);
+        self.0.sync();
+        true
+    }
+
+    /// Applies the last undone step again.
+    ///
+    /// Returns `false` if there is nothing to redo or a transaction is being
+    /// recorded.
+    #[allow(clippy::must_use_candidate)]
+    pub fn redo(&self) -> bool {
+        if self.0.depth.get() > 0 {
+            return false;
+        }
+        let Some(transaction) = self.0.redo_stack.borrow_mut().pop() else {
+            return false;
+        };
+        self.0.replay(|| {
+            for change in &transaction.changes {
+                (change.redo)();
+            }
+        });
+        self.0.undo_stack.borrow_mut().push(transaction);
+        self.0.sync();
+        true
+    }
+
+    /// Drops every recorded step.
+    pub fn clear(&self) {
+        self.0.undo_stack.borrow_mut().clear();
+        self.0.redo_stack.borrow_mut().clear();
+        self.0.sync();
+    }
+
+    /// Returns whether there is a step to undo.
+    #[must_use]
+    pub fn can_undo(&self) -> Computed<bool> {
+        self.0.can_undo.clone().computed()
+    }
+
+    /// Returns whether there is a step to redo.
+    #[must_use]
+    pub fn can_redo(&self) -> Computed<bool> {
+        self.0.can_redo.clone().computed()
+    }
+
+    /// Returns the name of the transaction [`undo`](Self::undo) would undo,
+    /// if it has one.
+    #[must_use]
+    pub fn undo_name(&self) -> Computed<Option<Str>> {
+        self.0.undo_name.clone().computed()
+    }
+
+    /// Returns the name of the transaction [`redo`](Self::redo) would redo,
+    /// if it has one.
+    #[must_use]
+    pub fn redo_name(&self) -> Computed<Option<Str>> {
+        self.0.redo_name.clone().computed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changes_are_undone_and_redone_in_order() {
+        let manager = UndoManager::new();
+        let count = Binding::container(0);
+        manager.register(&count);
+        let can_undo = manager.can_undo();
+
+        count.set(1);
+        count.set(2);
+        assert!(can_undo.get());
+
+        assert!(manager.undo());
+        assert_eq!(count.get(), 1);
+        assert!(manager.undo());
+        assert_eq!(count.get(), 0);
+        assert!(!manager.undo());
+        assert!(!can_undo.get());
+
+        assert!(manager.redo());
+        assert_eq!(count.get(), 1);
+
+        // A new change discards what could be redone
+        count.set(5);
+        assert!(!manager.can_redo().get());
+        assert!(manager.undo());
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn transactions_are_undone_as_one_named_step() {
+        let manager = UndoManager::new();
+        let title = Binding::container(Str::from("Draft"));
+        let size = Binding::container(12);
+        manager.register(&title);
+        manager.register(&size);
+
+        manager.transaction("Reset Style", || {
+            title.set("".into());
+            size.set(14);
+            manager.transaction("Nested", || size.set(16));
+        });
+        assert_eq!(manager.undo_name().get(), Some(Str::from("Reset Style")));
+
+        assert!(manager.undo());
+        assert_eq!(title.get(), Str::from("Draft"));
+        assert_eq!(size.get(), 12);
+        assert!(!manager.can_undo().get());
+
+        assert!(manager.redo());
+        assert_eq!(title.get(), Str::from(""));
+        assert_eq!(size.get(), 16);
+    }
+
+    #[test]
+    fn capacity_drops_oldest_steps() {
+        let manager = UndoManager::new().capacity(2);
+        let count = Binding::container(0);
+        manager.register(&count);
+
+        count.set(1);
+        count.set(2);
+        count.set(3);
+        assert!(manager.undo());
+        assert!(manager.undo());
+        assert!(!manager.undo());
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn zero_capacity_keeps_no_history() {
+        let manager = UndoManager::new().capacity(0);
+        let count = Binding::container(0);
+        manager.register(&count);
+
+        count.set(1);
+        assert!(!manager.can_undo().get());
+        assert!(!manager.undo());
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn panicking_replay_still_records_later_changes() {
+        let manager = UndoManager::new();
+        let count = Binding::container(0);
+        manager.register(&count);
+        let _guard = count.watch(|context| assert_ne!(context.into_value(), 0));
+
+        count.set(1);
+        let undo = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| manager.undo()));
+        assert!(undo.is_err());
+
+        count.set(2);
+        assert!(manager.can_undo().get());
+    }
+}
@@ -9,7 +9,8 @@
 //!
 //! These extensions help create a fluent API for constructing user interfaces.
 
-use executor_core::spawn_local;
+use core::hash::Hash;
+
 use nami::{Binding, Signal, signal::IntoComputed};
 use waterui_color::Color;
 pub use waterui_core::view::*;
@@ -17,25 +18,41 @@ use waterui_core::{
     AnyView, Environment, IgnorableMetadata, Retain,
     env::{With, use_env},
     handler::{HandlerFn, HandlerFnOnce},
+    id::ViewId,
+    idiom::{OrnamentPlacement, WithOrnament},
     metadata::MetadataKey,
+    platform::{OnPlatform, Platform},
     plugin::Plugin,
 };
 
 use waterui_layout::{
     EdgeSet, IgnoreSafeArea, Overlay,
     frame::Frame,
+    grid::GridCellView,
     padding::{EdgeInsets, Padding},
-    stack::Alignment,
+    stack::{Alignment, ZIndex, ZStackItem},
 };
 use waterui_navigation::NavigationView;
 use waterui_str::Str;
 
 use crate::{
-    accessibility::{self, AccessibilityLabel, AccessibilityRole},
+    accessibility::{
+        self, AccessibilityHeading, AccessibilityLabel, AccessibilityLandmark, AccessibilityRole,
+        HeadingLevel,
+    },
     background::{Background, ForegroundColor},
-    gesture::{Gesture, GestureObserver, TapGesture},
+    drag_drop::{DragItem, DragSource, DragType, DropEvent, DropTarget},
+    error::ErrorBoundary,
+    filter::ColorFilter,
+    gesture::{
+        Gesture, GestureObserver, GesturePhase, HoverGesture, HoverPhase, PressGesture,
+        SecondaryClickEvent, SecondaryClickGesture, TapGesture, TypedGesture,
+    },
     metadata::secure::Secure,
+    task::spawn_local,
+    theme::{ColorScheme, PreferredColorScheme, current_color_scheme, install_color_scheme},
     view_ext::OnChange,
+    weak::{BindingExt, scoped},
 };
 use crate::{
     component::{Text, badge::Badge, focus::Focused},
@@ -270,6 +287,39 @@ pub trait ViewExt: View + Sized {
         Frame::new(self).alignment(alignment)
     }
 
+    /// Makes this view span `columns` columns of the enclosing grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `columns` is 0.
+    fn grid_cell_columns(self, columns: usize) -> GridCellView {
+        GridCellView::columns(self, columns)
+    }
+
+    /// Anchors this view at `row` and `column` of the enclosing grid,
+    /// counted from zero.
+    fn grid_cell_position(self, row: usize, column: usize) -> GridCellView {
+        GridCellView::position(self, row, column)
+    }
+
+    /// Aligns this view within its cell of the enclosing grid.
+    fn grid_cell_alignment(self, alignment: Alignment) -> GridCellView {
+        GridCellView::alignment(self, alignment)
+    }
+
+    /// Aligns this view within the enclosing `ZStack`, overriding the
+    /// stack's alignment.
+    fn zstack_alignment(self, alignment: Alignment) -> ZStackItem {
+        ZStackItem::new(self, alignment)
+    }
+
+    /// Sets the drawing order of this view among the siblings it overlaps.
+    ///
+    /// Views with a higher index are drawn above; the default is 0.
+    fn z_index(self, index: impl IntoComputed<i32>) -> Metadata<ZIndex> {
+        Metadata::new(self, ZIndex::new(index))
+    }
+
     /// Adds padding to this view with custom edge insets.
     ///
     /// # Arguments
@@ -330,6 +380,25 @@ pub trait ViewExt: View + Sized {
         IgnorableMetadata::new(self, role)
     }
 
+    /// Marks this view as a heading of the given level.
+    ///
+    /// # Arguments
+    /// * `level` - The level of the heading in the screen's outline
+    fn accessibility_heading(self, level: HeadingLevel) -> IgnorableMetadata<AccessibilityHeading> {
+        IgnorableMetadata::new(self, AccessibilityHeading::new(level))
+    }
+
+    /// Marks this view as a landmark region of the screen.
+    ///
+    /// # Arguments
+    /// * `landmark` - The kind of region this view is
+    fn accessibility_landmark(
+        self,
+        landmark: AccessibilityLandmark,
+    ) -> IgnorableMetadata<AccessibilityLandmark> {
+        IgnorableMetadata::new(self, landmark)
+    }
+
     /// Observes a gesture and executes an action when the gesture is recognized.
     ///
     /// # Arguments
@@ -343,6 +412,36 @@ pub trait ViewExt: View + Sized {
         Metadata::new(self, GestureObserver::new(gesture, action))
     }
 
+    /// Observes a gesture and passes its value to `f` each time it changes.
+    ///
+    /// The value type follows the gesture: a [`DragGesture`](crate::gesture::DragGesture)
+    /// reports [`DragEvent`](crate::gesture::DragEvent)s with translation, velocity
+    /// and phase, a [`LongPressGesture`](crate::gesture::LongPressGesture) reports
+    /// its progress, and so on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use waterui::gesture::{DragGesture, GesturePhase};
+    /// use waterui::prelude::*;
+    ///
+    /// let offset = Binding::container(0.0_f32);
+    /// text!("Swipe me").on_gesture(DragGesture::new(5.0), move |drag| {
+    ///     if drag.phase == GesturePhase::Ended {
+    ///         offset.set(0.0);
+    ///     } else {
+    ///         offset.set(drag.translation.x);
+    ///     }
+    /// });
+    /// ```
+    fn on_gesture<G: TypedGesture + 'static>(
+        self,
+        gesture: G,
+        f: impl FnMut(G::Value) + 'static,
+    ) -> Metadata<GestureObserver> {
+        Metadata::new(self, GestureObserver::with_value(gesture, f))
+    }
+
     /// Adds a tap gesture recognizer to this view that triggers the specified action.
     ///
     /// # Arguments
@@ -362,11 +461,174 @@ pub trait ViewExt: View + Sized {
         self.gesture(TapGesture::new(), action)
     }
 
+    /// Calls `f` with `true` when the pointer enters this view and `false`
+    /// when it leaves.
+    ///
+    /// Use it for hover affordances on desktop and web; touch-only platforms
+    /// never call it. For pointer locations, observe a
+    /// [`HoverGesture`](crate::gesture::HoverGesture) with
+    /// [`on_gesture`](Self::on_gesture) instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use waterui::prelude::*;
+    ///
+    /// let hovered = Binding::container(false);
+    /// text!("Row").on_hover(move |hovering| hovered.set(hovering));
+    /// ```
+    fn on_hover(self, mut f: impl FnMut(bool) + 'static) -> Metadata<GestureObserver> {
+        self.on_gesture(HoverGesture::new(), move |event| match event.phase {
+            HoverPhase::Entered => f(true),
+            HoverPhase::Exited => f(false),
+            HoverPhase::Moved => {}
+        })
+    }
+
+    /// Calls `f` with `true` as soon as this view is pressed and `false` once
+    /// the press is released or cancelled.
+    ///
+    /// Lets custom controls react to touch-down like native buttons do; combine
+    /// it with [`on_tap`](Self::on_tap) for the action itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use waterui::prelude::*;
+    ///
+    /// let pressed = Binding::container(false);
+    /// let scale = pressed.clone().map(|pressed| if pressed { 0.95 } else { 1.0 });
+    /// text!("Custom button")
+    ///     .on_press(move |is_pressed| pressed.set(is_pressed))
+    ///     .on_tap(|| println!("Tapped"));
+    /// # let _ = scale;
+    /// ```
+    fn on_press(self, mut f: impl FnMut(bool) + 'static) -> Metadata<GestureObserver> {
+        self.on_gesture(PressGesture::new(), move |event| match event.phase {
+            GesturePhase::Started => f(true),
+            GesturePhase::Ended | GesturePhase::Cancelled => f(false),
+            GesturePhase::Updated => {}
+        })
+    }
+
+    /// Calls `f` when this view is right-clicked, or Control-clicked on macOS.
+    ///
+    /// Use it for context actions on desktop and web; platforms without a
+    /// pointer never call it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// text!("Row").on_secondary_click(|click| {
+    ///     println!("Context actions at {:?}", click.location);
+    /// });
+    /// ```
+    fn on_secondary_click(
+        self,
+        f: impl FnMut(SecondaryClickEvent) + 'static,
+    ) -> Metadata<GestureObserver> {
+        self.on_gesture(SecondaryClickGesture::new(), f)
+    }
+
+    /// Makes this view draggable.
+    ///
+    /// `provider` is called when a drag starts and returns the items being
+    /// dragged; returning no items cancels the drag.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use waterui::drag_drop::DragItem;
+    ///
+    /// text!("Buy milk").on_drag(|| Some(DragItem::text("Buy milk")));
+    /// ```
+    fn on_drag<I>(self, provider: impl Fn() -> I + 'static) -> Metadata<DragSource>
+    where
+        I: IntoIterator<Item = DragItem>,
+    {
+        Metadata::new(self, DragSource::new(provider))
+    }
+
+    /// Accepts items of the `accepting` types dropped onto this view.
+    ///
+    /// `handler` receives the accepted items and where they were dropped, and
+    /// returns whether it took them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use waterui::drag_drop::{DragItem, DragType};
+    ///
+    /// text!("Drop links here").on_drop([DragType::Url], |event| {
+    ///     for item in event.items {
+    ///         if let DragItem::Url(url) = item {
+    ///             println!("Dropped {url}");
+    ///         }
+    ///     }
+    ///     true
+    /// });
+    /// ```
+    fn on_drop(
+        self,
+        accepting: impl IntoIterator<Item = DragType>,
+        handler: impl FnMut(DropEvent) -> bool + 'static,
+    ) -> Metadata<DropTarget> {
+        Metadata::new(self, DropTarget::new(accepting, handler))
+    }
+
+    /// Like [`gesture`](Self::gesture), but the action only holds a weak
+    /// reference to `binding`, anchored to the returned view.
+    ///
+    /// The action receives the binding while the view is alive and is skipped
+    /// afterwards. For buttons, pass a [`WeakBinding`](crate::weak::WeakBinding)
+    /// to `action_with` instead.
+    fn gesture_weak<T: 'static>(
+        self,
+        gesture: impl Into<Gesture>,
+        binding: &Binding<T>,
+        mut action: impl FnMut(Binding<T>) + 'static,
+    ) -> Metadata<Retain> {
+        scoped(|| {
+            let binding = binding.downgrade();
+            self.gesture(gesture, move || {
+                if let Some(binding) = binding.upgrade() {
+                    action(binding);
+                }
+            })
+        })
+    }
+
+    /// Like [`on_tap`](Self::on_tap), but the action only holds a weak
+    /// reference to `binding`. See [`gesture_weak`](Self::gesture_weak).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use waterui::prelude::*;
+    ///
+    /// let count = Binding::container(0);
+    /// text!("Tap me").on_tap_weak(&count, |count| count.set(count.get() + 1));
+    /// ```
+    fn on_tap_weak<T: 'static>(
+        self,
+        binding: &Binding<T>,
+        action: impl FnMut(Binding<T>) + 'static,
+    ) -> Metadata<Retain> {
+        self.gesture_weak(TapGesture::new(), binding, action)
+    }
+
     /// Applies a shadow effect to this view.
     fn shadow(self, shadow: impl Into<Shadow>) -> Metadata<Shadow> {
         Metadata::new(self, shadow.into())
     }
 
+    /// Applies a color filter to this view and its entire subtree.
+    ///
+    /// Accepts a [`ColorFilter`] or a custom [`ColorMatrix`](crate::filter::ColorMatrix).
+    fn color_filter(self, filter: impl Into<ColorFilter>) -> Metadata<ColorFilter> {
+        Metadata::new(self, filter.into())
+    }
+
     /// Extends this view's bounds to ignore safe area insets on the specified edges.
     ///
     /// This allows backgrounds, images, and other visual elements to extend edge-to-edge
@@ -401,6 +663,117 @@ pub trait ViewExt: View + Sized {
         })
     }
 
+    /// Forces the color scheme of this view and its descendants.
+    ///
+    /// Theme colors, scheme-aware views and native controls in the subtree
+    /// use `scheme` instead of the ambient one, for example to keep a media
+    /// viewer dark. [`ColorScheme::System`] follows the system appearance.
+    fn preferred_color_scheme(self, scheme: impl IntoComputed<ColorScheme>) -> impl View {
+        let scheme = scheme.into_computed();
+        use_env(move |mut env: Environment| {
+            install_color_scheme(&mut env, scheme);
+            let resolved = PreferredColorScheme(current_color_scheme(&env));
+            Metadata::new(Metadata::new(self, resolved), env)
+        })
+    }
+
+    /// Gives this view an explicit identity.
+    ///
+    /// When a dynamic view swaps its content, backends keep the native view
+    /// and its state (scroll position, focus, running animations) if the new
+    /// content has the same identity, and rebuild it from scratch if the
+    /// identity changed. Use it to reset a subtree on purpose, or to keep
+    /// state when a rebuild produces the same logical view.
+    ///
+    /// To also recreate the bindings built inside the subtree, build it with
+    /// [`identified`](waterui_core::id::identified).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Scrolls back to the top whenever another article is shown
+    /// watch(article, |article| scroll(article_body(&article)).id(article.id))
+    /// ```
+    fn id(self, id: impl Hash) -> Metadata<ViewId> {
+        Metadata::new(self, ViewId::new(&id))
+    }
+
+    /// Applies `modifier` to this view only when rendered on `platform`.
+    ///
+    /// The platform is read from the environment at runtime rather than from
+    /// `cfg` attributes, so the same code builds for every target.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// content.on_platform(Platform::Web, |view| view.padding())
+    /// ```
+    fn on_platform<F, R>(self, platform: Platform, modifier: F) -> OnPlatform<Self, F>
+    where
+        F: FnOnce(Self) -> R + 'static,
+        R: View,
+    {
+        OnPlatform::new(platform, self, modifier)
+    }
+
+    /// Applies `modifier` to this view only on iOS.
+    ///
+    /// See [`on_platform`](Self::on_platform).
+    fn ios<F, R>(self, modifier: F) -> OnPlatform<Self, F>
+    where
+        F: FnOnce(Self) -> R + 'static,
+        R: View,
+    {
+        self.on_platform(Platform::Ios, modifier)
+    }
+
+    /// Applies `modifier` to this view only on macOS.
+    ///
+    /// See [`on_platform`](Self::on_platform).
+    fn macos<F, R>(self, modifier: F) -> OnPlatform<Self, F>
+    where
+        F: FnOnce(Self) -> R + 'static,
+        R: View,
+    {
+        self.on_platform(Platform::Macos, modifier)
+    }
+
+    /// Applies `modifier` to this view only on Android.
+    ///
+    /// See [`on_platform`](Self::on_platform).
+    fn android<F, R>(self, modifier: F) -> OnPlatform<Self, F>
+    where
+        F: FnOnce(Self) -> R + 'static,
+        R: View,
+    {
+        self.on_platform(Platform::Android, modifier)
+    }
+
+    /// Applies `modifier` to this view only in a web browser.
+    ///
+    /// See [`on_platform`](Self::on_platform).
+    fn web<F, R>(self, modifier: F) -> OnPlatform<Self, F>
+    where
+        F: FnOnce(Self) -> R + 'static,
+        R: View,
+    {
+        self.on_platform(Platform::Web, modifier)
+    }
+
+    /// Attaches `ornament` outside the window showing this view, on visionOS.
+    ///
+    /// Backends without ornaments show only this view, so the same code runs
+    /// everywhere.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// player.ornament(OrnamentPlacement::Bottom, playback_controls())
+    /// ```
+    fn ornament(self, placement: OrnamentPlacement, ornament: impl View) -> WithOrnament<Self> {
+        WithOrnament::new(self, placement, ornament)
+    }
+
     /// Retains a value for the lifetime of this view.
     ///
     /// This is useful for keeping watcher guards, subscriptions, or other values
@@ -425,6 +798,15 @@ pub trait ViewExt: View + Sized {
     fn retain<T: 'static>(self, value: T) -> Metadata<Retain> {
         Metadata::new(self, Retain::new(value))
     }
+
+    /// Replaces this view with the first error presented from inside it.
+    ///
+    /// Errors returned by [`fallible`](crate::error::fallible) and
+    /// [`fallible_async`](crate::error::fallible_async) handlers in this view
+    /// are rendered here instead of reaching an outer presenter.
+    fn error_boundary(self) -> ErrorBoundary<Self> {
+        ErrorBoundary::new(self)
+    }
 }
 
 impl<V: View + Sized> ViewExt for V {}
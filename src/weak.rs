@@ -0,0 +1,223 @@
+//! Weak bindings that do not keep view state alive.
+//!
+//! A closure that captures a [`Binding`] keeps the binding's state alive for
+//! as long as the closure lives. Stored in one of the binding's own watchers,
+//! or in a long-lived handler, it keeps the whole view state around after the
+//! view is gone.
+//!
+//! [`BindingExt::downgrade`] hands out a [`WeakBinding`] instead and anchors
+//! the binding to the enclosing [`scoped`] view, so the binding is released
+//! together with that view and later [`upgrade`](WeakBinding::upgrade)s
+//! return `None`.
+//!
+//! ```
+//! use waterui::prelude::*;
+//! use waterui::weak::scoped;
+//!
+//! fn counter(count: &Binding<i32>) -> impl View {
+//!     scoped(|| {
+//!         let count = count.downgrade();
+//!         text!("Tap me").on_tap(move || {
+//!             if let Some(count) = count.upgrade() {
+//!                 count.set(count.get() + 1);
+//!             }
+//!         })
+//!     })
+//! }
+//! ```
+
+use alloc::{
+    rc::{Rc, Weak},
+    vec::Vec,
+};
+use core::{
+    any::{Any, type_name},
+    cell::RefCell,
+    fmt,
+};
+
+use waterui_core::{Metadata, Retain, View};
+
+use crate::Binding;
+
+/// A reference to a [`Binding`] that does not keep it alive.
+pub struct WeakBinding<T: 'static> {
+    binding: Weak<Binding<T>>,
+}
+
+impl<T: 'static> WeakBinding<T> {
+    /// Returns the binding, or `None` once the scope anchoring it was dropped.
+    #[must_use]
+    pub fn upgrade(&self) -> Option<Binding<T>> {
+        self.binding.upgrade().map(|binding| (*binding).clone())
+    }
+
+    /// Returns `true` while the scope anchoring the binding is alive.
+    #[must_use]
+    pub fn is_alive(&self) -> bool {
+        self.binding.strong_count() > 0
+    }
+}
+
+impl<T: 'static> Clone for WeakBinding<T> {
+    fn clone(&self) -> Self {
+        Self {
+            binding: self.binding.clone(),
+        }
+    }
+}
+
+impl<T: 'static> fmt::Debug for WeakBinding<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakBinding")
+            .field("type", &type_name::<T>())
+            .field("alive", &self.is_alive())
+            .finish()
+    }
+}
+
+/// Extension trait creating [`WeakBinding`]s.
+pub trait BindingExt<T: 'static> {
+    /// Creates a weak reference to this binding, anchored to the innermost
+    /// [`scoped`] view being built.
+    ///
+    /// Outside of [`scoped`] the binding stays anchored until the thread
+    /// exits, which is no better than capturing it directly; such bindings
+    /// are listed as `(unscoped)` by [`retained_bindings`].
+    fn downgrade(&self) -> WeakBinding<T>;
+}
+
+impl<T: 'static> BindingExt<T> for Binding<T> {
+    fn downgrade(&self) -> WeakBinding<T> {
+        let binding = Rc::new(self.clone());
+        let weak = Rc::downgrade(&binding);
+        let anchor = Anchor {
+            binding: type_name::<T>(),
+            value: binding,
+        };
+        let scope = SCOPES
+            .with_borrow(|scopes| scopes.last().cloned())
+            .unwrap_or_else(|| ROOT.with(Rc::clone));
+        scope.anchors.borrow_mut().push(anchor);
+        WeakBinding { binding: weak }
+    }
+}
+
+/// Builds a view, anchoring every binding downgraded while building it to
+/// that view.
+///
+/// The anchored bindings are released when the returned view is dropped.
+pub fn scoped<V: View>(build: impl FnOnce() -> V) -> Metadata<Retain> {
+    let scope = Rc::new(Scope {
+        view: type_name::<V>(),
+        anchors: RefCell::default(),
+    });
+    LIVE.with_borrow_mut(|live| {
+        live.retain(|scope| scope.strong_count() > 0);
+        live.push(Rc::downgrade(&scope));
+    });
+    SCOPES.with_borrow_mut(|scopes| scopes.push(scope.clone()));
+    let view = {
+        let _enter = Enter;
+        build()
+    };
+    Metadata::new(view, Retain::new(scope))
+}
+
+/// The bindings one scope keeps alive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetainedBindings {
+    /// Type name of the view built in the scope, or `(unscoped)`.
+    pub view: &'static str,
+    /// Value types of the bindings anchored to it.
+    pub bindings: Vec<&'static str>,
+}
+
+/// Lists the bindings anchored by [`BindingExt::downgrade`], per live scope on
+/// this thread.
+///
+/// A scope that outlives its view, or an `(unscoped)` list that keeps
+/// growing, points at the owner of a leak.
+#[must_use]
+pub fn retained_bindings() -> Vec<RetainedBindings> {
+    let root = ROOT.with(Rc::clone);
+    let live = LIVE.with_borrow(|live| live.iter().filter_map(Weak::upgrade).collect::<Vec<_>>());
+    core::iter::once(root)
+        .chain(live)
+        .map(|scope| RetainedBindings {
+            view: scope.view,
+            bindings: scope
+                .anchors
+                .borrow()
+                .iter()
+                .map(|anchor| anchor.binding)
+                .collect(),
+        })
+        .filter(|retained| !retained.bindings.is_empty())
+        .collect()
+}
+
+/// A binding kept alive on behalf of its weak references.
+struct Anchor {
+    binding: &'static str,
+    #[allow(dead_code)] // Only held for its strong count
+    value: Rc<dyn Any>,
+}
+
+struct Scope {
+    view: &'static str,
+    anchors: RefCell<Vec<Anchor>>,
+}
+
+std::thread_local! {
+    /// Scopes being built, innermost last.
+    static SCOPES: RefCell<Vec<Rc<Scope>>> = const { RefCell::new(Vec::new()) };
+    /// Every scope created on this thread that may still be alive.
+    static LIVE: RefCell<Vec<Weak<Scope>>> = const { RefCell::new(Vec::new()) };
+    /// Anchors bindings downgraded outside of any scope.
+    static ROOT: Rc<Scope> = Rc::new(Scope {
+        view: "(unscoped)",
+        anchors: RefCell::default(),
+    });
+}
+
+/// Leaves the innermost scope when dropped, even if building panicked.
+struct Enter;
+
+impl Drop for Enter {
+    fn drop(&mut self) {
+        SCOPES.with_borrow_mut(Vec::pop);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reactive::binding;
+
+    #[test]
+    fn scoped_bindings_are_released_with_their_view() {
+        let count = binding(0);
+        let mut weak = None;
+        let view = scoped(|| {
+            weak = Some(count.downgrade());
+        });
+        let weak = weak.unwrap();
+
+        weak.upgrade().unwrap().set(3);
+        assert_eq!(count.get(), 3);
+        assert!(retained_bindings().contains(&RetainedBindings {
+            view: type_name::<()>(),
+            bindings: alloc::vec![type_name::<i32>()],
+        }));
+
+        drop(view);
+        assert!(!weak.is_alive());
+        assert!(weak.upgrade().is_none());
+        assert!(
+            retained_bindings()
+                .iter()
+                .all(|retained| retained.view != type_name::<()>())
+        );
+    }
+}
@@ -1,7 +1,5 @@
 use core::error::Error;
-use executor_core::spawn_local;
 use nami::Binding;
-use native_executor::sleep;
 use std::time::Duration;
 use waterui_color::Color;
 use waterui_core::View;
@@ -17,7 +15,10 @@ use waterui_text::{
     text,
 };
 
-use crate::{SignalExt, ViewExt};
+use crate::{
+    SignalExt, ViewExt,
+    task::{sleep, spawn_local},
+};
 
 /// Copies text to the system clipboard.
 fn copy_to_clipboard(text: &str) {
@@ -1,9 +1,13 @@
 //! Conditional view rendering components for reactive UI programming.
 //!
 //! This module provides the `When` and `WhenOr` components that enable conditional rendering
-//! of views based on reactive boolean conditions. These components are essential for building
+//! of views based on reactive boolean conditions, and [`match_signal`] for choosing between
+//! any number of views based on a reactive value. These components are essential for building
 //! dynamic user interfaces that respond to changing application state.
 //!
+//! Views are only rebuilt when the selected branch changes, not on every update of the
+//! underlying signal.
+//!
 //! # Basic Usage
 //!
 //! ```rust
@@ -19,10 +23,34 @@
 //! // Binding implements Not trait - no need to wrap with s!()
 //! when(!is_visible, || "This text is hidden");
 //! ```
+//!
+//! # Matching on a Signal
+//!
+//! ```rust
+//! use waterui::prelude::*;
+//! use waterui::widget::condition::match_signal;
+//! use waterui_core::binding;
+//!
+//! #[derive(Clone, PartialEq)]
+//! enum Status {
+//!     Loading,
+//!     Ready(u32),
+//!     Failed,
+//! }
+//!
+//! let status = binding(Status::Loading);
+//!
+//! match_signal(status, |status| match status {
+//!     Status::Loading => "Loading...".anyview(),
+//!     Status::Ready(count) => format!("{count} items").anyview(),
+//!     Status::Failed => "Something went wrong".anyview(),
+//! });
+//! ```
 
 use core::any::Any;
 
 use crate::{ViewExt, component::Dynamic};
+use nami::Signal;
 use nami::signal::IntoComputed;
 use waterui_core::{Environment, View, handler::ViewBuilder};
 
@@ -196,6 +224,28 @@ impl<Condition, Then> When<Condition, Then> {
             or,
         }
     }
+
+    /// Adds an alternative view to render when the condition is `false`.
+    ///
+    /// Equivalent to [`or`](When::or), reading as an `if`/`else` pair.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use waterui::widget::condition::when;
+    /// use nami::binding;
+    ///
+    /// let is_online: nami::Binding<bool> = binding(true);
+    ///
+    /// when(is_online, || "Connected").otherwise(|| "Offline");
+    /// ```
+    pub fn otherwise<Or>(self, or: Or) -> WhenOr<Condition, Then, Or>
+    where
+        Condition: IntoComputed<bool>,
+        Or: ViewBuilder,
+    {
+        self.or(or)
+    }
 }
 
 /// A component that conditionally renders one of two views based on a reactive boolean condition.
@@ -266,14 +316,70 @@ where
             };
         }
 
-        // dynamic condition, watch for changes
-        Dynamic::watch(condition, move |condition| {
-            if condition {
-                (self.then).build().anyview()
-            } else {
-                (self.or).build().anyview()
-            }
-        })
+        // dynamic condition, rebuild only when the branch flips
+        Dynamic::watch_by_id(
+            condition,
+            |condition| *condition,
+            move |condition| {
+                if condition {
+                    (self.then).build().anyview()
+                } else {
+                    (self.or).build().anyview()
+                }
+            },
+        )
         .anyview()
     }
 }
+
+/// A component that renders the view chosen for the current value of a signal.
+///
+/// Created by [`match_signal`].
+#[derive(Debug)]
+pub struct Match<S, F> {
+    signal: S,
+    arms: F,
+}
+
+/// Renders a view for each value of a reactive signal.
+///
+/// `arms` maps a value to its view, usually with a `match` expression so every
+/// case must be handled. The view is rebuilt only when the value changes; updates
+/// that carry an equal value keep the current view.
+///
+/// # Examples
+///
+/// ```rust
+/// use waterui::prelude::*;
+/// use waterui::widget::condition::match_signal;
+/// use nami::binding;
+///
+/// let step = binding(0u8);
+///
+/// match_signal(step, |step| match step {
+///     0 => "Welcome".anyview(),
+///     1 => "Choose a theme".anyview(),
+///     _ => "All set".anyview(),
+/// });
+/// ```
+pub const fn match_signal<S, F, V>(signal: S, arms: F) -> Match<S, F>
+where
+    S: Signal,
+    S::Output: PartialEq + Clone,
+    F: Fn(S::Output) -> V + 'static,
+    V: View,
+{
+    Match { signal, arms }
+}
+
+impl<S, F, V> View for Match<S, F>
+where
+    S: Signal,
+    S::Output: PartialEq + Clone,
+    F: Fn(S::Output) -> V + 'static,
+    V: View,
+{
+    fn body(self, _env: &Environment) -> impl View {
+        Dynamic::watch_by_id(self.signal, S::Output::clone, self.arms)
+    }
+}
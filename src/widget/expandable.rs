@@ -0,0 +1,165 @@
+//! Line-limited text with a "read more" toggle.
+
+use crate::{ViewExt, component::Dynamic};
+use nami::{Binding, Computed, SignalExt};
+use waterui_core::{Environment, View};
+use waterui_layout::stack::vstack;
+use waterui_text::Text;
+
+use super::condition::when;
+
+/// Text that shows a "more" label while it is truncated.
+///
+/// Tapping the label expands the text to its full length. Create it with
+/// [`TextExt::expandable`] on a [`Text`] that has a
+/// [`line_limit`](Text::line_limit); without a limit the text is never truncated.
+///
+/// # Examples
+///
+/// ```rust
+/// use waterui::prelude::*;
+/// use waterui::widget::TextExt;
+///
+/// text("A long description that does not fit in two lines...")
+///     .line_limit(2)
+///     .expandable("Read more");
+/// ```
+#[derive(Debug)]
+pub struct ExpandableText {
+    text: Text,
+    more_label: Text,
+    expanded: Binding<bool>,
+    truncated: Binding<bool>,
+}
+
+impl ExpandableText {
+    /// Creates expandable text with the given "more" label.
+    pub fn new(text: Text, more_label: impl Into<Text>) -> Self {
+        Self {
+            text,
+            more_label: more_label.into(),
+            expanded: Binding::bool(false),
+            truncated: Binding::bool(false),
+        }
+    }
+
+    /// Uses `expanded` to control whether the full text is shown.
+    ///
+    /// Setting the binding to `false` collapses the text again.
+    #[must_use]
+    pub fn expanded(mut self, expanded: &Binding<bool>) -> Self {
+        self.expanded = expanded.clone();
+        self
+    }
+
+    /// Returns whether the collapsed text is currently truncated.
+    ///
+    /// The value is reported by the backend after layout.
+    #[must_use]
+    pub fn is_truncated(&self) -> Computed<bool> {
+        self.truncated.clone().computed()
+    }
+}
+
+/// Extension methods for [`Text`] provided by the widget module.
+pub trait TextExt {
+    /// Shows `more_label` below the text while it is truncated; tapping it
+    /// reveals the full text.
+    fn expandable(self, more_label: impl Into<Text>) -> ExpandableText;
+}
+
+impl TextExt for Text {
+    fn expandable(self, more_label: impl Into<Self>) -> ExpandableText {
+        ExpandableText::new(self, more_label)
+    }
+}
+
+impl ExpandableText {
+    /// The text shown while collapsed, reporting whether it is truncated.
+    fn collapsed(&self) -> Text {
+        self.text.clone().report_truncation(&self.truncated)
+    }
+
+    /// The text shown once expanded.
+    fn full(&self) -> Text {
+        self.text.clone().disable_line_limit()
+    }
+
+    /// Whether the "more" label is visible.
+    fn shows_more(&self) -> Computed<bool> {
+        self.truncated
+            .clone()
+            .zip(self.expanded.clone())
+            .map(|(truncated, expanded)| truncated && !expanded)
+            .computed()
+    }
+}
+
+impl View for ExpandableText {
+    fn body(self, _env: &Environment) -> impl View {
+        let collapsed = self.collapsed();
+        let full = self.full();
+        let show_more = self.shows_more();
+        let content = Dynamic::watch_by_id(
+            self.expanded.clone(),
+            |expanded| *expanded,
+            move |expanded| {
+                if expanded {
+                    full.clone()
+                } else {
+                    collapsed.clone()
+                }
+            },
+        );
+
+        let expanded = self.expanded;
+        let more_label = self.more_label;
+        vstack((
+            content,
+            when(show_more, move || {
+                let expanded = expanded.clone();
+                more_label.clone().on_tap(move || expanded.set(true))
+            }),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nami::Signal;
+    use waterui_core::view::ConfigurableView;
+    use waterui_text::text;
+
+    #[test]
+    fn collapsed_text_keeps_limit_and_full_text_drops_it() {
+        let expandable = text("Long text").line_limit(2).expandable("More");
+
+        let collapsed = expandable.collapsed().config();
+        assert_eq!(collapsed.line_limit, core::num::NonZeroUsize::new(2));
+        assert!(collapsed.truncated.is_some());
+        assert_eq!(expandable.full().config().line_limit, None);
+    }
+
+    #[test]
+    fn more_label_shows_only_while_truncated_and_collapsed() {
+        let expanded = Binding::bool(false);
+        let expandable = text("Long text")
+            .line_limit(2)
+            .expandable("More")
+            .expanded(&expanded);
+        let shows_more = expandable.shows_more();
+        assert!(!shows_more.get());
+
+        // The backend reports truncation through the collapsed text's binding
+        expandable.collapsed().config().truncated.unwrap().set(true);
+        assert!(expandable.is_truncated().get());
+        assert!(shows_more.get());
+
+        expanded.set(true);
+        assert!(!shows_more.get());
+
+        expanded.set(false);
+        assert!(shows_more.get());
+    }
+}
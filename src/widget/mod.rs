@@ -2,11 +2,13 @@ pub mod accordion;
 pub mod card;
 pub mod condition;
 pub mod error;
+pub mod expandable;
 pub mod suspense;
 // pub mod tree;
 
 pub use accordion::{Accordion, accordion};
 pub use card::{Card, card};
+pub use expandable::{ExpandableText, TextExt};
 pub use suspense::{Suspense, suspense};
 // pub use tree::{TreeNode, TreeView, tree_view};
 
@@ -33,13 +33,13 @@
 
 use core::future::Future;
 
-use executor_core::spawn_local;
 use waterui_core::{
     AnyView, Environment, View,
+    animation::Transition,
     handler::{AnyViewBuilder, ViewBuilder},
 };
 
-use crate::{ViewExt, component::Dynamic};
+use crate::{ViewExt, component::Dynamic, task::spawn_local};
 
 /// A component that manages asynchronous content loading with loading states.
 ///
@@ -87,6 +87,7 @@ use crate::{ViewExt, component::Dynamic};
 pub struct Suspense<V, Loading> {
     content: V,
     loading: Loading,
+    transition: Option<Transition>,
 }
 
 /// Trait for views that can be loaded asynchronously within a `Suspense` component.
@@ -230,6 +231,7 @@ impl<V: SuspendedView> Suspense<V, UseDefaultLoadingView> {
         Self {
             content,
             loading: UseDefaultLoadingView,
+            transition: None,
         }
     }
 }
@@ -248,8 +250,31 @@ impl<V, Loading> Suspense<V, Loading> {
         Suspense {
             content: self.content,
             loading,
+            transition: self.transition,
         }
     }
+
+    /// Animates the switch from the loading view to the loaded content.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use core::time::Duration;
+    /// use waterui::animation::CrossFade;
+    /// use waterui::widget::suspense::Suspense;
+    /// use waterui::component::text::Text;
+    ///
+    /// async fn fetch_data() -> Text {
+    ///     Text::new("Data loaded!")
+    /// }
+    ///
+    /// let view = Suspense::new(fetch_data()).transition(CrossFade(Duration::from_millis(200)));
+    /// ```
+    #[must_use]
+    pub const fn transition(mut self, transition: Transition) -> Self {
+        self.transition = Some(transition);
+        self
+    }
 }
 
 impl<V, Loading> View for Suspense<V, Loading>
@@ -260,6 +285,9 @@ where
     fn body(self, env: &Environment) -> impl View {
         let (handler, view) = Dynamic::new();
         handler.set(self.loading);
+        if let Some(transition) = self.transition {
+            handler.set_transition(transition);
+        }
 
         let new_env = env.clone();
         spawn_local(async move {
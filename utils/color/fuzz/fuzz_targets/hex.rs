@@ -0,0 +1,16 @@
+//! Parses arbitrary strings as hex colors and checks that valid colors round-trip.
+//!
+//! Run with `cargo +nightly fuzz run hex` from `utils/color`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use waterui_color::Srgb;
+
+fuzz_target!(|input: &str| {
+    if let Ok(color) = Srgb::try_from_hex(input) {
+        let hex = color.to_hex();
+        let reparsed = Srgb::try_from_hex(&hex).expect("formatted color does not parse");
+        assert_eq!(reparsed.to_u8(), color.to_u8());
+    }
+});
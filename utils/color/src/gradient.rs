@@ -0,0 +1,351 @@
+//! Gradients that can be used wherever a color background is accepted.
+//!
+//! [`LinearGradient`], [`RadialGradient`] and [`AngularGradient`] describe
+//! their geometry relative to the bounds of the view they fill, so the same
+//! gradient works for views of any size. Their stops are [`Color`]s and
+//! resolve reactively, just like a solid color background.
+//!
+//! ```ignore
+//! text("Hello").background(
+//!     LinearGradient::new(UnitPoint::TOP, UnitPoint::BOTTOM)
+//!         .stop(0.0, Color::blue())
+//!         .stop(1.0, Color::purple()),
+//! )
+//! ```
+
+use core::f32::consts::TAU;
+
+use nami::{Computed, Signal, SignalExt, impl_constant};
+use waterui_core::{
+    Environment,
+    resolve::{AnyResolvable, Resolvable},
+};
+
+use crate::{Color, ResolvedColor};
+
+/// A point in the unit square of a view's bounds.
+///
+/// `(0, 0)` is the top leading corner and `(1, 1)` the bottom trailing one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UnitPoint {
+    /// Horizontal position, from leading (0) to trailing (1).
+    pub x: f32,
+    /// Vertical position, from top (0) to bottom (1).
+    pub y: f32,
+}
+
+impl UnitPoint {
+    /// The top leading corner.
+    pub const TOP_LEADING: Self = Self::new(0.0, 0.0);
+    /// The center of the top edge.
+    pub const TOP: Self = Self::new(0.5, 0.0);
+    /// The top trailing corner.
+    pub const TOP_TRAILING: Self = Self::new(1.0, 0.0);
+    /// The center of the leading edge.
+    pub const LEADING: Self = Self::new(0.0, 0.5);
+    /// The center.
+    pub const CENTER: Self = Self::new(0.5, 0.5);
+    /// The center of the trailing edge.
+    pub const TRAILING: Self = Self::new(1.0, 0.5);
+    /// The bottom leading corner.
+    pub const BOTTOM_LEADING: Self = Self::new(0.0, 1.0);
+    /// The center of the bottom edge.
+    pub const BOTTOM: Self = Self::new(0.5, 1.0);
+    /// The bottom trailing corner.
+    pub const BOTTOM_TRAILING: Self = Self::new(1.0, 1.0);
+
+    /// Creates a unit point.
+    #[must_use]
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+/// A color at a position along a gradient.
+#[derive(Debug, Clone)]
+pub struct GradientStop {
+    /// Position along the gradient (0.0 to 1.0).
+    pub offset: f32,
+    /// Color at this position.
+    pub color: Color,
+}
+
+impl GradientStop {
+    /// Creates a gradient stop.
+    #[must_use]
+    pub fn new(offset: f32, color: impl Into<Color>) -> Self {
+        Self {
+            offset,
+            color: color.into(),
+        }
+    }
+}
+
+/// A gradient stop whose color has been resolved.
+#[derive(Debug, Clone, Copy)]
+pub struct ResolvedGradientStop {
+    /// Position along the gradient (0.0 to 1.0).
+    pub offset: f32,
+    /// Color at this position.
+    pub color: ResolvedColor,
+}
+
+/// The shape of a gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientGeometry {
+    /// Colors change along the line from `start` to `end`.
+    Linear {
+        /// Where the first stop is drawn.
+        start: UnitPoint,
+        /// Where the last stop is drawn.
+        end: UnitPoint,
+    },
+    /// Colors change along circles around `center`.
+    Radial {
+        /// The center of the circles.
+        center: UnitPoint,
+        /// Radius of the first stop, in points.
+        start_radius: f32,
+        /// Radius of the last stop, in points.
+        end_radius: f32,
+    },
+    /// Colors change with the angle around `center`.
+    Angular {
+        /// The center of the sweep.
+        center: UnitPoint,
+        /// Angle of the first stop, in radians clockwise from the trailing edge.
+        start_angle: f32,
+        /// Angle of the last stop, in radians clockwise from the trailing edge.
+        end_angle: f32,
+    },
+}
+
+/// A gradient whose stop colors have been resolved.
+#[derive(Debug, Clone)]
+pub struct ResolvedGradient {
+    /// The shape of the gradient.
+    pub geometry: GradientGeometry,
+    /// The stops, in the order they were added.
+    pub stops: Vec<ResolvedGradientStop>,
+}
+
+impl_constant!(ResolvedGradient);
+
+/// Resolves every stop color and collects the result into one signal.
+fn resolve_stops(stops: &[GradientStop], env: &Environment) -> Computed<Vec<ResolvedGradientStop>> {
+    stops
+        .iter()
+        .fold(Computed::constant(Vec::new()), |resolved, stop| {
+            let offset = stop.offset;
+            resolved
+                .zip(stop.color.resolve(env))
+                .map(move |(mut stops, color)| {
+                    stops.push(ResolvedGradientStop { offset, color });
+                    stops
+                })
+                .computed()
+        })
+}
+
+fn resolve_gradient(
+    geometry: GradientGeometry,
+    stops: &[GradientStop],
+    env: &Environment,
+) -> impl Signal<Output = ResolvedGradient> {
+    resolve_stops(stops, env).map(move |stops| ResolvedGradient { geometry, stops })
+}
+
+macro_rules! gradient_stops {
+    ($ty:ty) => {
+        impl $ty {
+            /// Adds a color stop at `offset` (0.0 to 1.0) along the gradient.
+            #[must_use]
+            pub fn stop(mut self, offset: f32, color: impl Into<Color>) -> Self {
+                self.stops.push(GradientStop::new(offset, color));
+                self
+            }
+
+            /// Adds a color stop to the gradient in place.
+            pub fn add_color_stop(&mut self, offset: f32, color: impl Into<Color>) {
+                self.stops.push(GradientStop::new(offset, color));
+            }
+
+            /// Returns the stops of the gradient.
+            #[must_use]
+            pub fn stops(&self) -> &[GradientStop] {
+                &self.stops
+            }
+        }
+    };
+}
+
+/// A gradient along a straight line.
+#[derive(Debug, Clone)]
+pub struct LinearGradient {
+    start: UnitPoint,
+    end: UnitPoint,
+    stops: Vec<GradientStop>,
+}
+
+impl LinearGradient {
+    /// Creates a gradient from `start` to `end` without stops.
+    #[must_use]
+    pub const fn new(start: UnitPoint, end: UnitPoint) -> Self {
+        Self {
+            start,
+            end,
+            stops: Vec::new(),
+        }
+    }
+}
+
+gradient_stops!(LinearGradient);
+
+impl Resolvable for LinearGradient {
+    type Resolved = ResolvedGradient;
+    fn resolve(&self, env: &Environment) -> impl Signal<Output = Self::Resolved> {
+        let geometry = GradientGeometry::Linear {
+            start: self.start,
+            end: self.end,
+        };
+        resolve_gradient(geometry, &self.stops, env)
+    }
+}
+
+/// A gradient along circles around a center.
+#[derive(Debug, Clone)]
+pub struct RadialGradient {
+    center: UnitPoint,
+    start_radius: f32,
+    end_radius: f32,
+    stops: Vec<GradientStop>,
+}
+
+impl RadialGradient {
+    /// Creates a gradient around `center` from `start_radius` to
+    /// `end_radius` points, without stops.
+    #[must_use]
+    pub const fn new(center: UnitPoint, start_radius: f32, end_radius: f32) -> Self {
+        Self {
+            center,
+            start_radius,
+            end_radius,
+            stops: Vec::new(),
+        }
+    }
+}
+
+gradient_stops!(RadialGradient);
+
+impl Resolvable for RadialGradient {
+    type Resolved = ResolvedGradient;
+    fn resolve(&self, env: &Environment) -> impl Signal<Output = Self::Resolved> {
+        let geometry = GradientGeometry::Radial {
+            center: self.center,
+            start_radius: self.start_radius,
+            end_radius: self.end_radius,
+        };
+        resolve_gradient(geometry, &self.stops, env)
+    }
+}
+
+/// A gradient that sweeps around a center, also known as a conic gradient.
+#[derive(Debug, Clone)]
+pub struct AngularGradient {
+    center: UnitPoint,
+    start_angle: f32,
+    end_angle: f32,
+    stops: Vec<GradientStop>,
+}
+
+impl AngularGradient {
+    /// Creates a gradient that sweeps a full turn around `center`, without
+    /// stops.
+    #[must_use]
+    pub const fn new(center: UnitPoint) -> Self {
+        Self::with_angles(center, 0.0, TAU)
+    }
+
+    /// Creates a gradient that sweeps around `center` from `start_angle` to
+    /// `end_angle` radians, without stops.
+    #[must_use]
+    pub const fn with_angles(center: UnitPoint, start_angle: f32, end_angle: f32) -> Self {
+        Self {
+            center,
+            start_angle,
+            end_angle,
+            stops: Vec::new(),
+        }
+    }
+}
+
+gradient_stops!(AngularGradient);
+
+impl Resolvable for AngularGradient {
+    type Resolved = ResolvedGradient;
+    fn resolve(&self, env: &Environment) -> impl Signal<Output = Self::Resolved> {
+        let geometry = GradientGeometry::Angular {
+            center: self.center,
+            start_angle: self.start_angle,
+            end_angle: self.end_angle,
+        };
+        resolve_gradient(geometry, &self.stops, env)
+    }
+}
+
+/// A type-erased gradient of any shape.
+///
+/// Like [`Color`], this wraps a resolvable value and is resolved against the
+/// environment of the view it is drawn in.
+#[derive(Debug, Clone)]
+pub struct Gradient(AnyResolvable<ResolvedGradient>);
+
+impl Gradient {
+    /// Creates a gradient from a resolvable value.
+    pub fn new(gradient: impl Resolvable<Resolved = ResolvedGradient> + 'static) -> Self {
+        Self(AnyResolvable::new(gradient))
+    }
+
+    /// Resolves the gradient in the given environment.
+    #[must_use]
+    pub fn resolve(&self, env: &Environment) -> Computed<ResolvedGradient> {
+        self.0.resolve(env)
+    }
+}
+
+impl_constant!(Gradient);
+
+impl<T: Resolvable<Resolved = ResolvedGradient> + 'static> From<T> for Gradient {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Srgb;
+
+    #[test]
+    fn resolves_stops_and_geometry() {
+        let env = Environment::new();
+        let gradient = Gradient::from(
+            LinearGradient::new(UnitPoint::TOP, UnitPoint::BOTTOM)
+                .stop(0.0, Srgb::new(1.0, 0.0, 0.0))
+                .stop(1.0, Srgb::new(0.0, 0.0, 1.0)),
+        );
+
+        let resolved = gradient.resolve(&env).get();
+        assert_eq!(
+            resolved.geometry,
+            GradientGeometry::Linear {
+                start: UnitPoint::TOP,
+                end: UnitPoint::BOTTOM,
+            }
+        );
+        assert_eq!(resolved.stops.len(), 2);
+        assert!(resolved.stops[0].color.red > resolved.stops[1].color.red);
+        assert!(resolved.stops[1].color.blue > resolved.stops[0].color.blue);
+        assert!(resolved.stops[1].offset > resolved.stops[0].offset);
+    }
+}
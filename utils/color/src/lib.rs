@@ -11,11 +11,22 @@
 //!
 //! The primary type is `Color`, which can represent colors in sRGB, Display P3,
 //! or OKLCH color spaces, with conversion methods from various tuple formats.
+//! Linear, radial and angular gradients built from colors live alongside it.
 
+mod gradient;
+pub use gradient::{
+    AngularGradient, Gradient, GradientGeometry, GradientStop, LinearGradient, RadialGradient,
+    ResolvedGradient, ResolvedGradientStop, UnitPoint,
+};
 mod oklch;
 pub use oklch::Oklch;
 mod p3;
 pub use p3::P3;
+mod scheme;
+pub use scheme::{
+    ColorScheme, SystemColorScheme, current_color_scheme, current_system_color_scheme,
+    install_color_scheme, install_system_color_scheme,
+};
 mod srgb;
 use core::{
     fmt::{self, Debug, Display},
@@ -28,6 +39,8 @@ use nami::{Computed, Signal, SignalExt, impl_constant};
 
 use waterui_core::{
     Environment,
+    animation::Lerp,
+    display,
     layout::StretchAxis,
     raw_view,
     resolve::{self, AnyResolvable, Resolvable},
@@ -158,6 +171,7 @@ mod parse;
 /// This struct stores color components in linear RGB values (0.0-1.0 for standard sRGB,
 /// values outside this range represent colors in extended color spaces like P3).
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResolvedColor {
     /// Red component in linear RGB (0.0-1.0 for sRGB, <0 or >1 for P3)
     pub red: f32,
@@ -235,6 +249,13 @@ impl ResolvedColor {
     }
 }
 
+impl Lerp for ResolvedColor {
+    #[allow(clippy::cast_possible_truncation)]
+    fn lerp(&self, to: &Self, t: f64) -> Self {
+        Self::lerp(*self, *to, t as f32)
+    }
+}
+
 impl From<Srgb> for ResolvedColor {
     fn from(value: Srgb) -> Self {
         value.resolve()
@@ -433,12 +454,18 @@ impl Color {
 
     /// Creates a new color with extended headroom for HDR content.
     ///
+    /// The headroom is limited to what the display the color is shown on can
+    /// currently show, as reported by the backend through the display
+    /// capabilities.
+    ///
     /// # Arguments
     /// * `headroom` - Additional headroom value for extended range
     #[must_use]
     pub fn with_headroom(self, headroom: f32) -> Self {
-        let clamped = clamp_non_negative(headroom);
-        self.map_resolved(move |resolved| resolved.with_headroom(clamped))
+        Self::new(Headroom {
+            color: self.0,
+            headroom: clamp_non_negative(headroom),
+        })
     }
 
     /// Lightens the color by increasing its OKLCH lightness component.
@@ -495,6 +522,14 @@ impl Color {
     }
 }
 
+impl Lerp for Color {
+    /// Mixes the two colors, so theme colors keep following the environment.
+    #[allow(clippy::cast_possible_truncation)]
+    fn lerp(&self, to: &Self, t: f64) -> Self {
+        self.clone().mix(to.clone(), t as f32)
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Mix {
     first: AnyResolvable<ResolvedColor>,
@@ -514,6 +549,23 @@ impl Resolvable for Mix {
     }
 }
 
+#[derive(Debug, Clone)]
+struct Headroom {
+    color: AnyResolvable<ResolvedColor>,
+    headroom: f32,
+}
+
+impl Resolvable for Headroom {
+    type Resolved = ResolvedColor;
+
+    fn resolve(&self, env: &Environment) -> impl Signal<Output = Self::Resolved> {
+        self.color
+            .resolve(env)
+            .zip(display::clamp_headroom(env, self.headroom))
+            .map(|(color, headroom)| color.with_headroom(headroom))
+    }
+}
+
 macro_rules! color_const {
     ($name:ident,$doc:expr) => {
         paste! {
@@ -567,6 +619,47 @@ color_const!(Brown, "Brown color.");
 
 color_const!(Grey, "Grey color.");
 color_const!(BlueGrey, "Blue grey color.");
+
+/// Color for errors and destructive actions.
+///
+/// Themes install its signals as [`DestructiveColors`]; without them it
+/// resolves to [`Red`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Destructive;
+
+/// The signals a theme installs for the [`Destructive`] color.
+///
+/// With a dark variant, the color follows the [`current_color_scheme`] of the
+/// environment it is resolved in.
+#[derive(Debug, Clone)]
+pub struct DestructiveColors {
+    /// The color in light appearance, or in both without a dark variant.
+    pub light: Computed<ResolvedColor>,
+    /// The color in dark appearance.
+    pub dark: Option<Computed<ResolvedColor>>,
+}
+
+impl Resolvable for Destructive {
+    type Resolved = ResolvedColor;
+
+    fn resolve(&self, env: &Environment) -> impl Signal<Output = Self::Resolved> {
+        let Some(colors) = env.get::<DestructiveColors>() else {
+            return Color::red().resolve(env);
+        };
+        colors.dark.clone().map_or_else(
+            || colors.light.clone(),
+            |dark| {
+                current_color_scheme(env)
+                    .zip(colors.light.clone())
+                    .zip(dark)
+                    .map(|((scheme, light), dark)| if scheme.is_dark() { dark } else { light })
+                    .computed()
+            },
+        )
+    }
+}
+
+impl_constant!(Destructive);
 raw_view!(Color, StretchAxis::Both);
 
 // https://www.w3.org/TR/css-color-4/#color-conversion-code
@@ -912,4 +1005,50 @@ mod tests {
         assert!(approx_eq(mid.green, 0.5, EPSILON));
         assert!(approx_eq(mid.blue, 0.5, EPSILON));
     }
+
+    #[test]
+    fn headroom_is_limited_by_the_display() {
+        let mut env = Environment::new();
+        let color = Color::srgb(255, 255, 255).with_headroom(2.0);
+        assert!(approx_eq(color.resolve(&env).get().headroom, 2.0, EPSILON));
+
+        let reporter = waterui_core::display::DisplayReporter::install(&mut env);
+        let resolved = color.resolve(&env);
+        assert!(approx_eq(resolved.get().headroom, 0.0, EPSILON));
+        reporter.set_max_headroom(1.0);
+        assert!(approx_eq(resolved.get().headroom, 1.0, EPSILON));
+    }
+
+    #[test]
+    fn hex_formatting_clamps_components() {
+        assert_eq!(Srgb::new(1.5, -0.2, f32::NAN).to_hex(), "#FF0000");
+        assert_eq!(Srgb::from_hex("#1a2b3c").to_hex(), "#1A2B3C");
+    }
+
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        proptest! {
+            #[test]
+            fn try_from_hex_never_panics(input in "\\PC*") {
+                let _ = Srgb::try_from_hex(&input);
+            }
+
+            #[test]
+            fn hex_roundtrip(red: u8, green: u8, blue: u8) {
+                let color = Srgb::new_u8(red, green, blue);
+                let parsed = Srgb::try_from_hex(&color.to_hex()).unwrap();
+                prop_assert_eq!(parsed.to_u8(), [red, green, blue]);
+            }
+
+            #[test]
+            fn hex_prefixes_are_equivalent(digits in "[0-9a-fA-F]{6}") {
+                let bare = Srgb::try_from_hex(&digits).unwrap().to_u8();
+                prop_assert_eq!(Srgb::try_from_hex(&format!("#{digits}")).unwrap().to_u8(), bare);
+                prop_assert_eq!(Srgb::try_from_hex(&format!("0x{digits}")).unwrap().to_u8(), bare);
+                prop_assert_eq!(Srgb::from_hex(&digits).to_u8(), bare);
+            }
+        }
+    }
 }
@@ -0,0 +1,192 @@
+//! Light/dark appearance preference.
+
+use nami::{Binding, Computed, SignalExt, impl_constant, signal::IntoComputed};
+use waterui_core::Environment;
+
+/// The color scheme preference for the UI.
+///
+/// This is used to switch between light and dark appearances. Native backends
+/// typically bind this to the system appearance setting.
+///
+/// # Example
+///
+/// ```ignore
+/// use waterui::theme::{Theme, ColorScheme};
+/// use nami::binding;
+///
+/// // Static: always dark
+/// Theme::new().color_scheme(ColorScheme::Dark);
+///
+/// // Follows the operating system appearance
+/// Theme::new().color_scheme(ColorScheme::System);
+///
+/// // Reactive: driven by an app setting
+/// let scheme = binding(ColorScheme::Light);
+/// Theme::new().color_scheme(scheme);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorScheme {
+    /// Light appearance (light backgrounds, dark text).
+    #[default]
+    Light,
+    /// Dark appearance (dark backgrounds, light text).
+    Dark,
+    /// Follows the appearance of the operating system.
+    ///
+    /// Resolved against the signal installed with
+    /// [`install_system_color_scheme`]; [`current_color_scheme`] never
+    /// returns it.
+    System,
+}
+
+impl_constant!(ColorScheme);
+
+impl ColorScheme {
+    /// Resolves [`System`](Self::System) to the given system appearance.
+    ///
+    /// A system appearance of `System` itself resolves to `Light`.
+    #[must_use]
+    pub fn resolve(self, system: Self) -> Self {
+        match self {
+            Self::System => system.resolve(Self::Light),
+            scheme => scheme,
+        }
+    }
+
+    /// Returns `true` if this is the dark appearance.
+    #[must_use]
+    pub const fn is_dark(self) -> bool {
+        matches!(self, Self::Dark)
+    }
+}
+
+/// Storage for the color scheme signal.
+#[derive(Clone)]
+struct ColorSchemeSignal(Computed<ColorScheme>);
+
+/// Storage for the operating system appearance signal.
+#[derive(Clone)]
+struct SystemColorSchemeSignal(Computed<ColorScheme>);
+
+/// Returns the current color scheme signal from the environment.
+///
+/// The signal is always `Light` or `Dark`: an installed
+/// [`ColorScheme::System`] follows the system appearance. If no color scheme
+/// is installed, the system appearance is returned.
+#[must_use]
+pub fn current_color_scheme(env: &Environment) -> Computed<ColorScheme> {
+    let system = current_system_color_scheme(env);
+    env.get::<ColorSchemeSignal>().map_or_else(
+        || system.clone(),
+        |s| {
+            s.0.clone()
+                .zip(system.clone())
+                .map(|(scheme, system)| scheme.resolve(system))
+                .computed()
+        },
+    )
+}
+
+/// Installs a color scheme signal.
+///
+/// This is used by native backends to inject a reactive color scheme that
+/// tracks the system appearance setting.
+pub fn install_color_scheme(env: &mut Environment, signal: impl IntoComputed<ColorScheme>) {
+    env.insert(ColorSchemeSignal(signal.into_computed()));
+}
+
+/// Returns the appearance of the operating system.
+///
+/// If no system appearance is installed, returns a constant `Light` signal.
+#[must_use]
+pub fn current_system_color_scheme(env: &Environment) -> Computed<ColorScheme> {
+    env.get::<SystemColorSchemeSignal>()
+        .map_or_else(|| Computed::constant(ColorScheme::Light), |s| s.0.clone())
+}
+
+/// Installs the operating system appearance signal.
+///
+/// Native backends install this once and update it when the user switches
+/// between light and dark mode. It drives [`ColorScheme::System`].
+pub fn install_system_color_scheme(env: &mut Environment, signal: impl IntoComputed<ColorScheme>) {
+    env.insert(SystemColorSchemeSignal(signal.into_computed()));
+}
+
+/// Writes the operating system appearance that a backend observes.
+///
+/// Backends whose platform reports appearance changes through callbacks use
+/// this instead of building a signal themselves: an Android configuration
+/// change, or a `prefers-color-scheme` media query listener on the web, calls
+/// [`set`](Self::set). Backends without an appearance setting, such as a
+/// terminal, install nothing and the appearance stays `Light`.
+#[derive(Clone)]
+pub struct SystemColorScheme(Binding<ColorScheme>);
+
+impl core::fmt::Debug for SystemColorScheme {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("SystemColorScheme")
+            .field(&self.0.get())
+            .finish()
+    }
+}
+
+impl SystemColorScheme {
+    /// Installs the system appearance signal into `env`, starting at `initial`.
+    #[must_use]
+    pub fn install(env: &mut Environment, initial: ColorScheme) -> Self {
+        let scheme = Self(Binding::container(initial.resolve(ColorScheme::Light)));
+        install_system_color_scheme(env, scheme.0.clone().computed());
+        scheme
+    }
+
+    /// Sets the system appearance.
+    ///
+    /// `System` is treated as `Light`. Views are only updated if the
+    /// appearance changed.
+    pub fn set(&self, scheme: ColorScheme) {
+        let scheme = scheme.resolve(ColorScheme::Light);
+        if self.0.get() != scheme {
+            self.0.set(scheme);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nami::Signal;
+
+    #[test]
+    fn system_scheme_follows_system_appearance() {
+        let mut env = Environment::new();
+        assert_eq!(current_color_scheme(&env).get(), ColorScheme::Light);
+
+        let system = Binding::container(ColorScheme::Dark);
+        install_system_color_scheme(&mut env, system.clone().computed());
+        assert_eq!(current_color_scheme(&env).get(), ColorScheme::Dark);
+
+        install_color_scheme(&mut env, ColorScheme::System);
+        let scheme = current_color_scheme(&env);
+        assert_eq!(scheme.get(), ColorScheme::Dark);
+        system.set(ColorScheme::Light);
+        assert_eq!(scheme.get(), ColorScheme::Light);
+
+        install_color_scheme(&mut env, ColorScheme::Dark);
+        assert_eq!(current_color_scheme(&env).get(), ColorScheme::Dark);
+    }
+
+    #[test]
+    fn reporter_updates_system_appearance() {
+        let mut env = Environment::new();
+        let system = SystemColorScheme::install(&mut env, ColorScheme::Dark);
+        install_color_scheme(&mut env, ColorScheme::System);
+
+        let scheme = current_color_scheme(&env);
+        assert_eq!(scheme.get(), ColorScheme::Dark);
+        system.set(ColorScheme::Light);
+        assert_eq!(scheme.get(), ColorScheme::Light);
+        system.set(ColorScheme::System);
+        assert_eq!(current_system_color_scheme(&env).get(), ColorScheme::Light);
+    }
+}
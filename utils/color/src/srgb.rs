@@ -105,6 +105,26 @@ impl Srgb {
         )
     }
 
+    /// Returns the 8-bit red, green, and blue components.
+    ///
+    /// Components are clamped to 0.0..=1.0 and rounded to the nearest value;
+    /// `NaN` maps to 0.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn to_u8(&self) -> [u8; 3] {
+        let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [channel(self.red), channel(self.green), channel(self.blue)]
+    }
+
+    /// Formats this color as an uppercase `#RRGGBB` string.
+    ///
+    /// [`Srgb::try_from_hex`] parses the result back to the same 8-bit color.
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        let [red, green, blue] = self.to_u8();
+        format!("#{red:02X}{green:02X}{blue:02X}")
+    }
+
     /// Converts this sRGB color to the P3 color space.
     #[must_use]
     pub fn to_p3(&self) -> P3 {
@@ -0,0 +1,45 @@
+//! Error type for store operations.
+
+use core::fmt;
+
+/// Error returned when the database or a record's serialization fails.
+#[derive(Debug)]
+pub enum Error {
+    /// `SQLite` failed to open the database or run a statement.
+    Sqlite(rusqlite::Error),
+    /// A record or its id could not be converted to or from JSON.
+    Json(serde_json::Error),
+}
+
+/// Result of a store operation.
+pub type Result<T> = core::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sqlite(error) => write!(f, "database error: {error}"),
+            Self::Json(error) => write!(f, "failed to convert record: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Sqlite(error) => Some(error),
+            Self::Json(error) => Some(error),
+        }
+    }
+}
+
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Self::Sqlite(error)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
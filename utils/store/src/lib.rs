@@ -0,0 +1,162 @@
+//! # `WaterUI` Store
+//!
+//! Durable, reactive collections backed by a bundled `SQLite` database.
+//!
+//! Records are serde types implementing [`Record`], stored as JSON in one
+//! table per type. [`Store::table`] returns a typed [`Table`] for reading
+//! and writing them, and [`Table::all`] or [`Table::filter`] return a live
+//! [`Query`]. A query is both a [`Signal`](nami::Signal) of its rows and a
+//! [`Collection`](nami::collection::Collection), so it can be handed straight
+//! to `List::for_each`; every insert, update or delete made through the
+//! store refreshes the queries over the changed table.
+//!
+//! ```ignore
+//! use serde::{Deserialize, Serialize};
+//! use waterui::store::{Record, Store};
+//!
+//! #[derive(Clone, Serialize, Deserialize)]
+//! struct Todo {
+//!     id: u64,
+//!     title: String,
+//!     done: bool,
+//! }
+//!
+//! impl Identifiable for Todo {
+//!     type Id = u64;
+//!     fn id(&self) -> u64 {
+//!         self.id
+//!     }
+//! }
+//!
+//! impl Record for Todo {
+//!     const TABLE: &'static str = "todos";
+//! }
+//!
+//! let store = Store::open("todos.db")?;
+//! let todos = store.table::<Todo>()?;
+//!
+//! // Shows new open todos as soon as they are inserted
+//! let list = List::for_each(todos.filter(|todo| !todo.done), todo_row);
+//! todos.insert(&Todo { id: 1, title: "Water the plants".into(), done: false })?;
+//! ```
+//!
+//! A [`Store`] is cheap to clone; apps usually open it once and insert it
+//! into the [`Environment`](waterui_core::Environment) so views can reach it
+//! with `env.get::<Store>()`.
+
+mod error;
+mod query;
+mod table;
+
+pub use error::{Error, Result};
+pub use query::Query;
+pub use table::Table;
+
+use std::{
+    cell::RefCell,
+    fmt,
+    path::Path,
+    rc::{Rc, Weak},
+};
+
+use rusqlite::Connection;
+use serde::{Serialize, de::DeserializeOwned};
+use waterui_core::id::Identifiable;
+
+/// A type stored as rows of a [`Table`].
+///
+/// Records are serialized as JSON, so fields can be added later as long as
+/// older rows still deserialize, for example with `#[serde(default)]`. Rows
+/// that no longer deserialize are skipped by queries.
+pub trait Record:
+    Identifiable<Id: Serialize> + Serialize + DeserializeOwned + Clone + 'static
+{
+    /// Name of the `SQLite` table holding the records.
+    const TABLE: &'static str;
+}
+
+/// Reloads a live query after its table changes.
+trait Refresh {
+    fn refresh(&self);
+}
+
+struct Inner {
+    connection: Connection,
+    /// Live queries by table name, dropped lazily once their last clone is.
+    queries: RefCell<Vec<(&'static str, Weak<dyn Refresh>)>>,
+}
+
+/// A `SQLite` database holding tables of records.
+#[derive(Clone)]
+pub struct Store(Rc<Inner>);
+
+impl fmt::Debug for Store {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Store")
+            .field("path", &self.0.connection.path())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Store {
+    /// Opens the database at `path`, creating it if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `SQLite` cannot open or create the file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(Connection::open(path)?))
+    }
+
+    /// Opens an empty database that only lives in memory, as tests and
+    /// previews use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `SQLite` fails to allocate the database.
+    pub fn in_memory() -> Result<Self> {
+        Ok(Self::new(Connection::open_in_memory()?))
+    }
+
+    fn new(connection: Connection) -> Self {
+        Self(Rc::new(Inner {
+            connection,
+            queries: RefCell::new(Vec::new()),
+        }))
+    }
+
+    /// Returns the table of `T` records, creating it if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the table cannot be created.
+    pub fn table<T: Record>(&self) -> Result<Table<T>> {
+        Table::create(self.clone())
+    }
+
+    pub(crate) fn connection(&self) -> &Connection {
+        &self.0.connection
+    }
+
+    /// Keeps `query` up to date with changes to `table`.
+    fn register(&self, table: &'static str, query: Weak<dyn Refresh>) {
+        self.0.queries.borrow_mut().push((table, query));
+    }
+
+    /// Refreshes the live queries over `table`.
+    fn changed(&self, table: &'static str) {
+        // Collected first, so watchers reacting to the refresh may write to the store
+        let live: Vec<Rc<dyn Refresh>> = {
+            let mut queries = self.0.queries.borrow_mut();
+            queries.retain(|(_, query)| query.strong_count() > 0);
+            queries
+                .iter()
+                .filter(|(name, _)| *name == table)
+                .filter_map(|(_, query)| query.upgrade())
+                .collect()
+        };
+        for query in live {
+            query.refresh();
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Live queries over a table.
+
+use std::{
+    fmt,
+    ops::{Bound, Range, RangeBounds},
+    rc::Rc,
+};
+
+use nami::{Binding, Signal, collection::Collection, watcher::Context};
+
+use crate::{Record, Refresh, Table};
+
+type Predicate<T> = Box<dyn Fn(&T) -> bool>;
+
+struct State<T: 'static> {
+    table: Table<T>,
+    filter: Option<Predicate<T>>,
+    rows: Binding<Rc<[T]>>,
+}
+
+impl<T: Record> State<T> {
+    fn load(&self) -> Option<Rc<[T]>> {
+        match self.table.load() {
+            Ok(mut records) => {
+                if let Some(filter) = &self.filter {
+                    records.retain(|record| filter(record));
+                }
+                Some(records.into())
+            }
+            Err(error) => {
+                tracing::warn!("failed to load `{}`: {error}", T::TABLE);
+                None
+            }
+        }
+    }
+}
+
+impl<T: Record> Refresh for State<T> {
+    fn refresh(&self) {
+        // On failure the query keeps showing the rows it last loaded
+        if let Some(rows) = self.load() {
+            self.rows.set(rows);
+        }
+    }
+}
+
+/// The records of a table matching a filter, kept up to date as the table
+/// changes.
+///
+/// A query is a [`Signal`] of its rows and a [`Collection`] of records, so
+/// it can drive `List::for_each` and `ForEach` directly. Rows are reloaded
+/// whenever the store writes to the table; clones share the same rows.
+pub struct Query<T: 'static>(Rc<State<T>>);
+
+impl<T: 'static> Clone for Query<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Record> fmt::Debug for Query<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Query")
+            .field("table", &T::TABLE)
+            .field("len", &self.0.rows.get().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Record> Query<T> {
+    pub(crate) fn new(table: Table<T>, filter: Option<Predicate<T>>) -> Self {
+        let mut state = State {
+            table,
+            filter,
+            rows: Binding::container(Rc::from([])),
+        };
+        if let Some(rows) = state.load() {
+            state.rows = Binding::container(rows);
+        }
+        let state = Rc::new(state);
+        let weak = Rc::downgrade(&state);
+        state.table.store().register(T::TABLE, weak);
+        Self(state)
+    }
+
+    /// Returns the rows as of the last change, without copying them.
+    #[must_use]
+    pub fn rows(&self) -> Rc<[T]> {
+        self.0.rows.get()
+    }
+}
+
+/// Clamps `range` to a slice of `len` items.
+fn clamp(range: (Bound<usize>, Bound<usize>), len: usize) -> Range<usize> {
+    let start = match range.0 {
+        Bound::Included(start) => start,
+        Bound::Excluded(start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.1 {
+        Bound::Included(end) => end.saturating_add(1),
+        Bound::Excluded(end) => end,
+        Bound::Unbounded => len,
+    };
+    let end = end.min(len);
+    start.min(end)..end
+}
+
+impl<T: Record> Signal for Query<T> {
+    type Output = Vec<T>;
+    type Guard = <Binding<Rc<[T]>> as Signal>::Guard;
+
+    fn get(&self) -> Self::Output {
+        self.rows().to_vec()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.0
+            .rows
+            .watch(move |ctx| watcher(ctx.map(|rows| rows.to_vec())))
+    }
+}
+
+impl<T: Record> Collection for Query<T> {
+    type Item = T;
+    type Guard = <Binding<Rc<[T]>> as Signal>::Guard;
+
+    fn get(&self, index: usize) -> Option<Self::Item> {
+        self.rows().as_ref().get(index).cloned()
+    }
+
+    fn len(&self) -> usize {
+        self.rows().len()
+    }
+
+    fn watch(
+        &self,
+        range: impl RangeBounds<usize>,
+        watcher: impl for<'a> Fn(Context<&'a [Self::Item]>) + 'static,
+    ) -> Self::Guard {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.0.rows.watch(move |ctx| {
+            let ctx = ctx.map(|rows| rows[clamp(range, rows.len())].to_vec());
+            watcher(ctx.as_deref());
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use serde::{Deserialize, Serialize};
+    use waterui_core::id::Identifiable;
+
+    use super::*;
+    use crate::Store;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Todo {
+        id: u32,
+        done: bool,
+    }
+
+    impl Identifiable for Todo {
+        type Id = u32;
+
+        fn id(&self) -> u32 {
+            self.id
+        }
+    }
+
+    impl Record for Todo {
+        const TABLE: &'static str = "todos";
+    }
+
+    const fn todo(id: u32, done: bool) -> Todo {
+        Todo { id, done }
+    }
+
+    #[test]
+    fn queries_follow_writes_from_any_table_handle() {
+        let store = Store::in_memory().unwrap();
+        let open = store.table::<Todo>().unwrap().filter(|todo| !todo.done);
+        let todos = store.table::<Todo>().unwrap();
+        let changes = Rc::new(Cell::new(0));
+        let _guard = Collection::watch(&open, .., {
+            let changes = changes.clone();
+            move |_| changes.set(changes.get() + 1)
+        });
+
+        todos.insert(&todo(1, false)).unwrap();
+        todos.insert(&todo(2, false)).unwrap();
+        assert_eq!(Collection::len(&open), 2);
+
+        todos.update(&todo(1, true)).unwrap();
+        assert_eq!(Signal::get(&open), [todo(2, false)]);
+
+        todos.delete(&2).unwrap();
+        assert_eq!(Collection::len(&open), 0);
+        assert_eq!(changes.get(), 4);
+    }
+
+    #[test]
+    fn dropped_queries_stop_refreshing() {
+        let store = Store::in_memory().unwrap();
+        let todos = store.table::<Todo>().unwrap();
+        drop(todos.all());
+        todos.insert(&todo(1, false)).unwrap();
+        assert_eq!(store.0.queries.borrow().len(), 0);
+    }
+
+    #[test]
+    fn ranges_are_clamped_to_the_rows() {
+        assert_eq!(clamp((Bound::Included(2), Bound::Unbounded), 5), 2..5);
+        assert_eq!(clamp((Bound::Included(4), Bound::Excluded(10)), 3), 3..3);
+        assert_eq!(clamp((Bound::Unbounded, Bound::Included(1)), 3), 0..2);
+    }
+}
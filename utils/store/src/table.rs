@@ -0,0 +1,236 @@
+//! Typed access to the rows of one record type.
+
+use std::{fmt, marker::PhantomData};
+
+use rusqlite::{OptionalExtension, params};
+
+use crate::{Query, Record, Result, Store};
+
+/// The rows of one [`Record`] type.
+///
+/// Writes refresh every live [`Query`] over the table, including queries
+/// created from other `Table` handles of the same store.
+pub struct Table<T> {
+    store: Store,
+    _record: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Table<T> {
+    fn clone(&self) -> Self {
+        Self {
+            store: self.store.clone(),
+            _record: PhantomData,
+        }
+    }
+}
+
+impl<T: Record> fmt::Debug for Table<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Table")
+            .field("name", &T::TABLE)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Quotes a table name for use in SQL.
+fn quoted(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+fn id_key<T: Record>(id: &T::Id) -> Result<String> {
+    Ok(serde_json::to_string(id)?)
+}
+
+impl<T: Record> Table<T> {
+    pub(crate) fn create(store: Store) -> Result<Self> {
+        store.connection().execute(
+            &format!(
+                "CREATE TABLE IF NOT EXISTS {} (id TEXT PRIMARY KEY NOT NULL, value TEXT NOT NULL)",
+                quoted(T::TABLE)
+            ),
+            [],
+        )?;
+        Ok(Self {
+            store,
+            _record: PhantomData,
+        })
+    }
+
+    /// Returns a live query over every record, in insertion order.
+    #[must_use]
+    pub fn all(&self) -> Query<T> {
+        Query::new(self.clone(), None)
+    }
+
+    /// Returns a live query over the records matching `predicate`, in
+    /// insertion order.
+    #[must_use]
+    pub fn filter(&self, predicate: impl Fn(&T) -> bool + 'static) -> Query<T> {
+        Query::new(self.clone(), Some(Box::new(predicate)))
+    }
+
+    /// Returns the record with `id`, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails or the stored row no longer
+    /// deserializes as `T`.
+    pub fn get(&self, id: &T::Id) -> Result<Option<T>> {
+        let value: Option<String> = self
+            .store
+            .connection()
+            .query_row(
+                &format!("SELECT value FROM {} WHERE id = ?1", quoted(T::TABLE)),
+                params![id_key::<T>(id)?],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(value
+            .map(|value| serde_json::from_str(&value))
+            .transpose()?)
+    }
+
+    /// Inserts `record`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a record with the same id exists or the write
+    /// fails.
+    pub fn insert(&self, record: &T) -> Result<()> {
+        self.store.connection().execute(
+            &format!(
+                "INSERT INTO {} (id, value) VALUES (?1, ?2)",
+                quoted(T::TABLE)
+            ),
+            params![id_key::<T>(&record.id())?, serde_json::to_string(record)?],
+        )?;
+        self.store.changed(T::TABLE);
+        Ok(())
+    }
+
+    /// Replaces the record with the id of `record`, keeping its position.
+    ///
+    /// Returns `false` if there is no such record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn update(&self, record: &T) -> Result<bool> {
+        let updated = self.store.connection().execute(
+            &format!("UPDATE {} SET value = ?2 WHERE id = ?1", quoted(T::TABLE)),
+            params![id_key::<T>(&record.id())?, serde_json::to_string(record)?],
+        )?;
+        if updated > 0 {
+            self.store.changed(T::TABLE);
+        }
+        Ok(updated > 0)
+    }
+
+    /// Deletes the record with `id`.
+    ///
+    /// Returns `false` if there is no such record.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the write fails.
+    pub fn delete(&self, id: &T::Id) -> Result<bool> {
+        let deleted = self.store.connection().execute(
+            &format!("DELETE FROM {} WHERE id = ?1", quoted(T::TABLE)),
+            params![id_key::<T>(id)?],
+        )?;
+        if deleted > 0 {
+            self.store.changed(T::TABLE);
+        }
+        Ok(deleted > 0)
+    }
+
+    pub(crate) const fn store(&self) -> &Store {
+        &self.store
+    }
+
+    /// Loads every record that still deserializes, in insertion order.
+    pub(crate) fn load(&self) -> Result<Vec<T>> {
+        let mut statement = self.store.connection().prepare_cached(&format!(
+            "SELECT id, value FROM {} ORDER BY rowid",
+            quoted(T::TABLE)
+        ))?;
+        let rows = statement.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?;
+        let mut records = Vec::new();
+        for row in rows {
+            let (id, value) = row?;
+            match serde_json::from_str(&value) {
+                Ok(record) => records.push(record),
+                Err(error) => {
+                    tracing::warn!("skipping `{}` row {id}: {error}", T::TABLE);
+                }
+            }
+        }
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use waterui_core::id::Identifiable;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Note {
+        id: u32,
+        text: String,
+    }
+
+    impl Identifiable for Note {
+        type Id = u32;
+
+        fn id(&self) -> u32 {
+            self.id
+        }
+    }
+
+    impl Record for Note {
+        const TABLE: &'static str = "notes";
+    }
+
+    fn note(id: u32, text: &str) -> Note {
+        Note {
+            id,
+            text: text.into(),
+        }
+    }
+
+    #[test]
+    fn records_are_inserted_updated_and_deleted_by_id() {
+        let table = Store::in_memory().unwrap().table::<Note>().unwrap();
+        table.insert(&note(1, "first")).unwrap();
+        table.insert(&note(2, "second")).unwrap();
+        assert!(table.insert(&note(1, "duplicate")).is_err());
+
+        assert!(table.update(&note(1, "edited")).unwrap());
+        assert!(!table.update(&note(3, "missing")).unwrap());
+        assert_eq!(table.get(&1).unwrap(), Some(note(1, "edited")));
+
+        assert!(table.delete(&2).unwrap());
+        assert!(!table.delete(&2).unwrap());
+        assert_eq!(table.load().unwrap(), [note(1, "edited")]);
+    }
+
+    #[test]
+    fn undecodable_rows_are_skipped() {
+        let table = Store::in_memory().unwrap().table::<Note>().unwrap();
+        table.insert(&note(1, "kept")).unwrap();
+        table
+            .store()
+            .connection()
+            .execute(
+                "INSERT INTO notes (id, value) VALUES ('2', '{\"id\": 2}')",
+                [],
+            )
+            .unwrap();
+        assert_eq!(table.load().unwrap(), [note(1, "kept")]);
+    }
+}
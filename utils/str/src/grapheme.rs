@@ -0,0 +1,112 @@
+//! Grapheme-cluster-aware string helpers.
+//!
+//! A user-perceived character may span several `char`s: emoji with skin tones
+//! or ZWJ sequences, flags, and letters with combining marks. Counting or
+//! cutting by bytes or `char`s splits them; these helpers work on extended
+//! grapheme clusters instead.
+
+use alloc::string::String;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::Str;
+
+/// Returns the number of user-perceived characters in `s`.
+///
+/// # Examples
+///
+/// ```
+/// use waterui_str::grapheme::grapheme_count;
+///
+/// assert_eq!(grapheme_count("héllo"), 5);
+/// assert_eq!(grapheme_count("👩‍👩‍👧 👍🏽"), 3);
+/// ```
+#[must_use]
+pub fn grapheme_count(s: &str) -> usize {
+    s.graphemes(true).count()
+}
+
+/// Returns the longest prefix of `s` with at most `max` user-perceived characters.
+///
+/// # Examples
+///
+/// ```
+/// use waterui_str::grapheme::truncate_graphemes;
+///
+/// // Initials for an avatar
+/// assert_eq!(truncate_graphemes("🇯🇵 Tokyo", 1), "🇯🇵");
+/// assert_eq!(truncate_graphemes("e\u{301}cole", 1), "e\u{301}");
+/// assert_eq!(truncate_graphemes("short", 10), "short");
+/// ```
+#[must_use]
+pub fn truncate_graphemes(s: &str, max: usize) -> &str {
+    s.grapheme_indices(true)
+        .nth(max)
+        .map_or(s, |(end, _)| &s[..end])
+}
+
+impl Str {
+    /// Returns the number of user-perceived characters (grapheme clusters).
+    ///
+    /// Use this rather than [`len`](Str::len) for character counters.
+    #[must_use]
+    pub fn grapheme_count(&self) -> usize {
+        grapheme_count(self)
+    }
+
+    /// Returns the first `max` user-perceived characters.
+    ///
+    /// Never splits an emoji or a letter from its combining marks. Truncating a
+    /// static string does not allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waterui_str::Str;
+    ///
+    /// let name = Str::from("👨‍🚀 Astronaut");
+    /// assert_eq!(name.truncate_graphemes(1), "👨‍🚀");
+    /// ```
+    #[must_use]
+    pub fn truncate_graphemes(&self, max: usize) -> Self {
+        let prefix = truncate_graphemes(self, max);
+        if prefix.len() == self.len() {
+            return self.clone();
+        }
+        if self.is_shared() {
+            Self::from(String::from(prefix))
+        } else {
+            // SAFETY: a non-shared `Str` always points to `'static` data.
+            Self::from_static(unsafe { &*core::ptr::from_ref::<str>(prefix) })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emoji_sequences_are_single_graphemes() {
+        assert_eq!(grapheme_count("👨‍👩‍👧‍👦"), 1);
+        assert_eq!(grapheme_count("🏳️‍🌈"), 1);
+        assert_eq!(grapheme_count(""), 0);
+    }
+
+    #[test]
+    fn test_truncate_keeps_combining_marks() {
+        assert_eq!(truncate_graphemes("a\u{308}bc", 1), "a\u{308}");
+        assert_eq!(truncate_graphemes("abc", 0), "");
+    }
+
+    #[test]
+    fn test_truncate_str() {
+        let owned = Str::from(String::from("👍🏽 great"));
+        assert_eq!(owned.truncate_graphemes(2), "👍🏽 ");
+        assert_eq!(owned.grapheme_count(), 7);
+
+        let fixed = Str::from_static("hello");
+        let truncated = fixed.truncate_graphemes(2);
+        assert_eq!(truncated, "he");
+        assert!(!truncated.is_shared());
+    }
+}
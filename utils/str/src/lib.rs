@@ -2,6 +2,7 @@
 #![allow(clippy::cast_possible_wrap)]
 extern crate alloc;
 
+pub mod grapheme;
 mod impls;
 mod shared;
 use alloc::{
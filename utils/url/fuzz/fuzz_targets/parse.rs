@@ -0,0 +1,27 @@
+//! Parses arbitrary strings as URLs and checks the span invariants.
+//!
+//! Run with `cargo +nightly fuzz run parse` from `utils/url`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use waterui_url::Url;
+
+fuzz_target!(|input: &str| {
+    if let Ok(url) = input.parse::<Url>() {
+        url.validate_spans().expect("parsed URL has invalid spans");
+        assert_eq!(url.as_str(), input);
+        let _ = (url.scheme(), url.host(), url.port(), url.path());
+        let _ = (url.query(), url.fragment(), url.extension(), url.filename());
+
+        let joined = url.join(input);
+        joined
+            .validate_spans()
+            .expect("joined URL has invalid spans");
+    }
+
+    let local = Url::from_file_path_str(input.to_owned());
+    local
+        .validate_spans()
+        .expect("file path URL has invalid spans");
+});
@@ -0,0 +1,340 @@
+//! Programmatic URL construction.
+//!
+//! [`UrlBuilder`] assembles a URL from its components and percent-encodes each
+//! one with the character set RFC 3986 allows in that position, so callers never
+//! have to format or escape URL strings by hand.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::{ParseError, Url, parser};
+
+/// Characters that never need encoding (RFC 3986 `unreserved`).
+pub const fn is_unreserved(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~')
+}
+
+/// Characters allowed in a path segment (RFC 3986 `pchar`).
+const fn is_pchar(b: u8) -> bool {
+    is_unreserved(b)
+        || matches!(
+            b,
+            b'!' | b'$'
+                | b'&'
+                | b'\''
+                | b'('
+                | b')'
+                | b'*'
+                | b'+'
+                | b','
+                | b';'
+                | b'='
+                | b':'
+                | b'@'
+        )
+}
+
+/// Characters allowed in a fragment (RFC 3986 `fragment`).
+const fn is_fragment_char(b: u8) -> bool {
+    is_pchar(b) || matches!(b, b'/' | b'?')
+}
+
+/// Appends `input` to `out`, percent-encoding every byte not accepted by `allowed`.
+pub fn encode_into(out: &mut String, input: &str, allowed: fn(u8) -> bool) {
+    for &b in input.as_bytes() {
+        if allowed(b) {
+            out.push(char::from(b));
+        } else {
+            let _ = write!(out, "%{b:02X}");
+        }
+    }
+}
+
+/// Builder for constructing a [`Url`] from individual components.
+///
+/// Created with [`Url::builder`]. Path segments, query parameters and the
+/// fragment are percent-encoded as needed; the scheme and host are validated.
+///
+/// # Examples
+///
+/// ```
+/// use waterui_url::Url;
+///
+/// let url = Url::builder()
+///     .host("example.com")
+///     .path_segments(["api", "v1", "search"])
+///     .query_param("q", "rust & ui")
+///     .build()?;
+/// assert_eq!(url.as_str(), "https://example.com/api/v1/search?q=rust%20%26%20ui");
+/// # Ok::<(), waterui_url::ParseError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlBuilder {
+    scheme: String,
+    host: Option<String>,
+    port: Option<u16>,
+    path: String,
+    query: Vec<String>,
+    fragment: Option<String>,
+}
+
+impl Default for UrlBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl UrlBuilder {
+    /// Creates a builder for an `https` URL with no host, path or query.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            scheme: String::from("https"),
+            host: None,
+            port: None,
+            path: String::new(),
+            query: Vec::new(),
+            fragment: None,
+        }
+    }
+
+    /// Sets the scheme (e.g., "https", "wss", "mailto"). Defaults to "https".
+    #[must_use]
+    pub fn scheme(mut self, scheme: &str) -> Self {
+        self.scheme = scheme.to_ascii_lowercase();
+        self
+    }
+
+    /// Sets the host. IPv6 addresses may be given with or without brackets.
+    #[must_use]
+    pub fn host(mut self, host: &str) -> Self {
+        self.host = Some(String::from(host));
+        self
+    }
+
+    /// Sets the port.
+    #[must_use]
+    pub const fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Appends a single path segment, encoding any `/`, `?` or `#` it contains.
+    #[must_use]
+    pub fn path_segment(mut self, segment: &str) -> Self {
+        self.path.push('/');
+        encode_into(&mut self.path, segment, is_pchar);
+        self
+    }
+
+    /// Appends several path segments in order.
+    #[must_use]
+    pub fn path_segments<I>(self, segments: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        segments.into_iter().fold(self, |builder, segment| {
+            builder.path_segment(segment.as_ref())
+        })
+    }
+
+    /// Appends a `/`-separated path, encoding each segment.
+    ///
+    /// Empty segments (from leading, trailing or repeated slashes) are skipped.
+    #[must_use]
+    pub fn path(self, path: &str) -> Self {
+        self.path_segments(path.split('/').filter(|segment| !segment.is_empty()))
+    }
+
+    /// Appends a `key=value` query parameter. Parameters keep their insertion order.
+    #[must_use]
+    pub fn query_param(mut self, key: &str, value: &str) -> Self {
+        let mut pair = String::with_capacity(key.len() + value.len() + 1);
+        encode_into(&mut pair, key, is_unreserved);
+        pair.push('=');
+        encode_into(&mut pair, value, is_unreserved);
+        self.query.push(pair);
+        self
+    }
+
+    /// Sets the fragment (the part after `#`).
+    #[must_use]
+    pub fn fragment(mut self, fragment: &str) -> Self {
+        let mut encoded = String::with_capacity(fragment.len());
+        encode_into(&mut encoded, fragment, is_fragment_char);
+        self.fragment = Some(encoded);
+        self
+    }
+
+    /// Assembles and parses the URL.
+    ///
+    /// URLs with a host are written as `scheme://host[:port]/path`; URLs without
+    /// one, such as `mailto:`, as `scheme:path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if the scheme is not a valid RFC 3986 scheme, the
+    /// host is empty or contains characters that are not allowed in a host, or the
+    /// resulting URL cannot be parsed (e.g. a web scheme without a host).
+    pub fn build(self) -> Result<Url, ParseError> {
+        let mut scheme = self.scheme.bytes();
+        let valid_scheme = scheme.next().is_some_and(|b| b.is_ascii_alphabetic())
+            && scheme.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'));
+        if !valid_scheme {
+            return Err(ParseError::malformed("Invalid scheme"));
+        }
+
+        if self.host.is_none() && parser::is_web_scheme_name(self.scheme.as_bytes()) {
+            return Err(ParseError::malformed("Web URL must have a host"));
+        }
+
+        let mut url = self.scheme;
+        url.push(':');
+
+        if let Some(host) = &self.host {
+            url.push_str("//");
+            push_host(&mut url, host)?;
+            if let Some(port) = self.port {
+                let _ = write!(url, ":{port}");
+            }
+        }
+
+        if self.path.is_empty() {
+            if self.host.is_some() && (!self.query.is_empty() || self.fragment.is_some()) {
+                url.push('/');
+            }
+        } else if self.host.is_some() {
+            url.push_str(&self.path);
+        } else {
+            // Opaque URLs have no leading slash (`mailto:someone@example.com`).
+            url.push_str(&self.path[1..]);
+        }
+
+        if !self.query.is_empty() {
+            url.push('?');
+            url.push_str(&self.query.join("&"));
+        }
+
+        if let Some(fragment) = &self.fragment {
+            url.push('#');
+            url.push_str(fragment);
+        }
+
+        url.parse()
+    }
+}
+
+/// Appends a validated host, bracketing IPv6 addresses.
+fn push_host(url: &mut String, host: &str) -> Result<(), ParseError> {
+    let bare = host
+        .strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host);
+
+    if bare.is_empty() {
+        return Err(ParseError::malformed("Web URL must have a host"));
+    }
+
+    if bare.contains(':') {
+        if !bare
+            .bytes()
+            .all(|b| b.is_ascii_hexdigit() || matches!(b, b':' | b'.'))
+        {
+            return Err(ParseError::malformed("Invalid IPv6 host"));
+        }
+        url.push('[');
+        url.push_str(bare);
+        url.push(']');
+        return Ok(());
+    }
+
+    if bare
+        .chars()
+        .any(|c| c.is_whitespace() || c.is_control() || "/?#@[]%\\".contains(c))
+    {
+        return Err(ParseError::malformed("Invalid character in host"));
+    }
+    url.push_str(bare);
+    Ok(())
+}
+
+impl Url {
+    /// Creates a [`UrlBuilder`] for constructing a URL from its components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waterui_url::Url;
+    ///
+    /// let url = Url::builder()
+    ///     .scheme("http")
+    ///     .host("localhost")
+    ///     .port(3000)
+    ///     .path("files/my report.pdf")
+    ///     .fragment("page=2")
+    ///     .build()?;
+    /// assert_eq!(url.as_str(), "http://localhost:3000/files/my%20report.pdf#page=2");
+    /// assert_eq!(url.port(), Some(3000));
+    /// # Ok::<(), waterui_url::ParseError>(())
+    /// ```
+    #[must_use]
+    pub fn builder() -> UrlBuilder {
+        UrlBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segments_are_encoded() {
+        let url = Url::builder()
+            .host("example.com")
+            .path_segments(["a/b", "c?d", "é"])
+            .build()
+            .unwrap();
+        assert_eq!(url.path(), "/a%2Fb/c%3Fd/%C3%A9");
+    }
+
+    #[test]
+    fn test_query_params_keep_order() {
+        let url = Url::builder()
+            .host("example.com")
+            .query_param("b", "1=2")
+            .query_param("a", "x&y")
+            .build()
+            .unwrap();
+        assert_eq!(url.as_str(), "https://example.com/?b=1%3D2&a=x%26y");
+        assert_eq!(url.query(), Some("b=1%3D2&a=x%26y"));
+    }
+
+    #[test]
+    fn test_ipv6_host_is_bracketed() {
+        let url = Url::builder().host("::1").port(8080).build().unwrap();
+        assert_eq!(url.as_str(), "https://[::1]:8080");
+        assert_eq!(url.port(), Some(8080));
+    }
+
+    #[test]
+    fn test_opaque_url() {
+        let url = Url::builder()
+            .scheme("mailto")
+            .path("hello@example.com")
+            .query_param("subject", "Hi there")
+            .build()
+            .unwrap();
+        assert_eq!(url.as_str(), "mailto:hello@example.com?subject=Hi%20there");
+        assert!(url.is_opaque());
+    }
+
+    #[test]
+    fn test_invalid_components_are_rejected() {
+        assert!(Url::builder().build().is_err());
+        assert!(Url::builder().host("").build().is_err());
+        assert!(Url::builder().host("a/b").build().is_err());
+        assert!(Url::builder().scheme("1http").host("a").build().is_err());
+    }
+}
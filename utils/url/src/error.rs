@@ -12,6 +12,10 @@ pub struct ParseError {
 enum ParseErrorKind {
     /// The URL string is empty
     Empty,
+    /// The URL string is syntactically invalid
+    Malformed(&'static str),
+    /// A parsed component does not lie on UTF-8 boundaries inside the URL
+    InvalidSpan { start: u16, end: u16 },
 }
 
 impl ParseError {
@@ -21,12 +25,30 @@ impl ParseError {
             kind: ParseErrorKind::Empty,
         }
     }
+
+    /// Creates a new `ParseError` for syntactically invalid URLs.
+    pub(crate) const fn malformed(reason: &'static str) -> Self {
+        Self {
+            kind: ParseErrorKind::Malformed(reason),
+        }
+    }
+
+    /// Creates a new `ParseError` for a component span that cannot be sliced.
+    pub(crate) const fn invalid_span(start: u16, end: u16) -> Self {
+        Self {
+            kind: ParseErrorKind::InvalidSpan { start, end },
+        }
+    }
 }
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.kind {
             ParseErrorKind::Empty => write!(f, "URL string is empty"),
+            ParseErrorKind::Malformed(reason) => f.write_str(reason),
+            ParseErrorKind::InvalidSpan { start, end } => {
+                write!(f, "URL component {start}..{end} is not a valid UTF-8 range")
+            }
         }
     }
 }
@@ -0,0 +1,326 @@
+//! Reactive loading of URL content.
+//!
+//! [`Url::fetch`] starts loading in the background and returns a [`Fetched`]
+//! signal whose [`FetchState`] moves from [`FetchState::Pending`] through
+//! [`FetchState::Loading`] to [`FetchState::Loaded`] or [`FetchState::Failed`].
+//!
+//! - Web URLs are downloaded over HTTP(S), reporting progress per chunk
+//!   (`zenwave` on native targets, the browser's `fetch` on `wasm32`).
+//! - Local URLs are read with [`Url::read`].
+//! - Data URLs are decoded in place (base64 or percent-encoded).
+//!
+//! Blob and opaque URLs cannot be fetched and fail immediately.
+
+use alloc::format;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use nami::{Binding, Signal, watcher::Context};
+use waterui_str::Str;
+
+use crate::fs::percent_decode;
+use crate::{Url, UrlKind};
+
+/// The loading state of a [`Fetched`] URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchState {
+    /// The fetch has started but no data has arrived yet.
+    Pending,
+    /// Data is arriving.
+    Loading {
+        /// Bytes received so far.
+        received: u64,
+        /// Total size in bytes, if the server reported it.
+        total: Option<u64>,
+    },
+    /// All content has been received.
+    Loaded(Arc<[u8]>),
+    /// The fetch failed with the given message.
+    Failed(Str),
+}
+
+impl FetchState {
+    /// Returns the fraction of content received, from 0.0 to 1.0.
+    ///
+    /// Returns `None` while the total size is unknown and after a failure.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn progress(&self) -> Option<f64> {
+        match self {
+            Self::Pending => Some(0.0),
+            Self::Loading {
+                received,
+                total: Some(total),
+            } if *total > 0 => Some((*received as f64 / *total as f64).min(1.0)),
+            Self::Loading { .. } | Self::Failed(_) => None,
+            Self::Loaded(_) => Some(1.0),
+        }
+    }
+
+    /// Returns the content once it is fully loaded.
+    #[must_use]
+    pub fn data(&self) -> Option<&[u8]> {
+        match self {
+            Self::Loaded(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` once the fetch has either loaded or failed.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        matches!(self, Self::Loaded(_) | Self::Failed(_))
+    }
+}
+
+/// A reactive signal for fetched URL content.
+///
+/// Created by [`Url::fetch`]. Every clone observes the same fetch.
+#[derive(Debug, Clone)]
+pub struct Fetched {
+    url: Url,
+    state: Binding<FetchState>,
+}
+
+impl Fetched {
+    /// Returns the URL being fetched.
+    #[must_use]
+    pub const fn url(&self) -> &Url {
+        &self.url
+    }
+}
+
+impl Signal for Fetched {
+    type Output = FetchState;
+    type Guard = <Binding<FetchState> as Signal>::Guard;
+
+    fn get(&self) -> Self::Output {
+        self.state.get()
+    }
+
+    fn watch(&self, watcher: impl Fn(Context<Self::Output>) + 'static) -> Self::Guard {
+        self.state.watch(watcher)
+    }
+}
+
+impl Url {
+    /// Fetches the content at this URL.
+    ///
+    /// Loading starts immediately on the local executor. The returned signal
+    /// reports progress and completion, so views can show a progress indicator
+    /// while the content downloads.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use nami::Signal;
+    /// use waterui_url::{FetchState, Url};
+    ///
+    /// let fetched = Url::new("https://waterui.dev/logo.png").fetch();
+    /// let _guard = fetched.watch(|state| {
+    ///     if let FetchState::Loaded(bytes) = state.into_value() {
+    ///         let _ = bytes.len();
+    ///     }
+    /// });
+    /// ```
+    #[must_use]
+    pub fn fetch(&self) -> Fetched {
+        let state = Binding::container(FetchState::Pending);
+        let url = self.clone();
+        let task_state = state.clone();
+        executor_core::spawn_local(async move {
+            let result = match url.kind() {
+                UrlKind::Web => imp::download(&url, &task_state).await,
+                UrlKind::Local => url
+                    .read()
+                    .await
+                    .map_err(|error| Str::from(format!("{error}"))),
+                UrlKind::Data => decode_data_url(url.as_str()),
+                UrlKind::Blob | UrlKind::Opaque => Err(Str::from(format!("cannot fetch `{url}`"))),
+            };
+            task_state.set(match result {
+                Ok(data) => FetchState::Loaded(Arc::from(data)),
+                Err(message) => FetchState::Failed(message),
+            });
+        })
+        .detach();
+
+        Fetched {
+            url: self.clone(),
+            state,
+        }
+    }
+}
+
+/// Decodes the payload of a `data:` URL.
+fn decode_data_url(url: &str) -> Result<Vec<u8>, Str> {
+    let rest = url.get(5..).unwrap_or_default();
+    let (meta, payload) = rest
+        .split_once(',')
+        .ok_or_else(|| Str::from("data URL has no payload"))?;
+    if meta.ends_with(";base64") {
+        base64_decode(payload).ok_or_else(|| Str::from("invalid base64 in data URL"))
+    } else {
+        percent_decode(payload).ok_or_else(|| Str::from("invalid percent-encoding in data URL"))
+    }
+}
+
+fn base64_decode(input: &str) -> Option<Vec<u8>> {
+    const fn value(b: u8) -> Option<u8> {
+        match b {
+            b'A'..=b'Z' => Some(b - b'A'),
+            b'a'..=b'z' => Some(b - b'a' + 26),
+            b'0'..=b'9' => Some(b - b'0' + 52),
+            b'+' | b'-' => Some(62),
+            b'/' | b'_' => Some(63),
+            _ => None,
+        }
+    }
+
+    let digits: Vec<u8> = input.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    let digits = digits
+        .strip_suffix(b"==")
+        .or_else(|| digits.strip_suffix(b"="))
+        .unwrap_or(&digits);
+
+    let mut out = Vec::with_capacity(digits.len() * 3 / 4);
+    for chunk in digits.chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut bits = 0u32;
+        for (i, &b) in chunk.iter().enumerate() {
+            bits |= u32::from(value(b)?) << (18 - 6 * i);
+        }
+        let bytes = bits.to_be_bytes();
+        out.extend_from_slice(&bytes[1..chunk.len()]);
+    }
+    Some(out)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod imp {
+    use alloc::format;
+    use alloc::vec::Vec;
+
+    use futures_lite::StreamExt;
+    use nami::Binding;
+    use waterui_str::Str;
+
+    use super::FetchState;
+    use crate::Url;
+
+    fn error(error: impl core::fmt::Display) -> Str {
+        Str::from(format!("{error}"))
+    }
+
+    // Runs on the local executor that owns `state`, which is not `Send`
+    #[allow(clippy::future_not_send)]
+    pub async fn download(url: &Url, state: &Binding<FetchState>) -> Result<Vec<u8>, Str> {
+        let response = zenwave::get(url.as_str()).await.map_err(error)?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(Str::from(format!("HTTP {status} while fetching `{url}`")));
+        }
+
+        let total = response
+            .headers()
+            .get("content-length")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+
+        let mut body = response.into_body();
+        let mut data = Vec::new();
+        state.set(FetchState::Loading { received: 0, total });
+        while let Some(chunk) = body.next().await {
+            data.extend_from_slice(&chunk.map_err(error)?);
+            state.set(FetchState::Loading {
+                received: data.len() as u64,
+                total,
+            });
+        }
+        Ok(data)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod imp {
+    use alloc::format;
+    use alloc::vec::Vec;
+
+    use js_sys::Uint8Array;
+    use nami::Binding;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+    use waterui_str::Str;
+    use web_sys::Response;
+
+    use super::FetchState;
+    use crate::Url;
+
+    fn error(error: &wasm_bindgen::JsValue) -> Str {
+        Str::from(format!("{error:?}"))
+    }
+
+    /// Downloads with the browser's `fetch`, which does not report progress.
+    pub async fn download(url: &Url, state: &Binding<FetchState>) -> Result<Vec<u8>, Str> {
+        let window = web_sys::window().ok_or_else(|| Str::from("no window"))?;
+        let response: Response = JsFuture::from(window.fetch_with_str(url.as_str()))
+            .await
+            .map_err(|e| error(&e))?
+            .dyn_into()
+            .map_err(|e| error(&e))?;
+        if !response.ok() {
+            return Err(Str::from(format!(
+                "HTTP {} while fetching `{url}`",
+                response.status()
+            )));
+        }
+
+        state.set(FetchState::Loading {
+            received: 0,
+            total: None,
+        });
+        let buffer = JsFuture::from(response.array_buffer().map_err(|e| error(&e))?)
+            .await
+            .map_err(|e| error(&e))?;
+        Ok(Uint8Array::new(&buffer).to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_base64_data_url() {
+        let url = Url::from_data("text/plain", b"hello, world");
+        assert_eq!(decode_data_url(url.as_str()).unwrap(), b"hello, world");
+    }
+
+    #[test]
+    fn test_decode_percent_encoded_data_url() {
+        assert_eq!(
+            decode_data_url("data:text/plain,a%20b%2Cc").unwrap(),
+            b"a b,c"
+        );
+        assert!(decode_data_url("data:text/plain,%zz").is_err());
+        assert!(decode_data_url("data:text/plain").is_err());
+    }
+
+    #[test]
+    fn test_progress() {
+        assert_eq!(FetchState::Pending.progress(), Some(0.0));
+        let half = FetchState::Loading {
+            received: 50,
+            total: Some(100),
+        };
+        assert_eq!(half.progress(), Some(0.5));
+        let unknown = FetchState::Loading {
+            received: 50,
+            total: None,
+        };
+        assert_eq!(unknown.progress(), None);
+        assert!(FetchState::Loaded(Arc::from([])).is_finished());
+    }
+}
@@ -0,0 +1,350 @@
+//! Async file access for local URLs.
+//!
+//! [`Url::read`], [`Url::read_stream`] and [`Url::write`] work on local paths
+//! (including `file://` URLs) without blocking the executor. Native targets use
+//! `async-fs`; on `wasm32` paths are resolved inside the origin private file
+//! system (OPFS), relative to its root.
+//!
+//! Other kinds of URLs are rejected with [`io::ErrorKind::Unsupported`].
+//!
+//! The returned futures borrow the [`Url`], which is not `Send`, so they must be
+//! awaited on a local executor such as the UI thread's.
+
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::io;
+
+use futures_core::Stream;
+
+use crate::Url;
+
+/// Size of the chunks yielded by [`ReadStream`].
+const CHUNK_SIZE: usize = 64 * 1024;
+
+// Futures borrow the URL and are only awaited on local executors
+#[allow(clippy::future_not_send)]
+impl Url {
+    /// Returns the file system path of a local URL.
+    ///
+    /// `file://` URLs are percent-decoded; plain paths are used as written.
+    fn local_path(&self) -> io::Result<Cow<'_, str>> {
+        if !self.is_local() {
+            return Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "only local URLs can be read or written",
+            ));
+        }
+        let path = self.as_str();
+        let Some(encoded) = path.strip_prefix("file://") else {
+            return Ok(Cow::Borrowed(path));
+        };
+        if !encoded.contains('%') {
+            return Ok(Cow::Borrowed(encoded));
+        }
+        percent_decode(encoded)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .map(Cow::Owned)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid percent-encoding in file URL",
+                )
+            })
+    }
+
+    /// Reads the whole file behind a local URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not local or the file cannot be read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # async fn example() -> std::io::Result<()> {
+    /// use waterui_url::Url;
+    ///
+    /// let bytes = Url::new("/tmp/notes.txt").read().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read(&self) -> io::Result<Vec<u8>> {
+        imp::read(&self.local_path()?).await
+    }
+
+    /// Opens the file behind a local URL as a stream of byte chunks.
+    ///
+    /// Prefer this over [`Url::read`] for large files that can be processed
+    /// incrementally.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not local or the file cannot be opened.
+    pub async fn read_stream(&self) -> io::Result<ReadStream> {
+        imp::open(&self.local_path()?).await.map(ReadStream)
+    }
+
+    /// Writes `bytes` to the file behind a local URL, replacing its contents.
+    ///
+    /// The file is created if needed; its parent directory must exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not local or the file cannot be written.
+    pub async fn write(&self, bytes: impl AsRef<[u8]>) -> io::Result<()> {
+        imp::write(&self.local_path()?, bytes.as_ref()).await
+    }
+}
+
+/// A stream of byte chunks read from a local file.
+///
+/// Created by [`Url::read_stream`]. Each item is a chunk of at most 64 KiB.
+#[derive(Debug)]
+pub struct ReadStream(imp::Reader);
+
+impl Stream for ReadStream {
+    type Item = io::Result<Vec<u8>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_chunk(cx)
+    }
+}
+
+/// Decodes `%XX` escapes, returning `None` for malformed ones.
+pub fn percent_decode(input: &str) -> Option<Vec<u8>> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = input.get(i + 1..i + 3)?;
+            if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return None;
+            }
+            out.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            out.push(bytes[i]);
+            i += 1;
+        }
+    }
+    Some(out)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod imp {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+    use std::io;
+
+    use async_fs::File;
+    use futures_lite::AsyncRead;
+
+    use super::CHUNK_SIZE;
+
+    #[derive(Debug)]
+    pub struct Reader {
+        file: File,
+        done: bool,
+    }
+
+    impl Reader {
+        pub fn poll_chunk(&mut self, cx: &mut Context<'_>) -> Poll<Option<io::Result<Vec<u8>>>> {
+            if self.done {
+                return Poll::Ready(None);
+            }
+            let mut buf = vec![0; CHUNK_SIZE];
+            match Pin::new(&mut self.file).poll_read(cx, &mut buf) {
+                Poll::Ready(Ok(0)) => {
+                    self.done = true;
+                    Poll::Ready(None)
+                }
+                Poll::Ready(Ok(len)) => {
+                    buf.truncate(len);
+                    Poll::Ready(Some(Ok(buf)))
+                }
+                Poll::Ready(Err(error)) => {
+                    self.done = true;
+                    Poll::Ready(Some(Err(error)))
+                }
+                Poll::Pending => Poll::Pending,
+            }
+        }
+    }
+
+    pub async fn read(path: &str) -> io::Result<Vec<u8>> {
+        async_fs::read(path).await
+    }
+
+    pub async fn open(path: &str) -> io::Result<Reader> {
+        let file = File::open(path).await?;
+        Ok(Reader { file, done: false })
+    }
+
+    pub async fn write(path: &str, bytes: &[u8]) -> io::Result<()> {
+        async_fs::write(path, bytes).await
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod imp {
+    use alloc::format;
+    use alloc::vec::Vec;
+    use core::task::{Context, Poll};
+    use std::io;
+
+    use js_sys::{Promise, Uint8Array};
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::{
+        File, FileSystemDirectoryHandle, FileSystemFileHandle, FileSystemGetFileOptions,
+        FileSystemWritableFileStream,
+    };
+
+    use super::CHUNK_SIZE;
+
+    /// OPFS files are read into memory at once, then handed out in chunks.
+    #[derive(Debug)]
+    pub struct Reader {
+        data: Vec<u8>,
+        offset: usize,
+    }
+
+    impl Reader {
+        pub fn poll_chunk(&mut self, _cx: &mut Context<'_>) -> Poll<Option<io::Result<Vec<u8>>>> {
+            if self.offset >= self.data.len() {
+                return Poll::Ready(None);
+            }
+            let end = (self.offset + CHUNK_SIZE).min(self.data.len());
+            let chunk = self.data[self.offset..end].to_vec();
+            self.offset = end;
+            Poll::Ready(Some(Ok(chunk)))
+        }
+    }
+
+    fn js_error(error: JsValue) -> io::Error {
+        let kind = match error.dyn_ref::<js_sys::Error>().map(js_sys::Error::name) {
+            Some(name) if name == "NotFoundError" || name == "TypeMismatchError" => {
+                io::ErrorKind::NotFound
+            }
+            Some(name) if name == "NotAllowedError" => io::ErrorKind::PermissionDenied,
+            _ => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, format!("{error:?}"))
+    }
+
+    async fn resolve<T: JsCast>(promise: Promise) -> io::Result<T> {
+        let value = JsFuture::from(promise).await.map_err(js_error)?;
+        value
+            .dyn_into()
+            .map_err(|_| io::Error::other("unexpected value from the file system"))
+    }
+
+    /// Walks the OPFS directories of `path` and returns the file handle.
+    async fn file_handle(path: &str, create: bool) -> io::Result<FileSystemFileHandle> {
+        let window = web_sys::window()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Unsupported, "no window"))?;
+        let mut directory: FileSystemDirectoryHandle =
+            resolve(window.navigator().storage().get_directory()).await?;
+
+        let mut segments = path
+            .split(['/', '\\'])
+            .filter(|segment| !segment.is_empty() && *segment != ".")
+            .peekable();
+        while let Some(segment) = segments.next() {
+            if segments.peek().is_none() {
+                let options = FileSystemGetFileOptions::new();
+                options.set_create(create);
+                return resolve(directory.get_file_handle_with_options(segment, &options)).await;
+            }
+            directory = resolve(directory.get_directory_handle(segment)).await?;
+        }
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "path does not name a file",
+        ))
+    }
+
+    pub async fn read(path: &str) -> io::Result<Vec<u8>> {
+        let handle = file_handle(path, false).await?;
+        let file: File = resolve(handle.get_file()).await?;
+        let buffer = JsFuture::from(file.array_buffer())
+            .await
+            .map_err(js_error)?;
+        Ok(Uint8Array::new(&buffer).to_vec())
+    }
+
+    pub async fn open(path: &str) -> io::Result<Reader> {
+        read(path).await.map(|data| Reader { data, offset: 0 })
+    }
+
+    pub async fn write(path: &str, bytes: &[u8]) -> io::Result<()> {
+        let handle = file_handle(path, true).await?;
+        let stream: FileSystemWritableFileStream = resolve(handle.create_writable()).await?;
+        JsFuture::from(stream.write_with_u8_array(bytes).map_err(js_error)?)
+            .await
+            .map_err(js_error)?;
+        JsFuture::from(stream.close()).await.map_err(js_error)?;
+        Ok(())
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use futures_lite::{StreamExt, future::block_on};
+
+    #[test]
+    fn test_write_then_read() {
+        let path = std::env::temp_dir().join(format!("waterui-url-{}.bin", std::process::id()));
+        let url = Url::from_file_path(&path);
+        let data: Vec<u8> = (0..=255u8).cycle().take(CHUNK_SIZE + 10).collect();
+
+        block_on(async {
+            url.write(&data).await.unwrap();
+            assert_eq!(url.read().await.unwrap(), data);
+
+            let chunks: Vec<_> = url.read_stream().await.unwrap().collect().await;
+            let lengths: Vec<_> = chunks
+                .iter()
+                .map(|chunk| chunk.as_ref().unwrap().len())
+                .collect();
+            assert_eq!(lengths, [CHUNK_SIZE, 10]);
+        });
+
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_file_urls_are_percent_decoded() {
+        let dir = std::env::temp_dir().join(format!("waterui url {}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("a b.txt");
+        std::fs::write(&path, b"hello").unwrap();
+
+        let encoded = path.to_str().unwrap().replace(' ', "%20");
+        let url: Url = format!("file://{encoded}").parse().unwrap();
+        assert_eq!(url.local_path().unwrap(), path.to_str().unwrap());
+        assert_eq!(block_on(url.read()).unwrap(), b"hello");
+
+        let malformed = Url::new("file:///tmp/%zz");
+        assert_eq!(
+            malformed.local_path().unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_non_local_urls_are_rejected() {
+        let error = block_on(Url::new("https://example.com/a.txt").read()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Unsupported);
+    }
+}
@@ -24,22 +24,50 @@
 //! let url: Url = "https://example.com".parse()?;
 //! # Ok::<(), waterui_url::ParseError>(())
 //! ```
+//!
+//! # Building URLs
+//!
+//! To construct a URL from components with correct percent-encoding, use
+//! [`Url::builder`]:
+//!
+//! ```
+//! use waterui_url::Url;
+//!
+//! let url = Url::builder()
+//!     .host("api.example.com")
+//!     .path_segments(["users", "jane doe"])
+//!     .query_param("fields", "name,email")
+//!     .build()?;
+//! assert_eq!(url.as_str(), "https://api.example.com/users/jane%20doe?fields=name%2Cemail");
+//! # Ok::<(), waterui_url::ParseError>(())
+//! ```
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+mod builder;
 mod error;
+#[cfg(feature = "fetch")]
+mod fetch;
+#[cfg(feature = "fs")]
+mod fs;
+mod normalize;
 mod parser;
 
+pub use builder::UrlBuilder;
 pub use error::ParseError;
+#[cfg(feature = "fetch")]
+pub use fetch::{FetchState, Fetched};
+#[cfg(feature = "fs")]
+pub use fs::ReadStream;
+pub use normalize::NormalizeOptions;
 
 use alloc::borrow::Cow;
-use alloc::boxed::Box;
 
 use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::fmt;
-use nami_core::Signal;
 use waterui_str::Str;
 
 #[cfg(feature = "std")]
@@ -81,6 +109,7 @@ enum ParsedComponents {
     Local(LocalComponents),
     Data(DataComponents),
     Blob(BlobComponents),
+    Opaque(OpaqueComponents),
 }
 
 /// Components specific to web URLs (http://, https://, etc.).
@@ -131,6 +160,15 @@ struct BlobComponents {
     identifier: Span,
 }
 
+/// Components for opaque URLs (mailto:, tel:, sms:, custom app schemes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct OpaqueComponents {
+    /// URL scheme (e.g., "mailto")
+    scheme: Span,
+    /// Everything after the ':' (e.g., "hello@example.com")
+    payload: Span,
+}
+
 /// A URL that can represent either a web URL or a local file path.
 ///
 /// This type provides an ergonomic interface for working with both
@@ -176,6 +214,8 @@ pub enum UrlKind {
     Data,
     /// Blob URL (blob:)
     Blob,
+    /// Opaque URL with a non-web scheme (mailto:, tel:, sms:, myapp:)
+    Opaque,
 }
 
 impl Url {
@@ -247,16 +287,18 @@ impl Url {
     /// ```
     #[cfg(feature = "std")]
     pub fn from_file_path(path: impl AsRef<Path>) -> Self {
-        let path_str = path.as_ref().display().to_string();
-        let inner = Str::from(path_str);
-        let components = parser::parse_url(inner.as_bytes());
-        Self { inner, components }
+        Self::from_file_path_str(path.as_ref().display().to_string())
     }
 
     /// Creates a URL from a file path string.
+    ///
+    /// Strings that are not valid URLs (including the empty string) are kept as
+    /// local paths.
     pub fn from_file_path_str(path: impl Into<Str>) -> Self {
         let inner = path.into();
-        let components = parser::parse_url(inner.as_bytes());
+        let bytes = inner.as_bytes();
+        let components = parser::try_parse_url(bytes)
+            .unwrap_or_else(|_| ParsedComponents::Local(parser::parse_local_path(bytes)));
         Self { inner, components }
     }
 
@@ -285,18 +327,101 @@ impl Url {
 
     /// Helper method to extract a string slice from a Span.
     ///
-    /// # Safety
-    /// The parser ensures that all Span boundaries are valid UTF-8 boundaries.
+    /// Spans that do not lie on UTF-8 boundaries (see [`Url::validate_spans`])
+    /// yield an empty string rather than invalid UTF-8.
     #[inline]
     fn slice(&self, span: Span) -> &str {
         if !span.is_present() {
             return "";
         }
-        let bytes = self.inner.as_bytes();
-        let start = span.start as usize;
-        let end = span.end as usize;
-        // SAFETY: Parser ensures valid UTF-8 boundaries
-        unsafe { core::str::from_utf8_unchecked(&bytes[start..end]) }
+        self.inner
+            .get(span.start as usize..span.end as usize)
+            .unwrap_or_default()
+    }
+
+    /// All component spans of this URL, present or not.
+    const fn spans(&self) -> [Span; 7] {
+        match self.components {
+            ParsedComponents::Web(web) => [
+                web.scheme,
+                web.authority,
+                web.host,
+                web.port,
+                web.path,
+                web.query,
+                web.fragment,
+            ],
+            ParsedComponents::Local(local) => [
+                local.path,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+            ],
+            ParsedComponents::Data(data) => [
+                data.mime_type,
+                data.encoding,
+                data.data,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+            ],
+            ParsedComponents::Opaque(opaque) => [
+                opaque.scheme,
+                opaque.payload,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+            ],
+            ParsedComponents::Blob(blob) => [
+                blob.identifier,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+                Span::NONE,
+            ],
+        }
+    }
+
+    /// Checks that every parsed component lies inside the URL string on UTF-8
+    /// character boundaries.
+    ///
+    /// This invariant holds for every `Url` produced by the parser; the method
+    /// exists so tests and fuzzers can verify it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] describing the first component that is out of
+    /// bounds, reversed, or splits a multi-byte character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waterui_url::Url;
+    ///
+    /// let url: Url = "https://例え.jp:8080/パス?q=1#frag".parse()?;
+    /// assert!(url.validate_spans().is_ok());
+    /// # Ok::<(), waterui_url::ParseError>(())
+    /// ```
+    pub fn validate_spans(&self) -> Result<(), ParseError> {
+        for span in self.spans() {
+            if span.is_present()
+                && self
+                    .inner
+                    .get(span.start as usize..span.end as usize)
+                    .is_none()
+            {
+                return Err(ParseError::invalid_span(span.start, span.end));
+            }
+        }
+        Ok(())
     }
 
     /// Returns true if this is a web URL (http/https/ftp etc).
@@ -323,13 +448,61 @@ impl Url {
         matches!(self.components, ParsedComponents::Blob(_))
     }
 
+    /// Returns true if this is an opaque URL handled by a platform or app
+    /// handler, such as `mailto:`, `tel:`, `sms:` or a custom app scheme.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waterui_url::Url;
+    ///
+    /// assert!(Url::new("mailto:hello@example.com").is_opaque());
+    /// assert!(Url::new("myapp://settings/profile").is_opaque());
+    /// assert!(!Url::new("C:\\Users\\file.txt").is_opaque());
+    /// ```
+    #[must_use]
+    pub const fn is_opaque(&self) -> bool {
+        matches!(self.components, ParsedComponents::Opaque(_))
+    }
+
+    /// Returns the kind of this URL.
+    #[must_use]
+    pub const fn kind(&self) -> UrlKind {
+        match self.components {
+            ParsedComponents::Web(_) => UrlKind::Web,
+            ParsedComponents::Local(_) => UrlKind::Local,
+            ParsedComponents::Data(_) => UrlKind::Data,
+            ParsedComponents::Blob(_) => UrlKind::Blob,
+            ParsedComponents::Opaque(_) => UrlKind::Opaque,
+        }
+    }
+
+    /// Gets everything after the scheme of an opaque URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waterui_url::Url;
+    ///
+    /// assert_eq!(Url::new("tel:+1-555-0100").opaque_payload(), Some("+1-555-0100"));
+    /// assert_eq!(Url::new("https://example.com").opaque_payload(), None);
+    /// ```
+    #[must_use]
+    pub fn opaque_payload(&self) -> Option<&str> {
+        match self.components {
+            ParsedComponents::Opaque(opaque) => Some(self.slice(opaque.payload)),
+            _ => None,
+        }
+    }
+
     /// Returns true if this is an absolute path or URL.
     #[must_use]
     pub const fn is_absolute(&self) -> bool {
         match self.components {
-            ParsedComponents::Web(_) | ParsedComponents::Data(_) | ParsedComponents::Blob(_) => {
-                true
-            }
+            ParsedComponents::Web(_)
+            | ParsedComponents::Data(_)
+            | ParsedComponents::Blob(_)
+            | ParsedComponents::Opaque(_) => true,
             ParsedComponents::Local(local) => local.is_absolute,
         }
     }
@@ -353,6 +526,7 @@ impl Url {
     pub fn scheme(&self) -> Option<&str> {
         match self.components {
             ParsedComponents::Web(web) if web.scheme.is_present() => Some(self.slice(web.scheme)),
+            ParsedComponents::Opaque(opaque) => Some(self.slice(opaque.scheme)),
             ParsedComponents::Data(_) => Some("data"),
             ParsedComponents::Blob(_) => Some("blob"),
             ParsedComponents::Local(_) => Some("file"),
@@ -380,7 +554,9 @@ impl Url {
             ParsedComponents::Web(web) if web.path.is_present() => self.slice(web.path),
             ParsedComponents::Web(_) => "/", // No path means root
             ParsedComponents::Local(local) => self.slice(local.path),
-            ParsedComponents::Data(_) | ParsedComponents::Blob(_) => "",
+            ParsedComponents::Data(_) | ParsedComponents::Blob(_) | ParsedComponents::Opaque(_) => {
+                ""
+            }
         }
     }
 
@@ -418,6 +594,138 @@ impl Url {
         }
     }
 
+    /// Iterates over the `key=value` pairs of the query string.
+    ///
+    /// Keys and values are returned as they appear in the URL, still
+    /// percent-encoded. A pair without `=` yields an empty value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waterui_url::Url;
+    ///
+    /// let url = Url::new("https://example.com/search?q=rust&page=2&debug");
+    /// let pairs: Vec<_> = url.query_pairs().collect();
+    /// assert_eq!(pairs, [("q", "rust"), ("page", "2"), ("debug", "")]);
+    /// ```
+    pub fn query_pairs(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.query()
+            .into_iter()
+            .flat_map(|query| query.split('&'))
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+    }
+
+    /// Returns a copy of this URL with the query parameter `key` set to `value`.
+    ///
+    /// The key and value are percent-encoded. An existing parameter with the same
+    /// key is replaced in place (dropping any duplicates); otherwise the parameter
+    /// is appended. Non-web URLs are returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waterui_url::Url;
+    ///
+    /// let url = Url::new("https://example.com/search?q=rust&page=2#top");
+    /// let next = url.with_query_param("page", "3");
+    /// assert_eq!(next.as_str(), "https://example.com/search?q=rust&page=3#top");
+    ///
+    /// let filtered = next.with_query_param("tag", "ui & ux");
+    /// assert_eq!(filtered.query(), Some("q=rust&page=3&tag=ui%20%26%20ux"));
+    /// ```
+    #[must_use]
+    pub fn with_query_param(&self, key: &str, value: &str) -> Self {
+        let mut pair = String::with_capacity(key.len() + value.len() + 1);
+        builder::encode_into(&mut pair, key, builder::is_unreserved);
+        let encoded_key_len = pair.len();
+        pair.push('=');
+        builder::encode_into(&mut pair, value, builder::is_unreserved);
+        let encoded_key = &pair[..encoded_key_len];
+
+        let mut pairs = Vec::new();
+        let mut replaced = false;
+        for existing in self.query().unwrap_or_default().split('&') {
+            let k = existing.split_once('=').map_or(existing, |(k, _)| k);
+            if existing.is_empty() {
+                continue;
+            }
+            if k != key && k != encoded_key {
+                pairs.push(existing);
+            } else if !replaced {
+                pairs.push(&pair);
+                replaced = true;
+            }
+        }
+        if !replaced {
+            pairs.push(&pair);
+        }
+        self.with_query(&pairs.join("&"))
+    }
+
+    /// Returns a copy of this URL with every query parameter named `key` removed.
+    ///
+    /// The `?` is dropped once no parameters remain. Non-web URLs are returned
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use waterui_url::Url;
+    ///
+    /// let url = Url::new("https://example.com/?a=1&b=2&a=3#end");
+    /// assert_eq!(url.without_query_param("a").as_str(), "https://example.com/?b=2#end");
+    /// assert_eq!(
+    ///     url.without_query_param("a").without_query_param("b").as_str(),
+    ///     "https://example.com/#end"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn without_query_param(&self, key: &str) -> Self {
+        let mut encoded_key = String::with_capacity(key.len());
+        builder::encode_into(&mut encoded_key, key, builder::is_unreserved);
+
+        let query = self.query().unwrap_or_default();
+        let kept: Vec<&str> = query
+            .split('&')
+            .filter(|pair| {
+                let k = pair.split_once('=').map_or(*pair, |(k, _)| k);
+                !pair.is_empty() && k != key && k != encoded_key
+            })
+            .collect();
+        self.with_query(&kept.join("&"))
+    }
+
+    /// Returns a copy of this web URL with its query string replaced.
+    ///
+    /// An empty `query` removes the `?`. The spans are recomputed by reparsing.
+    fn with_query(&self, query: &str) -> Self {
+        let ParsedComponents::Web(web) = self.components else {
+            return self.clone();
+        };
+
+        let url = self.as_str();
+        let (start, end) = if web.query.is_present() {
+            // Include the leading '?'
+            (usize::from(web.query.start) - 1, usize::from(web.query.end))
+        } else if web.fragment.is_present() {
+            let hash = usize::from(web.fragment.start) - 1;
+            (hash, hash)
+        } else {
+            (url.len(), url.len())
+        };
+
+        let mut result = String::with_capacity(url.len() + query.len() + 1);
+        result.push_str(&url[..start]);
+        if !query.is_empty() {
+            result.push('?');
+            result.push_str(query);
+        }
+        result.push_str(&url[end..]);
+
+        result.parse().unwrap_or_else(|_| self.clone())
+    }
+
     /// Gets the fragment (without the '#') for web URLs.
     ///
     /// This is a new method enabled by the parsed component structure!
@@ -491,10 +799,12 @@ impl Url {
             return self.clone();
         }
 
-        // If path is absolute, return it as-is
-        if matches!(parser::parse_url(path.as_bytes()), ParsedComponents::Web(_))
-            || path.starts_with('/')
-        {
+        // If path is absolute, return it as-is; a malformed URL with a scheme
+        // is kept as a local path rather than appended to the base
+        let has_scheme = path
+            .split_once("://")
+            .is_some_and(|(scheme, _)| parser::is_scheme(scheme.as_bytes()));
+        if has_scheme || path.starts_with('/') {
             return path
                 .parse()
                 .unwrap_or_else(|_| Self::from_file_path_str(path.to_string()));
@@ -564,14 +874,6 @@ impl Url {
         }
     }
 
-    /// Fetches the content at this URL (for network resources).
-    ///
-    /// This returns a reactive signal that can be watched for changes.
-    #[must_use]
-    pub fn fetch(&self) -> Fetched {
-        Fetched { url: self.clone() }
-    }
-
     /// Returns the underlying string representation.
     #[must_use]
     pub const fn as_str(&self) -> &str {
@@ -616,9 +918,10 @@ impl core::str::FromStr for Url {
             return Err(ParseError::empty());
         }
 
+        let components = parser::try_parse_url(s.as_bytes()).map_err(ParseError::malformed)?;
         Ok(Self {
             inner: Str::from(s.to_string()),
-            components: parser::parse_url(s.as_bytes()),
+            components,
         })
     }
 }
@@ -670,34 +973,8 @@ impl From<Url> for Str {
 // This allows Url to be used directly with `IntoComputed<Url>`
 nami_core::impl_constant!(Url);
 
-/// A reactive signal for fetched URL content.
-#[derive(Debug, Clone)]
-pub struct Fetched {
-    url: Url,
-}
-
-impl Signal for Fetched {
-    type Output = Option<Url>;
-    type Guard = nami_core::watcher::BoxWatcherGuard;
-
-    fn get(&self) -> Self::Output {
-        // TODO: Implement actual fetching logic
-        Some(self.url.clone())
-    }
-
-    fn watch(
-        &self,
-        _watcher: impl Fn(nami_core::watcher::Context<Self::Output>) + 'static,
-    ) -> Self::Guard {
-        // TODO: Implement actual watching logic
-        Box::new(())
-    }
-}
-
 // Simple base64 encoding for data URLs
 fn base64_encode(data: &[u8]) -> String {
-    use alloc::vec::Vec;
-
     const TABLE: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
 
     let mut result = Vec::with_capacity(data.len().div_ceil(3) * 4);
@@ -943,6 +1220,34 @@ mod tests {
         assert_eq!(IPV6.path(), "/test");
     }
 
+    #[test]
+    fn test_query_params() {
+        let url = Url::new("https://example.com?a=1&b=2#frag");
+        assert_eq!(
+            url.query_pairs().collect::<Vec<_>>(),
+            [("a", "1"), ("b", "2")]
+        );
+
+        let url = url.with_query_param("a", "x y").with_query_param("c", "");
+        assert_eq!(url.as_str(), "https://example.com?a=x%20y&b=2&c=#frag");
+        assert_eq!(url.fragment(), Some("frag"));
+        assert!(url.validate_spans().is_ok());
+
+        let url = url.without_query_param("a").without_query_param("b");
+        assert_eq!(url.query(), Some("c="));
+        let url = url.without_query_param("c");
+        assert_eq!(url.as_str(), "https://example.com#frag");
+        assert_eq!(url.query(), None);
+
+        let added = Url::new("https://example.com/path").with_query_param("q", "1");
+        assert_eq!(added.as_str(), "https://example.com/path?q=1");
+        assert_eq!(added.path(), "/path");
+
+        let local = Url::new("/tmp/file.txt");
+        assert_eq!(local.with_query_param("q", "1"), local);
+        assert_eq!(local.query_pairs().count(), 0);
+    }
+
     #[test]
     fn test_query_and_fragment() {
         const URL1: Url = Url::new("https://example.com?foo=bar");
@@ -1022,4 +1327,116 @@ mod tests {
         assert!(Url::new("../parent/path").is_relative());
         assert!(Url::new("file.txt").is_relative());
     }
+
+    #[test]
+    fn test_malformed_web_urls_are_errors() {
+        assert!("https://".parse::<Url>().is_err());
+        assert!("http://host:80a".parse::<Url>().is_err());
+        assert!(Url::new("https://example.com").join("https://").is_local());
+        assert!(Url::from_file_path_str("").is_local());
+    }
+
+    #[test]
+    fn test_overlong_urls_are_rejected() {
+        let long = "a".repeat(parser::MAX_LEN + 1);
+        assert!(long.parse::<Url>().is_err());
+
+        // Clamping must not split a multi-byte character.
+        let path = format!("a{}", "é".repeat(parser::MAX_LEN));
+        let url = Url::from_file_path_str(path);
+        assert!(url.validate_spans().is_ok());
+        assert_eq!(url.path().len(), parser::MAX_LEN - 1);
+    }
+
+    mod properties {
+        use super::*;
+        use alloc::format;
+        use core::fmt::Write;
+        use proptest::prelude::*;
+
+        /// Checks that every accessor can be called without panicking.
+        fn touch_accessors(url: &Url) {
+            let _ = (url.scheme(), url.host(), url.port(), url.path());
+            let _ = (url.query(), url.fragment(), url.authority());
+            let _ = (url.extension(), url.filename());
+        }
+
+        proptest! {
+            #[test]
+            fn parse_never_panics(input in "\\PC{0,64}") {
+                if let Ok(url) = input.parse::<Url>() {
+                    prop_assert!(url.validate_spans().is_ok());
+                    prop_assert_eq!(url.as_str(), input.as_str());
+                    touch_accessors(&url);
+                }
+            }
+
+            #[test]
+            fn web_urls_have_valid_spans(
+                scheme in "https?|ftp|wss?",
+                host in "[a-zé0-9.-]{1,12}|\\[[0-9a-f:]{2,10}\\]",
+                port in proptest::option::of(any::<u16>()),
+                path in "(/[\\PC&&[^?#]]{0,8}){0,3}",
+                query in proptest::option::of("[\\PC&&[^#]]{0,8}"),
+                fragment in proptest::option::of("\\PC{0,8}"),
+            ) {
+                let mut input = format!("{scheme}://{host}");
+                if let Some(port) = port {
+                    write!(input, ":{port}").unwrap();
+                }
+                input.push_str(&path);
+                if let Some(query) = query {
+                    input.push('?');
+                    input.push_str(&query);
+                }
+                if let Some(fragment) = fragment {
+                    input.push('#');
+                    input.push_str(&fragment);
+                }
+
+                let url: Url = input.parse().unwrap();
+                prop_assert!(url.is_web());
+                prop_assert!(url.validate_spans().is_ok());
+                prop_assert_eq!(url.scheme(), Some(scheme.as_str()));
+                touch_accessors(&url);
+            }
+
+            #[test]
+            fn join_produces_valid_urls(base in "\\PC{1,32}", path in "\\PC{0,16}") {
+                let joined = Url::from_file_path_str(base).join(&path);
+                prop_assert!(joined.validate_spans().is_ok());
+                touch_accessors(&joined);
+            }
+        }
+    }
+
+    #[test]
+    fn test_opaque_urls() {
+        let mail: Url = "mailto:hello@example.com?subject=Hi".parse().unwrap();
+        assert_eq!(mail.kind(), UrlKind::Opaque);
+        assert_eq!(mail.scheme(), Some("mailto"));
+        assert_eq!(mail.opaque_payload(), Some("hello@example.com?subject=Hi"));
+        assert_eq!(mail.path(), "");
+        assert!(mail.is_absolute());
+
+        assert_eq!(Url::new("sms:+15550100").scheme(), Some("sms"));
+        assert_eq!(
+            Url::new("myapp://open/item?id=1").opaque_payload(),
+            Some("//open/item?id=1")
+        );
+        assert!(Url::new("./file:name.txt").is_local());
+        assert!(Url::new("file:///tmp/a.txt").is_local());
+    }
+
+    #[test]
+    fn test_colon_file_names_are_local() {
+        for name in ["x:y.txt", "1a:b.txt", "my notes:2024.txt", "C:file.txt"] {
+            let url: Url = name.parse().unwrap();
+            assert_eq!(url.kind(), UrlKind::Local, "{name}");
+        }
+
+        let base = Url::new("https://example.com/docs/");
+        assert_eq!(base.join("x:y.txt").as_str(), "https://example.com/docs/x:y.txt");
+        assert_eq!(Url::new("tel:+15550100").kind(), UrlKind::Opaque);
+    }
 }
@@ -8,13 +8,19 @@
 #![allow(clippy::cast_possible_truncation)]
 
 use crate::{
-    BlobComponents, DataComponents, LocalComponents, ParsedComponents, Span, WebComponents,
+    BlobComponents, DataComponents, LocalComponents, OpaqueComponents, ParsedComponents, Span,
+    WebComponents,
 };
 
 // ============================================================================
 // Public API
 // ============================================================================
 
+/// Longest URL, in bytes, whose components can be represented by a `Span`.
+///
+/// `0xFFFF` is reserved as the "not present" sentinel.
+pub const MAX_LEN: usize = 0xFFFE;
+
 /// Main entry point for URL parsing with validation.
 ///
 /// Detects the URL type and parses all components into a `ParsedComponents` struct.
@@ -25,62 +31,101 @@ use crate::{
 /// Panics if the URL is malformed. This enables compile-time syntax checking
 /// when used in const contexts.
 pub const fn parse_url(bytes: &[u8]) -> ParsedComponents {
+    match try_parse_url(bytes) {
+        Ok(components) => components,
+        Err(reason) => panic!("{}", reason),
+    }
+}
+
+/// Non-panicking variant of [`parse_url`] used for runtime parsing.
+///
+/// Returns a static description of the problem if the URL is malformed.
+pub const fn try_parse_url(bytes: &[u8]) -> Result<ParsedComponents, &'static str> {
     let len = bytes.len();
 
     // Check for empty URL
-    assert!(len != 0, "URL string is empty");
+    if len == 0 {
+        return Err("URL string is empty");
+    }
+    if len > MAX_LEN {
+        return Err("URL string is too long");
+    }
 
     // Check for data: URLs
     if len >= 5 && starts_with(bytes, b"data:") {
-        return ParsedComponents::Data(parse_data_url(bytes));
+        return Ok(ParsedComponents::Data(parse_data_url(bytes)));
     }
 
     // Check for blob: URLs
     if len >= 5 && starts_with(bytes, b"blob:") {
-        return ParsedComponents::Blob(parse_blob_url(bytes));
+        return Ok(ParsedComponents::Blob(parse_blob_url(bytes)));
     }
 
     // Check for web URLs (http://, https://, etc.)
     if let Some(scheme_end) = find_scheme_end(bytes) {
         if is_web_scheme(bytes, scheme_end) {
             let web = parse_web_url(bytes, scheme_end);
-            validate_web_url(&web, bytes);
-            return ParsedComponents::Web(web);
+            if let Err(reason) = validate_web_url(&web, bytes) {
+                return Err(reason);
+            }
+            return Ok(ParsedComponents::Web(web));
+        }
+
+        // Check for opaque URLs (mailto:, tel:, sms:, custom app schemes)
+        if is_opaque_scheme(bytes, scheme_end) {
+            return Ok(ParsedComponents::Opaque(OpaqueComponents {
+                scheme: Span {
+                    start: 0,
+                    end: scheme_end as u16,
+                },
+                payload: Span {
+                    start: (scheme_end + 1) as u16,
+                    end: len as u16,
+                },
+            }));
         }
     }
 
     // Default to local file path
-    ParsedComponents::Local(parse_local_path(bytes))
+    Ok(ParsedComponents::Local(parse_local_path(bytes)))
 }
 
-/// Validates a parsed web URL and panics if malformed.
-const fn validate_web_url(web: &WebComponents, bytes: &[u8]) {
+/// Validates a parsed web URL.
+const fn validate_web_url(web: &WebComponents, bytes: &[u8]) -> Result<(), &'static str> {
     // Scheme must be present
-    assert!(web.scheme.is_present(), "Web URL must have a scheme");
+    if !web.scheme.is_present() {
+        return Err("Web URL must have a scheme");
+    }
 
     // Host must be present for web URLs
-    assert!(web.host.is_present(), "Web URL must have a host");
+    if !web.host.is_present() {
+        return Err("Web URL must have a host");
+    }
 
     // Validate port if present (must be valid digits)
     if web.port.is_present() {
         let port_start = web.port.start as usize;
         let port_end = web.port.end as usize;
 
-        assert!(port_start < port_end, "Invalid port: empty");
+        if port_start >= port_end {
+            return Err("Invalid port: empty");
+        }
 
         // Check all characters are digits
         let mut i = port_start;
         while i < port_end {
-            assert!(
-                is_digit(bytes[i]),
-                "Invalid port: contains non-digit characters"
-            );
+            if !is_digit(bytes[i]) {
+                return Err("Invalid port: contains non-digit characters");
+            }
             i += 1;
         }
 
         // Port number should be reasonable (1-65535)
-        assert!(port_end - port_start <= 5, "Invalid port: too many digits");
+        if port_end - port_start > 5 {
+            return Err("Invalid port: too many digits");
+        }
     }
+    Ok(())
 }
 
 /// Check if a byte is an ASCII digit
@@ -334,7 +379,7 @@ const fn parse_blob_url(bytes: &[u8]) -> BlobComponents {
 // Local Path Parser
 // ============================================================================
 
-const fn parse_local_path(bytes: &[u8]) -> LocalComponents {
+pub const fn parse_local_path(bytes: &[u8]) -> LocalComponents {
     let len = bytes.len();
 
     // Determine if absolute
@@ -356,10 +401,17 @@ const fn parse_local_path(bytes: &[u8]) -> LocalComponents {
         contains_byte(bytes, b'\\')
     };
 
+    // Overlong paths are clamped back to the last char boundary so the span
+    // stays valid UTF-8.
+    let mut end = if len > MAX_LEN { MAX_LEN } else { len };
+    while end < len && bytes[end] & 0xC0 == 0x80 {
+        end -= 1;
+    }
+
     LocalComponents {
         path: Span {
             start: 0,
-            end: len as u16,
+            end: end as u16,
         },
         is_absolute,
         is_windows,
@@ -399,14 +451,59 @@ const fn is_web_scheme(bytes: &[u8], scheme_end: usize) -> bool {
     }
 
     // Check for known web schemes
-    starts_with(bytes, b"http://")
-        || starts_with(bytes, b"https://")
-        || starts_with(bytes, b"ftp://")
-        || starts_with(bytes, b"ftps://")
-        || starts_with(bytes, b"ws://")
-        || starts_with(bytes, b"wss://")
-        || starts_with(bytes, b"rtsp://")
-        || starts_with(bytes, b"rtmp://")
+    is_web_scheme_name(bytes.split_at(scheme_end).0)
+}
+
+/// Check if `name` is one of the known web schemes (case-insensitive, per RFC 3986).
+pub const fn is_web_scheme_name(name: &[u8]) -> bool {
+    const WEB_SCHEMES: [&[u8]; 8] = [
+        b"http", b"https", b"ftp", b"ftps", b"ws", b"wss", b"rtsp", b"rtmp",
+    ];
+
+    let mut i = 0;
+    while i < WEB_SCHEMES.len() {
+        let scheme = WEB_SCHEMES[i];
+        if name.len() == scheme.len() && starts_with_ignore_case(name, scheme) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Check if `scheme` is a URL scheme name.
+///
+/// Follows RFC 3986 (`ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )`), but requires
+/// at least two characters so Windows drive letters (`C:`) stay local paths.
+pub const fn is_scheme(scheme: &[u8]) -> bool {
+    if scheme.len() < 2 || !scheme[0].is_ascii_alphabetic() {
+        return false;
+    }
+
+    let mut i = 1;
+    while i < scheme.len() {
+        let b = scheme[i];
+        if !(b.is_ascii_alphanumeric() || b == b'+' || b == b'-' || b == b'.') {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Check if the bytes before `scheme_end` form a non-web URL scheme.
+///
+/// Leaves `file:` to the local path parser.
+const fn is_opaque_scheme(bytes: &[u8], scheme_end: usize) -> bool {
+    if !is_scheme(bytes.split_at(scheme_end).0) {
+        return false;
+    }
+
+    !(scheme_end == 4
+        && bytes[0].eq_ignore_ascii_case(&b'f')
+        && bytes[1].eq_ignore_ascii_case(&b'i')
+        && bytes[2].eq_ignore_ascii_case(&b'l')
+        && bytes[3].eq_ignore_ascii_case(&b'e'))
 }
 
 /// Find first occurrence of any character in set, or end of string
@@ -452,6 +549,22 @@ const fn contains_byte(bytes: &[u8], byte: u8) -> bool {
     false
 }
 
+/// Check if bytes starts with a lowercase prefix, ignoring ASCII case
+const fn starts_with_ignore_case(bytes: &[u8], prefix: &[u8]) -> bool {
+    if bytes.len() < prefix.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < prefix.len() {
+        if bytes[i].to_ascii_lowercase() != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 /// Check if bytes starts with prefix
 const fn starts_with(bytes: &[u8], prefix: &[u8]) -> bool {
     if bytes.len() < prefix.len() {
@@ -521,6 +634,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_opaque_url() {
+        let components = parse_url(b"mailto:hi@example.com");
+        if let ParsedComponents::Opaque(opaque) = components {
+            assert_eq!((opaque.scheme.start, opaque.scheme.end), (0, 6));
+            assert_eq!((opaque.payload.start, opaque.payload.end), (7, 21));
+        } else {
+            panic!("Expected Opaque components");
+        }
+
+        assert!(matches!(parse_url(b"C:/file"), ParsedComponents::Local(_)));
+        assert!(matches!(
+            parse_url(b"file:/tmp"),
+            ParsedComponents::Local(_)
+        ));
+        assert!(matches!(parse_url(b"my_app:x"), ParsedComponents::Local(_)));
+    }
+
     #[test]
     fn test_const_evaluation() {
         // Verify const compatibility